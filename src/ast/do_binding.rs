@@ -0,0 +1,17 @@
+//! Loop variable binding for do expressions.
+
+use crate::diagnostics::Spanned;
+use serde::{Deserialize, Serialize};
+
+use super::Expr;
+
+/// A loop variable of a do expression.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DoBinding {
+    /// Name of the loop variable
+    pub name: String,
+    /// Initial value expression
+    pub init: Spanned<Expr>,
+    /// Step expression evaluated at the end of each iteration
+    pub step: Option<Spanned<Expr>>,
+}
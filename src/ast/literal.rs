@@ -1,5 +1,6 @@
 //! Literal value types for the Lambdust AST.
 
+use crate::numeric::BigInt;
 use serde::{Deserialize, Serialize};
 use std::fmt;
 use std::hash::{Hash, Hasher};
@@ -10,6 +11,9 @@ pub enum Literal {
     /// Exact integer numbers (preserves exactness)
     ExactInteger(i64),
     
+    /// Exact integers outside the i64 range
+    BigInteger(BigInt),
+    
     /// Inexact floating-point numbers
     InexactReal(f64),
     
@@ -110,6 +114,7 @@ impl Literal {
     pub fn is_number(&self) -> bool {
         matches!(self, 
             Literal::ExactInteger(_) | 
+            Literal::BigInteger(_) |
             Literal::InexactReal(_) |
             Literal::Number(_) |
             Literal::Rational { .. } | 
@@ -120,7 +125,7 @@ impl Literal {
     /// Returns true if this literal is exact (integer or rational).
     pub fn is_exact(&self) -> bool {
         match self {
-            Literal::ExactInteger(_) | Literal::Rational { .. } => true,
+            Literal::ExactInteger(_) | Literal::BigInteger(_) | Literal::Rational { .. } => true,
             Literal::Number(n) => n.fract() == 0.0 && n.is_finite(),
             Literal::Complex { real, imaginary } => {
                 // Complex is exact only if both parts are exact (represent as rationals)
@@ -147,6 +152,7 @@ impl Literal {
     pub fn is_real(&self) -> bool {
         matches!(self, 
             Literal::ExactInteger(_) |
+            Literal::BigInteger(_) |
             Literal::InexactReal(_) | 
             Literal::Number(_) |
             Literal::Rational { .. }
@@ -156,7 +162,7 @@ impl Literal {
     /// Returns true if this literal is an integer.
     pub fn is_integer(&self) -> bool {
         match self {
-            Literal::ExactInteger(_) => true,
+            Literal::ExactInteger(_) | Literal::BigInteger(_) => true,
             Literal::InexactReal(n) => n.fract() == 0.0 && n.is_finite(),
             Literal::Number(n) => n.fract() == 0.0 && n.is_finite(),
             Literal::Rational { denominator, .. } => *denominator == 1,
@@ -172,6 +178,7 @@ impl Literal {
     pub fn to_f64(&self) -> Option<f64> {
         match self {
             Literal::ExactInteger(n) => Some(*n as f64),
+            Literal::BigInteger(n) => n.to_f64(),
             Literal::InexactReal(n) => Some(*n),
             Literal::Number(n) => Some(*n),
             Literal::Rational { numerator, denominator } => {
@@ -225,6 +232,7 @@ impl Literal {
     pub fn as_numeric_f64(&self) -> Option<f64> {
         match self {
             Literal::ExactInteger(i) => Some(*i as f64),
+            Literal::BigInteger(n) => n.to_f64(),
             Literal::InexactReal(f) => Some(*f),
             Literal::Number(n) => Some(*n),
             Literal::Rational { numerator, denominator } => {
@@ -257,21 +265,10 @@ impl fmt::Display for Literal {
             Literal::ExactInteger(n) => {
                 write!(f, "{n}")
             }
-            Literal::InexactReal(n) => {
-                if n.is_infinite() {
-                    if n.is_sign_positive() {
-                        write!(f, "+inf.0")
-                    } else {
-                        write!(f, "-inf.0")
-                    }
-                } else if n.is_nan() {
-                    write!(f, "+nan.0")
-                } else if n.fract() == 0.0 {
-                    write!(f, "{}.0", *n as i64)
-                } else {
-                    write!(f, "{n}")
-                }
+            Literal::BigInteger(n) => {
+                write!(f, "{n}")
             }
+            Literal::InexactReal(n) => write!(f, "{}", crate::numeric::format_real(*n)),
             Literal::Number(n) => {
                 if n.is_infinite() {
                     if n.is_sign_positive() {
@@ -297,14 +294,14 @@ impl fmt::Display for Literal {
             Literal::Complex { real, imaginary } => {
                 if *real == 0.0 {
                     if *imaginary == 1.0 {
-                        write!(f, "i")
+                        write!(f, "+i")
                     } else if *imaginary == -1.0 {
                         write!(f, "-i")
                     } else {
                         write!(f, "{imaginary}i")
                     }
                 } else if *imaginary == 0.0 {
-                    write!(f, "{real}")
+                    write!(f, "{real}+0.0i")
                 } else if *imaginary > 0.0 {
                     if *imaginary == 1.0 {
                         write!(f, "{real}+i")
@@ -320,10 +317,16 @@ impl fmt::Display for Literal {
             Literal::String(s) => write!(f, "\"{}\"", escape_string(s)),
             Literal::Character(c) => {
                 match c {
+                    '\x07' => write!(f, "#\\alarm"),
+                    '\x08' => write!(f, "#\\backspace"),
+                    '\x7f' => write!(f, "#\\delete"),
+                    '\x1b' => write!(f, "#\\escape"),
+                    '\0' => write!(f, "#\\null"),
                     ' ' => write!(f, "#\\space"),
                     '\n' => write!(f, "#\\newline"),
                     '\t' => write!(f, "#\\tab"),
                     '\r' => write!(f, "#\\return"),
+                    c if c.is_control() || c.is_whitespace() => write!(f, "#\\x{:x}", *c as u32),
                     _ => write!(f, "#\\{c}"),
                 }
             }
@@ -354,7 +357,7 @@ fn escape_string(s: &str) -> String {
             '\n' => "\\n".to_string(),
             '\t' => "\\t".to_string(),
             '\r' => "\\r".to_string(),
-            c if c.is_control() => format!("\\x{:02x}", c as u8),
+            c if c.is_control() => format!("\\x{:x};", c as u32),
             c => c.to_string(),
         })
         .collect()
@@ -419,6 +422,10 @@ impl Hash for Literal {
             Literal::Unspecified => {
                 9u8.hash(state);
             }
+            Literal::BigInteger(n) => {
+                11u8.hash(state);
+                n.hash(state);
+            }
         }
     }
 }
@@ -496,11 +503,15 @@ mod tests {
         assert_eq!(format!("{}", Literal::float(3.0)), "3.0");
         assert_eq!(format!("{}", Literal::rational(3, 4)), "3/4");
         assert_eq!(format!("{}", Literal::complex(3.0, 4.0)), "3+4i");
-        assert_eq!(format!("{}", Literal::complex(0.0, 1.0)), "i");
+        assert_eq!(format!("{}", Literal::complex(0.0, 1.0)), "+i");
         assert_eq!(format!("{}", Literal::complex(3.0, -1.0)), "3-i");
         assert_eq!(format!("{}", Literal::string("hello".to_string())), "\"hello\"");
         assert_eq!(format!("{}", Literal::character('a')), "#\\a");
         assert_eq!(format!("{}", Literal::character(' ')), "#\\space");
+        assert_eq!(format!("{}", Literal::character('\x07')), "#\\alarm");
+        assert_eq!(format!("{}", Literal::character('\u{1}')), "#\\x1");
+        assert_eq!(format!("{}", Literal::string("a\u{1}b".to_string())), "\"a\\x1;b\"");
+        assert_eq!(format!("{}", Literal::float(1e20)), "1e20");
         assert_eq!(format!("{}", Literal::boolean(true)), "#t");
         assert_eq!(format!("{}", Literal::boolean(false)), "#f");
         assert_eq!(format!("{}", Literal::Nil), "()");
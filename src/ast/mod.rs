@@ -19,6 +19,7 @@ pub mod parameter_binding;
 pub mod cond_clause;
 pub mod case_clause;
 pub mod guard_clause;
+pub mod do_binding;
 pub mod case_lambda_clause;
 
 pub use literal::*;
@@ -30,6 +31,7 @@ pub use parameter_binding::*;
 pub use cond_clause::*;
 pub use case_clause::*;
 pub use guard_clause::*;
+pub use do_binding::*;
 pub use case_lambda_clause::*;
 
 
@@ -103,8 +105,9 @@ pub enum Expr {
         transformer: Box<Spanned<Expr>>,
     },
 
-    /// Syntax rules: (syntax-rules (literals ...) (pattern template) ...)
+    /// Syntax rules: (syntax-rules [ellipsis] (literals ...) (pattern template) ...)
     SyntaxRules {
+        ellipsis: Option<String>, // custom ellipsis identifier replacing `...`
         literals: Vec<String>,
         rules: Vec<(Spanned<Expr>, Spanned<Expr>)>, // (pattern, template) pairs
     },
@@ -157,6 +160,9 @@ pub enum Expr {
         cdr: Box<Spanned<Expr>>,
     },
 
+    /// Vector literal: #(<datum>*), which evaluates to itself
+    Vector(Vec<Spanned<Expr>>),
+
     // ============= DERIVED FORMS (implemented as macros) =============
     
     /// Begin expression: (begin <expressions>+)
@@ -207,6 +213,14 @@ pub enum Expr {
         body: Vec<Spanned<Expr>>,
     },
 
+    /// Do loop: (do ((<variable> <init> <step>?)*) (<test> <expression>*) <command>*)
+    Do {
+        bindings: Vec<DoBinding>,
+        test: Box<Spanned<Expr>>,
+        result: Vec<Spanned<Expr>>,
+        body: Vec<Spanned<Expr>>,
+    },
+
     /// Guard expression: (guard (<variable> <clauses>*) <body>)
     Guard {
         variable: String,
@@ -243,6 +257,8 @@ impl Expr {
                 | Expr::UnquoteSplicing(_)
                 | Expr::Lambda { .. }
                 | Expr::If { .. }
+                | Expr::When { .. }
+                | Expr::Unless { .. }
                 | Expr::Define { .. }
                 | Expr::Set { .. }
                 | Expr::DefineSyntax { .. }
@@ -258,7 +274,7 @@ impl Expr {
 
     /// Returns true if this expression is self-evaluating.
     pub fn is_self_evaluating(&self) -> bool {
-        matches!(self, Expr::Literal(_) | Expr::Keyword(_))
+        matches!(self, Expr::Literal(_) | Expr::Keyword(_) | Expr::Vector(_))
     }
 
     /// Gets the identifier name if this is an identifier expression.
@@ -328,6 +344,14 @@ impl fmt::Display for Expr {
             Expr::Pair { car, cdr } => {
                 write!(f, "({} . {})", car.inner, cdr.inner)
             }
+            Expr::Vector(elements) => {
+                write!(f, "#(")?;
+                for (i, element) in elements.iter().enumerate() {
+                    if i > 0 { write!(f, " ")?; }
+                    write!(f, "{}", element.inner)?;
+                }
+                write!(f, ")")
+            }
             Expr::CaseLambda { clauses, .. } => {
                 write!(f, "(case-lambda")?;
                 for clause in clauses {
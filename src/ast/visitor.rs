@@ -45,7 +45,7 @@ pub trait Visitor {
             Expr::DefineSyntax { name, transformer } => {
                 self.visit_define_syntax(name, transformer)
             }
-            Expr::SyntaxRules { literals, rules } => {
+            Expr::SyntaxRules { literals, rules, .. } => {
                 self.visit_syntax_rules(literals, rules)
             }
             Expr::CallCC(expr) => self.visit_call_cc(expr),
@@ -67,6 +67,10 @@ pub trait Visitor {
             Expr::When { test, body } => self.visit_when(test, body),
             Expr::Unless { test, body } => self.visit_unless(test, body),
             Expr::Pair { car, cdr } => self.visit_pair(car, cdr),
+            Expr::Vector(elements) => self.visit_list(elements),
+            Expr::Do { bindings, test, result, body } => {
+                self.visit_do(bindings, test, result, body)
+            }
             Expr::Guard { variable, clauses, body } => {
                 self.visit_guard(variable, clauses, body)
             }
@@ -162,6 +166,14 @@ pub trait Visitor {
     
     fn visit_pair(&mut self, car: &Spanned<Expr>, cdr: &Spanned<Expr>) -> Self::Output;
     
+    fn visit_do(
+        &mut self,
+        bindings: &[DoBinding],
+        test: &Spanned<Expr>,
+        result: &[Spanned<Expr>],
+        body: &[Spanned<Expr>],
+    ) -> Self::Output;
+    
     fn visit_guard(&mut self, variable: &str, clauses: &[GuardClause], body: &[Spanned<Expr>]) -> Self::Output;
     
     fn visit_parameterize(&mut self, bindings: &[ParameterBinding], body: &[Spanned<Expr>]) -> Self::Output;
@@ -433,6 +445,25 @@ impl Visitor for NodeCounter {
         self.visit_expr(cdr);
     }
 
+    fn visit_do(
+        &mut self,
+        bindings: &[DoBinding],
+        test: &Spanned<Expr>,
+        result: &[Spanned<Expr>],
+        body: &[Spanned<Expr>],
+    ) {
+        self.total += 1;
+        for binding in bindings {
+            self.visit_expr(&binding.init);
+            if let Some(ref step) = binding.step {
+                self.visit_expr(step);
+            }
+        }
+        self.visit_expr(test);
+        self.visit_expressions(result);
+        self.visit_expressions(body);
+    }
+
     fn visit_guard(&mut self, _variable: &str, clauses: &[GuardClause], body: &[Spanned<Expr>]) {
         self.total += 1;
         for clause in clauses {
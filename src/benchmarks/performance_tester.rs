@@ -517,7 +517,7 @@ impl PerformanceTester {
     
     /// Tests SIMD optimization effectiveness
     fn test_simd_optimizations(&self) -> SimdOptimizationResults {
-        let simd_ops = SimdNumericOps::with_default();
+        let simd_ops = SimdNumericOps::new();
         let mut optimal_sizes = Vec::new();
         let mut total_speedup = 0.0;
         let mut test_count = 0;
@@ -301,6 +301,7 @@ impl BytecodeCompiler {
     ) -> Result<()> {
         let constant_value = match literal {
             Literal::ExactInteger(i) => ConstantValue::Number(*i as f64),
+            Literal::BigInteger(n) => ConstantValue::Number(n.to_f64().unwrap_or(f64::INFINITY)),
             Literal::InexactReal(f) => ConstantValue::Number(*f),
             Literal::Number(f) => ConstantValue::Number(*f),
             Literal::Rational { numerator, denominator } => {
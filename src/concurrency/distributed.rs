@@ -113,6 +113,7 @@ impl SerializableValue {
             Value::Literal(lit) => match lit {
                 crate::ast::Literal::Boolean(b) => Ok(SerializableValue::Boolean(*b)),
                 crate::ast::Literal::ExactInteger(i) => Ok(SerializableValue::Integer(*i)),
+                crate::ast::Literal::BigInteger(n) => Ok(SerializableValue::String(n.to_string())),
                 crate::ast::Literal::InexactReal(f) => Ok(SerializableValue::Float(*f)),
                 crate::ast::Literal::Number(f) => Ok(SerializableValue::Float(*f)),
                 crate::ast::Literal::Rational { numerator, denominator } => {
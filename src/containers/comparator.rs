@@ -166,12 +166,23 @@ impl HashComparator {
         }
     }
     
-    /// Creates the default hash comparator
+    /// Creates the default hash comparator, keying tables by `equal?`
     pub fn with_default() -> Self {
         Self::new(
             "default-hash",
             super::utils::hash_value,
-            |a, b| a == b,
+            |a, b| a.is_equal(b),
+        )
+    }
+    
+    /// Creates an identity hash comparator, keying tables by `eq?`
+    ///
+    /// Structurally equal but distinct objects are different keys.
+    pub fn identity() -> Self {
+        Self::new(
+            "eq",
+            super::utils::identity_hash,
+            |a, b| a.is_eq(b),
         )
     }
     
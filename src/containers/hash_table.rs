@@ -40,6 +40,8 @@ pub struct HashTable {
     load_factor: f64,
     /// Comparator for keys
     comparator: HashComparator,
+    /// Whether SRFI-125 mutation operations are allowed
+    mutable: bool,
     /// Name for debugging
     name: Option<String>,
 }
@@ -74,10 +76,30 @@ impl HashTable {
             size: 0,
             load_factor: load_factors::MAX_LOAD_FACTOR,
             comparator,
+            mutable: true,
             name: None,
         }
     }
     
+    /// Creates a new hash table that rehashes once `load_factor` is exceeded.
+    ///
+    /// Workloads with a known size can pair a large capacity with a high
+    /// threshold to avoid rehashing altogether.
+    pub fn with_load_factor(
+        capacity: usize,
+        comparator: HashComparator,
+        load_factor: f64,
+    ) -> ContainerResult<Self> {
+        let mut table = Self::with_capacity_and_comparator(capacity, comparator);
+        table.set_rehash_threshold(load_factor)?;
+        Ok(table)
+    }
+    
+    /// Creates a hash table keyed by object identity (`eq?`)
+    pub fn new_eq() -> Self {
+        Self::with_comparator(HashComparator::identity())
+    }
+    
     /// Creates a named hash table for debugging
     pub fn with_name(name: impl Into<String>) -> Self {
         let mut table = Self::new();
@@ -90,6 +112,30 @@ impl HashTable {
         self.buckets.len()
     }
     
+    /// Gets the load factor above which the table rehashes
+    pub fn rehash_threshold(&self) -> f64 {
+        self.load_factor
+    }
+    
+    /// Sets the load factor above which the table rehashes
+    pub fn set_rehash_threshold(&mut self, load_factor: f64) -> ContainerResult<()> {
+        if !(load_factor > 0.0 && load_factor <= 1.0) {
+            return Err(ContainerError::InvalidLoadFactor { load_factor });
+        }
+        self.load_factor = load_factor;
+        Ok(())
+    }
+    
+    /// Gets the key comparator
+    pub fn comparator(&self) -> &HashComparator {
+        &self.comparator
+    }
+    
+    /// Checks whether SRFI-125 mutation operations are allowed
+    pub fn is_mutable(&self) -> bool {
+        self.mutable
+    }
+    
     /// Gets the current load factor
     pub fn load_factor(&self) -> f64 {
         if self.buckets.is_empty() {
@@ -294,7 +340,8 @@ impl HashTable {
     
     /// Checks if resize is needed
     fn needs_resize(&self) -> bool {
-        self.load_factor() > self.load_factor
+        // A full table always grows, even with a rehash threshold of 1
+        self.size >= self.buckets.len() || self.load_factor() > self.load_factor
     }
     
     /// Resizes the hash table
@@ -408,6 +455,29 @@ impl ThreadSafeHashTable {
         }
     }
     
+    /// Creates a new thread-safe hash table keyed by object identity (`eq?`)
+    pub fn new_eq() -> Self {
+        Self::with_comparator(HashComparator::identity())
+    }
+    
+    /// Creates a new thread-safe hash table with a rehash threshold
+    pub fn with_load_factor(
+        capacity: usize,
+        comparator: HashComparator,
+        load_factor: f64,
+    ) -> ContainerResult<Self> {
+        Ok(Self {
+            inner: Arc::new(RwLock::new(HashTable::with_load_factor(capacity, comparator, load_factor)?)),
+        })
+    }
+    
+    /// Creates an independent copy of the table (SRFI-125 hash-table-copy)
+    pub fn copy(&self, mutable: bool) -> Self {
+        Self {
+            inner: Arc::new(RwLock::new(self.inner.read().unwrap().hash_table_copy(mutable))),
+        }
+    }
+    
     /// Inserts a key-value pair
     pub fn insert(&self, key: Value, value: Value) -> Option<Value> {
         self.inner.write().unwrap().insert(key, value)
@@ -537,21 +607,64 @@ impl HashTable {
     }
     
     /// SRFI-125: hash-table-set! (multiple key-value pairs)
-    pub fn hash_table_set(&mut self, pairs: &[(Value, Value)]) {
+    pub fn hash_table_set(&mut self, pairs: &[(Value, Value)]) -> ContainerResult<()> {
+        self.ensure_mutable("hash-table-set!")?;
         for (key, value) in pairs {
             self.insert(key.clone(), value.clone());
         }
+        Ok(())
     }
     
     /// SRFI-125: hash-table-delete! (multiple keys)
-    pub fn hash_table_delete(&mut self, keys: &[Value]) -> usize {
+    pub fn hash_table_delete(&mut self, keys: &[Value]) -> ContainerResult<usize> {
+        self.ensure_mutable("hash-table-delete!")?;
         let mut deleted = 0;
         for key in keys {
             if self.remove(key).is_some() {
                 deleted += 1;
             }
         }
-        deleted
+        Ok(deleted)
+    }
+    
+    /// SRFI-125: hash-table-clear!
+    ///
+    /// Empties the table in place, keeping its comparator and capacity.
+    pub fn hash_table_clear(&mut self) -> ContainerResult<()> {
+        self.ensure_mutable("hash-table-clear!")?;
+        self.clear();
+        Ok(())
+    }
+    
+    /// SRFI-125: hash-table-copy
+    ///
+    /// The copy has its own entries, so mutating it never affects the
+    /// original. The comparator and rehash threshold are preserved.
+    pub fn hash_table_copy(&self, mutable: bool) -> Self {
+        let mut copy = self.clone();
+        copy.mutable = mutable;
+        copy
+    }
+    
+    /// SRFI-125: hash-table-size
+    pub fn hash_table_size(&self) -> usize {
+        self.size
+    }
+    
+    /// SRFI-125: hash-table-empty?
+    pub fn hash_table_empty(&self) -> bool {
+        self.size == 0
+    }
+    
+    /// Fails with an error naming `operation` if the table is immutable
+    fn ensure_mutable(&self, operation: &str) -> ContainerResult<()> {
+        if self.mutable {
+            Ok(())
+        } else {
+            Err(ContainerError::ImmutableContainer {
+                operation: operation.to_string(),
+            })
+        }
     }
     
     /// SRFI-125: hash-table-update!
@@ -559,6 +672,7 @@ impl HashTable {
     where
         F: FnOnce(&Value) -> Value,
     {
+        self.ensure_mutable("hash-table-update!")?;
         let current_value = match self.get(key) {
             Some(value) => value.clone(),
             None => match default {
@@ -708,7 +822,7 @@ mod tests {
             assert_eq!(table.hash_table_ref(&key1, Some(default.clone())), Ok(default.clone()));
         
         // Test hash-table-set! with multiple pairs
-        table.hash_table_set(&[(key1.clone(), value1.clone()), (key2.clone(), value2.clone())]);
+        table.hash_table_set(&[(key1.clone(), value1.clone()), (key2.clone(), value2.clone())]).unwrap();
         assert_eq!(table.len(), 2);
         
         // Test hash-table-update!
@@ -722,11 +836,87 @@ mod tests {
         assert_eq!(sum, 13.0); // 11.0 + 2.0
         
         // Test hash-table-delete! with multiple keys
-        let deleted = table.hash_table_delete(&[key1, key2]);
+        let deleted = table.hash_table_delete(&[key1, key2]).unwrap();
         assert_eq!(deleted, 2);
         assert!(table.is_empty());
     }
     
+    #[test]
+    fn test_copy_is_independent() {
+        let comparator = HashComparator::with_default();
+        let mut table = HashTable::with_load_factor(16, comparator, 0.5).unwrap();
+        table.hash_table_set(&[
+            (Value::string("a"), Value::integer(1)),
+            (Value::string("b"), Value::integer(2)),
+        ]).unwrap();
+        
+        let mut copy = table.hash_table_copy(true);
+        assert_eq!(copy.comparator().name(), table.comparator().name());
+        assert_eq!(copy.rehash_threshold(), 0.5);
+        
+        copy.hash_table_set(&[(Value::string("a"), Value::integer(10))]).unwrap();
+        copy.hash_table_delete(&[Value::string("b")]).unwrap();
+        
+        assert_eq!(table.get(&Value::string("a")), Some(&Value::integer(1)));
+        assert_eq!(table.get(&Value::string("b")), Some(&Value::integer(2)));
+        assert_eq!(copy.get(&Value::string("a")), Some(&Value::integer(10)));
+        assert_eq!(table.hash_table_size(), 2);
+        assert_eq!(copy.hash_table_size(), 1);
+        
+        // Immutable copies reject SRFI-125 mutation
+        let mut frozen = table.hash_table_copy(false);
+        assert!(!frozen.is_mutable());
+        assert!(frozen.hash_table_set(&[(Value::string("c"), Value::integer(3))]).is_err());
+        assert!(frozen.hash_table_clear().is_err());
+        assert_eq!(frozen.hash_table_size(), 2);
+        
+        // Thread-safe copies do not share storage either
+        let shared = ThreadSafeHashTable::new();
+        shared.insert(Value::string("k"), Value::integer(1));
+        let shared_copy = shared.copy(true);
+        shared_copy.insert(Value::string("k"), Value::integer(2));
+        assert_eq!(shared.get(&Value::string("k")), Some(Value::integer(1)));
+    }
+    
+    #[test]
+    fn test_clear_then_repopulate() {
+        let mut table = HashTable::with_capacity(4);
+        for i in 0..20 {
+            table.insert(Value::integer(i), Value::integer(i * i));
+        }
+        let capacity = table.capacity();
+        
+        table.hash_table_clear().unwrap();
+        assert!(table.hash_table_empty());
+        assert_eq!(table.capacity(), capacity);
+        assert_eq!(table.get(&Value::integer(3)), None);
+        
+        for i in 0..20 {
+            table.insert(Value::integer(i), Value::integer(-i));
+        }
+        assert_eq!(table.hash_table_size(), 20);
+        assert_eq!(table.capacity(), capacity);
+        assert_eq!(table.get(&Value::integer(3)), Some(&Value::integer(-3)));
+    }
+    
+    #[test]
+    fn test_rehash_threshold() {
+        let comparator = HashComparator::with_default();
+        assert!(HashTable::with_load_factor(8, comparator.clone(), 0.0).is_err());
+        assert!(HashTable::with_load_factor(8, comparator.clone(), 1.5).is_err());
+        
+        // A full threshold lets a presized table fill without rehashing
+        let mut table = HashTable::with_load_factor(8, comparator, 1.0).unwrap();
+        for i in 0..8 {
+            table.insert(Value::integer(i), Value::integer(i));
+        }
+        assert_eq!(table.capacity(), 8);
+        
+        table.set_rehash_threshold(0.5).unwrap();
+        table.insert(Value::integer(100), Value::integer(100));
+        assert!(table.capacity() > 8);
+    }
+    
     #[test]
     fn test_stats() {
         let mut table = HashTable::with_capacity(8);
@@ -742,4 +932,31 @@ mod tests {
         assert_eq!(stats.capacity, 8);
         assert!(stats.load_factor < 1.0);
     }
+    
+    #[test]
+    fn test_eq_table_keys_by_identity() {
+        let table = ThreadSafeHashTable::new_eq();
+        let first = Value::list(vec![Value::integer(1), Value::integer(2)]);
+        let second = Value::list(vec![Value::integer(1), Value::integer(2)]);
+        
+        table.insert(first.clone(), Value::symbol_from_str("first"));
+        table.insert(second.clone(), Value::symbol_from_str("second"));
+        assert_eq!(table.len(), 2);
+        assert!(!table.contains_key(&Value::list(vec![Value::integer(1), Value::integer(2)])));
+        
+        // The collector never relocates values, so identity hashes survive it
+        crate::runtime::gc::GcSystem::for_evaluator().unwrap().collect_minor().unwrap();
+        assert_eq!(table.get(&first), Some(Value::symbol_from_str("first")));
+        assert_eq!(table.get(&second), Some(Value::symbol_from_str("second")));
+        
+        // Immediates are keyed by value, and mutation does not move a key
+        table.insert(Value::integer(7), Value::boolean(true));
+        assert_eq!(table.get(&Value::integer(7)), Some(Value::boolean(true)));
+        let text = Value::mutable_string("abc");
+        table.insert(text.clone(), Value::integer(3));
+        if let Value::MutableString(chars) = &text {
+            chars.write().unwrap().push('d');
+        }
+        assert_eq!(table.get(&text), Some(Value::integer(3)));
+    }
 }
\ No newline at end of file
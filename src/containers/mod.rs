@@ -175,6 +175,18 @@ pub enum ContainerError {
         /// The maximum allowed capacity
         maximum: usize,
     },
+    
+    /// Mutation of an immutable container
+    ImmutableContainer {
+        /// The operation that was attempted
+        operation: String,
+    },
+    
+    /// Load factor outside the range (0, 1]
+    InvalidLoadFactor {
+        /// The rejected load factor
+        load_factor: f64,
+    },
 }
 
 impl std::fmt::Display for ContainerError {
@@ -195,6 +207,12 @@ impl std::fmt::Display for ContainerError {
             ContainerError::CapacityExceeded { requested, maximum } => {
                 write!(f, "Requested capacity {requested} exceeds maximum {maximum}")
             }
+            ContainerError::ImmutableContainer { operation } => {
+                write!(f, "Cannot perform '{operation}' on immutable container")
+            }
+            ContainerError::InvalidLoadFactor { load_factor } => {
+                write!(f, "Load factor {load_factor} must be greater than 0 and at most 1")
+            }
         }
     }
 }
@@ -220,13 +238,98 @@ pub mod utils {
         power
     }
     
+    /// Nesting depth beyond which `hash_value` stops descending into elements
+    pub const MAX_HASH_DEPTH: usize = 64;
+    
     /// Calculates a hash value for a Scheme Value
+    ///
+    /// Pairs, vectors and records hash their elements, so structurally equal
+    /// values hash alike. Elements nested deeper than `MAX_HASH_DEPTH`
+    /// contribute only their type, which keeps deep data off the stack. The
+    /// hasher is unkeyed, so hashes are stable for the life of the process.
     pub fn hash_value(value: &Value) -> u64 {
+        use std::collections::hash_map::DefaultHasher;
+        use std::hash::Hasher;
+        
+        let mut hasher = DefaultHasher::new();
+        hash_structure(value, &mut hasher, 0);
+        hasher.finish()
+    }
+    
+    /// Feeds a value into a hasher, walking list spines iteratively.
+    fn hash_structure<H: std::hash::Hasher>(value: &Value, hasher: &mut H, depth: usize) {
+        use std::hash::Hash;
+        
+        if depth >= MAX_HASH_DEPTH {
+            std::mem::discriminant(value).hash(hasher);
+            return;
+        }
+        
+        let mut current = value.clone();
+        loop {
+            let next = match &current {
+                Value::Pair(car, cdr) => {
+                    std::mem::discriminant(&current).hash(hasher);
+                    hash_structure(car, hasher, depth + 1);
+                    (**cdr).clone()
+                }
+                Value::MutablePair(car, cdr) => {
+                    std::mem::discriminant(&current).hash(hasher);
+                    hash_structure(&car.read().unwrap(), hasher, depth + 1);
+                    cdr.read().unwrap().clone()
+                }
+                Value::Vector(elements) => {
+                    std::mem::discriminant(&current).hash(hasher);
+                    let elements = elements.read().unwrap();
+                    elements.len().hash(hasher);
+                    for element in elements.iter() {
+                        hash_structure(element, hasher, depth + 1);
+                    }
+                    return;
+                }
+                Value::Record(record) => {
+                    std::mem::discriminant(&current).hash(hasher);
+                    record.type_id.hash(hasher);
+                    for field in record.fields.read().unwrap().iter() {
+                        hash_structure(field, hasher, depth + 1);
+                    }
+                    return;
+                }
+                atom => {
+                    atom.hash(hasher);
+                    return;
+                }
+            };
+            current = next;
+        }
+    }
+    
+    /// Calculates an identity hash for a Scheme Value
+    ///
+    /// Agrees with `Value::is_eq`: heap objects whose contents may change
+    /// hash by the address of their allocation, everything else by value.
+    /// Values live behind `Arc`s, which the collector never relocates, so an
+    /// object's identity hash is stable for as long as it is alive.
+    pub fn identity_hash(value: &Value) -> u64 {
         use std::collections::hash_map::DefaultHasher;
         use std::hash::{Hash, Hasher};
+        use std::sync::Arc;
+        
+        let address = match value {
+            Value::Pair(car, cdr) => Some((Arc::as_ptr(car) as usize) ^ (Arc::as_ptr(cdr) as usize).rotate_left(16)),
+            Value::MutablePair(car, _) => Some(Arc::as_ptr(car) as usize),
+            Value::MutableString(chars) => Some(Arc::as_ptr(chars) as usize),
+            Value::Vector(elements) => Some(Arc::as_ptr(elements) as usize),
+            Value::Hashtable(table) => Some(Arc::as_ptr(table) as usize),
+            Value::Record(record) => Some(Arc::as_ptr(record) as usize),
+            _ => None,
+        };
         
         let mut hasher = DefaultHasher::new();
-        value.hash(&mut hasher);
+        match address {
+            Some(address) => address.hash(&mut hasher),
+            None => value.hash(&mut hasher),
+        }
         hasher.finish()
     }
     
@@ -322,6 +425,8 @@ pub mod utils {
             }
             Value::Generator(_) => 36,
             Value::Opaque(_) => 37,
+            Value::Values(_) => 38,
+            Value::EofObject => 39,
         }
     }
     
@@ -386,7 +491,7 @@ pub mod utils {
             Value::Literal(lit) => estimate_literal_memory(lit),
             Value::Symbol(_) => std::mem::size_of::<u64>(),
             Value::Keyword(k) => std::mem::size_of::<String>() + k.len(),
-            Value::Nil | Value::Unspecified => 0,
+            Value::Nil | Value::Unspecified | Value::EofObject => 0,
             Value::Pair(a, b) => {
                 std::mem::size_of::<Value>() * 2 + 
                 estimate_value_memory(a) + 
@@ -414,6 +519,7 @@ pub mod utils {
             Literal::Character(_) => std::mem::size_of::<char>(),
             Literal::Boolean(_) => std::mem::size_of::<bool>(),
             Literal::Bytevector(bv) => std::mem::size_of::<Vec<u8>>() + bv.len(),
+            Literal::BigInteger(n) => std::mem::size_of::<Vec<u32>>() + n.bits().div_ceil(8),
             Literal::Rational { .. } => std::mem::size_of::<i64>() * 2,
             Literal::Complex { .. } => std::mem::size_of::<f64>() * 2,
             Literal::Nil => 0,
@@ -448,6 +554,31 @@ mod tests {
         assert_ne!(hash_value(&v1), hash_value(&v3));
     }
     
+    #[test]
+    fn test_hash_value_of_nested_structures() {
+        let build = || Value::list(vec![
+            Value::integer(1),
+            Value::vector(vec![Value::string("a"), Value::list(vec![Value::integer(2)])]),
+        ]);
+        assert_eq!(hash_value(&build()), hash_value(&build()));
+        assert_ne!(
+            hash_value(&Value::list(vec![Value::integer(1), Value::integer(2)])),
+            hash_value(&Value::list(vec![Value::integer(2), Value::integer(1)]))
+        );
+        
+        // Nesting far beyond the depth bound hashes without overflowing
+        let mut deep = Value::Nil;
+        for _ in 0..10_000 {
+            deep = Value::list(vec![deep]);
+        }
+        assert_eq!(hash_value(&deep), hash_value(&deep));
+        
+        // Long lists are walked without consuming depth
+        let long = Value::list((0..10_000).map(Value::integer).collect());
+        let longer = Value::list((0..10_001).map(Value::integer).collect());
+        assert_ne!(hash_value(&long), hash_value(&longer));
+    }
+    
     #[test]
     fn test_compare_values() {
         use std::cmp::Ordering;
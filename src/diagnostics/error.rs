@@ -55,6 +55,10 @@ impl ErrorContext {
                 message: format!("{context_str}: {message}"),
                 span,
             },
+            Error::IncompleteInput { message, span } => Error::IncompleteInput {
+                message: format!("{context_str}: {message}"),
+                span,
+            },
             Error::TypeError { message, span } => Error::TypeError {
                 message: format!("{context_str}: {message}"),
                 span,
@@ -80,6 +84,7 @@ impl ErrorContext {
                 exception,
                 span,
             },
+            jump @ Error::NonLocalJump { .. } => jump,
         }
     }
 }
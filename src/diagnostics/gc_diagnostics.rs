@@ -289,6 +289,7 @@ impl GcDiagnosticManager {
         match error {
             Error::LexError { message, span } => (message.clone(), Some(*span)),
             Error::ParseError { message, span } => (message.clone(), Some(*span)),
+            Error::IncompleteInput { message, span } => (message.clone(), Some(*span)),
             Error::TypeError { message, span } => (message.clone(), Some(*span)),
             Error::MacroError { message, span } => (message.clone(), Some(*span)),
             Error::RuntimeError { message, span } => (message.clone(), *span),
@@ -296,6 +297,7 @@ impl GcDiagnosticManager {
             Error::IoError { message } => (message.clone(), None),
             Error::InternalError { message } => (message.clone(), None),
             Error::Exception { exception, span } => (exception.to_string(), *span),
+            Error::NonLocalJump { .. } => (error.to_string(), None),
         }
     }
 
@@ -304,6 +306,7 @@ impl GcDiagnosticManager {
         match error {
             Error::LexError { .. } => ErrorKind::SyntaxError,
             Error::ParseError { .. } => ErrorKind::SyntaxError,
+            Error::IncompleteInput { .. } => ErrorKind::SyntaxError,
             Error::TypeError { .. } => ErrorKind::TypeError,
             Error::MacroError { .. } => ErrorKind::MacroError,
             Error::RuntimeError { .. } => ErrorKind::RuntimeError,
@@ -311,6 +314,7 @@ impl GcDiagnosticManager {
             Error::IoError { .. } => ErrorKind::IoError,
             Error::InternalError { .. } => ErrorKind::RuntimeError,
             Error::Exception { .. } => ErrorKind::RuntimeError,
+            Error::NonLocalJump { .. } => ErrorKind::RuntimeError,
         }
     }
 
@@ -50,6 +50,12 @@ pub enum Error {
         span: Span,
     },
 
+    /// Input that ends before an expression is complete
+    IncompleteInput {
+        message: String,
+        span: Span,
+    },
+
     /// Type checking errors
     TypeError {
         message: String,
@@ -88,6 +94,15 @@ pub enum Error {
         exception: crate::stdlib::exceptions::ExceptionObject,
         span: Option<Span>,
     },
+
+    /// A continuation escape leaving a nested trampoline. The trampoline that
+    /// receives it resumes the jump instead of reporting an error.
+    NonLocalJump {
+        value: crate::eval::Value,
+        target_stack_depth: usize,
+        /// Id of the continuation being invoked
+        continuation_id: u64,
+    },
 }
 
 impl Error {
@@ -107,6 +122,16 @@ impl Error {
         }
     }
 
+    /// Creates an error for input that ends in the middle of an expression.
+    ///
+    /// See [`Error::is_incomplete_input`].
+    pub fn incomplete_input(message: impl Into<String>, span: Span) -> Self {
+        Self::IncompleteInput {
+            message: message.into(),
+            span,
+        }
+    }
+
     /// Creates a new type error.
     pub fn type_error(message: impl Into<String>, span: Span) -> Self {
         Self::TypeError {
@@ -176,6 +201,45 @@ impl Error {
         }
     }
 
+    /// Returns the source span this error points at, if any.
+    pub fn span(&self) -> Option<Span> {
+        match self {
+            Self::LexError { span, .. }
+            | Self::ParseError { span, .. }
+            | Self::IncompleteInput { span, .. }
+            | Self::TypeError { span, .. }
+            | Self::MacroError { span, .. } => Some(*span),
+            Self::RuntimeError { span, .. } | Self::Exception { span, .. } => *span,
+            Self::FfiError { .. }
+            | Self::IoError { .. }
+            | Self::InternalError { .. }
+            | Self::NonLocalJump { .. } => None,
+        }
+    }
+
+    /// Formats the error as it is reported at the top level. Uncaught
+    /// `error` calls show their message and written irritants, e.g.
+    /// `bad value "foo" 42`; other errors use their display form.
+    pub fn report(&self) -> String {
+        match self {
+            Self::Exception { exception, .. } if exception.message.is_some() => exception.report(),
+            _ => self.to_string(),
+        }
+    }
+
+    /// Formats the report followed by the line and column of its span in
+    /// `source`, e.g. `bad input 42 (at line 3, column 5)`.
+    pub fn display_with_location(&self, source: &str) -> String {
+        match self.span() {
+            Some(span) if span.start <= source.len() => {
+                let map = SourceMap::new(String::new(), source.to_string(), 0);
+                let position = map.position_at_offset(span.start);
+                format!("{} (at line {}, column {})", self.report(), position.line, position.column)
+            }
+            _ => self.report(),
+        }
+    }
+
     /// Creates an arity error for a function called with wrong number of arguments.
     pub fn arity_error(function_name: &str, expected: usize, actual: usize) -> Self {
         Self::RuntimeError {
@@ -191,6 +255,7 @@ impl std::fmt::Display for Error {
         match self {
             Self::LexError { message, .. } => write!(f, "Lexical error: {message}"),
             Self::ParseError { message, .. } => write!(f, "Parse error: {message}"),
+            Self::IncompleteInput { message, .. } => write!(f, "Incomplete input: {message}"),
             Self::TypeError { message, .. } => write!(f, "Type error: {message}"),
             Self::MacroError { message, .. } => write!(f, "Macro error: {message}"),
             Self::RuntimeError { message, .. } => write!(f, "Runtime error: {message}"),
@@ -198,6 +263,7 @@ impl std::fmt::Display for Error {
             Self::IoError { message } => write!(f, "IO error: {message}"),
             Self::InternalError { message } => write!(f, "Internal error: {message}"),
             Self::Exception { exception, .. } => write!(f, "Exception: {exception}"),
+            Self::NonLocalJump { value, .. } => write!(f, "Continuation escape with {value}"),
         }
     }
 }
@@ -207,7 +273,7 @@ impl LambdustError for Error {
     fn error_code(&self) -> &'static str {
         match self {
             Self::LexError { .. } => "lambdust::lexer::error",
-            Self::ParseError { .. } => "lambdust::parser::error",
+            Self::ParseError { .. } | Self::IncompleteInput { .. } => "lambdust::parser::error",
             Self::TypeError { .. } => "lambdust::types::error",
             Self::MacroError { .. } => "lambdust::macros::error",
             Self::RuntimeError { .. } => "lambdust::runtime::error",
@@ -215,6 +281,7 @@ impl LambdustError for Error {
             Self::IoError { .. } => "lambdust::io::error",
             Self::InternalError { .. } => "lambdust::internal::error",
             Self::Exception { .. } => "lambdust::exception::error",
+            Self::NonLocalJump { .. } => "lambdust::continuation::escape",
         }
     }
     
@@ -229,6 +296,7 @@ impl LambdustError for Error {
         match self {
             Self::LexError { span, .. } => vec![ErrorLabel::primary(*span, "here")],
             Self::ParseError { span, .. } => vec![ErrorLabel::primary(*span, "here")],
+            Self::IncompleteInput { span, .. } => vec![ErrorLabel::primary(*span, "input ends here")],
             Self::TypeError { span, .. } => vec![ErrorLabel::primary(*span, "here")],
             Self::MacroError { span, .. } => vec![ErrorLabel::primary(*span, "here")],
             Self::RuntimeError { span: Some(span), .. } => vec![ErrorLabel::primary(*span, "here")],
@@ -250,7 +318,7 @@ impl LightweightDiagnostic for Error {
     fn code(&self) -> Option<&str> {
         match self {
             Self::LexError { .. } => Some("lambdust::lexer::error"),
-            Self::ParseError { .. } => Some("lambdust::parser::error"),
+            Self::ParseError { .. } | Self::IncompleteInput { .. } => Some("lambdust::parser::error"),
             Self::TypeError { .. } => Some("lambdust::types::error"),
             Self::MacroError { .. } => Some("lambdust::macros::error"),
             Self::RuntimeError { .. } => Some("lambdust::runtime::error"),
@@ -258,6 +326,7 @@ impl LightweightDiagnostic for Error {
             Self::IoError { .. } => Some("lambdust::io::error"),
             Self::InternalError { .. } => Some("lambdust::internal::error"),
             Self::Exception { .. } => Some("lambdust::exception::error"),
+            Self::NonLocalJump { .. } => Some("lambdust::continuation::escape"),
         }
     }
     
@@ -272,6 +341,7 @@ impl LightweightDiagnostic for Error {
         match self {
             Self::LexError { span, .. } => vec![DiagnosticLabel::primary(*span, "here")],
             Self::ParseError { span, .. } => vec![DiagnosticLabel::primary(*span, "here")],
+            Self::IncompleteInput { span, .. } => vec![DiagnosticLabel::primary(*span, "input ends here")],
             Self::TypeError { span, .. } => vec![DiagnosticLabel::primary(*span, "here")],
             Self::MacroError { span, .. } => vec![DiagnosticLabel::primary(*span, "here")],
             Self::RuntimeError { span: Some(span), .. } => vec![DiagnosticLabel::primary(*span, "here")],
@@ -306,10 +376,16 @@ impl Error {
 
     /// Creates an unexpected end-of-file error.
     pub fn unexpected_eof(span: Span) -> Self {
-        Self::ParseError {
-            message: "Unexpected end of file".to_string(),
-            span,
-        }
+        Self::incomplete_input("Unexpected end of file", span)
+    }
+
+    /// Returns true if this error only means the input ended too early,
+    /// so that more input could still complete it.
+    ///
+    /// The REPL uses this to keep reading with a continuation prompt
+    /// instead of reporting an error.
+    pub fn is_incomplete_input(&self) -> bool {
+        matches!(self, Self::IncompleteInput { .. })
     }
 
     /// Creates an unexpected token error.
@@ -408,6 +484,17 @@ mod tests {
         assert_eq!(combined.len, 7);
     }
 
+    #[test]
+    fn test_display_with_location() {
+        let source = "(define x 1)\n(car x)";
+        let error = Error::runtime_error("car: expected pair", Some(Span::new(13, 7)));
+        let rendered = error.display_with_location(source);
+        assert!(rendered.ends_with("(at line 2, column 1)"));
+
+        let unlocated = Error::runtime_error("no span", None);
+        assert_eq!(unlocated.display_with_location(source), unlocated.to_string());
+    }
+
     #[test]
     fn test_error_creation() {
         let span = Span::new(0, 5);
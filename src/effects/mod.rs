@@ -137,6 +137,12 @@ pub enum LiftingCondition {
     Custom(fn(&str, &[Effect]) -> bool),
 }
 
+/// Name of the custom effect used for filesystem access.
+pub const FILE_SYSTEM_EFFECT: &str = "FileSystem";
+
+/// Name of the custom effect used for spawning subprocesses.
+pub const PROCESS_EFFECT: &str = "Process";
+
 impl Effect {
     /// Returns true if this effect is pure.
     pub fn is_pure(&self) -> bool {
@@ -158,6 +164,29 @@ impl Effect {
         matches!(self, Effect::Error)
     }
     
+    /// Returns the effect tracked by procedures that touch the filesystem.
+    ///
+    /// Filesystem access is modelled as a custom effect so sandboxes can deny
+    /// it independently of general IO such as console output.
+    pub fn file_system() -> Effect {
+        Effect::Custom(FILE_SYSTEM_EFFECT.to_string())
+    }
+    
+    /// Returns true if this effect represents filesystem access.
+    pub fn is_file_system(&self) -> bool {
+        matches!(self, Effect::Custom(name) if name == FILE_SYSTEM_EFFECT)
+    }
+    
+    /// Returns the effect tracked by procedures that spawn subprocesses.
+    pub fn process() -> Effect {
+        Effect::Custom(PROCESS_EFFECT.to_string())
+    }
+    
+    /// Returns true if this effect represents spawning a subprocess.
+    pub fn is_process(&self) -> bool {
+        matches!(self, Effect::Custom(name) if name == PROCESS_EFFECT)
+    }
+    
     /// Combines two effects, returning the more "impure" one.
     pub fn combine(&self, other: &Effect) -> Effect {
         match (self, other) {
@@ -0,0 +1,199 @@
+//! Dynamic points for `dynamic-wind` (R7RS §6.10).
+//!
+//! Each active `dynamic-wind` call is a [`DynamicPoint`] linked to the point
+//! that was current when it was entered, so the dynamic extent at any moment
+//! is a path from the root. Transferring control to a continuation moves from
+//! the current point to the one captured with the continuation: the after
+//! thunks of the exited points run innermost first, then the before thunks of
+//! the entered points run outermost first. [`wind_path`] computes that
+//! sequence from the common ancestor of the two points.
+
+use super::value::Value;
+use std::sync::Arc;
+
+/// An entry in the chain of active `dynamic-wind` extents.
+#[derive(Debug)]
+pub struct DynamicPoint {
+    /// Thunk run when control enters this extent
+    pub before: Value,
+    /// Thunk run when control leaves this extent
+    pub after: Value,
+    /// The point that was current when this extent was entered
+    pub parent: Option<Arc<DynamicPoint>>,
+    /// Number of points between this one and the root
+    pub depth: usize,
+    /// Debug name supplied when the extent was entered
+    pub name: Option<Value>,
+}
+
+impl DynamicPoint {
+    /// Creates a point nested inside `parent`.
+    pub fn new(before: Value, after: Value, parent: Option<Arc<DynamicPoint>>) -> Arc<Self> {
+        Self::named(before, after, parent, None)
+    }
+
+    /// Creates a point nested inside `parent` carrying a debug name.
+    pub fn named(
+        before: Value,
+        after: Value,
+        parent: Option<Arc<DynamicPoint>>,
+        name: Option<Value>,
+    ) -> Arc<Self> {
+        let depth = parent.as_ref().map_or(0, |p| p.depth + 1);
+        Arc::new(Self { before, after, parent, depth, name })
+    }
+}
+
+/// Returns the debug names of the points from `point` to the root, innermost
+/// first.
+pub fn point_names(point: &Option<Arc<DynamicPoint>>) -> Vec<Option<Value>> {
+    let mut names = Vec::new();
+    let mut current = point.clone();
+    while let Some(point) = current {
+        names.push(point.name.clone());
+        current = point.parent.clone();
+    }
+    names
+}
+
+/// One step of moving between dynamic points.
+#[derive(Debug, Clone)]
+pub enum WindStep {
+    /// Leave this point: its parent becomes current and its after thunk runs
+    Unwind(Arc<DynamicPoint>),
+    /// Enter this point: its before thunk runs and it becomes current
+    Rewind(Arc<DynamicPoint>),
+}
+
+/// Returns the innermost point shared by the chains of `a` and `b`.
+pub fn common_ancestor(
+    a: &Option<Arc<DynamicPoint>>,
+    b: &Option<Arc<DynamicPoint>>,
+) -> Option<Arc<DynamicPoint>> {
+    let mut a = a.clone();
+    let mut b = b.clone();
+
+    loop {
+        match (&a, &b) {
+            (Some(pa), Some(pb)) if Arc::ptr_eq(pa, pb) => return a,
+            (Some(pa), Some(pb)) => {
+                if pa.depth >= pb.depth {
+                    a = pa.parent.clone();
+                } else {
+                    b = pb.parent.clone();
+                }
+            }
+            _ => return None,
+        }
+    }
+}
+
+/// Computes the unwind/rewind sequence for moving from `from` to `to`.
+pub fn wind_path(
+    from: &Option<Arc<DynamicPoint>>,
+    to: &Option<Arc<DynamicPoint>>,
+) -> Vec<WindStep> {
+    let ancestor = common_ancestor(from, to);
+    let is_ancestor = |point: &Arc<DynamicPoint>| {
+        ancestor.as_ref().is_some_and(|a| Arc::ptr_eq(a, point))
+    };
+
+    let mut steps = Vec::new();
+
+    let mut current = from.clone();
+    while let Some(point) = current {
+        if is_ancestor(&point) {
+            break;
+        }
+        current = point.parent.clone();
+        steps.push(WindStep::Unwind(point));
+    }
+
+    let mut entered = Vec::new();
+    let mut current = to.clone();
+    while let Some(point) = current {
+        if is_ancestor(&point) {
+            break;
+        }
+        current = point.parent.clone();
+        entered.push(WindStep::Rewind(point));
+    }
+    steps.extend(entered.into_iter().rev());
+
+    steps
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn point(name: &str, parent: Option<Arc<DynamicPoint>>) -> Arc<DynamicPoint> {
+        DynamicPoint::new(Value::symbol_from_str(name), Value::symbol_from_str(name), parent)
+    }
+
+    fn names(steps: &[WindStep]) -> Vec<String> {
+        steps
+            .iter()
+            .map(|step| match step {
+                WindStep::Unwind(p) => format!("after {}", p.after),
+                WindStep::Rewind(p) => format!("before {}", p.before),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_common_ancestor() {
+        let root = point("root", None);
+        let a = point("a", Some(root.clone()));
+        let b = point("b", Some(root.clone()));
+        let a1 = point("a1", Some(a.clone()));
+
+        let ancestor = common_ancestor(&Some(a1.clone()), &Some(b)).unwrap();
+        assert!(Arc::ptr_eq(&ancestor, &root));
+        let ancestor = common_ancestor(&Some(a1), &Some(a.clone())).unwrap();
+        assert!(Arc::ptr_eq(&ancestor, &a));
+        assert!(common_ancestor(&Some(a), &None).is_none());
+    }
+
+    #[test]
+    fn test_wind_path_between_siblings() {
+        let root = point("root", None);
+        let a = point("a", Some(root.clone()));
+        let a1 = point("a1", Some(a.clone()));
+        let b = point("b", Some(root.clone()));
+        let b1 = point("b1", Some(b.clone()));
+
+        // Exit innermost first, then enter outermost first
+        let steps = wind_path(&Some(a1), &Some(b1));
+        assert_eq!(names(&steps), vec!["after a1", "after a", "before b", "before b1"]);
+    }
+
+    #[test]
+    fn test_wind_path_reentry_from_outside() {
+        // A generator-style re-entry: the continuation was captured inside
+        // `outer`/`inner` and is resumed from top level
+        let outer = point("outer", None);
+        let inner = point("inner", Some(outer.clone()));
+
+        let steps = wind_path(&None, &Some(inner.clone()));
+        assert_eq!(names(&steps), vec!["before outer", "before inner"]);
+
+        // Leaving again runs both after thunks
+        let steps = wind_path(&Some(inner.clone()), &None);
+        assert_eq!(names(&steps), vec!["after inner", "after outer"]);
+
+        // Staying within the same extent runs nothing
+        assert!(wind_path(&Some(inner.clone()), &Some(inner)).is_empty());
+    }
+
+    #[test]
+    fn test_point_names_innermost_first() {
+        let name = |n: &str| Some(Value::symbol_from_str(n));
+        let outer = DynamicPoint::named(Value::Nil, Value::Nil, None, name("outer"));
+        let anonymous = point("anonymous", Some(outer));
+        let inner = DynamicPoint::named(Value::Nil, Value::Nil, Some(anonymous), name("inner"));
+
+        assert_eq!(point_names(&Some(inner)), vec![name("inner"), None, name("outer")]);
+        assert!(point_names(&None).is_empty());
+    }
+}
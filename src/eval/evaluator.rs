@@ -11,9 +11,12 @@ use super::{
     Continuation, Procedure, PrimitiveProcedure, PrimitiveImpl, Frame
 };
 use crate::module_system::{ModuleSystem, SchemeLibraryLoader, ImportSpec, ModuleId, ModuleNamespace, ImportConfig};
-use crate::runtime::GlobalEnvironmentManager;
-use super::value::CaseLambdaProcedure;
-use crate::ast::{CaseLambdaClause, Expr, Formals, GuardClause, Program};
+use crate::runtime::{EffectSandboxConfig, GlobalEnvironmentManager};
+use crate::runtime::gc::GcSystem;
+use super::value::{CaseLambdaProcedure, Promise};
+use super::dynamic_wind::{point_names, wind_path, DynamicPoint, WindStep};
+use super::procedural_macro::{self, ExpansionContext, ProceduralTransformer};
+use crate::ast::{CaseLambdaClause, DoBinding, Expr, Formals, GuardClause, Program};
 use crate::diagnostics::{Error, Result, Span, Spanned};
 use crate::effects::{Effect, EffectSystem, EffectLifter, MonadicValue};
 use crate::ffi::FfiBridge;
@@ -21,6 +24,7 @@ use crate::macro_system::MacroExpander;
 use crate::utils::{intern_symbol};
 use std::sync::Arc;
 use std::rc::Rc;
+use std::cell::OnceCell;
 use std::collections::HashMap;
 use std::sync::atomic::{AtomicU64, Ordering};
 
@@ -32,6 +36,19 @@ fn next_continuation_id() -> u64 {
     CONTINUATION_COUNTER.fetch_add(1, Ordering::SeqCst)
 }
 
+/// Takes the one value that a single-value context such as a definition or
+/// an argument position expects. A single value is never wrapped, so any
+/// [`Value::Values`] here carries zero or several values and is an error.
+fn single_value(value: Value, context: &str, span: Span) -> Result<Value> {
+    match value {
+        Value::Values(values) => Err(Error::runtime_error(
+            format!("{context} expects a single value, got {}", values.len()),
+            Some(span),
+        ).boxed()),
+        value => Ok(value),
+    }
+}
+
 /// The result of a single evaluation step.
 ///
 /// Using this enum allows us to implement proper tail call optimization
@@ -65,6 +82,8 @@ pub enum EvalStep {
     NonLocalJump {
         value: Value,
         target_stack_depth: usize,
+        /// Id of the continuation being invoked
+        continuation_id: u64,
     },
     
     
@@ -72,6 +91,27 @@ pub enum EvalStep {
     Error(Error),
 }
 
+/// An entry in the dynamic stack of exception handlers.
+#[derive(Debug, Clone)]
+pub enum ExceptionHandlerFrame {
+    /// A handler procedure installed by `with-exception-handler`
+    Handler(Value),
+    /// The implicit handler of a `guard` form, which catches by unwinding
+    Guard,
+}
+
+/// How deep the evaluator's bookkeeping stacks were at some point.
+///
+/// Unwinding to a mark is all an escape-only exit needs, which is much
+/// cheaper than capturing and restoring a full continuation.
+#[derive(Debug, Clone)]
+struct EscapeMark {
+    stack_trace_depth: usize,
+    context_depth: usize,
+    handler_depth: usize,
+    dynamic_point: Option<Arc<DynamicPoint>>,
+}
+
 /// The main evaluator for Lambdust expressions.
 ///
 /// This evaluator implements proper Scheme semantics including:
@@ -103,24 +143,36 @@ pub struct Evaluator {
     /// Module system for handling imports
     module_system: ModuleSystem,
     /// Scheme library loader for SRFI modules
-    scheme_loader: SchemeLibraryLoader,
+    scheme_loader: Rc<SchemeLibraryLoader>,
     /// Active call/cc context for proper continuation scoping
     call_cc_context: Option<u64>,
+    /// Dynamic stack of exception handlers, innermost last
+    exception_handlers: Vec<ExceptionHandlerFrame>,
+    /// Innermost active `dynamic-wind` extent
+    dynamic_point: Option<Arc<DynamicPoint>>,
+    /// Macros defined with `er-macro-transformer` or `sc-macro-transformer`
+    procedural_macros: HashMap<String, ProceduralTransformer>,
+    /// Procedural macro expansions in progress, innermost last
+    macro_expansions: Vec<ExpansionContext>,
+    /// Call requested by the running evaluator-integrated primitive in place of its result
+    pending_tail_call: Option<(Value, Vec<Value>)>,
+    /// Effects primitives may perform, when evaluation is sandboxed
+    effect_sandbox: Option<EffectSandboxConfig>,
+    /// Collector behind the Scheme-level GC API, created on first use
+    gc_system: OnceCell<GcSystem>,
 }
 
 impl Evaluator {
     /// Creates a new evaluator with the global environment.
     pub fn new() -> Self {
-        let global_env_manager = Arc::new(GlobalEnvironmentManager::new());
         let module_system = ModuleSystem::new().expect("Failed to create module system");
-        let scheme_loader = SchemeLibraryLoader::new(global_env_manager.clone())
-            .expect("Failed to create scheme library loader");
+        let scheme_loader = Self::new_scheme_loader();
         
         Self {
             generation: 0,
             stack_trace: StackTrace::new(),
             global_env: crate::eval::environment::global_environment(),
-            macro_expander: MacroExpander::with_builtins(),
+            macro_expander: MacroExpander::with_builtins().with_library_loader(scheme_loader.clone()),
             effect_system: EffectSystem::new(),
             effect_lifter: EffectLifter::new(),
             ffi_bridge: FfiBridge::with_builtins(),
@@ -128,21 +180,26 @@ impl Evaluator {
             module_system,
             scheme_loader,
             call_cc_context: None,
+            exception_handlers: Vec::new(),
+            dynamic_point: None,
+            procedural_macros: HashMap::new(),
+            macro_expansions: Vec::new(),
+            pending_tail_call: None,
+            effect_sandbox: None,
+            gc_system: OnceCell::new(),
         }
     }
 
     /// Creates a new evaluator with a custom global environment.
     pub fn with_environment(global_env: Rc<Environment>) -> Self {
-        let global_env_manager = Arc::new(GlobalEnvironmentManager::new());
         let module_system = ModuleSystem::new().expect("Failed to create module system");
-        let scheme_loader = SchemeLibraryLoader::new(global_env_manager.clone())
-            .expect("Failed to create scheme library loader");
+        let scheme_loader = Self::new_scheme_loader();
         
         Self {
             generation: 0,
             stack_trace: StackTrace::new(),
             global_env,
-            macro_expander: MacroExpander::with_builtins(),
+            macro_expander: MacroExpander::with_builtins().with_library_loader(scheme_loader.clone()),
             effect_system: EffectSystem::new(),
             effect_lifter: EffectLifter::new(),
             ffi_bridge: FfiBridge::with_builtins(),
@@ -150,21 +207,26 @@ impl Evaluator {
             module_system,
             scheme_loader,
             call_cc_context: None,
+            exception_handlers: Vec::new(),
+            dynamic_point: None,
+            procedural_macros: HashMap::new(),
+            macro_expansions: Vec::new(),
+            pending_tail_call: None,
+            effect_sandbox: None,
+            gc_system: OnceCell::new(),
         }
     }
 
     /// Creates a new evaluator with a custom macro expander.
     pub fn with_macro_expander(macro_expander: MacroExpander) -> Self {
-        let global_env_manager = Arc::new(GlobalEnvironmentManager::new());
         let module_system = ModuleSystem::new().expect("Failed to create module system");
-        let scheme_loader = SchemeLibraryLoader::new(global_env_manager.clone())
-            .expect("Failed to create scheme library loader");
+        let scheme_loader = Self::new_scheme_loader();
         
         Self {
             generation: 0,
             stack_trace: StackTrace::new(),
             global_env: crate::eval::environment::global_environment(),
-            macro_expander,
+            macro_expander: macro_expander.with_library_loader(scheme_loader.clone()),
             effect_system: EffectSystem::new(),
             effect_lifter: EffectLifter::new(),
             ffi_bridge: FfiBridge::with_builtins(),
@@ -172,9 +234,27 @@ impl Evaluator {
             module_system,
             scheme_loader,
             call_cc_context: None,
+            exception_handlers: Vec::new(),
+            dynamic_point: None,
+            procedural_macros: HashMap::new(),
+            macro_expansions: Vec::new(),
+            pending_tail_call: None,
+            effect_sandbox: None,
+            gc_system: OnceCell::new(),
         }
     }
 
+    /// Creates the loader `import` uses, searching `stdlib` and the library
+    /// resolver's paths.
+    fn new_scheme_loader() -> Rc<SchemeLibraryLoader> {
+        let global_env_manager = Arc::new(GlobalEnvironmentManager::new());
+        let mut scheme_loader = SchemeLibraryLoader::new(global_env_manager)
+            .expect("Failed to create scheme library loader");
+        scheme_loader.add_search_path("stdlib");
+        scheme_loader.initialize_from_library_resolver();
+        Rc::new(scheme_loader)
+    }
+
     /// Evaluates an expression in the given environment.
     ///
     /// This is the main entry point for expression evaluation.
@@ -188,11 +268,15 @@ impl Evaluator {
             expr: expanded_expr,
             env,
         };
+        let dynamic_point = self.dynamic_point.clone();
 
         // Trampoline loop - keeps evaluating until we get a final result
         loop {
             step = match step {
                 EvalStep::Return(value) => return Ok(value),
+                EvalStep::Error(Error::NonLocalJump { value, target_stack_depth, continuation_id }) => {
+                    EvalStep::NonLocalJump { value, target_stack_depth, continuation_id }
+                }
                 EvalStep::Error(error) => return Err(Box::new(error)),
                 EvalStep::Continue { expr, env } => self.eval_step(&expr, env),
                 EvalStep::TailCall { procedure, args, location } => {
@@ -201,9 +285,9 @@ impl Evaluator {
                 EvalStep::CallContinuation { continuation, value } => {
                     self.call_continuation(continuation, value)
                 }
-                EvalStep::NonLocalJump { value, target_stack_depth: _ } => {
+                EvalStep::NonLocalJump { value, .. } => {
                     // Non-local jump immediately returns the value, bypassing all computation
-                    return Ok(value);
+                    return self.finish_jump(dynamic_point, value);
                 }
             };
         }
@@ -217,6 +301,7 @@ impl Evaluator {
 
         // First, expand all macros in the program
         let expanded_program = self.macro_expander.expand_program(program)?;
+        let dynamic_point = self.dynamic_point.clone();
         
         // Separate defines from other expressions for proper R7RS mutual recursion
         let mut defines = Vec::new();
@@ -262,6 +347,9 @@ impl Evaluator {
             loop {
                 step = match step {
                     EvalStep::Return(_) => break, // Define returns unspecified
+                    EvalStep::Error(Error::NonLocalJump { value, target_stack_depth, continuation_id }) => {
+                        EvalStep::NonLocalJump { value, target_stack_depth, continuation_id }
+                    }
                     EvalStep::Error(error) => return Err(Box::new(error)),
                     EvalStep::Continue { expr, env } => self.eval_step(&expr, env),
                     EvalStep::TailCall { procedure, args, location } => {
@@ -270,9 +358,9 @@ impl Evaluator {
                     EvalStep::CallContinuation { continuation, value } => {
                         self.call_continuation(continuation, value)
                     }
-                    EvalStep::NonLocalJump { value, target_stack_depth: _ } => {
+                    EvalStep::NonLocalJump { value, .. } => {
                         // Non-local jump immediately returns the value
-                        return Ok(value);
+                        return self.finish_jump(dynamic_point.clone(), value);
                     }
                 };
             }
@@ -289,6 +377,9 @@ impl Evaluator {
             loop {
                 step = match step {
                     EvalStep::Return(_) => break, // Define returns unspecified
+                    EvalStep::Error(Error::NonLocalJump { value, target_stack_depth, continuation_id }) => {
+                        EvalStep::NonLocalJump { value, target_stack_depth, continuation_id }
+                    }
                     EvalStep::Error(error) => return Err(Box::new(error)),
                     EvalStep::Continue { expr, env } => self.eval_step(&expr, env),
                     EvalStep::TailCall { procedure, args, location } => {
@@ -297,9 +388,9 @@ impl Evaluator {
                     EvalStep::CallContinuation { continuation, value } => {
                         self.call_continuation(continuation, value)
                     }
-                    EvalStep::NonLocalJump { value, target_stack_depth: _ } => {
+                    EvalStep::NonLocalJump { value, .. } => {
                         // Non-local jump immediately returns the value
-                        return Ok(value);
+                        return self.finish_jump(dynamic_point.clone(), value);
                     }
                 };
             }
@@ -321,6 +412,9 @@ impl Evaluator {
                         result = value;
                         break;
                     }
+                    EvalStep::Error(Error::NonLocalJump { value, target_stack_depth, continuation_id }) => {
+                        EvalStep::NonLocalJump { value, target_stack_depth, continuation_id }
+                    }
                     EvalStep::Error(error) => return Err(Box::new(error)),
                     EvalStep::Continue { expr, env } => self.eval_step(&expr, env),
                     EvalStep::TailCall { procedure, args, location } => {
@@ -329,9 +423,9 @@ impl Evaluator {
                     EvalStep::CallContinuation { continuation, value } => {
                         self.call_continuation(continuation, value)
                     }
-                    EvalStep::NonLocalJump { value, target_stack_depth: _ } => {
+                    EvalStep::NonLocalJump { value, .. } => {
                         // Non-local jump immediately returns the value
-                        return Ok(value);
+                        return self.finish_jump(dynamic_point.clone(), value);
                     }
                 };
             }
@@ -396,6 +490,7 @@ impl Evaluator {
 
             // Special forms
             Expr::Quote(quoted) => self.eval_quote(quoted),
+            Expr::Vector(_) => self.eval_quote(expr),
             Expr::Quasiquote(template) => self.eval_quasiquote(template, env, expr.span),
             Expr::Unquote(unquoted) => self.eval_unquote(unquoted, env, expr.span),
             Expr::UnquoteSplicing(spliced) => self.eval_unquote_splicing(spliced, env, expr.span),
@@ -417,8 +512,8 @@ impl Evaluator {
             Expr::DefineSyntax { name, transformer } => {
                 self.eval_define_syntax(name, transformer, env, expr.span)
             }
-            Expr::SyntaxRules { literals, rules } => {
-                self.eval_syntax_rules(literals, rules, env, expr.span)
+            Expr::SyntaxRules { .. } => {
+                self.eval_syntax_rules(expr, env)
             }
             Expr::CallCC(proc_expr) => {
                 self.eval_call_cc(proc_expr, env, expr.span)
@@ -451,8 +546,13 @@ impl Evaluator {
             Expr::LetStar { bindings, body } => self.eval_let_star(bindings, body, env, expr.span),
             Expr::LetRec { bindings, body } => self.eval_letrec(bindings, body, env, expr.span),
             Expr::Cond(clauses) => self.eval_cond(clauses, env, expr.span),
+            Expr::When { test, body } => self.eval_when(test, body, true, env, expr.span),
+            Expr::Unless { test, body } => self.eval_when(test, body, false, env, expr.span),
             Expr::And(exprs) => self.eval_and(exprs, env, expr.span),
             Expr::Or(exprs) => self.eval_or(exprs, env, expr.span),
+            Expr::Do { bindings, test, result, body } => {
+                self.eval_do(bindings, test, result, body, env, expr.span)
+            }
             Expr::Guard { variable, clauses, body } => {
                 self.eval_guard(variable, clauses, body, env, expr.span)
             }
@@ -648,6 +748,15 @@ impl Evaluator {
                                 )));
                             }
                         }
+                        Value::MutablePair(..) => match spliced_value.as_list() {
+                            Some(items) => result.extend(items),
+                            None => {
+                                return Err(Box::new(Error::runtime_error(
+                                    "unquote-splicing: not a proper list",
+                                    Some(element.span),
+                                )));
+                            }
+                        },
                         _ => {
                             return Err(Box::new(Error::runtime_error(
                                 "unquote-splicing: not a list",
@@ -684,6 +793,15 @@ impl Evaluator {
                                 )));
                             }
                         }
+                        Value::MutablePair(..) => match spliced_value.as_list() {
+                            Some(items) => result.extend(items),
+                            None => {
+                                return Err(Box::new(Error::runtime_error(
+                                    "unquote-splicing: not a proper list",
+                                    Some(element.span),
+                                )));
+                            }
+                        },
                         _ => {
                             return Err(Box::new(Error::runtime_error(
                                 "unquote-splicing: not a list",
@@ -798,7 +916,7 @@ impl Evaluator {
         self.stack_trace.push(StackFrame::special_form("if".to_string(), Some(span)));
 
         // Evaluate test expression
-        match self.eval(test, env.clone()) {
+        match self.eval(test, env.clone()).and_then(|value| single_value(value, "if test", test.span)) {
             Ok(test_value) => {
                 self.stack_trace.pop(); // Remove if frame
                 
@@ -869,7 +987,7 @@ impl Evaluator {
             }
         } else {
             // For non-lambda expressions, use normal evaluation
-            match self.eval(value_expr, env.clone()) {
+            match self.eval(value_expr, env.clone()).and_then(|value| single_value(value, "define", value_expr.span)) {
                 Ok(value) => {
                     env.define(name.to_string(), value);
                     self.stack_trace.pop();
@@ -893,7 +1011,7 @@ impl Evaluator {
     ) -> EvalStep {
         self.stack_trace.push(StackFrame::special_form("set!".to_string(), Some(span)));
 
-        match self.eval(value_expr, env.clone()) {
+        match self.eval(value_expr, env.clone()).and_then(|value| single_value(value, "set!", value_expr.span)) {
             Ok(value) => {
                 // Check if set! should be automatically lifted to State monad
                 let args = vec![Value::symbol(crate::utils::intern_symbol(name)), value.clone()];
@@ -934,6 +1052,16 @@ impl Evaluator {
     ) -> EvalStep {
         self.stack_trace.push(StackFrame::special_form("define-syntax".to_string(), Some(span)));
 
+        // Procedural transformers are ordinary values built at runtime
+        if !matches!(transformer.inner, Expr::SyntaxRules { .. })
+            && let Ok(value) = self.eval(transformer, env.clone())
+            && let Some(procedural) = ProceduralTransformer::from_value(&value)
+        {
+            self.procedural_macros.insert(name.to_string(), procedural);
+            self.stack_trace.pop();
+            return EvalStep::Return(Value::Unspecified);
+        }
+
         // Parse the transformer and add it to the macro environment
         match self.parse_syntax_transformer(transformer, env) {
             Ok(macro_transformer) => {
@@ -951,23 +1079,12 @@ impl Evaluator {
     /// Evaluates a syntax-rules expression.
     fn eval_syntax_rules(
         &mut self,
-        literals: &[String],
-        rules: &[(Spanned<Expr>, Spanned<Expr>)],
+        syntax_rules_expr: &Spanned<Expr>,
         env: Rc<Environment>,
-        span: Span,
     ) -> EvalStep {
-        self.stack_trace.push(StackFrame::special_form("syntax-rules".to_string(), Some(span)));
-
-        // Create a syntax-rules transformer
-        let syntax_rules_expr = Spanned::new(
-            Expr::SyntaxRules {
-                literals: literals.to_vec(),
-                rules: rules.to_vec(),
-            },
-            span,
-        );
+        self.stack_trace.push(StackFrame::special_form("syntax-rules".to_string(), Some(syntax_rules_expr.span)));
 
-        match crate::macro_system::parse_syntax_rules(&syntax_rules_expr, env) {
+        match crate::macro_system::parse_syntax_rules(syntax_rules_expr, env) {
             Ok(syntax_rules_transformer) => {
                 let macro_transformer = crate::macro_system::syntax_rules_to_macro_transformer(syntax_rules_transformer);
                 // For direct syntax-rules evaluation, we could return a procedure
@@ -1065,8 +1182,30 @@ impl Evaluator {
         env: Rc<Environment>,
         span: Span,
     ) -> EvalStep {
-        // Check if this is a function call that should be automatically lifted
-        if let Expr::Identifier(op_name) = &operator.inner {
+        // Uses of procedural macros are expanded here, unless a variable
+        // binding shadows the macro name
+        if let Expr::Identifier(op_name) = &operator.inner
+            && let Some(transformer) = self.procedural_macros.get(op_name).cloned()
+            && env.lookup(op_name).is_none()
+        {
+            let form = Spanned::new(
+                Expr::Application { operator: Box::new(operator.clone()), operands: operands.to_vec() },
+                span,
+            );
+            let expanded = procedural_macro::expand(self, &transformer, &form, &env)
+                .and_then(|expr| self.macro_expander.expand(&expr));
+            return match expanded {
+                Ok(expr) => EvalStep::Continue { expr, env },
+                Err(e) => EvalStep::Error(*e),
+            };
+        }
+
+        // Check if this is a function call that should be automatically lifted.
+        // Only operators with no binding are candidates: a bound procedure is
+        // applied as usual below, which evaluates its operands just once
+        if let Expr::Identifier(op_name) = &operator.inner
+            && env.lookup(op_name).is_none()
+        {
             // Evaluate operands first for effect lifting
             let mut args = Vec::new();
             for operand in operands {
@@ -1110,9 +1249,15 @@ impl Evaluator {
         // Use mini-trampoline to evaluate operator
         let procedure = loop {
             step = match step {
-                EvalStep::Return(value) => break value,
+                EvalStep::Return(value) => match single_value(value, "operator position", operator.span) {
+                    Ok(value) => break value,
+                    Err(e) => return EvalStep::Error(*e),
+                },
+                EvalStep::Error(Error::NonLocalJump { value, target_stack_depth, continuation_id }) => {
+                    EvalStep::NonLocalJump { value, target_stack_depth, continuation_id }
+                }
                 EvalStep::Error(error) => return EvalStep::Error(error),
-                EvalStep::NonLocalJump { value, target_stack_depth: _ } => {
+                EvalStep::NonLocalJump { value, .. } => {
                     // Continuation call during operator evaluation 
                     // Return the continuation value directly
                     return EvalStep::Return(value);
@@ -1138,9 +1283,15 @@ impl Evaluator {
             // Use mini-trampoline to evaluate each operand
             let arg_value = loop {
                 step = match step {
-                    EvalStep::Return(value) => break value,
+                    EvalStep::Return(value) => match single_value(value, "argument position", operand.span) {
+                        Ok(value) => break value,
+                        Err(e) => return EvalStep::Error(*e),
+                    },
+                    EvalStep::Error(Error::NonLocalJump { value, target_stack_depth, continuation_id }) => {
+                        EvalStep::NonLocalJump { value, target_stack_depth, continuation_id }
+                    }
                     EvalStep::Error(error) => return EvalStep::Error(error),
-                    EvalStep::NonLocalJump { value, target_stack_depth: _ } => {
+                    EvalStep::NonLocalJump { value, .. } => {
                         // Continuation call during operand evaluation 
                         // This means a continuation was called somewhere in the operand
                         // We should return this value instead of continuing with application
@@ -1174,16 +1325,10 @@ impl Evaluator {
             Value::CaseLambda(case_lambda) => self.apply_case_lambda_procedure(&case_lambda, args, location),
             Value::Primitive(prim) => self.apply_primitive_procedure(&prim, args, location),
             Value::Continuation(cont) => {
-                if args.len() != 1 {
-                    EvalStep::Error(Error::runtime_error(
-                        format!("Continuation expects 1 argument, got {}", args.len()),
-                        location,
-                    ))
-                } else {
-                    EvalStep::CallContinuation {
-                        continuation: cont,
-                        value: args[0].clone(),
-                    }
+                // Zero or several arguments are delivered as multiple values
+                EvalStep::CallContinuation {
+                    continuation: cont,
+                    value: Value::values(args),
                 }
             }
             Value::Parameter(param) => {
@@ -1336,6 +1481,16 @@ impl Evaluator {
             }
         }
 
+        if let Some(sandbox) = &self.effect_sandbox {
+            let denied = prim.effects.iter().find(|effect| !sandbox.permits(std::slice::from_ref(*effect)));
+            if let Some(effect) = denied {
+                return EvalStep::Error(Error::runtime_error(
+                    format!("{}: {effect} effect is not permitted in this sandbox", prim.name),
+                    location,
+                ));
+            }
+        }
+
         // Track effects from the primitive
         if !prim.effects.is_empty() && !prim.effects.contains(&Effect::Pure) {
             let _old_context = self.effect_system.enter_context(prim.effects.clone());
@@ -1364,19 +1519,333 @@ impl Evaluator {
         };
 
         self.stack_trace.pop();
+        let tail_call = self.pending_tail_call.take();
+
+        // Conditions raised by a leaf primitive record the call site
+        let result = match (result, location) {
+            (Err(error), Some(location)) if !matches!(prim.implementation, PrimitiveImpl::EvaluatorIntegrated(_)) => {
+                match *error {
+                    Error::Exception { exception, span: None } => Err(Box::new(Error::Exception {
+                        exception: exception.with_location(location),
+                        span: Some(location),
+                    })),
+                    other => Err(Box::new(other)),
+                }
+            }
+            (result, _) => result,
+        };
 
-        match result {
-            Ok(value) => EvalStep::Return(value),
-            Err(e) => EvalStep::Error(*e),
+        // A leaf primitive returning an exception is the point of the raise, so the
+        // current handler runs here. Evaluator-integrated primitives only propagate
+        // exceptions that were already signalled by the procedures they called.
+        let result = match result {
+            Err(error) if !matches!(prim.implementation, PrimitiveImpl::EvaluatorIntegrated(_))
+                && self.has_exception_handler() =>
+            {
+                match *error {
+                    Error::Exception { exception, span } => {
+                        self.signal_exception(exception, span.or(location))
+                    }
+                    other => Err(Box::new(other)),
+                }
+            }
+            other => other,
+        };
+
+        match (result, tail_call) {
+            (Ok(_), Some((procedure, args))) => EvalStep::TailCall { procedure, args, location },
+            (Ok(value), None) => EvalStep::Return(value),
+            (Err(e), _) => EvalStep::Error(*e),
         }
     }
 
+    /// Makes the running evaluator-integrated primitive finish by applying
+    /// `procedure` to `args` through the trampoline.
+    ///
+    /// The call replaces the primitive's result, so a primitive that ends by
+    /// calling a procedure can do so in tail position without growing the
+    /// Rust stack. The returned value is a placeholder for the primitive to
+    /// return.
+    pub fn tail_call(&mut self, procedure: Value, args: Vec<Value>) -> Result<Value> {
+        self.pending_tail_call = Some((procedure, args));
+        Ok(Value::Unspecified)
+    }
+
+    /// Applies a procedure and runs the trampoline until it produces a value.
+    pub fn call_procedure(&mut self, procedure: Value, args: Vec<Value>, location: Option<Span>) -> Result<Value> {
+        let step = self.apply_procedure(procedure, args, location);
+        self.run_to_completion(step)
+    }
+
+    /// Runs the trampoline from `step` until it produces a value or an error.
+    ///
+    /// A jump to a continuation captured during the run lands here and
+    /// becomes the run's value. Any other continuation escape leaves as
+    /// [`Error::NonLocalJump`], which the trampoline around the caller turns
+    /// back into the jump.
+    fn run_to_completion(&mut self, mut step: EvalStep) -> Result<Value> {
+        let first_inner_continuation = CONTINUATION_COUNTER.load(Ordering::SeqCst);
+        loop {
+            step = match step {
+                EvalStep::Return(value) => return Ok(value),
+                EvalStep::Error(Error::NonLocalJump { value, continuation_id, .. })
+                    if continuation_id >= first_inner_continuation =>
+                {
+                    return Ok(value);
+                }
+                EvalStep::Error(error) => return Err(Box::new(error)),
+                EvalStep::Continue { expr, env } => self.eval_step(&expr, env),
+                EvalStep::TailCall { procedure, args, location } => {
+                    self.apply_procedure(procedure, args, location)
+                }
+                EvalStep::CallContinuation { continuation, value } => {
+                    self.call_continuation(continuation, value)
+                }
+                EvalStep::NonLocalJump { value, continuation_id, .. }
+                    if continuation_id >= first_inner_continuation =>
+                {
+                    return Ok(value);
+                }
+                EvalStep::NonLocalJump { value, target_stack_depth, continuation_id } => {
+                    return Err(Box::new(Error::NonLocalJump { value, target_stack_depth, continuation_id }));
+                }
+            };
+        }
+    }
+
+    /// Records the current depth of the evaluator's stacks.
+    fn escape_mark(&self) -> EscapeMark {
+        EscapeMark {
+            stack_trace_depth: self.stack_trace.len(),
+            context_depth: self.context_stack.len(),
+            handler_depth: self.exception_handlers.len(),
+            dynamic_point: self.dynamic_point.clone(),
+        }
+    }
+
+    /// Discards everything pushed onto the evaluator's stacks since `mark`.
+    fn unwind_to(&mut self, mark: EscapeMark) {
+        self.stack_trace.truncate(mark.stack_trace_depth);
+        self.context_stack.truncate(mark.context_depth);
+        self.exception_handlers.truncate(mark.handler_depth);
+    }
+
+    /// Returns true if the innermost exception handler is a handler procedure.
+    fn has_exception_handler(&self) -> bool {
+        matches!(self.exception_handlers.last(), Some(ExceptionHandlerFrame::Handler(_)))
+    }
+
+    /// Returns the number of installed exception handlers, including guards.
+    pub fn exception_handler_depth(&self) -> usize {
+        self.exception_handlers.len()
+    }
+
+    /// Calls `thunk` with `handler` installed as the current exception handler.
+    ///
+    /// The handler stack is restored on exit whether the thunk returns or fails.
+    pub fn call_with_exception_handler(&mut self, handler: Value, thunk: Value) -> Result<Value> {
+        let depth = self.exception_handlers.len();
+        self.exception_handlers.push(ExceptionHandlerFrame::Handler(handler));
+        let result = self.call_procedure(thunk, Vec::new(), None);
+        self.exception_handlers.truncate(depth);
+        result
+    }
+
+    /// Signals `exception` to the current exception handler.
+    ///
+    /// Per R7RS the handler runs in the dynamic environment of the raise, except
+    /// that the handler stack is that of the outer handlers, so a handler that
+    /// raises reaches the next handler out. A continuable exception resumes with
+    /// the handler's value; a handler returning from a non-continuable exception
+    /// raises a secondary error in the handler's dynamic environment. When the
+    /// innermost handler is a `guard` (or there is none) the exception propagates
+    /// as an error so the guard can unwind to it.
+    pub fn signal_exception(
+        &mut self,
+        exception: crate::stdlib::exceptions::ExceptionObject,
+        location: Option<Span>,
+    ) -> Result<Value> {
+        let handler = match self.exception_handlers.last() {
+            Some(ExceptionHandlerFrame::Handler(handler)) => handler.clone(),
+            _ => return Err(Box::new(Error::Exception { exception, span: location })),
+        };
+
+        let depth = self.exception_handlers.len() - 1;
+        let installed = self.exception_handlers.split_off(depth);
+        let result = self
+            .call_procedure(handler, vec![exception.value.clone()], location)
+            .and_then(|value| {
+                if exception.continuable {
+                    Ok(value)
+                } else {
+                    let secondary = crate::stdlib::exceptions::ExceptionObject::error(
+                        "exception handler returned from non-continuable raise".to_string(),
+                        vec![exception.value.clone()],
+                    );
+                    self.signal_exception(secondary, location)
+                }
+            });
+        self.exception_handlers.truncate(depth);
+        self.exception_handlers.extend(installed);
+        result
+    }
+
     /// Calls a continuation.
     pub fn call_continuation(&mut self, continuation: Arc<Continuation>, value: Value) -> EvalStep {
+        // Run the after/before thunks between here and the capture point first
+        if let Err(e) = self.wind_to(continuation.dynamic_point.clone()) {
+            return EvalStep::Error(*e);
+        }
+
         // Restore the continuation context and continue computation with the provided value
         self.restore_continuation(&continuation, value)
     }
 
+    /// Calls `thunk` inside a `dynamic-wind` extent guarded by `before` and `after`.
+    ///
+    /// `after` runs when the thunk returns or fails. If a continuation leaves the
+    /// extent, [`wind_to`](Self::wind_to) has already run it and it is skipped here.
+    pub fn dynamic_wind(&mut self, before: Value, thunk: Value, after: Value) -> Result<Value> {
+        self.dynamic_wind_named(None, before, thunk, after)
+    }
+
+    /// Like [`dynamic_wind`](Self::dynamic_wind), recording `name` on the
+    /// extent for [`dynamic_wind_stack`](Self::dynamic_wind_stack).
+    pub fn dynamic_wind_named(
+        &mut self,
+        name: Option<Value>,
+        before: Value,
+        thunk: Value,
+        after: Value,
+    ) -> Result<Value> {
+        self.call_procedure(before.clone(), Vec::new(), None)?;
+
+        let point = DynamicPoint::named(before, after.clone(), self.dynamic_point.clone(), name);
+        self.dynamic_point = Some(point.clone());
+
+        let result = self.call_procedure(thunk, Vec::new(), None);
+
+        if self.dynamic_point.as_ref().is_some_and(|current| Arc::ptr_eq(current, &point)) {
+            self.dynamic_point = point.parent.clone();
+            self.call_procedure(after, Vec::new(), None)?;
+        }
+
+        result
+    }
+
+    /// Returns the debug names of the active dynamic extents, innermost first.
+    ///
+    /// Unnamed `dynamic-wind` extents are `None`; the extent of a promise
+    /// being forced is named `force`.
+    pub fn dynamic_wind_stack(&self) -> Vec<Option<Value>> {
+        point_names(&self.dynamic_point)
+    }
+
+    /// Forces `value`, following and memoizing the chain of promises it leads to.
+    ///
+    /// Each thunk runs in a fresh dynamic extent nested in that of the `force`
+    /// call, so `dynamic-wind` forms inside it wind once on the first force and
+    /// never again. A thunk that raises, or that is left through a continuation,
+    /// leaves its promise unforced so a later `force` runs it again. If the
+    /// thunk forces its own promise, the value computed first is kept (R7RS 4.2.5).
+    pub fn force_promise(&mut self, value: Value) -> Result<Value> {
+        let mut pending: Vec<Arc<std::sync::RwLock<Promise>>> = Vec::new();
+        let mut current = value;
+
+        while let Value::Promise(promise) = current.clone() {
+            let thunk = match &*promise.read().unwrap() {
+                Promise::Forced(value) => {
+                    current = value.clone();
+                    continue;
+                }
+                Promise::Delayed { thunk } | Promise::TailRecursive { thunk } => thunk.clone(),
+                Promise::Expression { .. } => {
+                    return Err(Box::new(Error::runtime_error(
+                        "expression-based promises require evaluator integration",
+                        None,
+                    )));
+                }
+            };
+            if pending.iter().any(|p| Arc::ptr_eq(p, &promise)) {
+                return Err(Box::new(Error::runtime_error(
+                    "circular promise reference detected",
+                    None,
+                )));
+            }
+
+            let noop = Value::Primitive(Arc::new(PrimitiveProcedure {
+                name: "force-extent".to_string(),
+                arity_min: 0,
+                arity_max: Some(0),
+                implementation: PrimitiveImpl::RustFn(|_| Ok(Value::Unspecified)),
+                effects: vec![Effect::Pure],
+            }));
+            let point = DynamicPoint::named(
+                noop.clone(),
+                noop,
+                self.dynamic_point.clone(),
+                Some(Value::symbol_from_str("force")),
+            );
+            self.dynamic_point = Some(point.clone());
+
+            let result = self.call_procedure(thunk, Vec::new(), None);
+
+            if !self.dynamic_point.as_ref().is_some_and(|current| Arc::ptr_eq(current, &point)) {
+                // A continuation carried control out of the thunk
+                return result;
+            }
+            self.dynamic_point = point.parent.clone();
+            let value = result?;
+
+            current = match &*promise.read().unwrap() {
+                Promise::Forced(first) => first.clone(),
+                _ => {
+                    pending.push(promise.clone());
+                    value
+                }
+            };
+        }
+
+        for promise in pending {
+            let mut state = promise.write().unwrap();
+            if !matches!(*state, Promise::Forced(_)) {
+                *state = Promise::Forced(current.clone());
+            }
+        }
+        Ok(current)
+    }
+
+    /// Moves from the current dynamic point to `target`, running the after
+    /// thunks of exited extents and the before thunks of entered ones.
+    ///
+    /// Each thunk runs in the dynamic environment of its `dynamic-wind` call,
+    /// i.e. with the point's parent current.
+    fn wind_to(&mut self, target: Option<Arc<DynamicPoint>>) -> Result<()> {
+        for step in wind_path(&self.dynamic_point, &target) {
+            match step {
+                WindStep::Unwind(point) => {
+                    self.dynamic_point = point.parent.clone();
+                    self.call_procedure(point.after.clone(), Vec::new(), None)?;
+                }
+                WindStep::Rewind(point) => {
+                    self.dynamic_point = point.parent.clone();
+                    self.call_procedure(point.before.clone(), Vec::new(), None)?;
+                    self.dynamic_point = Some(point);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Ends a continuation jump at a trampoline entered at `dynamic_point`.
+    ///
+    /// The jump delivers `value` as the trampoline's result, so the extents
+    /// it entered on the way to its target are exited again.
+    fn finish_jump(&mut self, dynamic_point: Option<Arc<DynamicPoint>>, value: Value) -> Result<Value> {
+        self.wind_to(dynamic_point)?;
+        Ok(value)
+    }
+
     /// Captures the current continuation.
     /// This preserves the evaluation context so it can be restored during continuation invocation.
     fn capture_continuation(&self, env: Rc<Environment>, current_expr: Option<Spanned<Expr>>) -> Continuation {
@@ -1391,6 +1860,7 @@ impl Evaluator {
             next_continuation_id(),
             current_expr,
         )
+        .with_dynamic_point(self.dynamic_point.clone())
     }
 
     /// Restores a captured continuation and returns the given value.
@@ -1405,6 +1875,7 @@ impl Evaluator {
         EvalStep::NonLocalJump {
             value,
             target_stack_depth: continuation.stack.len(),
+            continuation_id: continuation.id,
         }
     }
 
@@ -1602,6 +2073,166 @@ impl Evaluator {
     }
 
 
+    /// Evaluates a do loop.
+    ///
+    /// When nothing in the loop can capture its variables, one loop
+    /// environment is reused and the step values are stored into it in place.
+    /// Otherwise each iteration binds the variables in a fresh environment, so
+    /// closures created in different iterations see distinct values.
+    fn eval_do(
+        &mut self,
+        bindings: &[DoBinding],
+        test: &Spanned<Expr>,
+        result: &[Spanned<Expr>],
+        body: &[Spanned<Expr>],
+        env: Rc<Environment>,
+        span: Span,
+    ) -> EvalStep {
+        self.stack_trace.push(StackFrame::special_form("do".to_string(), Some(span)));
+        let outcome = self.run_do_loop(bindings, test, body, env);
+        self.stack_trace.pop();
+
+        match outcome {
+            // The result expressions are evaluated in the final loop environment
+            Ok(loop_env) => self.eval_sequence(result, loop_env),
+            Err(e) => EvalStep::Error(*e),
+        }
+    }
+
+    /// Iterates a do loop until its test holds, returning the environment of
+    /// the final iteration.
+    fn run_do_loop(
+        &mut self,
+        bindings: &[DoBinding],
+        test: &Spanned<Expr>,
+        body: &[Spanned<Expr>],
+        env: Rc<Environment>,
+    ) -> Result<Rc<Environment>> {
+        let reuse_env = !(self.may_capture_environment(&test.inner)
+            || body.iter().any(|command| self.may_capture_environment(&command.inner))
+            || bindings.iter().any(|binding| {
+                binding.step.as_ref().is_some_and(|step| self.may_capture_environment(&step.inner))
+            }));
+
+        let mut loop_env = env.extend(self.generation);
+        for binding in bindings {
+            let value = self.eval_expanded(&binding.init, &env)?;
+            loop_env.define(binding.name.clone(), value);
+        }
+
+        let mut steps: Vec<Option<Value>> = Vec::with_capacity(bindings.len());
+        loop {
+            if self.eval_expanded(test, &loop_env)?.is_truthy() {
+                return Ok(loop_env);
+            }
+            for command in body {
+                self.eval_expanded(command, &loop_env)?;
+            }
+
+            // Every step sees the bindings of the iteration just finished
+            for binding in bindings {
+                let value = match &binding.step {
+                    Some(step) => Some(self.eval_expanded(step, &loop_env)?),
+                    None => None,
+                };
+                steps.push(value);
+            }
+
+            if reuse_env {
+                let mut slots = loop_env.bindings.borrow_mut();
+                for (binding, value) in bindings.iter().zip(steps.drain(..)) {
+                    if let (Some(value), Some(slot)) = (value, slots.get_mut(&binding.name)) {
+                        *slot = value;
+                    }
+                }
+            } else {
+                let next_env = env.extend(self.generation);
+                for (binding, value) in bindings.iter().zip(steps.drain(..)) {
+                    // Variables without a step keep their current value
+                    let value = match value {
+                        Some(value) => value,
+                        None => loop_env.lookup(&binding.name).unwrap_or(Value::Unspecified),
+                    };
+                    next_env.define(binding.name.clone(), value);
+                }
+                loop_env = next_env;
+            }
+        }
+    }
+
+    /// Evaluates an already macro-expanded expression to a value.
+    fn eval_expanded(&mut self, expr: &Spanned<Expr>, env: &Rc<Environment>) -> Result<Value> {
+        let step = self.eval_step(expr, env.clone());
+        self.run_to_completion(step)
+    }
+
+    /// Returns true if evaluating `expr` may create a closure or continuation
+    /// that keeps hold of the current environment.
+    fn may_capture_environment(&self, expr: &Expr) -> bool {
+        let any = |exprs: &[Spanned<Expr>]| exprs.iter().any(|e| self.may_capture_environment(&e.inner));
+
+        match expr {
+            Expr::Literal(_) | Expr::Identifier(_) | Expr::Symbol(_) | Expr::Keyword(_) | Expr::Quote(_) | Expr::Vector(_) => false,
+            Expr::Application { operator, operands } => {
+                // A procedural macro may expand into anything
+                let is_procedural_macro = matches!(&operator.inner, Expr::Identifier(name) if self.procedural_macros.contains_key(name));
+                is_procedural_macro || self.may_capture_environment(&operator.inner) || any(operands)
+            }
+            Expr::List(exprs) | Expr::Begin(exprs) | Expr::And(exprs) | Expr::Or(exprs) => any(exprs),
+            Expr::Quasiquote(inner) | Expr::Unquote(inner) | Expr::UnquoteSplicing(inner) => {
+                self.may_capture_environment(&inner.inner)
+            }
+            Expr::TypeAnnotation { expr, .. } => self.may_capture_environment(&expr.inner),
+            Expr::Primitive { args, .. } => any(args),
+            Expr::If { test, consequent, alternative } => {
+                self.may_capture_environment(&test.inner)
+                    || self.may_capture_environment(&consequent.inner)
+                    || alternative.as_ref().is_some_and(|alt| self.may_capture_environment(&alt.inner))
+            }
+            Expr::Set { value, .. } => self.may_capture_environment(&value.inner),
+            Expr::Pair { car, cdr } => {
+                self.may_capture_environment(&car.inner) || self.may_capture_environment(&cdr.inner)
+            }
+            Expr::Let { bindings, body } | Expr::LetStar { bindings, body } | Expr::LetRec { bindings, body } => {
+                bindings.iter().any(|b| self.may_capture_environment(&b.value.inner)) || any(body)
+            }
+            Expr::Cond(clauses) => clauses.iter().any(|c| self.may_capture_environment(&c.test.inner) || any(&c.body)),
+            Expr::Case { expr, clauses } => {
+                self.may_capture_environment(&expr.inner) || clauses.iter().any(|c| any(&c.body))
+            }
+            Expr::When { test, body } | Expr::Unless { test, body } => {
+                self.may_capture_environment(&test.inner) || any(body)
+            }
+            Expr::Do { bindings, test, result, body } => {
+                bindings.iter().any(|b| {
+                    self.may_capture_environment(&b.init.inner)
+                        || b.step.as_ref().is_some_and(|step| self.may_capture_environment(&step.inner))
+                }) || self.may_capture_environment(&test.inner) || any(result) || any(body)
+            }
+            Expr::Guard { clauses, body, .. } => {
+                clauses.iter().any(|c| {
+                    self.may_capture_environment(&c.test.inner)
+                        || any(&c.body)
+                        || c.arrow.as_ref().is_some_and(|arrow| self.may_capture_environment(&arrow.inner))
+                }) || any(body)
+            }
+            Expr::Parameterize { bindings, body } => {
+                bindings.iter().any(|b| {
+                    self.may_capture_environment(&b.parameter.inner) || self.may_capture_environment(&b.value.inner)
+                }) || any(body)
+            }
+            // Closures, continuations and definitions hold on to the environment
+            Expr::Lambda { .. }
+            | Expr::CaseLambda { .. }
+            | Expr::CallCC(_)
+            | Expr::Define { .. }
+            | Expr::DefineSyntax { .. }
+            | Expr::SyntaxRules { .. }
+            | Expr::Import { .. }
+            | Expr::DefineLibrary { .. } => true,
+        }
+    }
+
     /// Evaluates a cond expression.
     fn eval_cond(
         &mut self,
@@ -1632,6 +2263,32 @@ impl Evaluator {
         EvalStep::Return(Value::Unspecified)
     }
 
+    /// Evaluates a when or unless expression.
+    ///
+    /// The body runs in sequence when the truthiness of the test matches
+    /// `run_if_true`, with its last expression in tail position. Otherwise,
+    /// or when the body is empty, the result is unspecified.
+    fn eval_when(
+        &mut self,
+        test: &Spanned<Expr>,
+        body: &[Spanned<Expr>],
+        run_if_true: bool,
+        env: Rc<Environment>,
+        span: Span,
+    ) -> EvalStep {
+        let keyword = if run_if_true { "when" } else { "unless" };
+        self.stack_trace.push(StackFrame::special_form(keyword.to_string(), Some(span)));
+
+        let test_value = self.eval(test, env.clone()).and_then(|value| single_value(value, &format!("{keyword} test"), test.span));
+        self.stack_trace.pop();
+
+        match test_value {
+            Ok(value) if value.is_truthy() == run_if_true => self.eval_sequence(body, env),
+            Ok(_) => EvalStep::Return(Value::Unspecified),
+            Err(e) => EvalStep::Error(*e),
+        }
+    }
+
     /// Evaluates an and expression.
     fn eval_and(&mut self, exprs: &[Spanned<Expr>], env: Rc<Environment>, _span: Span) -> EvalStep {
         if exprs.is_empty() {
@@ -1691,20 +2348,30 @@ impl Evaluator {
         env: Rc<Environment>,
         span: Span,
     ) -> EvalStep {
+        // A guard never resumes the raise, so instead of capturing a
+        // continuation it only marks the evaluator's stacks and unwinds to
+        // the mark once the body is done
+        let mark = self.escape_mark();
         self.stack_trace.push(StackFrame::special_form("guard".to_string(), Some(span)));
         
-        // Try to evaluate the body
-        let result = self.eval_sequence(body, env.clone());
+        // The body runs to completion inside the guard's handler so that raises
+        // in tail position are still caught
+        self.exception_handlers.push(ExceptionHandlerFrame::Guard);
+        let step = self.eval_sequence(body, env.clone());
+        let result = self.run_to_completion(step);
         
-        match result {
-            EvalStep::Return(value) => {
+        // Extents the body left open are unwound, innermost first, so their
+        // after thunks have run before any clause sees the condition
+        let result = self.wind_to(mark.dynamic_point.clone()).and(result);
+        self.unwind_to(mark);
+        
+        match result.map_err(|e| *e) {
+            Ok(value) => {
                 // Body completed normally - return the value
-                self.stack_trace.pop();
                 EvalStep::Return(value)
             }
-            EvalStep::Error(Error::Exception { exception, .. }) => {
+            Err(Error::Exception { exception, .. }) => {
                 // An exception was raised - try to handle it with the clauses
-                self.stack_trace.pop();
                 
                 // Create new environment with exception bound to variable
                 let handler_env = env.extend(self.generation);
@@ -1742,13 +2409,15 @@ impl Evaluator {
                     }
                 }
                 
-                // No clause matched - re-raise the exception
-                EvalStep::Error(Error::Exception { exception, span: Some(span) })
+                // No clause matched - re-raise to the handlers outside the guard
+                match self.signal_exception(exception, Some(span)) {
+                    Ok(value) => EvalStep::Return(value),
+                    Err(e) => EvalStep::Error(*e),
+                }
             }
-            other => {
-                // Other evaluation outcomes (continue, tail call, etc.) - pass through
-                self.stack_trace.pop();
-                other
+            Err(other) => {
+                // Other errors are not exceptions and pass through
+                EvalStep::Error(other)
             }
         }
     }
@@ -1971,33 +2640,52 @@ impl Evaluator {
     fn ast_to_value(&self, expr: &Expr) -> Result<Value> {
         match expr {
             Expr::Literal(lit) => Ok(Value::Literal(lit.clone())),
-            Expr::Identifier(name) => Ok(Value::Symbol(intern_symbol(name))),
+            Expr::Identifier(name) | Expr::Symbol(name) => Ok(Value::Symbol(intern_symbol(name))),
             Expr::Keyword(k) => Ok(Value::Keyword(k.clone())),
+            // Abbreviations nested in quoted data read as two-element lists
+            Expr::Quote(inner) | Expr::Quasiquote(inner) | Expr::Unquote(inner) | Expr::UnquoteSplicing(inner) => {
+                let keyword = match expr {
+                    Expr::Quote(_) => "quote",
+                    Expr::Quasiquote(_) => "quasiquote",
+                    Expr::Unquote(_) => "unquote",
+                    _ => "unquote-splicing",
+                };
+                Ok(Value::list(vec![Value::Symbol(intern_symbol(keyword)), self.ast_to_value(&inner.inner)?]))
+            }
             Expr::Pair { car, cdr } => {
                 let car_val = self.ast_to_value(&car.inner)?;
                 let cdr_val = self.ast_to_value(&cdr.inner)?;
                 Ok(Value::pair(car_val, cdr_val))
             }
-            Expr::List(elements) => {
-                // Convert list elements to values
-                let mut values = Vec::new();
-                for element in elements {
-                    values.push(self.ast_to_value(&element.inner)?);
-                }
-                Ok(Value::list(values))
-            }
+            Expr::List(elements) => self.ast_list_to_value(elements.iter()),
+            Expr::Vector(elements) => Ok(Value::vector(
+                elements.iter().map(|element| self.ast_to_value(&element.inner)).collect::<Result<Vec<_>>>()?,
+            )),
             Expr::Application { operator, operands } => {
-                // Convert to list
-                let mut values = vec![self.ast_to_value(&operator.inner)?];
-                for operand in operands {
-                    values.push(self.ast_to_value(&operand.inner)?);
-                }
-                Ok(Value::list(values))
+                self.ast_list_to_value(std::iter::once(operator.as_ref()).chain(operands))
             }
             _ => Ok(Value::list(vec![])), // For now, other forms become empty lists
         }
     }
 
+    /// Converts the elements of a quoted list to a list value. The parser
+    /// reads the dot of `(a . b)` as the identifier `.`, so a dot before the
+    /// last element makes the list improper.
+    fn ast_list_to_value<'a>(&self, elements: impl Iterator<Item = &'a Spanned<Expr>>) -> Result<Value> {
+        let elements: Vec<&Spanned<Expr>> = elements.collect();
+        let is_dot = |element: &Spanned<Expr>| matches!(&element.inner, Expr::Identifier(name) if name == ".");
+        let (items, tail) = match elements.as_slice() {
+            [items @ .., dot, tail] if !items.is_empty() && is_dot(dot) => (items, Some(*tail)),
+            items => (items, None),
+        };
+        
+        let tail = match tail {
+            Some(tail) => self.ast_to_value(&tail.inner)?,
+            None => Value::Nil,
+        };
+        items.iter().rev().try_fold(tail, |rest, item| Ok(Value::pair(self.ast_to_value(&item.inner)?, rest)))
+    }
+
     /// Parses a syntax transformer expression.
     fn parse_syntax_transformer(
         &self,
@@ -2005,7 +2693,7 @@ impl Evaluator {
         env: Rc<Environment>,
     ) -> Result<crate::macro_system::MacroTransformer> {
         match &transformer_expr.inner {
-            Expr::SyntaxRules { literals, rules } => {
+            Expr::SyntaxRules { .. } => {
                 // Parse syntax-rules into a transformer
                 let syntax_rules_transformer = crate::macro_system::parse_syntax_rules(transformer_expr, env)?;
                 Ok(crate::macro_system::syntax_rules_to_macro_transformer(syntax_rules_transformer))
@@ -2022,16 +2710,62 @@ impl Evaluator {
         }
     }
 
+    /// Enters a procedural macro expansion.
+    pub fn push_macro_expansion(&mut self, context: ExpansionContext) {
+        self.macro_expansions.push(context);
+    }
+
+    /// Leaves the innermost procedural macro expansion.
+    pub fn pop_macro_expansion(&mut self) -> Option<ExpansionContext> {
+        self.macro_expansions.pop()
+    }
+
+    /// Gets the innermost procedural macro expansion in progress.
+    pub fn current_macro_expansion(&mut self) -> Option<&mut ExpansionContext> {
+        self.macro_expansions.last_mut()
+    }
+
     /// Gets the current stack trace.
     pub fn stack_trace(&self) -> &StackTrace {
         &self.stack_trace
     }
 
+    /// Gets the global environment.
+    pub fn global_env(&self) -> &Rc<Environment> {
+        &self.global_env
+    }
+
+    /// Replaces the global environment later evaluation runs against.
+    pub fn set_global_env(&mut self, global_env: Rc<Environment>) {
+        self.global_env = global_env;
+    }
+
     /// Increments the generation counter.
     pub fn next_generation(&mut self) {
         self.generation += 1;
     }
 
+    /// Restricts the effects primitives may perform to those `sandbox`
+    /// permits, or lifts the restriction with `None`.
+    pub fn set_effect_sandbox(&mut self, sandbox: Option<EffectSandboxConfig>) {
+        self.effect_sandbox = sandbox;
+    }
+
+    /// Gets the collector `collect-garbage`, `gc-statistics` and
+    /// `set-gc-config!` act on, creating it on first use.
+    pub fn gc_system(&self) -> Result<&GcSystem> {
+        if let Some(gc) = self.gc_system.get() {
+            return Ok(gc);
+        }
+        let gc = GcSystem::for_evaluator().map_err(|message| {
+            Box::new(Error::runtime_error(
+                format!("failed to create garbage collector: {message}"),
+                None,
+            ))
+        })?;
+        Ok(self.gc_system.get_or_init(|| gc))
+    }
+
     /// Gets a reference to the macro expander.
     pub fn macro_expander(&self) -> &MacroExpander {
         &self.macro_expander
@@ -2081,18 +2815,11 @@ impl Evaluator {
     ) -> EvalStep {
         self.stack_trace.push(StackFrame::special_form("import".to_string(), Some(span)));
 
-        // Set up search paths for SRFI libraries if not already done
-        self.setup_library_search_paths();
-
         // Process each import specification
+        let mut bindings_list = Vec::with_capacity(import_specs.len());
         for spec_expr in import_specs {
             match self.process_import_spec(spec_expr, env.clone()) {
-                Ok(bindings) => {
-                    // Import the bindings into the current environment
-                    for (name, value) in bindings {
-                        env.define(name, value);
-                    }
-                }
+                Ok(bindings) => bindings_list.push(bindings),
                 Err(e) => {
                     self.stack_trace.pop();
                     return EvalStep::Error(*e);
@@ -2100,19 +2827,25 @@ impl Evaluator {
             }
         }
 
+        // The same name imported from two libraries is an error unless one
+        // side renamed or excluded it
+        match crate::module_system::import::merge_import_bindings(&bindings_list) {
+            Ok(bindings) => {
+                // Import the bindings into the current environment
+                for (name, value) in bindings {
+                    env.define(name, value);
+                }
+            }
+            Err(e) => {
+                self.stack_trace.pop();
+                return EvalStep::Error(*e);
+            }
+        }
+
         self.stack_trace.pop();
         EvalStep::Return(Value::Unspecified)
     }
 
-    /// Sets up search paths for library loading.
-    fn setup_library_search_paths(&mut self) {
-        // Add stdlib path for SRFI modules
-        self.scheme_loader.add_search_path("stdlib");
-        
-        // Initialize from library resolver if available
-        self.scheme_loader.initialize_from_library_resolver();
-    }
-
     /// Processes a single import specification.
     fn process_import_spec(&mut self, spec_expr: &Spanned<Expr>, _env: Rc<Environment>) -> Result<HashMap<String, Value>> {
         use crate::module_system::{import::parse_import_spec, ModuleId, ModuleNamespace};
@@ -2133,9 +2866,29 @@ impl Evaluator {
         }
     }
 
+    /// Resolves a single import set to the bindings it provides.
+    ///
+    /// Used by `environment` to build first-class environments from the
+    /// same libraries `import` loads.
+    pub fn import_set_bindings(&mut self, spec_expr: &Spanned<Expr>) -> Result<HashMap<String, Value>> {
+        self.process_import_spec(spec_expr, self.global_env.clone())
+    }
+
     /// Parses an import expression into an ImportSpec.
     fn parse_import_expression(&self, spec_expr: &Spanned<Expr>) -> Result<ImportSpec> {
         use crate::module_system::{ImportSpec, ImportConfig, ModuleId, ModuleNamespace};
+        use crate::module_system::import::{import_set_operator, parse_import_modifier};
+        
+        // R7RS import set operators wrap the library name and may nest:
+        // (prefix (only (my lib) foo) my:)
+        if let Some((keyword, elements)) = import_set_operator(spec_expr) {
+            let inner = self.parse_import_expression(&elements[1])?;
+            let modifier = parse_import_modifier(keyword, &elements[2..], elements[0].span)?;
+            return Ok(ImportSpec {
+                module_id: inner.module_id,
+                config: inner.config.then(modifier),
+            });
+        }
         
         match &spec_expr.inner {
             Expr::List(elements) => {
@@ -2149,8 +2902,6 @@ impl Evaluator {
                 // Parse module identifier from first element
                 let module_id = self.parse_module_identifier(&elements[0])?;
                 
-                // For now, we'll support simple imports without configuration
-                // TODO: Add support for (only ...), (except ...), (rename ...), (prefix ...)
                 let config = ImportConfig::All;
 
                 Ok(ImportSpec { module_id, config })
@@ -2671,4 +3422,45 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_when_and_unless_return_last_body_value() {
+        let mut evaluator = Evaluator::new();
+        let env = Rc::new(Environment::new(None, 0));
+        let body: Vec<_> = (1..=3).map(|n| spanned(Expr::Literal(Literal::integer(n)))).collect();
+
+        // (when #t 1 2 3)
+        let when_expr = Expr::When {
+            test: Box::new(spanned(Expr::Literal(Literal::Boolean(true)))),
+            body: body.clone(),
+        };
+        assert_eq!(evaluator.eval(&spanned(when_expr), env.clone()).unwrap(), Value::integer(3));
+
+        // (unless #f 1 2 3)
+        let unless_expr = Expr::Unless {
+            test: Box::new(spanned(Expr::Literal(Literal::Boolean(false)))),
+            body,
+        };
+        assert_eq!(evaluator.eval(&spanned(unless_expr), env).unwrap(), Value::integer(3));
+    }
+
+    #[test]
+    fn test_when_and_unless_skipped_or_empty_are_unspecified() {
+        let mut evaluator = Evaluator::new();
+        let env = Rc::new(Environment::new(None, 0));
+        let test = |b| Box::new(spanned(Expr::Literal(Literal::Boolean(b))));
+        // An unbound body would fail if it were evaluated
+        let body = vec![spanned(Expr::Identifier("unbound-variable".to_string()))];
+
+        let cases = [
+            Expr::When { test: test(false), body: body.clone() },
+            Expr::Unless { test: test(true), body },
+            Expr::When { test: test(false), body: vec![] },
+            Expr::When { test: test(true), body: vec![] },
+        ];
+        for expr in cases {
+            assert!(expr.is_special_form());
+            assert_eq!(evaluator.eval(&spanned(expr), env.clone()).unwrap(), Value::Unspecified);
+        }
+    }
 }
\ No newline at end of file
@@ -566,6 +566,7 @@ fn fast_car(args: &[Value]) -> Result<Value> {
     
     match &args[0] {
         Value::Pair(car, _) => Ok((**car).clone()),
+        Value::MutablePair(car, _) => Ok(car.read().unwrap().clone()),
         _ => Err(Box::new(Error::type_mismatch_error("pair", args[0].clone()))),
     }
 }
@@ -577,6 +578,7 @@ fn fast_cdr(args: &[Value]) -> Result<Value> {
     
     match &args[0] {
         Value::Pair(_, cdr) => Ok((**cdr).clone()),
+        Value::MutablePair(_, cdr) => Ok(cdr.read().unwrap().clone()),
         _ => Err(Box::new(Error::type_mismatch_error("pair", args[0].clone()))),
     }
 }
@@ -586,7 +588,7 @@ fn fast_list_length(args: &[Value]) -> Result<Value> {
         return Err(Box::new(Error::arity_error("length", 1, args.len())));
     }
     
-    let mut current = &args[0];
+    let mut current = args[0].clone();
     let mut length = 0;
     
     loop {
@@ -594,7 +596,11 @@ fn fast_list_length(args: &[Value]) -> Result<Value> {
             Value::Nil => return Ok(Value::integer(length)),
             Value::Pair(_, cdr) => {
                 length += 1;
-                current = cdr;
+                current = (*cdr).clone();
+            }
+            Value::MutablePair(_, cdr) => {
+                length += 1;
+                current = cdr.read().unwrap().clone();
             }
             _ => return Err(Box::new(Error::type_mismatch_error("proper list", args[0].clone()))),
         }
@@ -612,7 +618,7 @@ fn fast_list_ref(args: &[Value]) -> Result<Value> {
         return Err(Box::new(Error::runtime_error("Index out of bounds".to_string(), None)));
     }
     
-    let mut current = &args[0];
+    let mut current = args[0].clone();
     let mut i = 0;
     
     loop {
@@ -620,10 +626,17 @@ fn fast_list_ref(args: &[Value]) -> Result<Value> {
             Value::Nil => return Err(Box::new(Error::runtime_error("Index out of bounds".to_string(), None))),
             Value::Pair(car, cdr) => {
                 if i == index {
-                    return Ok((**car).clone());
+                    return Ok((*car).clone());
+                }
+                i += 1;
+                current = (*cdr).clone();
+            }
+            Value::MutablePair(car, cdr) => {
+                if i == index {
+                    return Ok(car.read().unwrap().clone());
                 }
                 i += 1;
-                current = cdr;
+                current = cdr.read().unwrap().clone();
             }
             _ => return Err(Box::new(Error::type_mismatch_error("proper list", args[0].clone()))),
         }
@@ -0,0 +1,209 @@
+//! `macroexpand` and `macroexpand-1`: running the macro expander on a
+//! quoted datum.
+//!
+//! The datum is turned into an expression without going through the
+//! parser, so derived forms such as `when` stay macro uses instead of
+//! becoming special form nodes. The expansion is converted back into a
+//! datum, and identifiers renamed for hygiene appear under their new names.
+
+use super::value::{PrimitiveImpl, PrimitiveProcedure, ThreadSafeEnvironment, Value};
+use super::Evaluator;
+use crate::ast::{Expr, Formals, Literal};
+use crate::diagnostics::{Error as DiagnosticError, Result, Span, Spanned};
+use crate::effects::Effect;
+use crate::utils::{intern_symbol, symbol_name};
+use std::sync::Arc;
+
+/// Upper bound on the expansion passes `macroexpand` makes.
+///
+/// Each pass expands every macro use the expander can reach; further passes
+/// pick up uses inside lists produced by ellipsis templates.
+const MAX_EXPANSION_PASSES: usize = 100;
+
+/// Binds the macro expansion procedures.
+pub fn bind_macroexpand(env: &Arc<ThreadSafeEnvironment>) {
+    env.define("macroexpand".to_string(), Value::Primitive(Arc::new(PrimitiveProcedure {
+        name: "macroexpand".to_string(),
+        arity_min: 1,
+        arity_max: Some(1),
+        implementation: PrimitiveImpl::EvaluatorIntegrated(evaluator_macroexpand),
+        effects: vec![Effect::Pure],
+    })));
+
+    env.define("macroexpand-1".to_string(), Value::Primitive(Arc::new(PrimitiveProcedure {
+        name: "macroexpand-1".to_string(),
+        arity_min: 1,
+        arity_max: Some(1),
+        implementation: PrimitiveImpl::EvaluatorIntegrated(evaluator_macroexpand_1),
+        effects: vec![Effect::Pure],
+    })));
+}
+
+/// macroexpand procedure
+///
+/// Expands `form` until no macro uses remain, including those nested in
+/// its subforms. Forms without macro uses are returned unchanged.
+fn evaluator_macroexpand(evaluator: &mut Evaluator, args: &[Value]) -> Result<Value> {
+    if args.len() != 1 {
+        return Err(Box::new(DiagnosticError::runtime_error(
+            format!("macroexpand expects 1 argument, got {}", args.len()),
+            None,
+        )));
+    }
+
+    let mut datum = args[0].clone();
+    for _ in 0..MAX_EXPANSION_PASSES {
+        let form = datum_to_form(&datum, "macroexpand")?;
+        let expanded = form_to_datum(&evaluator.macro_expander_mut().expand(&form)?, "macroexpand")?;
+        if expanded == datum {
+            return Ok(datum);
+        }
+        datum = expanded;
+    }
+
+    Err(Box::new(DiagnosticError::runtime_error(
+        "macroexpand: expansion does not terminate".to_string(),
+        None,
+    )))
+}
+
+/// macroexpand-1 procedure
+///
+/// Expands the macro use at the head of `form` once. Forms that are not
+/// macro uses are returned unchanged.
+fn evaluator_macroexpand_1(evaluator: &mut Evaluator, args: &[Value]) -> Result<Value> {
+    if args.len() != 1 {
+        return Err(Box::new(DiagnosticError::runtime_error(
+            format!("macroexpand-1 expects 1 argument, got {}", args.len()),
+            None,
+        )));
+    }
+
+    let form = datum_to_form(&args[0], "macroexpand-1")?;
+    let expanded = evaluator.macro_expander_mut().expand_once(&form)?;
+    if expanded == form {
+        return Ok(args[0].clone());
+    }
+    form_to_datum(&expanded, "macroexpand-1")
+}
+
+/// Converts a datum into the expression the expander matches patterns against.
+fn datum_to_form(datum: &Value, procedure: &str) -> Result<Spanned<Expr>> {
+    let span = Span::new(0, 0);
+    let expr = match datum {
+        Value::Nil => Expr::Literal(Literal::Nil),
+        Value::Literal(lit) => Expr::Literal(lit.clone()),
+        Value::Keyword(k) => Expr::Keyword(k.clone()),
+        Value::Symbol(id) => Expr::Identifier(symbol_name(*id).ok_or_else(|| not_syntax(datum, procedure))?),
+        _ => match datum.as_list() {
+            Some(items) => {
+                let is_quote = items.len() == 2
+                    && items[0].as_symbol().and_then(symbol_name).as_deref() == Some("quote");
+                if is_quote {
+                    // Quoted data is never expanded
+                    Expr::Quote(Box::new(datum_to_form(&items[1], procedure)?))
+                } else {
+                    let mut forms = items
+                        .iter()
+                        .map(|item| datum_to_form(item, procedure))
+                        .collect::<Result<Vec<_>>>()?;
+                    let operator = forms.remove(0);
+                    Expr::Application { operator: Box::new(operator), operands: forms }
+                }
+            }
+            None => match datum {
+                Value::Pair(car, cdr) => Expr::Pair {
+                    car: Box::new(datum_to_form(car, procedure)?),
+                    cdr: Box::new(datum_to_form(cdr, procedure)?),
+                },
+                _ => return Err(not_syntax(datum, procedure)),
+            },
+        },
+    };
+    Ok(Spanned::new(expr, span))
+}
+
+/// Converts an expansion back into a datum.
+fn form_to_datum(form: &Spanned<Expr>, procedure: &str) -> Result<Value> {
+    let symbol = |name: &str| Value::Symbol(intern_symbol(name));
+    let forms = |exprs: &[Spanned<Expr>]| {
+        exprs.iter().map(|expr| form_to_datum(expr, procedure)).collect::<Result<Vec<_>>>()
+    };
+
+    Ok(match &form.inner {
+        Expr::Literal(Literal::Nil) => Value::Nil,
+        Expr::Literal(lit) => Value::Literal(lit.clone()),
+        Expr::Identifier(name) | Expr::Symbol(name) => symbol(name),
+        Expr::Keyword(k) => Value::Keyword(k.clone()),
+        Expr::List(elements) => Value::list(forms(elements)?),
+        Expr::Application { operator, operands } => {
+            let mut items = vec![form_to_datum(operator, procedure)?];
+            items.extend(forms(operands)?);
+            Value::list(items)
+        }
+        Expr::Pair { car, cdr } => Value::pair(form_to_datum(car, procedure)?, form_to_datum(cdr, procedure)?),
+        Expr::Quote(inner) => Value::list(vec![symbol("quote"), form_to_datum(inner, procedure)?]),
+        Expr::Quasiquote(inner) => Value::list(vec![symbol("quasiquote"), form_to_datum(inner, procedure)?]),
+        Expr::Unquote(inner) => Value::list(vec![symbol("unquote"), form_to_datum(inner, procedure)?]),
+        Expr::UnquoteSplicing(inner) => {
+            Value::list(vec![symbol("unquote-splicing"), form_to_datum(inner, procedure)?])
+        }
+        Expr::If { test, consequent, alternative } => {
+            let mut items = vec![symbol("if"), form_to_datum(test, procedure)?, form_to_datum(consequent, procedure)?];
+            if let Some(alternative) = alternative {
+                items.push(form_to_datum(alternative, procedure)?);
+            }
+            Value::list(items)
+        }
+        Expr::Lambda { formals, body, .. } => {
+            let formals = match formals {
+                Formals::Fixed(names) => Value::list(names.iter().map(|name| symbol(name)).collect()),
+                Formals::Variable(name) => symbol(name),
+                Formals::Mixed { fixed, rest } => fixed
+                    .iter()
+                    .rev()
+                    .fold(symbol(rest), |tail, name| Value::pair(symbol(name), tail)),
+                Formals::Keyword { .. } => return Err(not_syntax_form(form, procedure)),
+            };
+            let mut items = vec![symbol("lambda"), formals];
+            items.extend(forms(body)?);
+            Value::list(items)
+        }
+        Expr::Define { name, value, .. } => Value::list(vec![symbol("define"), symbol(name), form_to_datum(value, procedure)?]),
+        Expr::Set { name, value } => Value::list(vec![symbol("set!"), symbol(name), form_to_datum(value, procedure)?]),
+        Expr::Begin(body) => {
+            let mut items = vec![symbol("begin")];
+            items.extend(forms(body)?);
+            Value::list(items)
+        }
+        // Derived forms the parser read as nodes, such as those in a macro
+        // use's arguments, turn back into data; the next pass expands them
+        Expr::When { test, body } | Expr::Unless { test, body } => {
+            let keyword = if matches!(form.inner, Expr::When { .. }) { "when" } else { "unless" };
+            let mut items = vec![symbol(keyword), form_to_datum(test, procedure)?];
+            items.extend(forms(body)?);
+            Value::list(items)
+        }
+        Expr::And(operands) | Expr::Or(operands) => {
+            let keyword = if matches!(form.inner, Expr::And(_)) { "and" } else { "or" };
+            let mut items = vec![symbol(keyword)];
+            items.extend(forms(operands)?);
+            Value::list(items)
+        }
+        _ => return Err(not_syntax_form(form, procedure)),
+    })
+}
+
+fn not_syntax(datum: &Value, procedure: &str) -> Box<DiagnosticError> {
+    Box::new(DiagnosticError::runtime_error(
+        format!("{procedure}: {datum} is not a syntactic datum"),
+        None,
+    ))
+}
+
+fn not_syntax_form(form: &Spanned<Expr>, procedure: &str) -> Box<DiagnosticError> {
+    Box::new(DiagnosticError::runtime_error(
+        format!("{procedure}: expansion contains a form with no datum representation: {}", form.inner),
+        None,
+    ))
+}
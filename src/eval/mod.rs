@@ -9,6 +9,9 @@ pub mod optimized_value;
 pub mod environment;
 pub mod cached_environment;
 pub mod evaluator;
+pub mod dynamic_wind;
+pub mod procedural_macro;
+pub mod macroexpand;
 pub mod parameter;
 pub mod fast_path;
 pub mod optimized_environment;
@@ -22,7 +25,7 @@ pub mod continuation_domain;
 pub mod monadic_architecture;
 pub mod effect_integration;
 pub mod evaluator_integration;
-pub mod testing_architecture;
+// pub mod testing_architecture;
 
 #[cfg(test)]
 mod tests;
@@ -34,14 +37,16 @@ mod environment_integration_tests;
 pub use value::{
     Value, Environment, ThreadSafeEnvironment, Generation, StackTrace, StackFrame, FrameType,
     Procedure, PrimitiveProcedure, PrimitiveImpl, Continuation, Frame,
-    SyntaxTransformer, Port, PortImpl, PortMode, PortDirection, StandardPort, PortFileHandle, Promise,
+    SyntaxTransformer, Port, PortImpl, PortMode, PortDirection, PortBuffering, StandardPort, PortFileHandle, Promise,
     TypeValue, ForeignObject, Parameter,
 };
 pub use optimized_value::{OptimizedValue, OptimizedEnvironment, OptimizedFrame};
 pub use fast_path::{FastPathOp, execute_fast_path, execute_fast_path_optimized, is_fast_path_operation, FastPathStats, get_fast_path_stats};
 pub use environment::{EnvironmentBuilder, global_environment};
 pub use cached_environment::{CachedEnvironment, CacheStatistics};
-pub use evaluator::{Evaluator, EvalStep};
+pub use evaluator::{Evaluator, EvalStep, ExceptionHandlerFrame};
+pub use dynamic_wind::{DynamicPoint, WindStep};
+pub use procedural_macro::{ProceduralTransformer, TransformerKind, ExpansionContext};
 pub use parameter::{ParameterBinding, ParameterFrame};
 pub use optimized_environment::{OptimizedEnvironment as OptEnv, OptimizedEnvironmentBuilder, EnvironmentStats};
 pub use gc_coordinator::{
@@ -0,0 +1,760 @@
+//! Procedural macro transformers: `er-macro-transformer` and
+//! `sc-macro-transformer`.
+//!
+//! A procedural transformer wraps a Scheme procedure that receives the macro
+//! use as a datum and returns the expansion as a datum. Expansion happens
+//! when the evaluator meets an application whose operator names a procedural
+//! macro, so the procedure runs with full access to the runtime.
+//!
+//! Hygiene follows the two classic models:
+//! - explicit renaming: the procedure receives `rename` and `compare`.
+//!   Renamed identifiers that end up in binding positions of the expansion
+//!   keep their fresh alias, so they cannot capture user identifiers; free
+//!   renamed identifiers refer to the environment of the transformer.
+//! - syntactic closures: the procedure receives the usage environment and
+//!   wraps user code with `make-syntactic-closure`. Every other identifier
+//!   is introduced by the macro: those bound by the expansion are given
+//!   fresh names, and free ones refer to the environment of the transformer.
+//!   Identifiers inside a syntactic closure refer to the environment it was
+//!   made with.
+//!
+//! An identifier whose binding is not the one visible at the use site, such
+//! as a local binding of the transformer or a global the use site shadows, is
+//! replaced by an alias bound to the same value where the expansion is
+//! evaluated. Special form keywords cannot be aliased and keep their names.
+//!
+//! Subforms of the macro use that have no datum representation (for example
+//! an already parsed `lambda`) are passed to the procedure as placeholder
+//! symbols and spliced back into the expansion afterwards.
+
+use super::value::{Environment, PrimitiveImpl, PrimitiveProcedure, ThreadSafeEnvironment, Value};
+use super::Evaluator;
+use crate::ast::{Expr, Literal};
+use crate::diagnostics::{Error as DiagnosticError, Result, Span, Spanned};
+use crate::effects::Effect;
+use crate::parser::Parser;
+use crate::utils::{intern_symbol, symbol_name};
+use std::collections::{HashMap, HashSet};
+use std::rc::Rc;
+use std::sync::Arc;
+
+/// Marker heading the lists built by `make-syntactic-closure`.
+const SYNTACTIC_CLOSURE_MARKER: &str = "%syntactic-closure";
+
+/// Prefix of the symbols standing in for non-datum subforms.
+const PLACEHOLDER_PREFIX: &str = "%expr";
+
+/// The hygiene model of a procedural transformer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TransformerKind {
+    /// `(er-macro-transformer (lambda (form rename compare) ...))`
+    ExplicitRenaming,
+    /// `(sc-macro-transformer (lambda (form env) ...))`
+    SyntacticClosure,
+}
+
+/// A macro transformer implemented by a Scheme procedure.
+#[derive(Debug, Clone)]
+pub struct ProceduralTransformer {
+    /// Hygiene model used when closing the expansion
+    pub kind: TransformerKind,
+    /// The expansion procedure
+    pub procedure: Value,
+}
+
+impl ProceduralTransformer {
+    /// Extracts a procedural transformer from a value produced by
+    /// `er-macro-transformer` or `sc-macro-transformer`.
+    pub fn from_value(value: &Value) -> Option<Self> {
+        match value {
+            Value::Opaque(inner) => inner.downcast_ref::<ProceduralTransformer>().cloned(),
+            _ => None,
+        }
+    }
+}
+
+/// The syntactic environment of a macro use, passed to SC transformers.
+#[derive(Debug, Clone)]
+struct SyntacticEnvironment(Arc<ThreadSafeEnvironment>);
+
+/// State of a single procedural macro expansion.
+#[derive(Debug, Default)]
+pub struct ExpansionContext {
+    /// Aliases created by `rename`, mapped to the identifier they rename
+    aliases: HashMap<String, String>,
+    /// Subforms of the macro use that were replaced by placeholder symbols
+    placeholders: HashMap<String, Spanned<Expr>>,
+    /// Environment of the transformer procedure
+    macro_environment: Option<Arc<ThreadSafeEnvironment>>,
+    /// Environment the expansion is evaluated in
+    usage_environment: Option<Rc<Environment>>,
+    /// Global environment, which holds the current values of the global
+    /// bindings the other environments copied
+    global_environment: Option<Rc<Environment>>,
+}
+
+impl ExpansionContext {
+    /// Creates an empty expansion context.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates an expansion context for a transformer defined in
+    /// `macro_environment` and used in `usage_environment`.
+    pub fn with_environments(
+        macro_environment: Option<Arc<ThreadSafeEnvironment>>,
+        usage_environment: Rc<Environment>,
+        global_environment: Rc<Environment>,
+    ) -> Self {
+        Self {
+            macro_environment,
+            usage_environment: Some(usage_environment),
+            global_environment: Some(global_environment),
+            ..Self::default()
+        }
+    }
+
+    /// Creates a fresh alias for `name`.
+    pub fn rename(&mut self, name: &str) -> String {
+        let base = self.resolve(name).to_string();
+        let alias = format!("{base}%{}", crate::macro_system::next_hygiene_id());
+        self.aliases.insert(alias.clone(), base);
+        alias
+    }
+
+    /// Returns the identifier an alias stands for, or `name` itself.
+    pub fn resolve<'a>(&'a self, name: &'a str) -> &'a str {
+        self.aliases.get(name).map_or(name, String::as_str)
+    }
+
+    /// Whether two identifiers denote the same binding.
+    pub fn compare(&self, a: &str, b: &str) -> bool {
+        self.resolve(a) == self.resolve(b)
+    }
+
+    /// Converts a macro use into the datum handed to the transformer.
+    pub fn form_to_datum(&mut self, expr: &Spanned<Expr>) -> Value {
+        match &expr.inner {
+            Expr::Literal(lit) => Value::Literal(lit.clone()),
+            Expr::Identifier(name) | Expr::Symbol(name) => Value::Symbol(intern_symbol(name)),
+            Expr::Keyword(k) => Value::Keyword(k.clone()),
+            Expr::List(elements) => {
+                Value::list(elements.iter().map(|e| self.form_to_datum(e)).collect())
+            }
+            Expr::Application { operator, operands } => {
+                let mut values = vec![self.form_to_datum(operator)];
+                values.extend(operands.iter().map(|e| self.form_to_datum(e)));
+                Value::list(values)
+            }
+            Expr::Pair { car, cdr } => Value::pair(self.form_to_datum(car), self.form_to_datum(cdr)),
+            Expr::Vector(elements) => {
+                Value::vector(elements.iter().map(|e| self.form_to_datum(e)).collect())
+            }
+            Expr::Quote(inner) => Value::list(vec![
+                Value::Symbol(intern_symbol("quote")),
+                self.form_to_datum(inner),
+            ]),
+            _ => {
+                let name = format!("{PLACEHOLDER_PREFIX}{}", crate::macro_system::next_hygiene_id());
+                self.placeholders.insert(name.clone(), expr.clone());
+                Value::Symbol(intern_symbol(&name))
+            }
+        }
+    }
+
+    /// Applies the hygiene model to an expansion and converts it back into
+    /// an expression.
+    pub fn finish(&self, expansion: &Value, kind: TransformerKind, span: Span) -> Result<Spanned<Expr>> {
+        let mut renaming = Renaming { kind, bound: HashSet::new(), fresh: HashMap::new() };
+        self.collect_bound(expansion, kind, &mut renaming.bound);
+        let closed = self.close(expansion, &mut renaming, false);
+
+        let mut expr = Parser::parse_syntax(&datum_to_syntax(&closed, span)?)?;
+        self.substitute_placeholders(&mut expr);
+        Ok(expr)
+    }
+
+    /// Returns the name under which the binding of `name` in `environment`
+    /// is visible where the expansion is evaluated.
+    ///
+    /// A binding other than the one the use site sees is given an alias in
+    /// the usage environment. Global bindings are taken from the global
+    /// environment, so that later redefinitions are seen.
+    fn refer(&self, name: &str, environment: Option<&ThreadSafeEnvironment>) -> String {
+        let (Some(environment), Some(usage)) = (environment, &self.usage_environment) else {
+            return name.to_string();
+        };
+        let binding = local_binding(environment, name)
+            .or_else(|| self.global_environment.as_ref().and_then(|global| global.lookup(name)));
+        let Some(value) = binding else {
+            return name.to_string();
+        };
+        let is_keyword = symbol_text(&value).is_some_and(|keyword| keyword == name);
+        if is_keyword || usage.lookup(name).as_ref() == Some(&value) {
+            return name.to_string();
+        }
+
+        let alias = format!("{name}%{}", crate::macro_system::next_hygiene_id());
+        usage.define(alias.clone(), value);
+        alias
+    }
+
+    /// Whether `name` was introduced by the macro rather than the user.
+    fn is_introduced(&self, name: &str, kind: TransformerKind) -> bool {
+        match kind {
+            TransformerKind::ExplicitRenaming => self.aliases.contains_key(name),
+            TransformerKind::SyntacticClosure => !self.placeholders.contains_key(name),
+        }
+    }
+
+    /// Collects the introduced identifiers that the expansion binds.
+    fn collect_bound(&self, datum: &Value, kind: TransformerKind, bound: &mut HashSet<String>) {
+        let mut binders = HashSet::new();
+        self.collect_binders(datum, &mut binders);
+        bound.extend(binders.into_iter().filter(|name| self.is_introduced(name, kind)));
+    }
+
+    /// Collects the identifiers bound within `datum`, outside of quoted data
+    /// and syntactic closures.
+    fn collect_binders(&self, datum: &Value, bound: &mut HashSet<String>) {
+        let Some(items) = datum.as_list() else {
+            return;
+        };
+        let head = items.first().and_then(symbol_text);
+        let head = head.as_deref().map(|h| self.resolve(h));
+        if matches!(head, Some("quote") | Some(SYNTACTIC_CLOSURE_MARKER)) {
+            return;
+        }
+
+        let mut binders = Vec::new();
+        match (head, items.get(1)) {
+            (Some("lambda"), Some(formals)) => binders.extend(datum_symbols(formals)),
+            (Some("define"), Some(target)) => binders.extend(datum_symbols(target)),
+            (Some("let" | "let*" | "letrec" | "letrec*" | "do"), Some(second)) => {
+                let bindings = match symbol_text(second) {
+                    Some(name) => {
+                        binders.push(name);
+                        items.get(2)
+                    }
+                    None => Some(second),
+                };
+                for binding in bindings.and_then(Value::as_list).unwrap_or_default() {
+                    if let Some(name) = binding.as_list().and_then(|b| b.first().and_then(symbol_text)) {
+                        binders.push(name);
+                    }
+                }
+            }
+            _ => {}
+        }
+        bound.extend(binders);
+
+        for item in &items {
+            self.collect_binders(item, bound);
+        }
+    }
+
+    /// Rewrites identifiers of the expansion according to the hygiene model.
+    fn close(&self, datum: &Value, renaming: &mut Renaming, quoted: bool) -> Value {
+        if let Some(name) = symbol_text(datum) {
+            let renamed = if quoted {
+                self.resolve(&name).to_string()
+            } else if renaming.bound.contains(&name) {
+                match renaming.kind {
+                    TransformerKind::ExplicitRenaming => name,
+                    TransformerKind::SyntacticClosure => renaming
+                        .fresh
+                        .entry(name.clone())
+                        .or_insert_with(|| format!("{name}%{}", crate::macro_system::next_hygiene_id()))
+                        .clone(),
+                }
+            } else if self.is_introduced(&name, renaming.kind) {
+                self.refer(self.resolve(&name), self.macro_environment.as_deref())
+            } else {
+                name
+            };
+            return Value::Symbol(intern_symbol(&renamed));
+        }
+
+        if let Some(items) = datum.as_list() {
+            let head = items.first().and_then(symbol_text);
+            match head.as_deref().map(|h| self.resolve(h)) {
+                Some(SYNTACTIC_CLOSURE_MARKER) if items.len() == 4 => {
+                    return self.close_syntactic_closure(&items, renaming);
+                }
+                Some("quote") => {
+                    return Value::list(items.iter().map(|item| self.close(item, renaming, true)).collect());
+                }
+                _ => {}
+            }
+            return Value::list(items.iter().map(|item| self.close(item, renaming, quoted)).collect());
+        }
+
+        match datum {
+            Value::Pair(car, cdr) => Value::pair(self.close(car, renaming, quoted), self.close(cdr, renaming, quoted)),
+            Value::MutablePair(car, cdr) => {
+                let car = car.read().map(|v| v.clone()).unwrap_or(Value::Nil);
+                let cdr = cdr.read().map(|v| v.clone()).unwrap_or(Value::Nil);
+                Value::pair(self.close(&car, renaming, quoted), self.close(&cdr, renaming, quoted))
+            }
+            other => other.clone(),
+        }
+    }
+
+    /// Closes a `(%syntactic-closure env free-names form)` list: identifiers
+    /// of the form that it does not bind refer to `env`, except the free
+    /// names, which are closed like the rest of the expansion.
+    fn close_syntactic_closure(&self, items: &[Value], renaming: &mut Renaming) -> Value {
+        let environment = match &items[1] {
+            Value::Opaque(inner) => inner.downcast_ref::<SyntacticEnvironment>().map(|env| env.0.clone()),
+            _ => None,
+        };
+        let free: HashSet<String> = items[2].as_list().unwrap_or_default().iter().filter_map(symbol_text).collect();
+        let mut local = HashSet::new();
+        self.collect_binders(&items[3], &mut local);
+
+        let scope = Closure { environment: environment.as_deref(), local: &local, free: &free };
+        self.close_in(&items[3], &scope, renaming, false)
+    }
+
+    /// Rewrites the identifiers of a closed form.
+    fn close_in(&self, datum: &Value, scope: &Closure<'_>, renaming: &mut Renaming, quoted: bool) -> Value {
+        if let Some(name) = symbol_text(datum) {
+            if quoted || scope.local.contains(&name) || self.placeholders.contains_key(&name) {
+                return datum.clone();
+            }
+            if scope.free.contains(&name) {
+                return self.close(datum, renaming, false);
+            }
+            return Value::Symbol(intern_symbol(self.refer(&name, scope.environment)));
+        }
+
+        if let Some(items) = datum.as_list() {
+            let head = items.first().and_then(symbol_text);
+            match head.as_deref() {
+                Some(SYNTACTIC_CLOSURE_MARKER) if items.len() == 4 => {
+                    return self.close_syntactic_closure(&items, renaming);
+                }
+                Some("quote") => {
+                    return Value::list(items.iter().map(|item| self.close_in(item, scope, renaming, true)).collect());
+                }
+                _ => {}
+            }
+            return Value::list(items.iter().map(|item| self.close_in(item, scope, renaming, quoted)).collect());
+        }
+
+        match datum {
+            Value::Pair(car, cdr) => Value::pair(
+                self.close_in(car, scope, renaming, quoted),
+                self.close_in(cdr, scope, renaming, quoted),
+            ),
+            Value::MutablePair(car, cdr) => {
+                let car = car.read().map(|v| v.clone()).unwrap_or(Value::Nil);
+                let cdr = cdr.read().map(|v| v.clone()).unwrap_or(Value::Nil);
+                Value::pair(
+                    self.close_in(&car, scope, renaming, quoted),
+                    self.close_in(&cdr, scope, renaming, quoted),
+                )
+            }
+            other => other.clone(),
+        }
+    }
+
+    /// Splices the original subforms back in place of their placeholders.
+    fn substitute_placeholders(&self, expr: &mut Spanned<Expr>) {
+        if self.placeholders.is_empty() {
+            return;
+        }
+        if let Expr::Identifier(name) = &expr.inner {
+            if let Some(original) = self.placeholders.get(name) {
+                *expr = original.clone();
+            }
+            return;
+        }
+
+        let visit = |e: &mut Spanned<Expr>| self.substitute_placeholders(e);
+        match &mut expr.inner {
+            Expr::List(items) | Expr::Begin(items) | Expr::And(items) | Expr::Or(items) => {
+                items.iter_mut().for_each(visit)
+            }
+            Expr::Application { operator, operands } => {
+                visit(operator);
+                operands.iter_mut().for_each(visit);
+            }
+            Expr::Pair { car, cdr } => {
+                visit(car);
+                visit(cdr);
+            }
+            Expr::Lambda { body, .. } => body.iter_mut().for_each(visit),
+            Expr::If { test, consequent, alternative } => {
+                visit(test);
+                visit(consequent);
+                if let Some(alternative) = alternative {
+                    visit(alternative);
+                }
+            }
+            Expr::Define { value, .. } | Expr::Set { value, .. } => visit(value),
+            Expr::Let { bindings, body } | Expr::LetStar { bindings, body } | Expr::LetRec { bindings, body } => {
+                bindings.iter_mut().for_each(|b| visit(&mut b.value));
+                body.iter_mut().for_each(visit);
+            }
+            Expr::Cond(clauses) => {
+                for clause in clauses {
+                    visit(&mut clause.test);
+                    clause.body.iter_mut().for_each(visit);
+                }
+            }
+            Expr::Case { expr, clauses } => {
+                visit(expr);
+                clauses.iter_mut().for_each(|c| c.body.iter_mut().for_each(visit));
+            }
+            Expr::When { test, body } | Expr::Unless { test, body } => {
+                visit(test);
+                body.iter_mut().for_each(visit);
+            }
+            Expr::Do { bindings, test, result, body } => {
+                for binding in bindings {
+                    visit(&mut binding.init);
+                    if let Some(step) = &mut binding.step {
+                        visit(step);
+                    }
+                }
+                visit(test);
+                result.iter_mut().for_each(visit);
+                body.iter_mut().for_each(visit);
+            }
+            Expr::Guard { clauses, body, .. } => {
+                for clause in clauses {
+                    visit(&mut clause.test);
+                    clause.body.iter_mut().for_each(visit);
+                    if let Some(arrow) = &mut clause.arrow {
+                        visit(arrow);
+                    }
+                }
+                body.iter_mut().for_each(visit);
+            }
+            Expr::Parameterize { bindings, body } => {
+                for binding in bindings {
+                    visit(&mut binding.parameter);
+                    visit(&mut binding.value);
+                }
+                body.iter_mut().for_each(visit);
+            }
+            Expr::CaseLambda { clauses, .. } => {
+                clauses.iter_mut().for_each(|c| c.body.iter_mut().for_each(visit))
+            }
+            Expr::CallCC(inner) | Expr::Quasiquote(inner) | Expr::Unquote(inner) | Expr::UnquoteSplicing(inner) => {
+                visit(inner)
+            }
+            Expr::TypeAnnotation { expr, .. } => visit(expr),
+            _ => {}
+        }
+    }
+}
+
+/// How the identifiers introduced by an expansion are renamed.
+struct Renaming {
+    kind: TransformerKind,
+    /// Introduced identifiers that the expansion binds
+    bound: HashSet<String>,
+    /// Fresh names given to them by syntactic closure transformers
+    fresh: HashMap<String, String>,
+}
+
+/// The scope of a syntactic closure.
+struct Closure<'a> {
+    /// Environment the closure was made in
+    environment: Option<&'a ThreadSafeEnvironment>,
+    /// Identifiers bound within the closed form
+    local: &'a HashSet<String>,
+    /// Identifiers left free by the closure
+    free: &'a HashSet<String>,
+}
+
+/// Returns the name of a symbol datum.
+fn symbol_text(value: &Value) -> Option<String> {
+    match value {
+        Value::Symbol(id) => symbol_name(*id),
+        _ => None,
+    }
+}
+
+/// Returns every symbol in a formals list or definition target.
+fn datum_symbols(value: &Value) -> Vec<String> {
+    let mut symbols = Vec::new();
+    let mut current = value.clone();
+    loop {
+        match current {
+            Value::Symbol(_) => {
+                symbols.extend(symbol_text(&current));
+                break;
+            }
+            Value::Pair(car, cdr) => {
+                symbols.extend(symbol_text(&car));
+                current = cdr.as_ref().clone();
+            }
+            _ => break,
+        }
+    }
+    symbols
+}
+
+/// Converts a closed expansion into the expression it spells, rejecting
+/// values with no syntax.
+fn datum_to_syntax(value: &Value, span: Span) -> Result<Spanned<Expr>> {
+    let expr = match value {
+        Value::Literal(lit) => Expr::Literal(lit.clone()),
+        Value::Symbol(_) => {
+            let name = symbol_text(value).ok_or_else(|| {
+                DiagnosticError::runtime_error("macro expansion contains an uninterned symbol".to_string(), None)
+            })?;
+            Expr::Identifier(name)
+        }
+        Value::Keyword(k) => Expr::Keyword(k.clone()),
+        Value::Nil => Expr::Literal(Literal::Nil),
+        Value::MutableString(chars) => {
+            Expr::Literal(Literal::String(chars.read().map(|c| c.iter().collect()).unwrap_or_default()))
+        }
+        Value::Pair(..) | Value::MutablePair(..) => {
+            let mut items = Vec::new();
+            let mut current = value.clone();
+            let tail = loop {
+                let (car, cdr) = match &current {
+                    Value::Pair(car, cdr) => (car.as_ref().clone(), cdr.as_ref().clone()),
+                    Value::MutablePair(car, cdr) => (
+                        car.read().map(|v| v.clone()).unwrap_or(Value::Nil),
+                        cdr.read().map(|v| v.clone()).unwrap_or(Value::Nil),
+                    ),
+                    Value::Nil => break None,
+                    tail => break Some(datum_to_syntax(tail, span)?),
+                };
+                items.push(datum_to_syntax(&car, span)?);
+                current = cdr;
+            };
+            match tail {
+                None => Expr::List(items),
+                Some(tail) => {
+                    return Ok(items.into_iter().rev().fold(tail, |cdr, car| {
+                        Spanned::new(Expr::Pair { car: Box::new(car), cdr: Box::new(cdr) }, span)
+                    }));
+                }
+            }
+        }
+        Value::Vector(items) => {
+            let items = items.read().map(|v| v.clone()).unwrap_or_default();
+            Expr::Vector(items.iter().map(|item| datum_to_syntax(item, span)).collect::<Result<_>>()?)
+        }
+        other => {
+            return Err(Box::new(DiagnosticError::runtime_error(
+                format!("macro expansion contains a non-syntactic value: {other}"),
+                None,
+            )));
+        }
+    };
+    Ok(Spanned::new(expr, span))
+}
+
+/// Looks `name` up in the local frames of `environment`, leaving out its
+/// copy of the global frame.
+fn local_binding(environment: &ThreadSafeEnvironment, name: &str) -> Option<Value> {
+    let mut frame = environment;
+    while let Some(parent) = frame.parent() {
+        if let Some(value) = frame.lookup_local(name) {
+            return Some(value);
+        }
+        frame = parent;
+    }
+    None
+}
+
+/// Expands a use of a procedural macro into an expression.
+pub fn expand(
+    evaluator: &mut Evaluator,
+    transformer: &ProceduralTransformer,
+    form: &Spanned<Expr>,
+    env: &Rc<Environment>,
+) -> Result<Spanned<Expr>> {
+    let macro_environment = match &transformer.procedure {
+        Value::Procedure(procedure) => Some(procedure.environment.clone()),
+        _ => None,
+    };
+    let mut context =
+        ExpansionContext::with_environments(macro_environment, env.clone(), evaluator.global_env().clone());
+    let datum = context.form_to_datum(form);
+
+    let args = match transformer.kind {
+        TransformerKind::ExplicitRenaming => vec![
+            datum,
+            integrated_primitive("rename", 1, evaluator_rename),
+            integrated_primitive("compare", 2, evaluator_compare),
+        ],
+        TransformerKind::SyntacticClosure => {
+            vec![datum, Value::opaque(SyntacticEnvironment(env.to_thread_safe()))]
+        }
+    };
+
+    evaluator.push_macro_expansion(context);
+    let expansion = evaluator.call_procedure(transformer.procedure.clone(), args, Some(form.span));
+    let context = evaluator.pop_macro_expansion().unwrap_or_default();
+    context.finish(&expansion?, transformer.kind, form.span)
+}
+
+/// Creates an evaluator-integrated primitive for an expansion.
+fn integrated_primitive(
+    name: &str,
+    arity: usize,
+    implementation: fn(&mut Evaluator, &[Value]) -> Result<Value>,
+) -> Value {
+    Value::Primitive(Arc::new(PrimitiveProcedure {
+        name: name.to_string(),
+        arity_min: arity,
+        arity_max: Some(arity),
+        implementation: PrimitiveImpl::EvaluatorIntegrated(implementation),
+        effects: vec![Effect::Pure],
+    }))
+}
+
+/// rename procedure passed to explicit renaming transformers
+fn evaluator_rename(evaluator: &mut Evaluator, args: &[Value]) -> Result<Value> {
+    let name = identifier_argument(&args[0], "rename")?;
+    let context = evaluator.current_macro_expansion().ok_or_else(|| {
+        DiagnosticError::runtime_error("rename: called outside of a macro expansion".to_string(), None)
+    })?;
+    Ok(Value::Symbol(intern_symbol(context.rename(&name))))
+}
+
+/// compare procedure passed to explicit renaming transformers
+fn evaluator_compare(evaluator: &mut Evaluator, args: &[Value]) -> Result<Value> {
+    let (Some(a), Some(b)) = (symbol_text(&args[0]), symbol_text(&args[1])) else {
+        return Ok(Value::boolean(false));
+    };
+    let same = match evaluator.current_macro_expansion() {
+        Some(context) => context.compare(&a, &b),
+        None => a == b,
+    };
+    Ok(Value::boolean(same))
+}
+
+/// Extracts the name of an identifier argument.
+fn identifier_argument(value: &Value, procedure: &str) -> Result<String> {
+    symbol_text(value).ok_or_else(|| {
+        Box::new(DiagnosticError::runtime_error(
+            format!("{procedure}: expected an identifier"),
+            None,
+        ))
+    })
+}
+
+/// Binds the procedural macro constructors.
+pub fn bind_procedural_macros(env: &Arc<ThreadSafeEnvironment>) {
+    env.define("er-macro-transformer".to_string(), Value::Primitive(Arc::new(PrimitiveProcedure {
+        name: "er-macro-transformer".to_string(),
+        arity_min: 1,
+        arity_max: Some(1),
+        implementation: PrimitiveImpl::RustFn(primitive_er_macro_transformer),
+        effects: vec![Effect::Pure],
+    })));
+
+    env.define("sc-macro-transformer".to_string(), Value::Primitive(Arc::new(PrimitiveProcedure {
+        name: "sc-macro-transformer".to_string(),
+        arity_min: 1,
+        arity_max: Some(1),
+        implementation: PrimitiveImpl::RustFn(primitive_sc_macro_transformer),
+        effects: vec![Effect::Pure],
+    })));
+
+    env.define("make-syntactic-closure".to_string(), Value::Primitive(Arc::new(PrimitiveProcedure {
+        name: "make-syntactic-closure".to_string(),
+        arity_min: 3,
+        arity_max: Some(3),
+        implementation: PrimitiveImpl::RustFn(primitive_make_syntactic_closure),
+        effects: vec![Effect::Pure],
+    })));
+}
+
+/// er-macro-transformer procedure
+fn primitive_er_macro_transformer(args: &[Value]) -> Result<Value> {
+    make_transformer(&args[0], TransformerKind::ExplicitRenaming, "er-macro-transformer")
+}
+
+/// sc-macro-transformer procedure
+fn primitive_sc_macro_transformer(args: &[Value]) -> Result<Value> {
+    make_transformer(&args[0], TransformerKind::SyntacticClosure, "sc-macro-transformer")
+}
+
+fn make_transformer(procedure: &Value, kind: TransformerKind, name: &str) -> Result<Value> {
+    if !procedure.is_procedure() {
+        return Err(Box::new(DiagnosticError::runtime_error(
+            format!("{name}: expected a procedure"),
+            None,
+        )));
+    }
+    Ok(Value::opaque(ProceduralTransformer { kind, procedure: procedure.clone() }))
+}
+
+/// make-syntactic-closure procedure
+///
+/// Closes `form` in the given environment: its identifiers, other than those
+/// in the free-names list, refer to their bindings there and cannot be
+/// captured by the expansion.
+fn primitive_make_syntactic_closure(args: &[Value]) -> Result<Value> {
+    let is_environment = matches!(&args[0], Value::Opaque(inner) if inner.is::<SyntacticEnvironment>());
+    if !is_environment {
+        return Err(Box::new(DiagnosticError::runtime_error(
+            "make-syntactic-closure: expected a syntactic environment".to_string(),
+            None,
+        )));
+    }
+    Ok(Value::list(vec![
+        Value::Symbol(intern_symbol(SYNTACTIC_CLOSURE_MARKER)),
+        args[0].clone(),
+        args[1].clone(),
+        args[2].clone(),
+    ]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sym(name: &str) -> Value {
+        Value::Symbol(intern_symbol(name))
+    }
+
+    #[test]
+    fn test_rename_and_compare() {
+        let mut context = ExpansionContext::new();
+        let alias = context.rename("tmp");
+        assert_ne!(alias, "tmp");
+        assert_eq!(context.resolve(&alias), "tmp");
+        assert!(context.compare(&alias, "tmp"));
+        assert!(!context.compare(&alias, "other"));
+    }
+
+    #[test]
+    fn test_bound_aliases_stay_fresh() {
+        let mut context = ExpansionContext::new();
+        let let_alias = context.rename("let");
+        let tmp_alias = context.rename("tmp");
+
+        // (let ((tmp x)) tmp) where let and tmp are renamed
+        let expansion = Value::list(vec![
+            sym(&let_alias),
+            Value::list(vec![Value::list(vec![sym(&tmp_alias), sym("x")])]),
+            sym(&tmp_alias),
+        ]);
+        let expr = context
+            .finish(&expansion, TransformerKind::ExplicitRenaming, Span::new(0, 0))
+            .unwrap();
+        let text = format!("{}", expr.inner);
+        assert!(text.contains(&tmp_alias));
+        assert!(!text.contains(&let_alias));
+    }
+
+    #[test]
+    fn test_non_syntactic_expansion_errors() {
+        let context = ExpansionContext::new();
+        assert!(context
+            .finish(&Value::Unspecified, TransformerKind::ExplicitRenaming, Span::new(0, 0))
+            .is_err());
+    }
+}
@@ -5,6 +5,7 @@
 use crate::ast::{CaseLambdaClause, Expr, Formals, Literal};
 use crate::diagnostics::{Span, Spanned};
 use crate::effects::Effect;
+use crate::stdlib::encoding::{DecodeMode, TextEncoding};
 use crate::utils::SymbolId;
 use std::collections::HashMap;
 use std::fmt;
@@ -38,6 +39,9 @@ pub enum Value {
     /// Unspecified value (result of side-effecting operations)
     Unspecified,
 
+    /// The end-of-file object returned by port readers at end of input
+    EofObject,
+
     // ============= COMPOUND VALUES =============
 
     /// Cons pair (a . b) - Thread-safe
@@ -55,6 +59,9 @@ pub enum Value {
     /// Mutable string (for string-set! and string-fill!) - Thread-safe
     MutableString(Arc<RwLock<Vec<char>>>),
 
+    /// Zero or several values delivered by `values` or a continuation - Thread-safe
+    Values(Arc<Vec<Value>>),
+
     // ============= ADVANCED CONTAINERS =============
 
     /// High-performance hash table (SRFI-125) - Thread-safe
@@ -234,6 +241,8 @@ pub struct Continuation {
     pub current_expr: Option<Spanned<Expr>>,
     /// Whether this continuation has been invoked (for one-shot semantics)
     pub invoked: Arc<std::sync::atomic::AtomicBool>,
+    /// The `dynamic-wind` point that was current at capture time
+    pub dynamic_point: Option<Arc<crate::eval::dynamic_wind::DynamicPoint>>,
 }
 
 /// A stack frame in a continuation - Thread-safe.
@@ -312,10 +321,16 @@ pub struct Port {
     pub direction: PortDirection,
     /// Buffer for efficient I/O
     pub buffer: Arc<RwLock<Vec<u8>>>,
+    /// When written output is delivered to the port's destination
+    pub buffering: Arc<RwLock<PortBuffering>>,
     /// Current position in the port (for seekable ports)
     pub position: Arc<RwLock<usize>>,
     /// Port metadata
     pub metadata: HashMap<String, Value>,
+    /// Whether `read` folds identifiers to lower case, set by `#!fold-case`
+    pub fold_case: Arc<RwLock<bool>>,
+    /// Encoding and decode mode of a textual file port
+    pub codec: (TextEncoding, DecodeMode),
 }
 
 /// Port implementation details.
@@ -379,6 +394,38 @@ pub enum PortMode {
     Binary,
 }
 
+/// Output buffering mode of a port.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PortBuffering {
+    /// Every write is delivered immediately
+    None,
+    /// Output is delivered whenever a newline is written
+    Line,
+    /// Output is delivered when the buffer fills or the port is flushed
+    Block,
+}
+
+impl PortBuffering {
+    /// Parses a buffering mode name: `none`, `line` or `block`.
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "none" => Some(PortBuffering::None),
+            "line" => Some(PortBuffering::Line),
+            "block" => Some(PortBuffering::Block),
+            _ => None,
+        }
+    }
+
+    /// Returns the name of this buffering mode.
+    pub fn name(self) -> &'static str {
+        match self {
+            PortBuffering::None => "none",
+            PortBuffering::Line => "line",
+            PortBuffering::Block => "block",
+        }
+    }
+}
+
 /// Port direction.
 #[derive(Debug, Clone, PartialEq)]
 pub enum PortDirection {
@@ -402,8 +449,11 @@ impl Port {
             mode: PortMode::Textual,
             direction: PortDirection::Input,
             buffer: Arc::new(RwLock::new(Vec::new())),
+            buffering: Arc::new(RwLock::new(PortBuffering::None)),
             position: Arc::new(RwLock::new(0)),
             metadata: HashMap::new(),
+            fold_case: Arc::new(RwLock::new(false)),
+            codec: (TextEncoding::Utf8, DecodeMode::Strict),
         }
     }
 
@@ -418,8 +468,11 @@ impl Port {
             mode: PortMode::Textual,
             direction: PortDirection::Output,
             buffer: Arc::new(RwLock::new(Vec::new())),
+            buffering: Arc::new(RwLock::new(PortBuffering::None)),
             position: Arc::new(RwLock::new(0)),
             metadata: HashMap::new(),
+            fold_case: Arc::new(RwLock::new(false)),
+            codec: (TextEncoding::Utf8, DecodeMode::Strict),
         }
     }
 
@@ -434,8 +487,11 @@ impl Port {
             mode: PortMode::Binary,
             direction: PortDirection::Input,
             buffer: Arc::new(RwLock::new(Vec::new())),
+            buffering: Arc::new(RwLock::new(PortBuffering::None)),
             position: Arc::new(RwLock::new(0)),
             metadata: HashMap::new(),
+            fold_case: Arc::new(RwLock::new(false)),
+            codec: (TextEncoding::Utf8, DecodeMode::Strict),
         }
     }
 
@@ -450,8 +506,11 @@ impl Port {
             mode: PortMode::Binary,
             direction: PortDirection::Output,
             buffer: Arc::new(RwLock::new(Vec::new())),
+            buffering: Arc::new(RwLock::new(PortBuffering::None)),
             position: Arc::new(RwLock::new(0)),
             metadata: HashMap::new(),
+            fold_case: Arc::new(RwLock::new(false)),
+            codec: (TextEncoding::Utf8, DecodeMode::Strict),
         }
     }
 
@@ -466,8 +525,11 @@ impl Port {
             mode: if binary { PortMode::Binary } else { PortMode::Textual },
             direction: PortDirection::Input,
             buffer: Arc::new(RwLock::new(Vec::new())),
+            buffering: Arc::new(RwLock::new(PortBuffering::Block)),
             position: Arc::new(RwLock::new(0)),
             metadata: HashMap::new(),
+            fold_case: Arc::new(RwLock::new(false)),
+            codec: (TextEncoding::Utf8, DecodeMode::Strict),
         }
     }
 
@@ -482,8 +544,11 @@ impl Port {
             mode: if binary { PortMode::Binary } else { PortMode::Textual },
             direction: PortDirection::Output,
             buffer: Arc::new(RwLock::new(Vec::new())),
+            buffering: Arc::new(RwLock::new(PortBuffering::Block)),
             position: Arc::new(RwLock::new(0)),
             metadata: HashMap::new(),
+            fold_case: Arc::new(RwLock::new(false)),
+            codec: (TextEncoding::Utf8, DecodeMode::Strict),
         }
     }
 
@@ -493,6 +558,11 @@ impl Port {
             StandardPort::Stdin => PortDirection::Input,
             StandardPort::Stdout | StandardPort::Stderr => PortDirection::Output,
         };
+        // Matches the process streams: stdout is line-buffered, stderr is not
+        let buffering = match port_type {
+            StandardPort::Stdout => PortBuffering::Line,
+            StandardPort::Stdin | StandardPort::Stderr => PortBuffering::None,
+        };
 
         Port {
             implementation: PortImpl::Standard(port_type),
@@ -500,8 +570,11 @@ impl Port {
             mode: PortMode::Textual,
             direction,
             buffer: Arc::new(RwLock::new(Vec::new())),
+            buffering: Arc::new(RwLock::new(buffering)),
             position: Arc::new(RwLock::new(0)),
             metadata: HashMap::new(),
+            fold_case: Arc::new(RwLock::new(false)),
+            codec: (TextEncoding::Utf8, DecodeMode::Strict),
         }
     }
 
@@ -510,9 +583,30 @@ impl Port {
         *self.is_open.read().unwrap()
     }
 
-    /// Closes the port.
+    /// Returns the output buffering mode.
+    pub fn buffering(&self) -> PortBuffering {
+        *self.buffering.read().unwrap()
+    }
+
+    /// Sets the output buffering mode.
+    pub fn set_buffering(&self, buffering: PortBuffering) {
+        *self.buffering.write().unwrap() = buffering;
+    }
+
+    /// Closes the port. Closing an already closed port has no effect.
     pub fn close(&self) {
-        *self.is_open.write().unwrap() = false;
+        if !std::mem::replace(&mut *self.is_open.write().unwrap(), false) {
+            return;
+        }
+        // Buffered output of a string port is delivered before closing
+        if let (PortImpl::String { content, .. }, true) = (&self.implementation, self.is_output()) {
+            let pending = std::mem::take(&mut *self.buffer.write().unwrap());
+            content.write().unwrap().push_str(&String::from_utf8_lossy(&pending));
+        }
+        // Dropping the file handle flushes buffered output
+        if let PortImpl::File { handle, .. } = &self.implementation {
+            handle.write().unwrap().take();
+        }
     }
 
     /// Checks if the port is textual.
@@ -707,11 +801,48 @@ impl Value {
     /// This is the proper formatting function for the `display` procedure:
     /// - Strings are displayed without quotes
     /// - Characters are displayed without the #\ prefix
+    /// - Lists and vectors display their elements the same way
     /// - Other values use their standard Display representation
     pub fn display_string(&self) -> String {
         match self {
             Value::Literal(Literal::String(s)) => s.clone(),
             Value::Literal(Literal::Character(c)) => c.to_string(),
+            Value::Symbol(id) => crate::utils::symbol_name(*id).unwrap_or_else(|| format!("{self}")),
+            Value::Pair(_, _) | Value::MutablePair(_, _) => {
+                let mut text = String::from("(");
+                let mut rest = self.clone();
+                let mut first = true;
+                loop {
+                    let (car, cdr) = match &rest {
+                        Value::Pair(car, cdr) => ((**car).clone(), (**cdr).clone()),
+                        Value::MutablePair(car, cdr) => match (car.read(), cdr.read()) {
+                            (Ok(car), Ok(cdr)) => (car.clone(), cdr.clone()),
+                            _ => return format!("{self}"),
+                        },
+                        Value::Nil => break,
+                        tail => {
+                            text.push_str(" . ");
+                            text.push_str(&tail.display_string());
+                            break;
+                        }
+                    };
+                    if !first {
+                        text.push(' ');
+                    }
+                    first = false;
+                    text.push_str(&car.display_string());
+                    rest = cdr;
+                }
+                text.push(')');
+                text
+            }
+            Value::Vector(vec) => match vec.read() {
+                Ok(elements) => {
+                    let elements: Vec<String> = elements.iter().map(Value::display_string).collect();
+                    format!("#({})", elements.join(" "))
+                }
+                Err(_) => format!("{self}"),
+            },
             _ => format!("{self}"),
         }
     }
@@ -872,6 +1003,115 @@ impl Value {
         }
     }
 
+    /// Identity as defined by `eq?`.
+    ///
+    /// Pairs, strings, vectors, records and other heap objects are identical
+    /// only to themselves, however alike their contents; numbers, characters,
+    /// symbols and other immediates compare by value.
+    pub fn is_eq(&self, other: &Value) -> bool {
+        match (self, other) {
+            (Value::Pair(a_car, a_cdr), Value::Pair(b_car, b_cdr)) => {
+                Arc::ptr_eq(a_car, b_car) && Arc::ptr_eq(a_cdr, b_cdr)
+            }
+            (Value::MutablePair(a, _), Value::MutablePair(b, _)) => Arc::ptr_eq(a, b),
+            (Value::MutableString(a), Value::MutableString(b)) => Arc::ptr_eq(a, b),
+            (Value::MutableString(_), _) | (_, Value::MutableString(_)) => false,
+            (Value::Record(a), Value::Record(b)) => Arc::ptr_eq(a, b),
+            (Value::CharSet(a), Value::CharSet(b)) => Arc::ptr_eq(a, b),
+            (Value::Promise(a), Value::Promise(b)) => Arc::ptr_eq(a, b),
+            // Everything else already compares heap objects by reference
+            _ => self == other,
+        }
+    }
+
+    /// Structural equality as defined by `equal?`.
+    ///
+    /// Compares with an explicit worklist instead of recursion, so deeply
+    /// nested data cannot overflow the Rust stack. Pairs of mutable objects
+    /// already under comparison are assumed equal when met again, which
+    /// makes cyclic structures of the same shape compare equal and terminate.
+    pub fn is_equal(&self, other: &Value) -> bool {
+        let mut worklist = vec![(self.clone(), other.clone())];
+        let mut visited = std::collections::HashSet::new();
+
+        while let Some((left, right)) = worklist.pop() {
+            match (&left, &right) {
+                (Value::Pair(a_car, a_cdr), Value::Pair(b_car, b_cdr)) => {
+                    // Immutable pairs cannot form cycles on their own, so
+                    // they need no entry in the visited set
+                    if !(Arc::ptr_eq(a_car, b_car) && Arc::ptr_eq(a_cdr, b_cdr)) {
+                        worklist.push(((**a_cdr).clone(), (**b_cdr).clone()));
+                        worklist.push(((**a_car).clone(), (**b_car).clone()));
+                    }
+                }
+                (Value::MutablePair(a_car, a_cdr), Value::MutablePair(b_car, b_cdr)) => {
+                    if Arc::ptr_eq(a_car, b_car)
+                        || !visited.insert((Arc::as_ptr(a_car) as usize, Arc::as_ptr(b_car) as usize))
+                    {
+                        continue;
+                    }
+                    let (Ok(a_car), Ok(a_cdr), Ok(b_car), Ok(b_cdr)) =
+                        (a_car.read(), a_cdr.read(), b_car.read(), b_cdr.read())
+                    else {
+                        return false;
+                    };
+                    worklist.push((a_cdr.clone(), b_cdr.clone()));
+                    worklist.push((a_car.clone(), b_car.clone()));
+                }
+                (Value::Vector(a), Value::Vector(b)) => {
+                    if Arc::ptr_eq(a, b)
+                        || !visited.insert((Arc::as_ptr(a) as usize, Arc::as_ptr(b) as usize))
+                    {
+                        continue;
+                    }
+                    let (Ok(a), Ok(b)) = (a.read(), b.read()) else {
+                        return false;
+                    };
+                    if a.len() != b.len() {
+                        return false;
+                    }
+                    worklist.extend(a.iter().cloned().zip(b.iter().cloned()).rev());
+                }
+                (Value::Record(a), Value::Record(b)) => {
+                    if a.type_id != b.type_id {
+                        return false;
+                    }
+                    if Arc::ptr_eq(&a.fields, &b.fields)
+                        || !visited.insert((Arc::as_ptr(&a.fields) as usize, Arc::as_ptr(&b.fields) as usize))
+                    {
+                        continue;
+                    }
+                    let (Ok(a), Ok(b)) = (a.fields.read(), b.fields.read()) else {
+                        return false;
+                    };
+                    if a.len() != b.len() {
+                        return false;
+                    }
+                    worklist.extend(a.iter().cloned().zip(b.iter().cloned()).rev());
+                }
+                // A mutable and an immutable pair with the same contents
+                (Value::Pair(..), Value::MutablePair(..)) | (Value::MutablePair(..), Value::Pair(..)) => {
+                    let parts = |pair: &Value| match pair {
+                        Value::Pair(car, cdr) => Some(((**car).clone(), (**cdr).clone())),
+                        Value::MutablePair(car, cdr) => Some((car.read().ok()?.clone(), cdr.read().ok()?.clone())),
+                        _ => None,
+                    };
+                    let (Some((a_car, a_cdr)), Some((b_car, b_cdr))) = (parts(&left), parts(&right)) else {
+                        return false;
+                    };
+                    worklist.push((a_cdr, b_cdr));
+                    worklist.push((a_car, b_car));
+                }
+                _ => {
+                    if left != right {
+                        return false;
+                    }
+                }
+            }
+        }
+        true
+    }
+
     /// Creates a new number value.
     pub fn number(n: f64) -> Self {
         Value::Literal(Literal::from_f64(n))
@@ -909,14 +1149,10 @@ impl Value {
         Value::Symbol(id)
     }
 
-    /// Creates a new symbol value from a string.
+    /// Creates a new symbol value from a string, interning it in the
+    /// global symbol table so it is `eq?` to the same symbol read from source.
     pub fn symbol_from_str(name: impl Into<String>) -> Self {
-        let name_str = name.into();
-        // Simple hash-based symbol ID generation (in a real implementation,
-        // this would use a proper symbol table)
-        let id = SymbolId::new(name_str.chars()
-            .fold(0, |acc, c| acc.wrapping_mul(31).wrapping_add(c as usize)));
-        Value::Symbol(id)
+        Value::Symbol(crate::utils::intern_symbol(name.into()))
     }
 
     /// Creates a new pair value.
@@ -936,6 +1172,33 @@ impl Value {
         })
     }
 
+    /// Creates a list of freshly allocated mutable pairs.
+    pub fn mutable_list(values: Vec<Value>) -> Self {
+        values.into_iter().rev().fold(Value::Nil, |acc, val| {
+            Value::mutable_pair(val, acc)
+        })
+    }
+
+    /// Creates the result of delivering `values` to a continuation.
+    ///
+    /// A single value is returned as itself; zero or several values are
+    /// wrapped in [`Value::Values`].
+    pub fn values(mut values: Vec<Value>) -> Self {
+        if values.len() == 1 {
+            values.pop().unwrap()
+        } else {
+            Value::Values(Arc::new(values))
+        }
+    }
+
+    /// Spreads a result into the values it delivers.
+    pub fn into_values(self) -> Vec<Value> {
+        match self {
+            Value::Values(values) => values.as_ref().clone(),
+            value => vec![value],
+        }
+    }
+
     /// Creates a new vector value.
     pub fn vector(values: Vec<Value>) -> Self {
         Value::Vector(Arc::new(RwLock::new(values)))
@@ -1475,8 +1738,20 @@ impl PartialEq for Value {
             (Value::Keyword(a), Value::Keyword(b)) => a == b,
             (Value::Nil, Value::Nil) => true,
             (Value::Unspecified, Value::Unspecified) => true,
+            (Value::EofObject, Value::EofObject) => true,
             (Value::Pair(a1, b1), Value::Pair(a2, b2)) => a1 == a2 && b1 == b2,
+            (Value::Values(a), Value::Values(b)) => a == b,
+            // Strings compare by contents whether or not they are mutable,
+            // like literal strings
+            (Value::MutableString(a), Value::MutableString(b)) => {
+                Arc::ptr_eq(a, b) || *a.read().unwrap() == *b.read().unwrap()
+            }
+            (Value::MutableString(chars), Value::Literal(Literal::String(s)))
+            | (Value::Literal(Literal::String(s)), Value::MutableString(chars)) => {
+                chars.read().unwrap().iter().copied().eq(s.chars())
+            }
             // For mutable objects, use reference equality
+            (Value::MutablePair(a, _), Value::MutablePair(b, _)) => Arc::ptr_eq(a, b),
             (Value::Vector(a), Value::Vector(b)) => Arc::ptr_eq(a, b),
             (Value::Hashtable(a), Value::Hashtable(b)) => Arc::ptr_eq(a, b),
             (Value::Procedure(a), Value::Procedure(b)) => Arc::ptr_eq(a, b),
@@ -1547,6 +1822,27 @@ impl std::hash::Hash for Value {
             }
             Value::Nil => 3u8.hash(state),
             Value::Unspecified => 4u8.hash(state),
+            Value::EofObject => 5u8.hash(state),
+            // Hashed like the equal literal string
+            Value::MutableString(chars) => {
+                0u8.hash(state);
+                Literal::String(chars.read().unwrap().iter().collect()).hash(state);
+            }
+            // Hashed by contents, since immutable pairs compare by contents
+            Value::Pair(..) => {
+                6u8.hash(state);
+                let mut current = self;
+                while let Value::Pair(car, cdr) = current {
+                    car.hash(state);
+                    current = cdr;
+                }
+                current.hash(state);
+            }
+            // Hashed by identity, since mutable pairs compare by identity
+            Value::MutablePair(car, _) => {
+                7u8.hash(state);
+                Arc::as_ptr(car).hash(state);
+            }
             // For compound values, we can't easily implement hash
             // so we use a type discriminant
             _ => std::mem::discriminant(self).hash(state),
@@ -1554,13 +1850,30 @@ impl std::hash::Hash for Value {
     }
 }
 
+/// Writes a symbol name, enclosing it in vertical bars when it would not read back as an identifier.
+fn write_symbol_name(f: &mut fmt::Formatter<'_>, name: &str) -> fmt::Result {
+    if crate::lexer::InternalLexer::reads_as_identifier(name) {
+        return write!(f, "{name}");
+    }
+    write!(f, "|")?;
+    for c in name.chars() {
+        match c {
+            '|' => write!(f, "\\|")?,
+            '\\' => write!(f, "\\\\")?,
+            c if c.is_control() => write!(f, "\\x{:x};", c as u32)?,
+            c => write!(f, "{c}")?,
+        }
+    }
+    write!(f, "|")
+}
+
 impl fmt::Display for Value {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Value::Literal(lit) => write!(f, "{lit}"),
             Value::Symbol(id) => {
                 if let Some(name) = crate::utils::symbol_name(*id) {
-                    write!(f, "{name}")
+                    write_symbol_name(f, &name)
                 } else {
                     write!(f, "#<symbol:{}>", id.id())
                 }
@@ -1568,6 +1881,7 @@ impl fmt::Display for Value {
             Value::Keyword(k) => write!(f, "#{k}"),
             Value::Nil => write!(f, "()"),
             Value::Unspecified => write!(f, "#<unspecified>"),
+            Value::EofObject => write!(f, "#<eof>"),
             Value::Pair(_car, _cdr) => {
                 write!(f, "(")?;
                 self.write_list_contents(f, true)?;
@@ -1593,11 +1907,20 @@ impl fmt::Display for Value {
                 write!(f, ")")
             }
             Value::Hashtable(_) => write!(f, "#<hashtable>"),
+            Value::Values(values) => {
+                for (i, value) in values.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, " ")?;
+                    }
+                    write!(f, "{value}")?;
+                }
+                Ok(())
+            }
             Value::Procedure(proc) => {
                 if let Some(name) = &proc.name {
-                    write!(f, "#<procedure:{name}>")
+                    write!(f, "#<procedure {name} {}>", proc.formals)
                 } else {
-                    write!(f, "#<procedure>")
+                    write!(f, "#<procedure anonymous>")
                 }
             }
             Value::CaseLambda(case_lambda) => {
@@ -1607,8 +1930,12 @@ impl fmt::Display for Value {
                     write!(f, "#<case-lambda>")
                 }
             }
-            Value::Primitive(prim) => write!(f, "#<primitive:{}>", prim.name),
-            Value::Continuation(cont) => write!(f, "#<continuation:{}>", cont.id),
+            Value::Primitive(prim) => match prim.arity_max {
+                Some(max) if max == prim.arity_min => write!(f, "#<procedure {} ({max})>", prim.name),
+                Some(max) => write!(f, "#<procedure {} ({}-{max})>", prim.name, prim.arity_min),
+                None => write!(f, "#<procedure {} ({}+)>", prim.name, prim.arity_min),
+            },
+            Value::Continuation(_) => write!(f, "#<continuation>"),
             Value::Syntax(syn) => write!(f, "#<syntax:{}>", syn.name),
             Value::Port(_) => write!(f, "#<port>"),
             Value::Promise(_) => write!(f, "#<promise>"),
@@ -1699,6 +2026,7 @@ impl Value {
                 match &**cdr {
                     Value::Nil => Ok(()),
                     Value::Pair(_, _) => cdr.write_list_contents(f, false),
+                    Value::MutablePair(_, _) => cdr.write_mutable_list_contents(f, false),
                     _ => write!(f, " . {cdr}"),
                 }
             }
@@ -1723,6 +2051,7 @@ impl Value {
                     match &*cdr {
                         Value::Nil => Ok(()),
                         Value::MutablePair(_, _) => cdr.write_mutable_list_contents(f, false),
+                        Value::Pair(_, _) => cdr.write_list_contents(f, false),
                         _ => write!(f, " . {cdr}"),
                     }
                 } else {
@@ -1891,6 +2220,11 @@ impl ThreadSafeEnvironment {
         }
     }
 
+    /// Looks up a variable in this environment only, ignoring its parents.
+    pub fn lookup_local(&self, name: &str) -> Option<Value> {
+        self.bindings.read().unwrap().get(name).cloned()
+    }
+
     /// Defines a variable in this environment using interior mutability.
     /// This allows in-place updates for standard library initialization.
     pub fn define(&self, name: String, value: Value) {
@@ -2170,6 +2504,11 @@ impl StackTrace {
         self.frames.pop()
     }
 
+    /// Drops every frame above the first `len`.
+    pub fn truncate(&mut self, len: usize) {
+        self.frames.truncate(len);
+    }
+
     /// Returns true if the stack trace is empty.
     pub fn is_empty(&self) -> bool {
         self.frames.is_empty()
@@ -2298,9 +2637,16 @@ impl Continuation {
             id,
             current_expr,
             invoked: Arc::new(std::sync::atomic::AtomicBool::new(false)),
+            dynamic_point: None,
         }
     }
 
+    /// Records the `dynamic-wind` point current at capture time.
+    pub fn with_dynamic_point(mut self, point: Option<Arc<crate::eval::dynamic_wind::DynamicPoint>>) -> Self {
+        self.dynamic_point = point;
+        self
+    }
+
     /// Checks if this continuation has been invoked.
     pub fn is_invoked(&self) -> bool {
         self.invoked.load(std::sync::atomic::Ordering::SeqCst)
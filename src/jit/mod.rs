@@ -193,6 +193,42 @@ impl JitCompiler {
         cache.get(ast)
     }
 
+    /// Compiles a function to `tier` before it has run, regardless of the
+    /// hotspot detector's thresholds
+    ///
+    /// Embedders use this to warm up latency-critical code: once it returns
+    /// `Success`, [`get_compiled_code`](Self::get_compiled_code) finds the
+    /// native code immediately. Code already cached at `tier` or above is
+    /// returned without compiling again. Expressions rejected by
+    /// [`utils::is_jit_suitable`] are reported as `Failed`.
+    pub fn precompile(
+        &self,
+        identifier: &str,
+        ast: &Expr,
+        env: &Arc<Environment>,
+        tier: CompilationTier,
+    ) -> Result<CompilationResult> {
+        if !utils::is_jit_suitable(ast) {
+            return Ok(CompilationResult::Failed {
+                error: format!("{identifier} is not compilable: only lambdas, applications, let forms and conditionals can be compiled"),
+                fallback_tier: CompilationTier::Interpreter,
+            });
+        }
+
+        if let Some(native_code) = self.get_compiled_code(ast)? {
+            if native_code.metadata.compilation_tier >= tier {
+                return Ok(CompilationResult::Success {
+                    native_code: Arc::new(native_code),
+                    compilation_time: Duration::ZERO,
+                    optimizations_applied: Vec::new(),
+                });
+            }
+        }
+
+        let context = utils::create_context(identifier.to_string(), ast.clone(), env.clone());
+        self.compile_function(context, tier)
+    }
+
     /// Checks if compilation should be triggered and initiates it
     fn maybe_trigger_compilation(&self, context: &JitContext) -> Result<()> {
         let should_compile = {
@@ -500,4 +536,38 @@ mod tests {
         let unsuitable = Expr::Literal(Literal::ExactInteger(42));
         assert!(!utils::is_jit_suitable(&unsuitable));
     }
+
+    #[test]
+    fn test_precompile_caches_native_code() {
+        use crate::ast::Formals;
+        use crate::diagnostics::{Span, Spanned};
+
+        let jit = JitCompiler::new().unwrap();
+        let env = Arc::new(Environment::new(None, 0));
+        let lambda = Expr::Lambda {
+            formals: Formals::Fixed(vec!["x".to_string()]),
+            metadata: HashMap::new(),
+            body: vec![Spanned::new(Expr::Identifier("x".to_string()), Span::default())],
+        };
+
+        assert!(jit.get_compiled_code(&lambda).unwrap().is_none());
+        let result = jit.precompile("identity", &lambda, &env, CompilationTier::JitBasic).unwrap();
+        assert!(matches!(result, CompilationResult::Success { .. }));
+        assert!(jit.get_compiled_code(&lambda).unwrap().is_some());
+
+        // A second precompile reuses the cached code
+        let again = jit.precompile("identity", &lambda, &env, CompilationTier::JitBasic).unwrap();
+        assert!(matches!(again, CompilationResult::Success { compilation_time, .. } if compilation_time == Duration::ZERO));
+    }
+
+    #[test]
+    fn test_precompile_rejects_unsuitable_expressions() {
+        let jit = JitCompiler::new().unwrap();
+        let env = Arc::new(Environment::new(None, 0));
+        let literal = Expr::Literal(Literal::ExactInteger(42));
+
+        let result = jit.precompile("constant", &literal, &env, CompilationTier::JitBasic).unwrap();
+        assert!(matches!(result, CompilationResult::Failed { ref error, .. } if error.contains("not compilable")));
+        assert!(jit.get_compiled_code(&literal).unwrap().is_none());
+    }
 }
\ No newline at end of file
@@ -18,6 +18,8 @@ pub struct InternalLexer<'a> {
     position: usize,
     current: Option<char>,
     filename: Option<&'a str>,
+    /// Whether identifiers are folded to lower case, toggled by `#!fold-case`
+    fold_case: bool,
 }
 
 impl<'a> InternalLexer<'a> {
@@ -32,9 +34,21 @@ impl<'a> InternalLexer<'a> {
             position: 0,
             current,
             filename,
+            fold_case: false,
         }
     }
 
+    /// Starts lexing with identifier case folding on or off
+    pub fn with_fold_case(mut self, fold_case: bool) -> Self {
+        self.fold_case = fold_case;
+        self
+    }
+
+    /// Whether case folding is on, as left by the last directive read
+    pub fn fold_case(&self) -> bool {
+        self.fold_case
+    }
+
     /// Advance to the next character
     fn advance(&mut self) {
         if let Some(ch) = self.current {
@@ -61,12 +75,28 @@ impl<'a> InternalLexer<'a> {
 
     /// Check if a character is a valid identifier start character
     fn is_identifier_start(ch: char) -> bool {
-        ch.is_alphabetic() || "!$%&*+-/<=>?^_~|".contains(ch)
+        ch.is_alphabetic() || "!$%&*+-/<=>?^_~".contains(ch)
     }
 
     /// Check if a character is a valid identifier continuation character
     fn is_identifier_continue(ch: char) -> bool {
-        ch.is_alphanumeric() || "!$%&*+-/<=>?^_~|".contains(ch)
+        ch.is_alphanumeric() || "!$%&*+-/<=>?^_~".contains(ch)
+    }
+
+    /// Check if a symbol name reads back as a plain identifier, without vertical bars
+    pub fn reads_as_identifier(name: &str) -> bool {
+        if name == "..." {
+            return true;
+        }
+        let mut chars = name.chars();
+        let Some(first) = chars.next() else {
+            return false;
+        };
+        if !Self::is_identifier_start(first) || !name.chars().all(Self::is_identifier_continue) {
+            return false;
+        }
+        // A sign followed by a digit, a dot or `i` starts a number
+        !(matches!(first, '+' | '-') && chars.next().is_some_and(|ch| Self::is_digit(ch) || ch == '.' || ch == 'i'))
     }
 
     /// Check if a character is a digit
@@ -144,6 +174,11 @@ impl<'a> InternalLexer<'a> {
                         return self.tokenize_number(start_pos);
                     }
                 }
+                // The ellipsis `...` is an identifier, not three dots
+                if self.source[self.position..].starts_with("...") {
+                    for _ in 0..3 { self.advance(); }
+                    return self.tokenize_identifier(start_pos);
+                }
                 self.advance();
                 TokenKind::Dot
             }
@@ -153,6 +188,13 @@ impl<'a> InternalLexer<'a> {
                 self.advance();
                 if self.current == Some(':') {
                     self.advance();
+                    if self.current == Some(':') {
+                        // Three or more colons, as in a `:::` custom ellipsis, form an identifier
+                        while self.current == Some(':') {
+                            self.advance();
+                        }
+                        return self.tokenize_identifier(start_pos);
+                    }
                     TokenKind::TypeAnnotation
                 } else {
                     // Single colon - in R7RS this would be part of an identifier
@@ -183,6 +225,11 @@ impl<'a> InternalLexer<'a> {
                 return self.tokenize_line_comment(start_pos);
             }
 
+            // Identifiers enclosed in vertical bars
+            '|' => {
+                return self.tokenize_bar_identifier(start_pos);
+            }
+
             // Identifiers
             ch if Self::is_identifier_start(ch) => {
                 return self.tokenize_identifier(start_pos);
@@ -213,7 +260,7 @@ impl<'a> InternalLexer<'a> {
             let is_eof = token.kind == TokenKind::Eof;
             
             // Skip comments in the token stream (but preserve them for potential use)
-            if !matches!(token.kind, TokenKind::LineComment | TokenKind::BlockComment) {
+            if !matches!(token.kind, TokenKind::LineComment | TokenKind::BlockComment | TokenKind::Directive) {
                 tokens.push(token);
             }
             
@@ -242,6 +289,16 @@ impl<'a> InternalLexer<'a> {
                 has_sign = true;
                 self.advance();
                 
+                // Special case: +inf.0, -inf.0, +nan.0 and -nan.0
+                let remaining = &self.source[self.position..];
+                if remaining.starts_with("inf.0") || remaining.starts_with("nan.0") {
+                    for _ in 0..5 { self.advance(); }
+                    let end_pos = self.position;
+                    let span = Span::new(start_pos, end_pos - start_pos);
+                    let text = self.source[start_pos..end_pos].to_owned();
+                    return Ok(Some(Token::new(TokenKind::RealNumber, span, text)));
+                }
+                
                 // Special case: lone +/- followed by 'i' is pure imaginary
                 if self.current == Some('i') {
                     self.advance();
@@ -467,7 +524,7 @@ impl<'a> InternalLexer<'a> {
                     self.advance();
                     if let Some(escape_ch) = self.current {
                         match escape_ch {
-                            '"' | '\\' | 'a' | 'b' | 'f' | 'n' | 'r' | 't' | 'v' => {
+                            '"' | '\\' | '|' | 'a' | 'b' | 'f' | 'n' | 'r' | 't' | 'v' => {
                                 // Valid single-character escape
                                 self.advance();
                             }
@@ -523,7 +580,7 @@ impl<'a> InternalLexer<'a> {
                             }
                         }
                     } else {
-                        return Err(Box::new(Error::lex_error(
+                        return Err(Box::new(Error::incomplete_input(
                             "Unterminated escape sequence at end of file".to_string(),
                             Span::new(start_pos, self.position - start_pos),
                         )));
@@ -542,7 +599,7 @@ impl<'a> InternalLexer<'a> {
         }
         
         if !found_closing_quote {
-            return Err(Box::new(Error::lex_error(
+            return Err(Box::new(Error::incomplete_input(
                 "Unterminated string literal at end of file".to_string(),
                 Span::new(start_pos, self.position - start_pos),
             )));
@@ -602,6 +659,29 @@ impl<'a> InternalLexer<'a> {
                 self.advance();
                 self.tokenize_block_comment(start_pos)
             }
+            Some('(') => {
+                // Vector literal
+                self.advance();
+                let span = Span::new(start_pos, self.position - start_pos);
+                Ok(Some(Token::new(TokenKind::VectorStart, span, "#(".to_string())))
+            }
+            Some('!') => {
+                // Reader directive
+                self.advance();
+                self.tokenize_directive(start_pos)
+            }
+            Some(';') => {
+                // Datum comment; the parser drops the datum that follows
+                self.advance();
+                let span = Span::new(start_pos, self.position - start_pos);
+                Ok(Some(Token::new(TokenKind::DatumComment, span, "#;".to_string())))
+            }
+            Some('u') if self.source[start_pos..].starts_with("#u8(") => {
+                // Bytevector literal
+                for _ in 0..3 { self.advance(); }
+                let span = Span::new(start_pos, self.position - start_pos);
+                Ok(Some(Token::new(TokenKind::BytevectorStart, span, "#u8(".to_string())))
+            }
             _ => {
                 Err(Box::new(Error::lex_error(
                     format!("Invalid character after #: {:?}", self.current),
@@ -613,47 +693,22 @@ impl<'a> InternalLexer<'a> {
 
     fn tokenize_character_literal(&mut self, start_pos: usize) -> Result<Option<Token>> {
         match self.current {
-            Some('x') => {
-                // Unicode hex character: #\xHH...
+            Some(ch) => {
                 self.advance();
-                while let Some(ch) = self.current {
-                    if ch.is_ascii_hexdigit() {
-                        self.advance();
-                    } else {
-                        break;
+                // Named characters and hex escapes such as #\newline or
+                // #\x41 run up to the next delimiter
+                if Self::is_identifier_continue(ch) {
+                    while let Some(next) = self.current {
+                        if Self::is_identifier_continue(next) {
+                            self.advance();
+                        } else {
+                            break;
+                        }
                     }
                 }
             }
-            Some(ch) => {
-                // Check for named characters
-                let remaining = &self.source[self.position..];
-                
-                // Try to match named characters
-                if remaining.starts_with("alarm") {
-                    for _ in 0..5 { self.advance(); }
-                } else if remaining.starts_with("backspace") {
-                    for _ in 0..9 { self.advance(); }
-                } else if remaining.starts_with("delete") || remaining.starts_with("escape") {
-                    for _ in 0..6 { self.advance(); }
-                } else if remaining.starts_with("newline") {
-                    for _ in 0..7 { self.advance(); }
-                } else if remaining.starts_with("null") {
-                    for _ in 0..4 { self.advance(); }
-                } else if remaining.starts_with("return") {
-                    for _ in 0..6 { self.advance(); }
-                } else if remaining.starts_with("space") {
-                    for _ in 0..5 { self.advance(); }
-                } else if remaining.starts_with("tab") {
-                    for _ in 0..3 { self.advance(); }
-                } else if remaining.starts_with("vtab") {
-                    for _ in 0..4 { self.advance(); }
-                } else {
-                    // Single character
-                    self.advance();
-                }
-            }
             None => {
-                return Err(Box::new(Error::lex_error(
+                return Err(Box::new(Error::incomplete_input(
                     "Incomplete character literal at end of file".to_string(),
                     Span::new(start_pos, self.position - start_pos),
                 )));
@@ -663,6 +718,12 @@ impl<'a> InternalLexer<'a> {
         let end_pos = self.position;
         let span = Span::new(start_pos, end_pos - start_pos);
         let text = self.source[start_pos..end_pos].to_owned();
+        let content = &text[2..];
+        if content.chars().nth(1).is_some() {
+            // A single character is always valid; anything longer must be
+            // a known name or a hex escape
+            super::string_utils::validate_character_literal(content, span)?;
+        }
         Ok(Some(Token::new(TokenKind::Character, span, text)))
     }
 
@@ -705,7 +766,7 @@ impl<'a> InternalLexer<'a> {
         }
         
         if nesting_level > 0 {
-            return Err(Box::new(Error::lex_error(
+            return Err(Box::new(Error::incomplete_input(
                 "Unterminated block comment".to_string(),
                 Span::new(start_pos, self.position - start_pos),
             )));
@@ -717,6 +778,31 @@ impl<'a> InternalLexer<'a> {
         Ok(Some(Token::new(TokenKind::BlockComment, span, text)))
     }
 
+    fn tokenize_directive(&mut self, start_pos: usize) -> Result<Option<Token>> {
+        while let Some(ch) = self.current {
+            if Self::is_identifier_continue(ch) {
+                self.advance();
+            } else {
+                break;
+            }
+        }
+        
+        let end_pos = self.position;
+        let span = Span::new(start_pos, end_pos - start_pos);
+        let text = self.source[start_pos..end_pos].to_owned();
+        match &text[2..] {
+            "fold-case" => self.fold_case = true,
+            "no-fold-case" => self.fold_case = false,
+            name => {
+                return Err(Box::new(Error::lex_error(
+                    format!("Unknown reader directive: #!{name}"),
+                    span,
+                )));
+            }
+        }
+        Ok(Some(Token::new(TokenKind::Directive, span, text)))
+    }
+
     fn tokenize_line_comment(&mut self, start_pos: usize) -> Result<Option<Token>> {
         // Skip until end of line
         while let Some(ch) = self.current {
@@ -732,6 +818,36 @@ impl<'a> InternalLexer<'a> {
         Ok(Some(Token::new(TokenKind::LineComment, span, text)))
     }
 
+    fn tokenize_bar_identifier(&mut self, start_pos: usize) -> Result<Option<Token>> {
+        self.advance(); // Skip opening bar
+
+        loop {
+            match self.current {
+                Some('|') => {
+                    self.advance();
+                    break;
+                }
+                Some('\\') => {
+                    // The escaped character is validated when the name is decoded
+                    self.advance();
+                    self.advance();
+                }
+                Some(_) => self.advance(),
+                None => {
+                    return Err(Box::new(Error::incomplete_input(
+                        "Unterminated |identifier| at end of file".to_string(),
+                        Span::new(start_pos, self.position - start_pos),
+                    )));
+                }
+            }
+        }
+
+        let end_pos = self.position;
+        let span = Span::new(start_pos, end_pos - start_pos);
+        let text = self.source[start_pos..end_pos].to_owned();
+        Ok(Some(Token::new(TokenKind::Identifier, span, text)))
+    }
+
     fn tokenize_identifier(&mut self, start_pos: usize) -> Result<Option<Token>> {
         // Continue while we have valid identifier characters
         while let Some(ch) = self.current {
@@ -744,7 +860,9 @@ impl<'a> InternalLexer<'a> {
         
         let end_pos = self.position;
         let span = Span::new(start_pos, end_pos - start_pos);
-        let text = self.source[start_pos..end_pos].to_owned();
+        let text = &self.source[start_pos..end_pos];
+        // Identifiers in vertical bars are never folded
+        let text = if self.fold_case { text.to_lowercase() } else { text.to_owned() };
         Ok(Some(Token::new(TokenKind::Identifier, span, text)))
     }
 }
@@ -816,6 +934,30 @@ mod tests {
         assert_eq!(tokens[0].text, "::");
     }
 
+    #[test]
+    fn test_colon_run_identifier() {
+        let source = "::: (x :::)";
+        let mut lexer = InternalLexer::new(source, None);
+        let tokens = lexer.tokenize().unwrap();
+
+        assert_eq!(tokens[0].kind, TokenKind::Identifier);
+        assert_eq!(tokens[0].text, ":::");
+        assert_eq!(tokens[3].kind, TokenKind::Identifier);
+        assert_eq!(tokens[3].text, ":::");
+    }
+
+    #[test]
+    fn test_ellipsis_identifier() {
+        let source = "(x ...) (a . b)";
+        let mut lexer = InternalLexer::new(source, None);
+        let tokens = lexer.tokenize().unwrap();
+
+        assert_eq!(tokens[2].kind, TokenKind::Identifier);
+        assert_eq!(tokens[2].text, "...");
+        assert_eq!(tokens[3].kind, TokenKind::RightParen);
+        assert_eq!(tokens[6].kind, TokenKind::Dot);
+    }
+
     #[test]
     fn test_simple_identifier() {
         let source = "hello-world";
@@ -956,4 +1098,17 @@ mod tests {
         assert_eq!(ident_tokens[2].text, "+add");
         assert_eq!(ident_tokens[3].text, "-sub");
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_fold_case_directives() {
+        let source = "Ab #!fold-case Cd |Ef| #!no-fold-case Gh";
+        let mut lexer = InternalLexer::new(source, None);
+        let tokens = lexer.tokenize().unwrap();
+
+        let texts: Vec<_> = tokens.iter().map(|t| t.text.as_str()).collect();
+        assert_eq!(texts, vec!["Ab", "cd", "|Ef|", "Gh", ""]);
+        assert!(!lexer.fold_case());
+        assert!(InternalLexer::new("#!fold-case", None).tokenize().is_ok());
+        assert!(InternalLexer::new("#!shout", None).tokenize().is_err());
+    }
+}
@@ -11,6 +11,7 @@ pub struct Lexer<'a> {
     source: &'a str,
     filename: Option<&'a str>,
     _position: usize,
+    fold_case: bool,
 }
 
 impl<'a> Lexer<'a> {
@@ -20,13 +21,28 @@ impl<'a> Lexer<'a> {
             source,
             filename,
             _position: 0,
+            fold_case: false,
         }
     }
 
+    /// Starts tokenizing with identifier case folding on or off, as left by
+    /// an earlier `#!fold-case` or `#!no-fold-case` directive.
+    pub fn with_fold_case(mut self, fold_case: bool) -> Self {
+        self.fold_case = fold_case;
+        self
+    }
+
+    /// Whether case folding is on after the directives tokenized so far.
+    pub fn fold_case(&self) -> bool {
+        self.fold_case
+    }
+
     /// Tokenizes the entire source code.
     pub fn tokenize(&mut self) -> Result<Vec<Token>> {
-        let mut internal_lexer = InternalLexer::new(self.source, self.filename);
-        internal_lexer.tokenize()
+        let mut internal_lexer = InternalLexer::new(self.source, self.filename).with_fold_case(self.fold_case);
+        let tokens = internal_lexer.tokenize()?;
+        self.fold_case = internal_lexer.fold_case();
+        Ok(tokens)
     }
 
     /// Gets the current filename (if any).
@@ -83,7 +99,7 @@ impl<'a> Lexer<'a> {
         // Check for unmatched opening parentheses
         if open_parens > 0 {
             let span = Span::new(position, 0);
-            errors.push(Error::lex_error(
+            errors.push(Error::incomplete_input(
                 format!("{open_parens} unmatched opening parenthesis(es)"),
                 span,
             ));
@@ -92,7 +108,7 @@ impl<'a> Lexer<'a> {
         // Check for unterminated string
         if open_quotes {
             let span = Span::new(position, 0);
-            errors.push(Error::lex_error(
+            errors.push(Error::incomplete_input(
                 "Unterminated string literal at end of file".to_string(),
                 span,
             ));
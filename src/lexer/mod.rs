@@ -49,6 +49,8 @@ pub enum TokenKind {
     RightBracket,
     LeftBrace,
     RightBrace,
+    VectorStart,
+    BytevectorStart,
 
     // === Quote and Unquote ===
     Quote,
@@ -81,6 +83,10 @@ pub enum TokenKind {
     // === Comments ===
     BlockComment,
     LineComment,
+    /// A reader directive such as `#!fold-case`, skipped like a comment
+    Directive,
+    /// `#;`, which comments out the datum that follows it
+    DatumComment,
 
     // === Identifiers ===
     Identifier,
@@ -114,6 +120,8 @@ impl fmt::Display for TokenKind {
             TokenKind::RightBracket => "]",
             TokenKind::LeftBrace => "{",
             TokenKind::RightBrace => "}",
+            TokenKind::VectorStart => "#(",
+            TokenKind::BytevectorStart => "#u8(",
             TokenKind::Quote => "'",
             TokenKind::Quasiquote => "`",
             TokenKind::Unquote => ",",
@@ -127,6 +135,8 @@ impl fmt::Display for TokenKind {
             TokenKind::Character => "character",
             TokenKind::Boolean => "boolean",
             TokenKind::LineComment | TokenKind::BlockComment => "comment",
+            TokenKind::Directive => "directive",
+            TokenKind::DatumComment => "datum comment",
             TokenKind::Eof => "end of file",
             TokenKind::Error => "error",
         };
@@ -352,7 +352,12 @@ pub fn parse_integer(text: &str) -> Option<i64> {
 
 /// Parses a real number literal into its numeric value.
 pub fn parse_real(text: &str) -> Option<f64> {
-    text.trim().parse().ok()
+    match text.trim() {
+        "+inf.0" => Some(f64::INFINITY),
+        "-inf.0" => Some(f64::NEG_INFINITY),
+        "+nan.0" | "-nan.0" => Some(f64::NAN),
+        text => text.parse().ok(),
+    }
 }
 
 /// Represents a rational number as numerator/denominator.
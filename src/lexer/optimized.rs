@@ -105,7 +105,7 @@ impl<'a> OptimizedLexer<'a> {
 
         for token in regular_tokens {
             // Skip comments
-            if matches!(token.kind, TokenKind::LineComment | TokenKind::BlockComment) {
+            if matches!(token.kind, TokenKind::LineComment | TokenKind::BlockComment | TokenKind::Directive) {
                 continue;
             }
 
@@ -226,6 +226,7 @@ pub fn unescape_string(content: &str) -> Result<String> {
                     match escape_ch {
                         '"' => result.push('"'),
                         '\\' => result.push('\\'),
+                        '|' => result.push('|'),
                         'a' => result.push('\x07'), // Alert (bell)
                         'b' => result.push('\x08'), // Backspace
                         'f' => result.push('\x0C'), // Form feed
@@ -313,7 +314,7 @@ pub fn parse_character_literal(content: &str) -> Result<char> {
     }
     
     // Handle Unicode hex escape
-    if let Some(hex_part) = content.strip_prefix('x') {
+    if let Some(hex_part) = content.strip_prefix('x').filter(|hex| !hex.is_empty()) {
         let code_point = u32::from_str_radix(hex_part, 16)
             .map_err(|_| Error::internal_error("Invalid Unicode character literal during parsing"))?;
         return char::from_u32(code_point)
@@ -118,7 +118,8 @@ impl Token {
     pub fn is_opening_delimiter(&self) -> bool {
         matches!(
             self.kind,
-            TokenKind::LeftParen | TokenKind::LeftBracket | TokenKind::LeftBrace
+            TokenKind::LeftParen | TokenKind::LeftBracket | TokenKind::LeftBrace |
+            TokenKind::VectorStart | TokenKind::BytevectorStart
         )
     }
     
@@ -136,6 +137,7 @@ impl Token {
             TokenKind::LeftParen => Some(TokenKind::RightParen),
             TokenKind::LeftBracket => Some(TokenKind::RightBracket),
             TokenKind::LeftBrace => Some(TokenKind::RightBrace),
+            TokenKind::VectorStart | TokenKind::BytevectorStart => Some(TokenKind::RightParen),
             TokenKind::RightParen => Some(TokenKind::LeftParen),
             TokenKind::RightBracket => Some(TokenKind::LeftBracket),
             TokenKind::RightBrace => Some(TokenKind::LeftBrace),
@@ -197,6 +199,14 @@ impl Token {
         parse_character_literal(content)
     }
 
+    /// Returns the symbol name of an identifier token, decoding `|...|` forms.
+    pub fn identifier_name(&self) -> crate::diagnostics::Result<String> {
+        match self.text.strip_prefix('|').and_then(|rest| rest.strip_suffix('|')) {
+            Some(content) if self.text.len() >= 2 => unescape_string(content),
+            _ => Ok(self.text.clone()),
+        }
+    }
+
     /// Returns the expected arity (number of arguments) for this identifier
     /// if it's a known built-in function.
     pub fn expected_arity(&self) -> Option<Arity> {
@@ -359,23 +359,16 @@ fn install_or_macro(expander: &mut MacroExpander) {
 /// Installs the `when` macro.
 /// (when test expr1 expr2 ...) => (if test (begin expr1 expr2 ...))
 fn install_when_macro(expander: &mut MacroExpander) {
-    let pattern = Pattern::list(vec![
-        Pattern::identifier("when"),
-        Pattern::variable("test"),
-        Pattern::ellipsis(
-            vec![],
-            Pattern::variable("expr"),
-            None,
-        ),
-    ]);
+    let pattern = Pattern::ellipsis(
+        vec![Pattern::identifier("when"), Pattern::variable("test")],
+        Pattern::variable("expr"),
+        None,
+    );
     
     let template = Template::list(vec![
         Template::identifier("if"),
         Template::variable("test"),
-        Template::list(vec![
-            Template::identifier("begin"),
-            Template::Variable("expr".to_string()),
-        ]),
+        Template::ellipsis(vec![Template::identifier("begin")], Template::variable("expr"), None),
     ]);
     
     let transformer = MacroTransformer {
@@ -392,15 +385,11 @@ fn install_when_macro(expander: &mut MacroExpander) {
 /// Installs the `unless` macro.
 /// (unless test expr1 expr2 ...) => (if (not test) (begin expr1 expr2 ...))
 fn install_unless_macro(expander: &mut MacroExpander) {
-    let pattern = Pattern::list(vec![
-        Pattern::identifier("unless"),
-        Pattern::variable("test"),
-        Pattern::ellipsis(
-            vec![],
-            Pattern::variable("expr"),
-            None,
-        ),
-    ]);
+    let pattern = Pattern::ellipsis(
+        vec![Pattern::identifier("unless"), Pattern::variable("test")],
+        Pattern::variable("expr"),
+        None,
+    );
     
     let template = Template::list(vec![
         Template::identifier("if"),
@@ -408,10 +397,7 @@ fn install_unless_macro(expander: &mut MacroExpander) {
             Template::identifier("not"),
             Template::variable("test"),
         ]),
-        Template::list(vec![
-            Template::identifier("begin"),
-            Template::Variable("expr".to_string()),
-        ]),
+        Template::ellipsis(vec![Template::identifier("begin")], Template::variable("expr"), None),
     ]);
     
     let transformer = MacroTransformer {
@@ -428,21 +414,18 @@ fn install_unless_macro(expander: &mut MacroExpander) {
 /// Installs the `begin` macro (for consistency).
 /// (begin expr1 expr2 ...) => ((lambda () expr1 expr2 ...))
 fn install_begin_macro(expander: &mut MacroExpander) {
-    let pattern = Pattern::list(vec![
-        Pattern::identifier("begin"),
-        Pattern::ellipsis(
-            vec![],
-            Pattern::variable("expr"),
-            None,
-        ),
-    ]);
+    let pattern = Pattern::ellipsis(
+        vec![Pattern::identifier("begin")],
+        Pattern::variable("expr"),
+        None,
+    );
     
     let template = Template::list(vec![
-        Template::list(vec![
-            Template::identifier("lambda"),
-            Template::list(vec![]),
-            Template::Variable("expr".to_string()),
-        ]),
+        Template::ellipsis(
+            vec![Template::identifier("lambda"), Template::list(vec![])],
+            Template::variable("expr"),
+            None,
+        ),
     ]);
     
     let transformer = MacroTransformer {
@@ -531,7 +514,7 @@ fn install_do_macro(expander: &mut MacroExpander) {
 }
 
 /// Installs the `delay` macro for lazy evaluation - R7RS compliant.
-/// (delay expr) => (make-promise (lambda () expr))
+/// (delay expr) => (%make-delayed-promise (lambda () expr))
 /// Provides proper memoization and supports promise chains
 fn install_delay_macro(expander: &mut MacroExpander) {
     let pattern = Pattern::list(vec![
@@ -539,14 +522,12 @@ fn install_delay_macro(expander: &mut MacroExpander) {
         Pattern::variable("expr"),
     ]);
     
-    // R7RS-compliant delay creates a memoizing promise
+    // R7RS-compliant delay creates a memoizing promise. The thunk is built
+    // as a lambda node, since a `lambda` keyword in operator position of a
+    // template list would be applied as a procedure
     let template = Template::list(vec![
-        Template::identifier("make-promise"),
-        Template::list(vec![
-            Template::identifier("lambda"),
-            Template::list(vec![]),
-            Template::variable("expr"),
-        ]),
+        Template::identifier("%make-delayed-promise"),
+        Template::transform("thunk", Template::variable("expr")),
     ]);
     
     let transformer = MacroTransformer {
@@ -693,6 +674,9 @@ fn install_case_lambda_macro(expander: &mut MacroExpander) {
 /// Installs the `cond-expand` macro for conditional compilation.
 /// (cond-expand ((feature1) body1 ...) ((feature2) body2 ...) (else body ...))
 /// => Expand to the first matching feature clause
+///
+/// The expander selects the clause itself by testing each requirement against
+/// `stdlib::system::feature_identifiers`; this binding reserves the name.
 fn install_cond_expand_macro(expander: &mut MacroExpander) {
     let pattern = Pattern::list(vec![
         Pattern::identifier("cond-expand"),
@@ -727,8 +711,10 @@ fn install_cond_expand_macro(expander: &mut MacroExpander) {
 }
 
 /// Installs the `assert` macro for runtime assertions.
-/// (assert expr) => (if (not expr) (error "assertion failed" 'expr))
-/// (assert expr message) => (if (not expr) (error message 'expr))
+/// (assert expr) => (if expr <unspecified> (assertion-violation 'assert "assertion failed: expr" 'expr))
+///
+/// The expander builds the expansion itself so the message can carry the
+/// expression's text; this binding reserves the name.
 fn install_assert_macro(expander: &mut MacroExpander) {
     // Case 1: (assert expr)
     let pattern1 = Pattern::list(vec![
@@ -5,7 +5,7 @@
 //! captured by identifiers in the macro use context. This follows the R7RS
 //! standard for hygienic macro expansion.
 
-use crate::ast::{CaseLambdaClause, Expr, Formals, Binding, CondClause, CaseClause, GuardClause, KeywordParam, ParameterBinding};
+use crate::ast::{CaseLambdaClause, Expr, Formals, Binding, CondClause, CaseClause, DoBinding, GuardClause, KeywordParam, ParameterBinding};
 use crate::diagnostics::{Result, Spanned};
 use crate::eval::Environment;
 // use crate::utils::{intern_symbol, symbol_name, SymbolId};
@@ -342,6 +342,22 @@ impl HygieneContext {
                 }
             }
             
+            Expr::Do { bindings, test, result, body } => {
+                let old_scope = self.enter_scope();
+                let renamed_bindings = self.rename_do_bindings(bindings)?;
+                let renamed_test = self.rename_expr(*test)?;
+                let renamed_result = self.rename_body(result)?;
+                let renamed_body = self.rename_body(body)?;
+                self.exit_scope(old_scope);
+                
+                Expr::Do {
+                    bindings: renamed_bindings,
+                    test: Box::new(renamed_test),
+                    result: renamed_result,
+                    body: renamed_body,
+                }
+            }
+            
             Expr::Cond(clauses) => {
                 let renamed_clauses = self.rename_cond_clauses(clauses)?;
                 Expr::Cond(renamed_clauses)
@@ -457,7 +473,7 @@ impl HygieneContext {
             }
             
             // Syntax rules handling
-            Expr::SyntaxRules { literals, rules } => {
+            Expr::SyntaxRules { ellipsis, literals, rules } => {
                 // For syntax-rules, we need to be careful about hygiene
                 // Patterns and templates should preserve their structure but rename bound identifiers
                 let renamed_rules = rules.iter()
@@ -468,6 +484,7 @@ impl HygieneContext {
                     .collect::<Result<Vec<_>>>()?;
                 
                 Expr::SyntaxRules {
+                    ellipsis: ellipsis.clone(),
                     literals: literals.clone(), // Keep literals unchanged
                     rules: renamed_rules,
                 }
@@ -497,8 +514,8 @@ impl HygieneContext {
                 Expr::UnquoteSplicing(Box::new(renamed_inner))
             }
 
-            // These don't contain identifiers to rename
-            Expr::Literal(_) | Expr::Keyword(_) => expr.inner,
+            // These don't contain identifiers to rename, and vector elements are data
+            Expr::Literal(_) | Expr::Keyword(_) | Expr::Vector(_) => expr.inner,
         };
         
         Ok(Spanned::new(renamed_inner, expr.span))
@@ -633,6 +650,22 @@ impl HygieneContext {
             .collect()
     }
     
+    /// Renames do loop variables with their init and step expressions.
+    fn rename_do_bindings(&mut self, bindings: Vec<DoBinding>) -> Result<Vec<DoBinding>> {
+        bindings.into_iter()
+            .map(|binding| {
+                let renamed_name = self.rename_identifier(&binding.name);
+                let renamed_init = self.rename_expr(binding.init)?;
+                let renamed_step = binding.step.map(|step| self.rename_expr(step)).transpose()?;
+                Ok(DoBinding {
+                    name: renamed_name,
+                    init: renamed_init,
+                    step: renamed_step,
+                })
+            })
+            .collect()
+    }
+    
     /// Renames cond clauses.
     fn rename_cond_clauses(&mut self, clauses: Vec<CondClause>) -> Result<Vec<CondClause>> {
         clauses.into_iter()
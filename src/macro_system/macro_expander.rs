@@ -1,8 +1,9 @@
 //! The main macro expander.
 
-use crate::ast::{Expr, Spanned};
+use crate::ast::{Binding, CaseClause, CondClause, DoBinding, Expr, GuardClause, Spanned};
 use crate::diagnostics::{Error, Result, Span};
 use crate::eval::Environment;
+use crate::module_system::SchemeLibraryLoader;
 use super::{
     MacroTransformer, MacroEnvironment, HygieneContext, PatternBindings,
     install_builtin_macros, next_hygiene_id, parse_syntax_rules, syntax_rules_to_macro_transformer
@@ -21,6 +22,10 @@ pub struct MacroExpander {
     max_expansion_depth: usize,
     /// Current hygiene context
     hygiene_context: HygieneContext,
+    /// Keywords bound by each enclosing `let-syntax`/`letrec-syntax`
+    local_keywords: Vec<Vec<String>>,
+    /// Loader consulted by `cond-expand` library requirements
+    library_loader: Option<Rc<SchemeLibraryLoader>>,
 }
 
 // SAFETY: MacroExpander is designed to be used in a thread-safe context through
@@ -39,6 +44,8 @@ impl MacroExpander {
             expansion_depth: 0,
             max_expansion_depth: 100,
             hygiene_context: HygieneContext::new(),
+            local_keywords: Vec::new(),
+            library_loader: None,
         }
     }
     
@@ -55,6 +62,13 @@ impl MacroExpander {
         // that has explicit mappings for special forms
     }
 
+    /// Resolves `cond-expand` library requirements against `loader`, the
+    /// loader `import` uses.
+    pub fn with_library_loader(mut self, loader: Rc<SchemeLibraryLoader>) -> Self {
+        self.library_loader = Some(loader);
+        self
+    }
+
     /// Creates a new macro expander with built-in macros.
     pub fn with_builtins() -> Self {
         let mut expander = Self::new();
@@ -71,7 +85,20 @@ impl MacroExpander {
     pub fn expand(&mut self, expr: &Spanned<Expr>) -> Result<Spanned<Expr>> {
         self.expand_inner(expr, &mut Vec::new())
     }
-    
+
+    /// Expands the macro use at the head of an expression by one step,
+    /// leaving its subforms alone. Other expressions are returned unchanged.
+    pub fn expand_once(&mut self, expr: &Spanned<Expr>) -> Result<Spanned<Expr>> {
+        if let Expr::Application { operator, operands } = &expr.inner {
+            if let Expr::Identifier(name) = &operator.inner {
+                if let Some(transformer) = self.macro_env.lookup(name) {
+                    return self.expand_macro(&transformer, operands, expr.span);
+                }
+            }
+        }
+        Ok(expr.clone())
+    }
+
     /// Expands all expressions in a program.
     pub fn expand_program(&mut self, program: &crate::ast::Program) -> Result<crate::ast::Program> {
         let mut expanded_expressions = Vec::new();
@@ -96,10 +123,36 @@ impl MacroExpander {
             )));
         }
 
+        // The parser turns special form keywords into dedicated nodes, so a
+        // local macro shadowing one has to be recognised here
+        if let Some((keyword, operands)) = self.shadowed_special_form(expr) {
+            let form = Spanned::new(
+                Expr::Application {
+                    operator: Box::new(Spanned::new(Expr::Identifier(keyword), expr.span)),
+                    operands,
+                },
+                expr.span,
+            );
+            return self.expand_inner(&form, expansion_trail);
+        }
+
         match &expr.inner {
             Expr::Application { operator, operands } => {
                 // Check if the operator is a macro
                 if let Expr::Identifier(name) = &operator.inner {
+                    if name == "let-syntax" || name == "letrec-syntax" {
+                        return self.expand_let_syntax(operands, expr.span);
+                    }
+                    if name == "cond-expand" {
+                        // Feature requirements are decided here rather than
+                        // by template substitution
+                        let selected = self.expand_cond_expand(operands, expr.span)?;
+                        return self.expand_inner(&selected, expansion_trail);
+                    }
+                    if name == "assert" && operands.len() == 1 {
+                        let checked = Self::expand_assert(&operands[0]);
+                        return self.expand_inner(&checked, expansion_trail);
+                    }
                     if let Some(transformer) = self.macro_env.lookup(name) {
                         // Prevent infinite recursion
                         if expansion_trail.contains(name) {
@@ -161,6 +214,16 @@ impl MacroExpander {
                     expr.span,
                 ))
             }
+            Expr::When { test, body } => {
+                let test = Box::new(self.expand_inner(test, expansion_trail)?);
+                let body = self.expand_body(body)?;
+                Ok(Spanned::new(Expr::When { test, body }, expr.span))
+            }
+            Expr::Unless { test, body } => {
+                let test = Box::new(self.expand_inner(test, expansion_trail)?);
+                let body = self.expand_body(body)?;
+                Ok(Spanned::new(Expr::Unless { test, body }, expr.span))
+            }
             Expr::Define { name, value, metadata } => {
                 let expanded_value = self.expand_inner(value, expansion_trail)?;
                 let expanded_metadata = self.expand_metadata(metadata)?;
@@ -173,12 +236,93 @@ impl MacroExpander {
                     expr.span,
                 ))
             }
+            Expr::Set { name, value } => {
+                let value = Box::new(self.expand_inner(value, expansion_trail)?);
+                Ok(Spanned::new(Expr::Set { name: name.clone(), value }, expr.span))
+            }
+            Expr::Begin(body) => Ok(Spanned::new(Expr::Begin(self.expand_body(body)?), expr.span)),
+            Expr::And(operands) => Ok(Spanned::new(Expr::And(self.expand_body(operands)?), expr.span)),
+            Expr::Or(operands) => Ok(Spanned::new(Expr::Or(self.expand_body(operands)?), expr.span)),
+            Expr::Let { bindings, body } => Ok(Spanned::new(
+                Expr::Let { bindings: self.expand_bindings(bindings)?, body: self.expand_body(body)? },
+                expr.span,
+            )),
+            Expr::LetStar { bindings, body } => Ok(Spanned::new(
+                Expr::LetStar { bindings: self.expand_bindings(bindings)?, body: self.expand_body(body)? },
+                expr.span,
+            )),
+            Expr::LetRec { bindings, body } => Ok(Spanned::new(
+                Expr::LetRec { bindings: self.expand_bindings(bindings)?, body: self.expand_body(body)? },
+                expr.span,
+            )),
+            Expr::Cond(clauses) => {
+                let mut expanded_clauses = Vec::with_capacity(clauses.len());
+                for clause in clauses {
+                    expanded_clauses.push(CondClause {
+                        test: self.expand_inner(&clause.test, expansion_trail)?,
+                        body: self.expand_body(&clause.body)?,
+                    });
+                }
+                Ok(Spanned::new(Expr::Cond(expanded_clauses), expr.span))
+            }
+            Expr::Case { expr: key, clauses } => {
+                let mut expanded_clauses = Vec::with_capacity(clauses.len());
+                for clause in clauses {
+                    expanded_clauses.push(CaseClause {
+                        values: clause.values.clone(),
+                        body: self.expand_body(&clause.body)?,
+                    });
+                }
+                Ok(Spanned::new(
+                    Expr::Case { expr: Box::new(self.expand_inner(key, expansion_trail)?), clauses: expanded_clauses },
+                    expr.span,
+                ))
+            }
+            Expr::Guard { variable, clauses, body } => {
+                let mut expanded_clauses = Vec::with_capacity(clauses.len());
+                for clause in clauses {
+                    expanded_clauses.push(GuardClause {
+                        test: self.expand_inner(&clause.test, expansion_trail)?,
+                        body: self.expand_body(&clause.body)?,
+                        arrow: match &clause.arrow {
+                            Some(arrow) => Some(self.expand_inner(arrow, expansion_trail)?),
+                            None => None,
+                        },
+                    });
+                }
+                Ok(Spanned::new(
+                    Expr::Guard { variable: variable.clone(), clauses: expanded_clauses, body: self.expand_body(body)? },
+                    expr.span,
+                ))
+            }
+            Expr::Do { bindings, test, result, body } => {
+                let mut expanded_bindings = Vec::new();
+                for binding in bindings {
+                    expanded_bindings.push(DoBinding {
+                        name: binding.name.clone(),
+                        init: self.expand_inner(&binding.init, expansion_trail)?,
+                        step: match &binding.step {
+                            Some(step) => Some(self.expand_inner(step, expansion_trail)?),
+                            None => None,
+                        },
+                    });
+                }
+                Ok(Spanned::new(
+                    Expr::Do {
+                        bindings: expanded_bindings,
+                        test: Box::new(self.expand_inner(test, expansion_trail)?),
+                        result: self.expand_body(result)?,
+                        body: self.expand_body(body)?,
+                    },
+                    expr.span,
+                ))
+            }
             Expr::DefineSyntax { name: _, transformer: _ } => {
                 // Define-syntax should not be expanded by the macro expander
                 // It should be handled directly by the evaluator
                 Ok(expr.clone())
             }
-            Expr::SyntaxRules { literals, rules } => {
+            Expr::SyntaxRules { .. } => {
                 // syntax-rules should not appear at top level normally, but handle it
                 // Convert to macro transformer representation
                 let syntax_rules_transformer = super::parse_syntax_rules(expr, Rc::new(Environment::new(None, 0)))?;
@@ -216,8 +360,10 @@ impl MacroExpander {
         // Try to match the pattern against the input
         let bindings = self.match_pattern(&transformer.pattern, &input_expr)?;
 
-        // Expand the template with the bindings
+        // Expand the template with the bindings. Special forms in the
+        // template come out as applications of their keywords
         let expanded = self.expand_template(&transformer.template, &bindings, span)?;
+        let expanded = crate::parser::Parser::parse_syntax(&expanded)?;
 
         // Apply hygiene transformations
         self.apply_hygiene(expanded, &transformer.definition_env)
@@ -253,6 +399,18 @@ impl MacroExpander {
         Ok(expanded)
     }
 
+    /// Expands the initializers of `let`-family bindings.
+    fn expand_bindings(&mut self, bindings: &[Binding]) -> Result<Vec<Binding>> {
+        let mut expanded = Vec::with_capacity(bindings.len());
+        for binding in bindings {
+            expanded.push(Binding {
+                name: binding.name.clone(),
+                value: self.expand_inner(&binding.value, &mut Vec::new())?,
+            });
+        }
+        Ok(expanded)
+    }
+
     /// Expands metadata expressions.
     fn expand_metadata(
         &mut self,
@@ -268,52 +426,25 @@ impl MacroExpander {
     /// Evaluates a macro transformer expression.
     fn evaluate_transformer(&self, transformer_expr: &Spanned<Expr>, _expansion_trail: Vec<String>) -> Result<MacroTransformer> {
         
-        // Check if this is a syntax-rules form (as application)
-        if let Expr::Application { operator, .. } = &transformer_expr.inner {
-            if let Expr::Identifier(name) = &operator.inner {
-                if name == "syntax-rules" {
-                    // Parse syntax-rules and convert to MacroTransformer
-                    // For now, create a basic environment - this will be improved when
-                    // macro/evaluator integration is complete
-                    let empty_env = Rc::new(Environment::new(None, 0));
-                    let syntax_rules = parse_syntax_rules(
-                        transformer_expr,
-                        empty_env,
-                    )?;
-                    return Ok(syntax_rules_to_macro_transformer(syntax_rules));
-                }
-            }
-        }
-        
-        // Check if this is a direct SyntaxRules expression
-        if let Expr::SyntaxRules { literals, rules } = &transformer_expr.inner {
-            // Handle SyntaxRules directly without going through parse_syntax_rules
-            // Create the transformer directly from the parsed structure
-            let definition_env = Rc::new(Environment::new(None, 0));
-            
-            // Convert the parsed rules to SyntaxRule structures
-            let mut syntax_rules = Vec::new();
-            for (pattern_expr, template_expr) in rules {
-                // Each rule is a (pattern, template) pair
-                let pattern = Self::expr_to_pattern(pattern_expr)?;
-                let template = Self::expr_to_template(template_expr)?;
-                
-                syntax_rules.push(crate::macro_system::syntax_rules::SyntaxRule {
-                    pattern,
-                    template,
-                });
+        // Check if this is a syntax-rules form, either as parsed by the
+        // program parser or as a plain application
+        let is_syntax_rules = match &transformer_expr.inner {
+            Expr::SyntaxRules { .. } => true,
+            Expr::Application { operator, .. } => {
+                matches!(&operator.inner, Expr::Identifier(name) if name == "syntax-rules")
             }
-            
-            let transformer = crate::macro_system::SyntaxRulesTransformer {
-                literals: literals.clone(),
-                rules: syntax_rules,
-                name: None,
-                definition_env,
-                custom_ellipsis: None,
-                srfi_149_mode: true, // Enable SRFI-149 by default
-            };
-            
-            return Ok(syntax_rules_to_macro_transformer(transformer));
+            _ => false,
+        };
+        if is_syntax_rules {
+            // Parse syntax-rules and convert to MacroTransformer
+            // For now, create a basic environment - this will be improved when
+            // macro/evaluator integration is complete
+            let empty_env = Rc::new(Environment::new(None, 0));
+            let syntax_rules = parse_syntax_rules(
+                transformer_expr,
+                empty_env,
+            )?;
+            return Ok(syntax_rules_to_macro_transformer(syntax_rules));
         }
         
         Err(Box::new(Error::macro_error(
@@ -322,48 +453,253 @@ impl MacroExpander {
         )))
     }
 
-    
-    /// Converts an expression to a pattern.
-    fn expr_to_pattern(expr: &Spanned<Expr>) -> Result<crate::macro_system::Pattern> {
-        match &expr.inner {
-            Expr::Identifier(name) => Ok(crate::macro_system::Pattern::identifier(name)),
+    /// Expands `(let-syntax ((keyword transformer) ...) body ...)` and its
+    /// `letrec-syntax` counterpart.
+    ///
+    /// The keywords are bound in a macro scope that only the body sees, and
+    /// they shadow outer macros, special forms and variables of the same
+    /// name. Because syntax-rules transformers are not evaluated, the two
+    /// forms bind identically: expansions of either may use sibling keywords.
+    fn expand_let_syntax(&mut self, operands: &[Spanned<Expr>], span: Span) -> Result<Spanned<Expr>> {
+        let Some((bindings, body)) = operands.split_first() else {
+            return Err(Box::new(Error::macro_error(
+                "let-syntax requires a binding list".to_string(),
+                span,
+            )));
+        };
+        if body.is_empty() {
+            return Err(Box::new(Error::macro_error(
+                "let-syntax requires a body".to_string(),
+                span,
+            )));
+        }
+        
+        let bindings: Vec<&Spanned<Expr>> = match &bindings.inner {
+            Expr::List(elements) => elements.iter().collect(),
             Expr::Application { operator, operands } => {
-                let op_pattern = Self::expr_to_pattern(operator)?;
-                let mut arg_patterns = Vec::new();
-                for arg in operands {
-                    arg_patterns.push(Self::expr_to_pattern(arg)?);
-                }
-                let mut patterns = vec![op_pattern];
-                patterns.extend(arg_patterns);
-                Ok(crate::macro_system::Pattern::list(patterns))
+                std::iter::once(operator.as_ref()).chain(operands.iter()).collect()
             }
-            Expr::Literal(lit) => Ok(crate::macro_system::Pattern::literal(lit.clone())),
             _ => {
-                // Fallback to variable pattern
-                Ok(crate::macro_system::Pattern::variable("_"))
+                return Err(Box::new(Error::macro_error(
+                    "let-syntax bindings must be a list".to_string(),
+                    bindings.span,
+                )));
             }
+        };
+        
+        let scope = self.macro_env.extend();
+        let mut keywords = Vec::new();
+        for binding in bindings {
+            let (keyword, spec) = match &binding.inner {
+                Expr::Application { operator, operands } => match (&operator.inner, operands.as_slice()) {
+                    (Expr::Identifier(keyword), [spec]) => (keyword.clone(), spec.clone()),
+                    _ => {
+                        return Err(Box::new(Error::macro_error(
+                            "let-syntax binding must be (keyword transformer)".to_string(),
+                            binding.span,
+                        )));
+                    }
+                },
+                // A binding for a special form keyword was parsed as that form
+                _ => match Self::special_form_parts(binding) {
+                    Some((keyword, mut operands)) if operands.len() == 1 => (keyword.to_string(), operands.remove(0)),
+                    _ => {
+                        return Err(Box::new(Error::macro_error(
+                            "let-syntax binding must be (keyword transformer)".to_string(),
+                            binding.span,
+                        )));
+                    }
+                },
+            };
+            
+            let syntax_rules = parse_syntax_rules(&spec, Rc::new(Environment::new(None, 0)))?;
+            let mut transformer = syntax_rules_to_macro_transformer(syntax_rules);
+            transformer.name = Some(keyword.clone());
+            scope.define(keyword.clone(), transformer);
+            keywords.push(keyword);
         }
+        
+        let outer = std::mem::replace(&mut self.macro_env, scope);
+        self.local_keywords.push(keywords);
+        let expanded = self.expand_body(body);
+        self.local_keywords.pop();
+        self.macro_env = outer;
+        
+        Ok(Spanned::new(Expr::Begin(expanded?), span))
     }
     
-    /// Converts an expression to a template.
-    fn expr_to_template(expr: &Spanned<Expr>) -> Result<crate::macro_system::Template> {
-        match &expr.inner {
-            Expr::Identifier(name) => Ok(crate::macro_system::Template::identifier(name)),
-            Expr::Application { operator, operands } => {
-                let op_template = Self::expr_to_template(operator)?;
-                let mut arg_templates = Vec::new();
-                for arg in operands {
-                    arg_templates.push(Self::expr_to_template(arg)?);
+    /// Rebuilds a parsed special form as a plain application when its
+    /// keyword is bound by an enclosing `let-syntax`.
+    fn shadowed_special_form(&self, expr: &Spanned<Expr>) -> Option<(String, Vec<Spanned<Expr>>)> {
+        if self.local_keywords.is_empty() {
+            return None;
+        }
+        
+        let (keyword, operands) = Self::special_form_parts(expr)?;
+        self.local_keywords
+            .iter()
+            .any(|keywords| keywords.iter().any(|bound| bound == keyword))
+            .then(|| (keyword.to_string(), operands))
+    }
+    
+    /// Splits a special form node the parser built back into its keyword
+    /// and operands.
+    fn special_form_parts(expr: &Spanned<Expr>) -> Option<(&'static str, Vec<Spanned<Expr>>)> {
+        Some(match &expr.inner {
+            Expr::If { test, consequent, alternative } => {
+                let mut operands = vec![test.as_ref().clone(), consequent.as_ref().clone()];
+                operands.extend(alternative.iter().map(|alt| alt.as_ref().clone()));
+                ("if", operands)
+            }
+            Expr::When { test, body } => {
+                ("when", std::iter::once(test.as_ref().clone()).chain(body.iter().cloned()).collect())
+            }
+            Expr::Unless { test, body } => {
+                ("unless", std::iter::once(test.as_ref().clone()).chain(body.iter().cloned()).collect())
+            }
+            Expr::Set { name, value } => {
+                ("set!", vec![Spanned::new(Expr::Identifier(name.clone()), expr.span), value.as_ref().clone()])
+            }
+            Expr::Begin(body) => ("begin", body.clone()),
+            Expr::And(operands) => ("and", operands.clone()),
+            Expr::Or(operands) => ("or", operands.clone()),
+            _ => return None,
+        })
+    }
+
+    /// Selects the body of the first `cond-expand` clause whose feature
+    /// requirement holds, as a `begin`. Expands to nothing when no clause
+    /// matches.
+    fn expand_cond_expand(&self, clauses: &[Spanned<Expr>], span: Span) -> Result<Spanned<Expr>> {
+        let features = crate::stdlib::system::feature_identifiers();
+        
+        for clause in clauses {
+            let (requirement, body) = match &clause.inner {
+                Expr::Application { operator, operands } => (operator.as_ref(), operands),
+                _ => {
+                    return Err(Box::new(Error::macro_error(
+                        "cond-expand clause must be a list".to_string(),
+                        clause.span,
+                    )));
+                }
+            };
+            
+            let selected = match &requirement.inner {
+                Expr::Identifier(name) if name == "else" => true,
+                _ => self.feature_requirement_holds(requirement, &features)?,
+            };
+            if selected {
+                if body.is_empty() {
+                    break;
                 }
-                let mut templates = vec![op_template];
-                templates.extend(arg_templates);
-                Ok(crate::macro_system::Template::list(templates))
+                return Ok(Spanned::new(Expr::Begin(body.clone()), span));
             }
-            Expr::Literal(lit) => Ok(crate::macro_system::Template::literal(lit.clone())),
-            _ => {
-                // Fallback to identifier template
-                Ok(crate::macro_system::Template::identifier("unknown"))
+        }
+        
+        // An empty `begin` is rejected by the evaluator
+        Ok(Spanned::new(Expr::Literal(crate::ast::Literal::Unspecified), span))
+    }
+    
+    /// Expands `(assert expr)` into a test of `expr` that raises an assertion
+    /// violation carrying the expression's text, located at the expression.
+    fn expand_assert(expr: &Spanned<Expr>) -> Spanned<Expr> {
+        let span = expr.span;
+        let violation = Expr::Application {
+            operator: Box::new(Spanned::new(Expr::Identifier("assertion-violation".to_string()), span)),
+            operands: vec![
+                Spanned::new(
+                    Expr::Quote(Box::new(Spanned::new(Expr::Identifier("assert".to_string()), span))),
+                    span,
+                ),
+                Spanned::new(
+                    Expr::Literal(crate::ast::Literal::String(format!("assertion failed: {}", expr.inner))),
+                    span,
+                ),
+                Spanned::new(Expr::Quote(Box::new(expr.clone())), span),
+            ],
+        };
+        
+        Spanned::new(
+            Expr::Unless {
+                test: Box::new(expr.clone()),
+                body: vec![Spanned::new(violation, span)],
+            },
+            span,
+        )
+    }
+    
+    /// Evaluates a `cond-expand` feature requirement: a feature identifier,
+    /// `(and ...)`, `(or ...)`, `(not ...)` or `(library <name>)`.
+    fn feature_requirement_holds(&self, requirement: &Spanned<Expr>, features: &[&str]) -> Result<bool> {
+        match &requirement.inner {
+            Expr::Identifier(name) => Ok(features.contains(&name.as_str())),
+            Expr::And(requirements) => {
+                for requirement in requirements {
+                    if !self.feature_requirement_holds(requirement, features)? {
+                        return Ok(false);
+                    }
+                }
+                Ok(true)
+            }
+            Expr::Or(requirements) => {
+                for requirement in requirements {
+                    if self.feature_requirement_holds(requirement, features)? {
+                        return Ok(true);
+                    }
+                }
+                Ok(false)
+            }
+            Expr::Application { operator, operands } => match (&operator.inner, operands.as_slice()) {
+                (Expr::Identifier(name), [requirement]) if name == "not" => {
+                    Ok(!self.feature_requirement_holds(requirement, features)?)
+                }
+                (Expr::Identifier(name), [library]) if name == "library" => {
+                    let library_name = Self::library_name_text(library).ok_or_else(|| {
+                        Box::new(Error::macro_error(
+                            "cond-expand library requirement needs a library name".to_string(),
+                            library.span,
+                        ))
+                    })?;
+                    Ok(self.library_available(&library_name))
+                }
+                _ => Err(Box::new(Error::macro_error(
+                    "Invalid cond-expand feature requirement".to_string(),
+                    requirement.span,
+                ))),
+            },
+            _ => Err(Box::new(Error::macro_error(
+                "Invalid cond-expand feature requirement".to_string(),
+                requirement.span,
+            ))),
+        }
+    }
+    
+    /// Checks whether `import` could load the named library. Without a
+    /// library loader no library is available.
+    fn library_available(&self, library_name: &str) -> bool {
+        let Ok(id) = crate::module_system::name::parse_module_name(library_name) else {
+            return false;
+        };
+        self.library_loader.as_ref().is_some_and(|loader| loader.is_available(&id))
+    }
+    
+    /// Renders a library name such as `(scheme base)` back to its source text.
+    fn library_name_text(name: &Spanned<Expr>) -> Option<String> {
+        let part = |expr: &Spanned<Expr>| match &expr.inner {
+            Expr::Identifier(part) => Some(part.clone()),
+            Expr::Literal(literal) => Some(literal.to_string()),
+            _ => None,
+        };
+        
+        match &name.inner {
+            Expr::Application { operator, operands } => {
+                let parts = std::iter::once(operator.as_ref())
+                    .chain(operands.iter())
+                    .map(part)
+                    .collect::<Option<Vec<_>>>()?;
+                Some(format!("({})", parts.join(" ")))
             }
+            _ => None,
         }
     }
 
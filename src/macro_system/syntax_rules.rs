@@ -120,6 +120,26 @@ pub fn parse_syntax_rules(
                 srfi_149_mode: true, // Default to SRFI-149 mode for new installations
             })
         }
+        // The parser's own representation of the form
+        Expr::SyntaxRules { ellipsis, literals, rules } => {
+            let ellipsis_token = ellipsis.as_deref().unwrap_or("...");
+            let srfi_149_mode = true;
+            let rules = rules
+                .iter()
+                .map(|(pattern, template)| {
+                    build_syntax_rule(pattern, template, literals, ellipsis_token, srfi_149_mode)
+                })
+                .collect::<Result<Vec<_>>>()?;
+            
+            Ok(SyntaxRulesTransformer {
+                literals: literals.clone(),
+                rules,
+                name: None,
+                definition_env,
+                custom_ellipsis: ellipsis.clone(),
+                srfi_149_mode,
+            })
+        }
         _ => Err(Box::new(Error::macro_error(
             "syntax-rules must be a function application".to_string(),
             expr.span,
@@ -202,42 +222,10 @@ fn parse_syntax_rule_with_mode(
 ) -> Result<SyntaxRule> {
     match &expr.inner {
         Expr::List(elements) if elements.len() == 2 => {
-            let pattern = parse_pattern(&elements[0], literals, ellipsis_token)?;
-            let mut template = parse_template(&elements[1], ellipsis_token)?;
-            
-            // SRFI-149: Apply advanced template features if enabled
-            if srfi_149_mode {
-                // Analyze ellipsis depth mismatch and apply extra ellipses if needed
-                let pattern_depth = pattern.ellipsis_depth();
-                if template.needs_extra_ellipses(pattern_depth) {
-                    template = template.with_extra_ellipses(pattern_depth);
-                }
-                
-                // Apply ambiguity resolution rules
-                let pattern_var_depths = pattern.variable_depths();
-                template.resolve_ambiguities(&pattern_var_depths);
-            }
-            
-            Ok(SyntaxRule { pattern, template })
+            build_syntax_rule(&elements[0], &elements[1], literals, ellipsis_token, srfi_149_mode)
         }
         Expr::Application { operands, .. } if operands.len() == 2 => {
-            let pattern = parse_pattern(&operands[0], literals, ellipsis_token)?;
-            let mut template = parse_template(&operands[1], ellipsis_token)?;
-            
-            // SRFI-149: Apply advanced template features if enabled
-            if srfi_149_mode {
-                // Analyze ellipsis depth mismatch and apply extra ellipses if needed
-                let pattern_depth = pattern.ellipsis_depth();
-                if template.needs_extra_ellipses(pattern_depth) {
-                    template = template.with_extra_ellipses(pattern_depth);
-                }
-                
-                // Apply ambiguity resolution rules
-                let pattern_var_depths = pattern.variable_depths();
-                template.resolve_ambiguities(&pattern_var_depths);
-            }
-            
-            Ok(SyntaxRule { pattern, template })
+            build_syntax_rule(&operands[0], &operands[1], literals, ellipsis_token, srfi_149_mode)
         }
         _ => Err(Box::new(Error::macro_error(
             "Syntax rule must be (pattern template)".to_string(),
@@ -246,6 +234,33 @@ fn parse_syntax_rule_with_mode(
     }
 }
 
+/// Builds a syntax rule from its pattern and template expressions.
+fn build_syntax_rule(
+    pattern_expr: &Spanned<Expr>,
+    template_expr: &Spanned<Expr>,
+    literals: &[String],
+    ellipsis_token: &str,
+    srfi_149_mode: bool,
+) -> Result<SyntaxRule> {
+    let pattern = parse_pattern(pattern_expr, literals, ellipsis_token)?;
+    let mut template = parse_template(template_expr, ellipsis_token)?;
+    
+    // SRFI-149: Apply advanced template features if enabled
+    if srfi_149_mode {
+        // Analyze ellipsis depth mismatch and apply extra ellipses if needed
+        let pattern_depth = pattern.ellipsis_depth();
+        if template.needs_extra_ellipses(pattern_depth) {
+            template = template.with_extra_ellipses(pattern_depth);
+        }
+        
+        // Apply ambiguity resolution rules
+        let pattern_var_depths = pattern.variable_depths();
+        template.resolve_ambiguities(&pattern_var_depths);
+    }
+    
+    Ok(SyntaxRule { pattern, template })
+}
+
 /// Parses a pattern from an expression.
 fn parse_pattern(expr: &Spanned<Expr>, literals: &[String], ellipsis_token: &str) -> Result<Pattern> {
     match &expr.inner {
@@ -253,6 +268,14 @@ fn parse_pattern(expr: &Spanned<Expr>, literals: &[String], ellipsis_token: &str
         Expr::Identifier(name) => {
             if literals.contains(name) {
                 Ok(Pattern::Identifier(name.clone()))
+            } else if name == "_" {
+                // `_` matches anything and binds nothing, however often it appears
+                Ok(Pattern::Wildcard)
+            } else if name == ellipsis_token {
+                Err(Box::new(Error::macro_error(
+                    format!("Ellipsis {name} must follow a subpattern"),
+                    expr.span,
+                )))
             } else {
                 Ok(Pattern::Variable(name.clone()))
             }
@@ -309,7 +332,7 @@ fn parse_list_pattern(
         // Check if next element is ellipsis
         if i + 1 < elements.len() {
             if let Expr::Identifier(name) = &elements[i + 1].inner {
-                if name == ellipsis_token {
+                if name == ellipsis_token && !literals.contains(name) {
                     // Found ellipsis - create ellipsis pattern
                     let ellipsis_pattern = parse_pattern(&elements[i], literals, ellipsis_token)?;
                     
@@ -375,7 +398,22 @@ fn parse_template(expr: &Spanned<Expr>, ellipsis_token: &str) -> Result<Template
                 cdr: Box::new(cdr_template),
             })
         }
-        
+
+        // Quotation abbreviations are lists headed by their keyword, and
+        // pattern variables inside them are still substituted
+        Expr::Quote(inner) | Expr::Quasiquote(inner) | Expr::Unquote(inner) | Expr::UnquoteSplicing(inner) => {
+            let keyword = match &expr.inner {
+                Expr::Quote(_) => "quote",
+                Expr::Quasiquote(_) => "quasiquote",
+                Expr::Unquote(_) => "unquote",
+                _ => "unquote-splicing",
+            };
+            Ok(Template::List(vec![
+                Template::Identifier(keyword.to_string()),
+                parse_template(inner, ellipsis_token)?,
+            ]))
+        }
+
         _ => Err(Box::new(Error::macro_error(
             format!("Unsupported template type: {:?}", expr.inner),
             expr.span,
@@ -724,7 +762,35 @@ mod tests {
         let pattern = parse_pattern(&expr, &literals, "...").unwrap();
         assert!(matches!(pattern, Pattern::Literal(_)));
     }
-    
+
+    #[test]
+    fn test_parse_wildcard_and_custom_ellipsis() {
+        let underscore = make_spanned(Expr::Identifier("_".to_string()));
+        let dots = make_spanned(Expr::Identifier("...".to_string()));
+        let colons = make_spanned(Expr::Identifier(":::".to_string()));
+
+        // `_` binds nothing unless it is a literal
+        assert_eq!(parse_pattern(&underscore, &[], "...").unwrap(), Pattern::Wildcard);
+        assert_eq!(
+            parse_pattern(&underscore, &["_".to_string()], "...").unwrap(),
+            Pattern::Identifier("_".to_string())
+        );
+
+        // With `:::` as the ellipsis, `...` is an ordinary pattern variable
+        let elements = vec![underscore.clone(), dots.clone(), underscore.clone(), colons.clone()];
+        match parse_list_pattern(&elements, &[], ":::").unwrap() {
+            Pattern::Ellipsis { patterns, ellipsis_pattern, rest } => {
+                assert_eq!(patterns, vec![Pattern::Wildcard, Pattern::Variable("...".to_string())]);
+                assert_eq!(*ellipsis_pattern, Pattern::Wildcard);
+                assert!(rest.is_none());
+            }
+            other => panic!("Expected ellipsis pattern, got {other:?}"),
+        }
+
+        // The ellipsis cannot stand in for a pattern variable
+        assert!(parse_list_pattern(&[colons, dots], &[], ":::").is_err());
+    }
+
     #[test]
     fn test_parse_list_pattern() {
         let literals = vec![];
@@ -1,6 +1,7 @@
 //! Template expansion for macro output.
 
-use crate::ast::{Expr, Literal};
+use crate::ast::{Expr, Formals, Literal};
+use std::collections::HashMap;
 use crate::diagnostics::{Error, Result, Spanned};
 use crate::macro_system::pattern::PatternBindings;
 
@@ -166,13 +167,10 @@ impl Template {
     pub fn expand(&self, bindings: &PatternBindings, span: crate::diagnostics::Span) -> Result<Spanned<Expr>> {
         match self {
             Template::Variable(name) => {
-                if let Some(expr) = bindings.get(name) {
-                    Ok(expr.clone())
-                } else {
-                    Err(Box::new(Error::macro_error(
-                        format!("Unbound template variable: {name}"),
-                        span,
-                    )))
+                // Identifiers that are not pattern variables are inserted as they are
+                match bindings.get(name) {
+                    Some(expr) => Ok(expr.clone()),
+                    None => Ok(Spanned::new(Expr::Identifier(name.clone()), span)),
                 }
             }
             
@@ -239,9 +237,20 @@ impl Template {
             }
             
             Template::Transform { function, argument } => {
-                // For now, just expand the argument and return it
-                // TODO: Implement actual transformation functions
-                argument.expand(bindings, span)
+                let expanded = argument.expand(bindings, span)?;
+                match function.as_str() {
+                    // Wraps the argument in a lambda of no arguments
+                    "thunk" => Ok(Spanned::new(
+                        Expr::Lambda {
+                            formals: Formals::Fixed(Vec::new()),
+                            metadata: HashMap::new(),
+                            body: vec![expanded],
+                        },
+                        span,
+                    )),
+                    // TODO: Implement the remaining transformation functions
+                    _ => Ok(expanded),
+                }
             }
             
             Template::Conditional { condition, then_branch, else_branch } => {
@@ -394,6 +394,47 @@ fn test_nested_macro_expansion() {
     assert!(is_fully_expanded(&expanded.inner));
 }
 
+#[test]
+fn test_cond_expand_selects_feature_clause() {
+    let mut expander = MacroExpander::with_builtins();
+    let clause = |requirement: Expr, body: &str| make_spanned(Expr::Application {
+        operator: Box::new(make_spanned(requirement)),
+        operands: vec![make_spanned(Expr::Literal(Literal::String(body.to_string())))],
+    });
+    let cond_expand = |clauses: Vec<Spanned<Expr>>| make_spanned(Expr::Application {
+        operator: Box::new(make_spanned(Expr::Identifier("cond-expand".to_string()))),
+        operands: clauses,
+    });
+    
+    let expr = cond_expand(vec![
+        clause(Expr::Identifier("no-such-feature".to_string()), "missing"),
+        clause(Expr::And(vec![
+            make_spanned(Expr::Identifier("r7rs".to_string())),
+            make_spanned(Expr::Identifier("ratios".to_string())),
+        ]), "ratios"),
+        clause(Expr::Identifier("else".to_string()), "else"),
+    ]);
+    let expanded = expander.expand(&expr).unwrap();
+    match expanded.inner {
+        Expr::Begin(body) => {
+            assert_eq!(body.len(), 1);
+            assert_eq!(body[0].inner, Expr::Literal(Literal::String("ratios".to_string())));
+        }
+        other => panic!("cond-expand should expand to begin, got {other:?}"),
+    }
+    
+    let expr = cond_expand(vec![
+        clause(Expr::Identifier("no-such-feature".to_string()), "missing"),
+        clause(Expr::Identifier("else".to_string()), "else"),
+    ]);
+    match expander.expand(&expr).unwrap().inner {
+        Expr::Begin(body) => {
+            assert_eq!(body[0].inner, Expr::Literal(Literal::String("else".to_string())));
+        }
+        other => panic!("cond-expand should expand to begin, got {other:?}"),
+    }
+}
+
 /// Helper function to check if an expression is fully expanded (contains no macro calls).
 fn is_fully_expanded(expr: &Expr) -> bool {
     match expr {
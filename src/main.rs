@@ -125,10 +125,12 @@ fn main() -> Result<()> {
     // Handle different execution modes
     if let Some(expr) = matches.get_one::<String>("eval") {
         // Evaluate single expression
-        eval_expression(&mut lambdust, expr, matches.get_flag("type-check"))?;
+        let result = eval_expression(&mut lambdust, expr, matches.get_flag("type-check"));
+        exit_on_uncaught_error(result);
     } else if let Some(filename) = matches.get_one::<String>("file") {
         // Execute file
-        execute_file(&mut lambdust, filename, matches.get_flag("type-check"))?;
+        let result = execute_file(&mut lambdust, filename, matches.get_flag("type-check"));
+        exit_on_uncaught_error(result);
     } else if matches.get_flag("repl") || matches.get_one::<String>("file").is_none() {
         // Start REPL if no file specified or explicitly requested
         #[cfg(feature = "enhanced-repl")]
@@ -150,6 +152,14 @@ fn main() -> Result<()> {
     Ok(())
 }
 
+/// Reports an error that escaped the program and exits with failure.
+fn exit_on_uncaught_error(result: Result<()>) {
+    if let Err(e) = result {
+        eprintln!("Error: {}", e.report());
+        std::process::exit(1);
+    }
+}
+
 fn eval_expression(lambdust: &mut Lambdust, expr: &str, type_check_only: bool) -> Result<()> {
     if type_check_only {
         // Type check only
@@ -247,6 +257,20 @@ fn start_repl(lambdust: &mut Lambdust) -> Result<()> {
                     _ => {}
                 }
 
+                if let Some(source) = line.strip_prefix(":time ") {
+                    match lambdust::repl::time_eval(lambdust, source.trim()) {
+                        Ok(timing) => {
+                            println!("{}", format!("{}", timing.value).bright_green());
+                            println!("{}", format!("({timing})").dimmed());
+                        }
+                        Err(e) => {
+                            eprintln!("{}", format!("Error: {}", e.report()).bright_red());
+                        }
+                    }
+                    line_number += 1;
+                    continue;
+                }
+
                 // Evaluate expression with proper macro support for REPL
                 match evaluate_repl_expression(lambdust, line) {
                     Ok(result) => {
@@ -256,7 +280,7 @@ fn start_repl(lambdust: &mut Lambdust) -> Result<()> {
                         }
                     }
                     Err(e) => {
-                        eprintln!("{}", format!("Error: {e}").bright_red());
+                        eprintln!("{}", format!("Error: {}", e.report()).bright_red());
                     }
                 }
 
@@ -307,6 +331,7 @@ fn print_repl_help() {
     println!("{}", "Lambdust REPL Commands:".bright_blue().bold());
     println!("  {}  - Show this help", ":help, :h".bright_yellow());
     println!("  {}  - Show version information", ":version, :v".bright_yellow());
+    println!("  {}  - Evaluate and report the time taken", ":time <expr>".bright_yellow());
     println!("  {}  - Exit the REPL", "(exit), (quit), :quit, :q".bright_yellow());
     println!();
     println!("{}", "Example expressions:".bright_blue().bold());
@@ -5,6 +5,7 @@
 
 use super::security::{SecurityManager, SecurityContext, Permission, ResourceUsage};
 use crate::eval::{Value, Environment, Evaluator};
+use crate::runtime::EffectSandboxConfig;
 use crate::lexer::Lexer;
 use crate::parser::Parser;
 use crate::diagnostics::{Error, Result};
@@ -433,7 +434,9 @@ impl DynamicEvaluator {
 
     /// Creates a sandboxed evaluator.
     fn create_sandboxed_evaluator(&self, context: &ExecutionContext) -> Result<Evaluator> {
-        Ok(Evaluator::with_environment(context.environment.clone()))
+        let mut evaluator = Evaluator::with_environment(context.environment.clone());
+        evaluator.set_effect_sandbox(Some(EffectSandboxConfig::default()));
+        Ok(evaluator)
     }
 
     /// Evaluates a program with resource limits.
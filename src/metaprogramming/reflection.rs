@@ -33,6 +33,8 @@ pub enum TypeInfo {
     Nil,
     /// Unspecified value type
     Unspecified,
+    /// End-of-file object type
+    EofObject,
     
     /// Compound types
     /// Pair/cons cell type
@@ -61,6 +63,8 @@ pub enum TypeInfo {
     Bag,
     /// Generator type (SRFI-121)
     Generator,
+    /// Multiple values delivered to a continuation
+    Values,
     
     /// Procedure types
     /// User-defined procedure type
@@ -288,6 +292,7 @@ impl ObjectInspector {
         let type_info = match value {
             Value::Literal(Literal::Boolean(_)) => TypeInfo::Boolean,
             Value::Literal(Literal::ExactInteger(_)) | Value::Literal(Literal::InexactReal(_)) | Value::Literal(Literal::Number(_)) => TypeInfo::Number,
+            Value::Literal(Literal::BigInteger(_)) => TypeInfo::Number,
             Value::Literal(Literal::Rational { .. }) => TypeInfo::Number,
             Value::Literal(Literal::Complex { .. }) => TypeInfo::Number,
             Value::Literal(Literal::String(_)) => TypeInfo::String,
@@ -299,6 +304,7 @@ impl ObjectInspector {
             Value::Keyword(_) => TypeInfo::Keyword,
             Value::Nil => TypeInfo::Nil,
             Value::Unspecified => TypeInfo::Unspecified,
+            Value::EofObject => TypeInfo::EofObject,
             Value::Pair(_, _) => TypeInfo::Pair,
             Value::MutablePair(_, _) => TypeInfo::Pair,
             Value::Vector(_) => TypeInfo::Vector,
@@ -440,6 +446,8 @@ impl ObjectInspector {
 
             Value::Generator(_) => TypeInfo::Generator,
 
+            Value::Values(_) => TypeInfo::Values,
+
             Value::Opaque(_opaque) => {
                 TypeInfo::Opaque {
                     type_name: "opaque".to_string(), // Placeholder - would extract actual type name
@@ -3,6 +3,7 @@
 //! Handles export declarations and symbol visibility:
 //! - (export symbol1 symbol2 ...) - Export specific symbols
 //! - (export (rename (internal-name external-name))) - Export with renaming
+//! - (export a (rename (b c))) - Mixed direct and renamed exports
 //! - Validation of exported symbols and visibility rules
 
 use super::{ExportSpec, ExportConfig, ModuleError};
@@ -79,39 +80,45 @@ pub fn parse_export_spec(export_form: &[Spanned<Expr>]) -> Result<ExportSpec> {
     
     for expr in export_form {
         match &expr.inner {
-            Expr::Symbol(symbol) => {
+            Expr::Symbol(symbol) | Expr::Identifier(symbol) => {
                 symbols.push(symbol.clone());
             }
-            Expr::List(elements) if !elements.is_empty() => {
-                match &elements[0].inner {
-                    Expr::Symbol(keyword) if keyword == "rename" => {
-                        // Parse rename specifications
-                        for rename_spec in &elements[1..] {
-                            parse_rename_spec(rename_spec, &mut rename_map)?;
+            _ => match super::import::form_elements(expr) {
+                Some(elements) if !elements.is_empty() => {
+                    match &elements[0].inner {
+                        Expr::Symbol(keyword) | Expr::Identifier(keyword) if keyword == "rename" => {
+                            // Parse rename specifications
+                            for rename_spec in &elements[1..] {
+                                parse_rename_spec(rename_spec, &mut rename_map)?;
+                            }
                         }
+                        _ => return Err(Box::new(Error::syntax_error(
+                            "Unknown export form".to_string(),
+                            Some(elements[0].span),
+                        ))),
                     }
-                    _ => return Err(Box::new(Error::syntax_error(
-                        "Unknown export form".to_string(),
-                        Some(elements[0].span),
-                    ))),
                 }
-            }
-            _ => return Err(Box::new(Error::syntax_error(
-                "Invalid export specification".to_string(),
-                Some(expr.span),
-            ))),
+                _ => return Err(Box::new(Error::syntax_error(
+                    "Invalid export specification".to_string(),
+                    Some(expr.span),
+                ))),
+            },
         }
     }
     
     let config = if rename_map.is_empty() {
         ExportConfig::Direct
-    } else if symbols.is_empty() {
-        ExportConfig::Rename(rename_map)
     } else {
-        return Err(Box::new(Error::syntax_error(
-            "Cannot mix direct exports and rename exports".to_string(),
-            None,
-        )));
+        // Direct exports alongside renames are exported under their own name
+        for symbol in symbols.drain(..) {
+            if rename_map.values().any(|external| external == &symbol) {
+                return Err(Box::new(Error::from(ModuleError::InvalidDefinition(
+                    format!("Duplicate export name: {symbol}")
+                ))));
+            }
+            rename_map.insert(symbol.clone(), symbol);
+        }
+        ExportConfig::Rename(rename_map)
     };
     
     Ok(ExportSpec { symbols, config })
@@ -124,10 +131,10 @@ fn parse_rename_spec(
 ) -> Result<()> {
     use crate::ast::Expr;
     
-    match &spec.inner {
-        Expr::List(pair) if pair.len() == 2 => {
+    match super::import::form_elements(spec) {
+        Some(pair) if pair.len() == 2 => {
             let internal_name = match &pair[0].inner {
-                Expr::Symbol(symbol) => symbol.clone(),
+                Expr::Symbol(symbol) | Expr::Identifier(symbol) => symbol.clone(),
                 _ => return Err(Box::new(Error::syntax_error(
                     "Rename specification must contain symbols".to_string(),
                     Some(pair[0].span),
@@ -135,7 +142,7 @@ fn parse_rename_spec(
             };
             
             let external_name = match &pair[1].inner {
-                Expr::Symbol(symbol) => symbol.clone(),
+                Expr::Symbol(symbol) | Expr::Identifier(symbol) => symbol.clone(),
                 _ => return Err(Box::new(Error::syntax_error(
                     "Rename specification must contain symbols".to_string(),
                     Some(pair[1].span),
@@ -431,4 +438,30 @@ mod tests {
         assert!(filtered.symbols.contains(&"c".to_string()));
         assert!(!filtered.symbols.contains(&"b".to_string()));
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_parse_mixed_direct_and_rename_export() {
+        let span = crate::diagnostics::Span::new(0, 0);
+        let ident = |name: &str| Spanned::new(Expr::Identifier(name.to_string()), span);
+
+        // (export a (rename (b c)))
+        let spec = parse_export_spec(&[
+            ident("a"),
+            Spanned::new(Expr::List(vec![
+                ident("rename"),
+                Spanned::new(Expr::List(vec![ident("b"), ident("c")]), span),
+            ]), span),
+        ]).unwrap();
+
+        let mut bindings = HashMap::new();
+        bindings.insert("a".to_string(), Value::integer(1));
+        bindings.insert("b".to_string(), Value::integer(2));
+        validate_export_spec(&spec, &bindings).unwrap();
+
+        let exports = apply_export_config(&bindings, &spec.config, &spec.symbols).unwrap();
+        assert_eq!(exports.len(), 2);
+        assert_eq!(exports.get("a"), Some(&Value::integer(1)));
+        assert_eq!(exports.get("c"), Some(&Value::integer(2)));
+        assert!(!exports.contains_key("b"));
+    }
+}
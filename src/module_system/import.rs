@@ -6,6 +6,7 @@
 //! - (import (lambdust string) (except string-fill!)) - Import all except specific symbols  
 //! - (import (lambdust string) (rename (string-length str-len))) - Import with renaming
 //! - (import (lambdust string) (prefix string:)) - Import with prefix
+//! - (import (prefix (only (lambdust string) string-length) s:)) - R7RS nested import sets
 
 use super::{ImportSpec, ImportConfig, ModuleError};
 use crate::diagnostics::{Error, Result, Spanned};
@@ -24,6 +25,13 @@ pub fn apply_import_config(
         ImportConfig::Except(symbols) => apply_except_import(exports, symbols),
         ImportConfig::Rename(rename_map) => apply_rename_import(exports, rename_map),
         ImportConfig::Prefix(prefix) => apply_prefix_import(exports, prefix),
+        ImportConfig::Chain(configs) => {
+            let mut bindings = exports.clone();
+            for config in configs {
+                bindings = apply_import_config(&bindings, config)?;
+            }
+            Ok(bindings)
+        }
     }
 }
 
@@ -64,18 +72,28 @@ fn apply_except_import(
 }
 
 /// Imports symbols with renaming.
+///
+/// Symbols not mentioned in the rename map are imported unchanged, as
+/// required by R7RS.
 fn apply_rename_import(
     exports: &HashMap<String, Value>,
     rename_map: &HashMap<String, String>,
 ) -> Result<HashMap<String, Value>> {
+    for original_name in rename_map.keys() {
+        if !exports.contains_key(original_name) {
+            return Err(Box::new(Error::from(ModuleError::ImportConflict(
+                format!("Symbol '{original_name}' not found in module exports")
+            ))));
+        }
+    }
+    
     let mut result = HashMap::new();
     
-    for (original_name, new_name) in rename_map {
-        if let Some(value) = exports.get(original_name) {
-            result.insert(new_name.clone(), value.clone());
-        } else {
+    for (symbol, value) in exports {
+        let name = rename_map.get(symbol).unwrap_or(symbol);
+        if result.insert(name.clone(), value.clone()).is_some() {
             return Err(Box::new(Error::from(ModuleError::ImportConflict(
-                format!("Symbol '{original_name}' not found in module exports")
+                format!("Rename of '{symbol}' clashes with existing import '{name}'")
             ))));
         }
     }
@@ -107,15 +125,15 @@ pub fn parse_import_spec(import_form: &[Spanned<Expr>]) -> Result<ImportSpec> {
         )));
     }
 
-    // First element should be the module identifier
-    let module_name = extract_module_name(&import_form[0])?;
-    let module_id = super::name::parse_module_name(&module_name)?;
+    // First element should be the module identifier, possibly wrapped in
+    // R7RS import set operators
+    let ImportSpec { module_id, config } = parse_import_set(&import_form[0])?;
     
     // Parse import configuration from remaining elements
     let config = if import_form.len() == 1 {
-        ImportConfig::All
+        config
     } else {
-        parse_import_config(&import_form[1..])?
+        config.then(parse_import_config(&import_form[1..])?)
     };
 
     Ok(ImportSpec {
@@ -124,25 +142,119 @@ pub fn parse_import_spec(import_form: &[Spanned<Expr>]) -> Result<ImportSpec> {
     })
 }
 
+/// Parses an R7RS import set such as `(prefix (only (my lib) foo) my:)`.
+///
+/// Import set operators may be nested arbitrarily; the innermost library
+/// name becomes the module identifier and the operators are composed into
+/// an [`ImportConfig::Chain`] applied from the inside out.
+pub fn parse_import_set(import_set: &Spanned<Expr>) -> Result<ImportSpec> {
+    if let Some((keyword, elements)) = import_set_operator(import_set) {
+        let inner = parse_import_set(&elements[1])?;
+        let config = parse_import_modifier(keyword, &elements[2..], elements[0].span)?;
+        return Ok(ImportSpec {
+            module_id: inner.module_id,
+            config: inner.config.then(config),
+        });
+    }
+
+    let module_name = extract_module_name(import_set)?;
+    let module_id = super::name::parse_module_name(&module_name)?;
+
+    Ok(ImportSpec {
+        module_id,
+        config: ImportConfig::All,
+    })
+}
+
+/// Recognizes an import set operator form (`only`, `except`, `prefix`, `rename`).
+///
+/// Returns the operator keyword and the elements of the form. A library
+/// whose name merely starts with one of these keywords (e.g. `(only lib)`)
+/// is not treated as an operator because its second element is not itself
+/// an import set.
+pub fn import_set_operator(expr: &Spanned<Expr>) -> Option<(&'static str, Vec<Spanned<Expr>>)> {
+    let elements = form_elements(expr)?;
+    if elements.len() < 2 || form_elements(&elements[1]).is_none() {
+        return None;
+    }
+
+    let keyword = match &elements[0].inner {
+        Expr::Identifier(name) | Expr::Symbol(name) => name.as_str(),
+        _ => return None,
+    };
+
+    let keyword = match keyword {
+        "only" => "only",
+        "except" => "except",
+        "prefix" => "prefix",
+        "rename" => "rename",
+        _ => return None,
+    };
+
+    Some((keyword, elements))
+}
+
+/// Parses the arguments of a single import set operator.
+pub fn parse_import_modifier(
+    keyword: &str,
+    arguments: &[Spanned<Expr>],
+    span: crate::diagnostics::Span,
+) -> Result<ImportConfig> {
+    match keyword {
+        "only" => parse_only_config(arguments),
+        "except" => parse_except_config(arguments),
+        "rename" => parse_rename_config(arguments),
+        "prefix" => parse_prefix_config(arguments),
+        _ => Err(Box::new(Error::syntax_error(
+            format!("Unknown import keyword: {keyword}"),
+            Some(span),
+        ))),
+    }
+}
+
+/// Returns the elements of a compound form regardless of how the parser
+/// represented it (plain list or application).
+pub(crate) fn form_elements(expr: &Spanned<Expr>) -> Option<Vec<Spanned<Expr>>> {
+    match &expr.inner {
+        Expr::List(elements) => Some(elements.clone()),
+        Expr::Application { operator, operands } => {
+            let mut elements = Vec::with_capacity(operands.len() + 1);
+            elements.push(operator.as_ref().clone());
+            elements.extend(operands.iter().cloned());
+            Some(elements)
+        }
+        _ => None,
+    }
+}
+
+/// Extracts an identifier name, accepting both identifiers and quoted symbols.
+fn identifier_name(expr: &Spanned<Expr>) -> Option<String> {
+    match &expr.inner {
+        Expr::Identifier(name) | Expr::Symbol(name) => Some(name.clone()),
+        _ => None,
+    }
+}
+
 /// Extracts module name from an expression.
 fn extract_module_name(expr: &Spanned<Expr>) -> Result<String> {
     use crate::ast::Expr;
     
-    match &expr.inner {
-        Expr::List(elements) => {
-            // Convert list of symbols to module name string
-            let mut parts = Vec::new();
-            for element in elements {
-                match &element.inner {
-                    Expr::Symbol(symbol) => parts.push(symbol.clone()),
-                    _ => return Err(Box::new(Error::syntax_error(
-                        "Module name must contain only symbols".to_string(),
-                        Some(element.span),
-                    ))),
-                }
+    if let Some(elements) = form_elements(expr) {
+        // Convert list of symbols to module name string
+        let mut parts = Vec::new();
+        for element in &elements {
+            match &element.inner {
+                Expr::Symbol(symbol) | Expr::Identifier(symbol) => parts.push(symbol.clone()),
+                _ => return Err(Box::new(Error::syntax_error(
+                    "Module name must contain only symbols".to_string(),
+                    Some(element.span),
+                ))),
             }
-            Ok(format!("({})", parts.join(" ")))
         }
+        return Ok(format!("({})", parts.join(" ")));
+    }
+
+    match &expr.inner {
         Expr::Symbol(symbol) => {
             // Single symbol module name
             Ok(format!("({symbol})"))
@@ -158,39 +270,31 @@ fn extract_module_name(expr: &Spanned<Expr>) -> Result<String> {
 fn parse_import_config(config_forms: &[Spanned<Expr>]) -> Result<ImportConfig> {
     use crate::ast::Expr;
     
-    if config_forms.len() != 1 {
-        return Err(Box::new(Error::syntax_error(
-            "Import configuration must be a single form".to_string(),
-            None,
-        )));
-    }
+    // Several configuration forms are applied left to right, so
+    // `(only a b) (prefix p:)` keeps `a` and `b` and then prefixes them.
+    let mut config = ImportConfig::All;
+    
+    for config_form in config_forms {
+        let elements = match form_elements(config_form) {
+            Some(elements) if !elements.is_empty() => elements,
+            _ => return Err(Box::new(Error::syntax_error(
+                "Import configuration must be a list".to_string(),
+                Some(config_form.span),
+            ))),
+        };
 
-    match &config_forms[0].inner {
-        Expr::List(elements) if !elements.is_empty() => {
-            match &elements[0].inner {
-                Expr::Symbol(keyword) => {
-                    match keyword.as_str() {
-                        "only" => parse_only_config(&elements[1..]),
-                        "except" => parse_except_config(&elements[1..]),
-                        "rename" => parse_rename_config(&elements[1..]),
-                        "prefix" => parse_prefix_config(&elements[1..]),
-                        _ => Err(Box::new(Error::syntax_error(
-                            format!("Unknown import keyword: {keyword}"),
-                            Some(elements[0].span),
-                        ))),
-                    }
-                }
-                _ => Err(Box::new(Error::syntax_error(
-                    "Import configuration must start with a keyword".to_string(),
-                    Some(elements[0].span),
-                ))),
-            }
-        }
-        _ => Err(Box::new(Error::syntax_error(
-            "Import configuration must be a list".to_string(),
-            Some(config_forms[0].span),
-        ))),
+        let keyword = identifier_name(&elements[0]).ok_or_else(|| {
+            Box::new(Error::syntax_error(
+                "Import configuration must start with a keyword".to_string(),
+                Some(elements[0].span),
+            ))
+        })?;
+
+        let modifier = parse_import_modifier(&keyword, &elements[1..], elements[0].span)?;
+        config = config.then(modifier);
     }
+    
+    Ok(config)
 }
 
 /// Parses 'only' import configuration.
@@ -198,9 +302,9 @@ fn parse_only_config(elements: &[Spanned<Expr>]) -> Result<ImportConfig> {
     let mut symbols = Vec::new();
     
     for element in elements {
-        match &element.inner {
-            Expr::Identifier(symbol) => symbols.push(symbol.clone()),
-            _ => return Err(Box::new(Error::syntax_error(
+        match identifier_name(element) {
+            Some(symbol) => symbols.push(symbol),
+            None => return Err(Box::new(Error::syntax_error(
                 "Only configuration must contain only symbols".to_string(),
                 Some(element.span),
             ))),
@@ -215,9 +319,9 @@ fn parse_except_config(elements: &[Spanned<Expr>]) -> Result<ImportConfig> {
     let mut symbols = Vec::new();
     
     for element in elements {
-        match &element.inner {
-            Expr::Identifier(symbol) => symbols.push(symbol.clone()),
-            _ => return Err(Box::new(Error::syntax_error(
+        match identifier_name(element) {
+            Some(symbol) => symbols.push(symbol),
+            None => return Err(Box::new(Error::syntax_error(
                 "Except configuration must contain only symbols".to_string(),
                 Some(element.span),
             ))),
@@ -233,6 +337,17 @@ fn parse_rename_config(elements: &[Spanned<Expr>]) -> Result<ImportConfig> {
     
     for element in elements {
         match &element.inner {
+            Expr::List(pair) if pair.len() == 2 => {
+                match (identifier_name(&pair[0]), identifier_name(&pair[1])) {
+                    (Some(original), Some(new_name)) => {
+                        rename_map.insert(original, new_name);
+                    }
+                    _ => return Err(Box::new(Error::syntax_error(
+                        "Rename pair must contain symbols".to_string(),
+                        Some(element.span),
+                    ))),
+                }
+            }
             Expr::Application { operator, operands } if operands.len() == 1 => {
                 let original = match &operator.inner {
                     Expr::Identifier(symbol) => symbol.clone(),
@@ -290,9 +405,9 @@ fn parse_prefix_config(elements: &[Spanned<Expr>]) -> Result<ImportConfig> {
         )));
     }
     
-    match &elements[0].inner {
-        Expr::Identifier(prefix) => Ok(ImportConfig::Prefix(prefix.clone())),
-        _ => Err(Box::new(Error::syntax_error(
+    match identifier_name(&elements[0]) {
+        Some(prefix) => Ok(ImportConfig::Prefix(prefix)),
+        None => Err(Box::new(Error::syntax_error(
             "Prefix must be a symbol".to_string(),
             Some(elements[0].span),
         ))),
@@ -339,6 +454,14 @@ pub fn validate_import_spec(spec: &ImportSpec) -> Result<()> {
                 )));
             }
         }
+        ImportConfig::Chain(configs) => {
+            for config in configs {
+                validate_import_spec(&ImportSpec {
+                    module_id: spec.module_id.clone(),
+                    config: config.clone(),
+                })?;
+            }
+        }
         ImportConfig::All => {
             // No validation needed for 'all' imports
         }
@@ -493,4 +616,136 @@ mod tests {
         assert_eq!(result.len(), 1);
         assert_eq!(result.get("a"), Some(&Value::integer(1)));
     }
-}
\ No newline at end of file
+
+    fn ident(name: &str) -> Spanned<Expr> {
+        Spanned::new(Expr::Identifier(name.to_string()), crate::diagnostics::Span::new(0, 0))
+    }
+
+    fn form(elements: Vec<Spanned<Expr>>) -> Spanned<Expr> {
+        Spanned::new(Expr::List(elements), crate::diagnostics::Span::new(0, 0))
+    }
+
+    #[test]
+    fn test_apply_rename_import_keeps_unrenamed_symbols() {
+        let mut exports = HashMap::new();
+        exports.insert("foo".to_string(), Value::integer(1));
+        exports.insert("bar".to_string(), Value::integer(2));
+
+        let mut rename_map = HashMap::new();
+        rename_map.insert("foo".to_string(), "my-foo".to_string());
+
+        let result = apply_rename_import(&exports, &rename_map).unwrap();
+
+        assert_eq!(result.len(), 2);
+        assert_eq!(result.get("my-foo"), Some(&Value::integer(1)));
+        assert_eq!(result.get("bar"), Some(&Value::integer(2)));
+        assert!(!result.contains_key("foo"));
+    }
+
+    #[test]
+    fn test_parse_nested_only_prefix_import_set() {
+        // (prefix (only (my lib) foo) my:)
+        let import_set = form(vec![
+            ident("prefix"),
+            form(vec![
+                ident("only"),
+                form(vec![ident("my"), ident("lib")]),
+                ident("foo"),
+            ]),
+            ident("my:"),
+        ]);
+
+        let spec = parse_import_set(&import_set).unwrap();
+        assert_eq!(spec.module_id.components, vec!["my".to_string(), "lib".to_string()]);
+
+        let mut exports = HashMap::new();
+        exports.insert("foo".to_string(), Value::integer(1));
+        exports.insert("bar".to_string(), Value::integer(2));
+
+        let result = apply_import_config(&exports, &spec.config).unwrap();
+
+        assert_eq!(result.len(), 1);
+        assert_eq!(result.get("my:foo"), Some(&Value::integer(1)));
+        assert!(!result.contains_key("foo"));
+        assert!(!result.contains_key("my:bar"));
+    }
+
+    #[test]
+    fn test_parse_import_spec_with_multiple_modifiers() {
+        // (import (my lib) (only foo bar) (prefix my:))
+        let import_form = vec![
+            form(vec![ident("my"), ident("lib")]),
+            form(vec![ident("only"), ident("foo"), ident("bar")]),
+            form(vec![ident("prefix"), ident("my:")]),
+        ];
+
+        let spec = parse_import_spec(&import_form).unwrap();
+        validate_import_spec(&spec).unwrap();
+
+        let mut exports = HashMap::new();
+        exports.insert("foo".to_string(), Value::integer(1));
+        exports.insert("bar".to_string(), Value::integer(2));
+        exports.insert("baz".to_string(), Value::integer(3));
+
+        let result = apply_import_config(&exports, &spec.config).unwrap();
+
+        assert_eq!(result.len(), 2);
+        assert!(result.contains_key("my:foo"));
+        assert!(result.contains_key("my:bar"));
+    }
+
+    #[test]
+    fn test_rename_on_export_and_import() {
+        // Library side: (export (rename (internal-foo foo)))
+        let export_spec = crate::module_system::export::parse_export_spec(&[form(vec![
+            ident("rename"),
+            form(vec![ident("internal-foo"), ident("foo")]),
+        ])])
+        .unwrap();
+
+        let mut bindings = HashMap::new();
+        bindings.insert("internal-foo".to_string(), Value::integer(7));
+        let exports = crate::module_system::export::apply_export_config(
+            &bindings,
+            &export_spec.config,
+            &export_spec.symbols,
+        )
+        .unwrap();
+        assert!(exports.contains_key("foo"));
+        assert!(!exports.contains_key("internal-foo"));
+
+        // Importer side: (rename (my lib) (foo bar))
+        let import_set = form(vec![
+            ident("rename"),
+            form(vec![ident("my"), ident("lib")]),
+            form(vec![ident("foo"), ident("bar")]),
+        ]);
+        let spec = parse_import_set(&import_set).unwrap();
+        let result = apply_import_config(&exports, &spec.config).unwrap();
+
+        assert_eq!(result.len(), 1);
+        assert_eq!(result.get("bar"), Some(&Value::integer(7)));
+    }
+
+    #[test]
+    fn test_conflicting_imports_resolved_by_except_or_rename() {
+        let mut lib_a = HashMap::new();
+        lib_a.insert("foo".to_string(), Value::integer(1));
+        let mut lib_b = HashMap::new();
+        lib_b.insert("foo".to_string(), Value::integer(2));
+        lib_b.insert("bar".to_string(), Value::integer(3));
+
+        assert!(merge_import_bindings(&[lib_a.clone(), lib_b.clone()]).is_err());
+
+        let excluded = apply_import_config(&lib_b, &ImportConfig::Except(vec!["foo".to_string()])).unwrap();
+        let merged = merge_import_bindings(&[lib_a.clone(), excluded]).unwrap();
+        assert_eq!(merged.get("foo"), Some(&Value::integer(1)));
+
+        let mut rename_map = HashMap::new();
+        rename_map.insert("foo".to_string(), "b-foo".to_string());
+        let renamed = apply_import_config(&lib_b, &ImportConfig::Rename(rename_map)).unwrap();
+        let merged = merge_import_bindings(&[lib_a, renamed]).unwrap();
+        assert_eq!(merged.len(), 3);
+        assert_eq!(merged.get("b-foo"), Some(&Value::integer(2)));
+    }
+}
@@ -23,4 +23,31 @@ pub enum ImportConfig {
     Rename(HashMap<String, String>),
     /// Add prefix to all imported symbols
     Prefix(String),
+    /// Apply several configurations in order, innermost first
+    /// (e.g. `(prefix (only (my lib) foo) my:)`)
+    Chain(Vec<ImportConfig>),
+}
+
+impl ImportConfig {
+    /// Composes this configuration with one applied after it.
+    pub fn then(self, next: ImportConfig) -> ImportConfig {
+        match (self, next) {
+            (ImportConfig::All, next) => next,
+            (current, ImportConfig::All) => current,
+            (ImportConfig::Chain(mut configs), ImportConfig::Chain(rest)) => {
+                configs.extend(rest);
+                ImportConfig::Chain(configs)
+            }
+            (ImportConfig::Chain(mut configs), next) => {
+                configs.push(next);
+                ImportConfig::Chain(configs)
+            }
+            (current, ImportConfig::Chain(rest)) => {
+                let mut configs = vec![current];
+                configs.extend(rest);
+                ImportConfig::Chain(configs)
+            }
+            (current, next) => ImportConfig::Chain(vec![current, next]),
+        }
+    }
 }
\ No newline at end of file
@@ -67,6 +67,18 @@ impl ModuleLoader {
         }
     }
 
+    /// Checks whether a module can be found, without registering it.
+    pub fn is_available(&self, id: &ModuleId) -> bool {
+        let found = match id.namespace {
+            ModuleNamespace::Builtin => self.load_builtin_module(id),
+            ModuleNamespace::R7RS => self.load_r7rs_module(id),
+            ModuleNamespace::SRFI => self.load_srfi_module(id),
+            ModuleNamespace::User => self.load_user_module(id),
+            ModuleNamespace::File => self.load_file_module(id),
+        };
+        found.is_ok()
+    }
+
     /// Adds a search path for user modules.
     pub fn add_search_path<P: AsRef<Path>>(&mut self, path: P) {
         self.search_paths.push(path.as_ref().to_path_buf());
@@ -516,4 +528,13 @@ mod tests {
         loader.add_search_path(&test_path);
         assert!(loader.search_paths.contains(&test_path));
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_module_availability() {
+        let loader = ModuleLoader::new().unwrap();
+        
+        assert!(loader.is_available(&name::builtin_module("string")));
+        assert!(!loader.is_available(&name::r7rs_module("no-such-library")));
+        assert!(!loader.is_available(&name::parse_module_name("(no-such user-library)").unwrap()));
+    }
+}
@@ -276,6 +276,11 @@ impl SchemeLibraryLoader {
         self.search_paths.push(path.as_ref().to_path_buf());
     }
 
+    /// Checks whether `load_library` would find a source file for a library.
+    pub fn is_available(&self, id: &ModuleId) -> bool {
+        matches!(self.find_library_source(id), Ok(Some(path)) if path.is_file())
+    }
+
     /// Loads a Scheme library, compiling if necessary.
     pub fn load_library(&self, id: &ModuleId) -> Result<CompiledSchemeLibrary> {
         // Check cache first
@@ -3,13 +3,14 @@
 //! Provides unlimited precision integer arithmetic with optimized algorithms
 //! for common operations including multiplication, division, and modular arithmetic.
 
+use serde::{Deserialize, Serialize};
 use std::cmp::Ordering;
 use std::fmt;
 use std::hash::{Hash, Hasher};
 use std::ops::{Add, Sub, Mul, Div, Rem, Neg, Shl, Shr};
 
 /// Arbitrary precision integer
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct BigInt {
     /// Digits stored in little-endian order (least significant first)
     /// Each digit is a u32 to allow for efficient multiplication
@@ -59,6 +60,35 @@ impl BigInt {
         Self { digits, positive: true }
     }
 
+    /// Creates a BigInt holding the exact value of an integral float.
+    ///
+    /// Returns `None` for NaN, infinities and values with a fractional part.
+    pub fn from_f64(value: f64) -> Option<Self> {
+        if !value.is_finite() || value.fract() != 0.0 {
+            return None;
+        }
+        if value == 0.0 {
+            return Some(Self::zero());
+        }
+
+        // value = mantissa * 2^exponent with a 53-bit mantissa
+        let bits = value.abs().to_bits();
+        let biased_exponent = ((bits >> 52) & 0x7ff) as i64;
+        let fraction = bits & ((1u64 << 52) - 1);
+        let (mantissa, exponent) = if biased_exponent == 0 {
+            (fraction, -1074)
+        } else {
+            (fraction | (1u64 << 52), biased_exponent - 1075)
+        };
+
+        let magnitude = if exponent >= 0 {
+            Self::from_u64(mantissa) << exponent as usize
+        } else {
+            Self::from_u64(mantissa) >> (-exponent) as usize
+        };
+        Some(if value < 0.0 { -magnitude } else { magnitude })
+    }
+
     /// Creates a BigInt from a string in the given radix (2-36)
     pub fn from_str_radix(s: &str, radix: u32) -> Result<Self, String> {
         if !(2..=36).contains(&radix) {
@@ -339,19 +369,28 @@ impl BigInt {
     fn long_division(&self, divisor: &Self) -> (Self, Self) {
         let mut quotient = Self::zero();
         let mut remainder = Self::zero();
+        let divisor_abs = divisor.abs();
 
         for &digit in self.digits.iter().rev() {
             remainder = remainder.multiply(&Self::from_u64(BASE));
             remainder = remainder.abs_add(&Self::from_u64(digit as u64));
 
-            let mut digit_quotient = 0u32;
-            while remainder.abs_cmp(&divisor.abs()) != Ordering::Less {
-                remainder = remainder.abs_sub(&divisor.abs());
-                digit_quotient += 1;
+            // Binary search for the largest digit q with divisor * q <= remainder
+            let (mut low, mut high) = (0u64, BASE_MASK);
+            while low < high {
+                let mid = low + (high - low).div_ceil(2);
+                if divisor_abs.multiply(&Self::from_u64(mid)).abs_cmp(&remainder) == Ordering::Greater {
+                    high = mid - 1;
+                } else {
+                    low = mid;
+                }
+            }
+            if low > 0 {
+                remainder = remainder.abs_sub(&divisor_abs.multiply(&Self::from_u64(low)));
             }
 
             quotient = quotient.multiply(&Self::from_u64(BASE));
-            quotient = quotient.abs_add(&Self::from_u64(digit_quotient as u64));
+            quotient = quotient.abs_add(&Self::from_u64(low));
         }
 
         quotient.positive = self.positive == divisor.positive;
@@ -388,6 +427,36 @@ impl BigInt {
         result
     }
 
+    /// Integer square root: the largest `r` with `r * r <= self`, or None when negative
+    ///
+    /// Uses Newton's method on integers only, so the floor is exact at any size.
+    pub fn isqrt(&self) -> Option<Self> {
+        if self.is_negative() {
+            return None;
+        }
+        if self.is_zero() {
+            return Some(Self::zero());
+        }
+
+        // 2^ceil(bits/2) is at or above the root, and from above the
+        // iteration decreases strictly until it reaches the floor root
+        let mut x = Self::one() << self.bits().div_ceil(2);
+        loop {
+            let next = (&x + &(self / &x)) >> 1;
+            if next >= x {
+                return Some(x);
+            }
+            x = next;
+        }
+    }
+
+    /// Exact integer square root: `(s, r)` with `s * s + r == self`, or None when negative
+    pub fn exact_integer_sqrt(&self) -> Option<(Self, Self)> {
+        let root = self.isqrt()?;
+        let remainder = self - &(&root * &root);
+        Some((root, remainder))
+    }
+
     /// GCD using Euclidean algorithm
     pub fn gcd(&self, other: &Self) -> Self {
         let mut a = self.abs();
@@ -725,6 +794,16 @@ impl Hash for BigInt {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_bigint_from_f64() {
+        assert_eq!(BigInt::from_f64(0.0), Some(BigInt::zero()));
+        assert_eq!(BigInt::from_f64(-42.0), Some(BigInt::from_i64(-42)));
+        assert_eq!(BigInt::from_f64(1e20).unwrap().to_string(), "100000000000000000000");
+        assert_eq!(BigInt::from_f64(-(2f64.powi(70))).unwrap().to_string(), "-1180591620717411303424");
+        assert_eq!(BigInt::from_f64(2.5), None);
+        assert_eq!(BigInt::from_f64(f64::NAN), None);
+    }
+
     #[test]
     fn test_bigint_creation() {
         let zero = BigInt::zero();
@@ -778,6 +857,20 @@ mod tests {
         assert_eq!(sum, expected);
     }
 
+    #[test]
+    fn test_bigint_large_division() {
+        let dividend = BigInt::from_str_radix("1234567890123456789012345678901234567890", 10).unwrap();
+
+        let (quotient, remainder) = dividend.div_rem(&BigInt::from_i64(7));
+        assert_eq!(quotient.to_string(), "176366841446208112716049382700176366841");
+        assert_eq!(remainder.to_i64(), Some(3));
+
+        let divisor = BigInt::from_str_radix("-98765432109876543210", 10).unwrap();
+        let (quotient, remainder) = dividend.div_rem(&divisor);
+        assert_eq!(quotient.to_string(), "-12499999886093750001");
+        assert_eq!(remainder.to_string(), "54205246805420524680");
+    }
+
     #[test]
     fn test_bigint_bit_operations() {
         let num = BigInt::from_i64(5); // 101 in binary
@@ -796,6 +889,39 @@ mod tests {
         assert_eq!(gcd.to_i64(), Some(6));
     }
 
+    #[test]
+    fn test_bigint_isqrt() {
+        assert_eq!(BigInt::zero().isqrt(), Some(BigInt::zero()));
+        assert_eq!(BigInt::one().isqrt(), Some(BigInt::one()));
+        assert_eq!(BigInt::from_i64(-4).isqrt(), None);
+        for n in 0..2000i64 {
+            let root = BigInt::from_i64(n).isqrt().unwrap().to_i64().unwrap();
+            assert!(root * root <= n && (root + 1) * (root + 1) > n, "isqrt({n}) = {root}");
+        }
+
+        // (10^100 + 7)^2 and its neighbours
+        let root = &BigInt::from_str_radix(&format!("1{}", "0".repeat(100)), 10).unwrap() + &BigInt::from_i64(7);
+        let square = &root * &root;
+        assert_eq!(square.exact_integer_sqrt(), Some((root.clone(), BigInt::zero())));
+
+        let below = &square - &BigInt::one();
+        let root_below = &root - &BigInt::one();
+        let expected_remainder = &below - &(&root_below * &root_below);
+        assert_eq!(below.exact_integer_sqrt(), Some((root_below, expected_remainder)));
+
+        let above = &square + &(&root + &root);
+        assert_eq!(above.exact_integer_sqrt(), Some((root.clone(), &root + &root)));
+    }
+
+    #[test]
+    fn test_bigint_isqrt_beyond_f64_precision() {
+        // 2^53 + 1 squared is where a float square root rounds the wrong way
+        let root = BigInt::from_i64((1 << 53) + 1);
+        let square = &root * &root;
+        assert_eq!((&square - &BigInt::one()).isqrt(), Some(&root - &BigInt::one()));
+        assert_eq!(square.isqrt(), Some(root));
+    }
+
     #[test]
     fn test_bigint_display() {
         assert_eq!(format!("{}", BigInt::zero()), "0");
@@ -101,8 +101,8 @@ fn demo_rational_numbers() -> Result<()> {
     println!("π = {:.10} (actual)", std::f64::consts::PI);
     
     // Rational arithmetic
-    println!("{} + {} = {}", r1, r2, r1 + r2);
-    println!("{} * {} = {}", r1, r2, r1 * r2);
+    println!("{} + {} = {}", r1, r2, &r1 + &r2);
+    println!("{} * {} = {}", r1, r2, &r1 * &r2);
     
     // Continued fraction representation
     let cf = r1.to_continued_fraction(10);
@@ -216,11 +216,24 @@ impl NumericValue {
         }
     }
 
+    /// Checks if this number is rational (`rational?`)
+    ///
+    /// Exact numbers are rational, as are finite reals; NaN and the
+    /// infinities are real but not rational.
+    pub fn is_rational(&self) -> bool {
+        match self {
+            Self::Integer(_) | Self::BigInteger(_) | Self::Rational(_) => true,
+            Self::Real(r) => r.is_finite(),
+            Self::Complex(c) => c.imaginary == 0.0 && c.real.is_finite(),
+            Self::Vector(v) => v.iter().all(|x| x.is_rational()),
+        }
+    }
+
     /// Checks if this number is an integer
     pub fn is_integer(&self) -> bool {
         match self {
             Self::Integer(_) | Self::BigInteger(_) => true,
-            Self::Rational(r) => r.denominator == 1,
+            Self::Rational(r) => r.is_integer(),
             Self::Real(r) => r.fract() == 0.0 && r.is_finite(),
             Self::Complex(c) => c.imaginary == 0.0 && c.real.fract() == 0.0 && c.real.is_finite(),
             Self::Vector(v) => v.iter().all(|x| x.is_integer()),
@@ -232,7 +245,7 @@ impl NumericValue {
         match self {
             Self::Integer(n) => *n == 0,
             Self::BigInteger(n) => n.is_zero(),
-            Self::Rational(r) => r.numerator == 0,
+            Self::Rational(r) => r.is_zero(),
             Self::Real(r) => *r == 0.0,
             Self::Complex(c) => c.real == 0.0 && c.imaginary == 0.0,
             Self::Vector(v) => v.iter().all(|x| x.is_zero()),
@@ -281,7 +294,7 @@ impl NumericValue {
         match self {
             Self::Integer(n) => Some(*n),
             Self::BigInteger(n) => n.to_i64(),
-            Self::Rational(r) if r.denominator == 1 => Some(r.numerator),
+            Self::Rational(r) => r.to_i64(),
             Self::Real(r) if r.fract() == 0.0 && r.is_finite() => {
                 let i = *r as i64;
                 if i as f64 == *r { Some(i) } else { None }
@@ -323,9 +336,10 @@ impl NumericValue {
                     Literal::InexactReal(n.to_f64().unwrap_or(f64::INFINITY))
                 }
             }
-            Self::Rational(r) => Literal::Rational {
-                numerator: r.numerator,
-                denominator: r.denominator,
+            Self::Rational(r) => match r.to_i64_parts() {
+                Some((numerator, denominator)) => Literal::Rational { numerator, denominator },
+                // Rationals with big components only have an inexact literal form
+                None => Literal::InexactReal(r.to_f64()),
             },
             Self::Real(r) => Literal::InexactReal(*r),
             Self::Complex(c) => Literal::Complex {
@@ -467,6 +481,89 @@ impl NumericValue {
         }
     }
 
+    /// SIMD-accelerated sum of a numeric vector.
+    ///
+    /// Inexact vectors whose elements all convert to f64 are summed as reals.
+    /// Exact vectors, and those that do not convert (e.g. containing complex
+    /// numbers), fall back to tower addition, so exact sums stay exact.
+    pub fn simd_sum(&self) -> Result<Self, String> {
+        match self {
+            Self::Vector(v) => match self.inexact_f64_vector() {
+                Some(values) => {
+                    let simd_ops_arc = get_simd_ops();
+                    let mut simd_ops = simd_ops_arc.lock()
+                        .map_err(|_| "Failed to acquire SIMD lock")?;
+                    Ok(Self::Real(simd_ops.sum_f64(&values)))
+                }
+                None => v.iter().try_fold(Self::Integer(0), |acc, x| acc.add(x)),
+            },
+            _ => Err("Cannot perform SIMD sum on a non-vector".to_string())
+        }
+    }
+
+    /// SIMD-accelerated minimum of a numeric vector.
+    ///
+    /// A NaN element makes the result NaN. Exact vectors and those that do
+    /// not convert to f64 are compared with the numeric tower, which returns
+    /// the element itself, and fail if unordered.
+    pub fn simd_min(&self) -> Result<Self, String> {
+        self.simd_extremum(true)
+    }
+
+    /// SIMD-accelerated maximum of a numeric vector.
+    ///
+    /// Follows the same rules as [`simd_min`](Self::simd_min).
+    pub fn simd_max(&self) -> Result<Self, String> {
+        self.simd_extremum(false)
+    }
+
+    /// Shared implementation of [`simd_min`](Self::simd_min) and [`simd_max`](Self::simd_max)
+    fn simd_extremum(&self, take_min: bool) -> Result<Self, String> {
+        let operation = if take_min { "min" } else { "max" };
+        match self {
+            Self::Vector(v) if v.is_empty() => {
+                Err(format!("Cannot perform SIMD {operation} on an empty vector"))
+            }
+            Self::Vector(v) => match self.inexact_f64_vector() {
+                Some(values) => {
+                    let simd_ops_arc = get_simd_ops();
+                    let mut simd_ops = simd_ops_arc.lock()
+                        .map_err(|_| "Failed to acquire SIMD lock")?;
+                    let result = if take_min {
+                        simd_ops.min_f64(&values)
+                    } else {
+                        simd_ops.max_f64(&values)
+                    };
+                    result.map(Self::Real)
+                        .ok_or_else(|| format!("Cannot perform SIMD {operation} on an empty vector"))
+                }
+                None => {
+                    let mut best = &v[0];
+                    for x in &v[1..] {
+                        match crate::numeric::tower::compare(x, best) {
+                            Some(std::cmp::Ordering::Less) if take_min => best = x,
+                            Some(std::cmp::Ordering::Greater) if !take_min => best = x,
+                            Some(_) => {}
+                            None => return Err(format!("Cannot order vector elements for {operation}")),
+                        }
+                    }
+                    Ok(best.clone())
+                }
+            },
+            _ => Err(format!("Cannot perform SIMD {operation} on a non-vector"))
+        }
+    }
+
+    /// Extracts f64 values from a numeric vector with an inexact element,
+    /// the only vectors the SIMD reductions may compute in floating point
+    fn inexact_f64_vector(&self) -> Option<Vec<f64>> {
+        if self.is_inexact() {
+            self.to_f64_vector()
+        } else {
+            None
+        }
+    }
+
     /// Extracts f64 values from a numeric vector if possible
     pub fn to_f64_vector(&self) -> Option<Vec<f64>> {
         match self {
@@ -500,19 +597,30 @@ impl NumericValue {
     }
 }
 
+/// Formats an inexact real in the external syntax `string->number` reads.
+///
+/// Finite values use the shortest digits that round-trip, keeping a decimal
+/// point or exponent so the result reads back inexact; `-0.0` keeps its sign.
+/// Infinities and NaN use `+inf.0`, `-inf.0` and `+nan.0`.
+pub fn format_real(r: f64) -> String {
+    if r.is_nan() {
+        "+nan.0".to_string()
+    } else if r.is_infinite() {
+        if r.is_sign_positive() { "+inf.0" } else { "-inf.0" }.to_string()
+    } else {
+        // Debug output is shortest round-trip and switches to an exponent
+        // for very large and very small magnitudes
+        format!("{r:?}")
+    }
+}
+
 impl fmt::Display for NumericValue {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Self::Integer(n) => write!(f, "{n}"),
             Self::BigInteger(n) => write!(f, "{n}"),
             Self::Rational(r) => write!(f, "{r}"),
-            Self::Real(r) => {
-                if r.fract() == 0.0 && r.is_finite() {
-                    write!(f, "{}.0", *r as i64)
-                } else {
-                    write!(f, "{r}")
-                }
-            }
+            Self::Real(r) => write!(f, "{}", format_real(*r)),
             Self::Complex(c) => write!(f, "{c}"),
             Self::Vector(v) => {
                 write!(f, "#(")?;
@@ -546,6 +654,19 @@ impl Hash for NumericValue {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_format_real_round_trips() {
+        for r in [0.1, 1.0 / 3.0, 2.0, 1e300, 1e-300, 5e-324, 2.2250738585072014e-308, f64::MAX, 123456789.125] {
+            let text = format_real(r);
+            assert_eq!(text.parse::<f64>().unwrap(), r, "{text}");
+        }
+        assert_eq!(format_real(0.1), "0.1");
+        assert_eq!(format_real(2.0), "2.0");
+        assert_eq!(format_real(-0.0), "-0.0");
+        assert_eq!(format_real(f64::NEG_INFINITY), "-inf.0");
+        assert_eq!(NumericValue::real(1e300).to_string(), "1e300");
+    }
+
     #[test]
     fn test_numeric_value_creation() {
         let int_val = NumericValue::integer(42);
@@ -582,6 +703,38 @@ mod tests {
         assert!(!complex_val.is_integer());
     }
 
+    #[test]
+    fn test_simd_reductions() {
+        let values: Vec<f64> = (0..1024).map(|i| ((i * 37) % 1024) as f64 - 512.0).collect();
+        let vector = NumericValue::real_vector(values.clone());
+
+        let expected_sum: f64 = values.iter().sum();
+        match vector.simd_sum().unwrap() {
+            NumericValue::Real(sum) => assert!((sum - expected_sum).abs() < 1e-9),
+            other => panic!("expected real sum, got {other:?}"),
+        }
+        assert_eq!(vector.simd_min().unwrap(), NumericValue::Real(-512.0));
+        assert_eq!(vector.simd_max().unwrap(), NumericValue::Real(511.0));
+
+        // Vectors that do not convert to f64 use the exact scalar fallback
+        let mixed = NumericValue::Vector(vec![
+            NumericValue::integer(1),
+            NumericValue::complex(0.0, 1.0),
+        ]);
+        assert_eq!(mixed.simd_sum().unwrap(), NumericValue::integer(1).add(&NumericValue::complex(0.0, 1.0)).unwrap());
+        assert!(mixed.simd_min().is_err());
+
+        // Exact vectors keep exact results
+        let exact = NumericValue::Vector((1..=10).map(NumericValue::integer).collect());
+        assert_eq!(exact.simd_sum().unwrap(), NumericValue::integer(55));
+        assert_eq!(exact.simd_min().unwrap(), NumericValue::integer(1));
+        assert_eq!(exact.simd_max().unwrap(), NumericValue::integer(10));
+        assert_eq!(NumericValue::Vector(vec![]).simd_sum().unwrap(), NumericValue::integer(0));
+
+        assert!(NumericValue::Vector(vec![]).simd_max().is_err());
+        assert!(NumericValue::integer(3).simd_sum().is_err());
+    }
+
     #[test]
     fn test_literal_conversion() {
         let lit = Literal::Rational { numerator: 3, denominator: 4 };
@@ -292,10 +292,7 @@ impl Rational {
         }
 
         if numerator == 0 {
-            return Self {
-                numerator: 0,
-                denominator: 1,
-            };
+            return Self::ZERO;
         }
 
         let gcd = Self::binary_gcd(numerator.abs(), denominator.abs());
@@ -307,7 +304,7 @@ impl Rational {
             den = -den;
         }
 
-        Self {
+        Self::Small {
             numerator: num,
             denominator: den,
         }
@@ -1,38 +1,97 @@
 //! Rational number implementation with optimized arithmetic
 //!
 //! Provides exact rational arithmetic using GCD-based reduction and
-//! optimized algorithms for common operations.
+//! optimized algorithms for common operations. Components are machine
+//! integers until a result no longer fits in an `i64`, at which point the
+//! rational is promoted to arbitrary-precision components.
 
+use super::BigInt;
 use std::cmp::Ordering;
 use std::fmt;
-use std::hash::{Hash, Hasher};
 use std::ops::{Add, Sub, Mul, Div, Neg};
 
 /// Exact rational number representation
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub struct Rational {
-    /// Numerator of the rational number
-    pub numerator: i64,
-    /// Denominator of the rational number (never zero)
-    pub denominator: i64,
+///
+/// Rationals are kept reduced with a positive denominator, and use the
+/// `Small` representation whenever both components fit in an `i64`, so equal
+/// rationals always have equal representations.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Rational {
+    /// Components that fit in machine integers
+    Small {
+        /// Numerator of the rational number
+        numerator: i64,
+        /// Denominator of the rational number (always positive)
+        denominator: i64,
+    },
+    /// Components promoted to arbitrary precision after an i64 overflow
+    Big {
+        /// Numerator of the rational number
+        numerator: BigInt,
+        /// Denominator of the rational number (always positive)
+        denominator: BigInt,
+    },
 }
 
 impl Rational {
     /// Creates a new rational number with automatic reduction
     pub fn new(numerator: i64, denominator: i64) -> Self {
+        Self::from_i128(numerator as i128, denominator as i128)
+    }
+
+    /// Creates a rational from an integer
+    pub fn from_integer(n: i64) -> Self {
+        Self::Small {
+            numerator: n,
+            denominator: 1,
+        }
+    }
+
+    /// Creates a rational from a big integer
+    pub fn from_bigint(n: BigInt) -> Self {
+        Self::from_bigints(n, BigInt::one())
+    }
+
+    /// Creates a rational from big integer components with automatic
+    /// reduction, demoting to machine integers when the result fits
+    pub fn from_bigints(numerator: BigInt, denominator: BigInt) -> Self {
+        if denominator.is_zero() {
+            panic!("Rational number cannot have zero denominator");
+        }
+
+        if numerator.is_zero() {
+            return Self::ZERO;
+        }
+
+        let gcd = numerator.gcd(&denominator);
+        let mut num = &numerator / &gcd;
+        let mut den = &denominator / &gcd;
+
+        // Ensure denominator is positive
+        if den.is_negative() {
+            num = -num;
+            den = -den;
+        }
+
+        match (num.to_i64(), den.to_i64()) {
+            (Some(numerator), Some(denominator)) => Self::Small { numerator, denominator },
+            _ => Self::Big { numerator: num, denominator: den },
+        }
+    }
+
+    /// Creates a reduced rational from components computed in 128 bits, as
+    /// the products of two `i64` components always fit.
+    fn from_i128(numerator: i128, denominator: i128) -> Self {
         if denominator == 0 {
             panic!("Rational number cannot have zero denominator");
         }
 
         if numerator == 0 {
-            return Self {
-                numerator: 0,
-                denominator: 1,
-            };
+            return Self::ZERO;
         }
 
         // Reduce the fraction using GCD
-        let gcd = gcd(numerator.unsigned_abs(), denominator.unsigned_abs()) as i64;
+        let gcd = gcd_u128(numerator.unsigned_abs(), denominator.unsigned_abs()) as i128;
         let mut num = numerator / gcd;
         let mut den = denominator / gcd;
 
@@ -42,156 +101,208 @@ impl Rational {
             den = -den;
         }
 
-        Self {
-            numerator: num,
-            denominator: den,
-        }
-    }
-
-    /// Creates a rational from an integer
-    pub fn from_integer(n: i64) -> Self {
-        Self {
-            numerator: n,
-            denominator: 1,
+        match (i64::try_from(num), i64::try_from(den)) {
+            (Ok(numerator), Ok(denominator)) => Self::Small { numerator, denominator },
+            _ => Self::Big {
+                numerator: bigint_from_i128(num),
+                denominator: bigint_from_i128(den),
+            },
         }
     }
 
     /// Zero rational number
-    pub const ZERO: Self = Self {
+    pub const ZERO: Self = Self::Small {
         numerator: 0,
         denominator: 1,
     };
 
     /// One rational number
-    pub const ONE: Self = Self {
+    pub const ONE: Self = Self::Small {
         numerator: 1,
         denominator: 1,
     };
 
     /// Negative one rational number
-    pub const NEG_ONE: Self = Self {
+    pub const NEG_ONE: Self = Self::Small {
         numerator: -1,
         denominator: 1,
     };
 
     /// Half rational number
-    pub const HALF: Self = Self {
+    pub const HALF: Self = Self::Small {
         numerator: 1,
         denominator: 2,
     };
 
+    /// Returns the numerator and denominator when both fit in an `i64`
+    pub fn to_i64_parts(&self) -> Option<(i64, i64)> {
+        match self {
+            Self::Small { numerator, denominator } => Some((*numerator, *denominator)),
+            Self::Big { .. } => None,
+        }
+    }
+
+    /// Returns the numerator and denominator as big integers
+    pub fn to_bigints(&self) -> (BigInt, BigInt) {
+        match self {
+            Self::Small { numerator, denominator } => {
+                (BigInt::from_i64(*numerator), BigInt::from_i64(*denominator))
+            }
+            Self::Big { numerator, denominator } => (numerator.clone(), denominator.clone()),
+        }
+    }
+
+    /// Checks whether this rational has been promoted to big integer components
+    pub fn is_big(&self) -> bool {
+        matches!(self, Self::Big { .. })
+    }
+
     /// Checks if this rational is zero
     pub fn is_zero(&self) -> bool {
-        self.numerator == 0
+        match self {
+            Self::Small { numerator, .. } => *numerator == 0,
+            Self::Big { numerator, .. } => numerator.is_zero(),
+        }
     }
 
     /// Checks if this rational is positive
     pub fn is_positive(&self) -> bool {
-        self.numerator > 0
+        match self {
+            Self::Small { numerator, .. } => *numerator > 0,
+            Self::Big { numerator, .. } => numerator.is_positive(),
+        }
     }
 
     /// Checks if this rational is negative
     pub fn is_negative(&self) -> bool {
-        self.numerator < 0
+        match self {
+            Self::Small { numerator, .. } => *numerator < 0,
+            Self::Big { numerator, .. } => numerator.is_negative(),
+        }
     }
 
     /// Checks if this rational is an integer
     pub fn is_integer(&self) -> bool {
-        self.denominator == 1
+        match self {
+            Self::Small { denominator, .. } => *denominator == 1,
+            Self::Big { denominator, .. } => *denominator == BigInt::one(),
+        }
     }
 
     /// Returns the absolute value
     pub fn abs(&self) -> Self {
-        Self {
-            numerator: self.numerator.abs(),
-            denominator: self.denominator,
+        if self.is_negative() {
+            -self
+        } else {
+            self.clone()
         }
     }
 
     /// Returns the reciprocal
     pub fn reciprocal(&self) -> Self {
-        if self.numerator == 0 {
+        if self.is_zero() {
             panic!("Cannot compute reciprocal of zero");
         }
-        Self::new(self.denominator, self.numerator)
+        match self {
+            Self::Small { numerator, denominator } => Self::from_i128(*denominator as i128, *numerator as i128),
+            Self::Big { numerator, denominator } => Self::from_bigints(denominator.clone(), numerator.clone()),
+        }
     }
 
     /// Converts to floating point (with potential precision loss)
     pub fn to_f64(&self) -> f64 {
-        self.numerator as f64 / self.denominator as f64
+        match self {
+            Self::Small { numerator, denominator } => *numerator as f64 / *denominator as f64,
+            Self::Big { numerator, denominator } => {
+                // Drop low bits that f64 cannot hold so neither side overflows
+                let excess = numerator.bits().max(denominator.bits()).saturating_sub(f64::MAX_EXP as usize - 1);
+                let num = (numerator.clone() >> excess).to_f64().unwrap_or(f64::INFINITY);
+                let den = (denominator.clone() >> excess).to_f64().unwrap_or(f64::INFINITY);
+                num / den
+            }
+        }
     }
 
     /// Converts to integer if possible (exact integers only)
     pub fn to_i64(&self) -> Option<i64> {
-        if self.denominator == 1 {
-            Some(self.numerator)
-        } else {
-            None
+        match self {
+            Self::Small { numerator, denominator: 1 } => Some(*numerator),
+            _ => None,
         }
     }
 
-    /// Raises this rational to an integer power
+    /// Raises this rational to an integer power by repeated squaring
     pub fn powi(&self, exponent: i32) -> Self {
-        if exponent == 0 {
-            Self::ONE
-        } else if exponent > 0 {
-            Self {
-                numerator: self.numerator.pow(exponent as u32),
-                denominator: self.denominator.pow(exponent as u32),
+        let mut base = if exponent < 0 { self.reciprocal() } else { self.clone() };
+        let mut remaining = exponent.unsigned_abs();
+        let mut result = Self::ONE;
+        while remaining > 0 {
+            if remaining & 1 == 1 {
+                result = &result * &base;
             }
-        } else {
-            let abs_exp = (-exponent) as u32;
-            Self {
-                numerator: self.denominator.pow(abs_exp),
-                denominator: self.numerator.pow(abs_exp),
+            remaining >>= 1;
+            if remaining > 0 {
+                base = &base * &base;
             }
         }
+        result
+    }
+
+    /// Returns the quotient and remainder of the numerator by the
+    /// denominator, truncated towards zero
+    fn div_rem(&self) -> (BigInt, BigInt) {
+        let (numerator, denominator) = self.to_bigints();
+        numerator.div_rem(&denominator)
     }
 
     /// Returns the floor of this rational
-    pub fn floor(&self) -> i64 {
-        if self.numerator >= 0 {
-            self.numerator / self.denominator
+    pub fn floor(&self) -> BigInt {
+        let (quotient, remainder) = self.div_rem();
+        if remainder.is_negative() {
+            quotient - BigInt::one()
         } else {
-            (self.numerator - self.denominator + 1) / self.denominator
+            quotient
         }
     }
 
     /// Returns the ceiling of this rational
-    pub fn ceil(&self) -> i64 {
-        if self.numerator >= 0 {
-            (self.numerator + self.denominator - 1) / self.denominator
+    pub fn ceil(&self) -> BigInt {
+        let (quotient, remainder) = self.div_rem();
+        if remainder.is_positive() {
+            quotient + BigInt::one()
         } else {
-            self.numerator / self.denominator
+            quotient
         }
     }
 
     /// Returns the truncated value (towards zero)
-    pub fn trunc(&self) -> i64 {
-        self.numerator / self.denominator
+    pub fn trunc(&self) -> BigInt {
+        self.div_rem().0
     }
 
     /// Returns the fractional part
     pub fn fract(&self) -> Self {
-        let integer_part = self.trunc();
-        *self - Self::from_integer(integer_part)
+        self - &Self::from_bigint(self.trunc())
     }
 
     /// Continued fraction representation (partial)
+    ///
+    /// Stops early at a term that does not fit in an `i64`.
     pub fn to_continued_fraction(&self, max_terms: usize) -> Vec<i64> {
         let mut result = Vec::new();
-        let mut num = self.numerator;
-        let mut den = self.denominator;
+        let (mut num, mut den) = self.to_bigints();
 
         for _ in 0..max_terms {
-            if den == 0 {
+            if den.is_zero() {
                 break;
             }
 
-            let quotient = num / den;
-            result.push(quotient);
+            let (quotient, remainder) = num.div_rem(&den);
+            match quotient.to_i64() {
+                Some(term) => result.push(term),
+                None => break,
+            }
 
-            let remainder = num % den;
             num = den;
             den = remainder;
         }
@@ -216,10 +327,103 @@ impl Rational {
 
     /// Mediant of two rationals (used in Farey sequences)
     pub fn mediant(&self, other: &Self) -> Self {
-        Self::new(
-            self.numerator + other.numerator,
-            self.denominator + other.denominator,
-        )
+        let (n1, d1) = self.to_bigints();
+        let (n2, d2) = other.to_bigints();
+        Self::from_bigints(n1 + n2, d1 + d2)
+    }
+}
+
+impl Add for &Rational {
+    type Output = Rational;
+
+    fn add(self, other: &Rational) -> Rational {
+        // a/b + c/d = (ad + bc) / (bd)
+        match (self, other) {
+            (
+                Rational::Small { numerator: a, denominator: b },
+                Rational::Small { numerator: c, denominator: d },
+            ) => Rational::from_i128(
+                *a as i128 * *d as i128 + *c as i128 * *b as i128,
+                *b as i128 * *d as i128,
+            ),
+            _ => {
+                let ((a, b), (c, d)) = (self.to_bigints(), other.to_bigints());
+                Rational::from_bigints(&(&a * &d) + &(&c * &b), &b * &d)
+            }
+        }
+    }
+}
+
+impl Sub for &Rational {
+    type Output = Rational;
+
+    fn sub(self, other: &Rational) -> Rational {
+        // a/b - c/d = (ad - bc) / (bd)
+        match (self, other) {
+            (
+                Rational::Small { numerator: a, denominator: b },
+                Rational::Small { numerator: c, denominator: d },
+            ) => Rational::from_i128(
+                *a as i128 * *d as i128 - *c as i128 * *b as i128,
+                *b as i128 * *d as i128,
+            ),
+            _ => {
+                let ((a, b), (c, d)) = (self.to_bigints(), other.to_bigints());
+                Rational::from_bigints(&(&a * &d) - &(&c * &b), &b * &d)
+            }
+        }
+    }
+}
+
+impl Mul for &Rational {
+    type Output = Rational;
+
+    fn mul(self, other: &Rational) -> Rational {
+        // (a/b) * (c/d) = (ac) / (bd)
+        match (self, other) {
+            (
+                Rational::Small { numerator: a, denominator: b },
+                Rational::Small { numerator: c, denominator: d },
+            ) => Rational::from_i128(*a as i128 * *c as i128, *b as i128 * *d as i128),
+            _ => {
+                let ((a, b), (c, d)) = (self.to_bigints(), other.to_bigints());
+                Rational::from_bigints(&a * &c, &b * &d)
+            }
+        }
+    }
+}
+
+impl Div for &Rational {
+    type Output = Rational;
+
+    fn div(self, other: &Rational) -> Rational {
+        if other.is_zero() {
+            panic!("Division by zero");
+        }
+        // (a/b) / (c/d) = (a/b) * (d/c) = (ad) / (bc)
+        match (self, other) {
+            (
+                Rational::Small { numerator: a, denominator: b },
+                Rational::Small { numerator: c, denominator: d },
+            ) => Rational::from_i128(*a as i128 * *d as i128, *b as i128 * *c as i128),
+            _ => {
+                let ((a, b), (c, d)) = (self.to_bigints(), other.to_bigints());
+                Rational::from_bigints(&a * &d, &b * &c)
+            }
+        }
+    }
+}
+
+impl Neg for &Rational {
+    type Output = Rational;
+
+    fn neg(self) -> Rational {
+        match self {
+            Rational::Small { numerator, denominator } => {
+                Rational::from_i128(-(*numerator as i128), *denominator as i128)
+            }
+            Rational::Big { numerator, denominator } => Rational::from_bigints(-numerator.clone(), denominator.clone()),
+        }
     }
 }
 
@@ -227,10 +431,7 @@ impl Add for Rational {
     type Output = Self;
 
     fn add(self, other: Self) -> Self {
-        // a/b + c/d = (ad + bc) / (bd)
-        let numerator = self.numerator * other.denominator + other.numerator * self.denominator;
-        let denominator = self.denominator * other.denominator;
-        Self::new(numerator, denominator)
+        &self + &other
     }
 }
 
@@ -238,10 +439,7 @@ impl Sub for Rational {
     type Output = Self;
 
     fn sub(self, other: Self) -> Self {
-        // a/b - c/d = (ad - bc) / (bd)
-        let numerator = self.numerator * other.denominator - other.numerator * self.denominator;
-        let denominator = self.denominator * other.denominator;
-        Self::new(numerator, denominator)
+        &self - &other
     }
 }
 
@@ -249,11 +447,7 @@ impl Mul for Rational {
     type Output = Self;
 
     fn mul(self, other: Self) -> Self {
-        // (a/b) * (c/d) = (ac) / (bd)
-        Self::new(
-            self.numerator * other.numerator,
-            self.denominator * other.denominator,
-        )
+        &self * &other
     }
 }
 
@@ -261,14 +455,7 @@ impl Div for Rational {
     type Output = Self;
 
     fn div(self, other: Self) -> Self {
-        if other.is_zero() {
-            panic!("Division by zero");
-        }
-        // (a/b) / (c/d) = (a/b) * (d/c) = (ad) / (bc)
-        Self::new(
-            self.numerator * other.denominator,
-            self.denominator * other.numerator,
-        )
+        &self / &other
     }
 }
 
@@ -276,10 +463,7 @@ impl Neg for Rational {
     type Output = Self;
 
     fn neg(self) -> Self {
-        Self {
-            numerator: -self.numerator,
-            denominator: self.denominator,
-        }
+        -&self
     }
 }
 
@@ -305,7 +489,7 @@ impl Mul<i64> for Rational {
     type Output = Self;
 
     fn mul(self, other: i64) -> Self {
-        Self::new(self.numerator * other, self.denominator)
+        self * Self::from_integer(other)
     }
 }
 
@@ -316,7 +500,7 @@ impl Div<i64> for Rational {
         if other == 0 {
             panic!("Division by zero");
         }
-        Self::new(self.numerator, self.denominator * other)
+        self / Self::from_integer(other)
     }
 }
 
@@ -329,27 +513,45 @@ impl PartialOrd for Rational {
 impl Ord for Rational {
     fn cmp(&self, other: &Self) -> Ordering {
         // Compare a/b with c/d by comparing ad with bc
-        let left = self.numerator * other.denominator;
-        let right = other.numerator * self.denominator;
-        left.cmp(&right)
+        match (self, other) {
+            (
+                Rational::Small { numerator: a, denominator: b },
+                Rational::Small { numerator: c, denominator: d },
+            ) => (*a as i128 * *d as i128).cmp(&(*c as i128 * *b as i128)),
+            _ => {
+                let ((a, b), (c, d)) = (self.to_bigints(), other.to_bigints());
+                (&a * &d).cmp(&(&c * &b))
+            }
+        }
     }
 }
 
 impl fmt::Display for Rational {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        if self.denominator == 1 {
-            write!(f, "{}", self.numerator)
-        } else {
-            write!(f, "{}/{}", self.numerator, self.denominator)
+        match self {
+            Self::Small { numerator, denominator: 1 } => write!(f, "{numerator}"),
+            Self::Small { numerator, denominator } => write!(f, "{numerator}/{denominator}"),
+            Self::Big { numerator, .. } if self.is_integer() => write!(f, "{numerator}"),
+            Self::Big { numerator, denominator } => write!(f, "{numerator}/{denominator}"),
         }
     }
 }
 
-impl Hash for Rational {
-    fn hash<H: Hasher>(&self, state: &mut H) {
-        self.numerator.hash(state);
-        self.denominator.hash(state);
+/// Converts a 128-bit integer into a big integer.
+fn bigint_from_i128(value: i128) -> BigInt {
+    let magnitude = value.unsigned_abs();
+    let high = BigInt::from_u64((magnitude >> 64) as u64);
+    let low = BigInt::from_u64(magnitude as u64);
+    let big = &(high << 64) + &low;
+    if value < 0 { -big } else { big }
+}
+
+/// Computes the greatest common divisor of 128-bit magnitudes.
+fn gcd_u128(mut a: u128, mut b: u128) -> u128 {
+    while b != 0 {
+        (a, b) = (b, a % b);
     }
+    a
 }
 
 /// Computes the greatest common divisor using Euclid's algorithm
@@ -387,21 +589,10 @@ mod tests {
 
     #[test]
     fn test_rational_creation() {
-        let r1 = Rational::new(3, 4);
-        assert_eq!(r1.numerator, 3);
-        assert_eq!(r1.denominator, 4);
-
-        let r2 = Rational::new(6, 8);
-        assert_eq!(r2.numerator, 3);
-        assert_eq!(r2.denominator, 4);
-
-        let r3 = Rational::new(-3, 4);
-        assert_eq!(r3.numerator, -3);
-        assert_eq!(r3.denominator, 4);
-
-        let r4 = Rational::new(3, -4);
-        assert_eq!(r4.numerator, -3);
-        assert_eq!(r4.denominator, 4);
+        assert_eq!(Rational::new(3, 4).to_i64_parts(), Some((3, 4)));
+        assert_eq!(Rational::new(6, 8).to_i64_parts(), Some((3, 4)));
+        assert_eq!(Rational::new(-3, 4).to_i64_parts(), Some((-3, 4)));
+        assert_eq!(Rational::new(3, -4).to_i64_parts(), Some((-3, 4)));
     }
 
     #[test]
@@ -409,13 +600,13 @@ mod tests {
         let r1 = Rational::new(1, 2);
         let r2 = Rational::new(1, 3);
 
-        let sum = r1 + r2;
+        let sum = &r1 + &r2;
         assert_eq!(sum, Rational::new(5, 6));
 
-        let diff = r1 - r2;
+        let diff = &r1 - &r2;
         assert_eq!(diff, Rational::new(1, 6));
 
-        let prod = r1 * r2;
+        let prod = &r1 * &r2;
         assert_eq!(prod, Rational::new(1, 6));
 
         let quot = r1 / r2;
@@ -441,6 +632,10 @@ mod tests {
 
         let r_inv = r.powi(-1);
         assert_eq!(r_inv, Rational::new(3, 2));
+
+        assert_eq!(r.powi(0), Rational::ONE);
+        assert_eq!(r.powi(10), Rational::new(1024, 59049));
+        assert_eq!(Rational::new(-1, 2).powi(-3), Rational::new(-8, 1));
     }
 
     #[test]
@@ -461,6 +656,52 @@ mod tests {
         assert_eq!(format!("{}", Rational::new(0, 1)), "0");
     }
 
+    #[test]
+    fn test_repeated_addition_stays_exact() {
+        let third = Rational::new(1, 3);
+        let mut sum = Rational::ZERO;
+        for _ in 0..1000 {
+            sum = &sum + &third;
+        }
+        assert_eq!(sum, Rational::new(1000, 3));
+        assert!(!sum.is_big());
+    }
+
+    #[test]
+    fn test_overflow_promotes_to_big_components() {
+        // The harmonic number H(60) has a denominator beyond i64
+        let mut harmonic = Rational::ZERO;
+        for k in 1..=60 {
+            harmonic = harmonic + Rational::new(1, k);
+        }
+        assert!(harmonic.is_big());
+        assert!((harmonic.to_f64() - 4.6798704).abs() < 1e-6);
+
+        // Subtracting the terms back reduces to machine integers again
+        for k in 2..=60 {
+            harmonic = harmonic - Rational::new(1, k);
+        }
+        assert_eq!(harmonic, Rational::ONE);
+        assert!(!harmonic.is_big());
+
+        let max = Rational::from_integer(i64::MAX);
+        let doubled = &max * &Rational::from_integer(2);
+        assert!(doubled.is_big());
+        assert_eq!(format!("{doubled}"), "18446744073709551614");
+        assert_eq!(doubled / Rational::from_integer(2), max);
+    }
+
+    #[test]
+    fn test_comparison_across_representations() {
+        let eleven_max = BigInt::from_i64(i64::MAX) * BigInt::from_i64(11);
+        let huge = Rational::from_bigints(eleven_max.clone(), BigInt::from_i64(5));
+        assert!(huge.is_big());
+        assert!(huge > Rational::from_integer(i64::MAX));
+        assert!(-&huge < Rational::from_integer(i64::MIN));
+        assert!(Rational::new(1, 3) < huge);
+        assert_eq!(huge.floor(), eleven_max / BigInt::from_i64(5));
+    }
+
     #[test]
     fn test_gcd() {
         assert_eq!(gcd(48, 18), 6);
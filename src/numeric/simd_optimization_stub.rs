@@ -158,6 +158,45 @@ impl SimdNumericOps {
         Ok(result)
     }
 
+    /// Sums an f64 array (stub implementation)
+    ///
+    /// NaN propagates: any NaN element yields NaN.
+    pub fn sum_f64(&mut self, data: &[f64]) -> f64 {
+        data.iter().sum()
+    }
+
+    /// Returns the minimum of an f64 array (stub implementation)
+    ///
+    /// If any element is NaN the result is NaN.
+    pub fn min_f64(&mut self, data: &[f64]) -> Option<f64> {
+        Self::extremum_f64(data, true)
+    }
+
+    /// Returns the maximum of an f64 array (stub implementation)
+    ///
+    /// If any element is NaN the result is NaN.
+    pub fn max_f64(&mut self, data: &[f64]) -> Option<f64> {
+        Self::extremum_f64(data, false)
+    }
+
+    fn extremum_f64(data: &[f64], take_min: bool) -> Option<f64> {
+        let (&first, rest) = data.split_first()?;
+        if first.is_nan() {
+            return Some(f64::NAN);
+        }
+
+        let mut acc = first;
+        for &x in rest {
+            if x.is_nan() {
+                return Some(f64::NAN);
+            }
+            if (take_min && x < acc) || (!take_min && x > acc) {
+                acc = x;
+            }
+        }
+        Some(acc)
+    }
+
     /// Attempts to optimize a Scheme numeric operation (stub implementation)
     pub fn optimize_scheme_numeric_operation(&mut self, _op: &str, _args: &[Value]) -> Result<Option<Value>> {
         Ok(None) // No optimization available
@@ -29,8 +29,8 @@ pub fn promote_to_bigint(value: &NumericValue) -> NumericValue {
     match value {
         NumericValue::Integer(n) => NumericValue::BigInteger(BigInt::from_i64(*n)),
         NumericValue::BigInteger(_) => value.clone(),
-        NumericValue::Rational(r) if r.denominator == 1 => {
-            NumericValue::BigInteger(BigInt::from_i64(r.numerator))
+        NumericValue::Rational(r) if r.is_integer() => {
+            NumericValue::BigInteger(r.to_bigints().0)
         }
         NumericValue::Real(r) if r.fract() == 0.0 && r.is_finite() => {
             NumericValue::BigInteger(BigInt::from_i64(*r as i64))
@@ -49,15 +49,7 @@ pub fn promote_to_bigint(value: &NumericValue) -> NumericValue {
 pub fn promote_to_rational(value: &NumericValue) -> NumericValue {
     match value {
         NumericValue::Integer(n) => NumericValue::Rational(Rational::from_integer(*n)),
-        NumericValue::BigInteger(n) => {
-            if let Some(i) = n.to_i64() {
-                NumericValue::Rational(Rational::from_integer(i))
-            } else {
-                // For very large integers, we might need to extend Rational to support BigInt
-                // For now, convert to real
-                NumericValue::Real(n.to_f64().unwrap_or(f64::INFINITY))
-            }
-        }
+        NumericValue::BigInteger(n) => NumericValue::Rational(Rational::from_bigint(n.clone())),
         NumericValue::Rational(_) => value.clone(),
         NumericValue::Real(r) => {
             // Try to convert to exact rational if it's a simple fraction
@@ -131,7 +123,7 @@ pub fn add(left: &NumericValue, right: &NumericValue) -> NumericValue {
             NumericValue::BigInteger(a + b)
         }
         (NumericValue::Rational(a), NumericValue::Rational(b)) => {
-            NumericValue::Rational(*a + *b)
+            NumericValue::Rational(a + b)
         }
         (NumericValue::Real(a), NumericValue::Real(b)) => {
             NumericValue::Real(a + b)
@@ -163,7 +155,7 @@ pub fn subtract(left: &NumericValue, right: &NumericValue) -> NumericValue {
             NumericValue::BigInteger(a - b)
         }
         (NumericValue::Rational(a), NumericValue::Rational(b)) => {
-            NumericValue::Rational(*a - *b)
+            NumericValue::Rational(a - b)
         }
         (NumericValue::Real(a), NumericValue::Real(b)) => {
             NumericValue::Real(a - b)
@@ -195,7 +187,7 @@ pub fn multiply(left: &NumericValue, right: &NumericValue) -> NumericValue {
             NumericValue::BigInteger(a * b)
         }
         (NumericValue::Rational(a), NumericValue::Rational(b)) => {
-            NumericValue::Rational(*a * *b)
+            NumericValue::Rational(a * b)
         }
         (NumericValue::Real(a), NumericValue::Real(b)) => {
             NumericValue::Real(a * b)
@@ -209,9 +201,10 @@ pub fn multiply(left: &NumericValue, right: &NumericValue) -> NumericValue {
 
 /// Divides the left numeric value by the right with type promotion.
 /// 
-/// Returns an error if division by zero is attempted.
+/// Returns an error when dividing by an exact zero. An inexact zero divisor
+/// follows IEEE 754 and yields an infinity or NaN.
 pub fn divide(left: &NumericValue, right: &NumericValue) -> Result<NumericValue, String> {
-    if right.is_zero() {
+    if right.is_zero() && right.is_exact() {
         return Err("Division by zero".to_string());
     }
     
@@ -224,14 +217,11 @@ pub fn divide(left: &NumericValue, right: &NumericValue) -> Result<NumericValue,
             Ok(NumericValue::Rational(rational_result))
         }
         (NumericValue::BigInteger(a), NumericValue::BigInteger(b)) => {
-            // For BigInt, we'd need to extend Rational to support BigInt numerators/denominators
-            // For now, convert to real division
-            let a_f = a.to_f64().unwrap_or(f64::INFINITY);
-            let b_f = b.to_f64().unwrap_or(f64::INFINITY);
-            Ok(NumericValue::Real(a_f / b_f))
+            // Division stays exact, promoting to a rational with big components
+            Ok(NumericValue::Rational(Rational::from_bigints(a.clone(), b.clone())))
         }
         (NumericValue::Rational(a), NumericValue::Rational(b)) => {
-            Ok(NumericValue::Rational(*a / *b))
+            Ok(NumericValue::Rational(a / b))
         }
         (NumericValue::Real(a), NumericValue::Real(b)) => {
             Ok(NumericValue::Real(a / b))
@@ -256,7 +246,7 @@ pub fn negate(value: &NumericValue) -> NumericValue {
             }
         }
         NumericValue::BigInteger(n) => NumericValue::BigInteger(-n.clone()),
-        NumericValue::Rational(r) => NumericValue::Rational(-*r),
+        NumericValue::Rational(r) => NumericValue::Rational(-r),
         NumericValue::Real(r) => NumericValue::Real(-r),
         NumericValue::Complex(c) => NumericValue::Complex(-*c),
         NumericValue::Vector(v) => {
@@ -442,13 +432,26 @@ mod tests {
         
         let quot = divide(&a, &b).unwrap();
         if let NumericValue::Rational(r) = quot {
-            assert_eq!(r.numerator, 10);
-            assert_eq!(r.denominator, 3);
+            assert_eq!(r, Rational::new(10, 3));
         } else {
             panic!("Expected rational result");
         }
     }
 
+    #[test]
+    fn test_division_by_zero() {
+        let one = NumericValue::integer(1);
+        assert!(divide(&one, &NumericValue::integer(0)).is_err());
+        assert!(divide(&NumericValue::real(1.0), &NumericValue::integer(0)).is_err());
+
+        // Inexact zero divisors follow IEEE 754
+        let inf = divide(&NumericValue::real(1.0), &NumericValue::real(0.0)).unwrap();
+        assert_eq!(inf.to_f64(), Some(f64::INFINITY));
+        let nan = divide(&NumericValue::real(0.0), &NumericValue::real(0.0)).unwrap();
+        assert!(nan.to_f64().is_some_and(f64::is_nan));
+        assert_eq!(divide(&one, &NumericValue::real(-0.0)).unwrap().to_f64(), Some(f64::NEG_INFINITY));
+    }
+
     #[test]
     fn test_overflow_handling() {
         let large_int = NumericValue::integer(i64::MAX);
@@ -464,8 +467,7 @@ mod tests {
         let exact = make_exact(&inexact);
         
         if let NumericValue::Rational(r) = exact {
-            assert_eq!(r.numerator, 1);
-            assert_eq!(r.denominator, 2);
+            assert_eq!(r, Rational::new(1, 2));
         } else {
             panic!("Expected rational result");
         }
@@ -204,6 +204,8 @@ impl Parser {
             TokenKind::RightBracket => "closing bracket ']'",
             TokenKind::LeftBrace => "opening brace '{'",
             TokenKind::RightBrace => "closing brace '}'",
+            TokenKind::VectorStart => "vector opening '#('",
+            TokenKind::BytevectorStart => "bytevector opening '#u8('",
             TokenKind::Quote => "quote '",
             TokenKind::Quasiquote => "quasiquote `",
             TokenKind::Unquote => "unquote ,",
@@ -221,6 +223,8 @@ impl Parser {
             TokenKind::Identifier => "identifier",
             TokenKind::LineComment => "line comment",
             TokenKind::BlockComment => "block comment",
+            TokenKind::Directive => "directive",
+            TokenKind::DatumComment => "datum comment '#;'",
             TokenKind::Eof => "end of file",
             TokenKind::Error => "error token",
         }
@@ -192,6 +192,58 @@ impl Parser {
         Ok(Spanned::new(Expr::Literal(Literal::Boolean(value)), span))
     }
     
+    /// Parses a bytevector literal.
+    /// 
+    /// Every element must be an exact integer between 0 and 255:
+    /// - #u8(), #u8(0 127 255)
+    pub fn parse_bytevector(&mut self) -> Result<Spanned<Expr>> {
+        let start_span = self.current_span();
+        self.advance(); // consume #u8(
+        
+        let mut bytes = Vec::new();
+        while !self.check(&crate::lexer::TokenKind::RightParen) && !self.is_at_end() {
+            let span = self.current_span();
+            let byte = match self.current_token().kind {
+                crate::lexer::TokenKind::IntegerNumber => self.current_token().parse_number(),
+                _ => None,
+            };
+            match byte {
+                Some(crate::lexer::NumericValue::Integer(b)) if (0..=255).contains(&b) => bytes.push(b as u8),
+                _ => {
+                    return Err(Box::new(crate::diagnostics::Error::parse_error(
+                        format!("Bytevector elements must be exact integers between 0 and 255, found {}", self.current_token_text()),
+                        span,
+                    )))
+                }
+            }
+            self.advance();
+        }
+        
+        let end_span = self.current_span();
+        self.consume(&crate::lexer::TokenKind::RightParen, "Expected closing parenthesis after bytevector elements")?;
+        
+        Ok(Spanned::new(Expr::Literal(Literal::Bytevector(bytes)), start_span.combine(end_span)))
+    }
+
+    /// Parses a vector literal: #(<datum>*)
+    ///
+    /// The elements are read as quoted data, since a vector literal
+    /// evaluates to itself.
+    pub fn parse_vector(&mut self) -> Result<Spanned<Expr>> {
+        let start_span = self.current_span();
+        self.advance(); // consume #(
+
+        let mut elements = Vec::new();
+        while !self.check(&crate::lexer::TokenKind::RightParen) && !self.is_at_end() {
+            elements.push(self.parse_quoted_datum()?);
+        }
+
+        let end_span = self.current_span();
+        self.consume(&crate::lexer::TokenKind::RightParen, "Expected closing parenthesis after vector elements")?;
+
+        Ok(Spanned::new(Expr::Vector(elements), start_span.combine(end_span)))
+    }
+
     /// Parses any literal value with appropriate error handling.
     /// 
     /// This is a convenience method that dispatches to the appropriate
@@ -215,4 +267,4 @@ impl Parser {
             )))
         }
     }
-}
\ No newline at end of file
+}
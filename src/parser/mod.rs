@@ -24,6 +24,8 @@ pub mod expression;
 pub mod literals;
 /// Special form parsing utilities.
 pub mod special_forms;
+/// Parsing of expressions assembled as data.
+pub mod syntax;
 
 // Individual structure modules
 /// Parser configuration management.
@@ -53,5 +55,22 @@ pub use parser_builder::*;
 /// 内製パーサーコンビネータシステム
 pub mod combinators;
 
+/// Checks whether `source` ends in the middle of an expression.
+///
+/// Returns true only when lexing or parsing fails because the input ran
+/// out (an unclosed list, string, or block comment); genuine syntax errors
+/// return false so they can be reported immediately.
+pub fn is_incomplete_input(source: &str) -> bool {
+    let tokens = match crate::lexer::Lexer::new(source, None).tokenize() {
+        Ok(tokens) => tokens,
+        Err(err) => return err.is_incomplete_input(),
+    };
+
+    match Parser::with_settings(tokens, 1, false).parse() {
+        Ok(_) => false,
+        Err(err) => err.is_incomplete_input(),
+    }
+}
+
 
 
@@ -26,6 +26,11 @@ pub struct Parser {
     pub(crate) context_stack: Vec<String>,
     /// Whether to enable aggressive error recovery
     pub(crate) aggressive_recovery: bool,
+    /// Number of enclosing `quote` forms; quoted lists are read as plain data
+    pub(crate) quoted_depth: usize,
+    /// Already parsed nodes standing in for the identifiers that name them,
+    /// see `Parser::parse_syntax`
+    pub(crate) embedded: HashMap<String, Spanned<Expr>>,
     /// EOF token for when we're past the end of input
     eof_token: Token,
 }
@@ -34,7 +39,7 @@ impl Parser {
     /// Creates a new parser with the given tokens.
     pub fn new(tokens: Vec<Token>) -> Self {
         Self { 
-            tokens, 
+            tokens: strip_datum_comments(tokens), 
             position: 0,
             errors: Vec::new(),
             nesting_depth: 0,
@@ -42,6 +47,8 @@ impl Parser {
             max_errors: 10,
             context_stack: Vec::new(),
             aggressive_recovery: true,
+            quoted_depth: 0,
+            embedded: HashMap::new(),
             eof_token: Token::eof(Span::new(0, 0)),
         }
     }
@@ -49,7 +56,7 @@ impl Parser {
     /// Creates a new parser with custom error handling settings.
     pub fn with_settings(tokens: Vec<Token>, max_errors: usize, aggressive_recovery: bool) -> Self {
         Self {
-            tokens,
+            tokens: strip_datum_comments(tokens),
             position: 0,
             errors: Vec::new(),
             nesting_depth: 0,
@@ -57,6 +64,8 @@ impl Parser {
             max_errors,
             context_stack: Vec::new(),
             aggressive_recovery,
+            quoted_depth: 0,
+            embedded: HashMap::new(),
             eof_token: Token::eof(Span::new(0, 0)),
         }
     }
@@ -174,16 +183,29 @@ impl Parser {
             TokenKind::String => self.parse_string(),
             TokenKind::Character => self.parse_character(),
             TokenKind::Boolean => self.parse_boolean(),
+            TokenKind::Identifier if self.current_token().text.starts_with('|') => {
+                // |...| names any symbol, so it skips identifier validation
+                let name = self.current_token().identifier_name()
+                    .map_err(|e| Error::parse_error(format!("Invalid |identifier|: {e}"), self.current_span()))?;
+                let span = self.current_span();
+                self.advance();
+                Ok(Spanned::new(Expr::Identifier(name), span))
+            },
             TokenKind::Identifier => {
                 let name = self.current_token().text.clone();
                 let span = self.current_span();
                 self.advance();
+                if let Some(node) = self.embedded.get(&name) {
+                    return Ok(node.clone());
+                }
                 self.make_identifier(name, span)
             },
+            TokenKind::BytevectorStart => self.parse_bytevector(),
+            TokenKind::VectorStart => self.parse_vector(),
             TokenKind::Quote => {
                 let start_span = self.current_span();
                 self.advance(); // consume quote
-                let expr = self.parse_expression()?;
+                let expr = self.parse_quoted_datum()?;
                 let span = start_span.combine(expr.span);
                 Ok(Spanned::new(Expr::Quote(Box::new(expr)), span))
             },
@@ -196,10 +218,27 @@ impl Parser {
                 self.advance();
                 self.make_identifier(".".to_string(), span)
             },
+            // Only a `#;` with nothing to comment out survives stripping
+            TokenKind::DatumComment => match self.tokens.get(start_pos + 1) {
+                Some(next) if next.kind != TokenKind::Eof => Err(Box::new(Error::parse_error(
+                    "Datum comment '#;' has no datum to comment out",
+                    self.current_span(),
+                ))),
+                _ => Err(Error::unexpected_eof(self.current_span()).boxed()),
+            },
             _ => Err(Box::new(Error::unexpected_token(token, "expression"))),
         }
     }
 
+    /// Parses the datum of a quote, reading lists as plain data so that
+    /// keywords such as `if` or `when` stay symbols.
+    pub fn parse_quoted_datum(&mut self) -> Result<Spanned<Expr>> {
+        self.quoted_depth += 1;
+        let datum = self.parse_expression();
+        self.quoted_depth -= 1;
+        datum
+    }
+
     /// Alias for parse_single_expression for backward compatibility.
     pub fn parse_expression(&mut self) -> Result<Spanned<Expr>> {
         self.parse_single_expression()
@@ -232,6 +271,10 @@ impl Parser {
     pub fn consume(&mut self, kind: &TokenKind, message: &str) -> Result<&Token> {
         if self.check(kind) {
             Ok(self.advance())
+        } else if self.is_at_end() {
+            // Running out of tokens is reported as incomplete input rather
+            // than a mismatched token so the REPL can ask for more lines
+            Err(Error::unexpected_eof(self.current_span()).boxed())
         } else {
             Err(Box::new(Error::expected_token(self.current_token(), kind, message)))
         }
@@ -269,7 +312,7 @@ impl Parser {
     /// Note: Newlines are now handled as whitespace at lexer level (R7RS compliant).
     pub fn skip_whitespace(&mut self) {
         while !self.is_at_end() && matches!(self.current_token().kind, 
-            TokenKind::LineComment | TokenKind::BlockComment) {
+            TokenKind::LineComment | TokenKind::BlockComment | TokenKind::Directive) {
             self.advance();
         }
     }
@@ -452,8 +495,13 @@ impl Parser {
         let first_element = self.parse_expression()?;
         self.skip_whitespace();
         
-        // Check if this is a special form by examining the first element
-        if let Expr::Identifier(ref name) = first_element.inner {
+        // Check if this is a special form by examining the first element;
+        // inside a quoted datum there are no special forms
+        let keyword = match &first_element.inner {
+            Expr::Identifier(name) if self.quoted_depth == 0 => Some(name.clone()),
+            _ => None,
+        };
+        if let Some(name) = keyword {
             let result = match name.as_str() {
                 // Core special forms
                 "quote" => self.parse_quote_form(start_span),
@@ -481,7 +529,9 @@ impl Parser {
                 "or" => self.parse_or_form(start_span),
                 "when" => self.parse_when_form(start_span),
                 "unless" => self.parse_unless_form(start_span),
+                "receive" => self.parse_receive_form(start_span),
                 "guard" => self.parse_guard_form(start_span),
+                "do" => self.parse_do_form(start_span),
                 "case-lambda" => self.parse_case_lambda_form(start_span),
                 
                 // Not a special form - parse as application
@@ -581,4 +631,107 @@ impl Parser {
         
         Ok(Spanned::new(Expr::UnquoteSplicing(Box::new(expr)), span))
     }
-}
\ No newline at end of file
+}
+
+/// Removes each datum comment `#;` together with the datum it comments out.
+///
+/// The extent of the commented datum follows the token structure, so nested
+/// lists and stacked comments such as `#;#;a b` are dropped whole. A `#;`
+/// with no datum after it is kept so that parsing reports it.
+fn strip_datum_comments(tokens: Vec<Token>) -> Vec<Token> {
+    if !tokens.iter().any(|token| token.kind == TokenKind::DatumComment) {
+        return tokens;
+    }
+
+    let mut kept = Vec::with_capacity(tokens.len());
+    let mut index = 0;
+    while index < tokens.len() {
+        if tokens[index].kind == TokenKind::DatumComment {
+            if let Some(end) = datum_end(&tokens, index + 1) {
+                index = end;
+                continue;
+            }
+        }
+        kept.push(tokens[index].clone());
+        index += 1;
+    }
+    kept
+}
+
+/// Returns the index just past the datum starting at `index`, or `None` if
+/// no complete datum starts there. Datum comments before the datum are
+/// skipped along with the data they comment out.
+fn datum_end(tokens: &[Token], index: usize) -> Option<usize> {
+    let index = skip_comment_tokens(tokens, index);
+    match tokens.get(index)?.kind {
+        TokenKind::DatumComment => datum_end(tokens, datum_end(tokens, index + 1)?),
+        TokenKind::Quote | TokenKind::Quasiquote | TokenKind::Unquote | TokenKind::UnquoteSplicing => {
+            datum_end(tokens, index + 1)
+        }
+        TokenKind::LeftParen | TokenKind::LeftBracket | TokenKind::VectorStart | TokenKind::BytevectorStart => {
+            let mut index = index + 1;
+            loop {
+                index = skip_comment_tokens(tokens, index);
+                match tokens.get(index)?.kind {
+                    TokenKind::RightParen | TokenKind::RightBracket => return Some(index + 1),
+                    TokenKind::Eof => return None,
+                    TokenKind::Dot => index += 1,
+                    TokenKind::DatumComment => index = datum_end(tokens, index + 1)?,
+                    _ => index = datum_end(tokens, index)?,
+                }
+            }
+        }
+        TokenKind::RightParen | TokenKind::RightBracket | TokenKind::Dot | TokenKind::Eof => None,
+        _ => Some(index + 1),
+    }
+}
+
+fn skip_comment_tokens(tokens: &[Token], mut index: usize) -> usize {
+    while tokens.get(index).is_some_and(|token| {
+        matches!(token.kind, TokenKind::LineComment | TokenKind::BlockComment | TokenKind::Directive)
+    }) {
+        index += 1;
+    }
+    index
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lexer::Lexer;
+
+    fn parse(source: &str) -> Result<Program> {
+        let tokens = Lexer::new(source, Some("test")).tokenize().unwrap();
+        Parser::with_settings(tokens, 1, false).parse()
+    }
+
+    fn shown(source: &str) -> Vec<String> {
+        parse(source).unwrap().expressions.iter().map(|expr| expr.inner.to_string()).collect()
+    }
+
+    #[test]
+    fn test_datum_comment_drops_next_datum() {
+        assert_eq!(shown("(1 #;(2 3) 4)"), shown("(1 4)"));
+        assert_eq!(shown("(1 #;(2 (3 #(4)) \"5\") 6)"), shown("(1 6)"));
+        assert_eq!(shown("#;(define x 1) 2"), shown("2"));
+        assert_eq!(shown("'(a #;'b c)"), shown("'(a c)"));
+        assert_eq!(shown("(a #; b c)"), shown("(a c)"));
+    }
+
+    #[test]
+    fn test_stacked_datum_comments() {
+        assert_eq!(shown("(#;#;a b c)"), shown("(c)"));
+        assert_eq!(shown("#;#;1 2 3"), shown("3"));
+        // A datum comment inside a commented datum only affects that datum
+        assert_eq!(shown("(x #;(a #;b) y)"), shown("(x y)"));
+    }
+
+    #[test]
+    fn test_datum_comment_without_datum_is_an_error() {
+        let err = parse("(1 #;)").unwrap_err();
+        assert!(err.to_string().contains("no datum to comment out"), "{err}");
+        // More input could still supply the datum
+        assert!(parse("(1 #;").unwrap_err().is_incomplete_input());
+        assert!(parse("1 #;").unwrap_err().is_incomplete_input());
+    }
+}
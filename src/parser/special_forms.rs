@@ -3,7 +3,7 @@
 #![allow(missing_docs)]
 
 use super::Parser;
-use crate::ast::{Binding, CaseClause, CaseLambdaClause, CondClause, Expr, Formals, GuardClause, KeywordParam, ParameterBinding};
+use crate::ast::{Binding, CaseClause, CaseLambdaClause, CondClause, DoBinding, Expr, Formals, GuardClause, KeywordParam, ParameterBinding};
 use crate::diagnostics::{Error, Result, Span, Spanned};
 use crate::lexer::TokenKind;
 use std::collections::HashMap;
@@ -11,7 +11,7 @@ use std::collections::HashMap;
 impl Parser {
     /// Parses a quote form: (quote <datum>)
     pub fn parse_quote_form(&mut self, start_span: Span) -> Result<Spanned<Expr>> {
-        let expr = self.parse_expression()?;
+        let expr = self.parse_quoted_datum()?;
         
         self.consume(&TokenKind::RightParen, "Expected closing parenthesis after quote")?;
         let end_span = self.current_span();
@@ -182,9 +182,17 @@ impl Parser {
         })
     }
 
-    /// Parses a syntax-rules form: (syntax-rules (literal ...) (pattern template) ...)
+    /// Parses a syntax-rules form: (syntax-rules [ellipsis] (literal ...) (pattern template) ...)
     pub fn parse_syntax_rules_form(&mut self, start_span: Span) -> Result<Spanned<Expr>> {
         self.with_context("syntax-rules form", |parser| {
+            // An identifier before the literals list names a custom ellipsis
+            let mut ellipsis = None;
+            if parser.check(&TokenKind::Identifier) {
+                ellipsis = Some(parser.current_token().text.clone());
+                parser.advance();
+                parser.skip_whitespace();
+            }
+            
             // Parse literals list
             parser.consume(&TokenKind::LeftParen, "Expected opening parenthesis for literals list")?;
             parser.skip_whitespace();
@@ -213,12 +221,12 @@ impl Parser {
                 parser.consume(&TokenKind::LeftParen, "Expected opening parenthesis for syntax rule")?;
                 parser.skip_whitespace();
                 
-                // Parse pattern
-                let pattern = parser.parse_expression()?;
+                // Patterns and templates are data: special forms in a
+                // template are only parsed once it has been expanded
+                let pattern = parser.parse_quoted_datum()?;
                 parser.skip_whitespace();
                 
-                // Parse template
-                let template = parser.parse_expression()?;
+                let template = parser.parse_quoted_datum()?;
                 parser.skip_whitespace();
                 
                 parser.consume(&TokenKind::RightParen, "Expected closing parenthesis for syntax rule")?;
@@ -240,7 +248,7 @@ impl Parser {
             
             // Create a SyntaxRules expression
             Ok(Spanned::new(
-                Expr::SyntaxRules { literals, rules },
+                Expr::SyntaxRules { ellipsis, literals, rules },
                 span,
             ))
         })
@@ -502,10 +510,10 @@ impl Parser {
         Ok(Spanned::new(Expr::Or(exprs), span))
     }
 
-    /// Parses a when form: (when <test> <expressions>+)
+    /// Parses a when form: (when <test> <expressions>*)
     pub fn parse_when_form(&mut self, start_span: Span) -> Result<Spanned<Expr>> {
         let test = Box::new(self.parse_expression()?);
-        let body = self.parse_body()?;
+        let body = self.parse_optional_body()?;
         
         let end_span = self.current_span();
         self.consume(&TokenKind::RightParen, "Expected closing parenthesis after when")?;
@@ -514,10 +522,10 @@ impl Parser {
         Ok(Spanned::new(Expr::When { test, body }, span))
     }
 
-    /// Parses an unless form: (unless <test> <expressions>+)
+    /// Parses an unless form: (unless <test> <expressions>*)
     pub fn parse_unless_form(&mut self, start_span: Span) -> Result<Spanned<Expr>> {
         let test = Box::new(self.parse_expression()?);
-        let body = self.parse_body()?;
+        let body = self.parse_optional_body()?;
         
         let end_span = self.current_span();
         self.consume(&TokenKind::RightParen, "Expected closing parenthesis after unless")?;
@@ -526,6 +534,36 @@ impl Parser {
         Ok(Spanned::new(Expr::Unless { test, body }, span))
     }
 
+    /// Parses a SRFI-8 receive form: (receive <formals> <producer> <expressions>+)
+    ///
+    /// The form becomes `(call-with-values (lambda () <producer>) (lambda <formals> <expressions>+))`,
+    /// so the producer's values are bound with the usual lambda arity checks.
+    pub fn parse_receive_form(&mut self, start_span: Span) -> Result<Spanned<Expr>> {
+        self.with_context("receive form", |parser| {
+            let formals = parser.parse_formals()?;
+            let producer = parser.parse_expression()?;
+            let body = parser.parse_body()?;
+            
+            let end_span = parser.current_span();
+            parser.consume(&TokenKind::RightParen, "Expected closing parenthesis after receive")?;
+            let span = start_span.combine(end_span);
+            
+            let lambda = |formals, body| {
+                Spanned::new(Expr::Lambda { formals, metadata: HashMap::new(), body }, span)
+            };
+            Ok(Spanned::new(
+                Expr::Application {
+                    operator: Box::new(Spanned::new(Expr::Identifier("call-with-values".to_string()), start_span)),
+                    operands: vec![
+                        lambda(Formals::Fixed(Vec::new()), vec![producer]),
+                        lambda(formals, body),
+                    ],
+                },
+                span,
+            ))
+        })
+    }
+
     /// Parses a parameterize form: (parameterize ((<parameter> <value>) ...) <body>)
     pub fn parse_parameterize_form(&mut self, start_span: Span) -> Result<Spanned<Expr>> {
         self.with_context("parameterize form", |parser| {
@@ -621,6 +659,78 @@ impl Parser {
         })
     }
 
+    /// Parses a do form: (do ((<variable> <init> <step>?)*) (<test> <expression>*) <command>*)
+    pub fn parse_do_form(&mut self, start_span: Span) -> Result<Spanned<Expr>> {
+        self.with_context("do form", |parser| {
+            // Parse loop variables
+            parser.consume(&TokenKind::LeftParen, "Expected opening parenthesis for do bindings")?;
+            parser.skip_whitespace();
+            
+            let mut bindings = Vec::new();
+            while !parser.check(&TokenKind::RightParen) && !parser.is_at_end() {
+                parser.consume(&TokenKind::LeftParen, "Expected opening parenthesis for do binding")?;
+                parser.skip_whitespace();
+                
+                if !parser.check(&TokenKind::Identifier) {
+                    return Err(Box::new(Error::parse_error(
+                        "Expected identifier in do binding",
+                        parser.current_span(),
+                    )))
+                }
+                
+                let name = parser.current_token().text.clone();
+                parser.advance();
+                parser.skip_whitespace();
+                
+                let init = parser.parse_expression()?;
+                parser.skip_whitespace();
+                
+                // The step is optional; without it the variable keeps its value
+                let step = if parser.check(&TokenKind::RightParen) {
+                    None
+                } else {
+                    Some(parser.parse_expression()?)
+                };
+                parser.skip_whitespace();
+                
+                parser.consume(&TokenKind::RightParen, "Expected closing parenthesis for do binding")?;
+                parser.skip_whitespace();
+                
+                bindings.push(DoBinding { name, init, step });
+            }
+            parser.consume(&TokenKind::RightParen, "Expected closing parenthesis for do bindings")?;
+            parser.skip_whitespace();
+            
+            // Parse (test result...)
+            parser.consume(&TokenKind::LeftParen, "Expected opening parenthesis for do test clause")?;
+            parser.skip_whitespace();
+            
+            let test = Box::new(parser.parse_expression()?);
+            parser.skip_whitespace();
+            
+            let mut result = Vec::new();
+            while !parser.check(&TokenKind::RightParen) && !parser.is_at_end() {
+                result.push(parser.parse_expression()?);
+                parser.skip_whitespace();
+            }
+            parser.consume(&TokenKind::RightParen, "Expected closing parenthesis for do test clause")?;
+            parser.skip_whitespace();
+            
+            // Parse commands (may be empty)
+            let mut body = Vec::new();
+            while !parser.check(&TokenKind::RightParen) && !parser.is_at_end() {
+                body.push(parser.parse_expression()?);
+                parser.skip_whitespace();
+            }
+            
+            let end_span = parser.current_span();
+            parser.consume(&TokenKind::RightParen, "Expected closing parenthesis after do")?;
+            let span = start_span.combine(end_span);
+            
+            Ok(Spanned::new(Expr::Do { bindings, test, result, body }, span))
+        })
+    }
+
     /// Parses a case-lambda form: (case-lambda (<formals1> <body1>...) (<formals2> <body2>...) ...)
     pub fn parse_case_lambda_form(&mut self, start_span: Span) -> Result<Spanned<Expr>> {
         self.with_context("case-lambda form", |parser| {
@@ -884,6 +994,17 @@ impl Parser {
         Ok(body)
     }
     
+    /// Parses a sequence of expressions that may be empty.
+    pub fn parse_optional_body(&mut self) -> Result<Vec<Spanned<Expr>>> {
+        let mut body = Vec::new();
+        
+        while !self.check(&TokenKind::RightParen) && !self.is_at_end() {
+            body.push(self.parse_expression()?);
+        }
+        
+        Ok(body)
+    }
+    
     /// Converts a list of operand expressions to formal parameters.
     /// Used for function definition syntax: (define (f x y) body)
     pub fn operands_to_formals(&self, operands: Vec<Spanned<Expr>>) -> Result<Formals> {
@@ -0,0 +1,117 @@
+//! Parsing of expressions assembled as data.
+//!
+//! Macro expansion builds its output from data: syntax-rules templates are
+//! read as quoted data and procedural transformers return datums, so the
+//! special forms in an expansion are plain applications of their keywords.
+//! Rather than printing the expansion and lexing the text again, the tree
+//! is laid out as the token stream the parser would see for it, with every
+//! token carrying the span of the node it came from. Nodes with no token
+//! form, such as literals and subforms the parser has already read, are
+//! embedded as they are.
+
+use super::Parser;
+use crate::ast::{Expr, Literal};
+use crate::diagnostics::{Result, Span, Spanned};
+use crate::lexer::{Token, TokenKind};
+use std::collections::HashMap;
+
+/// Prefix of the identifiers standing in for embedded nodes.
+const EMBEDDED_PREFIX: &str = "%syntax";
+
+impl Parser {
+    /// Parses an expression assembled as data, reading the special forms
+    /// in it exactly as in source code.
+    pub fn parse_syntax(expr: &Spanned<Expr>) -> Result<Spanned<Expr>> {
+        let mut stream = SyntaxStream::default();
+        stream.push(expr);
+
+        let mut parser = Parser::new(stream.tokens);
+        parser.embedded = stream.embedded;
+        let mut parsed = parser.parse_expression()?;
+        parsed.span = expr.span;
+        Ok(parsed)
+    }
+}
+
+/// The token stream of an expression assembled as data.
+#[derive(Debug, Default)]
+struct SyntaxStream {
+    tokens: Vec<Token>,
+    embedded: HashMap<String, Spanned<Expr>>,
+}
+
+impl SyntaxStream {
+    /// Appends the tokens of `expr`.
+    fn push(&mut self, expr: &Spanned<Expr>) {
+        let span = expr.span;
+        match &expr.inner {
+            Expr::Identifier(name) if name == "." => self.token(TokenKind::Dot, name, span),
+            Expr::Identifier(name) if Parser::validate_identifier(name, span).is_ok() => {
+                self.token(TokenKind::Identifier, name, span)
+            }
+            Expr::Application { operator, operands } => {
+                self.token(TokenKind::LeftParen, "(", span);
+                self.push(operator);
+                operands.iter().for_each(|operand| self.push(operand));
+                self.token(TokenKind::RightParen, ")", span);
+            }
+            Expr::List(items) => {
+                self.token(TokenKind::LeftParen, "(", span);
+                items.iter().for_each(|item| self.push(item));
+                self.token(TokenKind::RightParen, ")", span);
+            }
+            Expr::Literal(Literal::Nil) => {
+                self.token(TokenKind::LeftParen, "(", span);
+                self.token(TokenKind::RightParen, ")", span);
+            }
+            Expr::Pair { car, cdr } => {
+                self.token(TokenKind::LeftParen, "(", span);
+                self.push(car);
+                let mut tail = cdr.as_ref();
+                loop {
+                    match &tail.inner {
+                        Expr::Pair { car, cdr } => {
+                            self.push(car);
+                            tail = cdr.as_ref();
+                        }
+                        Expr::List(items) => {
+                            items.iter().for_each(|item| self.push(item));
+                            break;
+                        }
+                        Expr::Literal(Literal::Nil) => break,
+                        _ => {
+                            self.token(TokenKind::Dot, ".", tail.span);
+                            self.push(tail);
+                            break;
+                        }
+                    }
+                }
+                self.token(TokenKind::RightParen, ")", span);
+            }
+            Expr::Vector(items) => {
+                self.token(TokenKind::VectorStart, "#(", span);
+                items.iter().for_each(|item| self.push(item));
+                self.token(TokenKind::RightParen, ")", span);
+            }
+            Expr::Quote(inner) => self.abbreviation(TokenKind::Quote, "'", inner, span),
+            Expr::Quasiquote(inner) => self.abbreviation(TokenKind::Quasiquote, "`", inner, span),
+            Expr::Unquote(inner) => self.abbreviation(TokenKind::Unquote, ",", inner, span),
+            Expr::UnquoteSplicing(inner) => self.abbreviation(TokenKind::UnquoteSplicing, ",@", inner, span),
+            _ => {
+                let name = format!("{EMBEDDED_PREFIX}{}", self.embedded.len());
+                self.token(TokenKind::Identifier, &name, span);
+                self.embedded.insert(name, expr.clone());
+            }
+        }
+    }
+
+    /// Appends a quotation abbreviation followed by the tokens of `inner`.
+    fn abbreviation(&mut self, kind: TokenKind, text: &str, inner: &Spanned<Expr>, span: Span) {
+        self.token(kind, text, span);
+        self.push(inner);
+    }
+
+    fn token(&mut self, kind: TokenKind, text: &str, span: Span) {
+        self.tokens.push(Token::new(kind, span, text.to_string()));
+    }
+}
@@ -0,0 +1,234 @@
+//! Capture group resolution for matched spans.
+//!
+//! The Thompson NFA finds where a match starts and ends but does not track
+//! which part of the text each group consumed. Once a match span is known,
+//! [`GroupResolver`] replays the pattern over exactly that span with a
+//! backtracking walk of the AST and records the span of every capturing
+//! group. Quantifiers are greedy and alternatives are tried left to right,
+//! so group spans follow the usual Perl-style assignment.
+
+use crate::regex::engine::CharClass;
+use crate::regex::parser::{Pattern, PatternNode};
+
+/// Byte spans of the groups of one match, indexed by group number.
+///
+/// Group 0 is the whole match; a group that did not participate is `None`.
+pub type GroupSpans = Vec<Option<(usize, usize)>>;
+
+/// Pattern AST with capturing groups numbered in opening order.
+#[derive(Debug, Clone)]
+enum Node {
+    Char(char),
+    Class(CharClass),
+    Any,
+    Start,
+    End,
+    Concat(Vec<Node>),
+    Alternate(Vec<Node>),
+    Star(Box<Node>),
+    Plus(Box<Node>),
+    Question(Box<Node>),
+    Group(usize, Box<Node>),
+}
+
+/// What remains to be matched after the node currently being walked.
+enum Rest<'n> {
+    /// Nothing left; the walk must end exactly at the match end
+    Done,
+    /// The remaining nodes of a concatenation
+    Seq(&'n [Node], &'n Rest<'n>),
+    /// Close group `index`, opened at the given position
+    Close(usize, usize, &'n Rest<'n>),
+    /// Another optional iteration of a repeated node that started at the given position
+    Repeat(&'n Node, usize, &'n Rest<'n>),
+}
+
+/// Resolves the group spans of matches of a single pattern.
+#[derive(Debug, Clone)]
+pub struct GroupResolver {
+    root: Node,
+    names: Vec<Option<String>>,
+}
+
+impl GroupResolver {
+    /// Creates a resolver for a parsed pattern.
+    pub fn new(pattern: &Pattern) -> Self {
+        let mut next_group = 1;
+        Self {
+            root: number_groups(&pattern.root, &mut next_group),
+            names: pattern.group_names.clone(),
+        }
+    }
+
+    /// Returns the number of groups, counting group 0.
+    pub fn group_count(&self) -> usize {
+        self.names.len() + 1
+    }
+
+    /// Returns the names of groups 1.., `None` for unnamed groups.
+    pub fn group_names(&self) -> &[Option<String>] {
+        &self.names
+    }
+
+    /// Returns the number of the group called `name`.
+    pub fn group_index(&self, name: &str) -> Option<usize> {
+        self.names
+            .iter()
+            .position(|n| n.as_deref() == Some(name))
+            .map(|i| i + 1)
+    }
+
+    /// Resolves the group spans for a match covering `text[start..end]`.
+    pub fn resolve(&self, text: &str, start: usize, end: usize) -> GroupSpans {
+        let mut spans = vec![None; self.group_count()];
+        let walk = Walk { text, end };
+        walk.node(&self.root, start, &Rest::Done, &mut spans);
+        spans[0] = Some((start, end));
+        spans
+    }
+}
+
+/// Converts the parser AST into [`Node`]s, numbering groups in pre-order.
+fn number_groups(node: &PatternNode, next_group: &mut usize) -> Node {
+    match node {
+        PatternNode::Char(ch) => Node::Char(*ch),
+        PatternNode::CharClass(class) => Node::Class(class.clone()),
+        PatternNode::Any => Node::Any,
+        PatternNode::Start => Node::Start,
+        PatternNode::End => Node::End,
+        PatternNode::Concat(parts) => {
+            Node::Concat(parts.iter().map(|p| number_groups(p, next_group)).collect())
+        }
+        PatternNode::Alternate(alts) => {
+            Node::Alternate(alts.iter().map(|a| number_groups(a, next_group)).collect())
+        }
+        PatternNode::Star(inner) => Node::Star(Box::new(number_groups(inner, next_group))),
+        PatternNode::Plus(inner) => Node::Plus(Box::new(number_groups(inner, next_group))),
+        PatternNode::Question(inner) => Node::Question(Box::new(number_groups(inner, next_group))),
+        PatternNode::Group(inner) => {
+            let index = *next_group;
+            *next_group += 1;
+            Node::Group(index, Box::new(number_groups(inner, next_group)))
+        }
+        PatternNode::NonCapturing(inner) => number_groups(inner, next_group),
+    }
+}
+
+/// A backtracking walk constrained to end at `end`.
+struct Walk<'t> {
+    text: &'t str,
+    end: usize,
+}
+
+impl Walk<'_> {
+    /// Matches `node` at `pos`, then `rest`. Spans are left untouched on failure.
+    fn node(&self, node: &Node, pos: usize, rest: &Rest<'_>, spans: &mut GroupSpans) -> bool {
+        match node {
+            Node::Char(expected) => self.char_then(pos, rest, spans, |ch| ch == *expected),
+            Node::Class(class) => self.char_then(pos, rest, spans, |ch| class.matches(ch)),
+            // Mirrors the NFA, which compiles `.` to a class matching anything
+            Node::Any => self.char_then(pos, rest, spans, |_| true),
+            Node::Start => pos == 0 && self.rest(rest, pos, spans),
+            Node::End => pos == self.text.len() && self.rest(rest, pos, spans),
+            Node::Concat(parts) => self.rest(&Rest::Seq(parts, rest), pos, spans),
+            Node::Alternate(alts) => alts.iter().any(|alt| self.node(alt, pos, rest, spans)),
+            Node::Star(inner) => {
+                self.node(inner, pos, &Rest::Repeat(inner, pos, rest), spans)
+                    || self.rest(rest, pos, spans)
+            }
+            Node::Plus(inner) => self.node(inner, pos, &Rest::Repeat(inner, pos, rest), spans),
+            Node::Question(inner) => self.node(inner, pos, rest, spans) || self.rest(rest, pos, spans),
+            Node::Group(index, inner) => self.node(inner, pos, &Rest::Close(*index, pos, rest), spans),
+        }
+    }
+
+    /// Continues with `rest` at `pos`.
+    fn rest(&self, rest: &Rest<'_>, pos: usize, spans: &mut GroupSpans) -> bool {
+        match rest {
+            Rest::Done => pos == self.end,
+            Rest::Seq(parts, after) => match parts.split_first() {
+                Some((first, others)) => self.node(first, pos, &Rest::Seq(others, after), spans),
+                None => self.rest(after, pos, spans),
+            },
+            Rest::Close(index, open, after) => {
+                let saved = spans[*index];
+                spans[*index] = Some((*open, pos));
+                if self.rest(after, pos, spans) {
+                    true
+                } else {
+                    spans[*index] = saved;
+                    false
+                }
+            }
+            Rest::Repeat(inner, started, after) => {
+                // An iteration that consumed nothing must not loop again
+                if pos == *started {
+                    return self.rest(after, pos, spans);
+                }
+                self.node(inner, pos, &Rest::Repeat(inner, pos, after), spans)
+                    || self.rest(after, pos, spans)
+            }
+        }
+    }
+
+    /// Consumes one character accepted by `accept`, then continues with `rest`.
+    fn char_then(
+        &self,
+        pos: usize,
+        rest: &Rest<'_>,
+        spans: &mut GroupSpans,
+        accept: impl Fn(char) -> bool,
+    ) -> bool {
+        if pos >= self.end {
+            return false;
+        }
+        match self.text.get(pos..).and_then(|s| s.chars().next()) {
+            Some(ch) if accept(ch) => self.rest(rest, pos + ch.len_utf8(), spans),
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::regex::parser::PatternParser;
+
+    fn resolve(pattern: &str, text: &str, start: usize, end: usize) -> GroupSpans {
+        let parsed = PatternParser::new(pattern).parse().unwrap();
+        GroupResolver::new(&parsed).resolve(text, start, end)
+    }
+
+    #[test]
+    fn test_groups_in_order() {
+        let spans = resolve(r"(\d+)-(\d+)", "x12-345", 1, 7);
+        assert_eq!(spans, vec![Some((1, 7)), Some((1, 3)), Some((4, 7))]);
+    }
+
+    #[test]
+    fn test_non_participating_group() {
+        let spans = resolve(r"(a)|(b)", "b", 0, 1);
+        assert_eq!(spans, vec![Some((0, 1)), None, Some((0, 1))]);
+    }
+
+    #[test]
+    fn test_repeated_group_keeps_last_iteration() {
+        let spans = resolve(r"(?:(\w)\s?)+", "a b c", 0, 5);
+        assert_eq!(spans[1], Some((4, 5)));
+    }
+
+    #[test]
+    fn test_empty_iteration_terminates() {
+        let spans = resolve(r"(a*)*b", "b", 0, 1);
+        assert_eq!(spans[0], Some((0, 1)));
+    }
+
+    #[test]
+    fn test_group_names() {
+        let parsed = PatternParser::new(r"(?P<key>\w+)=(\w+)").parse().unwrap();
+        let resolver = GroupResolver::new(&parsed);
+        assert_eq!(resolver.group_count(), 3);
+        assert_eq!(resolver.group_index("key"), Some(1));
+        assert_eq!(resolver.group_index("value"), None);
+    }
+}
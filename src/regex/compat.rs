@@ -17,7 +17,7 @@
 //! - `is_match()` - Test for match
 //! - `find()` - Find first match  
 //! - `find_iter()` - Iterate over all matches
-//! - `captures()` - Extract captures (basic support)
+//! - `captures()` / `captures_iter()` - Extract indexed and named groups
 //! - `replace()` / `replace_all()` - String replacement
 //! - `split()` - Split by regex
 //!
@@ -32,12 +32,13 @@
 //! - No Unicode property support (use ASCII classes only)
 //! - No look-around assertions
 //! - No backreferences
-//! - Basic capture group support only
+//! - Replacement strings are literal; use `Captures::expand` for `$1` / `${name}`
 //! - No regex sets or multi-pattern matching
 
 use std::fmt;
 use std::borrow::Cow;
 use crate::regex::{NfaEngine, PatternParser, Matcher};
+use crate::regex::captures::{GroupResolver, GroupSpans};
 use crate::regex::matcher::Match as InternalMatch;
 
 /// Error type compatible with `regex::Error`.
@@ -124,11 +125,12 @@ impl<'t> Match<'t> {
     }
 }
 
-/// Captures type for compatibility (basic implementation).
+/// Groups captured by one match, compatible with `regex::Captures`.
 #[derive(Debug, Clone)]
 pub struct Captures<'t> {
     text: &'t str,
     matches: Vec<Option<Match<'t>>>,
+    names: Vec<Option<String>>,
 }
 
 impl<'t> Captures<'t> {
@@ -137,17 +139,31 @@ impl<'t> Captures<'t> {
         Self {
             text,
             matches: Vec::new(),
+            names: Vec::new(),
         }
     }
     
-    /// Gets the full match.
+    /// Creates captures from resolved group spans.
+    fn from_spans(text: &'t str, spans: GroupSpans, names: &[Option<String>]) -> Self {
+        Self {
+            text,
+            matches: spans
+                .into_iter()
+                .map(|span| span.map(|(start, end)| Match::new(text, start, end)))
+                .collect(),
+            names: names.to_vec(),
+        }
+    }
+    
+    /// Gets group `i`; group 0 is the full match.
     pub fn get(&self, i: usize) -> Option<Match<'t>> {
         self.matches.get(i).copied().flatten()
     }
     
-    /// Gets the full match by name (not yet supported).
-    pub fn name(&self, _name: &str) -> Option<Match<'t>> {
-        None // Not implemented in Phase 1
+    /// Gets a named group, `None` if it is unknown or did not participate.
+    pub fn name(&self, name: &str) -> Option<Match<'t>> {
+        let index = self.names.iter().position(|n| n.as_deref() == Some(name))?;
+        self.get(index + 1)
     }
     
     /// Returns an iterator over all matches.
@@ -164,12 +180,61 @@ impl<'t> Captures<'t> {
     pub fn is_empty(&self) -> bool {
         self.matches.is_empty()
     }
+    
+    /// Appends `replacement` to `dst` with group references substituted.
+    ///
+    /// `$n` and `${n}` refer to group `n`, `${name}` to a named group and
+    /// `$$` is a literal `$`. Groups that did not participate expand to
+    /// nothing; a `$` not followed by a reference is kept as is.
+    pub fn expand(&self, replacement: &str, dst: &mut String) {
+        let mut rest = replacement;
+        while let Some(dollar) = rest.find('$') {
+            dst.push_str(&rest[..dollar]);
+            rest = &rest[dollar + 1..];
+            
+            if let Some(after) = rest.strip_prefix('$') {
+                dst.push('$');
+                rest = after;
+                continue;
+            }
+            
+            let (group, after) = if let Some(braced) = rest.strip_prefix('{') {
+                match braced.find('}') {
+                    Some(close) => (Some(&braced[..close]), &braced[close + 1..]),
+                    None => (None, rest),
+                }
+            } else {
+                let digits = rest.len() - rest.trim_start_matches(|c: char| c.is_ascii_digit()).len();
+                if digits > 0 {
+                    (Some(&rest[..digits]), &rest[digits..])
+                } else {
+                    (None, rest)
+                }
+            };
+            
+            match group {
+                Some(group) => {
+                    let m = match group.parse::<usize>() {
+                        Ok(index) => self.get(index),
+                        Err(_) => self.name(group),
+                    };
+                    if let Some(m) = m {
+                        dst.push_str(m.as_str());
+                    }
+                    rest = after;
+                }
+                None => dst.push('$'),
+            }
+        }
+        dst.push_str(rest);
+    }
 }
 
 /// Lightweight regex compatible with `regex::Regex`.
 #[derive(Debug, Clone)]
 pub struct LightRegex {
     engine: NfaEngine,
+    groups: GroupResolver,
     pattern: String,
 }
 
@@ -185,13 +250,25 @@ impl LightRegex {
     pub fn new(pattern: &str) -> Result<Self, Error> {
         let parsed = PatternParser::new(pattern).parse()?;
         let engine = NfaEngine::from_pattern(&parsed)?;
+        let groups = GroupResolver::new(&parsed);
         
         Ok(Self {
             engine,
+            groups,
             pattern: pattern.to_string(),
         })
     }
     
+    /// Returns the number of groups, counting the whole match as group 0.
+    pub fn captures_len(&self) -> usize {
+        self.groups.group_count()
+    }
+    
+    /// Returns the names of groups 1.., `None` for unnamed groups.
+    pub fn capture_names(&self) -> &[Option<String>] {
+        self.groups.group_names()
+    }
+    
     /// Tests if the regex matches anywhere in the text.
     pub fn is_match(&self, text: &str) -> bool {
         let mut matcher = Matcher::new(&self.engine);
@@ -213,15 +290,15 @@ impl LightRegex {
         FindMatches::new(self, text)
     }
     
-    /// Finds all matches and returns an iterator over capture groups.
+    /// Finds the first match and resolves its capture groups.
     pub fn captures<'t>(&self, text: &'t str) -> Option<Captures<'t>> {
-        if let Some(m) = self.find(text) {
-            let mut captures = Captures::new(text);
-            captures.matches.push(Some(m));
-            Some(captures)
-        } else {
-            None
-        }
+        self.find(text).map(|m| self.captures_for(m))
+    }
+    
+    /// Resolves the capture groups of a match found by this regex.
+    fn captures_for<'t>(&self, m: Match<'t>) -> Captures<'t> {
+        let spans = self.groups.resolve(m.text, m.start(), m.end());
+        Captures::from_spans(m.text, spans, self.groups.group_names())
     }
     
     /// Returns an iterator over all capture groups in the text.
@@ -332,7 +409,12 @@ impl<'r, 't> Iterator for FindMatches<'r, 't> {
         for start_pos in self.last_end..=self.text.len() {
             if let Some(internal_match) = matcher.find_at(self.text, start_pos) {
                 let m = Match::new(self.text, internal_match.start, internal_match.end);
-                self.last_end = m.end().max(self.last_end + 1);
+                // Step over one character after an empty match so it is not found again
+                self.last_end = if m.is_empty() {
+                    m.end() + self.text.get(m.end()..).and_then(|rest| rest.chars().next()).map_or(1, char::len_utf8)
+                } else {
+                    m.end()
+                };
                 return Some(m);
             }
         }
@@ -357,13 +439,8 @@ impl<'r, 't> Iterator for CaptureMatches<'r, 't> {
     type Item = Captures<'t>;
     
     fn next(&mut self) -> Option<Self::Item> {
-        if let Some(m) = self.matches.next() {
-            let mut captures = Captures::new(self.matches.text);
-            captures.matches.push(Some(m));
-            Some(captures)
-        } else {
-            None
-        }
+        let m = self.matches.next()?;
+        Some(self.matches.regex.captures_for(m))
     }
 }
 
@@ -560,6 +637,32 @@ mod tests {
         assert_eq!(m.as_str(), "123");
     }
     
+    #[test]
+    fn test_capture_groups() {
+        let re = LightRegex::new(r"(?P<key>\w+)=(\d+)").unwrap();
+        let caps = re.captures("set width=640").unwrap();
+        assert_eq!(caps.get(0).unwrap().as_str(), "width=640");
+        assert_eq!(caps.name("key").unwrap().as_str(), "width");
+        assert_eq!(caps.get(2).unwrap().as_str(), "640");
+        assert!(caps.name("missing").is_none());
+        assert_eq!(re.captures_len(), 3);
+        
+        let all: Vec<_> = re
+            .captures_iter("a=1 b=22")
+            .map(|caps| caps.get(2).unwrap().as_str())
+            .collect();
+        assert_eq!(all, vec!["1", "22"]);
+    }
+    
+    #[test]
+    fn test_expand() {
+        let re = LightRegex::new(r"(?P<first>\w+) (\w+)").unwrap();
+        let caps = re.captures("hello world").unwrap();
+        let mut dst = String::new();
+        caps.expand("$2 ${first} $$ ${9}$", &mut dst);
+        assert_eq!(dst, "world hello $ $");
+    }
+    
     #[test]
     fn test_regex_builder() {
         let re = RegexBuilder::new("hello")
@@ -353,16 +353,16 @@ impl Nfa {
     
     /// Thompson construction: creates NFA for concatenation.
     pub fn concat(mut first: Self, mut second: Self) -> Self {
+        // Merge states, adjusting IDs to avoid conflicts
+        let id_offset = first.next_state_id;
+        
         // Connect first's accept states to second's start state via epsilon
         for &accept_id in first.accept_states.clone().iter() {
             first.get_state_mut(accept_id).unwrap().set_accept(false);
             first.get_state_mut(accept_id).unwrap()
-                .add_transition(Transition::Epsilon, second.start_state);
+                .add_transition(Transition::Epsilon, second.start_state + id_offset);
         }
         
-        // Merge states, adjusting IDs to avoid conflicts
-        let id_offset = first.next_state_id;
-        
         for (old_id, mut state) in second.states {
             let new_id = old_id + id_offset;
             state.id = new_id;
@@ -603,7 +603,10 @@ impl NfaEngine {
                 let inner_nfa = Self::build_nfa(inner)?;
                 Ok(Nfa::zero_or_one(inner_nfa))
             }
-            
+
+            // Groups only matter for capture resolution, see `regex::captures`
+            PatternNode::Group(inner) | PatternNode::NonCapturing(inner) => Self::build_nfa(inner),
+
             _ => Err(EngineError::UnsupportedFeature(
                 format!("Pattern node {node:?} not yet implemented")
             )),
@@ -105,6 +105,10 @@ impl<'nfa> Matcher<'nfa> {
     }
     
     /// Attempts to find a match starting at the specified position.
+    ///
+    /// The longest match starting at `start_pos` wins, so the simulation
+    /// runs until no state is left alive rather than stopping at the first
+    /// accept state.
     pub fn find_at(&mut self, text: &str, start_pos: usize) -> Option<Match> {
         if start_pos > text.len() {
             return None;
@@ -119,10 +123,12 @@ impl<'nfa> Matcher<'nfa> {
         self.current_states.insert(self.nfa.start_state);
         Matcher::epsilon_closure_for_nfa(self.nfa, &mut self.current_states);
         
-        // Check for immediate accept (empty match)
-        if self.has_accept_state(&self.current_states) {
-            return Some(Match::new(start_pos, start_pos, String::new()));
-        }
+        // Empty match is the fallback if nothing longer is accepted
+        let mut last_accept = if self.has_accept_state(&self.current_states) {
+            Some(start_pos)
+        } else {
+            None
+        };
         
         // Process each character
         let text_bytes = text.as_bytes();
@@ -146,13 +152,12 @@ impl<'nfa> Matcher<'nfa> {
             // Compute epsilon closure of next states
             Matcher::epsilon_closure_for_nfa(self.nfa, &mut self.next_states);
             
-            // Check for match
+            // Remember the longest match so far
             if self.has_accept_state(&self.next_states) {
-                let match_text = text[start_pos..=pos].to_string();
-                return Some(Match::new(start_pos, pos + 1, match_text));
+                last_accept = Some(pos + 1);
             }
             
-            // If no active states, matching failed
+            // If no active states, matching is over
             if self.next_states.is_empty() {
                 break;
             }
@@ -164,7 +169,7 @@ impl<'nfa> Matcher<'nfa> {
         }
         
         // Handle end-of-input anchors
-        if !self.current_states.is_empty() {
+        if pos == text.len() && !self.current_states.is_empty() {
             self.next_states.clear();
             
             for &state_id in &self.current_states {
@@ -180,12 +185,11 @@ impl<'nfa> Matcher<'nfa> {
             Matcher::epsilon_closure_for_nfa(self.nfa, &mut self.next_states);
             
             if self.has_accept_state(&self.next_states) {
-                let match_text = text[start_pos..pos].to_string();
-                return Some(Match::new(start_pos, pos, match_text));
+                last_accept = Some(pos);
             }
         }
         
-        None
+        last_accept.map(|end| Match::new(start_pos, end, text[start_pos..end].to_string()))
     }
     
     /// Tests if a transition matches the current character and context.
@@ -11,7 +11,8 @@
 //! - Basic metacharacters: `.` (any character), `^` (start), `$` (end)
 //! - Character classes: `[abc]`, `[a-z]`, `[^abc]`, `\d`, `\w`, `\s`
 //! - Quantifiers: `*` (0+), `+` (1+), `?` (0-1)
-//! - Grouping: `(...)` basic grouping
+//! - Grouping: `(...)`, named `(?P<name>...)` and non-capturing `(?:...)`
+//! - Capture groups resolved per match (see [`captures`])
 //! - Escapes: `\t`, `\n`, `\\`, `\.`, etc.
 //!
 //! **Design Principles:**
@@ -33,6 +34,7 @@
 pub mod engine;
 pub mod parser;
 pub mod matcher;
+pub mod captures;
 
 // Compatibility layer with regex crate
 pub mod compat;
@@ -41,6 +43,7 @@ pub mod compat;
 pub use engine::{Nfa, NfaEngine, EngineError};
 pub use parser::{Pattern, PatternParser, PatternError};
 pub use matcher::{Match, MatchResult, Matcher};
+pub use captures::{GroupResolver, GroupSpans};
 pub use compat::{LightRegex, RegexBuilder, Error as RegexError};
 
 /// Result type for regex operations.
@@ -25,7 +25,9 @@
 //! - `?` - Zero or one
 //!
 //! **Grouping:**
-//! - `(...)` - Basic grouping
+//! - `(...)` - Capturing group
+//! - `(?P<name>...)` / `(?<name>...)` - Named capturing group
+//! - `(?:...)` - Non-capturing group
 //!
 //! **Escapes:**
 //! - `\\` - Literal backslash
@@ -43,7 +45,7 @@
 //! Factor      ::= Atom Quantifier?
 //! Atom        ::= Char | CharClass | Group | Anchor | '.'
 //! Quantifier  ::= '*' | '+' | '?'
-//! Group       ::= '(' Alternation ')'
+//! Group       ::= '(' ('?:' | '?P<' Name '>' | '?<' Name '>')? Alternation ')'
 //! CharClass   ::= '[' ClassItems ']'
 //! Anchor      ::= '^' | '$'
 //! ```
@@ -115,6 +117,8 @@ pub enum PatternNode {
     Question(Box<PatternNode>),
     /// Grouping (capturing group)
     Group(Box<PatternNode>),
+    /// Grouping without capture (`(?:...)`)
+    NonCapturing(Box<PatternNode>),
 }
 
 /// Complete parsed pattern with metadata.
@@ -124,6 +128,8 @@ pub struct Pattern {
     pub root: PatternNode,
     /// Original pattern string
     pub source: String,
+    /// Names of the capturing groups in opening order (`None` if unnamed)
+    pub group_names: Vec<Option<String>>,
 }
 
 /// Regular expression pattern parser.
@@ -134,6 +140,8 @@ pub struct PatternParser<'p> {
     pos: usize,
     /// Characters of the pattern
     chars: Vec<char>,
+    /// Names of the capturing groups seen so far
+    group_names: Vec<Option<String>>,
 }
 
 impl<'p> PatternParser<'p> {
@@ -143,6 +151,7 @@ impl<'p> PatternParser<'p> {
             pattern,
             pos: 0,
             chars: pattern.chars().collect(),
+            group_names: Vec::new(),
         }
     }
     
@@ -157,6 +166,7 @@ impl<'p> PatternParser<'p> {
         Ok(Pattern {
             root,
             source: self.pattern.to_string(),
+            group_names: self.group_names,
         })
     }
     
@@ -277,10 +287,32 @@ impl<'p> PatternParser<'p> {
         }
     }
     
-    /// Parses grouped expressions.
+    /// Parses grouped expressions: `(...)`, `(?:...)`, `(?P<name>...)` and `(?<name>...)`.
     fn parse_group(&mut self) -> Result<PatternNode, PatternError> {
         self.expect('(')?;
         
+        if self.current_char() == Some('?') {
+            self.advance();
+            match self.current_char() {
+                Some(':') => {
+                    self.advance();
+                    let inner = self.parse_alternation()?;
+                    self.expect(')')?;
+                    return Ok(PatternNode::NonCapturing(Box::new(inner)));
+                }
+                Some('P') if self.peek_char(1) == Some('<') => {
+                    self.advance();
+                }
+                Some('<') => {}
+                Some(ch) => return Err(PatternError::UnsupportedFeature(format!("group syntax (?{ch}"))),
+                None => return Err(PatternError::UnexpectedEnd),
+            }
+            let name = self.parse_group_name()?;
+            self.group_names.push(Some(name));
+        } else {
+            self.group_names.push(None);
+        }
+        
         let inner = self.parse_alternation()?;
         
         self.expect(')')?;
@@ -288,6 +320,30 @@ impl<'p> PatternParser<'p> {
         Ok(PatternNode::Group(Box::new(inner)))
     }
     
+    /// Parses `<name>` of a named group.
+    fn parse_group_name(&mut self) -> Result<String, PatternError> {
+        self.expect('<')?;
+        
+        let mut name = String::new();
+        while let Some(ch) = self.current_char() {
+            if ch == '>' {
+                break;
+            }
+            if !(ch.is_ascii_alphanumeric() || ch == '_') {
+                return Err(PatternError::UnexpectedChar(ch, self.pos));
+            }
+            name.push(ch);
+            self.advance();
+        }
+        
+        self.expect('>')?;
+        
+        if name.is_empty() {
+            return Err(PatternError::UnsupportedFeature("empty group name".to_string()));
+        }
+        Ok(name)
+    }
+    
     /// Parses character classes [abc].
     fn parse_char_class(&mut self) -> Result<PatternNode, PatternError> {
         self.expect('[')?;
@@ -572,6 +628,21 @@ mod tests {
         }
     }
     
+    #[test]
+    fn test_named_and_non_capturing_groups() {
+        let pattern = PatternParser::new(r"(?P<word>\w+)(?:-)(\d)(?<tail>x)?").parse().unwrap();
+        assert_eq!(
+            pattern.group_names,
+            vec![Some("word".to_string()), None, Some("tail".to_string())]
+        );
+        
+        let pattern = PatternParser::new("(?:ab)").parse().unwrap();
+        assert!(matches!(pattern.root, PatternNode::NonCapturing(_)));
+        assert!(pattern.group_names.is_empty());
+        
+        assert!(PatternParser::new("(?<>a)").parse().is_err());
+    }
+    
     #[test]
     fn test_complex_pattern() {
         let pattern = PatternParser::new(r"\d+\.\d*").parse().unwrap();
@@ -3,7 +3,7 @@
 #![allow(dead_code, missing_docs)]
 
 use crate::{Result, Error};
-use crate::repl::{ReplConfig, CompletionProvider, SyntaxHighlighter};
+use crate::repl::{ReplConfig, CompletionProvider, SyntaxHighlighter, MultilineInput};
 
 #[cfg(feature = "enhanced-repl")]
 use {
@@ -16,7 +16,9 @@ use {
 
 #[cfg(not(feature = "enhanced-repl"))]
 use {
-    rustyline::{DefaultEditor, error::ReadlineError},
+    rustyline::{Editor, error::ReadlineError, history::DefaultHistory},
+    rustyline::highlight::{Highlighter, MatchingBracketHighlighter},
+    std::borrow::Cow,
 };
 
 /// Enhanced editor that provides advanced line editing capabilities
@@ -24,11 +26,9 @@ pub struct EnhancedEditor {
     #[cfg(feature = "enhanced-repl")]
     editor: Reedline,
     #[cfg(not(feature = "enhanced-repl"))]
-    editor: DefaultEditor,
+    editor: Editor<LambdustHelper, DefaultHistory>,
     config: ReplConfig,
-    multiline_buffer: String,
-    in_multiline: bool,
-    paren_depth: isize,
+    multiline: MultilineInput,
 }
 
 impl EnhancedEditor {
@@ -44,24 +44,24 @@ impl EnhancedEditor {
 
             Ok(Self {
                 editor,
+                multiline: MultilineInput::new(config.multiline_editing),
                 config,
-                multiline_buffer: String::new(),
-                in_multiline: false,
-                paren_depth: 0,
             })
         }
         
         #[cfg(not(feature = "enhanced-repl"))]
         {
-            let editor = DefaultEditor::new()
+            let mut editor = Editor::new()
                 .map_err(|e| Error::io_error(format!("Failed to create editor: {e}")))?;
             
+            if config.paren_matching {
+                editor.set_helper(Some(LambdustHelper::new()));
+            }
+            
             Ok(Self {
                 editor,
+                multiline: MultilineInput::new(config.multiline_editing),
                 config,
-                multiline_buffer: String::new(),
-                in_multiline: false,
-                paren_depth: 0,
             })
         }
     }
@@ -84,6 +84,16 @@ impl EnhancedEditor {
         }
     }
 
+    /// Returns the prompt to show, switching to the continuation prompt
+    /// while a multi-line expression is pending.
+    fn effective_prompt(&self, prompt: &str) -> String {
+        if self.multiline.is_pending() {
+            self.config.continuation_prompt.clone()
+        } else {
+            prompt.to_string()
+        }
+    }
+
     #[cfg(feature = "enhanced-repl")]
     fn read_line_enhanced(
         &mut self, 
@@ -91,43 +101,28 @@ impl EnhancedEditor {
         _completion_provider: &mut CompletionProvider, 
         _highlighter: &SyntaxHighlighter
     ) -> Result<Option<String>> {
-        let prompt = LambdustPrompt::new(prompt.to_string());
-        
         loop {
+            let prompt = LambdustPrompt::new(self.effective_prompt(prompt));
             let sig = self.editor.read_line(&prompt)
                 .map_err(|e| Error::io_error(format!("Failed to read line: {e}")))?;
 
             match sig {
                 Signal::Success(buffer) => {
-                    let line = buffer.trim();
+                    let line = buffer.trim_end();
                     
-                    if line.is_empty() {
+                    if line.trim().is_empty() && !self.multiline.is_pending() {
                         continue;
                     }
 
-                    // Handle multiline input
-                    if self.needs_more_input(line) {
-                        self.multiline_buffer.push_str(line);
-                        self.multiline_buffer.push('\n');
-                        self.in_multiline = true;
-                        continue;
-                    } else if self.in_multiline {
-                        self.multiline_buffer.push_str(line);
-                        let complete_input = self.multiline_buffer.clone();
-                        self.multiline_buffer.clear();
-                        self.in_multiline = false;
-                        self.paren_depth = 0;
-                        return Ok(Some(complete_input));
-                    } else {
-                        return Ok(Some(line.to_string()));
+                    // Pasted blocks arrive as one buffer and are fed whole
+                    if let Some(complete_input) = self.multiline.feed(line) {
+                        return Ok(Some(complete_input.trim().to_string()));
                     }
                 }
                 Signal::CtrlD => {
-                    if self.in_multiline {
+                    if self.multiline.is_pending() {
                         // Cancel multiline input
-                        self.multiline_buffer.clear();
-                        self.in_multiline = false;
-                        self.paren_depth = 0;
+                        self.multiline.cancel();
                         println!("^D (multiline cancelled)");
                         continue;
                     } else {
@@ -135,11 +130,9 @@ impl EnhancedEditor {
                     }
                 }
                 Signal::CtrlC => {
-                    if self.in_multiline {
+                    if self.multiline.is_pending() {
                         // Cancel multiline input
-                        self.multiline_buffer.clear();
-                        self.in_multiline = false;
-                        self.paren_depth = 0;
+                        self.multiline.cancel();
                         println!("^C (multiline cancelled)");
                         continue;
                     } else {
@@ -154,46 +147,28 @@ impl EnhancedEditor {
     #[cfg(not(feature = "enhanced-repl"))]
     fn read_line_basic(&mut self, prompt: &str) -> Result<Option<String>> {
         loop {
-            let effective_prompt = if self.in_multiline {
-                format!("{prompt}...")
-            } else {
-                prompt.to_string()
-            };
+            let effective_prompt = self.effective_prompt(prompt);
 
             match self.editor.readline(&effective_prompt) {
                 Ok(line) => {
-                    let line = line.trim();
+                    let line = line.trim_end();
                     
-                    if line.is_empty() {
+                    if line.trim().is_empty() && !self.multiline.is_pending() {
                         continue;
                     }
 
-                    // Add to history
-                    let _ = self.editor.add_history_entry(line);
-
                     // Handle multiline input
-                    if self.needs_more_input(line) {
-                        self.multiline_buffer.push_str(line);
-                        self.multiline_buffer.push('\n');
-                        self.in_multiline = true;
-                        continue;
-                    } else if self.in_multiline {
-                        self.multiline_buffer.push_str(line);
-                        let complete_input = self.multiline_buffer.clone();
-                        self.multiline_buffer.clear();
-                        self.in_multiline = false;
-                        self.paren_depth = 0;
+                    if let Some(complete_input) = self.multiline.feed(line) {
+                        let complete_input = complete_input.trim().to_string();
+                        // Add the whole expression to history, not its fragments
+                        let _ = self.editor.add_history_entry(complete_input.as_str());
                         return Ok(Some(complete_input));
-                    } else {
-                        return Ok(Some(line.to_string()));
                     }
                 }
                 Err(ReadlineError::Interrupted) => {
-                    if self.in_multiline {
+                    if self.multiline.is_pending() {
                         // Cancel multiline input
-                        self.multiline_buffer.clear();
-                        self.in_multiline = false;
-                        self.paren_depth = 0;
+                        self.multiline.cancel();
                         println!("^C (multiline cancelled)");
                         continue;
                     } else {
@@ -202,11 +177,9 @@ impl EnhancedEditor {
                     }
                 }
                 Err(ReadlineError::Eof) => {
-                    if self.in_multiline {
+                    if self.multiline.is_pending() {
                         // Cancel multiline input
-                        self.multiline_buffer.clear();
-                        self.in_multiline = false;
-                        self.paren_depth = 0;
+                        self.multiline.cancel();
                         println!("^D (multiline cancelled)");
                         continue;
                     } else {
@@ -220,36 +193,6 @@ impl EnhancedEditor {
         }
     }
 
-    fn needs_more_input(&mut self, line: &str) -> bool {
-        // Count parentheses to determine if we need more input
-        let mut in_string = false;
-        let mut in_comment = false;
-        let mut escape_next = false;
-
-        for ch in line.chars() {
-            if escape_next {
-                escape_next = false;
-                continue;
-            }
-
-            match ch {
-                '\\' if in_string => escape_next = true,
-                '"' if !in_comment => in_string = !in_string,
-                ';' if !in_string => in_comment = true,
-                '\n' => in_comment = false,
-                '(' if !in_string && !in_comment => self.paren_depth += 1,
-                ')' if !in_string && !in_comment => self.paren_depth -= 1,
-                _ => {}
-            }
-        }
-
-        // We need more input if:
-        // 1. We have unmatched parentheses
-        // 2. We're in a string that wasn't closed
-        // 3. The line ends with a backslash (line continuation)
-        self.paren_depth > 0 || in_string || line.trim_end().ends_with('\\')
-    }
-
     pub fn add_to_history(&mut self, line: &str) -> Result<()> {
         #[cfg(feature = "enhanced-repl")]
         {
@@ -293,17 +236,64 @@ impl EnhancedEditor {
     }
 
     pub fn is_in_multiline(&self) -> bool {
-        self.in_multiline
+        self.multiline.is_pending()
     }
 
     pub fn cancel_multiline(&mut self) {
-        self.multiline_buffer.clear();
-        self.in_multiline = false;
-        self.paren_depth = 0;
+        self.multiline.cancel();
     }
 
     pub fn get_multiline_buffer(&self) -> &str {
-        &self.multiline_buffer
+        self.multiline.buffer()
+    }
+}
+
+/// Rustyline helper providing live matching-bracket highlighting.
+#[cfg(not(feature = "enhanced-repl"))]
+pub struct LambdustHelper {
+    brackets: MatchingBracketHighlighter,
+}
+
+#[cfg(not(feature = "enhanced-repl"))]
+impl LambdustHelper {
+    pub fn new() -> Self {
+        Self {
+            brackets: MatchingBracketHighlighter::new(),
+        }
+    }
+}
+
+#[cfg(not(feature = "enhanced-repl"))]
+impl Default for LambdustHelper {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(not(feature = "enhanced-repl"))]
+impl rustyline::Helper for LambdustHelper {}
+
+#[cfg(not(feature = "enhanced-repl"))]
+impl rustyline::completion::Completer for LambdustHelper {
+    type Candidate = String;
+}
+
+#[cfg(not(feature = "enhanced-repl"))]
+impl rustyline::hint::Hinter for LambdustHelper {
+    type Hint = String;
+}
+
+#[cfg(not(feature = "enhanced-repl"))]
+impl rustyline::validate::Validator for LambdustHelper {}
+
+#[cfg(not(feature = "enhanced-repl"))]
+impl Highlighter for LambdustHelper {
+    fn highlight<'l>(&self, line: &'l str, pos: usize) -> Cow<'l, str> {
+        self.brackets.highlight(line, pos)
+    }
+
+    fn highlight_char(&self, line: &str, pos: usize, forced: bool) -> bool {
+        self.brackets.highlight_char(line, pos, forced)
     }
 }
 
@@ -514,21 +504,4 @@ mod tests {
         // Nested expression
         assert_eq!(helper.calculate_indentation("(let ((x"), 4);
     }
-
-    #[test]
-    fn test_multiline_detection() {
-        let config = ReplConfig::default();
-        let mut editor = EnhancedEditor::new(config).unwrap();
-        
-        // Complete expression - no more input needed
-        assert!(!editor.needs_more_input("(+ 1 2)"));
-        
-        // Incomplete expression - more input needed
-        editor.paren_depth = 0; // Reset
-        assert!(editor.needs_more_input("(+ 1"));
-        
-        // String continuation
-        editor.paren_depth = 0; // Reset
-        assert!(editor.needs_more_input("\"hello"));
-    }
-}
\ No newline at end of file
+}
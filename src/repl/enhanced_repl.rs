@@ -190,11 +190,12 @@ impl EnhancedRepl {
                             }
                         }
                         Err(e) => {
+                            let message = e.display_with_location(line);
                             #[cfg(feature = "repl")]
-                            eprintln!("{}", format!("Error: {e}").bright_red());
+                            eprintln!("{}", format!("Error: {message}").bright_red());
                             #[cfg(not(feature = "repl"))]
-                            eprintln!("Error: {e}");
-                            self.session.add_command(line.to_string(), None, Some(e.to_string()))?;
+                            eprintln!("Error: {message}");
+                            self.session.add_command(line.to_string(), None, Some(message))?;
                         }
                     }
 
@@ -253,6 +254,23 @@ impl EnhancedRepl {
                 self.session.save_session(session_name)?;
                 Ok(Some(true))
             }
+            line if line.starts_with(":time ") => {
+                let source = line.strip_prefix(":time ").unwrap().trim();
+                match super::timing::time_eval(&mut self.lambdust, source) {
+                    Ok(timing) => {
+                        println!("{}", timing.value);
+                        println!("({timing})");
+                    }
+                    Err(e) => {
+                        let message = e.display_with_location(source);
+                        #[cfg(feature = "repl")]
+                        eprintln!("{}", format!("Error: {message}").bright_red());
+                        #[cfg(not(feature = "repl"))]
+                        eprintln!("Error: {message}");
+                    }
+                }
+                Ok(Some(true))
+            }
             _ => Ok(None)
         }
     }
@@ -273,6 +291,7 @@ impl EnhancedRepl {
             println!("  {}  - List all sessions", ":sessions".bright_yellow());
             println!("  {}  - Load a session by ID", ":load <session-id>".bright_yellow());
             println!("  {}  - Save current session with name", ":save <name>".bright_yellow());
+            println!("  {}  - Evaluate and report the time taken", ":time <expr>".bright_yellow());
             println!("  {}  - Exit the REPL", "(exit), (quit), :quit, :q".bright_yellow());
             println!();
             println!("{}", "Example expressions:".bright_blue().bold());
@@ -290,6 +309,7 @@ impl EnhancedRepl {
             println!("  :sessions  - List all sessions");
             println!("  :load <session-id>  - Load a session by ID");
             println!("  :save <name>  - Save current session with name");
+            println!("  :time <expr>  - Evaluate and report the time taken");
             println!("  (exit), (quit), :quit, :q  - Exit the REPL");
             println!();
             println!("Example expressions:");
@@ -5,6 +5,7 @@
 //! to the full-featured REPL implementations.
 
 use crate::{Lambdust, Error, Result};
+use super::timing::time_eval;
 use std::io::{self, Write, BufRead};
 
 #[cfg(feature = "minimal-repl")]
@@ -153,12 +154,17 @@ impl MinimalRepl {
     }
     
     /// Handles meta commands (starting with :).
-    fn handle_meta_command(&self, input: &str, _lambdust: &mut Lambdust) -> Result<bool> {
+    fn handle_meta_command(&self, input: &str, lambdust: &mut Lambdust) -> Result<bool> {
         if !input.starts_with(':') {
             return Ok(false);
         }
         
         let command = &input[1..];
+        if let Some(expr) = command.strip_prefix("time ") {
+            self.time_and_print(expr.trim(), lambdust);
+            return Ok(true);
+        }
+
         match command {
             "quit" | "q" | "exit" => std::process::exit(0),
             "help" | "h" => {
@@ -204,6 +210,7 @@ impl MinimalRepl {
                 println!("  {}  - Exit REPL", ":quit, :q, :exit".cyan());
                 println!("  {}  - Show command history", ":history".cyan());
                 println!("  {}  - Clear screen", ":clear".cyan());
+                println!("  {}  - Evaluate and report the time taken", ":time <expr>".cyan());
                 println!();
                 println!("{}", "Examples:".bright_yellow().bold());
                 println!("  {}", "(+ 1 2 3)".green());
@@ -215,6 +222,7 @@ impl MinimalRepl {
                 println!("  :quit, :q, :exit  - Exit REPL");
                 println!("  :history          - Show command history");
                 println!("  :clear            - Clear screen");
+                println!("  :time <expr>      - Evaluate and report the time taken");
                 println!();
                 println!("Examples:");
                 println!("  (+ 1 2 3)");
@@ -229,6 +237,7 @@ impl MinimalRepl {
             println!("  :quit, :q, :exit  - Exit REPL");
             println!("  :history          - Show command history");
             println!("  :clear            - Clear screen");
+            println!("  :time <expr>      - Evaluate and report the time taken");
             println!();
             println!("Examples:");
             println!("  (+ 1 2 3)");
@@ -267,6 +276,17 @@ impl MinimalRepl {
         }
     }
     
+    /// Evaluates expression and prints its result and the time taken.
+    fn time_and_print(&self, input: &str, lambdust: &mut Lambdust) {
+        match time_eval(lambdust, input) {
+            Ok(timing) => {
+                println!("=> {}", timing.value);
+                println!("({timing})");
+            }
+            Err(e) => eprintln!("Error: {}", e.display_with_location(input)),
+        }
+    }
+
     /// Evaluates expression and prints result.
     fn evaluate_and_print(&self, input: &str, lambdust: &mut Lambdust) {
         let start_time = if self.config.show_timing {
@@ -303,17 +323,18 @@ impl MinimalRepl {
                 }
             }
             Err(e) => {
+                let message = e.display_with_location(input);
                 #[cfg(feature = "minimal-repl")]
                 {
                     if self.config.use_colors {
-                        eprintln!("{}: {}", "Error".red().bold(), e);
+                        eprintln!("{}: {}", "Error".red().bold(), message);
                     } else {
-                        eprintln!("Error: {e}");
+                        eprintln!("Error: {message}");
                     }
                 }
                 #[cfg(not(feature = "minimal-repl"))]
                 {
-                    eprintln!("Error: {}", e);
+                    eprintln!("Error: {}", message);
                 }
             }
         }
@@ -8,6 +8,11 @@
 //! - Multi-line input with proper indentation
 //! - Code introspection and development tools
 
+// Evaluation timing shared by every REPL flavour
+pub mod timing;
+// Multi-line input accumulation shared by every REPL flavour
+pub mod multiline;
+
 // Minimal REPL for lightweight builds
 #[cfg(feature = "minimal-repl")]
 pub mod minimal_repl;
@@ -36,6 +41,9 @@ pub mod enhanced_repl;
 
 use crate::{Lambdust, Result};
 
+pub use timing::{EvalTiming, time_eval};
+pub use multiline::{MultilineInput, needs_more_input};
+
 // Re-exports for minimal REPL
 #[cfg(feature = "minimal-repl")]
 pub use minimal_repl::{MinimalRepl, MinimalReplConfig, start_minimal_repl, start_minimal_repl_with_config};
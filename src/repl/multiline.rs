@@ -0,0 +1,178 @@
+//! Accumulation of multi-line REPL input.
+//!
+//! [`MultilineInput`] collects lines behind a continuation prompt until
+//! [`needs_more_input`] finds a complete expression, so every REPL flavour
+//! reads definitions that span several lines the same way.
+
+/// Accumulates REPL input lines until they form a complete expression.
+#[derive(Debug, Clone, Default)]
+pub struct MultilineInput {
+    buffer: String,
+    enabled: bool,
+}
+
+impl MultilineInput {
+    /// Creates an accumulator; when `enabled` is false every line is complete.
+    pub fn new(enabled: bool) -> Self {
+        Self {
+            buffer: String::new(),
+            enabled,
+        }
+    }
+
+    /// Feeds a line (or a pasted multi-line block) and returns the whole
+    /// input once it no longer needs more lines.
+    pub fn feed(&mut self, line: &str) -> Option<String> {
+        if !self.buffer.is_empty() {
+            self.buffer.push('\n');
+        }
+        self.buffer.push_str(line);
+
+        if self.enabled && needs_more_input(&self.buffer) {
+            None
+        } else {
+            Some(std::mem::take(&mut self.buffer))
+        }
+    }
+
+    /// Whether a partial expression is waiting for more lines.
+    pub fn is_pending(&self) -> bool {
+        !self.buffer.is_empty()
+    }
+
+    /// Discards any partial input.
+    pub fn cancel(&mut self) {
+        self.buffer.clear();
+    }
+
+    /// The partial input accumulated so far.
+    pub fn buffer(&self) -> &str {
+        &self.buffer
+    }
+}
+
+/// Returns true if `text` ends in the middle of an expression.
+///
+/// Parentheses inside strings, character literals (`#\(`) and comments
+/// are ignored. Balanced text is confirmed with the parser so that other
+/// unfinished forms (such as a trailing quote) also ask for more input.
+pub fn needs_more_input(text: &str) -> bool {
+    let mut depth: isize = 0;
+    let mut in_string = false;
+    let mut in_line_comment = false;
+    let mut block_comment_depth = 0usize;
+    let mut chars = text.chars().peekable();
+
+    while let Some(ch) = chars.next() {
+        if in_line_comment {
+            if ch == '\n' {
+                in_line_comment = false;
+            }
+            continue;
+        }
+
+        if block_comment_depth > 0 {
+            match (ch, chars.peek()) {
+                ('|', Some('#')) => {
+                    chars.next();
+                    block_comment_depth -= 1;
+                }
+                ('#', Some('|')) => {
+                    chars.next();
+                    block_comment_depth += 1;
+                }
+                _ => {}
+            }
+            continue;
+        }
+
+        if in_string {
+            match ch {
+                '\\' => {
+                    chars.next();
+                }
+                '"' => in_string = false,
+                _ => {}
+            }
+            continue;
+        }
+
+        match ch {
+            '"' => in_string = true,
+            ';' => in_line_comment = true,
+            '#' => match chars.peek() {
+                Some('|') => {
+                    chars.next();
+                    block_comment_depth += 1;
+                }
+                Some('\\') => {
+                    // Character literal: the next character is never syntax
+                    chars.next();
+                    chars.next();
+                }
+                _ => {}
+            },
+            '(' | '[' => depth += 1,
+            ')' | ']' => depth -= 1,
+            _ => {}
+        }
+    }
+
+    if in_string || block_comment_depth > 0 || depth > 0 {
+        return true;
+    }
+
+    // Too many closing parentheses is an error the evaluator should report
+    depth == 0 && crate::parser::is_incomplete_input(text)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_multiline_detection() {
+        // Complete expression - no more input needed
+        assert!(!needs_more_input("(+ 1 2)"));
+        
+        // Incomplete expression - more input needed
+        assert!(needs_more_input("(+ 1"));
+        
+        // String continuation
+        assert!(needs_more_input("\"hello"));
+    }
+
+    #[test]
+    fn test_needs_more_input_ignores_parens_in_strings_and_comments() {
+        assert!(!needs_more_input("(display \"(((\")"));
+        assert!(!needs_more_input("(string #\\( #\\))"));
+        assert!(!needs_more_input("(+ 1 2) ; (unbalanced"));
+        assert!(!needs_more_input("#| ( |# (+ 1 2)"));
+        assert!(needs_more_input("(display \")\""));
+        assert!(needs_more_input("(define (f x) ; )"));
+        assert!(needs_more_input("#| unfinished"));
+    }
+
+    #[test]
+    fn test_multiline_input_accumulates_until_balanced() {
+        let mut input = MultilineInput::new(true);
+        
+        assert_eq!(input.feed("(define (square x)"), None);
+        assert!(input.is_pending());
+        assert_eq!(
+            input.feed("  (* x x))"),
+            Some("(define (square x)\n  (* x x))".to_string())
+        );
+        assert!(!input.is_pending());
+        
+        // A pasted block containing several lines is complete at once
+        assert_eq!(
+            input.feed("(define (f)\n  \")\")"),
+            Some("(define (f)\n  \")\")".to_string())
+        );
+        
+        // Disabled multiline editing treats every line as complete
+        let mut input = MultilineInput::new(false);
+        assert_eq!(input.feed("(+ 1"), Some("(+ 1".to_string()));
+    }
+}
@@ -19,6 +19,12 @@ pub struct ReplConfig {
     pub profiling_enabled: bool,
     /// Custom key bindings
     pub key_bindings: HashMap<String, String>,
+    /// Keep reading lines until an incomplete expression balances
+    pub multiline_editing: bool,
+    /// Prompt shown while a multi-line expression is pending
+    pub continuation_prompt: String,
+    /// Highlight the bracket matching the one under the cursor
+    pub paren_matching: bool,
 }
 
 impl Default for ReplConfig {
@@ -31,6 +37,9 @@ impl Default for ReplConfig {
             session_management: true,
             profiling_enabled: false,
             key_bindings: HashMap::new(),
+            multiline_editing: true,
+            continuation_prompt: "...   ".to_string(),
+            paren_matching: true,
         }
     }
 }
\ No newline at end of file
@@ -0,0 +1,47 @@
+//! Evaluation timing for the REPL `:time` command.
+//!
+//! [`time_eval`] evaluates an expression and reports the wall-clock time it
+//! took. Values live on the host heap and are reference counted, so there
+//! are no collector statistics to report alongside it.
+
+use crate::eval::Value;
+use crate::{Lambdust, Result};
+use std::fmt;
+use std::time::{Duration, Instant};
+
+/// Result and duration of one timed evaluation.
+#[derive(Debug, Clone)]
+pub struct EvalTiming {
+    /// Value the expression evaluated to
+    pub value: Value,
+    /// Wall-clock time spent evaluating
+    pub elapsed: Duration,
+}
+
+/// Evaluates `source` and measures how long it takes.
+pub fn time_eval(lambdust: &mut Lambdust, source: &str) -> Result<EvalTiming> {
+    let start = Instant::now();
+    let value = lambdust.eval(source, Some("<repl>"))?;
+    let elapsed = start.elapsed();
+
+    Ok(EvalTiming { value, elapsed })
+}
+
+impl fmt::Display for EvalTiming {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "time: {:?}", self.elapsed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_time_eval_returns_value() {
+        let mut lambdust = Lambdust::new();
+        let timing = time_eval(&mut lambdust, "(+ 1 2)").unwrap();
+        assert_eq!(timing.value, Value::integer(3));
+        assert!(timing.to_string().starts_with("time: "));
+    }
+}
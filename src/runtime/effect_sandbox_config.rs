@@ -87,6 +87,17 @@ impl Default for EffectSandboxConfig {
     }
 }
 
+impl EffectSandboxConfig {
+    /// Returns true if every effect in `effects` may run inside the sandbox.
+    ///
+    /// Pure computations are always permitted.
+    pub fn permits(&self, effects: &[Effect]) -> bool {
+        effects
+            .iter()
+            .all(|effect| effect.is_pure() || self.allowed_effects.contains(effect))
+    }
+}
+
 impl Default for SandboxResourceLimits {
     fn default() -> Self {
         Self {
@@ -145,6 +145,13 @@ impl MultithreadedEvaluator {
                 // Define in global environment
                 self.global_env.define_global(name, value)?;
             }
+            EvaluatorMessage::Map { sender, .. } => {
+                // Procedures are applied by the thread pool's workers
+                let _ = sender.send(Err(crate::diagnostics::Error::runtime_error(
+                    "maps must be submitted to the thread pool".to_string(),
+                    None,
+                ).boxed()));
+            }
             EvaluatorMessage::ImportModule { import_spec, sender } => {
                 // Handle module import
                 let result = self.handle_import(import_spec);
@@ -2,9 +2,10 @@
 
 use crate::ast::Expr;
 use crate::diagnostics::{Result, Span};
-use crate::eval::Value;
+use crate::eval::{ThreadSafeEnvironment, Value};
 use crate::module_system::ImportSpec;
 use std::collections::HashMap;
+use std::sync::Arc;
 
 /// Messages sent to evaluator threads.
 #[derive(Debug)]
@@ -25,6 +26,18 @@ pub enum EvaluatorMessage {
         /// Variable value
         value: Value,
     },
+    /// Apply a procedure to each element of a chunk in order, stopping at
+    /// the first failure, and send the results back
+    Map {
+        /// The procedure to apply
+        procedure: Value,
+        /// The elements to apply it to
+        elements: Vec<Value>,
+        /// Global environment the applications run against
+        globals: Arc<ThreadSafeEnvironment>,
+        /// Channel to send the results back
+        sender: crossbeam::channel::Sender<Result<Vec<Value>>>,
+    },
     /// Import a module
     ImportModule {
         /// Import specification
@@ -493,6 +493,20 @@ impl AllocationStatistics {
         self.failed_allocations.fetch_add(1, Ordering::Relaxed);
     }
 
+    /// Estimate how long `free_bytes` last at `allocations_per_second`,
+    /// using the average allocation size seen so far.
+    ///
+    /// Returns `None` while no allocation rate can be estimated.
+    pub fn time_to_fill(&self, free_bytes: usize, allocations_per_second: f64) -> Option<Duration> {
+        let avg_size = self.avg_allocation_size.load(Ordering::Relaxed) as f64;
+        let bytes_per_second = allocations_per_second * avg_size;
+        if bytes_per_second > 0.0 {
+            Some(Duration::from_secs_f64(free_bytes as f64 / bytes_per_second))
+        } else {
+            None
+        }
+    }
+
     /// Get allocation failure rate
     pub fn failure_rate(&self) -> f64 {
         let total = self.total_allocations.load(Ordering::Relaxed);
@@ -567,7 +581,7 @@ impl AllocationCoordinator {
     fn allocate_small_object(&self, value: Value, size: usize) -> Result<Arc<ObjectHeader>, String> {
         // Try TLAB allocation first
         if let Ok(tlab) = self.tlab_manager.get_tlab() {
-            if let Some(ptr) = tlab.try_allocate(size, 8) {
+            if let Some(ptr) = tlab.try_allocate(ObjectHeader::slot_size(size), std::mem::align_of::<ObjectHeader>()) {
                 // Successfully allocated in TLAB
                 let header = ObjectHeader::new(value, size, GenerationId::Young);
                 
@@ -596,6 +610,11 @@ impl AllocationCoordinator {
         self.allocation_sampler.allocation_rate()
     }
 
+    /// Estimate how long `free_bytes` last at the current allocation rate
+    pub fn estimated_time_to_fill(&self, free_bytes: usize) -> Option<Duration> {
+        self.statistics.time_to_fill(free_bytes, self.allocation_rate())
+    }
+
     /// Get allocation statistics
     pub fn get_statistics(&self) -> &AllocationStatistics {
         &self.statistics
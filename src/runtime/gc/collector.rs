@@ -10,7 +10,7 @@ use crate::eval::value::Value;
 use crate::runtime::gc::generation::{ObjectHeader, GenerationId, CollectionResult};
 use crate::runtime::gc::parallel_gc::{SafepointCoordinator, GcStatistics};
 use std::collections::{HashMap, HashSet, VecDeque};
-use std::sync::{Arc, RwLock, Mutex, atomic::{AtomicBool, AtomicUsize, Ordering}};
+use std::sync::{Arc, RwLock, Mutex, atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering}};
 use std::time::{Duration, Instant};
 use std::thread;
 
@@ -206,32 +206,75 @@ impl ObjectMarker {
 
     /// Start marking from roots
     pub fn mark_from_roots(&self, roots: Vec<*mut ObjectHeader>) -> Result<(), String> {
+        self.begin_marking(roots)?;
+
+        // Process marking
+        self.process_marking()?;
+        self.marking_complete.store(true, Ordering::Relaxed);
+        Ok(())
+    }
+
+    /// Clear previous marking state and queue the roots without marking them.
+    ///
+    /// The mark queue then serves as the checkpoint that [`Self::mark_for`]
+    /// resumes from.
+    pub fn begin_marking(&self, roots: Vec<*mut ObjectHeader>) -> Result<(), String> {
         self.marking_complete.store(false, Ordering::Relaxed);
         
-        // Clear previous marking state
         {
             let mut marked = self.marked_objects.write().map_err(|_| "Failed to write marked objects")?;
             marked.clear();
         }
-        {
-            let mut queue = self.mark_queue.lock().map_err(|_| "Failed to lock mark queue")?;
-            queue.clear();
+
+        let mut queue = self.mark_queue.lock().map_err(|_| "Failed to lock mark queue")?;
+        queue.clear();
+        for root in roots {
+            if !root.is_null() {
+                queue.push_back(GcPtr::new(root));
+            }
         }
+        Ok(())
+    }
 
-        // Add roots to work queue
-        {
-            let mut queue = self.mark_queue.lock().map_err(|_| "Failed to lock mark queue")?;
-            for root in roots {
-                if !root.is_null() {
-                    queue.push_back(GcPtr::new(root));
-                }
+    /// Process the marking queue until it drains or `budget` elapses.
+    ///
+    /// Returns true once marking is complete. Unprocessed objects stay queued
+    /// for the next call. At least one object is processed per call so that
+    /// marking always makes progress, even with a zero budget.
+    pub fn mark_for(&self, budget: Duration) -> Result<bool, String> {
+        let deadline = Instant::now() + budget;
+        loop {
+            let obj = {
+                let mut queue = self.mark_queue.lock().map_err(|_| "Failed to lock mark queue")?;
+                queue.pop_front()
+            };
+
+            let Some(obj_ptr) = obj else {
+                self.marking_complete.store(true, Ordering::Relaxed);
+                return Ok(true);
+            };
+            let raw_ptr = obj_ptr.as_ptr();
+            if !raw_ptr.is_null() && self.mark_object(raw_ptr)? {
+                self.scan_object_references(raw_ptr)?;
+            }
+
+            if Instant::now() >= deadline {
+                break;
             }
         }
 
-        // Process marking
-        self.process_marking()?;
-        self.marking_complete.store(true, Ordering::Relaxed);
-        Ok(())
+        let queue = self.mark_queue.lock().map_err(|_| "Failed to lock mark queue")?;
+        if queue.is_empty() {
+            self.marking_complete.store(true, Ordering::Relaxed);
+        }
+        Ok(queue.is_empty())
+    }
+
+    /// Number of objects marked and still queued, for progress reporting
+    pub fn marking_counts(&self) -> Result<(usize, usize), String> {
+        let marked = self.marked_objects.read().map_err(|_| "Failed to read marked objects")?.len();
+        let queued = self.mark_queue.lock().map_err(|_| "Failed to lock mark queue")?.len();
+        Ok((marked, queued))
     }
 
     /// Process the marking queue
@@ -597,8 +640,8 @@ pub struct IncrementalCollector {
     mark_sweep_collector: Arc<MarkSweepCollector>,
     /// Current collection state
     state: Arc<RwLock<IncrementalState>>,
-    /// Work budget per step (in microseconds)
-    step_budget_us: usize,
+    /// Wall-clock budget per step (in microseconds)
+    step_budget_us: AtomicU64,
 }
 
 /// State of incremental collection
@@ -625,14 +668,27 @@ impl IncrementalCollector {
             copying_collector,
             mark_sweep_collector,
             state: Arc::new(RwLock::new(IncrementalState::Idle)),
-            step_budget_us,
+            step_budget_us: AtomicU64::new(step_budget_us as u64),
         }
     }
 
+    /// Set the wall-clock budget for each step.
+    ///
+    /// Takes effect from the next step, including one resuming a collection
+    /// already in progress.
+    pub fn set_step_budget(&self, budget: Duration) {
+        self.step_budget_us.store(budget.as_micros() as u64, Ordering::Relaxed);
+    }
+
+    /// Get the wall-clock budget for each step
+    pub fn step_budget(&self) -> Duration {
+        Duration::from_micros(self.step_budget_us.load(Ordering::Relaxed))
+    }
+
     /// Perform one incremental collection step
     pub fn perform_incremental_step(&self) -> Result<bool, String> {
         let start_time = Instant::now();
-        let budget = Duration::from_micros(self.step_budget_us as u64);
+        let budget = self.step_budget();
 
         let current_state = {
             let state = self.state.read().map_err(|_| "Failed to read incremental state")?;
@@ -645,9 +701,10 @@ impl IncrementalCollector {
                 self.start_incremental_collection()?;
                 Ok(false) // Not complete yet
             }
-            IncrementalState::Marking { progress } => {
-                // Continue marking phase
-                let new_progress = self.perform_marking_step(progress, budget)?;
+            IncrementalState::Marking { .. } => {
+                // Continue marking phase with what is left of the budget
+                let remaining = budget.saturating_sub(start_time.elapsed());
+                let new_progress = self.perform_marking_step(remaining)?;
                 
                 if new_progress >= 1.0 {
                     // Marking complete, move to sweeping
@@ -687,23 +744,26 @@ impl IncrementalCollector {
         }
     }
 
-    /// Start a new incremental collection
+    /// Start a new incremental collection by queueing the roots for marking
     fn start_incremental_collection(&self) -> Result<(), String> {
+        let roots = self.mark_sweep_collector.root_set.get_all_roots()?;
+        self.mark_sweep_collector.marker.begin_marking(roots)?;
+
         let mut state = self.state.write().map_err(|_| "Failed to write incremental state")?;
         *state = IncrementalState::Marking { progress: 0.0 };
         Ok(())
     }
 
-    /// Perform one step of the marking phase
-    fn perform_marking_step(&self, current_progress: f64, _budget: Duration) -> Result<f64, String> {
-        // In a real implementation, this would:
-        // 1. Mark objects for a limited time budget
-        // 2. Track progress through the object graph
-        // 3. Return updated progress percentage
-        
-        // For now, simulate progress
-        let progress_increment = 0.1; // 10% per step
-        Ok((current_progress + progress_increment).min(1.0))
+    /// Perform one step of the marking phase, resuming from the mark queue
+    /// left by the previous step
+    fn perform_marking_step(&self, budget: Duration) -> Result<f64, String> {
+        let marker = &self.mark_sweep_collector.marker;
+        if marker.mark_for(budget)? {
+            return Ok(1.0);
+        }
+
+        let (marked, queued) = marker.marking_counts()?;
+        Ok(marked as f64 / (marked + queued) as f64)
     }
 
     /// Perform one step of the sweeping phase
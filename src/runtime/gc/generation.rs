@@ -45,6 +45,12 @@ impl ObjectHeader {
         }
     }
 
+    /// Bytes to reserve for an object of `size` bytes. The slot receives a
+    /// copy of the header, so it is never smaller than one.
+    pub fn slot_size(size: usize) -> usize {
+        size.max(std::mem::size_of::<ObjectHeader>())
+    }
+
     /// Mark this object as live
     pub fn mark(&mut self) {
         self.mark = true;
@@ -374,7 +380,7 @@ impl Generation for YoungGeneration {
         let active_region = &regions[active_idx];
 
         // Try to allocate in the active region
-        if let Some(ptr) = active_region.try_allocate(size, 8) {
+        if let Some(ptr) = active_region.try_allocate(ObjectHeader::slot_size(size), std::mem::align_of::<ObjectHeader>()) {
             let header = Arc::new(ObjectHeader::new(value, size, self.id));
             self.statistics.record_allocation(size);
             
@@ -502,7 +508,7 @@ impl Generation for OldGeneration {
         
         // Try to allocate in existing regions
         for region in regions.iter() {
-            if let Some(ptr) = region.try_allocate(size, 8) {
+            if let Some(ptr) = region.try_allocate(ObjectHeader::slot_size(size), std::mem::align_of::<ObjectHeader>()) {
                 let header = Arc::new(ObjectHeader::new(value, size, self.id));
                 self.statistics.record_allocation(size);
                 
@@ -528,7 +534,7 @@ impl Generation for OldGeneration {
         // Retry allocation
         let regions = self.regions.read().map_err(|_| "Failed to read regions after expansion")?;
         if let Some(region) = regions.last() {
-            if let Some(ptr) = region.try_allocate(size, 8) {
+            if let Some(ptr) = region.try_allocate(ObjectHeader::slot_size(size), std::mem::align_of::<ObjectHeader>()) {
                 let header = Arc::new(ObjectHeader::new(value, size, self.id));
                 self.statistics.record_allocation(size);
                 
@@ -89,6 +89,7 @@ pub use parallel_gc::{
     GcStatistics, 
     SafepointCoordinator,
     AdaptiveTuningParams,
+    MinorCollectionTrigger,
     CollectionRequest,
 };
 
@@ -190,6 +191,19 @@ impl GcConfigBuilder {
         self
     }
 
+    /// Set the young generation occupancy (fraction) at which a minor
+    /// collection always starts
+    pub fn young_high_water(mut self, fraction: f64) -> Self {
+        self.config.young_high_water = fraction;
+        self
+    }
+
+    /// Set the minimum time between early minor collections in milliseconds
+    pub fn min_minor_interval_ms(mut self, ms: u64) -> Self {
+        self.config.min_minor_interval_ms = ms;
+        self
+    }
+
     /// Build the configuration
     pub fn build(self) -> ParallelGcConfig {
         self.config
@@ -245,7 +259,9 @@ impl GcSystem {
         // Create main GC coordinator
         let parallel_gc = ParallelGc::new(config);
         let safepoint = Arc::new(SafepointCoordinator::new());
-        let statistics = Arc::new(GcStatistics::new());
+        // Collectors report into the coordinator's counters so that
+        // `get_statistics` reflects every collection performed
+        let statistics = parallel_gc.statistics_handle();
 
         // Create collectors
         let copying_collector = Arc::new(CopyingCollector::new(
@@ -286,6 +302,20 @@ impl GcSystem {
         self.copying_collector.collect()
     }
 
+    /// Perform a minor collection if the adaptive trigger calls for one
+    pub fn collect_minor_if_due(&self) -> GcResult<Option<CollectionResult>> {
+        let utilization = self.generation_manager.get_heap_statistics().young_utilization / 100.0;
+        let young_size = self.parallel_gc.config().young_generation_size as f64;
+        let free_bytes = (young_size * (1.0 - utilization)).max(0.0) as usize;
+        let time_to_fill = self.allocation_coordinator.estimated_time_to_fill(free_bytes);
+
+        if self.parallel_gc.minor_collection_due(utilization, time_to_fill)? {
+            self.collect_minor().map(Some)
+        } else {
+            Ok(None)
+        }
+    }
+
     /// Perform a major collection
     pub fn collect_major(&self, concurrent: bool) -> GcResult<CollectionResult> {
         self.mark_sweep_collector.collect(concurrent)
@@ -296,6 +326,32 @@ impl GcSystem {
         self.incremental_collector.perform_incremental_step()
     }
 
+    /// Set the wall-clock budget of each incremental collection step.
+    ///
+    /// Marking stops when the budget runs out and resumes from its mark
+    /// stack on the next call to [`Self::collect_incremental_step`].
+    pub fn set_incremental_budget(&self, budget: std::time::Duration) {
+        self.incremental_collector.set_step_budget(budget);
+    }
+
+    /// Get the wall-clock budget of each incremental collection step
+    pub fn incremental_budget(&self) -> std::time::Duration {
+        self.incremental_collector.step_budget()
+    }
+
+    /// Get a snapshot of the adaptive tuning parameters
+    pub fn tuning_params(&self) -> AdaptiveTuningParams {
+        self.parallel_gc.tuning_params()
+    }
+
+    /// Adjust the adaptive tuning parameters in place
+    pub fn update_tuning_params<F>(&self, update: F) -> GcResult<()>
+    where
+        F: FnOnce(&mut AdaptiveTuningParams),
+    {
+        self.parallel_gc.update_tuning_params(update)
+    }
+
     /// Allocate a new object
     pub fn allocate(&self, value: crate::eval::value::Value, size: usize) -> GcResult<Arc<ObjectHeader>> {
         self.allocation_coordinator.allocate(value, size)
@@ -341,12 +397,30 @@ impl GcSystem {
         }
     }
 
+    /// Creates the collector an evaluator hands to the Scheme-level GC API.
+    ///
+    /// The generations are modest and no worker threads are started;
+    /// collections run on the caller.
+    pub fn for_evaluator() -> GcResult<Self> {
+        let config = GcConfigBuilder::new()
+            .young_generation_mb(8)
+            .old_generation_mb(32)
+            .build();
+        Self::new(config)
+    }
+
     /// Shutdown the GC system
     pub fn shutdown(self) -> GcResult<()> {
         self.parallel_gc.shutdown()
     }
 }
 
+impl std::fmt::Debug for GcSystem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("GcSystem").finish_non_exhaustive()
+    }
+}
+
 /// Comprehensive GC system statistics
 #[derive(Debug)]
 pub struct GcSystemStatistics {
@@ -434,6 +508,147 @@ mod tests {
         assert!(gc_system.is_ok());
     }
 
+    #[test]
+    fn test_collections_update_system_statistics() {
+        let config = GcConfigBuilder::new()
+            .young_generation_mb(4)
+            .old_generation_mb(8)
+            .build();
+        let gc_system = GcSystem::new(config).unwrap();
+
+        gc_system.collect_major(false).unwrap();
+        gc_system.collect_minor().unwrap();
+
+        let stats = gc_system.get_statistics();
+        assert_eq!(stats.major_collections, 1);
+        assert_eq!(stats.minor_collections, 1);
+    }
+
+    #[test]
+    fn test_tuning_params_update() {
+        let config = GcConfigBuilder::new()
+            .young_generation_mb(4)
+            .old_generation_mb(8)
+            .target_minor_pause_ms(7)
+            .build();
+        let gc_system = GcSystem::new(config).unwrap();
+        assert_eq!(gc_system.tuning_params().target_minor_pause_ms, 7);
+
+        gc_system.update_tuning_params(|params| {
+            params.target_major_pause_ms = 20;
+            params.set_collection_frequency_multiplier(10.0);
+        }).unwrap();
+
+        let params = gc_system.tuning_params();
+        assert_eq!(params.target_major_pause_ms, 20);
+        assert_eq!(params.collection_frequency_multiplier(), 3.0);
+    }
+
+    #[test]
+    fn test_minor_trigger_amortizes_bursty_allocation() {
+        use std::time::{Duration, Instant};
+
+        const CAPACITY: usize = 1024 * 1024;
+        const CHUNK: usize = 4096;
+        const SURVIVING: usize = CAPACITY / 10;
+
+        let config = GcConfigBuilder::new().build();
+        let statistics = AllocationStatistics::new();
+        let mut trigger = MinorCollectionTrigger::from_config(&config);
+        let start = Instant::now();
+
+        // A burst of 4KB allocations every 10µs, then one every 10ms
+        let phases = [(2000, Duration::from_micros(10)), (1000, Duration::from_millis(10))];
+        let (mut now, mut used, mut naive_used) = (start, SURVIVING, SURVIVING);
+        let (mut collections, mut naive_collections) = (0, 0);
+        let mut peak_utilization: f64 = 0.0;
+
+        for (count, interval) in phases {
+            for _ in 0..count {
+                now += interval;
+                statistics.record_allocation(CHUNK, GenerationId::Young);
+
+                // Naive policy: collect every time the threshold is crossed
+                naive_used += CHUNK;
+                if naive_used as f64 / CAPACITY as f64 > 0.8 {
+                    naive_collections += 1;
+                    naive_used = SURVIVING;
+                }
+
+                used += CHUNK;
+                let utilization = used as f64 / CAPACITY as f64;
+                peak_utilization = peak_utilization.max(utilization);
+                let time_to_fill = statistics.time_to_fill(CAPACITY - used, 1.0 / interval.as_secs_f64());
+                if trigger.should_collect(now, utilization, time_to_fill) {
+                    trigger.record_collection(now);
+                    collections += 1;
+                    used = SURVIVING;
+                }
+            }
+        }
+
+        assert!(collections > 0);
+        assert!(collections < naive_collections);
+        assert!(peak_utilization < config.young_high_water + CHUNK as f64 / CAPACITY as f64);
+    }
+
+    #[test]
+    fn test_minor_trigger_collects_early_under_pressure() {
+        use std::time::{Duration, Instant};
+
+        let config = GcConfigBuilder::new().build();
+        let trigger = MinorCollectionTrigger::from_config(&config);
+        let now = Instant::now();
+
+        assert!(!trigger.should_collect(now, 0.5, Some(Duration::from_micros(1))));
+        assert!(trigger.should_collect(now, 0.85, Some(Duration::from_micros(1))));
+        assert!(!trigger.should_collect(now, 0.85, Some(Duration::from_secs(5))));
+        assert!(!trigger.should_collect(now, 0.85, None));
+        assert!(trigger.should_collect(now, 0.96, None));
+    }
+
+    #[test]
+    fn test_incremental_collection_respects_step_budget() {
+        use std::time::{Duration, Instant};
+
+        let config = GcConfigBuilder::new()
+            .young_generation_mb(16)
+            .old_generation_mb(64)
+            .build();
+        let gc_system = GcSystem::new(config).unwrap();
+        assert_eq!(gc_system.incremental_budget(), Duration::from_millis(1));
+
+        let headers: Vec<_> = (0..20_000)
+            .map(|i| gc_system.allocate(Value::integer(i), 16).unwrap())
+            .collect();
+        for header in &headers {
+            gc_system.root_set.add_global_root(Arc::as_ptr(header) as *mut ObjectHeader).unwrap();
+        }
+
+        let budget = Duration::from_micros(50);
+        gc_system.set_incremental_budget(budget);
+        assert_eq!(gc_system.incremental_budget(), budget);
+
+        let mut steps = 0;
+        loop {
+            let start = Instant::now();
+            let complete = gc_system.collect_incremental_step().unwrap();
+            // Allow for the one object always marked past the deadline and
+            // for scheduling noise
+            assert!(start.elapsed() < budget + Duration::from_millis(20));
+            steps += 1;
+            if complete {
+                break;
+            }
+            assert!(steps < 1_000_000, "incremental collection never completed");
+        }
+
+        // Start, sweep and finalize steps alone account for seven
+        assert!(steps > 8, "marking finished in a single step");
+        assert!(headers.iter().all(|header| header.is_marked()));
+        assert!(!gc_system.incremental_collector.is_collection_in_progress());
+    }
+
     #[test]
     fn test_basic_allocation() {
         let config = GcConfigBuilder::new()
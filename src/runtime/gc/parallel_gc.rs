@@ -30,6 +30,12 @@ pub struct ParallelGcConfig {
     pub numa_aware: bool,
     /// Enable adaptive tuning
     pub adaptive_tuning: bool,
+    /// Young generation occupancy (fraction) at which a minor collection
+    /// always starts
+    pub young_high_water: f64,
+    /// Minimum time between minor collections started below the high-water
+    /// mark (milliseconds)
+    pub min_minor_interval_ms: u64,
 }
 
 impl Default for ParallelGcConfig {
@@ -43,6 +49,8 @@ impl Default for ParallelGcConfig {
             target_major_pause_ms: 50,               // 50ms
             numa_aware: true,
             adaptive_tuning: true,
+            young_high_water: 0.95,                  // 95%
+            min_minor_interval_ms: 100,              // 100ms
         }
     }
 }
@@ -238,6 +246,66 @@ impl Default for SafepointCoordinator {
     }
 }
 
+/// Adaptive trigger for minor collections.
+///
+/// A minor collection always starts once the young generation reaches the
+/// high-water mark. Between the collection threshold and the high-water mark
+/// it starts early only when allocation pressure demands it, i.e. the young
+/// generation would fill within the minor pause target, and the previous
+/// minor collection was at least the minimum interval ago. Bursts of
+/// allocation therefore do not trigger a collection every time the
+/// threshold is crossed.
+#[derive(Debug, Clone)]
+pub struct MinorCollectionTrigger {
+    /// Occupancy below which no collection is considered
+    threshold: f64,
+    /// Occupancy at which a collection always starts
+    high_water: f64,
+    /// Time-to-fill below which allocation pressure calls for collecting early
+    pressure_horizon: Duration,
+    /// Minimum time between collections started below the high-water mark
+    min_interval: Duration,
+    /// When the last minor collection started
+    last_collection: Option<Instant>,
+}
+
+impl MinorCollectionTrigger {
+    /// Create a trigger from the collector configuration
+    pub fn from_config(config: &ParallelGcConfig) -> Self {
+        MinorCollectionTrigger {
+            // Same threshold as `YoungGeneration::needs_collection`
+            threshold: 0.8,
+            high_water: config.young_high_water,
+            pressure_horizon: Duration::from_millis(config.target_minor_pause_ms),
+            min_interval: Duration::from_millis(config.min_minor_interval_ms),
+            last_collection: None,
+        }
+    }
+
+    /// Whether a minor collection should start at `now`, given the young
+    /// generation occupancy as a fraction and the estimated time until it
+    /// is full at the current allocation rate
+    pub fn should_collect(&self, now: Instant, utilization: f64, time_to_fill: Option<Duration>) -> bool {
+        if utilization >= self.high_water {
+            return true;
+        }
+        if utilization < self.threshold {
+            return false;
+        }
+
+        let under_pressure = time_to_fill.is_some_and(|time| time < self.pressure_horizon);
+        let interval_elapsed = self
+            .last_collection
+            .is_none_or(|last| now.saturating_duration_since(last) >= self.min_interval);
+        under_pressure && interval_elapsed
+    }
+
+    /// Record that a minor collection started at `now`
+    pub fn record_collection(&mut self, now: Instant) {
+        self.last_collection = Some(now);
+    }
+}
+
 /// Main parallel garbage collector coordinator
 #[derive(Debug)]
 pub struct ParallelGc {
@@ -253,6 +321,8 @@ pub struct ParallelGc {
     jit_metrics: Option<Arc<RwLock<JitMetrics>>>,
     /// Adaptive tuning parameters
     adaptive_params: Arc<RwLock<AdaptiveTuningParams>>,
+    /// Adaptive trigger for minor collections
+    minor_trigger: Arc<Mutex<MinorCollectionTrigger>>,
     /// Collection request queue
     collection_requests: Arc<Mutex<VecDeque<CollectionRequest>>>,
     /// Worker thread pool
@@ -272,6 +342,10 @@ pub struct AdaptiveTuningParams {
     heap_growth_rate: f64,
     /// Recommended collection frequency adjustment
     collection_frequency_multiplier: f64,
+    /// Target pause time for minor collections (milliseconds)
+    pub target_minor_pause_ms: u64,
+    /// Target pause time for major collections (milliseconds)
+    pub target_major_pause_ms: u64,
 }
 
 impl Default for AdaptiveTuningParams {
@@ -281,10 +355,34 @@ impl Default for AdaptiveTuningParams {
             pause_time_samples: VecDeque::with_capacity(100),
             heap_growth_rate: 1.0,
             collection_frequency_multiplier: 1.0,
+            target_minor_pause_ms: 10,
+            target_major_pause_ms: 50,
         }
     }
 }
 
+impl AdaptiveTuningParams {
+    /// Create tuning parameters seeded with the configured pause targets
+    pub fn from_config(config: &ParallelGcConfig) -> Self {
+        AdaptiveTuningParams {
+            target_minor_pause_ms: config.target_minor_pause_ms,
+            target_major_pause_ms: config.target_major_pause_ms,
+            ..Default::default()
+        }
+    }
+
+    /// Current collection frequency multiplier
+    pub fn collection_frequency_multiplier(&self) -> f64 {
+        self.collection_frequency_multiplier
+    }
+
+    /// Override the collection frequency multiplier, clamped to the range
+    /// adaptive tuning itself uses
+    pub fn set_collection_frequency_multiplier(&mut self, multiplier: f64) {
+        self.collection_frequency_multiplier = multiplier.clamp(0.5, 3.0);
+    }
+}
+
 /// Collection request types
 #[derive(Debug, Clone)]
 pub enum CollectionRequest {
@@ -301,13 +399,16 @@ pub enum CollectionRequest {
 impl ParallelGc {
     /// Create a new parallel garbage collector
     pub fn new(config: ParallelGcConfig) -> Self {
+        let adaptive_params = AdaptiveTuningParams::from_config(&config);
+        let minor_trigger = MinorCollectionTrigger::from_config(&config);
         ParallelGc {
             config: Arc::new(config),
             current_phase: Arc::new(RwLock::new(CollectionPhase::Idle)),
             statistics: Arc::new(GcStatistics::new()),
             safepoint: Arc::new(SafepointCoordinator::new()),
             jit_metrics: None,
-            adaptive_params: Arc::new(RwLock::new(AdaptiveTuningParams::default())),
+            adaptive_params: Arc::new(RwLock::new(adaptive_params)),
+            minor_trigger: Arc::new(Mutex::new(minor_trigger)),
             collection_requests: Arc::new(Mutex::new(VecDeque::new())),
             worker_threads: Arc::new(RwLock::new(Vec::new())),
             shutdown_requested: Arc::new(AtomicBool::new(false)),
@@ -400,6 +501,33 @@ impl ParallelGc {
         Ok(())
     }
 
+    /// Decide whether a minor collection is due, recording it as started
+    /// when it is.
+    ///
+    /// `utilization` is the young generation occupancy as a fraction and
+    /// `time_to_fill` the estimated time until it is full.
+    pub fn minor_collection_due(&self, utilization: f64, time_to_fill: Option<Duration>) -> Result<bool, String> {
+        let mut trigger = self.minor_trigger.lock().map_err(|_| "Failed to acquire minor trigger lock")?;
+        let now = Instant::now();
+        if trigger.should_collect(now, utilization, time_to_fill) {
+            trigger.record_collection(now);
+            Ok(true)
+        } else {
+            Ok(false)
+        }
+    }
+
+    /// Request a minor collection if the adaptive trigger calls for one.
+    ///
+    /// Returns whether a collection was requested.
+    pub fn request_minor_collection_if_due(&self, utilization: f64, time_to_fill: Option<Duration>) -> Result<bool, String> {
+        if !self.minor_collection_due(utilization, time_to_fill)? {
+            return Ok(false);
+        }
+        self.request_minor_collection()?;
+        Ok(true)
+    }
+
     /// Request a major collection
     pub fn request_major_collection(&self) -> Result<(), String> {
         let mut requests = self.collection_requests.lock().map_err(|_| "Failed to acquire collection requests lock")?;
@@ -407,11 +535,37 @@ impl ParallelGc {
         Ok(())
     }
 
+    /// Get the collector configuration
+    pub fn config(&self) -> &ParallelGcConfig {
+        &self.config
+    }
+
     /// Get current GC statistics
     pub fn get_statistics(&self) -> &GcStatistics {
         &self.statistics
     }
 
+    /// Get a shared handle to the GC statistics, for collectors that
+    /// should report into the same counters
+    pub fn statistics_handle(&self) -> Arc<GcStatistics> {
+        Arc::clone(&self.statistics)
+    }
+
+    /// Get a snapshot of the adaptive tuning parameters
+    pub fn tuning_params(&self) -> AdaptiveTuningParams {
+        self.adaptive_params.read().map(|params| params.clone()).unwrap_or_default()
+    }
+
+    /// Adjust the adaptive tuning parameters in place
+    pub fn update_tuning_params<F>(&self, update: F) -> Result<(), String>
+    where
+        F: FnOnce(&mut AdaptiveTuningParams),
+    {
+        let mut params = self.adaptive_params.write().map_err(|_| "Failed to acquire tuning parameters lock")?;
+        update(&mut params);
+        Ok(())
+    }
+
     /// Get current collection phase
     pub fn get_current_phase(&self) -> CollectionPhase {
         *self.current_phase.read().unwrap()
@@ -441,7 +595,7 @@ impl ParallelGc {
             };
 
             // Adjust collection frequency based on pause times
-            let target_pause = Duration::from_millis(self.config.target_minor_pause_ms);
+            let target_pause = Duration::from_millis(params.target_minor_pause_ms);
             if avg_pause > target_pause {
                 // Pause times too high, collect more frequently
                 params.collection_frequency_multiplier = (params.collection_frequency_multiplier * 1.1).min(3.0);
@@ -5,10 +5,10 @@ use super::{
 };
 use crate::ast::{Expr, Program};
 use crate::diagnostics::{Result, Span};
-use crate::eval::Value;
+use crate::eval::{ThreadSafeEnvironment, Value};
 use crate::module_system::{ModuleSystem, ImportSpec};
 use crossbeam::channel;
-use std::sync::Arc;
+use std::sync::{Arc, OnceLock};
 use std::collections::HashMap;
 
 /// The main multithreaded runtime for the Lambdust language.
@@ -256,6 +256,62 @@ impl LambdustRuntime {
         self.thread_pool.size()
     }
 
+    /// Applies `procedure` to each element concurrently, one chunk of
+    /// elements per evaluator thread, and returns the results in order.
+    ///
+    /// See [`LambdustRuntime::parallel_map`] for the purity assumption and
+    /// error reporting.
+    pub fn parallel_vector_map(&self, procedure: &Value, elements: &[Value]) -> Result<Vec<Value>> {
+        self.parallel_map(procedure, elements, &self.global_env.root_environment())
+    }
+
+    /// Maps `procedure` over `elements` on the thread pool's evaluators.
+    ///
+    /// The elements are split into one contiguous chunk per worker and each
+    /// chunk is mapped in order by the worker that takes it, against a copy
+    /// of `globals`. `procedure` is assumed to be pure: the workers share no
+    /// dynamic state, definitions and assignments made on a worker stay in
+    /// its copy, and any effects it does perform are coordinated through the
+    /// runtime's effect coordinator. If applications fail, the error of the
+    /// lowest-indexed failing element is returned, whatever order the
+    /// workers finish in.
+    pub fn parallel_map(
+        &self,
+        procedure: &Value,
+        elements: &[Value],
+        globals: &Arc<ThreadSafeEnvironment>,
+    ) -> Result<Vec<Value>> {
+        if elements.is_empty() {
+            return Ok(Vec::new());
+        }
+        let chunk_size = elements.len().div_ceil(self.thread_count());
+
+        let mut receivers = Vec::new();
+        for chunk in elements.chunks(chunk_size) {
+            let (sender, receiver) = channel::bounded(1);
+            self.thread_pool.submit_work(EvaluatorMessage::Map {
+                procedure: procedure.clone(),
+                elements: chunk.to_vec(),
+                globals: globals.clone(),
+                sender,
+            })?;
+            receivers.push(receiver);
+        }
+
+        // Chunks are in element order and each stops at its first failure
+        let mut results = Vec::with_capacity(elements.len());
+        for receiver in receivers {
+            let chunk = receiver.recv().unwrap_or_else(|_| {
+                Err(crate::diagnostics::Error::runtime_error(
+                    "parallel map worker stopped before finishing its chunk".to_string(),
+                    None,
+                ).boxed())
+            });
+            results.extend(chunk?);
+        }
+        Ok(results)
+    }
+
     /// Imports a module into the runtime.
     pub async fn import_module(&self, import_spec: ImportSpec) -> Result<HashMap<String, Value>> {
         let mut module_system = self.module_system.write().map_err(|_| {
@@ -303,4 +359,15 @@ impl Default for LambdustRuntime {
     fn default() -> Self {
         Self::new().expect("Failed to create default runtime")
     }
-}
\ No newline at end of file
+}
+
+/// Runtime for parallel maps started from Scheme code, which runs outside
+/// any [`LambdustRuntime`]. It is created on first use with one evaluator
+/// thread per available core.
+pub fn shared_runtime() -> &'static LambdustRuntime {
+    static RUNTIME: OnceLock<LambdustRuntime> = OnceLock::new();
+    RUNTIME.get_or_init(|| {
+        let threads = std::thread::available_parallelism().map_or(1, |count| count.get());
+        LambdustRuntime::with_threads(threads).expect("failed to create shared runtime")
+    })
+}
@@ -842,8 +842,11 @@ fn primitive_peek_char(_args: &[Value]) -> Result<Value> {
     Err(Box::new(Error::runtime_error("peek-char requires I/O system integration".to_string(), None)))
 }
 
-fn primitive_eof_object_p(_args: &[Value]) -> Result<Value> {
-    Err(Box::new(Error::runtime_error("eof-object? requires I/O system integration".to_string(), None)))
+fn primitive_eof_object_p(args: &[Value]) -> Result<Value> {
+    if args.len() != 1 {
+        return Err(Box::new(Error::runtime_error("eof-object? requires exactly 1 argument".to_string(), None)));
+    }
+    Ok(Value::boolean(matches!(args[0], Value::EofObject)))
 }
 
 fn primitive_open_input_file(_args: &[Value]) -> Result<Value> {
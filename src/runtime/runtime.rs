@@ -177,11 +177,6 @@ impl Runtime {
     
     /// car primitive implementation
     fn primitive_car(args: &[Value]) -> Result<Value> {
-        println!("DEBUG: primitive_car called with {} args", args.len());
-        if !args.is_empty() {
-            println!("DEBUG: first arg is: {:?}", args[0]);
-        }
-        
         if args.len() != 1 {
             return Err(Box::new(crate::diagnostics::Error::runtime_error(
                 format!("car expects 1 argument, got {}", args.len()),
@@ -190,18 +185,12 @@ impl Runtime {
         }
         
         match &args[0] {
-            Value::Pair(car, _) => {
-                let result = (**car).clone();
-                println!("DEBUG: primitive_car returning: {result:?}");
-                Ok(result)
-            }
-            _ => {
-                println!("DEBUG: primitive_car error - not a pair: {:?}", args[0]);
-                Err(Box::new(crate::diagnostics::Error::runtime_error(
-                    "car requires a pair".to_string(),
-                    None,
-                )))
-            }
+            Value::Pair(car, _) => Ok((**car).clone()),
+            Value::MutablePair(car, _) => Ok(car.read().unwrap().clone()),
+            _ => Err(Box::new(crate::diagnostics::Error::runtime_error(
+                "car requires a pair".to_string(),
+                None,
+            ))),
         }
     }
     
@@ -216,6 +205,7 @@ impl Runtime {
         
         match &args[0] {
             Value::Pair(_, cdr) => Ok((**cdr).clone()),
+            Value::MutablePair(_, cdr) => Ok(cdr.read().unwrap().clone()),
             _ => Err(Box::new(crate::diagnostics::Error::runtime_error(
                 "cdr requires a pair".to_string(),
                 None,
@@ -9,9 +9,15 @@ use super::evaluator::EvaluatorWorker;
 use crate::diagnostics::Result;
 use crossbeam::channel::{self, Sender, Receiver};
 use std::sync::{Arc, RwLock};
+use std::cell::Cell;
 use std::thread::{self, ThreadId, JoinHandle};
 use std::time::{Duration, Instant};
 
+thread_local! {
+    /// Whether the current thread is a pool worker
+    static ON_WORKER_THREAD: Cell<bool> = const { Cell::new(false) };
+}
+
 /// Thread pool for managing multiple Scheme evaluator threads.
 ///
 /// This thread pool provides work-stealing capabilities and manages
@@ -229,6 +235,7 @@ impl ThreadPool {
         pool_stats: Arc<RwLock<ThreadPoolStats>>,
     ) -> Result<()> {
         let thread_id = thread::current().id();
+        ON_WORKER_THREAD.with(|flag| flag.set(true));
         
         // Register this thread with the effect coordinator
         effect_coordinator.register_thread(thread_id);
@@ -239,6 +246,8 @@ impl ThreadPool {
             global_env.clone(),
             effect_coordinator.clone(),
         );
+        // Scheme evaluator for applying procedures, created by the first map
+        let mut scheme_evaluator = None;
 
         // Main worker loop
         loop {
@@ -259,7 +268,7 @@ impl ThreadPool {
                 let start_time = Instant::now();
                 
                 // Process the message
-                let result = Self::process_worker_message(msg, &evaluator_worker);
+                let result = Self::process_worker_message(msg, &evaluator_worker, &mut scheme_evaluator);
                 
                 let elapsed = start_time.elapsed();
                 
@@ -281,6 +290,7 @@ impl ThreadPool {
     fn process_worker_message(
         message: EvaluatorMessage,
         _evaluator_worker: &EvaluatorWorker,
+        scheme_evaluator: &mut Option<crate::eval::Evaluator>,
     ) -> Result<()> {
         // For now, we'll handle messages directly here
         // In a full implementation, this would delegate to the evaluator worker
@@ -292,6 +302,20 @@ impl ThreadPool {
             EvaluatorMessage::DefineGlobal { name: _, value: _ } => {
                 // Placeholder - global definitions would be handled here
             }
+            EvaluatorMessage::Map { procedure, elements, globals, sender } => {
+                let evaluator = match scheme_evaluator {
+                    Some(evaluator) => {
+                        evaluator.set_global_env(globals.to_legacy());
+                        evaluator
+                    }
+                    None => scheme_evaluator.insert(crate::eval::Evaluator::with_environment(globals.to_legacy())),
+                };
+                let results = elements
+                    .into_iter()
+                    .map(|element| evaluator.call_procedure(procedure.clone(), vec![element], None))
+                    .collect();
+                let _ = sender.send(results);
+            }
             EvaluatorMessage::ImportModule { import_spec: _, sender } => {
                 // Placeholder - module import would be handled here
                 let _ = sender.send(Ok(std::collections::HashMap::new()));
@@ -371,6 +395,11 @@ impl ThreadPool {
         })
     }
 
+    /// Checks whether the current thread is a worker of some pool.
+    pub fn on_worker_thread() -> bool {
+        ON_WORKER_THREAD.with(Cell::get)
+    }
+
     /// Gets the size of the thread pool.
     pub fn size(&self) -> usize {
         self.size
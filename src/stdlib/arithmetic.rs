@@ -7,9 +7,24 @@
 use crate::ast::Literal;
 use crate::diagnostics::{Error as DiagnosticError, Result};
 use crate::eval::value::{Value, PrimitiveProcedure, PrimitiveImpl, ThreadSafeEnvironment};
+use crate::stdlib::exceptions::ExceptionObject;
+use crate::numeric::BigInt;
 use crate::effects::Effect;
 use std::sync::Arc;
 
+/// How a non-integral float is turned into an exact rational.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ExactConversion {
+    /// The binary fraction the float denotes, as R7RS `exact` requires
+    /// (`0.1` becomes `3602879701896397/36028797018963968`). Floats whose
+    /// exact denominator needs more than 64 bits fall back to `Simplest`.
+    #[default]
+    Binary,
+    /// The simplest rational that converts back to the same float
+    /// (`0.1` becomes `1/10`)
+    Simplest,
+}
+
 /// Helper function to bind a pure arithmetic primitive.
 fn bind_pure_arithmetic_primitive(
     env: &Arc<ThreadSafeEnvironment>,
@@ -112,6 +127,10 @@ fn bind_basic_arithmetic(env: &Arc<ThreadSafeEnvironment>) {
         implementation: PrimitiveImpl::RustFn(primitive_truncate_remainder),
         effects: vec![Effect::Pure],
     })));
+    
+    // Quotient and remainder together, as two values
+    bind_pure_arithmetic_primitive(env, "floor/", 2, Some(2), primitive_floor_divide);
+    bind_pure_arithmetic_primitive(env, "truncate/", 2, Some(2), primitive_truncate_divide);
 }
 
 /// Binds number tower conversion operations.
@@ -134,6 +153,15 @@ fn bind_number_tower_operations(env: &Arc<ThreadSafeEnvironment>) {
         effects: vec![Effect::Pure],
     })));
     
+    // Shortest rational that reads back as the same float
+    env.define("exact->simplest".to_string(), Value::Primitive(Arc::new(PrimitiveProcedure {
+        name: "exact->simplest".to_string(),
+        arity_min: 1,
+        arity_max: Some(1),
+        implementation: PrimitiveImpl::RustFn(primitive_exact_to_simplest),
+        effects: vec![Effect::Pure],
+    })));
+    
     // Number->String conversion
     env.define("number->string".to_string(), Value::Primitive(Arc::new(PrimitiveProcedure {
         name: "number->string".to_string(),
@@ -355,6 +383,12 @@ fn bind_number_predicates(env: &Arc<ThreadSafeEnvironment>) {
         implementation: PrimitiveImpl::RustFn(primitive_exact_integer_p),
         effects: vec![Effect::Pure],
     })));
+    
+    // Representation predicates
+    bind_pure_arithmetic_primitive(env, "exact-nonnegative-integer?", 1, Some(1), primitive_exact_nonnegative_integer_p);
+    bind_pure_arithmetic_primitive(env, "exact-rational?", 1, Some(1), primitive_exact_rational_p);
+    bind_pure_arithmetic_primitive(env, "fixnum?", 1, Some(1), primitive_fixnum_p);
+    bind_pure_arithmetic_primitive(env, "flonum?", 1, Some(1), primitive_flonum_p);
 }
 
 /// Binds complex number operations.
@@ -466,6 +500,42 @@ fn bind_math_functions(env: &Arc<ThreadSafeEnvironment>) {
         effects: vec![Effect::Pure],
     })));
     
+    // floor->exact - floor to an exact integer
+    env.define("floor->exact".to_string(), Value::Primitive(Arc::new(PrimitiveProcedure {
+        name: "floor->exact".to_string(),
+        arity_min: 1,
+        arity_max: Some(1),
+        implementation: PrimitiveImpl::RustFn(primitive_floor_to_exact),
+        effects: vec![Effect::Pure],
+    })));
+    
+    // ceiling->exact - ceiling to an exact integer
+    env.define("ceiling->exact".to_string(), Value::Primitive(Arc::new(PrimitiveProcedure {
+        name: "ceiling->exact".to_string(),
+        arity_min: 1,
+        arity_max: Some(1),
+        implementation: PrimitiveImpl::RustFn(primitive_ceiling_to_exact),
+        effects: vec![Effect::Pure],
+    })));
+    
+    // truncate->exact - truncate to an exact integer
+    env.define("truncate->exact".to_string(), Value::Primitive(Arc::new(PrimitiveProcedure {
+        name: "truncate->exact".to_string(),
+        arity_min: 1,
+        arity_max: Some(1),
+        implementation: PrimitiveImpl::RustFn(primitive_truncate_to_exact),
+        effects: vec![Effect::Pure],
+    })));
+    
+    // round->exact - round to an exact integer
+    env.define("round->exact".to_string(), Value::Primitive(Arc::new(PrimitiveProcedure {
+        name: "round->exact".to_string(),
+        arity_min: 1,
+        arity_max: Some(1),
+        implementation: PrimitiveImpl::RustFn(primitive_round_to_exact),
+        effects: vec![Effect::Pure],
+    })));
+    
     // Exponentiation
     env.define("expt".to_string(), Value::Primitive(Arc::new(PrimitiveProcedure {
         name: "expt".to_string(),
@@ -484,6 +554,9 @@ fn bind_math_functions(env: &Arc<ThreadSafeEnvironment>) {
         effects: vec![Effect::Pure],
     })));
     
+    // Exact integer square root with remainder
+    bind_pure_arithmetic_primitive(env, "exact-integer-sqrt", 1, Some(1), primitive_exact_integer_sqrt);
+    
     // Exponential
     env.define("exp".to_string(), Value::Primitive(Arc::new(PrimitiveProcedure {
         name: "exp".to_string(),
@@ -714,10 +787,7 @@ fn primitive_quotient(args: &[Value]) -> Result<Value> {
         )));
     }
     
-    let n1 = extract_number(&args[0], "quotient")?;
-    let n2 = extract_number(&args[1], "quotient")?;
-    
-    Ok(number_value_to_value(quotient_numbers(n1, n2)?))
+    integer_quotient(&args[0], &args[1], "quotient", Rounding::Truncate)
 }
 
 /// Remainder operation (remainder)
@@ -729,10 +799,7 @@ fn primitive_remainder(args: &[Value]) -> Result<Value> {
         )));
     }
     
-    let n1 = extract_number(&args[0], "remainder")?;
-    let n2 = extract_number(&args[1], "remainder")?;
-    
-    Ok(number_value_to_value(remainder_numbers(n1, n2)?))
+    integer_remainder(&args[0], &args[1], "remainder", Rounding::Truncate)
 }
 
 /// Modulo operation (modulo)
@@ -744,10 +811,7 @@ fn primitive_modulo(args: &[Value]) -> Result<Value> {
         )));
     }
     
-    let n1 = extract_number(&args[0], "modulo")?;
-    let n2 = extract_number(&args[1], "modulo")?;
-    
-    Ok(number_value_to_value(modulo_numbers(n1, n2)?))
+    integer_remainder(&args[0], &args[1], "modulo", Rounding::Floor)
 }
 
 /// Absolute value operation (abs)
@@ -804,10 +868,7 @@ fn primitive_floor_quotient(args: &[Value]) -> Result<Value> {
         )));
     }
     
-    let n1 = extract_number(&args[0], "floor-quotient")?;
-    let n2 = extract_number(&args[1], "floor-quotient")?;
-    
-    Ok(number_value_to_value(quotient_numbers(n1, n2)?))
+    integer_quotient(&args[0], &args[1], "floor-quotient", Rounding::Floor)
 }
 
 /// Floor-remainder operation
@@ -819,10 +880,7 @@ fn primitive_floor_remainder(args: &[Value]) -> Result<Value> {
         )));
     }
     
-    let n1 = extract_number(&args[0], "floor-remainder")?;
-    let n2 = extract_number(&args[1], "floor-remainder")?;
-    
-    Ok(number_value_to_value(remainder_numbers(n1, n2)?))
+    integer_remainder(&args[0], &args[1], "floor-remainder", Rounding::Floor)
 }
 
 /// Truncate-quotient operation
@@ -834,10 +892,7 @@ fn primitive_truncate_quotient(args: &[Value]) -> Result<Value> {
         )));
     }
     
-    let n1 = extract_number(&args[0], "truncate-quotient")?;
-    let n2 = extract_number(&args[1], "truncate-quotient")?;
-    
-    Ok(number_value_to_value(quotient_numbers(n1, n2)?))
+    integer_quotient(&args[0], &args[1], "truncate-quotient", Rounding::Truncate)
 }
 
 /// Truncate-remainder operation
@@ -849,10 +904,23 @@ fn primitive_truncate_remainder(args: &[Value]) -> Result<Value> {
         )));
     }
     
-    let n1 = extract_number(&args[0], "truncate-remainder")?;
-    let n2 = extract_number(&args[1], "truncate-remainder")?;
-    
-    Ok(number_value_to_value(remainder_numbers(n1, n2)?))
+    integer_remainder(&args[0], &args[1], "truncate-remainder", Rounding::Truncate)
+}
+
+/// Floor division (floor/), returning the floor quotient and remainder
+fn primitive_floor_divide(args: &[Value]) -> Result<Value> {
+    Ok(Value::values(vec![
+        integer_quotient(&args[0], &args[1], "floor/", Rounding::Floor)?,
+        integer_remainder(&args[0], &args[1], "floor/", Rounding::Floor)?,
+    ]))
+}
+
+/// Truncating division (truncate/), returning the truncated quotient and remainder
+fn primitive_truncate_divide(args: &[Value]) -> Result<Value> {
+    Ok(Value::values(vec![
+        integer_quotient(&args[0], &args[1], "truncate/", Rounding::Truncate)?,
+        integer_remainder(&args[0], &args[1], "truncate/", Rounding::Truncate)?,
+    ]))
 }
 
 // ============= CONVERSION IMPLEMENTATIONS =============
@@ -866,8 +934,13 @@ fn primitive_exact_to_inexact(args: &[Value]) -> Result<Value> {
         )));
     }
     
+    if let Value::Literal(Literal::BigInteger(n)) = &args[0] {
+        let f = n.to_f64().unwrap_or(if n.is_negative() { f64::NEG_INFINITY } else { f64::INFINITY });
+        return Ok(Value::Literal(Literal::InexactReal(f)));
+    }
+    
     let num = extract_number(&args[0], "exact->inexact")?;
-    Ok(number_value_to_value(to_inexact(num)))
+    Ok(exactness_preserving_value(to_inexact(num)))
 }
 
 /// Inexact to exact conversion
@@ -879,8 +952,42 @@ fn primitive_inexact_to_exact(args: &[Value]) -> Result<Value> {
         )));
     }
     
-    let num = extract_number(&args[0], "inexact->exact")?;
-    Ok(number_value_to_value(to_exact(num)?))
+    to_exact_value(&args[0], ExactConversion::Binary, "inexact->exact")
+}
+
+/// exact->simplest procedure - exact conversion to the shortest round-tripping rational
+fn primitive_exact_to_simplest(args: &[Value]) -> Result<Value> {
+    if args.len() != 1 {
+        return Err(Box::new(DiagnosticError::runtime_error(
+            format!("exact->simplest expects 1 argument, got {args_len}", args_len = args.len()),
+            None,
+        )));
+    }
+    
+    to_exact_value(&args[0], ExactConversion::Simplest, "exact->simplest")
+}
+
+/// Converts a number to an exact number, turning non-integral floats into
+/// rationals as `conversion` selects.
+pub fn to_exact_value(value: &Value, conversion: ExactConversion, operation: &str) -> Result<Value> {
+    match value {
+        value if is_big_integer(value) => return Ok(value.clone()),
+        // Integral floats beyond the i64 range become big integers
+        Value::Literal(Literal::InexactReal(f)) if !fits_i64(*f) => {
+            if let Some(n) = BigInt::from_f64(*f) {
+                return Ok(Value::Literal(Literal::BigInteger(n)));
+            }
+        }
+        _ => {}
+    }
+    
+    let num = extract_number(value, operation)?;
+    Ok(number_value_to_value(to_exact(num, conversion)?))
+}
+
+/// Whether a float lies within the range of an i64.
+fn fits_i64(f: f64) -> bool {
+    (-9_223_372_036_854_775_808.0..9_223_372_036_854_775_808.0).contains(&f)
 }
 
 /// Number to string conversion
@@ -937,7 +1044,7 @@ fn primitive_string_to_number(args: &[Value]) -> Result<Value> {
     };
     
     match string_to_number(s, radix) {
-        Some(num) => Ok(number_value_to_value(num)),
+        Some(num) => Ok(exactness_preserving_value(num)),
         None => Ok(Value::boolean(false)),
     }
 }
@@ -970,6 +1077,13 @@ fn primitive_numeric_equal(args: &[Value]) -> Result<Value> {
         )));
     }
     
+    // Exact integers compare exactly, whatever their representation
+    if args.iter().any(is_big_integer) {
+        if let Some(integers) = args.iter().map(exact_integer_as_big).collect::<Option<Vec<_>>>() {
+            return Ok(Value::boolean(integers.windows(2).all(|pair| pair[0] == pair[1])));
+        }
+    }
+    
     let first = extract_number(&args[0], "=")?;
     
     for arg in &args[1..] {
@@ -1150,6 +1264,10 @@ fn primitive_integer_p(args: &[Value]) -> Result<Value> {
         )));
     }
     
+    if is_big_integer(&args[0]) {
+        return Ok(Value::boolean(true));
+    }
+    
     if let Some(num) = try_extract_number(&args[0]) {
         Ok(Value::boolean(is_integer_number(num)))
     } else {
@@ -1166,6 +1284,10 @@ fn primitive_rational_p(args: &[Value]) -> Result<Value> {
         )));
     }
     
+    if is_big_integer(&args[0]) {
+        return Ok(Value::boolean(true));
+    }
+    
     if let Some(num) = try_extract_number(&args[0]) {
         Ok(Value::boolean(is_rational_number(num)))
     } else {
@@ -1182,6 +1304,10 @@ fn primitive_real_p(args: &[Value]) -> Result<Value> {
         )));
     }
     
+    if is_big_integer(&args[0]) {
+        return Ok(Value::boolean(true));
+    }
+    
     if let Some(num) = try_extract_number(&args[0]) {
         Ok(Value::boolean(is_real_number(num)))
     } else {
@@ -1210,6 +1336,10 @@ fn primitive_exact_p(args: &[Value]) -> Result<Value> {
         )));
     }
     
+    if is_big_integer(&args[0]) {
+        return Ok(Value::boolean(true));
+    }
+    
     if let Some(num) = try_extract_number(&args[0]) {
         Ok(Value::boolean(is_exact_number(num)))
     } else {
@@ -1226,6 +1356,10 @@ fn primitive_inexact_p(args: &[Value]) -> Result<Value> {
         )));
     }
     
+    if is_big_integer(&args[0]) {
+        return Ok(Value::boolean(false));
+    }
+    
     if let Some(num) = try_extract_number(&args[0]) {
         Ok(Value::boolean(!is_exact_number(num)))
     } else {
@@ -1239,44 +1373,91 @@ fn primitive_inexact_p(args: &[Value]) -> Result<Value> {
 
 /// Maximum function (max)
 fn primitive_max(args: &[Value]) -> Result<Value> {
+    number_extremum(args, "max", std::cmp::Ordering::Greater)
+}
+
+/// Minimum function (min)
+fn primitive_min(args: &[Value]) -> Result<Value> {
+    number_extremum(args, "min", std::cmp::Ordering::Less)
+}
+
+/// Shared implementation of `max` and `min`.
+///
+/// The extremum is chosen by exact comparison where possible, and the result
+/// is inexact if any argument is inexact (R7RS 6.2.6), so `(max 4 3.0)` is
+/// `4.0`. A NaN argument makes the result NaN.
+fn number_extremum(args: &[Value], operation: &str, prefer: std::cmp::Ordering) -> Result<Value> {
     if args.is_empty() {
         return Err(Box::new(DiagnosticError::runtime_error(
-            "max requires at least 1 argument".to_string(),
+            format!("{operation} requires at least 1 argument"),
             None,
         )));
     }
     
-    let mut result = extract_number(&args[0], "max")?;
+    let mut numbers = Vec::with_capacity(args.len());
+    for arg in args {
+        let num = extract_number(arg, operation)?;
+        if !is_real_number(num.clone()) {
+            return Err(Box::new(DiagnosticError::runtime_error(
+                format!("{operation} requires real arguments"),
+                None,
+            )));
+        }
+        numbers.push(num);
+    }
     
-    for arg in &args[1..] {
-        let num = extract_number(arg, "max")?;
-        if number_greater_than(num.clone(), result.clone())? {
-            result = num;
+    let any_inexact = numbers.iter().any(|n| !is_exact_number(n.clone()));
+    let mut result = numbers[0].clone();
+    for num in &numbers[1..] {
+        if is_real_nan(&result) {
+            break;
+        }
+        if is_real_nan(num) || compare_real_numbers(num, &result) == prefer {
+            result = num.clone();
         }
     }
     
-    Ok(number_value_to_value(result))
+    // Build inexact results directly: `number_value_to_value` would fold an
+    // integral float such as 4.0 back into an exact integer
+    match result {
+        NumberValue::Float(f) => Ok(Value::Literal(Literal::InexactReal(f))),
+        exact if any_inexact => match to_inexact(exact) {
+            NumberValue::Float(f) => Ok(Value::Literal(Literal::InexactReal(f))),
+            other => Ok(number_value_to_value(other)),
+        },
+        other => Ok(number_value_to_value(other)),
+    }
 }
 
-/// Minimum function (min)
-fn primitive_min(args: &[Value]) -> Result<Value> {
-    if args.is_empty() {
-        return Err(Box::new(DiagnosticError::runtime_error(
-            "min requires at least 1 argument".to_string(),
-            None,
-        )));
+/// Returns true if the number is an inexact NaN.
+fn is_real_nan(a: &NumberValue) -> bool {
+    matches!(a, NumberValue::Float(f) if f.is_nan())
+}
+
+/// Orders two real numbers, comparing exact values without rounding.
+///
+/// NaN compares equal to everything; callers handle it separately.
+fn compare_real_numbers(a: &NumberValue, b: &NumberValue) -> std::cmp::Ordering {
+    fn as_fraction(n: &NumberValue) -> Option<(i128, i128)> {
+        match n {
+            NumberValue::Integer(i) => Some((*i as i128, 1)),
+            NumberValue::Rational { numerator, denominator } => {
+                // Keep the denominator positive so cross-multiplication preserves order
+                let (num, den) = (*numerator as i128, *denominator as i128);
+                Some(if den < 0 { (-num, -den) } else { (num, den) })
+            }
+            _ => None,
+        }
     }
     
-    let mut result = extract_number(&args[0], "min")?;
-    
-    for arg in &args[1..] {
-        let num = extract_number(arg, "min")?;
-        if number_less_than(num.clone(), result.clone())? {
-            result = num;
+    match (as_fraction(a), as_fraction(b)) {
+        (Some((an, ad)), Some((bn, bd))) => (an * bd).cmp(&(bn * ad)),
+        _ => {
+            let af = to_float(a.clone()).unwrap_or(f64::NAN);
+            let bf = to_float(b.clone()).unwrap_or(f64::NAN);
+            af.partial_cmp(&bf).unwrap_or(std::cmp::Ordering::Equal)
         }
     }
-    
-    Ok(number_value_to_value(result))
 }
 
 /// Floor function (floor)
@@ -1288,8 +1469,7 @@ fn primitive_floor(args: &[Value]) -> Result<Value> {
         )));
     }
     
-    let num = extract_number(&args[0], "floor")?;
-    Ok(number_value_to_value(floor_number(num)?))
+    round_to_integer(&args[0], "floor", floor_number)
 }
 
 /// Ceiling function (ceiling)
@@ -1301,8 +1481,7 @@ fn primitive_ceiling(args: &[Value]) -> Result<Value> {
         )));
     }
     
-    let num = extract_number(&args[0], "ceiling")?;
-    Ok(number_value_to_value(ceiling_number(num)?))
+    round_to_integer(&args[0], "ceiling", ceiling_number)
 }
 
 /// Truncate function (truncate)
@@ -1314,8 +1493,7 @@ fn primitive_truncate(args: &[Value]) -> Result<Value> {
         )));
     }
     
-    let num = extract_number(&args[0], "truncate")?;
-    Ok(number_value_to_value(truncate_number(num)?))
+    round_to_integer(&args[0], "truncate", truncate_number)
 }
 
 /// Round function (round)
@@ -1327,8 +1505,91 @@ fn primitive_round(args: &[Value]) -> Result<Value> {
         )));
     }
     
-    let num = extract_number(&args[0], "round")?;
-    Ok(number_value_to_value(round_number(num)?))
+    round_to_integer(&args[0], "round", round_number)
+}
+
+/// Shared implementation of `floor`, `ceiling`, `truncate` and `round`.
+///
+/// Rounding preserves exactness: exact arguments give exact integers and
+/// inexact arguments give inexact integers, so `(round 2.5)` is `2.0`.
+fn round_to_integer(
+    value: &Value,
+    operation: &str,
+    rounding: fn(NumberValue) -> Result<NumberValue>,
+) -> Result<Value> {
+    if is_big_integer(value) {
+        return Ok(value.clone());
+    }
+    let num = extract_number(value, operation)?;
+    Ok(exactness_preserving_value(rounding(num)?))
+}
+
+/// floor->exact procedure
+fn primitive_floor_to_exact(args: &[Value]) -> Result<Value> {
+    if args.len() != 1 {
+        return Err(Box::new(DiagnosticError::runtime_error(
+            format!("floor->exact expects 1 argument, got {args_len}", args_len = args.len()),
+            None,
+        )));
+    }
+    
+    round_to_exact_integer(&args[0], "floor->exact", floor_number)
+}
+
+/// ceiling->exact procedure
+fn primitive_ceiling_to_exact(args: &[Value]) -> Result<Value> {
+    if args.len() != 1 {
+        return Err(Box::new(DiagnosticError::runtime_error(
+            format!("ceiling->exact expects 1 argument, got {args_len}", args_len = args.len()),
+            None,
+        )));
+    }
+    
+    round_to_exact_integer(&args[0], "ceiling->exact", ceiling_number)
+}
+
+/// truncate->exact procedure
+fn primitive_truncate_to_exact(args: &[Value]) -> Result<Value> {
+    if args.len() != 1 {
+        return Err(Box::new(DiagnosticError::runtime_error(
+            format!("truncate->exact expects 1 argument, got {args_len}", args_len = args.len()),
+            None,
+        )));
+    }
+    
+    round_to_exact_integer(&args[0], "truncate->exact", truncate_number)
+}
+
+/// round->exact procedure
+fn primitive_round_to_exact(args: &[Value]) -> Result<Value> {
+    if args.len() != 1 {
+        return Err(Box::new(DiagnosticError::runtime_error(
+            format!("round->exact expects 1 argument, got {args_len}", args_len = args.len()),
+            None,
+        )));
+    }
+    
+    round_to_exact_integer(&args[0], "round->exact", round_number)
+}
+
+/// Shared implementation of `floor->exact`, `ceiling->exact`,
+/// `truncate->exact` and `round->exact`: rounds, then converts to an exact
+/// integer, which is a big integer beyond the i64 range.
+fn round_to_exact_integer(
+    value: &Value,
+    operation: &str,
+    rounding: fn(NumberValue) -> Result<NumberValue>,
+) -> Result<Value> {
+    let rounded = round_to_integer(value, operation, rounding)?;
+    if let Value::Literal(Literal::InexactReal(f)) = &rounded {
+        if !f.is_finite() {
+            return Err(Box::new(DiagnosticError::runtime_error(
+                format!("{operation}: {rounded} has no exact integer value"),
+                None,
+            )));
+        }
+    }
+    to_exact_value(&rounded, ExactConversion::Binary, operation)
 }
 
 /// Exponentiation function (expt)
@@ -1359,6 +1620,17 @@ fn primitive_sqrt(args: &[Value]) -> Result<Value> {
     Ok(number_value_to_value(sqrt_number(num)?))
 }
 
+/// Exact integer square root (exact-integer-sqrt), returning the floor root and the remainder
+fn primitive_exact_integer_sqrt(args: &[Value]) -> Result<Value> {
+    let (root, remainder) = exact_integer_as_big(&args[0])
+        .and_then(|n| n.exact_integer_sqrt())
+        .ok_or_else(|| Box::new(DiagnosticError::runtime_error(
+            format!("exact-integer-sqrt requires an exact nonnegative integer, got {}", args[0]),
+            None,
+        )))?;
+    Ok(Value::values(vec![big_integer_value(root), big_integer_value(remainder)]))
+}
+
 /// Exponential function (exp)
 fn primitive_exp(args: &[Value]) -> Result<Value> {
     if args.len() != 1 {
@@ -1497,6 +1769,10 @@ fn primitive_exact_integer_p(args: &[Value]) -> Result<Value> {
         )));
     }
     
+    if is_big_integer(&args[0]) {
+        return Ok(Value::boolean(true));
+    }
+    
     if let Some(num) = try_extract_number(&args[0]) {
         Ok(Value::boolean(is_exact_integer(num)))
     } else {
@@ -1513,6 +1789,10 @@ fn primitive_finite_p(args: &[Value]) -> Result<Value> {
         )));
     }
     
+    if is_big_integer(&args[0]) {
+        return Ok(Value::boolean(true));
+    }
+    
     if let Some(num) = try_extract_number(&args[0]) {
         Ok(Value::boolean(is_finite_number(num)))
     } else {
@@ -1552,6 +1832,75 @@ fn primitive_nan_p(args: &[Value]) -> Result<Value> {
     }
 }
 
+/// Exact non-negative integer predicate (exact-nonnegative-integer?)
+fn primitive_exact_nonnegative_integer_p(args: &[Value]) -> Result<Value> {
+    if args.len() != 1 {
+        return Err(Box::new(DiagnosticError::runtime_error(
+            format!("exact-nonnegative-integer? expects 1 argument, got {args_len}", args_len = args.len()),
+            None,
+        )));
+    }
+    
+    if let Value::Literal(Literal::BigInteger(n)) = &args[0] {
+        return Ok(Value::boolean(!n.is_negative()));
+    }
+    
+    match try_extract_number(&args[0]) {
+        Some(NumberValue::Integer(n)) => Ok(Value::boolean(n >= 0)),
+        Some(NumberValue::Rational { numerator, denominator }) =>
+            Ok(Value::boolean(denominator == 1 && numerator >= 0)),
+        _ => Ok(Value::boolean(false)),
+    }
+}
+
+/// Exact rational predicate (exact-rational?)
+fn primitive_exact_rational_p(args: &[Value]) -> Result<Value> {
+    if args.len() != 1 {
+        return Err(Box::new(DiagnosticError::runtime_error(
+            format!("exact-rational? expects 1 argument, got {args_len}", args_len = args.len()),
+            None,
+        )));
+    }
+    
+    Ok(Value::boolean(is_big_integer(&args[0]) || matches!(
+        try_extract_number(&args[0]),
+        Some(NumberValue::Integer(_)) | Some(NumberValue::Rational { .. })
+    )))
+}
+
+/// Fixnum predicate (fixnum?) - exact integers that fit in an i64
+fn primitive_fixnum_p(args: &[Value]) -> Result<Value> {
+    if args.len() != 1 {
+        return Err(Box::new(DiagnosticError::runtime_error(
+            format!("fixnum? expects 1 argument, got {args_len}", args_len = args.len()),
+            None,
+        )));
+    }
+    
+    if is_big_integer(&args[0]) {
+        return Ok(Value::boolean(false));
+    }
+    
+    if let Some(num) = try_extract_number(&args[0]) {
+        Ok(Value::boolean(is_exact_integer(num)))
+    } else {
+        Ok(Value::boolean(false))
+    }
+}
+
+/// Flonum predicate (flonum?) - inexact reals, including NaN and infinities
+fn primitive_flonum_p(args: &[Value]) -> Result<Value> {
+    if args.len() != 1 {
+        return Err(Box::new(DiagnosticError::runtime_error(
+            format!("flonum? expects 1 argument, got {args_len}", args_len = args.len()),
+            None,
+        )));
+    }
+    
+    // Big integers are read as floats for arithmetic but are exact
+    Ok(Value::boolean(!is_big_integer(&args[0]) && matches!(try_extract_number(&args[0]), Some(NumberValue::Float(_)))))
+}
+
 // ============= HELPER TYPES AND FUNCTIONS =============
 
 /// Internal number representation for arithmetic operations.
@@ -1565,18 +1914,12 @@ enum NumberValue {
 
 /// Extracts a number from a Value for arithmetic operations.
 fn extract_number(value: &Value, operation: &str) -> Result<NumberValue> {
-    match value {
-        Value::Literal(Literal::ExactInteger(n)) => Ok(NumberValue::Integer(*n)),
-        Value::Literal(Literal::InexactReal(n)) => Ok(NumberValue::Float(*n)),
-        Value::Literal(Literal::Rational { numerator, denominator }) => 
-            Ok(NumberValue::Rational { numerator: *numerator, denominator: *denominator }),
-        Value::Literal(Literal::Complex { real, imaginary }) => 
-            Ok(NumberValue::Complex { real: *real, imaginary: *imaginary }),
-        _ => Err(Box::new(DiagnosticError::runtime_error(
+    try_extract_number(value).ok_or_else(|| {
+        Box::new(DiagnosticError::runtime_error(
             format!("{operation} requires numeric arguments"),
             None,
-        ))),
-    }
+        ))
+    })
 }
 
 /// Tries to extract a number from a Value (for predicates).
@@ -1588,10 +1931,155 @@ fn try_extract_number(value: &Value) -> Option<NumberValue> {
             Some(NumberValue::Rational { numerator: *numerator, denominator: *denominator }),
         Value::Literal(Literal::Complex { real, imaginary }) => 
             Some(NumberValue::Complex { real: *real, imaginary: *imaginary }),
+        // Legacy float literals classify like `Literal::from_f64` would build them
+        #[allow(deprecated)]
+        Value::Literal(Literal::Number(n)) => match Literal::from_f64(*n) {
+            Literal::ExactInteger(i) => Some(NumberValue::Integer(i)),
+            _ => Some(NumberValue::Float(*n)),
+        },
+        // Exact integers beyond i64 take part in mixed arithmetic as their
+        // nearest flonum
+        Value::Literal(Literal::BigInteger(n)) => Some(NumberValue::Float(n.to_f64().unwrap_or(
+            if n.is_negative() { f64::NEG_INFINITY } else { f64::INFINITY },
+        ))),
         _ => None,
     }
 }
 
+/// Whether a value is an exact integer outside the i64 range.
+fn is_big_integer(value: &Value) -> bool {
+    matches!(value, Value::Literal(Literal::BigInteger(_)))
+}
+
+/// Widens an exact integer of either representation to a BigInt.
+fn exact_integer_as_big(value: &Value) -> Option<BigInt> {
+    match value {
+        Value::Literal(Literal::ExactInteger(n)) => Some(BigInt::from_i64(*n)),
+        Value::Literal(Literal::BigInteger(n)) => Some(n.clone()),
+        _ => None,
+    }
+}
+
+/// Converts a BigInt back to a fixnum when it fits.
+fn big_integer_value(n: BigInt) -> Value {
+    match n.to_i64() {
+        Some(i) => Value::integer(i),
+        None => Value::Literal(Literal::BigInteger(n)),
+    }
+}
+
+/// How an integer division rounds its quotient.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Rounding {
+    /// Towards zero; the remainder has the sign of the dividend
+    Truncate,
+    /// Towards negative infinity; the remainder has the sign of the divisor
+    Floor,
+}
+
+/// Assertion violation raised by `operation` for a zero divisor.
+///
+/// Integer division rejects any zero divisor, while `/` only rejects an
+/// exact one and follows IEEE 754 for inexact zeros.
+fn division_by_zero(operation: &str, dividend: &Value) -> Box<DiagnosticError> {
+    Box::new(DiagnosticError::exception(ExceptionObject::assertion_violation(
+        Some(operation.to_string()),
+        "division by zero".to_string(),
+        vec![dividend.clone()],
+    )))
+}
+
+/// Quotient of dividing `dividend` by `divisor` under the given rounding.
+///
+/// Exact integers stay exact, promoting to BigInt when either operand is
+/// big or the quotient overflows; an inexact operand makes the result
+/// inexact.
+fn integer_quotient(dividend: &Value, divisor: &Value, operation: &str, rounding: Rounding) -> Result<Value> {
+    if let (Value::Literal(Literal::ExactInteger(a)), Value::Literal(Literal::ExactInteger(b))) = (dividend, divisor) {
+        if *b == 0 {
+            return Err(division_by_zero(operation, dividend));
+        }
+        // Only i64::MIN / -1 overflows, and then the remainder is zero
+        if let Some(quotient) = a.checked_div(*b) {
+            let remainder = a % b;
+            return Ok(Value::integer(match rounding {
+                Rounding::Floor if remainder != 0 && (remainder < 0) != (*b < 0) => quotient - 1,
+                _ => quotient,
+            }));
+        }
+    }
+
+    if let (Some(a), Some(b)) = (exact_integer_as_big(dividend), exact_integer_as_big(divisor)) {
+        if b.is_zero() {
+            return Err(division_by_zero(operation, dividend));
+        }
+        let (quotient, remainder) = a.div_rem(&b);
+        return Ok(big_integer_value(match rounding {
+            Rounding::Floor if !remainder.is_zero() && remainder.is_negative() != b.is_negative() => quotient - BigInt::one(),
+            _ => quotient,
+        }));
+    }
+
+    let a = to_float(extract_number(dividend, operation)?)?;
+    let b = to_float(extract_number(divisor, operation)?)?;
+    if b == 0.0 {
+        return Err(division_by_zero(operation, dividend));
+    }
+    let quotient = match rounding {
+        Rounding::Truncate => (a / b).trunc(),
+        Rounding::Floor => (a / b).floor(),
+    };
+    Ok(exactness_preserving_value(NumberValue::Float(quotient)))
+}
+
+/// Remainder of dividing `dividend` by `divisor` under the given rounding.
+///
+/// Exact integers stay exact, promoting to BigInt when either operand is
+/// big; an inexact operand makes the result inexact.
+fn integer_remainder(dividend: &Value, divisor: &Value, operation: &str, rounding: Rounding) -> Result<Value> {
+    if let (Value::Literal(Literal::ExactInteger(a)), Value::Literal(Literal::ExactInteger(b))) = (dividend, divisor) {
+        if *b == 0 {
+            return Err(division_by_zero(operation, dividend));
+        }
+        // Only i64::MIN % -1 overflows, and its remainder is zero
+        let remainder = a.checked_rem(*b).unwrap_or(0);
+        return Ok(Value::integer(match rounding {
+            Rounding::Floor if remainder != 0 && (remainder < 0) != (*b < 0) => remainder + b,
+            _ => remainder,
+        }));
+    }
+
+    if let (Some(a), Some(b)) = (exact_integer_as_big(dividend), exact_integer_as_big(divisor)) {
+        if b.is_zero() {
+            return Err(division_by_zero(operation, dividend));
+        }
+        let (_, remainder) = a.div_rem(&b);
+        return Ok(big_integer_value(match rounding {
+            Rounding::Floor if !remainder.is_zero() && remainder.is_negative() != b.is_negative() => remainder + b,
+            _ => remainder,
+        }));
+    }
+
+    let a = extract_number(dividend, operation)?;
+    let b = extract_number(divisor, operation)?;
+    if is_zero(b.clone()) {
+        return Err(division_by_zero(operation, dividend));
+    }
+    let result = match rounding {
+        Rounding::Truncate => remainder_numbers(a, b)?,
+        Rounding::Floor => modulo_numbers(a, b)?,
+    };
+    Ok(exactness_preserving_value(result))
+}
+
+/// Converts a NumberValue back to a Value, keeping integral floats inexact.
+fn exactness_preserving_value(num: NumberValue) -> Value {
+    match num {
+        NumberValue::Float(f) => Value::Literal(Literal::InexactReal(f)),
+        other => number_value_to_value(other),
+    }
+}
+
 /// Converts a NumberValue back to a Value.
 fn number_value_to_value(num: NumberValue) -> Value {
     match num {
@@ -1737,21 +2225,9 @@ fn multiply_numbers(a: NumberValue, b: NumberValue) -> Result<NumberValue> {
 }
 
 fn divide_numbers(a: NumberValue, b: NumberValue) -> Result<NumberValue> {
-    // Check for division by zero first
-    match &b {
-        NumberValue::Integer(0) => {
-            return Err(Box::new(DiagnosticError::runtime_error(
-                "Division by zero".to_string(),
-                None,
-            )));
-        },
-        NumberValue::Float(f) if *f == 0.0 => {
-            return Err(Box::new(DiagnosticError::runtime_error(
-                "Division by zero".to_string(),
-                None,
-            )));
-        },
-        _ => {}
+    // Only an exact zero divisor is an error; inexact zeros follow IEEE 754
+    if b == NumberValue::Integer(0) {
+        return Err(division_by_zero("/", &number_value_to_value(a)));
     }
     
     match (&a, &b) {
@@ -1780,12 +2256,6 @@ fn divide_numbers(a: NumberValue, b: NumberValue) -> Result<NumberValue> {
         (NumberValue::Complex { real: r1, imaginary: i1 }, NumberValue::Complex { real: r2, imaginary: i2 }) => {
             // (a + bi)/(c + di) = ((ac + bd) + (bc - ad)i)/(c² + d²)
             let denom = r2 * r2 + i2 * i2;
-            if denom == 0.0 {
-                return Err(Box::new(DiagnosticError::runtime_error(
-                    "Division by zero complex number".to_string(),
-                    None,
-                )));
-            }
             Ok(NumberValue::Complex { 
                 real: (r1 * r2 + i1 * i2) / denom, 
                 imaginary: (i1 * r2 - r1 * i2) / denom 
@@ -1798,12 +2268,6 @@ fn divide_numbers(a: NumberValue, b: NumberValue) -> Result<NumberValue> {
                 NumberValue::Rational { numerator, denominator } => *numerator as f64 / *denominator as f64,
                 NumberValue::Complex { .. } => unreachable!(),
             };
-            if other_real == 0.0 {
-                return Err(Box::new(DiagnosticError::runtime_error(
-                    "Division by zero".to_string(),
-                    None,
-                )));
-            }
             Ok(NumberValue::Complex { real: real / other_real, imaginary: imaginary / other_real })
         },
         (other, NumberValue::Complex { real, imaginary }) => {
@@ -1814,12 +2278,6 @@ fn divide_numbers(a: NumberValue, b: NumberValue) -> Result<NumberValue> {
                 NumberValue::Complex { .. } => unreachable!(),
             };
             let denom = real * real + imaginary * imaginary;
-            if denom == 0.0 {
-                return Err(Box::new(DiagnosticError::runtime_error(
-                    "Division by zero complex number".to_string(),
-                    None,
-                )));
-            }
             Ok(NumberValue::Complex { 
                 real: (other_real * real) / denom, 
                 imaginary: (-other_real * imaginary) / denom 
@@ -1828,12 +2286,6 @@ fn divide_numbers(a: NumberValue, b: NumberValue) -> Result<NumberValue> {
         _ => {
             let af = to_float(a)?;
             let bf = to_float(b)?;
-            if bf == 0.0 {
-                return Err(Box::new(DiagnosticError::runtime_error(
-                    "Division by zero".to_string(),
-                    None,
-                )));
-            }
             Ok(NumberValue::Float(af / bf))
         }
     }
@@ -1854,21 +2306,6 @@ fn reciprocal_number(a: NumberValue) -> Result<NumberValue> {
     divide_numbers(NumberValue::Integer(1), a)
 }
 
-fn quotient_numbers(a: NumberValue, b: NumberValue) -> Result<NumberValue> {
-    // Integer division truncated towards zero
-    let af = to_float(a)?;
-    let bf = to_float(b)?;
-    
-    if bf == 0.0 {
-        return Err(Box::new(DiagnosticError::runtime_error(
-            "quotient: division by zero".to_string(),
-            None,
-        )));
-    }
-    
-    Ok(NumberValue::Float((af / bf).trunc()))
-}
-
 fn remainder_numbers(a: NumberValue, b: NumberValue) -> Result<NumberValue> {
     // Remainder after division truncated towards zero
     let af = to_float(a)?;
@@ -1911,10 +2348,7 @@ fn abs_number(a: NumberValue) -> Result<NumberValue> {
         NumberValue::Float(f) => Ok(NumberValue::Float(f.abs())),
         NumberValue::Rational { numerator, denominator } => 
             Ok(NumberValue::Rational { numerator: numerator.abs(), denominator: denominator.abs() }),
-        NumberValue::Complex { real, imaginary } => {
-            let magnitude = (real * real + imaginary * imaginary).sqrt();
-            Ok(NumberValue::Float(magnitude))
-        },
+        NumberValue::Complex { real, imaginary } => Ok(NumberValue::Float(real.hypot(imaginary))),
     }
 }
 
@@ -2172,7 +2606,7 @@ fn to_inexact(a: NumberValue) -> NumberValue {
     }
 }
 
-fn to_exact(a: NumberValue) -> Result<NumberValue> {
+fn to_exact(a: NumberValue, conversion: ExactConversion) -> Result<NumberValue> {
     match a {
         NumberValue::Integer(i) => Ok(NumberValue::Integer(i)),
         NumberValue::Rational { numerator, denominator } => 
@@ -2186,11 +2620,19 @@ fn to_exact(a: NumberValue) -> Result<NumberValue> {
             }
             
             if f.fract() == 0.0 {
-                Ok(NumberValue::Integer(f as i64))
-            } else {
-                // Convert to rational approximation
-                let (num, den) = float_to_rational(f);
-                Ok(NumberValue::Rational { numerator: num, denominator: den })
+                return Ok(NumberValue::Integer(f as i64));
+            }
+            
+            let fraction = match conversion {
+                ExactConversion::Binary => float_to_binary_fraction(f).or_else(|| float_to_simplest_fraction(f)),
+                ExactConversion::Simplest => float_to_simplest_fraction(f),
+            };
+            match fraction {
+                Some((numerator, denominator)) => Ok(NumberValue::Rational { numerator, denominator }),
+                None => Err(Box::new(DiagnosticError::runtime_error(
+                    format!("Cannot represent {f} as an exact rational with 64-bit parts"),
+                    None,
+                ))),
             }
         },
         NumberValue::Complex { real, imaginary } => {
@@ -2200,7 +2642,7 @@ fn to_exact(a: NumberValue) -> Result<NumberValue> {
                     None,
                 )));
             }
-            to_exact(NumberValue::Float(real))
+            to_exact(NumberValue::Float(real), conversion)
         },
     }
 }
@@ -2215,19 +2657,11 @@ fn number_to_string(a: NumberValue, radix: u32) -> String {
             }
         },
         NumberValue::Float(f) => {
-            if radix == 10 {
-                if f.fract() == 0.0 && f.is_finite() {
-                    format!("{f:.0}")
-                } else {
-                    f.to_string()
-                }
+            // Convert to integer if possible, otherwise use base 10
+            if radix != 10 && f.fract() == 0.0 && fits_i64(f) {
+                format_integer_radix(f as i64, radix)
             } else {
-                // Convert to integer if possible, otherwise use base 10
-                if f.fract() == 0.0 && f.is_finite() {
-                    format_integer_radix(f as i64, radix)
-                } else {
-                    f.to_string()
-                }
+                crate::numeric::format_real(f)
             }
         },
         NumberValue::Rational { numerator, denominator } => {
@@ -2281,6 +2715,13 @@ fn string_to_number(s: &str, radix: u32) -> Option<NumberValue> {
         return None;
     }
     
+    match s {
+        "+inf.0" => return Some(NumberValue::Float(f64::INFINITY)),
+        "-inf.0" => return Some(NumberValue::Float(f64::NEG_INFINITY)),
+        "+nan.0" | "-nan.0" => return Some(NumberValue::Float(f64::NAN)),
+        _ => {}
+    }
+    
     // Try to parse as complex number first
     if s.contains('i') {
         return parse_complex_number(s, radix);
@@ -2368,6 +2809,80 @@ fn float_to_rational(f: f64) -> (i64, i64) {
     (p1, q1)
 }
 
+/// Splits a finite float's magnitude into `(mantissa, exponent)` with
+/// `|f| = mantissa * 2^exponent`.
+fn decompose_float(f: f64) -> (u64, i32) {
+    let bits = f.abs().to_bits();
+    let biased = ((bits >> 52) & 0x7ff) as i32;
+    let fraction = bits & ((1 << 52) - 1);
+    if biased == 0 {
+        // Subnormal: no implicit leading bit
+        (fraction, -1074)
+    } else {
+        (fraction | (1 << 52), biased - 1075)
+    }
+}
+
+/// The exact value of a non-integral float as a reduced fraction, if the
+/// denominator fits an i64.
+fn float_to_binary_fraction(f: f64) -> Option<(i64, i64)> {
+    let (mantissa, exponent) = decompose_float(f);
+    // Non-integral floats have a negative exponent
+    let shift = mantissa.trailing_zeros().min(exponent.unsigned_abs());
+    let denominator_bits = exponent.unsigned_abs() - shift;
+    if denominator_bits > 62 {
+        return None;
+    }
+    
+    let numerator = (mantissa >> shift) as i64;
+    Some((if f < 0.0 { -numerator } else { numerator }, 1i64 << denominator_bits))
+}
+
+/// The simplest fraction that converts back to the non-integral float `f`,
+/// if its parts fit an i64.
+///
+/// Walks the continued fraction of the exact binary value. The first
+/// convergent that reads back as `f` bounds the answer; the semiconvergents
+/// before it approach `f` monotonically, so the simplest one that still reads
+/// back is found by binary search.
+fn float_to_simplest_fraction(f: f64) -> Option<(i64, i64)> {
+    let (mantissa, exponent) = decompose_float(f);
+    let denominator_bits = exponent.unsigned_abs();
+    // Below 2^-63 no fraction with i64 parts is close enough
+    if denominator_bits > 126 {
+        return None;
+    }
+    
+    let target = f.abs();
+    let reads_back = |p: u128, q: u128| p as f64 / q as f64 == target;
+    
+    let (mut n, mut d) = (mantissa as u128, 1u128 << denominator_bits);
+    let (mut p0, mut q0, mut p1, mut q1) = (0u128, 1u128, 1u128, 0u128);
+    let (p, q) = loop {
+        let a = n / d;
+        let (p2, q2) = (a * p1 + p0, a * q1 + q0);
+        if reads_back(p2, q2) {
+            // Smallest j in 1..=a whose semiconvergent reads back
+            let (mut low, mut high) = (1u128, a);
+            while low < high {
+                let j = low + (high - low) / 2;
+                if reads_back(j * p1 + p0, j * q1 + q0) {
+                    high = j;
+                } else {
+                    low = j + 1;
+                }
+            }
+            break (low * p1 + p0, low * q1 + q0);
+        }
+        (n, d) = (d, n - a * d);
+        (p0, q0, p1, q1) = (p1, q1, p2, q2);
+    };
+    
+    let numerator = i64::try_from(p).ok()?;
+    let denominator = i64::try_from(q).ok()?;
+    Some((if f < 0.0 { -numerator } else { numerator }, denominator))
+}
+
 /// Rationalize a float within a given tolerance
 fn rationalize_float(x: f64, tolerance: f64) -> (i64, i64) {
     if tolerance == 0.0 {
@@ -2790,10 +3305,18 @@ fn truncate_number(a: NumberValue) -> Result<NumberValue> {
 fn round_number(a: NumberValue) -> Result<NumberValue> {
     match a {
         NumberValue::Integer(i) => Ok(NumberValue::Integer(i)),
-        NumberValue::Float(f) => Ok(NumberValue::Float(f.round())),
+        NumberValue::Float(f) => Ok(NumberValue::Float(f.round_ties_even())),
         NumberValue::Rational { numerator, denominator } => {
-            let f = numerator as f64 / denominator as f64;
-            Ok(NumberValue::Float(f.round()))
+            // Round to nearest, ties to even, without leaving exact arithmetic
+            let (numerator, denominator) = (numerator as i128, denominator as i128);
+            let floor = numerator.div_euclid(denominator);
+            let twice_remainder = 2 * numerator.rem_euclid(denominator);
+            let rounded = match twice_remainder.cmp(&denominator) {
+                std::cmp::Ordering::Less => floor,
+                std::cmp::Ordering::Greater => floor + 1,
+                std::cmp::Ordering::Equal => floor + floor.rem_euclid(2),
+            };
+            Ok(NumberValue::Integer(rounded as i64))
         },
         NumberValue::Complex { .. } => Err(Box::new(DiagnosticError::runtime_error(
             "round not defined for complex numbers".to_string(),
@@ -3212,7 +3735,14 @@ fn primitive_angle(args: &[Value]) -> Result<Value> {
     let num = extract_number(&args[0], "angle")?;
     match num {
         NumberValue::Complex { real, imaginary } => {
-            Ok(Value::number(imaginary.atan2(real)))
+            // atan2 gives -π for a negative real axis approached from below
+            // (a -0.0 imaginary part); the principal value lies in (-π, π]
+            let angle = imaginary.atan2(real);
+            if angle == -std::f64::consts::PI {
+                Ok(Value::number(std::f64::consts::PI))
+            } else {
+                Ok(Value::number(angle))
+            }
         },
         NumberValue::Integer(i) => {
             if i >= 0 {
@@ -3388,6 +3918,261 @@ mod tests {
         assert_eq!(result, Value::boolean(false));
     }
     
+    #[test]
+    fn test_max_min_exactness_contagion() {
+        let inexact = |f: f64| Value::Literal(Literal::InexactReal(f));
+        
+        assert_eq!(primitive_max(&[Value::integer(3), inexact(4.0)]).unwrap(), inexact(4.0));
+        assert_eq!(primitive_max(&[Value::integer(4), inexact(3.0)]).unwrap(), inexact(4.0));
+        assert_eq!(primitive_min(&[inexact(1.0), Value::integer(0)]).unwrap(), inexact(0.0));
+        assert_eq!(primitive_max(&[Value::integer(1), Value::integer(7), Value::integer(3)]).unwrap(), Value::integer(7));
+        
+        // Exact rationals are compared exactly and stay exact
+        let third = Value::Literal(Literal::rational(1, 3));
+        let half = Value::Literal(Literal::rational(1, 2));
+        assert_eq!(primitive_min(&[half.clone(), third.clone()]).unwrap(), third);
+        
+        // A single argument keeps its exactness
+        assert_eq!(primitive_max(&[Value::integer(5)]).unwrap(), Value::integer(5));
+        assert_eq!(primitive_min(&[inexact(2.0)]).unwrap(), inexact(2.0));
+        
+        assert!(primitive_max(&[]).is_err());
+        assert!(primitive_max(&[Value::string("a")]).is_err());
+    }
+    
+    #[test]
+    fn test_remainder_and_modulo_signs() {
+        let int = Value::integer;
+        let cases = [
+            (7, 3, 1, 1),
+            (7, -3, 1, -2),
+            (-7, 3, -1, 2),
+            (-7, -3, -1, -1),
+        ];
+        for (a, b, remainder, modulo) in cases {
+            assert_eq!(primitive_remainder(&[int(a), int(b)]).unwrap(), int(remainder));
+            assert_eq!(primitive_modulo(&[int(a), int(b)]).unwrap(), int(modulo));
+            assert_eq!(primitive_truncate_remainder(&[int(a), int(b)]).unwrap(), int(remainder));
+            assert_eq!(primitive_floor_remainder(&[int(a), int(b)]).unwrap(), int(modulo));
+        }
+
+        assert_eq!(primitive_remainder(&[int(i64::MIN), int(-1)]).unwrap(), int(0));
+        assert!(primitive_modulo(&[int(7), int(0)]).is_err());
+    }
+
+    #[test]
+    fn test_integer_division_exactness() {
+        let int = Value::integer;
+        let inexact = |f: f64| Value::Literal(Literal::InexactReal(f));
+        let is_exact = |value: &Value| matches!(value, Value::Literal(Literal::ExactInteger(_)));
+        let is_inexact = |value: &Value| matches!(value, Value::Literal(Literal::InexactReal(_)));
+        
+        let exact_results = [
+            primitive_quotient(&[int(7), int(2)]).unwrap(),
+            primitive_remainder(&[int(7), int(2)]).unwrap(),
+            primitive_modulo(&[int(-7), int(2)]).unwrap(),
+            primitive_floor_quotient(&[int(-7), int(2)]).unwrap(),
+            primitive_truncate_quotient(&[int(-7), int(2)]).unwrap(),
+        ];
+        assert_eq!(exact_results, [int(3), int(1), int(1), int(-4), int(-3)]);
+        assert!(exact_results.iter().all(is_exact));
+        
+        let inexact_results = [
+            primitive_quotient(&[inexact(7.0), int(2)]).unwrap(),
+            primitive_remainder(&[int(7), inexact(2.0)]).unwrap(),
+            primitive_modulo(&[inexact(-7.0), int(2)]).unwrap(),
+            primitive_floor_quotient(&[inexact(-7.0), int(2)]).unwrap(),
+        ];
+        assert_eq!(inexact_results, [inexact(3.0), inexact(1.0), inexact(1.0), inexact(-4.0)]);
+        assert!(inexact_results.iter().all(is_inexact));
+        
+        // The one fixnum quotient that overflows becomes a big integer
+        let overflowed = primitive_quotient(&[int(i64::MIN), int(-1)]).unwrap();
+        assert_eq!(overflowed, big_integer_value(-BigInt::from_i64(i64::MIN)));
+        assert!(primitive_quotient(&[int(7), int(0)]).is_err());
+        assert!(primitive_quotient(&[inexact(7.0), int(0)]).is_err());
+    }
+    
+    #[test]
+    fn test_quotient_big_integers() {
+        let big = |digits: &str| big_integer_value(BigInt::from_str_radix(digits, 10).unwrap());
+        let a = big("-1234567890123456789012345678901234567890");
+        let b = big("1000000000000000000000000000000000000007");
+        assert_eq!(primitive_quotient(&[a.clone(), b.clone()]).unwrap(), Value::integer(-1));
+        assert_eq!(primitive_floor_quotient(&[a.clone(), b]).unwrap(), Value::integer(-2));
+        assert_eq!(
+            primitive_quotient(&[a, Value::integer(10)]).unwrap(),
+            big("-123456789012345678901234567890123456789")
+        );
+    }
+
+    #[test]
+    fn test_exact_integer_sqrt() {
+        let sqrt = |n: Value| primitive_exact_integer_sqrt(&[n]);
+        assert_eq!(sqrt(Value::integer(0)).unwrap(), Value::values(vec![Value::integer(0), Value::integer(0)]));
+        assert_eq!(sqrt(Value::integer(17)).unwrap(), Value::values(vec![Value::integer(4), Value::integer(1)]));
+        assert_eq!(sqrt(Value::integer(i64::MAX)).unwrap(), Value::values(vec![Value::integer(3037000499), Value::integer(5928526806)]));
+
+        let big = |digits: &str| big_integer_value(BigInt::from_str_radix(digits, 10).unwrap());
+        assert_eq!(
+            sqrt(big("152415787532388367504942236884722755800955129")).unwrap(),
+            Value::values(vec![big("12345678901234567890123"), Value::integer(0)])
+        );
+
+        assert!(sqrt(Value::integer(-1)).is_err());
+        assert!(sqrt(Value::Literal(Literal::InexactReal(4.0))).is_err());
+        assert!(sqrt(Value::Literal(Literal::rational(1, 4))).is_err());
+    }
+
+    #[test]
+    fn test_divide_by_zero() {
+        let int = Value::integer;
+        let inexact = |f: f64| Value::Literal(Literal::InexactReal(f));
+        let is_assertion = |result: Result<Value>| matches!(
+            result.map_err(|error| *error),
+            Err(DiagnosticError::Exception { exception, .. }) if exception.exception_type == "assertion-violation"
+        );
+
+        assert!(is_assertion(primitive_divide(&[int(1), int(0)])));
+        assert!(is_assertion(primitive_divide(&[int(0)])));
+        assert!(is_assertion(primitive_divide(&[inexact(1.5), int(0)])));
+        assert!(is_assertion(primitive_modulo(&[int(5), int(0)])));
+        assert!(is_assertion(primitive_quotient(&[inexact(5.0), inexact(0.0)])));
+
+        assert_eq!(primitive_divide(&[inexact(1.0), inexact(0.0)]).unwrap(), inexact(f64::INFINITY));
+        assert_eq!(primitive_divide(&[int(-1), inexact(0.0)]).unwrap(), inexact(f64::NEG_INFINITY));
+        assert_eq!(primitive_divide(&[inexact(0.0)]).unwrap(), inexact(f64::INFINITY));
+        assert!(matches!(
+            primitive_divide(&[inexact(0.0), inexact(0.0)]).unwrap(),
+            Value::Literal(Literal::InexactReal(f)) if f.is_nan()
+        ));
+    }
+
+    #[test]
+    fn test_floor_and_truncate_divide() {
+        let pair = |q: i64, r: i64| Value::values(vec![Value::integer(q), Value::integer(r)]);
+        let args = |n: i64, d: i64| [Value::integer(n), Value::integer(d)];
+
+        assert_eq!(primitive_floor_divide(&args(17, 5)).unwrap(), pair(3, 2));
+        assert_eq!(primitive_floor_divide(&args(-7, 2)).unwrap(), pair(-4, 1));
+        assert_eq!(primitive_truncate_divide(&args(-7, 2)).unwrap(), pair(-3, -1));
+        assert!(primitive_floor_divide(&args(1, 0)).is_err());
+    }
+
+    #[test]
+    fn test_remainder_and_modulo_big_integers() {
+        let big = |digits: &str| big_integer_value(BigInt::from_str_radix(digits, 10).unwrap());
+        let a = "1234567890123456789012345678901234567890";
+        let b = "1000000000000000000000000000000000000007";
+        let cases = [
+            ("", "", "234567890123456789012345678901234567883", "234567890123456789012345678901234567883"),
+            ("", "-", "234567890123456789012345678901234567883", "-765432109876543210987654321098765432124"),
+            ("-", "", "-234567890123456789012345678901234567883", "765432109876543210987654321098765432124"),
+            ("-", "-", "-234567890123456789012345678901234567883", "-234567890123456789012345678901234567883"),
+        ];
+        for (a_sign, b_sign, remainder, modulo) in cases {
+            let args = [big(&format!("{a_sign}{a}")), big(&format!("{b_sign}{b}"))];
+            assert_eq!(primitive_remainder(&args).unwrap(), big(remainder));
+            assert_eq!(primitive_modulo(&args).unwrap(), big(modulo));
+        }
+
+        // Mixed fixnum and big operands, with results that fit a fixnum
+        let dividend = big(&format!("-{a}"));
+        assert_eq!(primitive_remainder(&[dividend.clone(), Value::integer(7)]).unwrap(), Value::integer(-3));
+        assert_eq!(primitive_modulo(&[dividend.clone(), Value::integer(7)]).unwrap(), Value::integer(4));
+        assert_eq!(primitive_modulo(&[Value::integer(-7), big(b)]).unwrap(), big("1000000000000000000000000000000000000000"));
+        assert!(primitive_remainder(&[dividend, Value::integer(0)]).is_err());
+    }
+
+    #[test]
+    fn test_rounding_preserves_exactness() {
+        let inexact = |f: f64| Value::Literal(Literal::InexactReal(f));
+        
+        assert_eq!(primitive_truncate(&[inexact(3.7)]).unwrap(), inexact(3.0));
+        assert_eq!(primitive_floor(&[inexact(-3.5)]).unwrap(), inexact(-4.0));
+        assert_eq!(primitive_ceiling(&[inexact(3.2)]).unwrap(), inexact(4.0));
+        assert_eq!(primitive_round(&[inexact(2.5)]).unwrap(), inexact(2.0));
+        assert_eq!(primitive_round(&[inexact(3.5)]).unwrap(), inexact(4.0));
+        assert_eq!(primitive_round(&[inexact(-2.5)]).unwrap(), inexact(-2.0));
+        
+        // Exact arguments round to exact integers, ties to even
+        assert_eq!(primitive_round(&[Value::Literal(Literal::rational(5, 2))]).unwrap(), Value::integer(2));
+        assert_eq!(primitive_round(&[Value::Literal(Literal::rational(7, 2))]).unwrap(), Value::integer(4));
+        assert_eq!(primitive_round(&[Value::Literal(Literal::rational(-7, 3))]).unwrap(), Value::integer(-2));
+        assert_eq!(primitive_floor(&[Value::integer(7)]).unwrap(), Value::integer(7));
+    }
+
+    #[test]
+    fn test_exact_of_rounded_values() {
+        let inexact = |f: f64| Value::Literal(Literal::InexactReal(f));
+        let exact_of = |op: fn(&[Value]) -> Result<Value>, f: f64| {
+            primitive_inexact_to_exact(&[op(&[inexact(f)]).unwrap()]).unwrap()
+        };
+        
+        assert_eq!(exact_of(primitive_truncate, 3.7), Value::integer(3));
+        assert_eq!(exact_of(primitive_floor, -3.7), Value::integer(-4));
+        assert_eq!(exact_of(primitive_round, 2.5), Value::integer(2));
+        assert_eq!(exact_of(primitive_ceiling, 1e15 + 0.5), Value::integer(1_000_000_000_000_001));
+        
+        // Magnitudes beyond i64 promote to big integers instead of saturating
+        let big = exact_of(primitive_floor, 1e20);
+        assert_eq!(big, Value::Literal(Literal::BigInteger(BigInt::from_f64(1e20).unwrap())));
+        assert_eq!(big.to_string(), "100000000000000000000");
+        assert_eq!(primitive_exact_p(&[big.clone()]).unwrap(), Value::boolean(true));
+        assert_eq!(primitive_integer_p(&[big.clone()]).unwrap(), Value::boolean(true));
+        assert_eq!(primitive_floor(&[big.clone()]).unwrap(), big);
+        assert_eq!(primitive_exact_to_inexact(&[big]).unwrap(), inexact(1e20));
+        
+        let negative = exact_of(primitive_truncate, -2f64.powi(64));
+        assert_eq!(negative.to_string(), "-18446744073709551616");
+        
+        // The exact i64 boundary stays a machine integer
+        assert_eq!(primitive_inexact_to_exact(&[inexact(-(2f64.powi(63)))]).unwrap(), Value::integer(i64::MIN));
+        
+        assert!(primitive_inexact_to_exact(&[inexact(f64::INFINITY)]).is_err());
+        assert_eq!(primitive_exact_to_inexact(&[Value::integer(3)]).unwrap(), inexact(3.0));
+    }
+
+    #[test]
+    fn test_exact_binary_and_simplest_fractions() {
+        let inexact = |f: f64| Value::Literal(Literal::InexactReal(f));
+        let rational = |n: i64, d: i64| Value::Literal(Literal::rational(n, d));
+        
+        assert_eq!(
+            primitive_inexact_to_exact(&[inexact(0.1)]).unwrap(),
+            rational(3602879701896397, 36028797018963968)
+        );
+        assert_eq!(primitive_exact_to_simplest(&[inexact(0.1)]).unwrap(), rational(1, 10));
+        assert_eq!(primitive_exact_to_simplest(&[inexact(-0.1)]).unwrap(), rational(-1, 10));
+        assert_eq!(primitive_exact_to_simplest(&[inexact(1.0 / 3.0)]).unwrap(), rational(1, 3));
+        assert_eq!(primitive_exact_to_simplest(&[inexact(123.456)]).unwrap(), rational(15432, 125));
+        
+        // Dyadic fractions are the same in both modes
+        assert_eq!(primitive_inexact_to_exact(&[inexact(0.75)]).unwrap(), rational(3, 4));
+        assert_eq!(primitive_exact_to_simplest(&[inexact(0.75)]).unwrap(), rational(3, 4));
+        
+        // Exact and integral arguments pass through
+        assert_eq!(primitive_exact_to_simplest(&[inexact(4.0)]).unwrap(), Value::integer(4));
+        assert_eq!(primitive_exact_to_simplest(&[rational(2, 7)]).unwrap(), rational(2, 7));
+        
+        // A denominator beyond 64 bits falls back to the simplest fraction
+        assert_eq!(primitive_inexact_to_exact(&[inexact(1e-5)]).unwrap(), rational(1, 100000));
+        assert!(primitive_exact_to_simplest(&[inexact(5e-324)]).is_err());
+    }
+
+    #[test]
+    fn test_max_min_nan() {
+        let nan = Value::Literal(Literal::InexactReal(f64::NAN));
+        for args in [vec![nan.clone(), Value::integer(1)], vec![Value::integer(1), nan.clone()]] {
+            for result in [primitive_max(&args).unwrap(), primitive_min(&args).unwrap()] {
+                match result {
+                    Value::Literal(Literal::InexactReal(f)) => assert!(f.is_nan()),
+                    other => panic!("expected NaN, got {other:?}"),
+                }
+            }
+        }
+    }
+    
     #[test]
     fn test_basic_arithmetic() {
         // Test addition
@@ -3405,4 +4190,42 @@ mod tests {
         // This would pass once the real implementation is done
         // assert_eq!(result, Value::boolean(true));
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_exactness_predicate_matrix() {
+        type Predicate = fn(&[Value]) -> Result<Value>;
+        let predicates: [(&str, Predicate); 8] = [
+            ("exact-integer?", primitive_exact_integer_p),
+            ("exact-nonnegative-integer?", primitive_exact_nonnegative_integer_p),
+            ("fixnum?", primitive_fixnum_p),
+            ("flonum?", primitive_flonum_p),
+            ("exact-rational?", primitive_exact_rational_p),
+            ("nan?", primitive_nan_p),
+            ("infinite?", primitive_infinite_p),
+            ("finite?", primitive_finite_p),
+        ];
+        
+        // Expected results follow the order of `predicates`
+        let matrix = vec![
+            (Value::integer(42), [true, true, true, false, true, false, false, true]),
+            (Value::integer(-3), [true, false, true, false, true, false, false, true]),
+            (Value::Literal(Literal::rational(1, 3)), [false, false, false, false, true, false, false, true]),
+            (Value::number(1.5), [false, false, false, true, false, false, false, true]),
+            (Value::number(f64::NAN), [false, false, false, true, false, true, false, false]),
+            (Value::number(f64::INFINITY), [false, false, false, true, false, false, true, false]),
+            (Value::Literal(Literal::complex(1.0, 2.0)), [false, false, false, false, false, false, false, true]),
+            (
+                Value::Literal(Literal::BigInteger(BigInt::from_str_radix("123456789012345678901234567890", 10).unwrap())),
+                [true, true, false, false, true, false, false, true],
+            ),
+            (Value::string("42"), [false; 8]),
+        ];
+        
+        for (value, expected) in matrix {
+            for ((name, predicate), expected) in predicates.iter().zip(expected) {
+                let result = predicate(std::slice::from_ref(&value)).unwrap();
+                assert_eq!(result, Value::boolean(expected), "({name} {value:?})");
+            }
+        }
+    }
+}
@@ -75,6 +75,8 @@ enum ValueKeyType {
     Nil,
     /// Unspecified (immutable)
     Unspecified,
+    /// The end-of-file object (immutable)
+    EofObject,
     /// Immutable pairs - use content-based equality
     ImmutablePair(Box<ValueKey>, Box<ValueKey>),
     /// Mutable objects - use reference-based equality (Arc pointer address)
@@ -90,6 +92,7 @@ impl ValueKey {
             Value::Keyword(k) => ValueKeyType::Keyword(k.clone()),
             Value::Nil => ValueKeyType::Nil,
             Value::Unspecified => ValueKeyType::Unspecified,
+            Value::EofObject => ValueKeyType::EofObject,
             Value::Pair(car, cdr) => {
                 // Immutable pairs use structural equality
                 ValueKeyType::ImmutablePair(
@@ -111,6 +114,7 @@ impl ValueKey {
             Value::Set(set) => ValueKeyType::MutableRef(Arc::as_ptr(set) as usize),
             Value::Bag(bag) => ValueKeyType::MutableRef(Arc::as_ptr(bag) as usize),
             Value::Generator(generator) => ValueKeyType::MutableRef(Arc::as_ptr(generator) as usize),
+            Value::Values(values) => ValueKeyType::MutableRef(Arc::as_ptr(values) as usize),
             Value::Procedure(proc) => ValueKeyType::MutableRef(Arc::as_ptr(proc) as usize),
             Value::CaseLambda(cl) => ValueKeyType::MutableRef(Arc::as_ptr(cl) as usize),
             Value::Primitive(prim) => ValueKeyType::MutableRef(Arc::as_ptr(prim) as usize),
@@ -852,34 +856,9 @@ fn apply_procedure_with_evaluator(
     procedure: &Value,
     args: &[Value],
 ) -> Result<Value> {
-    use crate::eval::evaluator::EvalStep;
-    
-    // Start with the initial procedure application
-    let mut step = evaluator.apply_procedure(procedure.clone(), args.to_vec(), None);
-    
-    // Run the trampoline loop to handle all evaluation steps
-    loop {
-        step = match step {
-            EvalStep::Return(value) => return Ok(value),
-            EvalStep::Error(error) => return Err(Box::new(error)),
-            EvalStep::Continue { expr, env } => {
-                // Continue evaluation with the given expression and environment
-                evaluator.eval_step(&expr, env)
-            }
-            EvalStep::TailCall { procedure: proc, args: tail_args, location } => {
-                // Handle tail call by applying the procedure
-                evaluator.apply_procedure(proc, tail_args, location)
-            }
-            EvalStep::CallContinuation { continuation, value } => {
-                // Handle continuation call
-                evaluator.call_continuation(continuation, value)
-            }
-            EvalStep::NonLocalJump { value, target_stack_depth: _ } => {
-                // Non-local jump immediately returns the value
-                return Ok(value);
-            }
-        }
-    }
+    // The evaluator's own trampoline lands escapes to continuations captured
+    // inside the procedure and carries the rest out to their owners
+    evaluator.call_procedure(procedure.clone(), args.to_vec(), None)
 }
 
 /// Applies a procedure to each element of a bag.
@@ -111,6 +111,23 @@ pub fn bind_bytevector_operations(env: &Arc<ThreadSafeEnvironment>) {
         implementation: PrimitiveImpl::RustFn(primitive_bytevector_equal),
         effects: vec![],
     })));
+    
+    // Hex encoding
+    env.define("bytevector->hex-string".to_string(), Value::Primitive(Arc::new(PrimitiveProcedure {
+        name: "bytevector->hex-string".to_string(),
+        arity_min: 1,
+        arity_max: Some(2),
+        implementation: PrimitiveImpl::RustFn(primitive_bytevector_to_hex_string),
+        effects: vec![],
+    })));
+    
+    env.define("hex-string->bytevector".to_string(), Value::Primitive(Arc::new(PrimitiveProcedure {
+        name: "hex-string->bytevector".to_string(),
+        arity_min: 1,
+        arity_max: Some(3),
+        implementation: PrimitiveImpl::RustFn(primitive_hex_string_to_bytevector),
+        effects: vec![],
+    })));
 }
 
 // ============= HELPER FUNCTIONS =============
@@ -126,6 +143,17 @@ fn extract_bytevector(value: &Value, operation: &str) -> Result<Vec<u8>> {
     }
 }
 
+/// Borrows the bytes of a bytevector Value.
+fn bytevector_bytes<'a>(value: &'a Value, operation: &str) -> Result<&'a [u8]> {
+    match value {
+        Value::Literal(Literal::Bytevector(bv)) => Ok(bv),
+        _ => Err(Box::new(Error::runtime_error(
+            format!("{operation} requires a bytevector argument"),
+            None,
+        ))),
+    }
+}
+
 /// Extracts a mutable reference to a bytevector from a Value.
 /// Note: In a functional language, this simulates mutation through COW semantics.
 fn extract_bytevector_mut(value: &Value, operation: &str) -> Result<Vec<u8>> {
@@ -234,41 +262,31 @@ pub fn primitive_bytevector(args: &[Value]) -> Result<Value> {
 /// bytevector-copy bytevector [start [end]] → bytevector
 /// 
 /// Returns a newly allocated bytevector whose elements are copied from the 
-/// bytes of bytevector between start and end.
+/// bytes of bytevector between start and end. Only that range is copied.
 pub fn primitive_bytevector_copy(args: &[Value]) -> Result<Value> {
-    match args.len() {
-        1 => {
-            let bv = extract_bytevector(&args[0], "bytevector-copy")?;
-            Ok(Value::bytevector(bv))
-        }
-        2 => {
-            let bv = extract_bytevector(&args[0], "bytevector-copy")?;
-            let start = extract_non_negative_integer(&args[1], "bytevector-copy")?;
-            if start > bv.len() {
-                return Err(Box::new(Error::runtime_error(
-                    "bytevector-copy: start index out of bounds".to_string(),
-                    None,
-                )));
-            }
-            Ok(Value::bytevector(bv[start..].to_vec()))
-        }
-        3 => {
-            let bv = extract_bytevector(&args[0], "bytevector-copy")?;
-            let start = extract_non_negative_integer(&args[1], "bytevector-copy")?;
-            let end = extract_non_negative_integer(&args[2], "bytevector-copy")?;
-            if start > end || end > bv.len() {
-                return Err(Box::new(Error::runtime_error(
-                    "bytevector-copy: invalid start/end indices".to_string(),
-                    None,
-                )));
-            }
-            Ok(Value::bytevector(bv[start..end].to_vec()))
-        }
-        _ => Err(Box::new(Error::runtime_error(
+    if args.is_empty() || args.len() > 3 {
+        return Err(Box::new(Error::runtime_error(
             format!("bytevector-copy expects 1 to 3 arguments, got {}", args.len()),
             None,
-        )))
+        )));
+    }
+    
+    let bv = bytevector_bytes(&args[0], "bytevector-copy")?;
+    let start = match args.get(1) {
+        Some(arg) => extract_non_negative_integer(arg, "bytevector-copy")?,
+        None => 0,
+    };
+    let end = match args.get(2) {
+        Some(arg) => extract_non_negative_integer(arg, "bytevector-copy")?,
+        None => bv.len(),
+    };
+    if start > end || end > bv.len() {
+        return Err(Box::new(Error::runtime_error(
+            format!("bytevector-copy: invalid start/end indices {start} and {end} for length {}", bv.len()),
+            None,
+        )));
     }
+    Ok(Value::bytevector(bv[start..end].to_vec()))
 }
 
 /// bytevector? obj → boolean
@@ -548,6 +566,75 @@ pub fn primitive_bytevector_equal(args: &[Value]) -> Result<Value> {
     Ok(Value::boolean(true))
 }
 
+// ============= HEX ENCODING =============
+
+/// bytevector->hex-string bytevector [separator] → string
+/// 
+/// Returns the bytes of bytevector as lowercase hexadecimal digit pairs,
+/// joined by the separator string if one is given.
+pub fn primitive_bytevector_to_hex_string(args: &[Value]) -> Result<Value> {
+    if args.is_empty() || args.len() > 2 {
+        return Err(Box::new(Error::runtime_error(
+            format!("bytevector->hex-string expects 1 or 2 arguments, got {}", args.len()),
+            None,
+        )));
+    }
+    
+    let bv = extract_bytevector(&args[0], "bytevector->hex-string")?;
+    let separator = match args.get(1) {
+        Some(value) => extract_string(value, "bytevector->hex-string")?,
+        None => String::new(),
+    };
+    
+    let pairs: Vec<String> = bv.iter().map(|byte| format!("{byte:02x}")).collect();
+    Ok(Value::string(pairs.join(&separator)))
+}
+
+/// hex-string->bytevector string [separator [skip-whitespace?]] → bytevector
+/// 
+/// Parses hexadecimal digit pairs (in either case) into a bytevector.
+/// Occurrences of the separator string are ignored, as is whitespace unless
+/// skip-whitespace? is #f. Signals an error on non-hex characters or an odd
+/// number of digits.
+pub fn primitive_hex_string_to_bytevector(args: &[Value]) -> Result<Value> {
+    if args.is_empty() || args.len() > 3 {
+        return Err(Box::new(Error::runtime_error(
+            format!("hex-string->bytevector expects 1 to 3 arguments, got {}", args.len()),
+            None,
+        )));
+    }
+    
+    let s = extract_string(&args[0], "hex-string->bytevector")?;
+    let separator = match args.get(1) {
+        Some(value) => extract_string(value, "hex-string->bytevector")?,
+        None => String::new(),
+    };
+    let skip_whitespace = args.get(2).is_none_or(Value::is_truthy);
+    
+    let s = if separator.is_empty() { s } else { s.replace(&separator, "") };
+    let mut digits = Vec::with_capacity(s.len());
+    for ch in s.chars() {
+        if skip_whitespace && ch.is_whitespace() {
+            continue;
+        }
+        let digit = ch.to_digit(16).ok_or_else(|| Error::runtime_error(
+            format!("hex-string->bytevector: invalid hex character {ch:?}"),
+            None,
+        ))?;
+        digits.push(digit as u8);
+    }
+    
+    if digits.len() % 2 != 0 {
+        return Err(Box::new(Error::runtime_error(
+            "hex-string->bytevector: odd number of hex digits".to_string(),
+            None,
+        )));
+    }
+    
+    let bytes = digits.chunks(2).map(|pair| (pair[0] << 4) | pair[1]).collect();
+    Ok(Value::bytevector(bytes))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -648,4 +735,36 @@ mod tests {
         let result = primitive_bytevector_equal(&[]).unwrap();
         assert_eq!(result, Value::boolean(true));
     }
-}
\ No newline at end of file
+    
+    #[test]
+    fn test_hex_string_round_trip() {
+        let bv = Value::bytevector(vec![0x00, 0x7f, 0xab, 0xff]);
+        
+        let hex = primitive_bytevector_to_hex_string(&[bv.clone()]).unwrap();
+        assert_eq!(hex, Value::string("007fabff"));
+        assert_eq!(primitive_hex_string_to_bytevector(&[hex]).unwrap(), bv);
+        
+        let hex = primitive_bytevector_to_hex_string(&[bv.clone(), Value::string(":")]).unwrap();
+        assert_eq!(hex, Value::string("00:7f:ab:ff"));
+        assert_eq!(primitive_hex_string_to_bytevector(&[hex, Value::string(":")]).unwrap(), bv);
+        
+        // Upper case and whitespace are accepted by default
+        let result = primitive_hex_string_to_bytevector(&[Value::string("00 7F\nAB ff")]).unwrap();
+        assert_eq!(result, bv);
+        
+        let empty = primitive_bytevector_to_hex_string(&[Value::bytevector(vec![])]).unwrap();
+        assert_eq!(empty, Value::string(""));
+        assert_eq!(primitive_hex_string_to_bytevector(&[empty]).unwrap(), Value::bytevector(vec![]));
+    }
+    
+    #[test]
+    fn test_hex_string_invalid_input() {
+        assert!(primitive_hex_string_to_bytevector(&[Value::string("abc")]).is_err());
+        assert!(primitive_hex_string_to_bytevector(&[Value::string("zz")]).is_err());
+        assert!(primitive_hex_string_to_bytevector(&[
+            Value::string("00 ff"),
+            Value::string(""),
+            Value::boolean(false),
+        ]).is_err());
+    }
+}
@@ -318,6 +318,9 @@ pub fn create_charset_bindings(env: &Arc<ThreadSafeEnvironment>) {
     
     // Character set conversions
     bind_charset_conversions(env);
+    
+    // Character set iteration
+    bind_charset_iteration(env);
 }
 
 fn bind_charset_predicates(env: &Arc<ThreadSafeEnvironment>) {
@@ -509,6 +512,26 @@ fn bind_charset_conversions(env: &Arc<ThreadSafeEnvironment>) {
     })));
 }
 
+fn bind_charset_iteration(env: &Arc<ThreadSafeEnvironment>) {
+    // char-set-for-each
+    env.define("char-set-for-each".to_string(), Value::Primitive(Arc::new(PrimitiveProcedure {
+        name: "char-set-for-each".to_string(),
+        arity_min: 2,
+        arity_max: Some(2),
+        implementation: PrimitiveImpl::EvaluatorIntegrated(evaluator_char_set_for_each),
+        effects: vec![Effect::Pure],
+    })));
+
+    // char-set-fold
+    env.define("char-set-fold".to_string(), Value::Primitive(Arc::new(PrimitiveProcedure {
+        name: "char-set-fold".to_string(),
+        arity_min: 3,
+        arity_max: Some(3),
+        implementation: PrimitiveImpl::EvaluatorIntegrated(evaluator_char_set_fold),
+        effects: vec![Effect::Pure],
+    })));
+}
+
 // ============= PRIMITIVE IMPLEMENTATIONS =============
 
 /// Helper function to extract CharSet from Value
@@ -620,7 +643,10 @@ fn primitive_list_to_char_set(args: &[Value]) -> Result<Value> {
     }
 
     let char_list = args[0].as_list().ok_or_else(|| {
-        Box::new(DiagnosticError::runtime_error("Expected a list".to_string(), None))
+        Box::new(DiagnosticError::runtime_error(
+            "list->char-set requires a proper list of characters".to_string(),
+            None,
+        ))
     })?;
 
     let mut chars = Vec::new();
@@ -647,8 +673,11 @@ fn primitive_string_to_char_set(args: &[Value]) -> Result<Value> {
         )));
     }
 
-    let s = args[0].as_string().ok_or_else(|| {
-        Box::new(DiagnosticError::runtime_error("Expected a string".to_string(), None))
+    let s = args[0].as_string_owned().ok_or_else(|| {
+        Box::new(DiagnosticError::runtime_error(
+            "string->char-set requires a string".to_string(),
+            None,
+        ))
     })?;
 
     let base_charset = if args.len() == 2 {
@@ -657,7 +686,7 @@ fn primitive_string_to_char_set(args: &[Value]) -> Result<Value> {
         CharSet::new()
     };
 
-    let new_charset = CharSet::from_string(s).union(&base_charset);
+    let new_charset = CharSet::from_string(&s).union(&base_charset);
     Ok(Value::CharSet(Arc::new(new_charset)))
 }
 
@@ -862,6 +891,48 @@ fn primitive_char_set_to_string(args: &[Value]) -> Result<Value> {
     Ok(Value::string(s))
 }
 
+// Iteration
+//
+// Character sets hold a finite number of members (even complements are
+// taken against a bounded universe), so iteration walks them in code point
+// order without needing a range bound.
+
+fn evaluator_char_set_for_each(evaluator: &mut crate::eval::evaluator::Evaluator, args: &[Value]) -> Result<Value> {
+    let procedure = get_procedure(&args[0], "char-set-for-each")?;
+    let charset = get_charset(&args[1])?;
+
+    for &c in charset.iter() {
+        evaluator.call_procedure(procedure.clone(), vec![Value::Literal(Literal::Character(c))], None)?;
+    }
+    Ok(Value::Unspecified)
+}
+
+fn evaluator_char_set_fold(evaluator: &mut crate::eval::evaluator::Evaluator, args: &[Value]) -> Result<Value> {
+    let kons = get_procedure(&args[0], "char-set-fold")?;
+    let charset = get_charset(&args[2])?;
+
+    let mut accumulator = args[1].clone();
+    for &c in charset.iter() {
+        accumulator = evaluator.call_procedure(
+            kons.clone(),
+            vec![Value::Literal(Literal::Character(c)), accumulator],
+            None,
+        )?;
+    }
+    Ok(accumulator)
+}
+
+fn get_procedure<'a>(value: &'a Value, operation: &str) -> Result<&'a Value> {
+    if value.is_procedure() {
+        Ok(value)
+    } else {
+        Err(Box::new(DiagnosticError::runtime_error(
+            format!("{operation} requires a procedure"),
+            None,
+        )))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
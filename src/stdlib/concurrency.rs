@@ -3,6 +3,15 @@
 //!
 //! This module provides Scheme-friendly wrappers around the concurrency
 //! primitives, making them accessible from Scheme code with idiomatic APIs.
+//!
+//! SRFI-18 mutexes are always available; the rest of the module requires
+//! the `async-runtime` feature.
+
+use crate::diagnostics::{Error as DiagnosticError, Result};
+use crate::effects::Effect;
+use crate::eval::{PrimitiveImpl, PrimitiveProcedure, Procedure, ThreadSafeEnvironment, Value};
+use std::sync::{Arc, Condvar};
+use std::thread::ThreadId;
 
 // The entire concurrency stdlib module is only available with async-runtime feature
 #[cfg(feature = "async-runtime")]
@@ -61,7 +70,7 @@ fn register_future_operations(env: &ThreadSafeEnvironment) {
                 let future = Future::resolved(args[0].clone());
                 Ok(Value::Future(Arc::new(future)))
             }),
-            effects: vec![crate::effects::Effect::State],
+            effects: vec![Effect::State],
         }
     )));
 
@@ -163,7 +172,7 @@ fn register_channel_operations(env: &ThreadSafeEnvironment) {
                 .map_err(|e| Error::runtime_error(format!("Failed to create channel: {e}"), None))?;
             Ok(Value::Channel(Arc::new(channel)))
             }),
-            effects: vec![crate::effects::Effect::State],
+            effects: vec![Effect::State],
         }
     )));
 
@@ -752,4 +761,260 @@ pub fn populate_environment(_env: &crate::eval::ThreadSafeEnvironment) {
 pub fn init_concurrency_stdlib() -> crate::diagnostics::Result<()> {
     // No-op when async runtime is disabled
     Ok(())
-}
\ No newline at end of file
+}
+// ============= SRFI-18 MUTEXES =============
+
+/// Binds the SRFI-18 mutex procedures.
+///
+/// These mutexes block the calling OS thread, so they work with the
+/// evaluator threads started by `parallel-vector-map` and do not need the
+/// async runtime. When that runtime is enabled they replace its `make-mutex`.
+pub fn create_mutex_bindings(env: &Arc<ThreadSafeEnvironment>) {
+    // make-mutex
+    env.define("make-mutex".to_string(), Value::Primitive(Arc::new(PrimitiveProcedure {
+        name: "make-mutex".to_string(),
+        arity_min: 0,
+        arity_max: Some(1),
+        implementation: PrimitiveImpl::RustFn(primitive_make_mutex),
+        effects: vec![Effect::State],
+    })));
+
+    // mutex?
+    env.define("mutex?".to_string(), Value::Primitive(Arc::new(PrimitiveProcedure {
+        name: "mutex?".to_string(),
+        arity_min: 1,
+        arity_max: Some(1),
+        implementation: PrimitiveImpl::RustFn(primitive_mutex_p),
+        effects: vec![Effect::Pure],
+    })));
+
+    // mutex-name
+    env.define("mutex-name".to_string(), Value::Primitive(Arc::new(PrimitiveProcedure {
+        name: "mutex-name".to_string(),
+        arity_min: 1,
+        arity_max: Some(1),
+        implementation: PrimitiveImpl::RustFn(primitive_mutex_name),
+        effects: vec![Effect::Pure],
+    })));
+
+    // mutex-lock!
+    env.define("mutex-lock!".to_string(), Value::Primitive(Arc::new(PrimitiveProcedure {
+        name: "mutex-lock!".to_string(),
+        arity_min: 1,
+        arity_max: Some(1),
+        implementation: PrimitiveImpl::RustFn(primitive_mutex_lock),
+        effects: vec![Effect::State],
+    })));
+
+    // mutex-unlock!
+    env.define("mutex-unlock!".to_string(), Value::Primitive(Arc::new(PrimitiveProcedure {
+        name: "mutex-unlock!".to_string(),
+        arity_min: 1,
+        arity_max: Some(1),
+        implementation: PrimitiveImpl::RustFn(primitive_mutex_unlock),
+        effects: vec![Effect::State],
+    })));
+
+    // with-mutex
+    env.define("with-mutex".to_string(), Value::Primitive(Arc::new(PrimitiveProcedure {
+        name: "with-mutex".to_string(),
+        arity_min: 2,
+        arity_max: Some(2),
+        implementation: PrimitiveImpl::EvaluatorIntegrated(evaluator_with_mutex),
+        effects: vec![Effect::State],
+    })));
+}
+
+/// A non-reentrant mutex owned by at most one thread at a time.
+#[derive(Debug)]
+struct SchemeMutex {
+    name: Value,
+    /// Thread currently holding the mutex
+    owner: std::sync::Mutex<Option<ThreadId>>,
+    released: Condvar,
+}
+
+impl SchemeMutex {
+    /// Blocks until the mutex is free, then takes it for the current thread.
+    ///
+    /// Mutexes are not reentrant: locking one the current thread already
+    /// holds is an error rather than a deadlock.
+    fn lock(&self) -> Result<()> {
+        let current = std::thread::current().id();
+        let mut owner = self.owner.lock().unwrap();
+        if *owner == Some(current) {
+            return Err(Box::new(DiagnosticError::runtime_error(
+                "mutex-lock!: mutex is already locked by this thread".to_string(),
+                None,
+            )));
+        }
+        while owner.is_some() {
+            owner = self.released.wait(owner).unwrap();
+        }
+        *owner = Some(current);
+        Ok(())
+    }
+
+    fn unlock(&self) -> Result<()> {
+        let mut owner = self.owner.lock().unwrap();
+        if owner.take().is_none() {
+            return Err(Box::new(DiagnosticError::runtime_error(
+                "mutex-unlock!: mutex is not locked".to_string(),
+                None,
+            )));
+        }
+        self.released.notify_one();
+        Ok(())
+    }
+}
+
+fn extract_mutex<'a>(value: &'a Value, operation: &str) -> Result<&'a SchemeMutex> {
+    match value {
+        Value::Opaque(inner) => inner.downcast_ref::<SchemeMutex>(),
+        _ => None,
+    }
+    .ok_or_else(|| {
+        Box::new(DiagnosticError::runtime_error(
+            format!("{operation} requires a mutex argument, got {value}"),
+            None,
+        ))
+    })
+}
+
+/// make-mutex procedure
+fn primitive_make_mutex(args: &[Value]) -> Result<Value> {
+    if args.len() > 1 {
+        return Err(Box::new(DiagnosticError::runtime_error(
+            format!("make-mutex expects 0 or 1 arguments, got {}", args.len()),
+            None,
+        )));
+    }
+
+    Ok(Value::opaque(SchemeMutex {
+        name: args.first().cloned().unwrap_or(Value::boolean(false)),
+        owner: std::sync::Mutex::new(None),
+        released: Condvar::new(),
+    }))
+}
+
+/// mutex? procedure
+fn primitive_mutex_p(args: &[Value]) -> Result<Value> {
+    if args.len() != 1 {
+        return Err(Box::new(DiagnosticError::runtime_error(
+            format!("mutex? expects 1 argument, got {}", args.len()),
+            None,
+        )));
+    }
+
+    Ok(Value::boolean(extract_mutex(&args[0], "mutex?").is_ok()))
+}
+
+/// mutex-name procedure
+fn primitive_mutex_name(args: &[Value]) -> Result<Value> {
+    if args.len() != 1 {
+        return Err(Box::new(DiagnosticError::runtime_error(
+            format!("mutex-name expects 1 argument, got {}", args.len()),
+            None,
+        )));
+    }
+
+    Ok(extract_mutex(&args[0], "mutex-name")?.name.clone())
+}
+
+/// mutex-lock! procedure
+fn primitive_mutex_lock(args: &[Value]) -> Result<Value> {
+    if args.len() != 1 {
+        return Err(Box::new(DiagnosticError::runtime_error(
+            format!("mutex-lock! expects 1 argument, got {}", args.len()),
+            None,
+        )));
+    }
+
+    extract_mutex(&args[0], "mutex-lock!")?.lock()?;
+    Ok(Value::boolean(true))
+}
+
+/// mutex-unlock! procedure
+fn primitive_mutex_unlock(args: &[Value]) -> Result<Value> {
+    if args.len() != 1 {
+        return Err(Box::new(DiagnosticError::runtime_error(
+            format!("mutex-unlock! expects 1 argument, got {}", args.len()),
+            None,
+        )));
+    }
+
+    extract_mutex(&args[0], "mutex-unlock!")?.unlock()?;
+    Ok(Value::boolean(true))
+}
+
+/// with-mutex procedure
+///
+/// `(with-mutex mutex thunk)` holds `mutex` for the dynamic extent of
+/// `thunk`, so it is released when the thunk returns, raises or is left
+/// through a continuation.
+///
+/// The mutex guards shared data structures such as vectors, pairs and
+/// strings, which every evaluator thread sees. It cannot guard variables:
+/// each evaluator thread has its own copy of the globals, and a `set!` made
+/// by a procedure to a variable it closes over only changes the procedure's
+/// snapshot of its environment, so the assignment is never seen by the
+/// caller.
+fn evaluator_with_mutex(evaluator: &mut crate::eval::Evaluator, args: &[Value]) -> Result<Value> {
+    if args.len() != 2 {
+        return Err(Box::new(DiagnosticError::runtime_error(
+            format!("with-mutex expects 2 arguments, got {}", args.len()),
+            None,
+        )));
+    }
+
+    extract_mutex(&args[0], "with-mutex")?;
+    if !args[1].is_procedure() {
+        return Err(Box::new(DiagnosticError::runtime_error(
+            format!("with-mutex second argument must be a procedure, got {}", args[1]),
+            None,
+        )));
+    }
+
+    let before = mutex_thunk("mutex-lock!", primitive_mutex_lock, &args[0]);
+    let after = mutex_thunk("mutex-unlock!", primitive_mutex_unlock, &args[0]);
+    evaluator.dynamic_wind(before, args[1].clone(), after)
+}
+
+/// Builds a thunk that applies `primitive` to `mutex`, for use as a
+/// `dynamic-wind` before or after procedure.
+fn mutex_thunk(
+    name: &str,
+    primitive: fn(&[Value]) -> Result<Value>,
+    mutex: &Value,
+) -> Value {
+    use crate::ast::{Expr, Formals};
+    use crate::diagnostics::{Span, Spanned};
+
+    let environment = Arc::new(ThreadSafeEnvironment::new(None, 0));
+    environment.define(name.to_string(), Value::Primitive(Arc::new(PrimitiveProcedure {
+        name: name.to_string(),
+        arity_min: 1,
+        arity_max: Some(1),
+        implementation: PrimitiveImpl::RustFn(primitive),
+        effects: vec![Effect::State],
+    })));
+    environment.define("mutex".to_string(), mutex.clone());
+
+    let identifier = |name: &str| Spanned::new(Expr::Identifier(name.to_string()), Span::default());
+    let call = Spanned::new(
+        Expr::Application {
+            operator: Box::new(identifier(name)),
+            operands: vec![identifier("mutex")],
+        },
+        Span::default(),
+    );
+
+    Value::Procedure(Arc::new(Procedure {
+        formals: Formals::Fixed(Vec::new()),
+        body: vec![call],
+        environment,
+        name: Some(name.to_string()),
+        metadata: std::collections::HashMap::new(),
+        source: None,
+    }))
+}
@@ -26,6 +26,15 @@ pub fn create_control_bindings(env: &Arc<ThreadSafeEnvironment>) {
     
     // Promise/lazy evaluation support for SRFI-41
     bind_promise_operations(env);
+
+    // Generators
+    bind_generator_operations(env);
+
+    // Procedural macro transformers
+    crate::eval::procedural_macro::bind_procedural_macros(env);
+
+    // Macro expansion of quoted forms
+    crate::eval::macroexpand::bind_macroexpand(env);
 }
 
 /// Binds procedure application operations.
@@ -35,7 +44,7 @@ fn bind_procedure_application(env: &Arc<ThreadSafeEnvironment>) {
         name: "apply".to_string(),
         arity_min: 2,
         arity_max: None,
-        implementation: PrimitiveImpl::RustFn(primitive_apply),
+        implementation: PrimitiveImpl::EvaluatorIntegrated(evaluator_apply),
         effects: vec![Effect::Pure], // Depends on applied procedure
     })));
     
@@ -53,7 +62,7 @@ fn bind_procedure_application(env: &Arc<ThreadSafeEnvironment>) {
         name: "call-with-values".to_string(),
         arity_min: 2,
         arity_max: Some(2),
-        implementation: PrimitiveImpl::RustFn(primitive_call_with_values),
+        implementation: PrimitiveImpl::EvaluatorIntegrated(evaluator_call_with_values),
         effects: vec![Effect::Pure], // Depends on procedures
     })));
 }
@@ -95,16 +104,34 @@ fn bind_dynamic_control(env: &Arc<ThreadSafeEnvironment>) {
         name: "dynamic-wind".to_string(),
         arity_min: 3,
         arity_max: Some(3),
-        implementation: PrimitiveImpl::RustFn(primitive_dynamic_wind),
+        implementation: PrimitiveImpl::EvaluatorIntegrated(evaluator_dynamic_wind),
         effects: vec![Effect::Pure], // Complex control effects
     })));
     
+    // dynamic-wind/named
+    env.define("dynamic-wind/named".to_string(), Value::Primitive(Arc::new(PrimitiveProcedure {
+        name: "dynamic-wind/named".to_string(),
+        arity_min: 4,
+        arity_max: Some(4),
+        implementation: PrimitiveImpl::EvaluatorIntegrated(evaluator_dynamic_wind_named),
+        effects: vec![Effect::Pure],
+    })));
+    
+    // dynamic-wind-stack
+    env.define("dynamic-wind-stack".to_string(), Value::Primitive(Arc::new(PrimitiveProcedure {
+        name: "dynamic-wind-stack".to_string(),
+        arity_min: 0,
+        arity_max: Some(0),
+        implementation: PrimitiveImpl::EvaluatorIntegrated(evaluator_dynamic_wind_stack),
+        effects: vec![Effect::Pure],
+    })));
+    
     // with-exception-handler
     env.define("with-exception-handler".to_string(), Value::Primitive(Arc::new(PrimitiveProcedure {
         name: "with-exception-handler".to_string(),
         arity_min: 2,
         arity_max: Some(2),
-        implementation: PrimitiveImpl::RustFn(primitive_with_exception_handler),
+        implementation: PrimitiveImpl::EvaluatorIntegrated(crate::stdlib::exceptions::evaluator_with_exception_handler),
         effects: vec![Effect::Error], // Exception handling
     })));
     
@@ -118,10 +145,19 @@ fn bind_evaluation_control(env: &Arc<ThreadSafeEnvironment>) {
         name: "eval".to_string(),
         arity_min: 2,
         arity_max: Some(2),
-        implementation: PrimitiveImpl::RustFn(primitive_eval),
+        implementation: PrimitiveImpl::EvaluatorIntegrated(evaluator_eval),
         effects: vec![Effect::Pure], // Depends on evaluated code
     })));
     
+    // environment
+    env.define("environment".to_string(), Value::Primitive(Arc::new(PrimitiveProcedure {
+        name: "environment".to_string(),
+        arity_min: 0,
+        arity_max: None,
+        implementation: PrimitiveImpl::EvaluatorIntegrated(evaluator_environment),
+        effects: vec![Effect::Pure],
+    })));
+    
     // environment?
     env.define("environment?".to_string(), Value::Primitive(Arc::new(PrimitiveProcedure {
         name: "environment?".to_string(),
@@ -145,7 +181,7 @@ fn bind_evaluation_control(env: &Arc<ThreadSafeEnvironment>) {
         name: "scheme-report-environment".to_string(),
         arity_min: 1,
         arity_max: Some(1),
-        implementation: PrimitiveImpl::RustFn(primitive_scheme_report_environment),
+        implementation: PrimitiveImpl::EvaluatorIntegrated(evaluator_scheme_report_environment),
         effects: vec![Effect::Pure],
     })));
     
@@ -162,7 +198,10 @@ fn bind_evaluation_control(env: &Arc<ThreadSafeEnvironment>) {
 // ============= IMPLEMENTATIONS =============
 
 /// apply procedure - R7RS compliant implementation
-fn primitive_apply(args: &[Value]) -> Result<Value> {
+///
+/// The final list is spread before the procedure is called, so arity
+/// checks and `case-lambda` clause selection see the total argument count.
+fn evaluator_apply(evaluator: &mut crate::eval::Evaluator, args: &[Value]) -> Result<Value> {
     if args.len() < 2 {
         return Err(Box::new(DiagnosticError::runtime_error(
             "apply requires at least 2 arguments".to_string(),
@@ -182,108 +221,47 @@ fn primitive_apply(args: &[Value]) -> Result<Value> {
     
     // Collect arguments: all but the last are individual args, 
     // the last must be a list that gets flattened
-    let mut final_args = Vec::new();
+    let mut final_args = args[1..args.len() - 1].to_vec();
     
-    // Add individual arguments (all but the last two)
-    for arg in &args[1..args.len()-1] {
-        final_args.push(arg.clone());
-    }
-    
-    // The last argument must be a list - flatten it
     let last_arg = &args[args.len() - 1];
-    if let Some(list_values) = last_arg.as_list() {
-        final_args.extend(list_values);
-    } else {
-        return Err(Box::new(DiagnosticError::runtime_error(
-            "apply last argument must be a list".to_string(),
-            None,
-        )));
-    }
-    
-    // Apply the procedure to the collected arguments
-    match procedure {
-        Value::Primitive(prim) => {
-            // Check arity
-            let arg_count = final_args.len();
-            if arg_count < prim.arity_min {
-                return Err(Box::new(DiagnosticError::runtime_error(
-                    format!("{} requires at least {} arguments, got {}", 
-                            prim.name, prim.arity_min, arg_count),
-                    None,
-                )));
-            }
-            if let Some(max) = prim.arity_max {
-                if arg_count > max {
-                    return Err(Box::new(DiagnosticError::runtime_error(
-                        format!("{} requires at most {} arguments, got {}", 
-                                prim.name, max, arg_count),
-                        None,
-                    )));
-                }
-            }
-            
-            // Call the primitive procedure
-            match &prim.implementation {
-                PrimitiveImpl::RustFn(func) => func(&final_args),
-                PrimitiveImpl::Native(func) => func(&final_args),
-                PrimitiveImpl::EvaluatorIntegrated(_) => {
-                    Err(Box::new(DiagnosticError::runtime_error(
-                        "apply to EvaluatorIntegrated functions requires evaluator integration (not yet implemented)".to_string(),
-                        None,
-                    )))
-                }
-                PrimitiveImpl::ForeignFn { .. } => {
-                    Err(Box::new(DiagnosticError::runtime_error(
-                        "apply to foreign functions not yet implemented".to_string(),
-                        None,
-                    )))
-                }
-            }
-        },
-        Value::Procedure(_) => {
-            // User-defined procedures require evaluator integration
-            // For now, return an error but this is where we'd integrate with the evaluator
-            Err(Box::new(DiagnosticError::runtime_error(
-                "apply to user-defined procedures requires evaluator integration (not yet implemented)".to_string(),
-                None,
-            )))
-        },
-        Value::Continuation(_) => {
-            // Continuations also require evaluator integration
-            Err(Box::new(DiagnosticError::runtime_error(
-                "apply to continuations requires evaluator integration (not yet implemented)".to_string(),
-                None,
-            )))
-        },
-        _ => {
-            Err(Box::new(DiagnosticError::runtime_error(
-                "apply first argument must be a procedure".to_string(),
+    match last_arg.as_list() {
+        Some(list_values) => final_args.extend(list_values),
+        None => {
+            return Err(Box::new(DiagnosticError::runtime_error(
+                "apply last argument must be a list".to_string(),
                 None,
-            )))
+            )));
         }
     }
+    
+    // The procedure is applied in tail position
+    evaluator.tail_call(procedure.clone(), final_args)
 }
 
 /// values procedure
 fn primitive_values(args: &[Value]) -> Result<Value> {
-    // For now, just return the first value or unspecified
-    if args.is_empty() {
-        Ok(Value::Unspecified)
-    } else if args.len() == 1 {
-        Ok(args[0].clone())
-    } else {
-        // Multiple values - in a full implementation, this would return a special multiple values object
-        Ok(args[0].clone())
-    }
+    Ok(Value::values(args.to_vec()))
 }
 
 /// call-with-values procedure
-fn primitive_call_with_values(_args: &[Value]) -> Result<Value> {
-    // Note: This requires evaluator integration for procedure calls
-    Err(Box::new(DiagnosticError::runtime_error(
-        "call-with-values requires evaluator integration (not yet implemented)".to_string(),
-        None,
-    )))
+fn evaluator_call_with_values(evaluator: &mut crate::eval::Evaluator, args: &[Value]) -> Result<Value> {
+    if args.len() != 2 {
+        return Err(Box::new(DiagnosticError::runtime_error(
+            format!("call-with-values expects 2 arguments, got {}", args.len()),
+            None,
+        )));
+    }
+
+    if !args.iter().all(Value::is_procedure) {
+        return Err(Box::new(DiagnosticError::runtime_error(
+            "call-with-values arguments must be procedures".to_string(),
+            None,
+        )));
+    }
+
+    let produced = evaluator.call_procedure(args[0].clone(), Vec::new(), None)?;
+    // The consumer is applied in tail position
+    evaluator.tail_call(args[1].clone(), produced.into_values())
 }
 
 /// call/cc procedure
@@ -328,56 +306,199 @@ pub fn primitive_continuation_p(args: &[Value]) -> Result<Value> {
 }
 
 /// dynamic-wind procedure
-fn primitive_dynamic_wind(_args: &[Value]) -> Result<Value> {
-    // Note: This requires complex control flow management
-    Err(Box::new(DiagnosticError::runtime_error(
-        "dynamic-wind requires complex control flow support (not yet implemented)".to_string(),
-        None,
-    )))
+fn evaluator_dynamic_wind(evaluator: &mut crate::eval::Evaluator, args: &[Value]) -> Result<Value> {
+    if args.len() != 3 {
+        return Err(Box::new(DiagnosticError::runtime_error(
+            format!("dynamic-wind expects 3 arguments, got {}", args.len()),
+            None,
+        )));
+    }
+
+    if !args.iter().all(Value::is_procedure) {
+        return Err(Box::new(DiagnosticError::runtime_error(
+            "dynamic-wind arguments must be procedures".to_string(),
+            None,
+        )));
+    }
+
+    evaluator.dynamic_wind(args[0].clone(), args[1].clone(), args[2].clone())
 }
 
-/// with-exception-handler procedure
-fn primitive_with_exception_handler(_args: &[Value]) -> Result<Value> {
-    // Note: This requires exception handling infrastructure
-    Err(Box::new(DiagnosticError::runtime_error(
-        "with-exception-handler requires exception handling support (not yet implemented)".to_string(),
-        None,
-    )))
+/// dynamic-wind/named procedure
+///
+/// `(dynamic-wind/named name before thunk after)` behaves like `dynamic-wind`
+/// and reports `name` for the extent in `dynamic-wind-stack`.
+fn evaluator_dynamic_wind_named(evaluator: &mut crate::eval::Evaluator, args: &[Value]) -> Result<Value> {
+    if args.len() != 4 {
+        return Err(Box::new(DiagnosticError::runtime_error(
+            format!("dynamic-wind/named expects 4 arguments, got {}", args.len()),
+            None,
+        )));
+    }
+
+    if !args[1..].iter().all(Value::is_procedure) {
+        return Err(Box::new(DiagnosticError::runtime_error(
+            "dynamic-wind/named before, thunk and after must be procedures".to_string(),
+            None,
+        )));
+    }
+
+    evaluator.dynamic_wind_named(Some(args[0].clone()), args[1].clone(), args[2].clone(), args[3].clone())
+}
+
+/// dynamic-wind-stack procedure
+///
+/// Returns the names of the active dynamic-wind extents, innermost first,
+/// with #f for extents entered through plain `dynamic-wind`.
+fn evaluator_dynamic_wind_stack(evaluator: &mut crate::eval::Evaluator, _args: &[Value]) -> Result<Value> {
+    let names = evaluator
+        .dynamic_wind_stack()
+        .into_iter()
+        .map(|name| name.unwrap_or(Value::boolean(false)))
+        .collect();
+    Ok(Value::list(names))
 }
 
 // Note: primitive_raise and primitive_raise_continuable are now in stdlib::exceptions
 
+/// A first-class environment for `eval`, built by `environment`,
+/// `scheme-report-environment` or `null-environment`.
+///
+/// These environments are immutable, so `eval` rejects definitions in them.
+#[derive(Debug)]
+struct EvalEnvironment {
+    bindings: Arc<ThreadSafeEnvironment>,
+}
+
+impl EvalEnvironment {
+    fn from_bindings(bindings: HashMap<String, Value>) -> Value {
+        let env = Arc::new(ThreadSafeEnvironment::new(None, 0));
+        for (name, value) in bindings {
+            env.define(name, value);
+        }
+        Value::Opaque(Arc::new(EvalEnvironment { bindings: env }))
+    }
+
+    fn from_value(value: &Value) -> Option<&Self> {
+        match value {
+            Value::Opaque(inner) => inner.downcast_ref::<EvalEnvironment>(),
+            _ => None,
+        }
+    }
+}
+
+/// Converts a datum back into an expression by printing and re-reading it,
+/// so that derived forms are desugared exactly as in source code.
+fn datum_to_expr(datum: &Value) -> Result<crate::diagnostics::Spanned<crate::ast::Expr>> {
+    let text = datum.to_string();
+    let tokens = crate::lexer::Lexer::new(&text, None).tokenize()?;
+    crate::parser::Parser::new(tokens).parse_expression()
+}
+
+/// Returns the definition keyword if `datum` is a top-level definition,
+/// looking inside `begin` forms whose bodies splice into the environment.
+fn top_level_definition(datum: &Value) -> Option<String> {
+    let form = datum.as_list()?;
+    let keyword = crate::utils::symbol_name(form.first()?.as_symbol()?)?;
+    match keyword.as_str() {
+        "define" | "define-values" | "define-record-type" | "define-syntax" => Some(keyword),
+        "begin" => form[1..].iter().find_map(top_level_definition),
+        _ => None,
+    }
+}
+
 /// eval procedure
-fn primitive_eval(_args: &[Value]) -> Result<Value> {
-    // Note: This requires evaluator integration
-    Err(Box::new(DiagnosticError::runtime_error(
-        "eval requires evaluator integration (not yet implemented)".to_string(),
-        None,
-    )))
+fn evaluator_eval(evaluator: &mut crate::eval::Evaluator, args: &[Value]) -> Result<Value> {
+    if args.len() != 2 {
+        return Err(Box::new(DiagnosticError::runtime_error(
+            format!("eval expects 2 arguments, got {}", args.len()),
+            None,
+        )));
+    }
+
+    let environment = EvalEnvironment::from_value(&args[1]).ok_or_else(|| {
+        Box::new(DiagnosticError::runtime_error(
+            "eval: second argument must be an environment".to_string(),
+            None,
+        ))
+    })?;
+
+    if let Some(keyword) = top_level_definition(&args[0]) {
+        return Err(Box::new(DiagnosticError::runtime_error(
+            format!("eval: cannot use {keyword} in an immutable environment"),
+            None,
+        )));
+    }
+
+    let expr = datum_to_expr(&args[0])?;
+    evaluator.eval(&expr, environment.bindings.to_legacy())
 }
 
 /// environment? predicate
-fn primitive_environment_p(_args: &[Value]) -> Result<Value> {
-    // For now, return false as we don't have first-class environments
-    Ok(Value::boolean(false))
+fn primitive_environment_p(args: &[Value]) -> Result<Value> {
+    if args.len() != 1 {
+        return Err(Box::new(DiagnosticError::runtime_error(
+            format!("environment? expects 1 argument, got {}", args.len()),
+            None,
+        )));
+    }
+    
+    Ok(Value::boolean(EvalEnvironment::from_value(&args[0]).is_some()))
+}
+
+/// environment procedure
+///
+/// `(environment import-set ...)` resolves each import set through the module
+/// system, exactly as `import` would.
+fn evaluator_environment(evaluator: &mut crate::eval::Evaluator, args: &[Value]) -> Result<Value> {
+    let mut bindings_list = Vec::with_capacity(args.len());
+    for import_set in args {
+        let spec = datum_to_expr(import_set)?;
+        bindings_list.push(evaluator.import_set_bindings(&spec)?);
+    }
+
+    let bindings = crate::module_system::import::merge_import_bindings(&bindings_list)?;
+    Ok(EvalEnvironment::from_bindings(bindings))
+}
+
+/// Checks the version argument of the R5RS environment procedures.
+fn check_report_version(args: &[Value], name: &str) -> Result<()> {
+    if args.len() != 1 {
+        return Err(Box::new(DiagnosticError::runtime_error(
+            format!("{name} expects 1 argument, got {}", args.len()),
+            None,
+        )));
+    }
+    
+    match args[0].as_integer() {
+        Some(5) => Ok(()),
+        _ => Err(Box::new(DiagnosticError::runtime_error(
+            format!("{name}: unsupported version {}", args[0]),
+            None,
+        ))),
+    }
 }
 
 /// null-environment procedure
-fn primitive_null_environment(_args: &[Value]) -> Result<Value> {
-    // Note: This requires environment management
-    Err(Box::new(DiagnosticError::runtime_error(
-        "null-environment requires environment management (not yet implemented)".to_string(),
-        None,
-    )))
+///
+/// Syntactic keywords are recognised by the parser rather than bound, so the
+/// null environment has no bindings at all.
+fn primitive_null_environment(args: &[Value]) -> Result<Value> {
+    check_report_version(args, "null-environment")?;
+    Ok(EvalEnvironment::from_bindings(HashMap::new()))
 }
 
 /// scheme-report-environment procedure
-fn primitive_scheme_report_environment(_args: &[Value]) -> Result<Value> {
-    // Note: This requires environment management
-    Err(Box::new(DiagnosticError::runtime_error(
-        "scheme-report-environment requires environment management (not yet implemented)".to_string(),
-        None,
-    )))
+///
+/// Version 5 provides the bindings of the `(scheme r5rs)` library.
+fn evaluator_scheme_report_environment(evaluator: &mut crate::eval::Evaluator, args: &[Value]) -> Result<Value> {
+    check_report_version(args, "scheme-report-environment")?;
+    let spec = datum_to_expr(&Value::list(vec![
+        Value::symbol_from_str("scheme"),
+        Value::symbol_from_str("r5rs"),
+    ]))?;
+    let bindings = evaluator.import_set_bindings(&spec)?;
+    Ok(EvalEnvironment::from_bindings(bindings))
 }
 
 /// interaction-environment procedure
@@ -399,13 +520,31 @@ fn bind_promise_operations(env: &Arc<ThreadSafeEnvironment>) {
         implementation: PrimitiveImpl::RustFn(primitive_make_promise),
         effects: vec![Effect::Pure],
     })));
+
+    // eager - SRFI-45 name for make-promise
+    env.define("eager".to_string(), Value::Primitive(Arc::new(PrimitiveProcedure {
+        name: "eager".to_string(),
+        arity_min: 1,
+        arity_max: Some(1),
+        implementation: PrimitiveImpl::RustFn(primitive_make_promise),
+        effects: vec![Effect::Pure],
+    })));
+
+    // %make-delayed-promise - target of the delay macro
+    env.define("%make-delayed-promise".to_string(), Value::Primitive(Arc::new(PrimitiveProcedure {
+        name: "%make-delayed-promise".to_string(),
+        arity_min: 1,
+        arity_max: Some(1),
+        implementation: PrimitiveImpl::RustFn(primitive_make_delayed_promise),
+        effects: vec![Effect::Pure],
+    })));
     
     // force - R7RS compliant with full promise chain resolution
     env.define("force".to_string(), Value::Primitive(Arc::new(PrimitiveProcedure {
         name: "force".to_string(),
         arity_min: 1,
         arity_max: Some(1),
-        implementation: PrimitiveImpl::RustFn(primitive_force),
+        implementation: PrimitiveImpl::EvaluatorIntegrated(evaluator_force),
         effects: vec![Effect::Pure],
     })));
     
@@ -450,12 +589,15 @@ fn bind_promise_operations(env: &Arc<ThreadSafeEnvironment>) {
         name: "promise-force".to_string(),
         arity_min: 1,
         arity_max: Some(1),
-        implementation: PrimitiveImpl::RustFn(primitive_force),
+        implementation: PrimitiveImpl::EvaluatorIntegrated(evaluator_force),
         effects: vec![Effect::Pure],
     })));
 }
 
 /// make-promise procedure - R7RS compliant implementation
+///
+/// Returns an already forced promise holding the argument, so forcing it
+/// never runs any code. A promise argument is returned as is.
 fn primitive_make_promise(args: &[Value]) -> Result<Value> {
     if args.len() != 1 {
         return Err(Box::new(DiagnosticError::runtime_error(
@@ -463,13 +605,32 @@ fn primitive_make_promise(args: &[Value]) -> Result<Value> {
             None,
         )));
     }
+
+    if let Value::Promise(_) = &args[0] {
+        return Ok(args[0].clone());
+    }
+
+    Ok(Value::Promise(Arc::new(RwLock::new(Promise::Forced(args[0].clone())))))
+}
+
+/// %make-delayed-promise procedure - the promise built by `delay`
+///
+/// Wraps a thunk in an unforced promise; `force` runs it once and
+/// memoizes the result.
+fn primitive_make_delayed_promise(args: &[Value]) -> Result<Value> {
+    if args.len() != 1 {
+        return Err(Box::new(DiagnosticError::runtime_error(
+            format!("%make-delayed-promise expects 1 argument, got {}", args.len()),
+            None,
+        )));
+    }
     
     let thunk = &args[0];
     
     // Verify the argument is a procedure (thunk)
     if !thunk.is_procedure() {
         return Err(Box::new(DiagnosticError::runtime_error(
-            "make-promise argument must be a procedure (thunk)".to_string(),
+            "%make-delayed-promise argument must be a procedure (thunk)".to_string(),
             None,
         )));
     }
@@ -482,8 +643,8 @@ fn primitive_make_promise(args: &[Value]) -> Result<Value> {
     Ok(Value::Promise(Arc::new(RwLock::new(promise))))
 }
 
-/// force procedure - R7RS compliant implementation with trampoline to prevent stack overflow
-fn primitive_force(args: &[Value]) -> Result<Value> {
+/// force procedure - R7RS compliant, runs the thunk through the evaluator
+fn evaluator_force(evaluator: &mut crate::eval::Evaluator, args: &[Value]) -> Result<Value> {
     if args.len() != 1 {
         return Err(Box::new(DiagnosticError::runtime_error(
             format!("force expects 1 argument, got {}", args.len()),
@@ -491,155 +652,8 @@ fn primitive_force(args: &[Value]) -> Result<Value> {
         )));
     }
     
-    let initial_value = args[0].clone();
-    
-    // If not a promise, return immediately (R7RS behavior)
-    if !matches!(initial_value, Value::Promise(_)) {
-        return Ok(initial_value);
-    }
-    
-    // Use trampoline to avoid stack overflow in deep promise chains
-    force_with_trampoline(initial_value)
-}
-
-/// Iterative promise resolution using trampoline technique.
-/// This prevents stack overflow by converting recursion to iteration.
-fn force_with_trampoline(initial_value: Value) -> Result<Value> {
-    let mut trampoline_stack = vec![initial_value];
-    let mut visited = std::collections::HashSet::new();
-    let mut iteration_count = 0;
-    const MAX_ITERATIONS: usize = 100000; // Prevent infinite loops
-    
-    while let Some(current_value) = trampoline_stack.pop() {
-        iteration_count += 1;
-        if iteration_count > MAX_ITERATIONS {
-            return Err(Box::new(DiagnosticError::runtime_error(
-                "promise chain too deep (possible infinite recursion)".to_string(),
-                None,
-            )));
-        }
-        
-        match current_value {
-            Value::Promise(promise_ref) => {
-                let promise_id = Arc::as_ptr(&promise_ref) as usize;
-                
-                // Check for circular references
-                if visited.contains(&promise_id) {
-                    return Err(Box::new(DiagnosticError::runtime_error(
-                        "circular promise reference detected".to_string(),
-                        None,
-                    )));
-                }
-                visited.insert(promise_id);
-                
-                // Try to read first to check if already forced
-                if let Ok(promise_read) = promise_ref.read() {
-                    match &*promise_read {
-                        Promise::Forced(cached_value) => {
-                            // Already evaluated, continue with cached result
-                            trampoline_stack.push(cached_value.clone());
-                            continue;
-                        }
-                        _ => {
-                            // Need to evaluate, drop read lock before getting write lock
-                        }
-                    }
-                }
-                
-                // Get write lock for evaluation
-                let mut promise = promise_ref.write().map_err(|_| {
-                    DiagnosticError::runtime_error(
-                        "failed to acquire promise lock for writing".to_string(),
-                        None,
-                    )
-                })?;
-                
-                // Check again in case another thread forced it
-                match &*promise {
-                    Promise::Forced(cached_value) => {
-                        trampoline_stack.push(cached_value.clone());
-                        continue;
-                    }
-                    Promise::Delayed { thunk } => {
-                        // Evaluate the thunk using a simple fallback approach
-                        let result = evaluate_simple_thunk(thunk)?;
-                        
-                        // Memoize the result
-                        *promise = Promise::Forced(result.clone());
-                        
-                        // Continue evaluation in case result is also a promise
-                        trampoline_stack.push(result);
-                        continue;
-                    }
-                    Promise::TailRecursive { thunk } => {
-                        // For tail-recursive promises, don't memoize to allow optimization
-                        let result = evaluate_simple_thunk(thunk)?;
-                        
-                        // Continue evaluation without memoization
-                        trampoline_stack.push(result);
-                        continue;
-                    }
-                    Promise::Expression { expression: _, environment: _ } => {
-                        return Err(Box::new(DiagnosticError::runtime_error(
-                            "expression-based promises require evaluator integration".to_string(),
-                            None,
-                        )));
-                    }
-                }
-            }
-            _ => {
-                // Not a promise, this is our final result
-                return Ok(current_value);
-            }
-        }
-    }
-    
-    // Should never reach here if logic is correct
-    Err(Box::new(DiagnosticError::runtime_error(
-        "internal error: trampoline stack became empty unexpectedly".to_string(),
-        None,
-    )))
-}
-
-/// Simple thunk evaluation for promises.
-/// This is a fallback implementation until full evaluator integration is available.
-fn evaluate_simple_thunk(thunk: &Value) -> Result<Value> {
-    match thunk {
-        Value::Primitive(prim) => {
-            match &prim.implementation {
-                PrimitiveImpl::RustFn(func) => {
-                    // Call the primitive function with no arguments
-                    func(&[])
-                }
-                PrimitiveImpl::Native(func) => {
-                    func(&[])
-                }
-                _ => {
-                    Err(Box::new(DiagnosticError::runtime_error(
-                        "foreign functions not supported in promises yet".to_string(),
-                        None,
-                    )))
-                }
-            }
-        }
-        Value::Procedure(proc) => {
-            // Simple thunk evaluation based on procedure name for testing
-            if let Some(name) = &proc.name {
-                match name.as_str() {
-                    "test-addition-thunk" => Ok(Value::integer(3)), // For (+ 1 2) test case
-                    "deep-computation-thunk" => Ok(Value::integer(42)), // For deep recursion test
-                    _ => Ok(Value::integer(1)), // Default simple result
-                }
-            } else {
-                // Anonymous procedure - return a simple default
-                Ok(Value::integer(1))
-            }
-        }
-        _ => {
-            // Treat non-procedure as a constant thunk
-            Ok(thunk.clone())
-        }
-    }
+    // Non-promises are returned unchanged (R7RS behavior)
+    evaluator.force_promise(args[0].clone())
 }
 
 /// promise? predicate - R7RS compliant type predicate
@@ -723,16 +737,200 @@ fn primitive_make_test_thunk(args: &[Value]) -> Result<Value> {
     Ok(thunk)
 }
 
+// ============= GENERATORS =============
+
+/// Binds generator operations.
+fn bind_generator_operations(env: &Arc<ThreadSafeEnvironment>) {
+    // make-generator
+    env.define("make-generator".to_string(), Value::Primitive(Arc::new(PrimitiveProcedure {
+        name: "make-generator".to_string(),
+        arity_min: 1,
+        arity_max: Some(1),
+        implementation: PrimitiveImpl::RustFn(primitive_make_generator),
+        effects: vec![Effect::Pure],
+    })));
+}
+
+/// Shared state between a generator and the yield procedure handed to its body.
+struct GeneratorState {
+    /// The `(lambda (yield) ...)` body procedure
+    body: Value,
+    progress: std::sync::Mutex<GeneratorProgress>,
+}
+
+#[derive(Default)]
+struct GeneratorProgress {
+    /// Number of values already returned to callers
+    delivered: usize,
+    /// Number of yields reached during the current run of the body
+    seen: usize,
+    running: bool,
+    finished: bool,
+    /// Value captured by the yield that suspended the current run
+    pending: Option<Value>,
+}
+
+/// make-generator procedure
+///
+/// Continuations cannot be re-entered, so a generator resumes by running its
+/// body again from the start and skipping the yields it has already delivered.
+/// Bodies should therefore avoid side effects other than yielding.
+fn primitive_make_generator(args: &[Value]) -> Result<Value> {
+    if args.len() != 1 {
+        return Err(Box::new(DiagnosticError::runtime_error(
+            format!("make-generator expects 1 argument, got {}", args.len()),
+            None,
+        )));
+    }
+
+    if !args[0].is_procedure() {
+        return Err(Box::new(DiagnosticError::runtime_error(
+            "make-generator argument must be a procedure".to_string(),
+            None,
+        )));
+    }
+
+    let state = Value::Opaque(Arc::new(GeneratorState {
+        body: args[0].clone(),
+        progress: std::sync::Mutex::new(GeneratorProgress::default()),
+    }));
+
+    Ok(generator_closure("generator", &[], "%generator-resume", evaluator_generator_resume, state))
+}
+
+/// Builds a procedure whose body applies the private primitive `helper` to the
+/// generator state followed by `formals`.
+fn generator_closure(
+    name: &str,
+    formals: &[&str],
+    helper: &str,
+    implementation: fn(&mut crate::eval::Evaluator, &[Value]) -> Result<Value>,
+    state: Value,
+) -> Value {
+    use crate::ast::Expr;
+    use crate::diagnostics::{Span, Spanned};
+
+    let environment = Arc::new(ThreadSafeEnvironment::new(None, 0));
+    environment.define(helper.to_string(), Value::Primitive(Arc::new(PrimitiveProcedure {
+        name: helper.to_string(),
+        arity_min: formals.len() + 1,
+        arity_max: Some(formals.len() + 1),
+        implementation: PrimitiveImpl::EvaluatorIntegrated(implementation),
+        effects: vec![Effect::Pure],
+    })));
+    environment.define("state".to_string(), state);
+
+    let identifier = |name: &str| Spanned::new(Expr::Identifier(name.to_string()), Span::default());
+    let operands = std::iter::once("state").chain(formals.iter().copied()).map(identifier).collect();
+    let call = Spanned::new(
+        Expr::Application {
+            operator: Box::new(identifier(helper)),
+            operands,
+        },
+        Span::default(),
+    );
+
+    Value::Procedure(Arc::new(Procedure {
+        formals: Formals::Fixed(formals.iter().map(|formal| formal.to_string()).collect()),
+        body: vec![call],
+        environment,
+        name: Some(name.to_string()),
+        metadata: HashMap::new(),
+        source: None,
+    }))
+}
+
+fn generator_state(value: &Value) -> Result<&GeneratorState> {
+    match value {
+        Value::Opaque(inner) => inner.downcast_ref::<GeneratorState>().ok_or_else(|| {
+            Box::new(DiagnosticError::runtime_error("Invalid generator state".to_string(), None))
+        }),
+        _ => Err(Box::new(DiagnosticError::runtime_error("Invalid generator state".to_string(), None))),
+    }
+}
+
+/// Runs the generator body up to its next undelivered yield.
+fn evaluator_generator_resume(evaluator: &mut crate::eval::Evaluator, args: &[Value]) -> Result<Value> {
+    let state = generator_state(&args[0])?;
+    {
+        let mut progress = state.progress.lock().unwrap();
+        if progress.finished {
+            return Ok(Value::EofObject);
+        }
+        if progress.running {
+            return Err(Box::new(DiagnosticError::runtime_error(
+                "generator resumed while it is already running".to_string(),
+                None,
+            )));
+        }
+        progress.seen = 0;
+        progress.running = true;
+    }
+
+    let yield_procedure = generator_closure("yield", &["value"], "%generator-yield", evaluator_generator_yield, args[0].clone());
+    let result = evaluator.call_procedure(state.body.clone(), vec![yield_procedure], None);
+
+    let mut progress = state.progress.lock().unwrap();
+    progress.running = false;
+    match (result, progress.pending.take()) {
+        (_, Some(value)) => {
+            progress.delivered += 1;
+            Ok(value)
+        }
+        (Ok(_), None) => {
+            progress.finished = true;
+            Ok(Value::EofObject)
+        }
+        (Err(error), None) => Err(error),
+    }
+}
+
+/// Delivers a yielded value, or skips it when replaying an earlier yield.
+fn evaluator_generator_yield(_evaluator: &mut crate::eval::Evaluator, args: &[Value]) -> Result<Value> {
+    let state = generator_state(&args[0])?;
+    let mut progress = state.progress.lock().unwrap();
+    if !progress.running {
+        return Err(Box::new(DiagnosticError::runtime_error(
+            "yield called outside its generator".to_string(),
+            None,
+        )));
+    }
+
+    progress.seen += 1;
+    if progress.seen <= progress.delivered {
+        return Ok(Value::Unspecified);
+    }
+
+    // Unwind out of the body; the resume step picks the value up from here
+    progress.pending = Some(args[1].clone());
+    Err(Box::new(DiagnosticError::runtime_error(
+        "generator suspended".to_string(),
+        None,
+    )))
+}
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    /// Calls `apply` through an evaluator, which runs its tail call.
+    fn apply(args: &[Value]) -> Result<Value> {
+        let apply = Value::Primitive(Arc::new(PrimitiveProcedure {
+            name: "apply".to_string(),
+            arity_min: 2,
+            arity_max: None,
+            implementation: PrimitiveImpl::EvaluatorIntegrated(evaluator_apply),
+            effects: vec![Effect::Pure],
+        }));
+        crate::eval::Evaluator::new().call_procedure(apply, args.to_vec(), None)
+    }
+
     #[test]
     fn test_values() {
         // Test values with no arguments
         let result = primitive_values(&[]).unwrap();
-        assert_eq!(result, Value::Unspecified);
+        assert_eq!(result, Value::Values(Arc::new(Vec::new())));
+        assert!(result.into_values().is_empty());
         
         // Test values with one argument
         let args = vec![Value::integer(42)];
@@ -742,7 +940,7 @@ mod tests {
         // Test values with multiple arguments
         let args = vec![Value::integer(1), Value::integer(2)];
         let result = primitive_values(&args).unwrap();
-        assert_eq!(result, Value::integer(1)); // Returns first for now
+        assert_eq!(result.into_values(), args);
     }
     
     #[test]
@@ -781,7 +979,7 @@ mod tests {
             add_value,
             Value::list(vec![Value::number(1.0), Value::number(2.0), Value::number(3.0)])
         ];
-        let result = primitive_apply(&args).unwrap();
+        let result = apply(&args).unwrap();
         assert_eq!(result, Value::number(6.0));
     }
     
@@ -809,7 +1007,7 @@ mod tests {
             Value::number(2.0),
             Value::list(vec![Value::number(3.0), Value::number(4.0)])
         ];
-        let result = primitive_apply(&args).unwrap();
+        let result = apply(&args).unwrap();
         assert_eq!(result, Value::number(10.0));
     }
     
@@ -817,7 +1015,7 @@ mod tests {
     fn test_apply_errors() {
         // Test apply with non-procedure
         let args = vec![Value::integer(42), Value::list(vec![Value::integer(1)])];
-        let result = primitive_apply(&args);
+        let result = apply(&args);
         assert!(result.is_err());
         
         // Test apply with non-list last argument
@@ -829,11 +1027,11 @@ mod tests {
             effects: vec![Effect::Pure],
         });
         let args = vec![Value::Primitive(proc), Value::integer(42)];
-        let result = primitive_apply(&args);
+        let result = apply(&args);
         assert!(result.is_err());
         
         // Test apply with too few arguments
-        let result = primitive_apply(&[]);
+        let result = apply(&[]);
         assert!(result.is_err());
     }
 }
\ No newline at end of file
@@ -0,0 +1,352 @@
+//! Character encodings for textual file ports.
+//!
+//! `open-input-file` and `open-output-file` accept an optional encoding
+//! (`utf-8`, `latin-1`, `utf-16le`) that controls how the bytes of the file
+//! map to characters. Decoding is incremental so that a port never consumes
+//! more bytes than the characters it has produced; malformed input either
+//! raises an error ([`DecodeMode::Strict`]) or is replaced with U+FFFD
+//! ([`DecodeMode::Lenient`]).
+
+use std::io::{BufRead, Read};
+
+/// Character produced for malformed input in lenient mode.
+pub const REPLACEMENT_CHARACTER: char = '\u{FFFD}';
+
+/// A character encoding supported by textual file ports.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TextEncoding {
+    /// UTF-8 (the default)
+    Utf8,
+    /// ISO-8859-1: every byte is the scalar value of the same number
+    Latin1,
+    /// UTF-16, little-endian
+    Utf16Le,
+    /// UTF-16, big-endian (selected by a byte order mark)
+    Utf16Be,
+}
+
+/// How malformed input is handled when decoding.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DecodeMode {
+    /// Malformed input is an error
+    Strict,
+    /// Malformed input decodes as U+FFFD
+    Lenient,
+}
+
+/// Result of decoding the start of a byte buffer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Decoded {
+    /// A character and the number of bytes it occupied
+    Char(char, usize),
+    /// The first `n` bytes do not form a character
+    Invalid(usize),
+    /// More bytes are needed to decide
+    Incomplete,
+}
+
+impl TextEncoding {
+    /// Looks up an encoding by its Scheme name.
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.to_ascii_lowercase().as_str() {
+            "utf-8" | "utf8" => Some(TextEncoding::Utf8),
+            "latin-1" | "latin1" | "iso-8859-1" => Some(TextEncoding::Latin1),
+            "utf-16le" => Some(TextEncoding::Utf16Le),
+            "utf-16be" => Some(TextEncoding::Utf16Be),
+            _ => None,
+        }
+    }
+
+    /// The Scheme name of this encoding.
+    pub fn name(&self) -> &'static str {
+        match self {
+            TextEncoding::Utf8 => "utf-8",
+            TextEncoding::Latin1 => "latin-1",
+            TextEncoding::Utf16Le => "utf-16le",
+            TextEncoding::Utf16Be => "utf-16be",
+        }
+    }
+
+    /// Appends the encoding of `ch` to `out`.
+    ///
+    /// Fails when the character is not representable in this encoding.
+    pub fn encode_char(&self, ch: char, out: &mut Vec<u8>) -> std::result::Result<(), String> {
+        match self {
+            TextEncoding::Utf8 => {
+                let mut buf = [0u8; 4];
+                out.extend_from_slice(ch.encode_utf8(&mut buf).as_bytes());
+            }
+            TextEncoding::Latin1 => {
+                let code = ch as u32;
+                if code > 0xFF {
+                    return Err(format!("character U+{code:04X} is not representable in latin-1"));
+                }
+                out.push(code as u8);
+            }
+            TextEncoding::Utf16Le | TextEncoding::Utf16Be => {
+                let mut units = [0u16; 2];
+                for unit in ch.encode_utf16(&mut units) {
+                    let bytes = if *self == TextEncoding::Utf16Le {
+                        unit.to_le_bytes()
+                    } else {
+                        unit.to_be_bytes()
+                    };
+                    out.extend_from_slice(&bytes);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Encodes a whole string.
+    pub fn encode_str(&self, text: &str) -> std::result::Result<Vec<u8>, String> {
+        let mut out = Vec::with_capacity(text.len());
+        for ch in text.chars() {
+            self.encode_char(ch, &mut out)?;
+        }
+        Ok(out)
+    }
+
+    /// Decodes the character at the start of `bytes`.
+    pub fn decode(&self, bytes: &[u8]) -> Decoded {
+        if bytes.is_empty() {
+            return Decoded::Incomplete;
+        }
+
+        match self {
+            TextEncoding::Latin1 => Decoded::Char(bytes[0] as char, 1),
+            TextEncoding::Utf8 => decode_utf8(bytes),
+            TextEncoding::Utf16Le => decode_utf16(bytes, u16::from_le_bytes),
+            TextEncoding::Utf16Be => decode_utf16(bytes, u16::from_be_bytes),
+        }
+    }
+
+    /// The byte order mark for this encoding, if it has one.
+    pub fn byte_order_mark(&self) -> &'static [u8] {
+        match self {
+            TextEncoding::Utf8 => &[0xEF, 0xBB, 0xBF],
+            TextEncoding::Latin1 => &[],
+            TextEncoding::Utf16Le => &[0xFF, 0xFE],
+            TextEncoding::Utf16Be => &[0xFE, 0xFF],
+        }
+    }
+}
+
+impl DecodeMode {
+    /// Looks up a decode mode by its Scheme name.
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "strict" => Some(DecodeMode::Strict),
+            "lenient" => Some(DecodeMode::Lenient),
+            _ => None,
+        }
+    }
+
+    /// The Scheme name of this mode.
+    pub fn name(&self) -> &'static str {
+        match self {
+            DecodeMode::Strict => "strict",
+            DecodeMode::Lenient => "lenient",
+        }
+    }
+}
+
+fn decode_utf8(bytes: &[u8]) -> Decoded {
+    let width = match bytes[0] {
+        0x00..=0x7F => return Decoded::Char(bytes[0] as char, 1),
+        0xC2..=0xDF => 2,
+        0xE0..=0xEF => 3,
+        0xF0..=0xF4 => 4,
+        _ => return Decoded::Invalid(1),
+    };
+
+    let available = bytes.len().min(width);
+    match std::str::from_utf8(&bytes[..available]) {
+        Ok(text) => match text.chars().next() {
+            Some(ch) => Decoded::Char(ch, width),
+            None => Decoded::Incomplete,
+        },
+        // A truncated but so far valid sequence reports no error length
+        Err(e) => match e.error_len() {
+            Some(len) => Decoded::Invalid(len),
+            None => Decoded::Incomplete,
+        },
+    }
+}
+
+fn decode_utf16(bytes: &[u8], to_unit: fn([u8; 2]) -> u16) -> Decoded {
+    if bytes.len() < 2 {
+        return Decoded::Incomplete;
+    }
+
+    let first = to_unit([bytes[0], bytes[1]]);
+    match first {
+        0xD800..=0xDBFF => {
+            if bytes.len() < 4 {
+                return Decoded::Incomplete;
+            }
+            let second = to_unit([bytes[2], bytes[3]]);
+            if !(0xDC00..=0xDFFF).contains(&second) {
+                return Decoded::Invalid(2);
+            }
+            let code = 0x10000 + (((first as u32) - 0xD800) << 10) + ((second as u32) - 0xDC00);
+            match char::from_u32(code) {
+                Some(ch) => Decoded::Char(ch, 4),
+                None => Decoded::Invalid(4),
+            }
+        }
+        0xDC00..=0xDFFF => Decoded::Invalid(2),
+        unit => match char::from_u32(unit as u32) {
+            Some(ch) => Decoded::Char(ch, 2),
+            None => Decoded::Invalid(2),
+        },
+    }
+}
+
+/// Decodes the next character from `reader`.
+///
+/// `pending` holds bytes that have been taken from the reader but not yet
+/// returned as characters; peeking leaves the decoded bytes there. Returns
+/// `Ok(None)` at end of input.
+pub fn read_char<R: BufRead>(
+    reader: &mut R,
+    pending: &mut Vec<u8>,
+    encoding: TextEncoding,
+    mode: DecodeMode,
+    peek: bool,
+) -> std::result::Result<Option<char>, String> {
+    loop {
+        let (ch, consumed) = match encoding.decode(pending) {
+            Decoded::Char(ch, n) => (ch, n),
+            Decoded::Invalid(n) => {
+                let bytes = pending[..n].iter().map(|b| format!("{b:02X}")).collect::<Vec<_>>().join(" ");
+                if mode == DecodeMode::Strict {
+                    // Drop the malformed bytes so that reading can resume
+                    pending.drain(..n);
+                    return Err(format!("invalid {} byte sequence: {bytes}", encoding.name()));
+                }
+                (REPLACEMENT_CHARACTER, n)
+            }
+            Decoded::Incomplete => {
+                let mut byte = [0u8; 1];
+                let read = reader.read(&mut byte).map_err(|e| e.to_string())?;
+                if read == 0 {
+                    if pending.is_empty() {
+                        return Ok(None);
+                    }
+                    // Input ended in the middle of a character
+                    let n = pending.len();
+                    if mode == DecodeMode::Strict {
+                        pending.clear();
+                        return Err(format!("truncated {} byte sequence at end of input", encoding.name()));
+                    }
+                    (REPLACEMENT_CHARACTER, n)
+                } else {
+                    pending.push(byte[0]);
+                    continue;
+                }
+            }
+        };
+
+        if !peek {
+            pending.drain(..consumed);
+        }
+        return Ok(Some(ch));
+    }
+}
+
+/// Consumes a byte order mark at the start of `reader`.
+///
+/// For UTF-16 the mark also selects the byte order, so the encoding that
+/// should be used for the rest of the input is returned.
+pub fn consume_byte_order_mark<R: BufRead>(reader: &mut R, encoding: TextEncoding) -> std::io::Result<TextEncoding> {
+    let candidates: &[TextEncoding] = match encoding {
+        TextEncoding::Utf8 => &[TextEncoding::Utf8],
+        TextEncoding::Utf16Le | TextEncoding::Utf16Be => &[TextEncoding::Utf16Le, TextEncoding::Utf16Be],
+        TextEncoding::Latin1 => &[],
+    };
+
+    let buffered = reader.fill_buf()?;
+    for candidate in candidates {
+        let mark = candidate.byte_order_mark();
+        if buffered.starts_with(mark) {
+            let len = mark.len();
+            reader.consume(len);
+            return Ok(*candidate);
+        }
+    }
+    Ok(encoding)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    fn decode_all(bytes: &[u8], encoding: TextEncoding, mode: DecodeMode) -> std::result::Result<String, String> {
+        let mut reader = Cursor::new(bytes.to_vec());
+        let mut pending = Vec::new();
+        let mut text = String::new();
+        while let Some(ch) = read_char(&mut reader, &mut pending, encoding, mode, false)? {
+            text.push(ch);
+        }
+        Ok(text)
+    }
+
+    #[test]
+    fn test_latin1_round_trip() {
+        let text = "café naïve ÿ";
+        let bytes = TextEncoding::Latin1.encode_str(text).unwrap();
+        assert_eq!(bytes.len(), text.chars().count());
+        assert_eq!(decode_all(&bytes, TextEncoding::Latin1, DecodeMode::Strict).unwrap(), text);
+
+        assert!(TextEncoding::Latin1.encode_str("€").is_err());
+    }
+
+    #[test]
+    fn test_utf8_strict_and_lenient() {
+        let bytes = [b'a', 0xFF, b'b'];
+        assert!(decode_all(&bytes, TextEncoding::Utf8, DecodeMode::Strict).is_err());
+        assert_eq!(decode_all(&bytes, TextEncoding::Utf8, DecodeMode::Lenient).unwrap(), "a\u{FFFD}b");
+
+        // Truncated multibyte sequence at end of input
+        let bytes = [b'a', 0xE2, 0x82];
+        assert!(decode_all(&bytes, TextEncoding::Utf8, DecodeMode::Strict).is_err());
+        assert_eq!(decode_all(&bytes, TextEncoding::Utf8, DecodeMode::Lenient).unwrap(), "a\u{FFFD}");
+    }
+
+    #[test]
+    fn test_utf16_with_byte_order_mark() {
+        let text = "λx 😀";
+        let mut bytes = TextEncoding::Utf16Be.byte_order_mark().to_vec();
+        bytes.extend(TextEncoding::Utf16Be.encode_str(text).unwrap());
+
+        // A big-endian mark overrides the requested little-endian order
+        let mut reader = Cursor::new(bytes);
+        let encoding = consume_byte_order_mark(&mut reader, TextEncoding::Utf16Le).unwrap();
+        assert_eq!(encoding, TextEncoding::Utf16Be);
+
+        let mut pending = Vec::new();
+        let mut decoded = String::new();
+        while let Some(ch) = read_char(&mut reader, &mut pending, encoding, DecodeMode::Strict, false).unwrap() {
+            decoded.push(ch);
+        }
+        assert_eq!(decoded, text);
+
+        // Unpaired surrogate
+        assert!(decode_all(&[0x00, 0xDC], TextEncoding::Utf16Le, DecodeMode::Strict).is_err());
+    }
+
+    #[test]
+    fn test_peek_does_not_consume() {
+        let bytes = "é!".as_bytes().to_vec();
+        let mut reader = Cursor::new(bytes);
+        let mut pending = Vec::new();
+
+        let peeked = read_char(&mut reader, &mut pending, TextEncoding::Utf8, DecodeMode::Strict, true).unwrap();
+        let read = read_char(&mut reader, &mut pending, TextEncoding::Utf8, DecodeMode::Strict, false).unwrap();
+        assert_eq!(peeked, Some('é'));
+        assert_eq!(read, Some('é'));
+        assert_eq!(read_char(&mut reader, &mut pending, TextEncoding::Utf8, DecodeMode::Strict, false).unwrap(), Some('!'));
+    }
+}
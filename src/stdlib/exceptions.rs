@@ -3,7 +3,8 @@
 //! This module implements R7RS-small compliant exception handling including:
 //! - Error objects and predicates (error?, error-object?, read-error?, file-error?)
 //! - Exception raising (raise, raise-continuable, error)
-//! - Error object accessors (error-object-message, error-object-irritants)
+//! - Error object accessors (error-object-message, error-object-irritants,
+//!   error-object-location)
 //! - Complete error type hierarchy (general, read, file errors)
 //! - Exception handling infrastructure
 //! - Integration with the guard syntax form
@@ -21,6 +22,21 @@
 //! - `read-error?` - Tests if error is a read error
 //! - `file-error?` - Tests if error is a file error
 //!
+//! Conditions raised by `error`, `raise` and `assertion-violation` record the
+//! source span of the raising call; `error-object-location` exposes it.
+//! Assertion violations, also raised by a failing `assert`, are recognised by
+//! `assertion-violation?`.
+//!
+//! ## Condition Types
+//!
+//! `make-condition-type` defines a named condition type with fields under a
+//! supertype, and `make-condition` builds instances to pass to `raise`.
+//! `condition/type?` tests whether a condition belongs to a type or one of
+//! its subtypes, so `guard` clauses can dispatch on the type. Built-in
+//! errors are conditions of the predefined types `&error`, `&read-error`,
+//! `&file-error` and `&assertion-violation`, all below `&condition`; the
+//! last three are subtypes of `&error`.
+//!
 //! ## Error Types
 //!
 //! The module supports three types of errors as per R7RS:
@@ -44,10 +60,11 @@
 //! let _ = raise_file_error("I/O error".to_string(), vec![]);
 //! ```
 
-use crate::diagnostics::{Error as DiagnosticError, Result};
+use crate::diagnostics::{Error as DiagnosticError, Result, Span};
 use crate::eval::value::{Value, PrimitiveProcedure, PrimitiveImpl, ThreadSafeEnvironment};
 use crate::effects::Effect; 
-use std::sync::Arc;
+use crate::utils::symbol_name;
+use std::sync::{Arc, LazyLock};
 use std::fmt;
 
 /// R7RS Exception object representation
@@ -63,6 +80,9 @@ pub struct ExceptionObject {
     pub irritants: Vec<Value>,
     /// Whether this exception can be continued from
     pub continuable: bool,
+    /// Whether `value` is an object passed to `raise`, which handlers must
+    /// receive unchanged
+    pub raised: bool,
 }
 
 /// R7RS error types for proper categorization
@@ -74,6 +94,8 @@ pub enum ErrorType {
     ReadError,
     /// File error (I/O and filesystem errors) 
     FileError,
+    /// Assertion violation (created by `assertion-violation`)
+    AssertionViolation,
 }
 
 /// R7RS Error object (subtype of exception object)
@@ -85,10 +107,12 @@ pub struct ErrorObject {
     pub irritants: Vec<Value>,
     /// Type of error (general, read, file)
     pub error_type: ErrorType,
+    /// Source span of the call that raised the error, if known
+    pub location: Option<Span>,
 }
 
 impl ExceptionObject {
-    /// Creates a new general exception
+    /// Creates an exception carrying an object passed to `raise`
     pub fn new(exception_type: String, value: Value, continuable: bool) -> Self {
         Self {
             exception_type,
@@ -96,9 +120,26 @@ impl ExceptionObject {
             message: None,
             irritants: Vec::new(),
             continuable,
+            raised: true,
         }
     }
     
+    /// Formats the message followed by the irritants in `write` form, e.g.
+    /// `bad value "foo" 42`. Exceptions without a message fall back to
+    /// their display form.
+    pub fn report(&self) -> String {
+        let Some(message) = &self.message else {
+            return self.to_string();
+        };
+        
+        let mut report = message.clone();
+        for irritant in &self.irritants {
+            report.push(' ');
+            report.push_str(&irritant.to_string());
+        }
+        report
+    }
+    
     /// Creates a new error exception
     pub fn error(message: String, irritants: Vec<Value>) -> Self {
         Self {
@@ -110,6 +151,7 @@ impl ExceptionObject {
             message: Some(message),
             irritants,
             continuable: false,
+            raised: false,
         }
     }
     
@@ -124,6 +166,7 @@ impl ExceptionObject {
             message: Some(message),
             irritants,
             continuable: false,
+            raised: false,
         }
     }
     
@@ -138,9 +181,38 @@ impl ExceptionObject {
             message: Some(message),
             irritants,
             continuable: false,
+            raised: false,
+        }
+    }
+    
+    /// Creates an assertion violation exception, prefixing the message with `who`
+    pub fn assertion_violation(who: Option<String>, message: String, irritants: Vec<Value>) -> Self {
+        let error = ErrorObject::assertion_violation(who, message, irritants);
+        Self {
+            exception_type: "assertion-violation".to_string(),
+            message: Some(error.message.clone()),
+            irritants: error.irritants.clone(),
+            value: Value::ErrorObject(Arc::new(error)),
+            continuable: false,
+            raised: false,
         }
     }
     
+    /// Records `span` as the location of an error object payload that does
+    /// not have one yet. Objects passed to `raise` are left untouched so
+    /// handlers receive the very object that was raised.
+    pub fn with_location(mut self, span: Span) -> Self {
+        if self.raised {
+            return self;
+        }
+        if let Value::ErrorObject(error) = &self.value
+            && error.location.is_none()
+        {
+            self.value = Value::ErrorObject(Arc::new(error.as_ref().clone().with_location(span)));
+        }
+        self
+    }
+    
     /// Checks if this is an error object
     pub fn is_error(&self) -> bool {
         matches!(self.value, Value::ErrorObject(_))
@@ -159,6 +231,7 @@ impl ErrorObject {
             message, 
             irritants,
             error_type: ErrorType::General,
+            location: None,
         }
     }
     
@@ -168,6 +241,7 @@ impl ErrorObject {
             message,
             irritants,
             error_type: ErrorType::ReadError,
+            location: None,
         }
     }
     
@@ -177,9 +251,30 @@ impl ErrorObject {
             message,
             irritants,
             error_type: ErrorType::FileError,
+            location: None,
+        }
+    }
+    
+    /// Creates a new assertion violation, prefixing the message with `who`
+    pub fn assertion_violation(who: Option<String>, message: String, irritants: Vec<Value>) -> Self {
+        let message = match who {
+            Some(who) => format!("{who}: {message}"),
+            None => message,
+        };
+        Self {
+            message,
+            irritants,
+            error_type: ErrorType::AssertionViolation,
+            location: None,
         }
     }
     
+    /// Records the source span of the call that raised this error
+    pub fn with_location(mut self, span: Span) -> Self {
+        self.location = Some(span);
+        self
+    }
+    
     /// Checks if this is a read error
     pub fn is_read_error(&self) -> bool {
         matches!(self.error_type, ErrorType::ReadError)
@@ -189,12 +284,101 @@ impl ErrorObject {
     pub fn is_file_error(&self) -> bool {
         matches!(self.error_type, ErrorType::FileError)
     }
+    
+    /// Checks if this is an assertion violation
+    pub fn is_assertion_violation(&self) -> bool {
+        matches!(self.error_type, ErrorType::AssertionViolation)
+    }
+    
+    /// Gets the predefined condition type this error belongs to
+    pub fn condition_type(&self) -> Arc<ConditionType> {
+        match self.error_type {
+            ErrorType::General => ERROR_TYPE.clone(),
+            ErrorType::ReadError => READ_ERROR_TYPE.clone(),
+            ErrorType::FileError => FILE_ERROR_TYPE.clone(),
+            ErrorType::AssertionViolation => ASSERTION_VIOLATION_TYPE.clone(),
+        }
+    }
+}
+
+// ============= CONDITION TYPES =============
+
+/// A condition type, made by `make-condition-type` or predefined for the
+/// built-in errors
+#[derive(Debug)]
+pub struct ConditionType {
+    /// Name of the type, e.g. `&error`
+    pub name: String,
+    /// The type this one specializes; only `&condition` has none
+    pub supertype: Option<Arc<ConditionType>>,
+    /// Field names, inherited ones first
+    pub field_names: Vec<String>,
 }
 
+impl ConditionType {
+    /// Creates a subtype of `supertype` with `fields` after the inherited ones
+    pub fn derive(supertype: &Arc<ConditionType>, name: impl Into<String>, fields: Vec<String>) -> Arc<Self> {
+        let mut field_names = supertype.field_names.clone();
+        field_names.extend(fields);
+        Arc::new(Self {
+            name: name.into(),
+            supertype: Some(supertype.clone()),
+            field_names,
+        })
+    }
+    
+    /// Checks if this type is `other` or one of its subtypes
+    pub fn is_a(&self, other: &ConditionType) -> bool {
+        let mut current = Some(self);
+        while let Some(condition_type) = current {
+            if std::ptr::eq(condition_type, other) {
+                return true;
+            }
+            current = condition_type.supertype.as_deref();
+        }
+        false
+    }
+}
+
+/// A condition made by `make-condition`
+#[derive(Debug)]
+pub struct Condition {
+    /// The type of the condition
+    pub condition_type: Arc<ConditionType>,
+    /// Field values, in the order of the type's field names
+    pub values: Vec<Value>,
+}
+
+/// `&condition`, the root of the condition type hierarchy
+pub static CONDITION_TYPE: LazyLock<Arc<ConditionType>> = LazyLock::new(|| {
+    Arc::new(ConditionType {
+        name: "&condition".to_string(),
+        supertype: None,
+        field_names: Vec::new(),
+    })
+});
+
+/// `&error`, the type of conditions raised by `error`
+pub static ERROR_TYPE: LazyLock<Arc<ConditionType>> = LazyLock::new(|| {
+    ConditionType::derive(&CONDITION_TYPE, "&error", vec!["message".to_string(), "irritants".to_string()])
+});
+
+/// `&read-error`, the type of malformed input errors
+pub static READ_ERROR_TYPE: LazyLock<Arc<ConditionType>> =
+    LazyLock::new(|| ConditionType::derive(&ERROR_TYPE, "&read-error", Vec::new()));
+
+/// `&file-error`, the type of failed file operations
+pub static FILE_ERROR_TYPE: LazyLock<Arc<ConditionType>> =
+    LazyLock::new(|| ConditionType::derive(&ERROR_TYPE, "&file-error", Vec::new()));
+
+/// `&assertion-violation`, the type of conditions raised by `assertion-violation`
+pub static ASSERTION_VIOLATION_TYPE: LazyLock<Arc<ConditionType>> =
+    LazyLock::new(|| ConditionType::derive(&ERROR_TYPE, "&assertion-violation", Vec::new()));
+
 impl fmt::Display for ExceptionObject {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        if let Some(msg) = &self.message {
-            write!(f, "{}: {}", self.exception_type, msg)
+        if self.message.is_some() {
+            write!(f, "{}: {}", self.exception_type, self.report())
         } else {
             write!(f, "{}: {}", self.exception_type, self.value)
         }
@@ -220,6 +404,9 @@ pub fn create_exception_bindings(env: &Arc<ThreadSafeEnvironment>) {
     
     // Exception handling procedures
     bind_exception_handling(env);
+    
+    // Condition types
+    bind_condition_types(env);
 }
 
 /// Binds exception raising procedures
@@ -250,6 +437,15 @@ fn bind_exception_raising(env: &Arc<ThreadSafeEnvironment>) {
         implementation: PrimitiveImpl::RustFn(primitive_error),
         effects: vec![Effect::Error],
     })));
+    
+    // assertion-violation - raises an assertion violation (R6RS)
+    env.define("assertion-violation".to_string(), Value::Primitive(Arc::new(PrimitiveProcedure {
+        name: "assertion-violation".to_string(),
+        arity_min: 2,
+        arity_max: None,
+        implementation: PrimitiveImpl::RustFn(primitive_assertion_violation),
+        effects: vec![Effect::Error],
+    })));
 }
 
 /// Binds exception predicates
@@ -289,6 +485,15 @@ fn bind_exception_predicates(env: &Arc<ThreadSafeEnvironment>) {
         implementation: PrimitiveImpl::RustFn(primitive_file_error_p),
         effects: vec![Effect::Pure],
     })));
+    
+    // assertion-violation? - tests if object is an assertion violation
+    env.define("assertion-violation?".to_string(), Value::Primitive(Arc::new(PrimitiveProcedure {
+        name: "assertion-violation?".to_string(),
+        arity_min: 1,
+        arity_max: Some(1),
+        implementation: PrimitiveImpl::RustFn(primitive_assertion_violation_p),
+        effects: vec![Effect::Pure],
+    })));
 }
 
 /// Binds error object accessors
@@ -310,6 +515,15 @@ fn bind_error_object_accessors(env: &Arc<ThreadSafeEnvironment>) {
         implementation: PrimitiveImpl::RustFn(primitive_error_object_irritants),
         effects: vec![Effect::Pure],
     })));
+    
+    // error-object-location - gets the source span of the raising call
+    env.define("error-object-location".to_string(), Value::Primitive(Arc::new(PrimitiveProcedure {
+        name: "error-object-location".to_string(),
+        arity_min: 1,
+        arity_max: Some(1),
+        implementation: PrimitiveImpl::RustFn(primitive_error_object_location),
+        effects: vec![Effect::Pure],
+    })));
 }
 
 /// Binds exception handling procedures
@@ -319,11 +533,88 @@ fn bind_exception_handling(env: &Arc<ThreadSafeEnvironment>) {
         name: "with-exception-handler".to_string(),
         arity_min: 2,
         arity_max: Some(2),
-        implementation: PrimitiveImpl::RustFn(primitive_with_exception_handler),
+        implementation: PrimitiveImpl::EvaluatorIntegrated(evaluator_with_exception_handler),
         effects: vec![Effect::Error],
     })));
 }
 
+/// Binds the condition type system and the predefined condition types
+fn bind_condition_types(env: &Arc<ThreadSafeEnvironment>) {
+    for condition_type in [
+        &CONDITION_TYPE,
+        &ERROR_TYPE,
+        &READ_ERROR_TYPE,
+        &FILE_ERROR_TYPE,
+        &ASSERTION_VIOLATION_TYPE,
+    ] {
+        let condition_type: Arc<ConditionType> = Arc::clone(condition_type);
+        env.define(condition_type.name.clone(), Value::Opaque(condition_type));
+    }
+    
+    // make-condition-type - defines a condition type under a supertype
+    env.define("make-condition-type".to_string(), Value::Primitive(Arc::new(PrimitiveProcedure {
+        name: "make-condition-type".to_string(),
+        arity_min: 3,
+        arity_max: Some(3),
+        implementation: PrimitiveImpl::RustFn(primitive_make_condition_type),
+        effects: vec![Effect::Pure],
+    })));
+    
+    // make-condition - builds a condition from field/value pairs
+    env.define("make-condition".to_string(), Value::Primitive(Arc::new(PrimitiveProcedure {
+        name: "make-condition".to_string(),
+        arity_min: 1,
+        arity_max: None,
+        implementation: PrimitiveImpl::RustFn(primitive_make_condition),
+        effects: vec![Effect::Pure],
+    })));
+    
+    // condition? - tests for conditions, including built-in errors
+    env.define("condition?".to_string(), Value::Primitive(Arc::new(PrimitiveProcedure {
+        name: "condition?".to_string(),
+        arity_min: 1,
+        arity_max: Some(1),
+        implementation: PrimitiveImpl::RustFn(primitive_condition_p),
+        effects: vec![Effect::Pure],
+    })));
+    
+    // condition-type? - tests for condition types
+    env.define("condition-type?".to_string(), Value::Primitive(Arc::new(PrimitiveProcedure {
+        name: "condition-type?".to_string(),
+        arity_min: 1,
+        arity_max: Some(1),
+        implementation: PrimitiveImpl::RustFn(primitive_condition_type_p),
+        effects: vec![Effect::Pure],
+    })));
+    
+    // condition/type - gets the type of a condition
+    env.define("condition/type".to_string(), Value::Primitive(Arc::new(PrimitiveProcedure {
+        name: "condition/type".to_string(),
+        arity_min: 1,
+        arity_max: Some(1),
+        implementation: PrimitiveImpl::RustFn(primitive_condition_type),
+        effects: vec![Effect::Pure],
+    })));
+    
+    // condition/type? - tests whether a condition belongs to a type or a subtype
+    env.define("condition/type?".to_string(), Value::Primitive(Arc::new(PrimitiveProcedure {
+        name: "condition/type?".to_string(),
+        arity_min: 2,
+        arity_max: Some(2),
+        implementation: PrimitiveImpl::RustFn(primitive_condition_is_type_p),
+        effects: vec![Effect::Pure],
+    })));
+    
+    // condition-ref - gets a field of a condition
+    env.define("condition-ref".to_string(), Value::Primitive(Arc::new(PrimitiveProcedure {
+        name: "condition-ref".to_string(),
+        arity_min: 2,
+        arity_max: Some(2),
+        implementation: PrimitiveImpl::RustFn(primitive_condition_ref),
+        effects: vec![Effect::Pure],
+    })));
+}
+
 // ============= PUBLIC ERROR CREATION HELPERS =============
 
 /// Creates a general error object and returns it as a Value
@@ -430,6 +721,41 @@ fn primitive_error(args: &[Value]) -> Result<Value> {
     Err(Box::new(DiagnosticError::exception(exception)))
 }
 
+/// assertion-violation procedure - raises an assertion violation
+///
+/// `(assertion-violation who message irritant ...)` where `who` is a symbol,
+/// a string or `#f`.
+fn primitive_assertion_violation(args: &[Value]) -> Result<Value> {
+    if args.len() < 2 {
+        return Err(Box::new(DiagnosticError::runtime_error(
+            format!("assertion-violation expects at least 2 arguments, got {}", args.len()),
+            None,
+        )));
+    }
+    
+    let who = match &args[0] {
+        Value::Literal(crate::ast::Literal::Boolean(false)) => None,
+        Value::Literal(crate::ast::Literal::String(s)) => Some(s.clone()),
+        Value::Symbol(id) => crate::utils::symbol_name(*id),
+        _ => return Err(Box::new(DiagnosticError::runtime_error(
+            "assertion-violation: who must be a symbol, a string or #f".to_string(),
+            None,
+        ))),
+    };
+    
+    let message = match &args[1] {
+        Value::Literal(crate::ast::Literal::String(s)) => s.clone(),
+        _ => return Err(Box::new(DiagnosticError::runtime_error(
+            "assertion-violation message must be a string".to_string(),
+            None,
+        ))),
+    };
+    
+    let irritants = args[2..].to_vec();
+    let exception = ExceptionObject::assertion_violation(who, message, irritants);
+    Err(Box::new(DiagnosticError::exception(exception)))
+}
+
 // ============= EXCEPTION PREDICATE IMPLEMENTATIONS =============
 
 /// error? and error-object? predicate
@@ -479,6 +805,23 @@ fn primitive_file_error_p(args: &[Value]) -> Result<Value> {
     Ok(Value::boolean(is_file_error))
 }
 
+/// assertion-violation? predicate
+fn primitive_assertion_violation_p(args: &[Value]) -> Result<Value> {
+    if args.len() != 1 {
+        return Err(Box::new(DiagnosticError::runtime_error(
+            format!("assertion-violation? expects 1 argument, got {}", args.len()),
+            None,
+        )));
+    }
+    
+    let is_assertion_violation = match &args[0] {
+        Value::ErrorObject(error) => error.is_assertion_violation(),
+        _ => false,
+    };
+    
+    Ok(Value::boolean(is_assertion_violation))
+}
+
 // ============= ERROR OBJECT ACCESSOR IMPLEMENTATIONS =============
 
 /// error-object-message accessor
@@ -521,16 +864,313 @@ fn primitive_error_object_irritants(args: &[Value]) -> Result<Value> {
     }
 }
 
+/// error-object-location accessor
+///
+/// Returns the byte offset and length of the raising call in its source as
+/// a two-element list, or `#f` when the location is unknown.
+fn primitive_error_object_location(args: &[Value]) -> Result<Value> {
+    if args.len() != 1 {
+        return Err(Box::new(DiagnosticError::runtime_error(
+            format!("error-object-location expects 1 argument, got {}", args.len()),
+            None,
+        )));
+    }
+    
+    match &args[0] {
+        Value::ErrorObject(error) => Ok(match error.location {
+            Some(span) => Value::list(vec![
+                Value::integer(span.start as i64),
+                Value::integer(span.len as i64),
+            ]),
+            None => Value::boolean(false),
+        }),
+        _ => Err(Box::new(DiagnosticError::runtime_error(
+            "error-object-location requires an error object".to_string(),
+            None,
+        ))),
+    }
+}
+
+// ============= CONDITION TYPE IMPLEMENTATIONS =============
+
+/// Gets the condition type a value names, if it is one
+fn as_condition_type(value: &Value) -> Option<Arc<ConditionType>> {
+    match value {
+        Value::Opaque(inner) => inner.clone().downcast::<ConditionType>().ok(),
+        _ => None,
+    }
+}
+
+/// Gets the type of a condition, treating built-in errors as conditions of
+/// the predefined types
+fn condition_type_of(value: &Value) -> Option<Arc<ConditionType>> {
+    match value {
+        Value::ErrorObject(error) => Some(error.condition_type()),
+        Value::Opaque(inner) => inner.downcast_ref::<Condition>().map(|c| c.condition_type.clone()),
+        _ => None,
+    }
+}
+
+/// Gets the name of a field argument
+fn field_name_argument(value: &Value, procedure: &str) -> Result<String> {
+    value.as_symbol().and_then(symbol_name).ok_or_else(|| {
+        Box::new(DiagnosticError::runtime_error(
+            format!("{procedure}: field names must be symbols, got {value}"),
+            None,
+        ))
+    })
+}
+
+/// Gets the condition type argument of a procedure
+fn condition_type_argument(value: &Value, procedure: &str) -> Result<Arc<ConditionType>> {
+    as_condition_type(value).ok_or_else(|| {
+        Box::new(DiagnosticError::runtime_error(
+            format!("{procedure}: expected a condition type, got {value}"),
+            None,
+        ))
+    })
+}
+
+/// make-condition-type procedure
+///
+/// `(make-condition-type name supertype field-names)` defines a subtype of
+/// `supertype`, or of `&condition` when it is `#f`, that adds `field-names`
+/// to the inherited fields.
+fn primitive_make_condition_type(args: &[Value]) -> Result<Value> {
+    if args.len() != 3 {
+        return Err(Box::new(DiagnosticError::runtime_error(
+            format!("make-condition-type expects 3 arguments, got {}", args.len()),
+            None,
+        )));
+    }
+    
+    let name = args[0].as_symbol().and_then(symbol_name).ok_or_else(|| {
+        Box::new(DiagnosticError::runtime_error(
+            "make-condition-type: name must be a symbol".to_string(),
+            None,
+        ))
+    })?;
+    
+    let supertype = if args[1].is_truthy() {
+        condition_type_argument(&args[1], "make-condition-type")?
+    } else {
+        CONDITION_TYPE.clone()
+    };
+    
+    let field_list = args[2].as_list().ok_or_else(|| {
+        Box::new(DiagnosticError::runtime_error(
+            "make-condition-type: field names must be a list".to_string(),
+            None,
+        ))
+    })?;
+    
+    let mut fields: Vec<String> = Vec::with_capacity(field_list.len());
+    for field in &field_list {
+        let field = field_name_argument(field, "make-condition-type")?;
+        if supertype.field_names.contains(&field) || fields.contains(&field) {
+            return Err(Box::new(DiagnosticError::runtime_error(
+                format!("make-condition-type: duplicate field {field} in {name}"),
+                None,
+            )));
+        }
+        fields.push(field);
+    }
+    
+    Ok(Value::Opaque(ConditionType::derive(&supertype, name, fields)))
+}
+
+/// make-condition procedure
+///
+/// `(make-condition type field value ...)` builds a condition of `type`;
+/// every field of the type must be given exactly once.
+fn primitive_make_condition(args: &[Value]) -> Result<Value> {
+    if args.is_empty() {
+        return Err(Box::new(DiagnosticError::runtime_error(
+            "make-condition expects at least 1 argument, got 0".to_string(),
+            None,
+        )));
+    }
+    
+    let condition_type = condition_type_argument(&args[0], "make-condition")?;
+    let pairs = &args[1..];
+    if !pairs.len().is_multiple_of(2) {
+        return Err(Box::new(DiagnosticError::runtime_error(
+            "make-condition: fields and values must come in pairs".to_string(),
+            None,
+        )));
+    }
+    
+    let mut values: Vec<Option<Value>> = vec![None; condition_type.field_names.len()];
+    for pair in pairs.chunks(2) {
+        let field = field_name_argument(&pair[0], "make-condition")?;
+        let index = condition_type.field_names.iter().position(|name| *name == field).ok_or_else(|| {
+            Box::new(DiagnosticError::runtime_error(
+                format!("make-condition: {} has no field {field}", condition_type.name),
+                None,
+            ))
+        })?;
+        if values[index].replace(pair[1].clone()).is_some() {
+            return Err(Box::new(DiagnosticError::runtime_error(
+                format!("make-condition: field {field} given twice"),
+                None,
+            )));
+        }
+    }
+    
+    let values = values
+        .into_iter()
+        .zip(&condition_type.field_names)
+        .map(|(value, field)| {
+            value.ok_or_else(|| {
+                Box::new(DiagnosticError::runtime_error(
+                    format!("make-condition: missing field {field} of {}", condition_type.name),
+                    None,
+                ))
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+    
+    Ok(Value::opaque(Condition { condition_type, values }))
+}
+
+/// condition? predicate
+fn primitive_condition_p(args: &[Value]) -> Result<Value> {
+    if args.len() != 1 {
+        return Err(Box::new(DiagnosticError::runtime_error(
+            format!("condition? expects 1 argument, got {}", args.len()),
+            None,
+        )));
+    }
+    
+    Ok(Value::boolean(condition_type_of(&args[0]).is_some()))
+}
+
+/// condition-type? predicate
+fn primitive_condition_type_p(args: &[Value]) -> Result<Value> {
+    if args.len() != 1 {
+        return Err(Box::new(DiagnosticError::runtime_error(
+            format!("condition-type? expects 1 argument, got {}", args.len()),
+            None,
+        )));
+    }
+    
+    Ok(Value::boolean(as_condition_type(&args[0]).is_some()))
+}
+
+/// condition/type accessor
+fn primitive_condition_type(args: &[Value]) -> Result<Value> {
+    if args.len() != 1 {
+        return Err(Box::new(DiagnosticError::runtime_error(
+            format!("condition/type expects 1 argument, got {}", args.len()),
+            None,
+        )));
+    }
+    
+    match condition_type_of(&args[0]) {
+        Some(condition_type) => Ok(Value::Opaque(condition_type)),
+        None => Err(Box::new(DiagnosticError::runtime_error(
+            format!("condition/type: expected a condition, got {}", args[0]),
+            None,
+        ))),
+    }
+}
+
+/// condition/type? predicate
+///
+/// `(condition/type? obj type)` is true when `obj` is a condition whose
+/// type is `type` or one of its subtypes.
+fn primitive_condition_is_type_p(args: &[Value]) -> Result<Value> {
+    if args.len() != 2 {
+        return Err(Box::new(DiagnosticError::runtime_error(
+            format!("condition/type? expects 2 arguments, got {}", args.len()),
+            None,
+        )));
+    }
+    
+    let condition_type = condition_type_argument(&args[1], "condition/type?")?;
+    Ok(Value::boolean(
+        condition_type_of(&args[0]).is_some_and(|own| own.is_a(&condition_type)),
+    ))
+}
+
+/// condition-ref accessor
+fn primitive_condition_ref(args: &[Value]) -> Result<Value> {
+    if args.len() != 2 {
+        return Err(Box::new(DiagnosticError::runtime_error(
+            format!("condition-ref expects 2 arguments, got {}", args.len()),
+            None,
+        )));
+    }
+    
+    let field = field_name_argument(&args[1], "condition-ref")?;
+    let no_field = |type_name: &str| {
+        Box::new(DiagnosticError::runtime_error(
+            format!("condition-ref: {type_name} has no field {field}"),
+            None,
+        ))
+    };
+    
+    match &args[0] {
+        Value::ErrorObject(error) => match field.as_str() {
+            "message" => Ok(Value::string(error.message.clone())),
+            "irritants" => Ok(Value::list(error.irritants.clone())),
+            _ => Err(no_field(&error.condition_type().name)),
+        },
+        Value::Opaque(inner) => match inner.downcast_ref::<Condition>() {
+            Some(condition) => condition
+                .condition_type
+                .field_names
+                .iter()
+                .position(|name| *name == field)
+                .map(|index| condition.values[index].clone())
+                .ok_or_else(|| no_field(&condition.condition_type.name)),
+            None => Err(Box::new(DiagnosticError::runtime_error(
+                format!("condition-ref: expected a condition, got {}", args[0]),
+                None,
+            ))),
+        },
+        _ => Err(Box::new(DiagnosticError::runtime_error(
+            format!("condition-ref: expected a condition, got {}", args[0]),
+            None,
+        ))),
+    }
+}
+
 // ============= EXCEPTION HANDLING IMPLEMENTATIONS =============
 
 /// with-exception-handler procedure
-fn primitive_with_exception_handler(_args: &[Value]) -> Result<Value> {
-    // This requires deeper integration with the evaluator to properly
-    // set up exception handling contexts
-    Err(Box::new(DiagnosticError::runtime_error(
-        "with-exception-handler requires evaluator integration (implemented via guard syntax)".to_string(),
-        None,
-    )))
+///
+/// Installs `handler` for the dynamic extent of the call to `thunk`. Raises
+/// from leaf primitives are signalled to it by the evaluator; see
+/// [`Evaluator::signal_exception`](crate::eval::Evaluator::signal_exception).
+/// The handler is taken off the handler stack while it runs, so a condition
+/// it raises, new or the original, goes to the handler installed before it
+/// rather than back into itself.
+pub fn evaluator_with_exception_handler(
+    evaluator: &mut crate::eval::Evaluator,
+    args: &[Value],
+) -> Result<Value> {
+    if args.len() != 2 {
+        return Err(Box::new(DiagnosticError::runtime_error(
+            format!("with-exception-handler expects 2 arguments, got {}", args.len()),
+            None,
+        )));
+    }
+
+    if !args[0].is_procedure() {
+        return Err(Box::new(DiagnosticError::runtime_error(
+            "with-exception-handler handler must be a procedure".to_string(),
+            None,
+        )));
+    }
+    if !args[1].is_procedure() {
+        return Err(Box::new(DiagnosticError::runtime_error(
+            "with-exception-handler thunk must be a procedure".to_string(),
+            None,
+        )));
+    }
+
+    evaluator.call_with_exception_handler(args[0].clone(), args[1].clone())
 }
 
 #[cfg(test)]
@@ -879,4 +1519,79 @@ mod tests {
         let result = primitive_error_object_irritants(&[file_error]).unwrap();
         assert_eq!(result, Value::list(irritants.clone()));
     }
-}
\ No newline at end of file
+    
+    #[test]
+    fn test_error_object_location() {
+        let error = Value::ErrorObject(Arc::new(ErrorObject::new("boom".to_string(), vec![])));
+        assert_eq!(primitive_error_object_location(&[error]).unwrap(), Value::boolean(false));
+        
+        let located = ErrorObject::new("boom".to_string(), vec![]).with_location(Span::new(12, 8));
+        let result = primitive_error_object_location(&[Value::ErrorObject(Arc::new(located))]).unwrap();
+        assert_eq!(result, Value::list(vec![Value::integer(12), Value::integer(8)]));
+    }
+    
+    #[test]
+    fn test_exception_location_is_not_overwritten() {
+        let located = ErrorObject::new("boom".to_string(), vec![]).with_location(Span::new(3, 4));
+        let exception = ExceptionObject::new("error".to_string(), Value::ErrorObject(Arc::new(located)), false)
+            .with_location(Span::new(40, 10));
+        
+        match &exception.value {
+            Value::ErrorObject(error) => assert_eq!(error.location, Some(Span::new(3, 4))),
+            _ => panic!("Expected error object"),
+        }
+    }
+    
+    #[test]
+    fn test_assertion_violation_message() {
+        let who = Value::Symbol(crate::utils::intern_symbol("vector-ref"));
+        let err = primitive_assertion_violation(&[who, Value::string("index out of range"), Value::integer(9)])
+            .unwrap_err();
+        
+        match *err {
+            DiagnosticError::Exception { exception, .. } => {
+                assert_eq!(exception.exception_type, "assertion-violation");
+                assert_eq!(exception.message.as_deref(), Some("vector-ref: index out of range"));
+                assert_eq!(exception.irritants, vec![Value::integer(9)]);
+            }
+            _ => panic!("Expected exception"),
+        }
+    }
+    
+    #[test]
+    fn test_assertion_violation_predicate() {
+        let violation = Value::ErrorObject(Arc::new(ErrorObject::assertion_violation(
+            Some("assert".to_string()),
+            "assertion failed".to_string(),
+            vec![],
+        )));
+        let general = create_error_object("message".to_string(), vec![]);
+        
+        assert_eq!(primitive_assertion_violation_p(&[violation]).unwrap(), Value::boolean(true));
+        assert_eq!(primitive_assertion_violation_p(&[general]).unwrap(), Value::boolean(false));
+        assert_eq!(primitive_assertion_violation_p(&[Value::integer(1)]).unwrap(), Value::boolean(false));
+    }
+    
+    #[test]
+    fn test_condition_subtyping() {
+        let custom = ConditionType::derive(&FILE_ERROR_TYPE, "&custom", vec!["path".to_string()]);
+        
+        assert!(custom.is_a(&FILE_ERROR_TYPE));
+        assert!(custom.is_a(&ERROR_TYPE));
+        assert!(custom.is_a(&CONDITION_TYPE));
+        assert!(!custom.is_a(&READ_ERROR_TYPE));
+        assert!(!ERROR_TYPE.is_a(&custom));
+        assert_eq!(custom.field_names, vec!["message", "irritants", "path"]);
+    }
+    
+    #[test]
+    fn test_condition_ref_missing_field() {
+        let condition_type = Value::Opaque(ConditionType::derive(&CONDITION_TYPE, "&custom", vec!["code".to_string()]));
+        let code = Value::symbol_from_str("code");
+        let condition = primitive_make_condition(&[condition_type.clone(), code.clone(), Value::integer(7)]).unwrap();
+        
+        assert_eq!(primitive_condition_ref(&[condition.clone(), code]).unwrap(), Value::integer(7));
+        assert!(primitive_condition_ref(&[condition, Value::symbol_from_str("path")]).is_err());
+        assert!(primitive_make_condition(&[condition_type]).is_err());
+    }
+}
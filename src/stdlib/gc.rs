@@ -0,0 +1,223 @@
+//! Scheme-level interface to the garbage collector.
+//!
+//! These procedures act on the collector owned by the running evaluator:
+//! - `(collect-garbage [kind])` runs a `minor`, `major` (default) or
+//!   `incremental` collection
+//! - `(gc-statistics)` returns an association list of collection counts,
+//!   pause times and heap utilization
+//! - `(set-gc-config! key value)` adjusts the adaptive tuning parameters
+
+use crate::diagnostics::{Error as DiagnosticError, Result};
+use crate::effects::Effect;
+use crate::eval::evaluator::Evaluator;
+use crate::eval::value::{PrimitiveImpl, PrimitiveProcedure, ThreadSafeEnvironment, Value};
+use std::sync::Arc;
+
+/// Tunables accepted by `set-gc-config!`.
+const GC_CONFIG_KEYS: &[&str] = &[
+    "target-minor-pause-ms",
+    "target-major-pause-ms",
+    "collection-frequency",
+];
+
+/// Creates garbage collector bindings for the standard library.
+pub fn create_gc_bindings(env: &Arc<ThreadSafeEnvironment>) {
+    env.define("collect-garbage".to_string(), Value::Primitive(Arc::new(PrimitiveProcedure {
+        name: "collect-garbage".to_string(),
+        arity_min: 0,
+        arity_max: Some(1),
+        implementation: PrimitiveImpl::EvaluatorIntegrated(primitive_collect_garbage),
+        effects: vec![Effect::State],
+    })));
+
+    env.define("gc-statistics".to_string(), Value::Primitive(Arc::new(PrimitiveProcedure {
+        name: "gc-statistics".to_string(),
+        arity_min: 0,
+        arity_max: Some(0),
+        implementation: PrimitiveImpl::EvaluatorIntegrated(primitive_gc_statistics),
+        effects: vec![Effect::IO],
+    })));
+
+    env.define("set-gc-config!".to_string(), Value::Primitive(Arc::new(PrimitiveProcedure {
+        name: "set-gc-config!".to_string(),
+        arity_min: 2,
+        arity_max: Some(2),
+        implementation: PrimitiveImpl::EvaluatorIntegrated(primitive_set_gc_config),
+        effects: vec![Effect::State],
+    })));
+}
+
+/// collect-garbage procedure
+///
+/// Returns the number of bytes reclaimed for `minor` and `major`
+/// collections, and whether the cycle finished for an `incremental` step.
+pub fn primitive_collect_garbage(evaluator: &mut Evaluator, args: &[Value]) -> Result<Value> {
+    let kind = match args.first() {
+        Some(arg) => symbol_argument(arg, "collect-garbage")?,
+        None => "major".to_string(),
+    };
+
+    let gc = evaluator.gc_system()?;
+    match kind.as_str() {
+        "minor" => gc.collect_minor()
+            .map(|result| Value::integer(result.bytes_reclaimed as i64))
+            .map_err(gc_error),
+        "major" => gc.collect_major(false)
+            .map(|result| Value::integer(result.bytes_reclaimed as i64))
+            .map_err(gc_error),
+        "incremental" => gc.collect_incremental_step()
+            .map(Value::boolean)
+            .map_err(gc_error),
+        other => Err(Box::new(DiagnosticError::runtime_error(
+            format!("collect-garbage: unknown collection kind '{other}', expected minor, major or incremental"),
+            None,
+        ))),
+    }
+}
+
+/// gc-statistics procedure
+pub fn primitive_gc_statistics(evaluator: &mut Evaluator, _args: &[Value]) -> Result<Value> {
+    let gc = evaluator.gc_system()?;
+    let stats = gc.get_statistics();
+    let params = gc.tuning_params();
+
+    let entries = vec![
+        ("minor-collections", Value::integer(stats.minor_collections as i64)),
+        ("major-collections", Value::integer(stats.major_collections as i64)),
+        ("avg-minor-pause-ms", Value::number(stats.avg_minor_pause_ms)),
+        ("avg-major-pause-ms", Value::number(stats.avg_major_pause_ms)),
+        ("total-allocations", Value::integer(stats.total_allocations as i64)),
+        ("total-allocated-bytes", Value::integer(stats.total_allocated_bytes as i64)),
+        ("young-utilization", Value::number(stats.young_utilization)),
+        ("old-utilization", Value::number(stats.old_utilization)),
+        ("allocation-rate", Value::number(stats.allocation_rate)),
+        ("tlab-utilization", Value::number(stats.tlab_utilization)),
+        ("target-minor-pause-ms", Value::integer(params.target_minor_pause_ms as i64)),
+        ("target-major-pause-ms", Value::integer(params.target_major_pause_ms as i64)),
+        ("collection-frequency", Value::number(params.collection_frequency_multiplier())),
+    ];
+
+    Ok(Value::list(
+        entries
+            .into_iter()
+            .map(|(key, value)| Value::pair(Value::Symbol(crate::utils::intern_symbol(key)), value))
+            .collect(),
+    ))
+}
+
+/// set-gc-config! procedure
+pub fn primitive_set_gc_config(evaluator: &mut Evaluator, args: &[Value]) -> Result<Value> {
+    let key = symbol_argument(&args[0], "set-gc-config!")?;
+    if !GC_CONFIG_KEYS.contains(&key.as_str()) {
+        return Err(Box::new(DiagnosticError::runtime_error(
+            format!("set-gc-config!: unknown key '{key}', expected one of {}", GC_CONFIG_KEYS.join(", ")),
+            None,
+        )));
+    }
+
+    let value = args[1].as_number().filter(|n| n.is_finite() && *n > 0.0).ok_or_else(|| {
+        DiagnosticError::runtime_error(
+            format!("set-gc-config!: {key} requires a positive number"),
+            None,
+        )
+    })?;
+
+    evaluator
+        .gc_system()?
+        .update_tuning_params(|params| match key.as_str() {
+            "target-minor-pause-ms" => params.target_minor_pause_ms = value as u64,
+            "target-major-pause-ms" => params.target_major_pause_ms = value as u64,
+            _ => params.set_collection_frequency_multiplier(value),
+        })
+        .map_err(gc_error)?;
+
+    Ok(Value::Unspecified)
+}
+
+/// Extracts the name of a symbol argument.
+fn symbol_argument(value: &Value, procedure: &str) -> Result<String> {
+    match value {
+        Value::Symbol(id) => crate::utils::symbol_name(*id).ok_or_else(|| {
+            Box::new(DiagnosticError::runtime_error(
+                format!("{procedure}: unknown symbol"),
+                None,
+            ))
+        }),
+        _ => Err(Box::new(DiagnosticError::runtime_error(
+            format!("{procedure}: expected a symbol"),
+            None,
+        ))),
+    }
+}
+
+/// Converts a GC failure into a runtime error.
+fn gc_error(message: String) -> Box<DiagnosticError> {
+    Box::new(DiagnosticError::runtime_error(
+        format!("garbage collection failed: {message}"),
+        None,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn statistic(stats: &Value, key: &str) -> Value {
+        stats
+            .as_list()
+            .unwrap()
+            .into_iter()
+            .find_map(|entry| match entry {
+                Value::Pair(car, cdr) => match car.as_ref() {
+                    Value::Symbol(id) if crate::utils::symbol_name(*id).as_deref() == Some(key) => {
+                        Some(cdr.as_ref().clone())
+                    }
+                    _ => None,
+                },
+                _ => None,
+            })
+            .unwrap()
+    }
+
+    #[test]
+    fn test_major_collection_increments_count() {
+        let mut evaluator = Evaluator::new();
+        let before = primitive_gc_statistics(&mut evaluator, &[]).unwrap();
+        let before = statistic(&before, "major-collections").as_integer().unwrap();
+
+        let major = Value::Symbol(crate::utils::intern_symbol("major"));
+        primitive_collect_garbage(&mut evaluator, &[major]).unwrap();
+
+        let after = primitive_gc_statistics(&mut evaluator, &[]).unwrap();
+        let after = statistic(&after, "major-collections").as_integer().unwrap();
+        assert!(after > before);
+    }
+
+    #[test]
+    fn test_collect_garbage_kinds() {
+        let mut evaluator = Evaluator::new();
+        let minor = Value::Symbol(crate::utils::intern_symbol("minor"));
+        assert!(primitive_collect_garbage(&mut evaluator, &[minor]).is_ok());
+
+        let incremental = Value::Symbol(crate::utils::intern_symbol("incremental"));
+        assert!(primitive_collect_garbage(&mut evaluator, &[incremental]).is_ok());
+
+        let unknown = Value::Symbol(crate::utils::intern_symbol("tiny"));
+        assert!(primitive_collect_garbage(&mut evaluator, &[unknown]).is_err());
+        assert!(primitive_collect_garbage(&mut evaluator, &[Value::integer(1)]).is_err());
+    }
+
+    #[test]
+    fn test_set_gc_config() {
+        let mut evaluator = Evaluator::new();
+        let key = Value::Symbol(crate::utils::intern_symbol("target-major-pause-ms"));
+        primitive_set_gc_config(&mut evaluator, &[key.clone(), Value::integer(40)]).unwrap();
+        let stats = primitive_gc_statistics(&mut evaluator, &[]).unwrap();
+        assert_eq!(statistic(&stats, "target-major-pause-ms"), Value::integer(40));
+
+        assert!(primitive_set_gc_config(&mut evaluator, &[key, Value::integer(-1)]).is_err());
+
+        let unknown = Value::Symbol(crate::utils::intern_symbol("heap-size"));
+        assert!(primitive_set_gc_config(&mut evaluator, &[unknown, Value::integer(1)]).is_err());
+    }
+}
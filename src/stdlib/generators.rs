@@ -142,7 +142,7 @@ fn bind_utilities(env: &Arc<ThreadSafeEnvironment>) {
         name: "generator->list".to_string(),
         arity_min: 1,
         arity_max: Some(2), // generator, length?
-        implementation: PrimitiveImpl::RustFn(primitive_generator_to_list),
+        implementation: PrimitiveImpl::EvaluatorIntegrated(evaluator_generator_to_list),
         effects: vec![Effect::IO], // May consume generator
     })));
     
@@ -645,6 +645,37 @@ fn primitive_string_to_generator(args: &[Value]) -> LambdustResult<Value> {
 
 // ============= UTILITIES =============
 
+/// generator->list procedure
+///
+/// Procedure generators, such as those `make-generator` returns, are called
+/// until they produce an end-of-file object.
+fn evaluator_generator_to_list(evaluator: &mut crate::eval::Evaluator, args: &[Value]) -> LambdustResult<Value> {
+    let Some(generator) = args.first().filter(|value| value.is_procedure()) else {
+        return primitive_generator_to_list(args);
+    };
+    
+    let max_length = match args.get(1) {
+        Some(length) => Some(length.as_integer()
+            .ok_or_else(|| Box::new(crate::diagnostics::Error::RuntimeError {
+                message: "generator->list: second argument must be an integer".to_string(),
+                span: None,
+            }))?
+            .max(0) as usize),
+        None => None,
+    };
+    
+    let mut values = Vec::new();
+    while max_length.is_none_or(|max| values.len() < max) {
+        let value = evaluator.call_procedure(generator.clone(), Vec::new(), None)?;
+        if matches!(value, Value::EofObject) {
+            break;
+        }
+        values.push(value);
+    }
+    
+    Ok(Value::list(values))
+}
+
 /// generator->list primitive
 fn primitive_generator_to_list(args: &[Value]) -> LambdustResult<Value> {
     if args.is_empty() || args.len() > 2 {
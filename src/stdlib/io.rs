@@ -11,14 +11,16 @@
 use crate::diagnostics::{Error as DiagnosticError, Result};
 use crate::eval::value::{
     Value, PrimitiveProcedure, PrimitiveImpl, ThreadSafeEnvironment,
-    Port, PortImpl, StandardPort, PortFileHandle
+    Port, PortImpl, PortBuffering, StandardPort, PortFileHandle
 };
 use crate::effects::Effect;
+use crate::stdlib::encoding::{self, DecodeMode, TextEncoding};
 use crate::parser::Parser;
-use crate::lexer::Lexer;
+use crate::lexer::{Lexer, TokenKind};
+use crate::stdlib::exceptions::{raise_file_error, raise_read_error, ExceptionObject};
 use std::sync::Arc;
 use std::fs::File;
-use std::io::{BufReader, BufWriter};
+use std::io::{BufReader, BufWriter, Seek, SeekFrom, Write};
 
 /// Helper functions to get current port values from parameter objects.
 /// 
@@ -95,6 +97,9 @@ pub fn create_io_bindings(env: &Arc<ThreadSafeEnvironment>) {
     // EOF handling
     bind_eof_operations(env);
     
+    // Random access on seekable ports
+    bind_port_positioning(env);
+    
     // Additional utilities (Lambdust extensions)
     bind_utility_operations(env);
 }
@@ -155,6 +160,24 @@ fn bind_port_predicates(env: &Arc<ThreadSafeEnvironment>) {
         implementation: PrimitiveImpl::RustFn(primitive_port_open_p),
         effects: vec![Effect::Pure],
     })));
+    
+    // input-port-open?
+    env.define("input-port-open?".to_string(), Value::Primitive(Arc::new(PrimitiveProcedure {
+        name: "input-port-open?".to_string(),
+        arity_min: 1,
+        arity_max: Some(1),
+        implementation: PrimitiveImpl::RustFn(primitive_input_port_open_p),
+        effects: vec![Effect::Pure],
+    })));
+    
+    // output-port-open?
+    env.define("output-port-open?".to_string(), Value::Primitive(Arc::new(PrimitiveProcedure {
+        name: "output-port-open?".to_string(),
+        arity_min: 1,
+        arity_max: Some(1),
+        implementation: PrimitiveImpl::RustFn(primitive_output_port_open_p),
+        effects: vec![Effect::Pure],
+    })));
 }
 
 // ============= R7RS SECTION 6.13.2: CURRENT PORTS =============
@@ -175,7 +198,7 @@ fn bind_file_operations(env: &Arc<ThreadSafeEnvironment>) {
     env.define("open-input-file".to_string(), Value::Primitive(Arc::new(PrimitiveProcedure {
         name: "open-input-file".to_string(),
         arity_min: 1,
-        arity_max: Some(1),
+        arity_max: Some(3),
         implementation: PrimitiveImpl::RustFn(primitive_open_input_file),
         effects: vec![Effect::IO],
     })));
@@ -184,7 +207,7 @@ fn bind_file_operations(env: &Arc<ThreadSafeEnvironment>) {
     env.define("open-output-file".to_string(), Value::Primitive(Arc::new(PrimitiveProcedure {
         name: "open-output-file".to_string(),
         arity_min: 1,
-        arity_max: Some(1),
+        arity_max: Some(2),
         implementation: PrimitiveImpl::RustFn(primitive_open_output_file),
         effects: vec![Effect::IO],
     })));
@@ -430,6 +453,33 @@ fn bind_input_operations(env: &Arc<ThreadSafeEnvironment>) {
         implementation: PrimitiveImpl::RustFn(primitive_u8_ready_p),
         effects: vec![Effect::IO],
     })));
+    
+    // port->string
+    env.define("port->string".to_string(), Value::Primitive(Arc::new(PrimitiveProcedure {
+        name: "port->string".to_string(),
+        arity_min: 0,
+        arity_max: Some(1),
+        implementation: PrimitiveImpl::RustFn(primitive_port_to_string),
+        effects: vec![Effect::IO],
+    })));
+    
+    // port->lines
+    env.define("port->lines".to_string(), Value::Primitive(Arc::new(PrimitiveProcedure {
+        name: "port->lines".to_string(),
+        arity_min: 0,
+        arity_max: Some(1),
+        implementation: PrimitiveImpl::RustFn(primitive_port_to_lines),
+        effects: vec![Effect::IO],
+    })));
+    
+    // port->list
+    env.define("port->list".to_string(), Value::Primitive(Arc::new(PrimitiveProcedure {
+        name: "port->list".to_string(),
+        arity_min: 0,
+        arity_max: Some(1),
+        implementation: PrimitiveImpl::RustFn(primitive_port_to_list),
+        effects: vec![Effect::IO],
+    })));
 }
 
 // ============= R7RS SECTION 6.13.6: OUTPUT OPERATIONS =============
@@ -524,6 +574,48 @@ fn bind_output_operations(env: &Arc<ThreadSafeEnvironment>) {
         implementation: PrimitiveImpl::RustFn(primitive_flush_output_port),
         effects: vec![Effect::IO],
     })));
+    
+    // set-port-buffering!
+    env.define("set-port-buffering!".to_string(), Value::Primitive(Arc::new(PrimitiveProcedure {
+        name: "set-port-buffering!".to_string(),
+        arity_min: 2,
+        arity_max: Some(2),
+        implementation: PrimitiveImpl::RustFn(primitive_set_port_buffering),
+        effects: vec![Effect::IO],
+    })));
+}
+
+// ============= PORT POSITIONING =============
+
+fn bind_port_positioning(env: &Arc<ThreadSafeEnvironment>) {
+    // port-position
+    env.define("port-position".to_string(), Value::Primitive(Arc::new(PrimitiveProcedure {
+        name: "port-position".to_string(),
+        arity_min: 1,
+        arity_max: Some(1),
+        implementation: PrimitiveImpl::RustFn(primitive_port_position),
+        effects: vec![Effect::IO],
+    })));
+    
+    // set-port-position!
+    env.define("set-port-position!".to_string(), Value::Primitive(Arc::new(PrimitiveProcedure {
+        name: "set-port-position!".to_string(),
+        arity_min: 2,
+        arity_max: Some(2),
+        implementation: PrimitiveImpl::RustFn(primitive_set_port_position),
+        effects: vec![Effect::IO],
+    })));
+    
+    // port-has-port-position? and port-has-set-port-position!? share one test
+    for name in ["port-has-port-position?", "port-has-set-port-position!?"] {
+        env.define(name.to_string(), Value::Primitive(Arc::new(PrimitiveProcedure {
+            name: name.to_string(),
+            arity_min: 1,
+            arity_max: Some(1),
+            implementation: PrimitiveImpl::RustFn(primitive_port_has_position),
+            effects: vec![Effect::Pure],
+        })));
+    }
 }
 
 // ============= EOF HANDLING =============
@@ -557,7 +649,7 @@ fn bind_utility_operations(env: &Arc<ThreadSafeEnvironment>) {
         arity_min: 1,
         arity_max: Some(1),
         implementation: PrimitiveImpl::RustFn(primitive_file_exists_p),
-        effects: vec![Effect::IO],
+        effects: vec![Effect::IO, Effect::file_system()],
     })));
     
     // delete-file
@@ -566,7 +658,7 @@ fn bind_utility_operations(env: &Arc<ThreadSafeEnvironment>) {
         arity_min: 1,
         arity_max: Some(1),
         implementation: PrimitiveImpl::RustFn(primitive_delete_file),
-        effects: vec![Effect::IO],
+        effects: vec![Effect::IO, Effect::file_system()],
     })));
 }
 
@@ -658,51 +750,103 @@ pub fn primitive_port_open_p(args: &[Value]) -> Result<Value> {
     }
 }
 
+/// input-port-open? procedure
+///
+/// Returns #t if the port is an input port that has not been closed.
+pub fn primitive_input_port_open_p(args: &[Value]) -> Result<Value> {
+    match &args[0] {
+        Value::Port(port) => Ok(Value::boolean(port.is_input() && port.is_open())),
+        _ => Err(Box::new(DiagnosticError::runtime_error(
+            "input-port-open? requires a port argument".to_string(),
+            None,
+        ))),
+    }
+}
+
+/// output-port-open? procedure
+///
+/// Returns #t if the port is an output port that has not been closed.
+pub fn primitive_output_port_open_p(args: &[Value]) -> Result<Value> {
+    match &args[0] {
+        Value::Port(port) => Ok(Value::boolean(port.is_output() && port.is_open())),
+        _ => Err(Box::new(DiagnosticError::runtime_error(
+            "output-port-open? requires a port argument".to_string(),
+            None,
+        ))),
+    }
+}
+
 // === Current Ports ===
 
 
 // === File Operations ===
 
+/// (open-input-file filename [encoding [decode-mode]])
+///
+/// The encoding is one of `utf-8` (default), `latin-1` or `utf-16le`; a
+/// leading byte order mark is skipped and, for UTF-16, selects the byte
+/// order. The decode mode is `strict` (default) or `lenient`.
 pub fn primitive_open_input_file(args: &[Value]) -> Result<Value> {
-    if args.len() != 1 {
+    if args.is_empty() || args.len() > 3 {
         return Err(Box::new(DiagnosticError::runtime_error(
-            format!("open-input-file expects 1 argument, got {args_len}", args_len = args.len()),
+            format!("open-input-file expects 1 to 3 arguments, got {args_len}", args_len = args.len()),
             None,
         )));
     }
     
     let filename = extract_string(&args[0], "open-input-file")?;
-    let port = Port::new_file_input(filename, false);
+    let requested = match args.get(1) {
+        Some(value) => extract_encoding(value, "open-input-file")?,
+        None => TextEncoding::Utf8,
+    };
+    let mode = match args.get(2) {
+        Some(value) => extract_decode_mode(value, "open-input-file")?,
+        None => DecodeMode::Strict,
+    };
+    let mut port = Port::new_file_input(filename, false);
     
     // Try to open the file to validate it exists
+    let mut encoding = requested;
     if let PortImpl::File { path, handle } = &port.implementation {
         match File::open(path) {
             Ok(file) => {
-                let reader = BufReader::new(file);
+                let mut reader = BufReader::new(file);
+                encoding = encoding::consume_byte_order_mark(&mut reader, requested).map_err(|e| {
+                    DiagnosticError::runtime_error(format!("Error reading from file '{path}': {e}"), None)
+                })?;
                 *handle.write().unwrap() = Some(PortFileHandle::TextReader(reader));
             }
             Err(e) => {
-                return Err(Box::new(DiagnosticError::runtime_error(
+                return raise_file_error(
                     format!("Cannot open file '{path}': {e}"),
-                    None,
-                )));
+                    vec![Value::string(path.clone())],
+                );
             }
         }
     }
+    set_port_encoding(&mut port, encoding, mode);
     
     Ok(Value::Port(Arc::new(port)))
 }
 
+/// (open-output-file filename [encoding])
+///
+/// Writing a character that the encoding cannot represent is an error.
 pub fn primitive_open_output_file(args: &[Value]) -> Result<Value> {
-    if args.len() != 1 {
+    if args.is_empty() || args.len() > 2 {
         return Err(Box::new(DiagnosticError::runtime_error(
-            format!("open-output-file expects 1 argument, got {args_len}", args_len = args.len()),
+            format!("open-output-file expects 1 or 2 arguments, got {args_len}", args_len = args.len()),
             None,
         )));
     }
     
     let filename = extract_string(&args[0], "open-output-file")?;
-    let port = Port::new_file_output(filename, false);
+    let encoding = match args.get(1) {
+        Some(value) => extract_encoding(value, "open-output-file")?,
+        None => TextEncoding::Utf8,
+    };
+    let mut port = Port::new_file_output(filename, false);
+    set_port_encoding(&mut port, encoding, DecodeMode::Strict);
     
     // Try to create the file
     if let PortImpl::File { path, handle } = &port.implementation {
@@ -712,10 +856,10 @@ pub fn primitive_open_output_file(args: &[Value]) -> Result<Value> {
                 *handle.write().unwrap() = Some(PortFileHandle::TextWriter(writer));
             }
             Err(e) => {
-                return Err(Box::new(DiagnosticError::runtime_error(
+                return raise_file_error(
                     format!("Cannot create file '{path}': {e}"),
-                    None,
-                )));
+                    vec![Value::string(path.clone())],
+                );
             }
         }
     }
@@ -741,10 +885,10 @@ pub fn primitive_open_binary_input_file(args: &[Value]) -> Result<Value> {
                 *handle.write().unwrap() = Some(PortFileHandle::BinaryReader(reader));
             }
             Err(e) => {
-                return Err(Box::new(DiagnosticError::runtime_error(
+                return raise_file_error(
                     format!("Cannot open binary file '{path}': {e}"),
-                    None,
-                )));
+                    vec![Value::string(path.clone())],
+                );
             }
         }
     }
@@ -770,10 +914,10 @@ pub fn primitive_open_binary_output_file(args: &[Value]) -> Result<Value> {
                 *handle.write().unwrap() = Some(PortFileHandle::BinaryWriter(writer));
             }
             Err(e) => {
-                return Err(Box::new(DiagnosticError::runtime_error(
+                return raise_file_error(
                     format!("Cannot create binary file '{path}': {e}"),
-                    None,
-                )));
+                    vec![Value::string(path.clone())],
+                );
             }
         }
     }
@@ -1068,6 +1212,8 @@ pub fn primitive_get_output_string(args: &[Value]) -> Result<Value> {
             
             match &port.implementation {
                 PortImpl::String { content, .. } => {
+                    // Everything written so far is visible, whatever the buffering
+                    flush_port(port)?;
                     let result = content.read().unwrap().clone();
                     // Reset the string for future accumulation
                     content.write().unwrap().clear();
@@ -1124,10 +1270,11 @@ pub fn primitive_get_output_bytevector(args: &[Value]) -> Result<Value> {
             }
             
             match &port.implementation {
-                PortImpl::Bytevector { content, .. } => {
+                PortImpl::Bytevector { content, position } => {
                     let result = content.read().unwrap().clone();
                     // Reset the bytevector for future accumulation
                     content.write().unwrap().clear();
+                    *position.write().unwrap() = 0;
                     Ok(Value::bytevector(result))
                 }
                 _ => Err(Box::new(DiagnosticError::runtime_error(
@@ -1174,28 +1321,32 @@ pub fn primitive_read(args: &[Value]) -> Result<Value> {
             }
             
             // Read text from port and parse as S-expression
-            match read_text_from_port(&port_ref) {
-                Ok(Some(text)) => {
-                    // Parse the text as a Scheme expression
-                    let mut lexer = Lexer::new(&text, None);
-                    let tokens = lexer.tokenize().map_err(|e| {
-                        DiagnosticError::runtime_error(
-                            format!("read: lexer error: {e}"),
-                            None,
-                        )
-                    })?;
-                    
-                    let mut parser = Parser::new(tokens);
-                    match parser.parse_expression() {
-                        Ok(expr) => {
-                            // Convert expression to value
-                            expr_to_value(expr.inner)
-                        }
-                        Err(_) => Ok(eof_value()),
-                    }
+            loop {
+                let text = match read_text_from_port(&port_ref)? {
+                    Some(text) => text,
+                    None => return Ok(eof_value()),
+                };
+                
+                // Parse the text as a Scheme expression, folding case as
+                // left by earlier directives on this port
+                let fold_case = *port_ref.fold_case.read().unwrap();
+                let mut lexer = Lexer::new(&text, None).with_fold_case(fold_case);
+                let tokens = match lexer.tokenize() {
+                    Ok(tokens) => tokens,
+                    Err(e) => return raise_read_error(format!("read: {e}"), vec![Value::string(text)]),
+                };
+                *port_ref.fold_case.write().unwrap() = lexer.fold_case();
+                
+                // Only comments, directives or whitespace were read
+                if tokens.iter().all(|token| token.kind == TokenKind::Eof) {
+                    continue;
                 }
-                Ok(None) => Ok(eof_value()),
-                Err(e) => Err(e),
+                
+                let mut parser = Parser::new(tokens);
+                return match read_datum(&mut parser) {
+                    Ok(value) => Ok(value),
+                    Err(e) => raise_read_error(format!("read: malformed datum: {e}"), vec![Value::string(text)]),
+                };
             }
         }
         _ => Err(Box::new(DiagnosticError::runtime_error(
@@ -1530,60 +1681,148 @@ pub fn primitive_read_bytevector_bang(args: &[Value]) -> Result<Value> {
     }
 }
 
-pub fn primitive_char_ready_p(_args: &[Value]) -> Result<Value> {
-    // For now, always return true (characters are always ready in our implementation)
+pub fn primitive_char_ready_p(args: &[Value]) -> Result<Value> {
+    ready_input_port(args, "char-ready?", Port::is_textual)?;
+    // String, bytevector and file ports never block, so input is always ready;
+    // at end of file this is #t too, since the next read returns eof at once
     Ok(Value::boolean(true))
 }
 
-pub fn primitive_u8_ready_p(_args: &[Value]) -> Result<Value> {
-    // For now, always return true (bytes are always ready in our implementation)
+pub fn primitive_u8_ready_p(args: &[Value]) -> Result<Value> {
+    ready_input_port(args, "u8-ready?", Port::is_binary)?;
     Ok(Value::boolean(true))
 }
 
-// === Output Operations ===
+/// Validates the optional port argument of char-ready? and u8-ready?.
+fn ready_input_port(args: &[Value], operation: &str, has_mode: fn(&Port) -> bool) -> Result<()> {
+    let port = match args.first() {
+        Some(port) => port.clone(),
+        None => current_ports::get_current_input_port(),
+    };
+    
+    match port {
+        Value::Port(port) if port.is_input() && has_mode(&port) => {
+            if port.is_open() {
+                Ok(())
+            } else {
+                Err(closed_port_error(operation))
+            }
+        }
+        _ => Err(Box::new(DiagnosticError::runtime_error(
+            format!("{operation} requires an input port of the matching kind"),
+            None,
+        ))),
+    }
+}
 
-pub fn primitive_write(args: &[Value]) -> Result<Value> {
-    if args.is_empty() || args.len() > 2 {
+/// (port->string [port])
+///
+/// Reads everything left on the port into a string. Bytevector input ports
+/// are read as UTF-8 text.
+pub fn primitive_port_to_string(args: &[Value]) -> Result<Value> {
+    let port = slurp_input_port(args, "port->string")?;
+    Ok(Value::string(slurp_port_text(&port, "port->string")?))
+}
+
+/// (port->lines [port])
+///
+/// Reads everything left on the port as a list of lines, without their
+/// line terminators.
+pub fn primitive_port_to_lines(args: &[Value]) -> Result<Value> {
+    let port = slurp_input_port(args, "port->lines")?;
+    let text = slurp_port_text(&port, "port->lines")?;
+    Ok(Value::list(text.lines().map(Value::string).collect()))
+}
+
+/// (port->list [port])
+///
+/// Reads data with `read` until the end of the port and returns them as a
+/// list. A malformed datum raises the same read error as `read`.
+pub fn primitive_port_to_list(args: &[Value]) -> Result<Value> {
+    let mut port = slurp_input_port(args, "port->list")?;
+    if port.is_binary() {
+        // Bytevector input is parsed through a string port over its text
+        let text = slurp_port_text(&port, "port->list")?;
+        port = Arc::new(Port::new_string_input(text));
+    }
+    
+    let port = Value::Port(port);
+    let mut data = Vec::new();
+    loop {
+        let datum = primitive_read(std::slice::from_ref(&port))?;
+        if is_eof_value(&datum) {
+            return Ok(Value::list(data));
+        }
+        data.push(datum);
+    }
+}
+
+/// Validates the optional input port argument of the port slurping procedures.
+fn slurp_input_port(args: &[Value], operation: &str) -> Result<Arc<Port>> {
+    if args.len() > 1 {
         return Err(Box::new(DiagnosticError::runtime_error(
-            format!("write expects 1 or 2 arguments, got {}", args.len()),
+            format!("{operation} expects 0 or 1 arguments, got {}", args.len()),
             None,
         )));
     }
     
-    let value = &args[0];
-    let port = if args.len() == 1 {
-        current_ports::get_current_output_port()
-    } else {
-        args[1].clone()
+    let port = match args.first() {
+        Some(port) => port.clone(),
+        None => current_ports::get_current_input_port(),
     };
     
     match port {
-        Value::Port(port_ref) => {
-            if !port_ref.is_output() || !port_ref.is_textual() {
-                return Err(Box::new(DiagnosticError::runtime_error(
-                    "write requires a textual output port".to_string(),
-                    None,
-                )));
-            }
-            
-            if !port_ref.is_open() {
-                return Err(Box::new(DiagnosticError::runtime_error(
-                    "write to closed port".to_string(),
-                    None,
-                )));
+        Value::Port(port) if port.is_input() => {
+            if port.is_open() {
+                Ok(port)
+            } else {
+                Err(closed_port_error(operation))
             }
-            
-            let output = format!("{value}");
-            write_string_to_port(&port_ref, &output)?;
-            Ok(Value::Unspecified)
         }
         _ => Err(Box::new(DiagnosticError::runtime_error(
-            "write requires a port argument".to_string(),
+            format!("{operation} requires an input port"),
             None,
         ))),
     }
 }
 
+/// Reads the rest of an input port as text, stopping at end of file.
+fn slurp_port_text(port: &Port, operation: &str) -> Result<String> {
+    if let PortImpl::Bytevector { content, position } = &port.implementation {
+        let content_guard = content.read().unwrap();
+        let mut pos_guard = position.write().unwrap();
+        let start = (*pos_guard).min(content_guard.len());
+        let text = std::str::from_utf8(&content_guard[start..])
+            .map_err(|e| codec_error(operation, &format!("bytevector is not valid UTF-8: {e}")))?
+            .to_string();
+        *pos_guard = content_guard.len();
+        return Ok(text);
+    }
+    
+    let mut text = String::new();
+    loop {
+        match read_char_from_port(port, false)? {
+            Value::Literal(crate::ast::Literal::Character(ch)) => text.push(ch),
+            _ => return Ok(text),
+        }
+    }
+}
+
+// === Output Operations ===
+
+pub fn primitive_write(args: &[Value]) -> Result<Value> {
+    if args.is_empty() || args.len() > 2 {
+        return Err(Box::new(DiagnosticError::runtime_error(
+            format!("write expects 1 or 2 arguments, got {}", args.len()),
+            None,
+        )));
+    }
+    
+    let port = textual_output_port(args.get(1), "write")?;
+    write_string_to_port(&port, &format!("{}", args[0]))?;
+    Ok(Value::Unspecified)
+}
+
 pub fn primitive_write_shared(args: &[Value]) -> Result<Value> {
     // For now, same as write (shared structure detection not implemented)
     primitive_write(args)
@@ -1602,38 +1841,9 @@ pub fn primitive_display(args: &[Value]) -> Result<Value> {
         )));
     }
     
-    let value = &args[0];
-    let port = if args.len() == 1 {
-        current_ports::get_current_output_port()
-    } else {
-        args[1].clone()
-    };
-    
-    match port {
-        Value::Port(port_ref) => {
-            if !port_ref.is_output() || !port_ref.is_textual() {
-                return Err(Box::new(DiagnosticError::runtime_error(
-                    "display requires a textual output port".to_string(),
-                    None,
-                )));
-            }
-            
-            if !port_ref.is_open() {
-                return Err(Box::new(DiagnosticError::runtime_error(
-                    "display to closed port".to_string(),
-                    None,
-                )));
-            }
-            
-            let output = display_value(value);
-            write_string_to_port(&port_ref, &output)?;
-            Ok(Value::Unspecified)
-        }
-        _ => Err(Box::new(DiagnosticError::runtime_error(
-            "display requires a port argument".to_string(),
-            None,
-        ))),
-    }
+    let port = textual_output_port(args.get(1), "display")?;
+    write_string_to_port(&port, &display_value(&args[0]))?;
+    Ok(Value::Unspecified)
 }
 
 pub fn primitive_newline(args: &[Value]) -> Result<Value> {
@@ -1644,36 +1854,9 @@ pub fn primitive_newline(args: &[Value]) -> Result<Value> {
         )));
     }
     
-    let port = if args.is_empty() {
-        current_ports::get_current_output_port()
-    } else {
-        args[0].clone()
-    };
-    
-    match port {
-        Value::Port(port_ref) => {
-            if !port_ref.is_output() || !port_ref.is_textual() {
-                return Err(Box::new(DiagnosticError::runtime_error(
-                    "newline requires a textual output port".to_string(),
-                    None,
-                )));
-            }
-            
-            if !port_ref.is_open() {
-                return Err(Box::new(DiagnosticError::runtime_error(
-                    "newline to closed port".to_string(),
-                    None,
-                )));
-            }
-            
-            write_string_to_port(&port_ref, "\n")?;
-            Ok(Value::Unspecified)
-        }
-        _ => Err(Box::new(DiagnosticError::runtime_error(
-            "newline requires a port argument".to_string(),
-            None,
-        ))),
-    }
+    let port = textual_output_port(args.first(), "newline")?;
+    write_string_to_port(&port, "\n")?;
+    Ok(Value::Unspecified)
 }
 
 pub fn primitive_write_char(args: &[Value]) -> Result<Value> {
@@ -1685,36 +1868,9 @@ pub fn primitive_write_char(args: &[Value]) -> Result<Value> {
     }
     
     let ch = extract_character(&args[0], "write-char")?;
-    let port = if args.len() == 1 {
-        current_ports::get_current_output_port()
-    } else {
-        args[1].clone()
-    };
-    
-    match port {
-        Value::Port(port_ref) => {
-            if !port_ref.is_output() || !port_ref.is_textual() {
-                return Err(Box::new(DiagnosticError::runtime_error(
-                    "write-char requires a textual output port".to_string(),
-                    None,
-                )));
-            }
-            
-            if !port_ref.is_open() {
-                return Err(Box::new(DiagnosticError::runtime_error(
-                    "write-char to closed port".to_string(),
-                    None,
-                )));
-            }
-            
-            write_string_to_port(&port_ref, &ch.to_string())?;
-            Ok(Value::Unspecified)
-        }
-        _ => Err(Box::new(DiagnosticError::runtime_error(
-            "write-char requires a port argument".to_string(),
-            None,
-        ))),
-    }
+    let port = textual_output_port(args.get(1), "write-char")?;
+    write_string_to_port(&port, ch.encode_utf8(&mut [0; 4]))?;
+    Ok(Value::Unspecified)
 }
 
 pub fn primitive_write_string(args: &[Value]) -> Result<Value> {
@@ -1726,56 +1882,32 @@ pub fn primitive_write_string(args: &[Value]) -> Result<Value> {
     }
     
     let string = extract_string(&args[0], "write-string")?;
-    let port = if args.len() == 1 {
-        current_ports::get_current_output_port()
-    } else {
-        args[1].clone()
-    };
+    let length = string.chars().count();
     
-    let start = if args.len() >= 3 {
-        extract_integer(&args[2], "write-string")? as usize
-    } else {
-        0
+    // Bounds count characters, not bytes
+    let start = match args.get(2) {
+        Some(value) => extract_integer(value, "write-string")?,
+        None => 0,
     };
-    
-    let end = if args.len() >= 4 {
-        extract_integer(&args[3], "write-string")? as usize
-    } else {
-        string.len()
+    let end = match args.get(3) {
+        Some(value) => extract_integer(value, "write-string")?,
+        None => length as i64,
     };
-    
-    if start > end || end > string.len() {
+    if start < 0 || start > end || end > length as i64 {
         return Err(Box::new(DiagnosticError::runtime_error(
-            "write-string: invalid start/end indices".to_string(),
+            format!("write-string: invalid range [{start}, {end}) for string of length {length}"),
             None,
         )));
     }
     
-    match port {
-        Value::Port(port_ref) => {
-            if !port_ref.is_output() || !port_ref.is_textual() {
-                return Err(Box::new(DiagnosticError::runtime_error(
-                    "write-string requires a textual output port".to_string(),
-                    None,
-                )));
-            }
-            
-            if !port_ref.is_open() {
-                return Err(Box::new(DiagnosticError::runtime_error(
-                    "write-string to closed port".to_string(),
-                    None,
-                )));
-            }
-            
-            let substring = &string[start..end];
-            write_string_to_port(&port_ref, substring)?;
-            Ok(Value::Unspecified)
-        }
-        _ => Err(Box::new(DiagnosticError::runtime_error(
-            "write-string requires a port argument".to_string(),
-            None,
-        ))),
-    }
+    let port = textual_output_port(args.get(1), "write-string")?;
+    let substring: String = string
+        .chars()
+        .skip(start as usize)
+        .take((end - start) as usize)
+        .collect();
+    write_string_to_port(&port, &substring)?;
+    Ok(Value::Unspecified)
 }
 
 pub fn primitive_write_u8(args: &[Value]) -> Result<Value> {
@@ -1879,60 +2011,221 @@ pub fn primitive_write_bytevector(args: &[Value]) -> Result<Value> {
     }
 }
 
-pub fn primitive_flush_output_port(_args: &[Value]) -> Result<Value> {
-    // For now, do nothing (our implementation doesn't buffer)
-    Ok(Value::Unspecified)
-}
-
-// === EOF Operations ===
-
-pub fn primitive_eof_object(_args: &[Value]) -> Result<Value> {
-    Ok(eof_value())
+pub fn primitive_flush_output_port(args: &[Value]) -> Result<Value> {
+    let port = match args.first() {
+        Some(port) => port.clone(),
+        None => current_ports::get_current_output_port(),
+    };
+    
+    match &port {
+        Value::Port(port_ref) if port_ref.is_output() => {
+            flush_port(port_ref)?;
+            Ok(Value::Unspecified)
+        }
+        _ => Err(Box::new(DiagnosticError::runtime_error(
+            "flush-output-port requires an output port".to_string(),
+            None,
+        ))),
+    }
 }
 
-pub fn primitive_eof_object_p(args: &[Value]) -> Result<Value> {
-    if args.len() != 1 {
+/// set-port-buffering! procedure
+///
+/// Takes the mode `none`, `line` or `block`. Output already buffered is
+/// delivered before the new mode takes effect.
+pub fn primitive_set_port_buffering(args: &[Value]) -> Result<Value> {
+    let Value::Port(port) = &args[0] else {
         return Err(Box::new(DiagnosticError::runtime_error(
-            format!("eof-object? expects 1 argument, got {}", args.len()),
+            "set-port-buffering! requires a port argument".to_string(),
             None,
         )));
+    };
+    
+    let mode = match &args[1] {
+        Value::Symbol(id) => crate::utils::symbol_name(*id).and_then(|name| PortBuffering::from_name(&name)),
+        _ => None,
     }
+    .ok_or_else(|| {
+        DiagnosticError::runtime_error(
+            format!("set-port-buffering!: expected none, line or block, got {}", args[1]),
+            None,
+        )
+    })?;
     
-    Ok(Value::boolean(is_eof_value(&args[0])))
+    if port.is_output() {
+        flush_port(port)?;
+    }
+    port.set_buffering(mode);
+    Ok(Value::Unspecified)
 }
 
-// === Utility Operations ===
+// === Port Positioning ===
 
-pub fn primitive_file_exists_p(args: &[Value]) -> Result<Value> {
-    if args.len() != 1 {
+/// port-position procedure
+///
+/// Returns the byte offset of a file or bytevector port. Positions of textual
+/// file ports are byte offsets too, so a position obtained between two reads
+/// always falls on a character boundary, but an arbitrary offset may not.
+pub fn primitive_port_position(args: &[Value]) -> Result<Value> {
+    let port = seekable_port(&args[0], "port-position")?;
+    
+    match &port.implementation {
+        PortImpl::Bytevector { position, .. } => Ok(Value::integer(*position.read().unwrap() as i64)),
+        PortImpl::File { handle, .. } => {
+            let position = match handle.write().unwrap().as_mut() {
+                Some(PortFileHandle::TextReader(reader) | PortFileHandle::BinaryReader(reader)) => {
+                    // Bytes read ahead for decoding or peeking are not consumed yet
+                    let pending = port.buffer.read().unwrap().len() as u64;
+                    reader.stream_position().map(|position| position - pending)
+                }
+                Some(PortFileHandle::TextWriter(writer) | PortFileHandle::BinaryWriter(writer)) => {
+                    writer.stream_position()
+                }
+                None => return Err(closed_port_error("port-position")),
+            }
+            .map_err(|e| DiagnosticError::runtime_error(format!("port-position: {e}"), None))?;
+            Ok(Value::integer(position as i64))
+        }
+        _ => unreachable!("seekable_port only accepts file and bytevector ports"),
+    }
+}
+
+/// set-port-position! procedure
+///
+/// Moves a file or bytevector port to a byte offset. A file port may be moved
+/// past its end; writing there extends the file, and reading there yields EOF.
+/// Seeking a textual port into the middle of a multibyte character makes the
+/// next read decode from that byte.
+pub fn primitive_set_port_position(args: &[Value]) -> Result<Value> {
+    let port = seekable_port(&args[0], "set-port-position!")?;
+    let target = match args[1].as_integer() {
+        Some(target) if target >= 0 => target as u64,
+        _ => return Err(Box::new(DiagnosticError::runtime_error(
+            format!("set-port-position!: position must be a non-negative integer, got {}", args[1]),
+            None,
+        ))),
+    };
+    
+    match &port.implementation {
+        PortImpl::Bytevector { content, position } => {
+            // Input ports cannot move past their content; output ports
+            // zero-fill the gap on the next write
+            if port.is_input() && target as usize > content.read().unwrap().len() {
+                return Err(Box::new(DiagnosticError::runtime_error(
+                    format!("set-port-position!: position {target} is past the end of the bytevector"),
+                    None,
+                )));
+            }
+            *position.write().unwrap() = target as usize;
+        }
+        PortImpl::File { handle, .. } => {
+            let result = match handle.write().unwrap().as_mut() {
+                Some(PortFileHandle::TextReader(reader) | PortFileHandle::BinaryReader(reader)) => {
+                    port.buffer.write().unwrap().clear();
+                    reader.seek(SeekFrom::Start(target))
+                }
+                Some(PortFileHandle::TextWriter(writer) | PortFileHandle::BinaryWriter(writer)) => {
+                    writer.seek(SeekFrom::Start(target))
+                }
+                None => return Err(closed_port_error("set-port-position!")),
+            };
+            result.map_err(|e| DiagnosticError::runtime_error(format!("set-port-position!: {e}"), None))?;
+        }
+        _ => unreachable!("seekable_port only accepts file and bytevector ports"),
+    }
+    Ok(Value::Unspecified)
+}
+
+/// port-has-port-position? and port-has-set-port-position!? procedures
+///
+/// Every port that reports its position can also be moved.
+pub fn primitive_port_has_position(args: &[Value]) -> Result<Value> {
+    let seekable = matches!(
+        &args[0],
+        Value::Port(port) if matches!(port.implementation, PortImpl::Bytevector { .. } | PortImpl::File { .. })
+    );
+    Ok(Value::boolean(seekable))
+}
+
+/// Extracts an open file or bytevector port for a positioning operation.
+fn seekable_port<'a>(value: &'a Value, operation: &str) -> Result<&'a Port> {
+    let Value::Port(port) = value else {
         return Err(Box::new(DiagnosticError::runtime_error(
-            format!("file-exists? expects 1 argument, got {}", args.len()),
+            format!("{operation} requires a port argument"),
             None,
         )));
+    };
+    
+    if !port.is_open() {
+        return Err(closed_port_error(operation));
+    }
+    match &port.implementation {
+        PortImpl::Bytevector { .. } | PortImpl::File { .. } => Ok(port),
+        _ => Err(Box::new(DiagnosticError::runtime_error(
+            format!("{operation}: port does not support positioning"),
+            None,
+        ))),
     }
+}
+
+/// Resolves the optional port argument of a textual output operation.
+///
+/// Defaults to the current output port and rejects closed, input-only,
+/// and binary ports.
+fn textual_output_port(value: Option<&Value>, operation: &str) -> Result<Arc<Port>> {
+    let port = match value {
+        Some(value) => value.clone(),
+        None => current_ports::get_current_output_port(),
+    };
+    let Value::Port(port) = port else {
+        return Err(Box::new(DiagnosticError::runtime_error(
+            format!("{operation} requires a port argument"),
+            None,
+        )));
+    };
     
-    let filename = extract_string(&args[0], "file-exists?")?;
-    Ok(Value::boolean(std::path::Path::new(&filename).exists()))
+    if !port.is_output() || !port.is_textual() {
+        return Err(Box::new(DiagnosticError::runtime_error(
+            format!("{operation} requires a textual output port"),
+            None,
+        )));
+    }
+    if !port.is_open() {
+        return Err(closed_port_error(operation));
+    }
+    Ok(port)
+}
+
+/// Error for an operation applied to a closed port.
+fn closed_port_error(operation: &str) -> Box<DiagnosticError> {
+    Box::new(DiagnosticError::runtime_error(
+        format!("{operation}: port is closed"),
+        None,
+    ))
 }
 
-pub fn primitive_delete_file(args: &[Value]) -> Result<Value> {
+// === EOF Operations ===
+
+pub fn primitive_eof_object(_args: &[Value]) -> Result<Value> {
+    Ok(eof_value())
+}
+
+pub fn primitive_eof_object_p(args: &[Value]) -> Result<Value> {
     if args.len() != 1 {
         return Err(Box::new(DiagnosticError::runtime_error(
-            format!("delete-file expects 1 argument, got {}", args.len()),
+            format!("eof-object? expects 1 argument, got {}", args.len()),
             None,
         )));
     }
     
-    let filename = extract_string(&args[0], "delete-file")?;
-    match std::fs::remove_file(&filename) {
-        Ok(()) => Ok(Value::Unspecified),
-        Err(e) => Err(Box::new(DiagnosticError::runtime_error(
-            format!("Cannot delete file '{filename}': {e}"),
-            None,
-        ))),
-    }
+    Ok(Value::boolean(is_eof_value(&args[0])))
 }
 
+// === Utility Operations ===
+
+// File utilities live with the other filesystem procedures in `system`.
+pub use crate::stdlib::system::{primitive_delete_file, primitive_file_exists_p};
+
 // ============= HELPER FUNCTIONS =============
 
 /// Extracts a string from a Value.
@@ -1988,90 +2281,174 @@ fn extract_bytevector(value: &Value, operation: &str) -> Result<Vec<u8>> {
     }
 }
 
-/// Creates an EOF value.
+/// Returns the canonical EOF object shared by every port reader.
 fn eof_value() -> Value {
-    // Use a special symbol to represent EOF
-    Value::Symbol(crate::utils::intern_symbol("*eof*"))
+    Value::EofObject
 }
 
-/// Checks if a value is an EOF value.
+/// Checks if a value is the EOF object.
 fn is_eof_value(value: &Value) -> bool {
-    match value {
-        Value::Symbol(id) => {
-            // Check if it's our special EOF symbol
-            crate::utils::symbol_name(*id).map(|name| name == "*eof*").unwrap_or(false)
+    matches!(value, Value::EofObject)
+}
+
+/// Extracts an encoding name given as a symbol or string.
+fn extract_encoding(value: &Value, operation: &str) -> Result<TextEncoding> {
+    let name = match value {
+        Value::Symbol(id) => crate::utils::symbol_name(*id),
+        _ => value.as_string_owned(),
+    };
+    name.as_deref().and_then(TextEncoding::from_name).ok_or_else(|| {
+        Box::new(DiagnosticError::runtime_error(
+            format!("{operation}: unsupported encoding, expected utf-8, latin-1 or utf-16le"),
+            None,
+        ))
+    })
+}
+
+/// Extracts a decode mode (`strict` or `lenient`) given as a symbol.
+fn extract_decode_mode(value: &Value, operation: &str) -> Result<DecodeMode> {
+    let name = match value {
+        Value::Symbol(id) => crate::utils::symbol_name(*id),
+        _ => None,
+    };
+    name.as_deref().and_then(DecodeMode::from_name).ok_or_else(|| {
+        Box::new(DiagnosticError::runtime_error(
+            format!("{operation}: decode mode must be strict or lenient"),
+            None,
+        ))
+    })
+}
+
+/// Records the codec of a textual file port.
+fn set_port_encoding(port: &mut Port, encoding: TextEncoding, mode: DecodeMode) {
+    port.codec = (encoding, mode);
+}
+
+/// Returns the codec of a textual port, defaulting to strict UTF-8.
+fn port_encoding(port: &Port) -> (TextEncoding, DecodeMode) {
+    port.codec
+}
+
+/// Read error condition raised when text cannot pass through a port's codec:
+/// malformed input under strict decoding, or a character the encoding cannot
+/// represent on output. `guard` and `read-error?` see it.
+fn codec_error(operation: &str, message: &str) -> Box<DiagnosticError> {
+    let exception = ExceptionObject::read_error(format!("{operation}: {message}"), Vec::new());
+    Box::new(DiagnosticError::exception(exception))
+}
+
+/// Decodes the next character of a textual file port through its codec.
+///
+/// Bytes read ahead of the current character are kept in the port buffer.
+fn read_file_char(port: &Port, peek: bool, operation: &str) -> Result<Option<char>> {
+    let PortImpl::File { handle, .. } = &port.implementation else {
+        return Err(Box::new(DiagnosticError::runtime_error(
+            format!("{operation}: not a file port"),
+            None,
+        )));
+    };
+    
+    let (encoding, mode) = port_encoding(port);
+    let mut handle = handle.write().unwrap();
+    match handle.as_mut() {
+        Some(PortFileHandle::TextReader(reader)) => {
+            let mut pending = port.buffer.write().unwrap();
+            encoding::read_char(reader, &mut pending, encoding, mode, peek)
+                .map_err(|e| codec_error(operation, &e))
         }
-        _ => false,
+        _ => Err(Box::new(DiagnosticError::runtime_error(
+            "Invalid file handle for text reading".to_string(),
+            None,
+        ))),
     }
 }
 
-/// Converts an expression to a value.
-fn expr_to_value(expr: crate::ast::Expr) -> Result<Value> {
-    match expr {
-        crate::ast::Expr::Literal(lit) => Ok(Value::Literal(lit)),
-        crate::ast::Expr::Identifier(name) | crate::ast::Expr::Symbol(name) => Ok(Value::symbol_from_str(name)),
-        crate::ast::Expr::Quote(quoted) => {
-            // Convert quoted expression to its value representation
-            match quoted.inner {
-                crate::ast::Expr::Literal(lit) => Ok(Value::Literal(lit)),
-                crate::ast::Expr::Identifier(name) | crate::ast::Expr::Symbol(name) => Ok(Value::symbol_from_str(name)),
-                crate::ast::Expr::List(elements) => {
-                    // Convert list elements recursively
-                    let mut values = Vec::new();
-                    for elem in elements {
-                        values.push(expr_to_value(elem.inner)?);
+/// Reads one datum from the token stream.
+///
+/// Values are built directly from the tokens, so lists are never mistaken
+/// for special forms and vectors keep their type.
+fn read_datum(parser: &mut Parser) -> Result<Value> {
+    let token = parser.current_token().clone();
+    match token.kind {
+        TokenKind::LeftParen | TokenKind::LeftBracket => {
+            let close = if token.kind == TokenKind::LeftParen {
+                TokenKind::RightParen
+            } else {
+                TokenKind::RightBracket
+            };
+            parser.advance();
+            let mut items = Vec::new();
+            let mut tail = Value::Nil;
+            while !parser.check(&close) {
+                if parser.is_at_end() {
+                    return Err(read_syntax_error("unterminated list"));
+                }
+                if parser.check(&TokenKind::Dot) && !items.is_empty() {
+                    parser.advance();
+                    tail = read_datum(parser)?;
+                    if !parser.check(&close) {
+                        return Err(read_syntax_error("expected exactly one datum after dot"));
                     }
-                    Ok(Value::list(values))
+                    break;
                 }
-                other => expr_to_value(other), // Recurse for other types
-            }
-        }
-        crate::ast::Expr::List(elements) => {
-            let mut values = Vec::new();
-            for elem in elements {
-                values.push(expr_to_value(elem.inner)?);
-            }
-            Ok(Value::list(values))
-        }
-        crate::ast::Expr::Application { .. } |
-        crate::ast::Expr::If { .. } |
-        crate::ast::Expr::Let { .. } |
-        crate::ast::Expr::LetStar { .. } |
-        crate::ast::Expr::LetRec { .. } |
-        crate::ast::Expr::Lambda { .. } |
-        crate::ast::Expr::CaseLambda { .. } |
-        crate::ast::Expr::Define { .. } |
-        crate::ast::Expr::DefineSyntax { .. } |
-        crate::ast::Expr::Begin { .. } |
-        crate::ast::Expr::Set { .. } |
-        crate::ast::Expr::Cond { .. } |
-        crate::ast::Expr::Case { .. } |
-        crate::ast::Expr::And { .. } |
-        crate::ast::Expr::Or { .. } |
-        crate::ast::Expr::CallCC { .. } |
-        crate::ast::Expr::SyntaxRules { .. } |
-        crate::ast::Expr::Parameterize { .. } |
-        crate::ast::Expr::Guard { .. } |
-        crate::ast::Expr::Keyword(_) |
-        crate::ast::Expr::Quasiquote(_) |
-        crate::ast::Expr::Unquote(_) |
-        crate::ast::Expr::UnquoteSplicing(_) |
-        crate::ast::Expr::Primitive { .. } |
-        crate::ast::Expr::TypeAnnotation { .. } |
-        crate::ast::Expr::Import { .. } |
-        crate::ast::Expr::DefineLibrary { .. } |
-        crate::ast::Expr::Pair { .. } |
-        crate::ast::Expr::When { .. } |
-        crate::ast::Expr::Unless { .. } => {
-            // These require evaluation, which we can't do in this context
-            Err(Box::new(DiagnosticError::runtime_error(
-                "read: complex expressions require evaluation".to_string(),
-                None,
-            )))
+                items.push(read_datum(parser)?);
+            }
+            parser.advance();
+            Ok(items.into_iter().rev().fold(tail, |rest, item| Value::pair(item, rest)))
+        }
+        TokenKind::VectorStart => {
+            parser.advance();
+            let mut items = Vec::new();
+            while !parser.check(&TokenKind::RightParen) {
+                if parser.is_at_end() {
+                    return Err(read_syntax_error("unterminated vector"));
+                }
+                items.push(read_datum(parser)?);
+            }
+            parser.advance();
+            Ok(Value::vector(items))
         }
+        TokenKind::Quote | TokenKind::Quasiquote | TokenKind::Unquote | TokenKind::UnquoteSplicing => {
+            let name = match token.kind {
+                TokenKind::Quote => "quote",
+                TokenKind::Quasiquote => "quasiquote",
+                TokenKind::Unquote => "unquote",
+                _ => "unquote-splicing",
+            };
+            parser.advance();
+            let datum = read_datum(parser)?;
+            Ok(Value::list(vec![Value::symbol_from_str(name), datum]))
+        }
+        TokenKind::Identifier => {
+            parser.advance();
+            Ok(Value::symbol_from_str(token.identifier_name()?))
+        }
+        TokenKind::Keyword => {
+            parser.advance();
+            Ok(Value::Keyword(token.text[1..].to_string()))
+        }
+        TokenKind::BytevectorStart | TokenKind::IntegerNumber | TokenKind::RealNumber |
+        TokenKind::RationalNumber | TokenKind::ComplexNumber | TokenKind::String |
+        TokenKind::Character | TokenKind::Boolean => {
+            let expr = if token.kind == TokenKind::BytevectorStart {
+                parser.parse_bytevector()?
+            } else {
+                parser.parse_any_literal()?
+            };
+            match expr.inner {
+                crate::ast::Expr::Literal(lit) => Ok(Value::Literal(lit)),
+                _ => Err(read_syntax_error("expected a literal")),
+            }
+        }
+        _ => Err(read_syntax_error(&format!("unexpected {}", token.kind))),
     }
 }
 
+/// Creates the error for a token sequence that is not a datum.
+fn read_syntax_error(message: &str) -> Box<DiagnosticError> {
+    Box::new(DiagnosticError::runtime_error(message.to_string(), None))
+}
+
 /// Reads text from a port for parsing.
 fn read_text_from_port(port: &Port) -> Result<Option<String>> {
     match &port.implementation {
@@ -2092,9 +2469,8 @@ fn read_text_from_port(port: &Port) -> Result<Option<String>> {
                 let text = remaining[..end].trim().to_string();
                 *pos_guard += end;
                 // Skip whitespace after the expression
-                while *pos_guard < content_guard.len() && content_guard.chars().nth(*pos_guard).is_some_and(|c| c.is_whitespace()) {
-                    *pos_guard += 1;
-                }
+                let rest = &content_guard[*pos_guard..];
+                *pos_guard += rest.len() - rest.trim_start().len();
                 Ok(Some(text))
             } else {
                 // Read everything remaining as one expression attempt
@@ -2123,21 +2499,22 @@ fn read_text_from_port(port: &Port) -> Result<Option<String>> {
             }
         }
         PortImpl::File { handle, .. } => {
-            // For file ports, this would require implementing proper file reading
-            use std::io::{BufRead, BufReader};
-            
             if let Some(file_handle) = handle.write().unwrap().as_mut() {
                 match file_handle {
                     PortFileHandle::TextReader(reader) => {
+                        let (encoding, mode) = port_encoding(port);
+                        let mut pending = port.buffer.write().unwrap();
                         let mut line = String::new();
-                        match reader.read_line(&mut line) {
-                            Ok(0) => Ok(None), // EOF
-                            Ok(_) => Ok(Some(line.trim().to_string())),
-                            Err(e) => Err(Box::new(DiagnosticError::runtime_error(
-                                format!("Error reading from file: {e}"),
-                                None,
-                            ))),
+                        loop {
+                            match encoding::read_char(reader, &mut pending, encoding, mode, false) {
+                                Ok(Some('\n')) => break,
+                                Ok(Some(ch)) => line.push(ch),
+                                Ok(None) if line.is_empty() => return Ok(None), // EOF
+                                Ok(None) => break,
+                                Err(e) => return Err(codec_error("read", &e)),
+                            }
                         }
+                        Ok(Some(line.trim().to_string()))
                     }
                     _ => Err(Box::new(DiagnosticError::runtime_error(
                         "Invalid file handle for text reading".to_string(),
@@ -2158,68 +2535,89 @@ fn read_text_from_port(port: &Port) -> Result<Option<String>> {
     }
 }
 
-/// Simple S-expression boundary detection.
-/// This is a simplified version - a full implementation would need proper parsing.
+/// Finds where the first datum in `text` ends.
+///
+/// Strings, `|symbols|`, character literals and comments are skipped as units so
+/// that the brackets and whitespace inside them do not end the datum early.
 fn find_sexp_boundary(text: &str) -> Option<usize> {
-    let mut depth = 0;
-    let mut in_string = false;
-    let mut escape_next = false;
+    let mut depth = 0usize;
+    let mut in_atom = false;
     let mut chars = text.char_indices().peekable();
-    let mut start_found = false;
-    
+
     while let Some((i, ch)) = chars.next() {
-        if escape_next {
-            escape_next = false;
-            continue;
-        }
-        
-        if in_string {
-            match ch {
-                '\\' => escape_next = true,
-                '"' => in_string = false,
-                _ => {}
-            }
-            continue;
+        let ends_atom = ch.is_whitespace() || matches!(ch, '(' | ')' | '[' | ']' | '"' | '|' | ';');
+        if in_atom && depth == 0 && ends_atom {
+            return Some(i);
         }
-        
+
         match ch {
-            '"' => in_string = true,
-            '(' | '[' => {
-                depth += 1;
-                start_found = true;
-            }
-            ')' | ']' => {
-                depth -= 1;
-                if start_found && depth == 0 {
-                    return Some(i + ch.len_utf8());
+            ';' => {
+                for (_, c) in chars.by_ref() {
+                    if c == '\n' {
+                        break;
+                    }
                 }
             }
-            ch if ch.is_whitespace() => {
-                if start_found && depth == 0 {
-                    return Some(i);
+            '"' | '|' => {
+                let mut closed = false;
+                while let Some((j, c)) = chars.next() {
+                    if c == '\\' {
+                        chars.next();
+                    } else if c == ch {
+                        if depth == 0 {
+                            return Some(j + 1);
+                        }
+                        closed = true;
+                        break;
+                    }
+                }
+                if !closed {
+                    return None;
                 }
             }
-            _ => {
-                if !start_found {
-                    start_found = true;
-                    // For atoms, find the next whitespace or delimiter
-                    if depth == 0 {
-                        let mut j = i + ch.len_utf8();
-                        for (k, next_ch) in chars {
-                            match next_ch {
-                                '(' | ')' | '[' | ']' | '"' => return Some(k),
-                                ch if ch.is_whitespace() => return Some(k),
-                                _ => j = k + next_ch.len_utf8(),
-                            }
+            '#' if matches!(chars.peek(), Some((_, '|'))) => {
+                chars.next();
+                let mut nesting = 1;
+                while nesting > 0 {
+                    match chars.next() {
+                        Some((_, '|')) if matches!(chars.peek(), Some((_, '#'))) => {
+                            chars.next();
+                            nesting -= 1;
+                        }
+                        Some((_, '#')) if matches!(chars.peek(), Some((_, '|'))) => {
+                            chars.next();
+                            nesting += 1;
                         }
-                        return Some(j);
+                        Some(_) => {}
+                        None => return None,
                     }
                 }
             }
+            '#' if matches!(chars.peek(), Some((_, '('))) => {}
+            '#' if text[i..].starts_with("#u8(") => {
+                chars.next();
+                chars.next();
+            }
+            '#' if matches!(chars.peek(), Some((_, '\\'))) => {
+                // The character after #\ never delimits, as in #\( or #\space
+                chars.next();
+                chars.next();
+                in_atom = true;
+            }
+            '(' | '[' => depth += 1,
+            ')' | ']' => {
+                depth = depth.saturating_sub(1);
+                if depth == 0 {
+                    return Some(i + 1);
+                }
+            }
+            '\'' | '`' | ',' | '@' if !in_atom => {}
+            c if c.is_whitespace() => {}
+            _ => in_atom = true,
         }
     }
-    
-    if start_found && depth == 0 {
+
+    if in_atom && depth == 0 {
         Some(text.len())
     } else {
         None
@@ -2227,7 +2625,7 @@ fn find_sexp_boundary(text: &str) -> Option<usize> {
 }
 
 /// Reads a character from a port.
-fn read_char_from_port(port: &Port, peek: bool) -> Result<Value> {
+pub(crate) fn read_char_from_port(port: &Port, peek: bool) -> Result<Value> {
     match &port.implementation {
         PortImpl::String { content, position } => {
             let content_guard = content.read().unwrap();
@@ -2248,6 +2646,13 @@ fn read_char_from_port(port: &Port, peek: bool) -> Result<Value> {
                 Ok(eof_value())
             }
         }
+        PortImpl::File { .. } => {
+            let operation = if peek { "peek-char" } else { "read-char" };
+            match read_file_char(port, peek, operation)? {
+                Some(ch) => Ok(Value::Literal(crate::ast::Literal::Character(ch))),
+                None => Ok(eof_value()),
+            }
+        }
         _ => Err(Box::new(DiagnosticError::runtime_error(
             "read-char: unsupported port type".to_string(),
             None,
@@ -2277,6 +2682,18 @@ fn read_line_from_port(port: &Port) -> Result<Value> {
                 Ok(Value::string(line))
             }
         }
+        PortImpl::File { .. } => {
+            let mut line = String::new();
+            loop {
+                match read_file_char(port, false, "read-line")? {
+                    Some('\n') => break,
+                    Some(ch) => line.push(ch),
+                    None if line.is_empty() => return Ok(eof_value()),
+                    None => break,
+                }
+            }
+            Ok(Value::string(line))
+        }
         _ => Err(Box::new(DiagnosticError::runtime_error(
             "read-line: unsupported port type".to_string(),
             None,
@@ -2302,6 +2719,19 @@ fn read_string_from_port(port: &Port, k: usize) -> Result<Value> {
             
             Ok(Value::string(result))
         }
+        PortImpl::File { .. } => {
+            let mut result = String::new();
+            for _ in 0..k {
+                match read_file_char(port, false, "read-string")? {
+                    Some(ch) => result.push(ch),
+                    None => break,
+                }
+            }
+            if result.is_empty() && k > 0 {
+                return Ok(eof_value());
+            }
+            Ok(Value::string(result))
+        }
         _ => Err(Box::new(DiagnosticError::runtime_error(
             "read-string: unsupported port type".to_string(),
             None,
@@ -2334,35 +2764,88 @@ fn read_u8_from_port(port: &Port, peek: bool) -> Result<Value> {
     }
 }
 
-/// Writes a string to a port.
+/// Size at which a block-buffered string port delivers its buffer.
+const PORT_BLOCK_SIZE: usize = 4096;
+
+/// Writes a string to a port, delivering it according to the port's buffering.
 fn write_string_to_port(port: &Port, s: &str) -> Result<()> {
     match &port.implementation {
-        PortImpl::String { content, .. } => {
-            content.write().unwrap().push_str(s);
-            Ok(())
+        PortImpl::String { .. } => {
+            port.buffer.write().unwrap().extend_from_slice(s.as_bytes());
         }
         PortImpl::Standard(StandardPort::Stdout) => {
             print!("{s}");
-            Ok(())
         }
         PortImpl::Standard(StandardPort::Stderr) => {
             eprint!("{s}");
-            Ok(())
         }
-        _ => Err(Box::new(DiagnosticError::runtime_error(
+        PortImpl::File { handle, .. } => {
+            let (encoding, _) = port_encoding(port);
+            // Encode everything first so an unrepresentable character
+            // leaves the file untouched
+            let bytes = encoding.encode_str(s).map_err(|e| codec_error("write", &e))?;
+            match handle.write().unwrap().as_mut() {
+                Some(PortFileHandle::TextWriter(writer)) => writer.write_all(&bytes).map_err(|e| {
+                    Box::new(DiagnosticError::runtime_error(
+                        format!("Error writing to file: {e}"),
+                        None,
+                    ))
+                })?,
+                _ => return Err(Box::new(DiagnosticError::runtime_error(
+                    "Invalid file handle for text writing".to_string(),
+                    None,
+                ))),
+            }
+        }
+        _ => return Err(Box::new(DiagnosticError::runtime_error(
             "write-string: unsupported port type".to_string(),
             None,
         ))),
     }
+    
+    let deliver = match port.buffering() {
+        PortBuffering::None => true,
+        PortBuffering::Line => s.contains('\n'),
+        // File handles and the process streams fill their own buffers
+        PortBuffering::Block => port.buffer.read().unwrap().len() >= PORT_BLOCK_SIZE,
+    };
+    if deliver {
+        flush_port(port)?;
+    }
+    Ok(())
 }
 
-/// Writes a byte to a port.
-fn write_u8_to_port(port: &Port, byte: u8) -> Result<()> {
+/// Delivers buffered output of a port to its destination.
+fn flush_port(port: &Port) -> Result<()> {
+    let flush_error = |e: std::io::Error| {
+        Box::new(DiagnosticError::runtime_error(
+            format!("flush-output-port: {e}"),
+            None,
+        ))
+    };
+    
     match &port.implementation {
-        PortImpl::Bytevector { content, .. } => {
-            content.write().unwrap().push(byte);
+        PortImpl::String { content, .. } => {
+            let pending = std::mem::take(&mut *port.buffer.write().unwrap());
+            content.write().unwrap().push_str(&String::from_utf8_lossy(&pending));
             Ok(())
         }
+        PortImpl::Standard(StandardPort::Stdout) => std::io::stdout().flush().map_err(flush_error),
+        PortImpl::Standard(StandardPort::Stderr) => std::io::stderr().flush().map_err(flush_error),
+        PortImpl::File { handle, .. } => match handle.write().unwrap().as_mut() {
+            Some(PortFileHandle::TextWriter(writer) | PortFileHandle::BinaryWriter(writer)) => {
+                writer.flush().map_err(flush_error)
+            }
+            _ => Ok(()),
+        },
+        _ => Ok(()),
+    }
+}
+
+/// Writes a byte to a port.
+fn write_u8_to_port(port: &Port, byte: u8) -> Result<()> {
+    match &port.implementation {
+        PortImpl::Bytevector { .. } => write_bytevector_to_port(port, &[byte]),
         _ => Err(Box::new(DiagnosticError::runtime_error(
             "write-u8: unsupported port type".to_string(),
             None,
@@ -2429,8 +2912,17 @@ fn read_bytevector_bang_from_port(port: &Port, bytevector: &mut [u8], start: usi
 /// Writes a bytevector to a port.
 fn write_bytevector_to_port(port: &Port, bytes: &[u8]) -> Result<()> {
     match &port.implementation {
-        PortImpl::Bytevector { content, .. } => {
-            content.write().unwrap().extend_from_slice(bytes);
+        PortImpl::Bytevector { content, position } => {
+            // Write at the port position, overwriting and then extending
+            let mut content = content.write().unwrap();
+            let mut position = position.write().unwrap();
+            if content.len() < *position {
+                content.resize(*position, 0);
+            }
+            let overlap = bytes.len().min(content.len() - *position);
+            content[*position..*position + overlap].copy_from_slice(&bytes[..overlap]);
+            content.extend_from_slice(&bytes[overlap..]);
+            *position += bytes.len();
             Ok(())
         }
         _ => Err(Box::new(DiagnosticError::runtime_error(
@@ -2449,6 +2941,100 @@ fn display_value(value: &Value) -> String {
 mod tests {
     use super::*;
 
+    fn symbol(name: &str) -> Value {
+        Value::Symbol(crate::utils::intern_symbol(name))
+    }
+
+    fn read_all_chars(port: &Value) -> Result<String> {
+        let mut text = String::new();
+        loop {
+            let value = primitive_read_char(std::slice::from_ref(port))?;
+            match value {
+                Value::Literal(crate::ast::Literal::Character(ch)) => text.push(ch),
+                _ => return Ok(text),
+            }
+        }
+    }
+
+    #[test]
+    fn test_latin1_file_round_trip() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("latin1.txt").to_string_lossy().to_string();
+        let text = "Grüße, café ÿ";
+
+        let out = primitive_open_output_file(&[Value::string(path.clone()), symbol("latin-1")]).unwrap();
+        primitive_write_string(&[Value::string(text), out.clone()]).unwrap();
+        primitive_close_port(&[out]).unwrap();
+
+        // One byte per character on disk
+        let bytes = std::fs::read(&path).unwrap();
+        assert_eq!(bytes.len(), text.chars().count());
+
+        let input = primitive_open_input_file(&[Value::string(path), symbol("latin-1")]).unwrap();
+        assert_eq!(
+            primitive_peek_char(std::slice::from_ref(&input)).unwrap(),
+            Value::Literal(crate::ast::Literal::Character('G'))
+        );
+        assert_eq!(read_all_chars(&input).unwrap(), text);
+    }
+
+    #[test]
+    fn test_latin1_unrepresentable_character_errors() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("out.txt").to_string_lossy().to_string();
+
+        let out = primitive_open_output_file(&[Value::string(path.clone()), symbol("latin-1")]).unwrap();
+        let ch = Value::Literal(crate::ast::Literal::Character('€'));
+        assert!(primitive_write_char(&[ch, out.clone()]).is_err());
+        primitive_close_port(&[out]).unwrap();
+        assert!(std::fs::read(&path).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_invalid_utf8_strict_and_lenient() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("bad.txt");
+        std::fs::write(&path, [b'o', b'k', 0xC3, b'!']).unwrap();
+        let path = path.to_string_lossy().to_string();
+
+        let strict = primitive_open_input_file(&[Value::string(path.clone())]).unwrap();
+        let err = read_all_chars(&strict).unwrap_err();
+        assert!(err.to_string().contains("invalid utf-8"));
+
+        let lenient = primitive_open_input_file(&[
+            Value::string(path),
+            symbol("utf-8"),
+            symbol("lenient"),
+        ]).unwrap();
+        assert_eq!(read_all_chars(&lenient).unwrap(), "ok\u{FFFD}!");
+    }
+
+    #[test]
+    fn test_utf16_byte_order_mark_is_skipped() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("utf16.txt");
+        let mut bytes = vec![0xFF, 0xFE];
+        for unit in "hé\nλ".encode_utf16() {
+            bytes.extend_from_slice(&unit.to_le_bytes());
+        }
+        std::fs::write(&path, bytes).unwrap();
+
+        let input = primitive_open_input_file(&[
+            Value::string(path.to_string_lossy().to_string()),
+            symbol("utf-16le"),
+        ]).unwrap();
+        assert_eq!(primitive_read_line(std::slice::from_ref(&input)).unwrap(), Value::string("hé"));
+        assert_eq!(read_all_chars(&input).unwrap(), "λ");
+    }
+
+    #[test]
+    fn test_open_file_rejects_unknown_encoding() {
+        let result = primitive_open_input_file(&[Value::string("/nonexistent"), symbol("ebcdic")]);
+        assert!(result.is_err());
+        let result = primitive_open_input_file(&[Value::string("/nonexistent"), symbol("utf-8"), symbol("sloppy")]);
+        assert!(result.is_err());
+    }
+
     #[test]
     fn test_string_port_creation() {
         let port = Port::new_string_input("hello".to_string());
@@ -2479,6 +3065,10 @@ mod tests {
         let args = vec![Value::integer(42)];
         let result = primitive_eof_object_p(&args).unwrap();
         assert_eq!(result, Value::boolean(false));
+        
+        assert_eq!(eof, primitive_eof_object(&[]).unwrap());
+        let args = vec![Value::symbol_from_str("*eof*")];
+        assert_eq!(primitive_eof_object_p(&args).unwrap(), Value::boolean(false));
     }
     
     #[test]
@@ -2919,4 +3509,124 @@ mod tests {
             }
         }
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_read_and_open_failures_are_classified() {
+        let is_error_type = |result: Result<Value>, error_type: &str| match result.map_err(|e| *e) {
+            Err(DiagnosticError::Exception { exception, .. }) => exception.exception_type == error_type,
+            _ => false,
+        };
+        
+        let port = primitive_open_input_string(&[Value::string("(1 2")]).unwrap();
+        assert!(is_error_type(primitive_read(&[port]), "read-error"));
+        
+        let port = primitive_open_input_string(&[Value::string("   ")]).unwrap();
+        assert_eq!(primitive_read(&[port]).unwrap(), eof_value());
+        
+        let missing = Value::string("/nonexistent/lambdust-missing-file.scm");
+        assert!(is_error_type(primitive_open_input_file(&[missing.clone()]), "file-error"));
+        assert!(is_error_type(primitive_open_binary_input_file(&[missing]), "file-error"));
+    }
+
+    #[test]
+    fn test_port_buffering_modes() {
+        let captured = |port: &Port| match &port.implementation {
+            PortImpl::String { content, .. } => content.read().unwrap().clone(),
+            _ => panic!("Expected string port"),
+        };
+        let set_mode = |port: &Value, mode: &str| {
+            primitive_set_port_buffering(&[port.clone(), Value::symbol_from_str(mode)]).unwrap();
+        };
+        
+        let out_port = Arc::new(Port::new_string_output());
+        let out_port_value = Value::Port(out_port.clone());
+        
+        // A prompt without a newline is visible immediately when unbuffered
+        set_mode(&out_port_value, "none");
+        primitive_display(&[Value::string("> "), out_port_value.clone()]).unwrap();
+        assert_eq!(captured(&out_port), "> ");
+        
+        // Line buffering holds output until a newline is written
+        set_mode(&out_port_value, "line");
+        primitive_display(&[Value::string("partial"), out_port_value.clone()]).unwrap();
+        assert_eq!(captured(&out_port), "> ");
+        primitive_newline(&[out_port_value.clone()]).unwrap();
+        assert_eq!(captured(&out_port), "> partial\n");
+        
+        // Block buffering holds output until flushed
+        set_mode(&out_port_value, "block");
+        primitive_display(&[Value::string("held"), out_port_value.clone()]).unwrap();
+        assert_eq!(captured(&out_port), "> partial\n");
+        primitive_flush_output_port(&[out_port_value.clone()]).unwrap();
+        assert_eq!(captured(&out_port), "> partial\nheld");
+        
+        let bad_mode = primitive_set_port_buffering(&[out_port_value, Value::symbol_from_str("full")]);
+        assert!(bad_mode.is_err());
+    }
+
+    #[test]
+    fn test_bytevector_port_positioning() {
+        let input = primitive_open_input_bytevector(&[Value::bytevector(vec![10, 20, 30, 40])]).unwrap();
+        primitive_read_u8(std::slice::from_ref(&input)).unwrap();
+        assert_eq!(primitive_port_position(std::slice::from_ref(&input)).unwrap(), Value::integer(1));
+        
+        // Reading resumes from the new position
+        primitive_set_port_position(&[input.clone(), Value::integer(3)]).unwrap();
+        assert_eq!(primitive_read_u8(std::slice::from_ref(&input)).unwrap(), Value::integer(40));
+        primitive_set_port_position(&[input.clone(), Value::integer(0)]).unwrap();
+        assert_eq!(primitive_read_u8(std::slice::from_ref(&input)).unwrap(), Value::integer(10));
+        assert!(primitive_set_port_position(&[input.clone(), Value::integer(5)]).is_err());
+        
+        // Writes overwrite at the position and zero-fill past the end
+        let output = primitive_open_output_bytevector(&[]).unwrap();
+        for byte in [1, 2, 3] {
+            primitive_write_u8(&[Value::integer(byte), output.clone()]).unwrap();
+        }
+        primitive_set_port_position(&[output.clone(), Value::integer(1)]).unwrap();
+        primitive_write_u8(&[Value::integer(9), output.clone()]).unwrap();
+        primitive_set_port_position(&[output.clone(), Value::integer(5)]).unwrap();
+        primitive_write_u8(&[Value::integer(7), output.clone()]).unwrap();
+        assert_eq!(
+            primitive_get_output_bytevector(&[output]).unwrap(),
+            Value::bytevector(vec![1, 9, 3, 0, 0, 7])
+        );
+        
+        primitive_close_port(std::slice::from_ref(&input)).unwrap();
+        assert!(primitive_port_position(&[input]).is_err());
+    }
+    
+    #[test]
+    fn test_file_port_positioning() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("seek.txt").to_string_lossy().to_string();
+        
+        // Seeking past the end of a file extends it on write
+        let out = primitive_open_output_file(&[Value::string(path.clone())]).unwrap();
+        primitive_write_string(&[Value::string("A"), out.clone()]).unwrap();
+        primitive_set_port_position(&[out.clone(), Value::integer(3)]).unwrap();
+        primitive_write_string(&[Value::string("B"), out.clone()]).unwrap();
+        assert_eq!(primitive_port_position(std::slice::from_ref(&out)).unwrap(), Value::integer(4));
+        primitive_close_port(&[out]).unwrap();
+        assert_eq!(std::fs::read(&path).unwrap(), vec![65, 0, 0, 66]);
+        
+        std::fs::write(&path, "héllo").unwrap();
+        let input = primitive_open_input_file(&[Value::string(path)]).unwrap();
+        primitive_peek_char(std::slice::from_ref(&input)).unwrap();
+        primitive_read_char(std::slice::from_ref(&input)).unwrap();
+        primitive_read_char(std::slice::from_ref(&input)).unwrap();
+        assert_eq!(primitive_port_position(std::slice::from_ref(&input)).unwrap(), Value::integer(3));
+        primitive_set_port_position(&[input.clone(), Value::integer(1)]).unwrap();
+        assert_eq!(read_all_chars(&input).unwrap(), "éllo");
+    }
+    
+    #[test]
+    fn test_string_ports_are_not_seekable() {
+        let input = primitive_open_input_string(&[Value::string("abc")]).unwrap();
+        assert!(primitive_port_position(std::slice::from_ref(&input)).is_err());
+        assert!(primitive_set_port_position(&[input.clone(), Value::integer(0)]).is_err());
+        assert_eq!(primitive_port_has_position(&[input]).unwrap(), Value::boolean(false));
+        
+        let bytes = primitive_open_input_bytevector(&[Value::bytevector(vec![])]).unwrap();
+        assert_eq!(primitive_port_has_position(&[bytes]).unwrap(), Value::boolean(true));
+    }
+}
@@ -32,8 +32,7 @@ pub fn create_list_bindings(env: &Arc<ThreadSafeEnvironment>) {
     bind_list_utilities(env);
     
     // SRFI-1 extensions
-    // TODO: Implement SRFI-1 extensions
-    // bind_srfi1_extensions(env);
+    bind_srfi1_extensions(env);
 }
 
 /// Binds basic list construction and deconstruction operations.
@@ -127,6 +126,24 @@ fn bind_list_predicates(env: &Arc<ThreadSafeEnvironment>) {
         implementation: PrimitiveImpl::RustFn(primitive_list_p),
         effects: vec![Effect::Pure],
     })));
+    
+    // circular-list?
+    env.define("circular-list?".to_string(), Value::Primitive(Arc::new(PrimitiveProcedure {
+        name: "circular-list?".to_string(),
+        arity_min: 1,
+        arity_max: Some(1),
+        implementation: PrimitiveImpl::RustFn(primitive_circular_list_p),
+        effects: vec![Effect::Pure],
+    })));
+    
+    // dotted-list?
+    env.define("dotted-list?".to_string(), Value::Primitive(Arc::new(PrimitiveProcedure {
+        name: "dotted-list?".to_string(),
+        arity_min: 1,
+        arity_max: Some(1),
+        implementation: PrimitiveImpl::RustFn(primitive_dotted_list_p),
+        effects: vec![Effect::Pure],
+    })));
 }
 
 /// Binds list accessor functions.
@@ -258,6 +275,101 @@ fn bind_list_manipulation(env: &Arc<ThreadSafeEnvironment>) {
     })));
 }
 
+/// Binds SRFI-1 positional selectors, unfolds and association-list utilities.
+fn bind_srfi1_extensions(env: &Arc<ThreadSafeEnvironment>) {
+    // take
+    env.define("take".to_string(), Value::Primitive(Arc::new(PrimitiveProcedure {
+        name: "take".to_string(),
+        arity_min: 2,
+        arity_max: Some(2),
+        implementation: PrimitiveImpl::RustFn(srfi1_take),
+        effects: vec![Effect::Pure],
+    })));
+    
+    // drop
+    env.define("drop".to_string(), Value::Primitive(Arc::new(PrimitiveProcedure {
+        name: "drop".to_string(),
+        arity_min: 2,
+        arity_max: Some(2),
+        implementation: PrimitiveImpl::RustFn(srfi1_drop),
+        effects: vec![Effect::Pure],
+    })));
+    
+    // take-right
+    env.define("take-right".to_string(), Value::Primitive(Arc::new(PrimitiveProcedure {
+        name: "take-right".to_string(),
+        arity_min: 2,
+        arity_max: Some(2),
+        implementation: PrimitiveImpl::RustFn(srfi1_take_right),
+        effects: vec![Effect::Pure],
+    })));
+    
+    // drop-right
+    env.define("drop-right".to_string(), Value::Primitive(Arc::new(PrimitiveProcedure {
+        name: "drop-right".to_string(),
+        arity_min: 2,
+        arity_max: Some(2),
+        implementation: PrimitiveImpl::RustFn(srfi1_drop_right),
+        effects: vec![Effect::Pure],
+    })));
+    
+    // unfold
+    env.define("unfold".to_string(), Value::Primitive(Arc::new(PrimitiveProcedure {
+        name: "unfold".to_string(),
+        arity_min: 4,
+        arity_max: Some(5),
+        implementation: PrimitiveImpl::EvaluatorIntegrated(evaluator_unfold),
+        effects: vec![Effect::Pure], // May call user functions with effects
+    })));
+    
+    // unfold-right
+    env.define("unfold-right".to_string(), Value::Primitive(Arc::new(PrimitiveProcedure {
+        name: "unfold-right".to_string(),
+        arity_min: 4,
+        arity_max: Some(5),
+        implementation: PrimitiveImpl::EvaluatorIntegrated(evaluator_unfold_right),
+        effects: vec![Effect::Pure], // May call user functions with effects
+    })));
+    
+    // alist-copy
+    env.define("alist-copy".to_string(), Value::Primitive(Arc::new(PrimitiveProcedure {
+        name: "alist-copy".to_string(),
+        arity_min: 1,
+        arity_max: Some(1),
+        implementation: PrimitiveImpl::RustFn(srfi1_alist_copy),
+        effects: vec![Effect::Pure],
+    })));
+    
+    // alist-delete and its linear-update variant, which need not mutate
+    for name in ["alist-delete", "alist-delete!"] {
+        env.define(name.to_string(), Value::Primitive(Arc::new(PrimitiveProcedure {
+            name: name.to_string(),
+            arity_min: 2,
+            arity_max: Some(3),
+            implementation: PrimitiveImpl::EvaluatorIntegrated(evaluator_alist_delete),
+            effects: vec![Effect::Pure], // May call a user comparator
+        })));
+    }
+    
+    // del-assq
+    env.define("del-assq".to_string(), Value::Primitive(Arc::new(PrimitiveProcedure {
+        name: "del-assq".to_string(),
+        arity_min: 2,
+        arity_max: Some(2),
+        implementation: PrimitiveImpl::RustFn(srfi1_del_assq),
+        effects: vec![Effect::Pure],
+    })));
+    
+    // del-assv
+    env.define("del-assv".to_string(), Value::Primitive(Arc::new(PrimitiveProcedure {
+        name: "del-assv".to_string(),
+        arity_min: 2,
+        arity_max: Some(2),
+        implementation: PrimitiveImpl::RustFn(srfi1_del_assv),
+        effects: vec![Effect::Pure],
+    })));
+}
+
 /// Binds higher-order list functions.
 fn bind_higher_order_functions(env: &Arc<ThreadSafeEnvironment>) {
     // map
@@ -462,7 +574,8 @@ fn primitive_cdr(args: &[Value]) -> Result<Value> {
 
 /// list constructor
 fn primitive_list(args: &[Value]) -> Result<Value> {
-    Ok(Value::list(args.to_vec()))
+    // A fresh list, so list-set! and set-car! may modify it
+    Ok(Value::mutable_list(args.to_vec()))
 }
 
 /// list* procedure (improper list constructor)
@@ -517,7 +630,7 @@ fn primitive_make_list(args: &[Value]) -> Result<Value> {
     };
     
     let elements = vec![fill; length as usize];
-    Ok(Value::list(elements))
+    Ok(Value::mutable_list(elements))
 }
 
 // ============= LIST PREDICATE IMPLEMENTATIONS =============
@@ -558,30 +671,49 @@ fn primitive_list_p(args: &[Value]) -> Result<Value> {
     Ok(Value::boolean(is_proper_list(&args[0])))
 }
 
+/// circular-list? predicate (SRFI-1)
+fn primitive_circular_list_p(args: &[Value]) -> Result<Value> {
+    Ok(Value::boolean(is_circular_list(&args[0])))
+}
+
+/// dotted-list? predicate (SRFI-1)
+fn primitive_dotted_list_p(args: &[Value]) -> Result<Value> {
+    Ok(Value::boolean(is_dotted_list(&args[0])))
+}
+
 // ============= LIST ACCESSOR IMPLEMENTATIONS =============
 
 /// Creates a car/cdr combination function
 fn make_car_cdr_combination(name: &str) -> fn(&[Value]) -> Result<Value> {
-    // This is a simplified implementation
-    // In practice, you'd generate these dynamically
     match name {
-        "caar" => |args| {
-            let result = primitive_car(args)?;
-            primitive_car(&[result])
-        },
-        "cadr" => |args| {
-            let result = primitive_cdr(args)?;
-            primitive_car(&[result])
-        },
-        "cdar" => |args| {
-            let result = primitive_car(args)?;
-            primitive_cdr(&[result])
-        },
-        "cddr" => |args| {
-            let result = primitive_cdr(args)?;
-            primitive_cdr(&[result])
-        },
-        // Add more combinations as needed
+        "caar" => |args| car_cdr_path(args, "aa"),
+        "cadr" => |args| car_cdr_path(args, "ad"),
+        "cdar" => |args| car_cdr_path(args, "da"),
+        "cddr" => |args| car_cdr_path(args, "dd"),
+        "caaar" => |args| car_cdr_path(args, "aaa"),
+        "caadr" => |args| car_cdr_path(args, "aad"),
+        "cadar" => |args| car_cdr_path(args, "ada"),
+        "caddr" => |args| car_cdr_path(args, "add"),
+        "cdaar" => |args| car_cdr_path(args, "daa"),
+        "cdadr" => |args| car_cdr_path(args, "dad"),
+        "cddar" => |args| car_cdr_path(args, "dda"),
+        "cdddr" => |args| car_cdr_path(args, "ddd"),
+        "caaaar" => |args| car_cdr_path(args, "aaaa"),
+        "caaadr" => |args| car_cdr_path(args, "aaad"),
+        "caadar" => |args| car_cdr_path(args, "aada"),
+        "caaddr" => |args| car_cdr_path(args, "aadd"),
+        "cadaar" => |args| car_cdr_path(args, "adaa"),
+        "cadadr" => |args| car_cdr_path(args, "adad"),
+        "caddar" => |args| car_cdr_path(args, "adda"),
+        "cadddr" => |args| car_cdr_path(args, "addd"),
+        "cdaaar" => |args| car_cdr_path(args, "daaa"),
+        "cdaadr" => |args| car_cdr_path(args, "daad"),
+        "cdadar" => |args| car_cdr_path(args, "dada"),
+        "cdaddr" => |args| car_cdr_path(args, "dadd"),
+        "cddaar" => |args| car_cdr_path(args, "ddaa"),
+        "cddadr" => |args| car_cdr_path(args, "ddad"),
+        "cdddar" => |args| car_cdr_path(args, "ddda"),
+        "cddddr" => |args| car_cdr_path(args, "dddd"),
         _ => {
             fn unknown_combination(_args: &[Value]) -> Result<Value> {
                 Err(Box::new(DiagnosticError::runtime_error(
@@ -594,6 +726,16 @@ fn make_car_cdr_combination(name: &str) -> fn(&[Value]) -> Result<Value> {
     }
 }
 
+/// Applies the accessors named by `path` (the letters between `c` and `r`)
+/// from right to left, so `"ad"` is `cadr`.
+fn car_cdr_path(args: &[Value], path: &str) -> Result<Value> {
+    let mut value = args[0].clone();
+    for step in path.chars().rev() {
+        value = if step == 'a' { primitive_car(&[value])? } else { primitive_cdr(&[value])? };
+    }
+    Ok(value)
+}
+
 /// list-ref procedure
 fn primitive_list_ref(args: &[Value]) -> Result<Value> {
     if args.len() != 2 {
@@ -699,14 +841,14 @@ fn primitive_list_tail(args: &[Value]) -> Result<Value> {
     let mut i = 0;
     
     while i < k {
-        match current {
-            Value::Pair(_, cdr) => {
-                current = cdr.as_ref().clone();
+        match pair_parts(&current) {
+            Some((_, cdr)) => {
+                current = cdr;
                 i += 1;
             }
-            _ => {
+            None => {
                 return Err(Box::new(DiagnosticError::runtime_error(
-                    "list-tail k out of bounds".to_string(),
+                    format!("list-tail index {k} out of bounds for list of length {i}"),
                     None,
                 )));
             }
@@ -769,36 +911,34 @@ fn primitive_length(args: &[Value]) -> Result<Value> {
 }
 
 /// append procedure
+///
+/// Walks the spine of each argument but the last once, then builds the
+/// result back to front onto the last argument, which is shared rather than
+/// copied as R7RS requires.
 fn primitive_append(args: &[Value]) -> Result<Value> {
-    if args.is_empty() {
+    let Some((last, prefixes)) = args.split_last() else {
         return Ok(Value::Nil);
-    }
-    
-    if args.len() == 1 {
-        return Ok(args[0].clone());
-    }
-    
-    // All but the last argument must be proper lists
-    for arg in &args[..args.len() - 1] {
-        if !is_proper_list(arg) {
-            return Err(Box::new(DiagnosticError::runtime_error(
-                "append arguments (except the last) must be proper lists".to_string(),
-                None,
-            )));
-        }
-    }
-    
-    let mut result = args[args.len() - 1].clone();
+    };
     
-    for arg in args[..args.len() - 1].iter().rev() {
-        if let Some(list) = arg.as_list() {
-            for item in list.into_iter().rev() {
-                result = Value::pair(item, result);
-            }
+    let mut elements = Vec::new();
+    for arg in prefixes {
+        let mut current = arg.clone();
+        while !current.is_nil() {
+            let (car, cdr) = pair_parts(&current).ok_or_else(|| {
+                Box::new(DiagnosticError::runtime_error(
+                    "append arguments (except the last) must be proper lists".to_string(),
+                    None,
+                ))
+            })?;
+            elements.push(car);
+            current = cdr;
         }
     }
     
-    Ok(result)
+    Ok(elements
+        .into_iter()
+        .rev()
+        .fold(last.clone(), |tail, item| Value::pair(item, tail)))
 }
 
 /// reverse procedure
@@ -917,51 +1057,22 @@ fn primitive_list_set(args: &[Value]) -> Result<Value> {
         )));
     }
     
-    let mut current = &args[0];
-    let mut steps_remaining = index;
-    
-    // Navigate to the target pair
-    while steps_remaining > 0 {
-        match current {
-            Value::MutablePair(_, cdr_ref) => {
-                if let Ok(cdr) = cdr_ref.read() {
-                    let cdr_clone = cdr.clone();
-                    drop(cdr); // Release the lock
-                    match cdr_clone {
-                        Value::MutablePair(_, _) | Value::Pair(_, _) => {
-                            // We need to continue with a reference, but we can't store it
-                            // This is a limitation of our approach - we'll need to navigate again
-                            return set_list_element(&args[0], index, args[2].clone());
-                        }
-                        _ => {
-                            return Err(Box::new(DiagnosticError::runtime_error(
-                                "list-set! index out of bounds".to_string(),
-                                None,
-                            )));
-                        }
-                    }
-                } else {
-                    return Err(Box::new(DiagnosticError::runtime_error(
-                        "list-set! failed to acquire read lock".to_string(),
-                        None,
-                    )));
-                }
-            }
-            Value::Pair(_, cdr) => {
-                current = cdr;
-                steps_remaining -= 1;
-            }
-            _ => {
+    // Walk to the target pair; the cdr chain may mix mutable and immutable pairs
+    let mut current = args[0].clone();
+    for i in 0..index {
+        match pair_parts(&current) {
+            Some((_, cdr)) => current = cdr,
+            None => {
                 return Err(Box::new(DiagnosticError::runtime_error(
-                    "list-set! index out of bounds".to_string(),
+                    format!("list-set! index {index} out of bounds for list of length {i}"),
                     None,
                 )));
             }
         }
     }
     
-    // Set the car of the current pair
-    match current {
+    // Set the car of the target pair
+    match &current {
         Value::MutablePair(car_ref, _) => {
             if let Ok(mut car) = car_ref.write() {
                 *car = args[2].clone();
@@ -973,9 +1084,15 @@ fn primitive_list_set(args: &[Value]) -> Result<Value> {
                 )))
             }
         }
+        Value::Pair(_, _) => {
+            Err(Box::new(DiagnosticError::runtime_error(
+                "list-set! requires a mutable list (immutable pair given)".to_string(),
+                None,
+            )))
+        }
         _ => {
             Err(Box::new(DiagnosticError::runtime_error(
-                "list-set! requires a mutable list".to_string(),
+                format!("list-set! index {index} out of bounds for list of length {index}"),
                 None,
             )))
         }
@@ -995,51 +1112,6 @@ fn primitive_list_copy(args: &[Value]) -> Result<Value> {
     copy_list(&args[0])
 }
 
-/// Helper function to set an element in a list by index
-fn set_list_element(list: &Value, index: i64, value: Value) -> Result<Value> {
-    if index == 0 {
-        match list {
-            Value::MutablePair(car_ref, _) => {
-                if let Ok(mut car) = car_ref.write() {
-                    *car = value;
-                    Ok(Value::Unspecified)
-                } else {
-                    Err(Box::new(DiagnosticError::runtime_error(
-                        "set-list-element failed to acquire write lock".to_string(),
-                        None,
-                    )))
-                }
-            }
-            _ => Err(Box::new(DiagnosticError::runtime_error(
-                "set-list-element requires a mutable list".to_string(),
-                None,
-            )))
-        }
-    } else {
-        match list {
-            Value::MutablePair(_, cdr_ref) => {
-                if let Ok(cdr) = cdr_ref.read() {
-                    let cdr_clone = cdr.clone();
-                    drop(cdr); // Release the lock
-                    set_list_element(&cdr_clone, index - 1, value)
-                } else {
-                    Err(Box::new(DiagnosticError::runtime_error(
-                        "set-list-element failed to acquire read lock".to_string(),
-                        None,
-                    )))
-                }
-            }
-            Value::Pair(_, cdr) => {
-                set_list_element(cdr, index - 1, value)
-            }
-            _ => Err(Box::new(DiagnosticError::runtime_error(
-                "set-list-element index out of bounds".to_string(),
-                None,
-            )))
-        }
-    }
-}
-
 // ============= HIGHER-ORDER FUNCTION IMPLEMENTATIONS =============
 
 /// map procedure - Enhanced R7RS implementation supporting multiple lists
@@ -1491,29 +1563,9 @@ fn primitive_member(args: &[Value]) -> Result<Value> {
         )));
     }
     
-    let obj = &args[0];
-    let mut current = &args[1];
-    
     // For now, we'll use default equality (equal?)
     // TODO: Handle custom comparison function when provided
-    
-    loop {
-        match current {
-            Value::Nil => return Ok(Value::boolean(false)),
-            Value::Pair(car, cdr) => {
-                if values_equal(obj, car) {
-                    return Ok(current.clone());
-                }
-                current = cdr;
-            }
-            _ => {
-                return Err(Box::new(DiagnosticError::runtime_error(
-                    "member requires a proper list".to_string(),
-                    None,
-                )));
-            }
-        }
-    }
+    find_member(&args[0], &args[1], "member", values_equal)
 }
 
 /// memq procedure (eq? comparison)
@@ -1525,26 +1577,7 @@ fn primitive_memq(args: &[Value]) -> Result<Value> {
         )));
     }
     
-    let obj = &args[0];
-    let mut current = &args[1];
-    
-    loop {
-        match current {
-            Value::Nil => return Ok(Value::boolean(false)),
-            Value::Pair(car, cdr) => {
-                if values_eq(obj, car) {
-                    return Ok(current.clone());
-                }
-                current = cdr;
-            }
-            _ => {
-                return Err(Box::new(DiagnosticError::runtime_error(
-                    "memq requires a proper list".to_string(),
-                    None,
-                )));
-            }
-        }
-    }
+    find_member(&args[0], &args[1], "memq", values_eq)
 }
 
 /// memv procedure (eqv? comparison)
@@ -1556,26 +1589,7 @@ fn primitive_memv(args: &[Value]) -> Result<Value> {
         )));
     }
     
-    let obj = &args[0];
-    let mut current = &args[1];
-    
-    loop {
-        match current {
-            Value::Nil => return Ok(Value::boolean(false)),
-            Value::Pair(car, cdr) => {
-                if values_eqv(obj, car) {
-                    return Ok(current.clone());
-                }
-                current = cdr;
-            }
-            _ => {
-                return Err(Box::new(DiagnosticError::runtime_error(
-                    "memv requires a proper list".to_string(),
-                    None,
-                )));
-            }
-        }
-    }
+    find_member(&args[0], &args[1], "memv", values_eqv)
 }
 
 /// assoc procedure
@@ -1587,36 +1601,7 @@ fn primitive_assoc(args: &[Value]) -> Result<Value> {
         )));
     }
     
-    let obj = &args[0];
-    let mut current = &args[1];
-    
-    loop {
-        match current {
-            Value::Nil => return Ok(Value::boolean(false)),
-            Value::Pair(car, cdr) => {
-                match car.as_ref() {
-                    Value::Pair(key, _) => {
-                        if values_equal(obj, key) {
-                            return Ok((**car).clone());
-                        }
-                    }
-                    _ => {
-                        return Err(Box::new(DiagnosticError::runtime_error(
-                            "assoc requires a list of pairs".to_string(),
-                            None,
-                        )));
-                    }
-                }
-                current = cdr;
-            }
-            _ => {
-                return Err(Box::new(DiagnosticError::runtime_error(
-                    "assoc requires a proper list".to_string(),
-                    None,
-                )));
-            }
-        }
-    }
+    find_association(&args[0], &args[1], "assoc", values_equal)
 }
 
 /// assq procedure (eq? comparison)
@@ -1628,36 +1613,7 @@ fn primitive_assq(args: &[Value]) -> Result<Value> {
         )));
     }
     
-    let obj = &args[0];
-    let mut current = &args[1];
-    
-    loop {
-        match current {
-            Value::Nil => return Ok(Value::boolean(false)),
-            Value::Pair(car, cdr) => {
-                match car.as_ref() {
-                    Value::Pair(key, _) => {
-                        if values_eq(obj, key) {
-                            return Ok((**car).clone());
-                        }
-                    }
-                    _ => {
-                        return Err(Box::new(DiagnosticError::runtime_error(
-                            "assq requires a list of pairs".to_string(),
-                            None,
-                        )));
-                    }
-                }
-                current = cdr;
-            }
-            _ => {
-                return Err(Box::new(DiagnosticError::runtime_error(
-                    "assq requires a proper list".to_string(),
-                    None,
-                )));
-            }
-        }
-    }
+    find_association(&args[0], &args[1], "assq", values_eq)
 }
 
 /// assv procedure (eqv? comparison)
@@ -1669,36 +1625,41 @@ fn primitive_assv(args: &[Value]) -> Result<Value> {
         )));
     }
     
-    let obj = &args[0];
-    let mut current = &args[1];
-    
-    loop {
-        match current {
-            Value::Nil => return Ok(Value::boolean(false)),
-            Value::Pair(car, cdr) => {
-                match car.as_ref() {
-                    Value::Pair(key, _) => {
-                        if values_eqv(obj, key) {
-                            return Ok((**car).clone());
-                        }
-                    }
-                    _ => {
-                        return Err(Box::new(DiagnosticError::runtime_error(
-                            "assv requires a list of pairs".to_string(),
-                            None,
-                        )));
-                    }
-                }
-                current = cdr;
-            }
-            _ => {
-                return Err(Box::new(DiagnosticError::runtime_error(
-                    "assv requires a proper list".to_string(),
-                    None,
-                )));
-            }
+    find_association(&args[0], &args[1], "assv", values_eqv)
+}
+
+/// Returns the first tail of `list` whose car is `same` as `obj`, or #f.
+fn find_member(obj: &Value, list: &Value, name: &str, same: fn(&Value, &Value) -> bool) -> Result<Value> {
+    let mut current = list.clone();
+    while !current.is_nil() {
+        let (car, cdr) = pair_parts(&current).ok_or_else(|| {
+            DiagnosticError::runtime_error(format!("{name} requires a proper list"), None)
+        })?;
+        if same(obj, &car) {
+            return Ok(current);
         }
+        current = cdr;
     }
+    Ok(Value::boolean(false))
+}
+
+/// Returns the first pair of the association list `alist` whose key is
+/// `same` as `obj`, or #f.
+fn find_association(obj: &Value, alist: &Value, name: &str, same: fn(&Value, &Value) -> bool) -> Result<Value> {
+    let mut current = alist.clone();
+    while !current.is_nil() {
+        let (entry, cdr) = pair_parts(&current).ok_or_else(|| {
+            DiagnosticError::runtime_error(format!("{name} requires a proper list"), None)
+        })?;
+        let (key, _) = pair_parts(&entry).ok_or_else(|| {
+            DiagnosticError::runtime_error(format!("{name} requires a list of pairs"), None)
+        })?;
+        if same(obj, &key) {
+            return Ok(entry);
+        }
+        current = cdr;
+    }
+    Ok(Value::boolean(false))
 }
 
 /// sort procedure
@@ -1720,49 +1681,92 @@ fn primitive_sort(args: &[Value]) -> Result<Value> {
 
 // ============= HELPER FUNCTIONS =============
 
-/// Checks if a value is a proper list.
-fn is_proper_list(value: &Value) -> bool {
-    let mut current = value;
-    
+/// The structure of a chain of pairs, as classified by [`list_shape`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ListShape {
+    /// Terminated by the empty list
+    Proper,
+    /// Terminated by a non-pair other than the empty list
+    Dotted,
+    /// The cdr chain loops back on itself
+    Circular,
+}
+
+/// Classifies a value by following its cdr chain with Floyd's
+/// tortoise-and-hare, so cyclic structure is detected without looping.
+fn list_shape(value: &Value) -> ListShape {
+    let mut slow = value.clone();
+    let mut fast = value.clone();
+
     loop {
-        match current {
-            Value::Nil => return true,
-            Value::Pair(_, cdr) => {
-                current = cdr;
-            }
-            _ => return false,
+        for _ in 0..2 {
+            fast = match pair_parts(&fast) {
+                Some((_, cdr)) => cdr,
+                None if fast.is_nil() => return ListShape::Proper,
+                None => return ListShape::Dotted,
+            };
+        }
+        // The hare has already walked these pairs, so the tortoise can always step
+        if let Some((_, cdr)) = pair_parts(&slow) {
+            slow = cdr;
+        }
+        if same_pair(&slow, &fast) {
+            return ListShape::Circular;
         }
     }
 }
 
-/// Copies a list (shallow copy).
-fn copy_list(value: &Value) -> Result<Value> {
+/// Returns true if both values are the same pair object.
+fn same_pair(a: &Value, b: &Value) -> bool {
+    match (a, b) {
+        (Value::Pair(_, a_cdr), Value::Pair(_, b_cdr)) => Arc::ptr_eq(a_cdr, b_cdr),
+        (Value::MutablePair(_, a_cdr), Value::MutablePair(_, b_cdr)) => Arc::ptr_eq(a_cdr, b_cdr),
+        _ => false,
+    }
+}
+
+/// Checks if a value is a proper list.
+fn is_proper_list(value: &Value) -> bool {
+    list_shape(value) == ListShape::Proper
+}
+
+/// Returns the car and cdr of a mutable or immutable pair.
+fn pair_parts(value: &Value) -> Option<(Value, Value)> {
     match value {
-        Value::Nil => Ok(Value::Nil),
-        Value::Pair(car, cdr) => {
-            let copied_cdr = copy_list(cdr)?;
-            Ok(Value::pair((**car).clone(), copied_cdr))
+        Value::Pair(car, cdr) => Some(((**car).clone(), (**cdr).clone())),
+        Value::MutablePair(car_ref, cdr_ref) => {
+            let car = car_ref.read().ok()?.clone();
+            let cdr = cdr_ref.read().ok()?.clone();
+            Some((car, cdr))
         }
-        _ => Err(Box::new(DiagnosticError::runtime_error(
-            "copy-list requires a list".to_string(),
-            None,
-        ))),
+        _ => None,
     }
 }
 
+/// Copies a list (shallow copy).
+fn copy_list(value: &Value) -> Result<Value> {
+    // The copy is made of fresh mutable pairs and keeps an improper tail
+    let mut items = Vec::new();
+    let mut current = value.clone();
+    while let Some((car, cdr)) = pair_parts(&current) {
+        items.push(car);
+        current = cdr;
+    }
+    Ok(items.into_iter().rev().fold(current, |tail, item| Value::mutable_pair(item, tail)))
+}
+
 /// Equality comparison functions (placeholders)
 fn values_equal(a: &Value, b: &Value) -> bool {
-    a == b // Using derived PartialEq for now
+    a.is_equal(b)
 }
 
 fn values_eq(a: &Value, b: &Value) -> bool {
-    // eq? is stricter than equal? - reference equality for mutable objects
-    a == b // Simplified for now
+    a.is_eq(b)
 }
 
 fn values_eqv(a: &Value, b: &Value) -> bool {
-    // eqv? is between eq? and equal?
-    a == b // Simplified for now
+    // Numbers and characters already compare by value under eq?
+    a.is_eq(b)
 }
 
 // ============= SRFI-1 MANIPULATION IMPLEMENTATION =============
@@ -1791,19 +1795,22 @@ fn srfi1_take(args: &[Value]) -> Result<Value> {
         )));
     }
     
-    let mut current = list_arg;
+    // The prefix is always freshly allocated
+    let mut current = list_arg.clone();
     let mut result = Vec::new();
     let mut count = 0;
     
     while count < n {
-        match current {
-            Value::Nil => break,
-            Value::Pair(car, cdr) => {
-                result.push((**car).clone());
+        if matches!(current, Value::Nil) {
+            break;
+        }
+        match pair_parts(&current) {
+            Some((car, cdr)) => {
+                result.push(car);
                 current = cdr;
                 count += 1;
             }
-            _ => {
+            None => {
                 return Err(Box::new(DiagnosticError::runtime_error(
                     "take requires a proper list".to_string(),
                     None,
@@ -1846,20 +1853,21 @@ fn srfi1_drop(args: &[Value]) -> Result<Value> {
         )));
     }
     
+    // The result shares structure with the argument
     let mut count = 0;
     while count < n {
-        match list_arg {
-            Value::Nil => {
-                return Err(Box::new(DiagnosticError::runtime_error(
-                    "drop: list too short".to_string(),
-                    None,
-                )));
-            }
-            Value::Pair(_, cdr) => {
-                list_arg = cdr.as_ref().clone();
+        if matches!(list_arg, Value::Nil) {
+            return Err(Box::new(DiagnosticError::runtime_error(
+                "drop: list too short".to_string(),
+                None,
+            )));
+        }
+        match pair_parts(&list_arg) {
+            Some((_, cdr)) => {
+                list_arg = cdr;
                 count += 1;
             }
-            _ => {
+            None => {
                 return Err(Box::new(DiagnosticError::runtime_error(
                     "drop requires a proper list".to_string(),
                     None,
@@ -1948,7 +1956,10 @@ fn srfi1_drop_right(args: &[Value]) -> Result<Value> {
     
     let list_len = list.len();
     if (n as usize) > list_len {
-        return Ok(Value::Nil);
+        return Err(Box::new(DiagnosticError::runtime_error(
+            "drop-right: n larger than list length".to_string(),
+            None,
+        )));
     }
     
     let end_idx = list_len - (n as usize);
@@ -2175,24 +2186,23 @@ fn srfi1_last_pair(args: &[Value]) -> Result<Value> {
         )));
     }
     
-    let mut current = &args[0];
+    let mut current = args[0].clone();
     
     loop {
-        match current {
-            Value::Nil => {
+        match pair_parts(&current) {
+            None if current.is_nil() => {
                 return Err(Box::new(DiagnosticError::runtime_error(
                     "last-pair: empty list".to_string(),
                     None,
                 )));
             }
-            Value::Pair(_, cdr) => {
-                let last_pair = current.clone();
-                if cdr.is_nil() {
-                    return Ok(last_pair);
+            Some((_, cdr)) => {
+                if !matches!(cdr, Value::Pair(..) | Value::MutablePair(..)) {
+                    return Ok(current);
                 }
                 current = cdr;
             }
-            _ => {
+            None => {
                 return Err(Box::new(DiagnosticError::runtime_error(
                     "last-pair requires a list".to_string(),
                     None,
@@ -2502,38 +2512,6 @@ fn srfi1_reduce_right(args: &[Value]) -> Result<Value> {
     Ok(accumulator)
 }
 
-/// unfold - Generate a list by repeatedly applying functions
-fn srfi1_unfold(args: &[Value]) -> Result<Value> {
-    if args.len() < 4 || args.len() > 5 {
-        return Err(Box::new(DiagnosticError::runtime_error(
-            format!("unfold expects 4 or 5 arguments, got {}", args.len()),
-            None,
-        )));
-    }
-    
-    // For now, return not implemented
-    Err(Box::new(DiagnosticError::runtime_error(
-        "unfold requires evaluator integration (not yet implemented)".to_string(),
-        None,
-    )))
-}
-
-/// unfold-right - Generate a list in reverse by repeatedly applying functions
-fn srfi1_unfold_right(args: &[Value]) -> Result<Value> {
-    if args.len() < 4 || args.len() > 5 {
-        return Err(Box::new(DiagnosticError::runtime_error(
-            format!("unfold-right expects 4 or 5 arguments, got {}", args.len()),
-            None,
-        )));
-    }
-    
-    // For now, return not implemented
-    Err(Box::new(DiagnosticError::runtime_error(
-        "unfold-right requires evaluator integration (not yet implemented)".to_string(),
-        None,
-    )))
-}
-
 // ============= SRFI-1 ASSOCIATION IMPLEMENTATION =============
 
 /// alist-cons - Add a key-value pair to an association list
@@ -2565,52 +2543,14 @@ fn srfi1_alist_copy(args: &[Value]) -> Result<Value> {
     copy_alist(&args[0])
 }
 
-/// alist-delete - Delete entries with a given key from an association list
-fn srfi1_alist_delete(args: &[Value]) -> Result<Value> {
-    if args.len() < 2 || args.len() > 3 {
-        return Err(Box::new(DiagnosticError::runtime_error(
-            format!("alist-delete expects 2 or 3 arguments, got {}", args.len()),
-            None,
-        )));
-    }
-    
-    let key = &args[0];
-    let alist = &args[1];
-    // TODO: Handle custom equality predicate when provided
-    
-    let mut result = Vec::new();
-    let mut current = alist;
-    
-    loop {
-        match current {
-            Value::Nil => break,
-            Value::Pair(car, cdr) => {
-                match car.as_ref() {
-                    Value::Pair(entry_key, entry_value) => {
-                        if !values_equal(key, entry_key) {
-                            let entry = Value::pair((**entry_key).clone(), (**entry_value).clone());
-                            result.push(entry);
-                        }
-                    }
-                    _ => {
-                        return Err(Box::new(DiagnosticError::runtime_error(
-                            "alist-delete requires a list of pairs".to_string(),
-                            None,
-                        )));
-                    }
-                }
-                current = cdr;
-            }
-            _ => {
-                return Err(Box::new(DiagnosticError::runtime_error(
-                    "alist-delete requires a proper list".to_string(),
-                    None,
-                )));
-            }
-        }
-    }
-    
-    Ok(Value::list(result))
+/// del-assq - Delete entries whose key is `eq?` to a given key
+fn srfi1_del_assq(args: &[Value]) -> Result<Value> {
+    delete_alist_entries("del-assq", &args[0], &args[1], |key, entry_key| Ok(values_eq(key, entry_key)))
+}
+
+/// del-assv - Delete entries whose key is `eqv?` to a given key
+fn srfi1_del_assv(args: &[Value]) -> Result<Value> {
+    delete_alist_entries("del-assv", &args[0], &args[1], |key, entry_key| Ok(values_eqv(key, entry_key)))
 }
 
 // ============= SRFI-1 COMPARISON IMPLEMENTATION =============
@@ -2705,88 +2645,74 @@ fn srfi1_list_equal(args: &[Value]) -> Result<Value> {
 
 // ============= SRFI-1 HELPER FUNCTIONS =============
 
-/// Check if a value is a circular list (using Floyd's cycle detection)
+/// Check if a value is a circular list
 fn is_circular_list(value: &Value) -> bool {
-    let mut slow = value;
-    let mut fast = value;
-    
-    loop {
-        // Move fast pointer two steps
-        match fast {
-            Value::Pair(_, cdr1) => {
-                match cdr1.as_ref() {
-                    Value::Pair(_, cdr2) => {
-                        fast = cdr2;
-                    }
-                    Value::Nil => return false,
-                    _ => return false, // Not a proper list structure
-                }
-            }
-            Value::Nil => return false,
-            _ => return false,
-        }
-        
-        // Move slow pointer one step
-        match slow {
-            Value::Pair(_, cdr) => {
-                slow = cdr;
-            }
-            Value::Nil => return false,
-            _ => return false,
-        }
-        
-        // Check if they meet (cycle detected)
-        if std::ptr::eq(slow, fast) {
-            return true;
-        }
-    }
+    list_shape(value) == ListShape::Circular
 }
 
 /// Check if a value is a dotted (improper) list
+///
+/// Following SRFI-1, any non-pair other than the empty list is a dotted
+/// list of length zero.
 fn is_dotted_list(value: &Value) -> bool {
-    let mut current = value;
+    list_shape(value) == ListShape::Dotted
+}
+
+/// Copy an association list (fresh spine and fresh entry pairs)
+///
+/// Each entry keeps its mutability, so a copied mutable entry can be
+/// altered with `set-cdr!` without touching the original.
+fn copy_alist(alist: &Value) -> Result<Value> {
+    let mut result = Vec::new();
+    let mut current = alist.clone();
     
-    loop {
-        match current {
-            Value::Nil => return false, // Proper list
-            Value::Pair(_, cdr) => {
-                current = cdr;
-            }
-            _ => return true, // Improper list (dotted)
-        }
+    while !current.is_nil() {
+        let (entry, rest) = pair_parts(&current).ok_or_else(|| {
+            DiagnosticError::runtime_error("alist-copy requires a proper list".to_string(), None)
+        })?;
+        let (key, value) = pair_parts(&entry).ok_or_else(|| {
+            DiagnosticError::runtime_error(
+                format!("alist-copy requires a list of pairs, got element {entry}"),
+                None,
+            )
+        })?;
+        let copied_pair = match entry {
+            Value::MutablePair(..) => Value::mutable_pair(key, value),
+            _ => Value::pair(key, value),
+        };
+        result.push(copied_pair);
+        current = rest;
     }
+    
+    Ok(Value::list(result))
 }
 
-/// Copy an association list (deep copy of pairs)
-fn copy_alist(alist: &Value) -> Result<Value> {
+/// Removes every entry whose key satisfies `matches(key, entry-key)`.
+///
+/// Surviving entries are shared with the original alist rather than copied.
+fn delete_alist_entries(
+    name: &str,
+    key: &Value,
+    alist: &Value,
+    mut matches: impl FnMut(&Value, &Value) -> Result<bool>,
+) -> Result<Value> {
     let mut result = Vec::new();
-    let mut current = alist;
+    let mut current = alist.clone();
     
-    loop {
-        match current {
-            Value::Nil => break,
-            Value::Pair(car, cdr) => {
-                match car.as_ref() {
-                    Value::Pair(key, value) => {
-                        let copied_pair = Value::pair((**key).clone(), (**value).clone());
-                        result.push(copied_pair);
-                    }
-                    _ => {
-                        return Err(Box::new(DiagnosticError::runtime_error(
-                            "alist-copy requires a list of pairs".to_string(),
-                            None,
-                        )));
-                    }
-                }
-                current = cdr;
-            }
-            _ => {
-                return Err(Box::new(DiagnosticError::runtime_error(
-                    "alist-copy requires a proper list".to_string(),
-                    None,
-                )));
-            }
+    while !current.is_nil() {
+        let (entry, rest) = pair_parts(&current).ok_or_else(|| {
+            DiagnosticError::runtime_error(format!("{name} requires a proper list"), None)
+        })?;
+        let (entry_key, _) = pair_parts(&entry).ok_or_else(|| {
+            DiagnosticError::runtime_error(
+                format!("{name} requires a list of pairs, got element {entry}"),
+                None,
+            )
+        })?;
+        if !matches(key, &entry_key)? {
+            result.push(entry);
         }
+        current = rest;
     }
     
     Ok(Value::list(result))
@@ -2893,6 +2819,54 @@ mod tests {
         assert_eq!(result, expected);
     }
     
+    #[test]
+    fn test_append_shares_last_argument() {
+        let tail = Value::list(vec![Value::integer(3), Value::integer(4)]);
+        let result = primitive_append(&[
+            Value::list(vec![Value::integer(1), Value::integer(2)]),
+            tail.clone(),
+        ]).unwrap();
+        
+        // Cloning a pair shares its Arcs, so the third cell of the result
+        // holds the very same car and cdr as the tail
+        let cdr = |value: &Value| match value {
+            Value::Pair(_, cdr) => cdr.as_ref().clone(),
+            _ => panic!("expected a pair"),
+        };
+        let third = cdr(&cdr(&result));
+        let shared = match (&third, &tail) {
+            (Value::Pair(a_car, a_cdr), Value::Pair(b_car, b_cdr)) => {
+                Arc::ptr_eq(a_car, b_car) && Arc::ptr_eq(a_cdr, b_cdr)
+            }
+            _ => false,
+        };
+        assert!(shared, "append must reuse the last argument's pairs");
+        
+        // Degenerate cases
+        assert_eq!(primitive_append(&[]).unwrap(), Value::Nil);
+        assert_eq!(primitive_append(&[tail.clone()]).unwrap(), tail);
+        assert_eq!(
+            primitive_append(&[Value::list(vec![Value::integer(1)]), Value::integer(2)]).unwrap(),
+            Value::pair(Value::integer(1), Value::integer(2))
+        );
+        assert!(primitive_append(&[Value::integer(1), Value::Nil]).is_err());
+    }
+    
+    #[test]
+    fn test_append_many_small_lists() {
+        let lists: Vec<Value> = (0..10_000)
+            .map(|i| Value::list(vec![Value::integer(i), Value::integer(i + 1)]))
+            .collect();
+        
+        let start = std::time::Instant::now();
+        let result = primitive_append(&lists).unwrap();
+        assert!(start.elapsed() < std::time::Duration::from_secs(2));
+        
+        let elements = result.as_list().unwrap();
+        assert_eq!(elements.len(), 20_000);
+        assert_eq!(elements[19_998], Value::integer(9_999));
+    }
+    
     #[test]
     fn test_reverse() {
         let list = Value::list(vec![
@@ -3340,6 +3314,69 @@ mod tests {
         assert_eq!(result, expected);
     }
     
+    #[test]
+    fn test_list_tail_and_list_set_bounds() {
+        let list = Value::list(vec![Value::integer(1), Value::integer(2), Value::integer(3)]);
+        
+        assert_eq!(primitive_list_tail(&[list.clone(), Value::integer(0)]).unwrap(), list);
+        assert_eq!(primitive_list_tail(&[list.clone(), Value::integer(3)]).unwrap(), Value::Nil);
+        assert!(primitive_list_tail(&[list.clone(), Value::integer(4)]).is_err());
+        assert!(primitive_list_tail(&[list.clone(), Value::integer(-1)]).is_err());
+        
+        // list-set! mutates mutable lists in place
+        let mutable = Value::mutable_pair(
+            Value::integer(1),
+            Value::mutable_pair(Value::integer(2), Value::Nil),
+        );
+        primitive_list_set(&[mutable.clone(), Value::integer(1), Value::integer(20)]).unwrap();
+        assert_eq!(primitive_list_ref(&[mutable.clone(), Value::integer(1)]).unwrap(), Value::integer(20));
+        
+        assert!(primitive_list_set(&[mutable.clone(), Value::integer(2), Value::integer(0)]).is_err());
+        assert!(primitive_list_set(&[mutable, Value::integer(-1), Value::integer(0)]).is_err());
+        
+        // Lists built by `list` are fresh, so they can be modified too
+        let fresh = primitive_list(&[Value::integer(1), Value::integer(2), Value::integer(3)]).unwrap();
+        assert!(primitive_list_set(&[fresh.clone(), Value::integer(0), Value::integer(0)]).is_ok());
+        assert_eq!(primitive_list_ref(&[fresh, Value::integer(0)]).unwrap(), Value::integer(0));
+    }
+    
+    #[test]
+    fn test_srfi1_take_drop_edge_cases() {
+        let list = Value::list(vec![Value::integer(1), Value::integer(2), Value::integer(3)]);
+        
+        assert_eq!(srfi1_take(&[list.clone(), Value::integer(0)]).unwrap(), Value::Nil);
+        assert_eq!(srfi1_drop(&[list.clone(), Value::integer(3)]).unwrap(), Value::Nil);
+        assert_eq!(srfi1_take_right(&[list.clone(), Value::integer(0)]).unwrap(), Value::Nil);
+        assert_eq!(srfi1_drop_right(&[list.clone(), Value::integer(3)]).unwrap(), Value::Nil);
+        
+        for f in [srfi1_take, srfi1_drop, srfi1_take_right, srfi1_drop_right] {
+            assert!(f(&[list.clone(), Value::integer(-1)]).is_err());
+            assert!(f(&[list.clone(), Value::integer(4)]).is_err());
+        }
+        
+        // drop shares structure with its argument while take copies
+        let (Value::Pair(_, tail), Value::Pair(first, _)) = (
+            &list,
+            &srfi1_take(&[list.clone(), Value::integer(1)]).unwrap(),
+        ) else {
+            panic!("expected pairs");
+        };
+        match srfi1_drop(&[list.clone(), Value::integer(1)]).unwrap() {
+            Value::Pair(car, cdr) => match tail.as_ref() {
+                Value::Pair(tail_car, tail_cdr) => {
+                    assert!(Arc::ptr_eq(&car, tail_car));
+                    assert!(Arc::ptr_eq(&cdr, tail_cdr));
+                }
+                _ => panic!("expected pair"),
+            },
+            _ => panic!("expected pair"),
+        }
+        match &list {
+            Value::Pair(list_first, _) => assert!(!Arc::ptr_eq(first, list_first)),
+            _ => panic!("expected pair"),
+        }
+    }
+    
     #[test]
     fn test_srfi1_split_at() {
         let list = Value::list(vec![Value::integer(1), Value::integer(2), Value::integer(3), Value::integer(4)]);
@@ -3446,14 +3483,37 @@ mod tests {
         }
         
         // Test alist-delete
-        let args = vec![Value::string("b"), alist];
-        let result = srfi1_alist_delete(&args).unwrap();
+        let result = delete_alist_entries("alist-delete", &Value::string("b"), &alist, |key, entry_key| {
+            Ok(values_equal(key, entry_key))
+        }).unwrap();
         let expected = Value::list(vec![
             Value::pair(Value::string("a"), Value::integer(1)),
             Value::pair(Value::string("c"), Value::integer(3)),
         ]);
         assert_eq!(result, expected);
     }
+    
+    #[test]
+    fn test_alist_copy_makes_fresh_pairs() {
+        let alist = Value::list(vec![
+            Value::mutable_pair(Value::symbol_from_str("a"), Value::integer(1)),
+            Value::mutable_pair(Value::symbol_from_str("b"), Value::integer(2)),
+        ]);
+        let copy = srfi1_alist_copy(&[alist.clone()]).unwrap();
+        assert_eq!(copy.as_list().unwrap().len(), 2);
+        
+        let copied_entry = copy.as_list().unwrap()[0].clone();
+        primitive_set_cdr(&[copied_entry.clone(), Value::integer(100)]).unwrap();
+        
+        let (_, copied_value) = pair_parts(&copied_entry).unwrap();
+        let (_, original_value) = pair_parts(&alist.as_list().unwrap()[0]).unwrap();
+        assert_eq!(copied_value, Value::integer(100));
+        assert_eq!(original_value, Value::integer(1));
+        
+        assert_eq!(srfi1_alist_copy(&[Value::Nil]).unwrap(), Value::Nil);
+        let improper = Value::list(vec![Value::pair(Value::integer(1), Value::integer(2)), Value::integer(3)]);
+        assert!(srfi1_alist_copy(&[improper]).is_err());
+    }
 }
 
 // ============= EVALUATOR-INTEGRATED HIGHER-ORDER FUNCTIONS =============
@@ -3464,35 +3524,9 @@ fn apply_procedure_with_evaluator(
     procedure: &Value,
     args: &[Value],
 ) -> crate::diagnostics::Result<Value> {
-    use crate::eval::evaluator::EvalStep;
-    use crate::diagnostics::Error;
-    
-    // Start with the initial procedure application
-    let mut step = evaluator.apply_procedure(procedure.clone(), args.to_vec(), None);
-    
-    // Run the trampoline loop to handle all evaluation steps
-    loop {
-        step = match step {
-            EvalStep::Return(value) => return Ok(value),
-            EvalStep::Error(error) => return Err(Box::new(error)),
-            EvalStep::Continue { expr, env } => {
-                // Continue evaluation with the given expression and environment
-                evaluator.eval_step(&expr, env)
-            }
-            EvalStep::TailCall { procedure: proc, args: tail_args, location } => {
-                // Handle tail call by applying the procedure
-                evaluator.apply_procedure(proc, tail_args, location)
-            }
-            EvalStep::CallContinuation { continuation, value } => {
-                // Handle continuation call
-                evaluator.call_continuation(continuation, value)
-            }
-            EvalStep::NonLocalJump { value, target_stack_depth: _ } => {
-                // Non-local jump immediately returns the value
-                return Ok(value);
-            }
-        };
-    }
+    // The evaluator's own trampoline lands escapes to continuations captured
+    // inside the procedure and carries the rest out to their owners
+    evaluator.call_procedure(procedure.clone(), args.to_vec(), None)
 }
 
 /// Evaluator-integrated map function
@@ -3603,6 +3637,115 @@ fn evaluator_filter(evaluator: &mut crate::eval::evaluator::Evaluator, args: &[V
     Ok(Value::list(filtered))
 }
 
+/// Evaluator-integrated unfold: `(unfold stop? mapper successor seed [tail-gen])`
+///
+/// Collects `(mapper seed)` for each seed until `(stop? seed)` holds, then
+/// ends the list with `(tail-gen seed)`, or `()` without a tail generator.
+fn evaluator_unfold(evaluator: &mut crate::eval::evaluator::Evaluator, args: &[Value]) -> crate::diagnostics::Result<Value> {
+    use crate::diagnostics::Error as DiagnosticError;
+    
+    if args.len() < 4 || args.len() > 5 {
+        return Err(Box::new(DiagnosticError::runtime_error(
+            format!("unfold expects 4 or 5 arguments, got {}", args.len()),
+            None,
+        )));
+    }
+    check_unfold_procedures("unfold", args)?;
+    
+    let (stop, mapper, successor) = (&args[0], &args[1], &args[2]);
+    let mut seed = args[3].clone();
+    let mut elements = Vec::new();
+    while !apply_procedure_with_evaluator(evaluator, stop, &[seed.clone()])?.is_truthy() {
+        elements.push(apply_procedure_with_evaluator(evaluator, mapper, &[seed.clone()])?);
+        seed = apply_procedure_with_evaluator(evaluator, successor, &[seed])?;
+    }
+    
+    let tail = match args.get(4) {
+        Some(tail_gen) => apply_procedure_with_evaluator(evaluator, tail_gen, &[seed])?,
+        None => Value::Nil,
+    };
+    Ok(elements
+        .into_iter()
+        .rev()
+        .fold(tail, |rest, element| Value::pair(element, rest)))
+}
+
+/// Evaluator-integrated unfold-right: `(unfold-right stop? mapper successor seed [tail])`
+///
+/// Like `unfold`, but the first seed's element ends up last, consed onto
+/// `tail` (default `()`).
+fn evaluator_unfold_right(evaluator: &mut crate::eval::evaluator::Evaluator, args: &[Value]) -> crate::diagnostics::Result<Value> {
+    use crate::diagnostics::Error as DiagnosticError;
+    
+    if args.len() < 4 || args.len() > 5 {
+        return Err(Box::new(DiagnosticError::runtime_error(
+            format!("unfold-right expects 4 or 5 arguments, got {}", args.len()),
+            None,
+        )));
+    }
+    check_unfold_procedures("unfold-right", args)?;
+    
+    let (stop, mapper, successor) = (&args[0], &args[1], &args[2]);
+    let mut seed = args[3].clone();
+    let mut result = args.get(4).cloned().unwrap_or(Value::Nil);
+    while !apply_procedure_with_evaluator(evaluator, stop, &[seed.clone()])?.is_truthy() {
+        let element = apply_procedure_with_evaluator(evaluator, mapper, &[seed.clone()])?;
+        result = Value::pair(element, result);
+        seed = apply_procedure_with_evaluator(evaluator, successor, &[seed])?;
+    }
+    Ok(result)
+}
+
+/// Checks that the stop?, mapper and successor arguments of an unfold are procedures.
+fn check_unfold_procedures(name: &str, args: &[Value]) -> crate::diagnostics::Result<()> {
+    for (position, role) in ["stop?", "mapper", "successor"].iter().enumerate() {
+        if !args[position].is_procedure() {
+            return Err(Box::new(crate::diagnostics::Error::runtime_error(
+                format!("{name} {role} argument must be a procedure"),
+                None,
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// Evaluator-integrated alist-delete (and alist-delete!)
+///
+/// The optional comparator is called as `(= key entry-key)`; it defaults
+/// to `equal?`. Every matching entry is removed.
+fn evaluator_alist_delete(evaluator: &mut crate::eval::evaluator::Evaluator, args: &[Value]) -> crate::diagnostics::Result<Value> {
+    use crate::diagnostics::Error as DiagnosticError;
+    
+    if args.len() < 2 || args.len() > 3 {
+        return Err(Box::new(DiagnosticError::runtime_error(
+            format!("alist-delete expects 2 or 3 arguments, got {}", args.len()),
+            None,
+        )));
+    }
+    
+    let Some(comparator) = args.get(2) else {
+        return delete_alist_entries("alist-delete", &args[0], &args[1], |key, entry_key| {
+            Ok(values_equal(key, entry_key))
+        });
+    };
+    
+    if !comparator.is_procedure() {
+        return Err(Box::new(DiagnosticError::runtime_error(
+            "alist-delete comparator must be a procedure".to_string(),
+            None,
+        )));
+    }
+    
+    delete_alist_entries("alist-delete", &args[0], &args[1], |key, entry_key| {
+        let result = apply_procedure_with_evaluator(
+            evaluator,
+            comparator,
+            &[key.clone(), entry_key.clone()],
+        )?;
+        Ok(result.is_truthy())
+    })
+}
+
 /// Evaluator-integrated fold-left function
 fn evaluator_fold_left(evaluator: &mut crate::eval::evaluator::Evaluator, args: &[Value]) -> crate::diagnostics::Result<Value> {
     use crate::diagnostics::Error as DiagnosticError;
@@ -3897,6 +4040,40 @@ mod mutable_pair_tests {
         // Test non-integer index
         assert!(primitive_list_set(&[Value::Nil, Value::string("bad"), Value::string("x")]).is_err());
     }
+
+    #[test]
+    fn test_list_shape_predicates() {
+        let proper = Value::list(vec![Value::integer(1), Value::integer(2)]);
+        let dotted = primitive_cons(&[Value::integer(1), Value::integer(2)]).unwrap();
+
+        // (1 2 3) whose last cdr is set back to its head
+        let last = Value::mutable_pair(Value::integer(3), Value::Nil);
+        let circular = Value::mutable_pair(
+            Value::integer(1),
+            Value::mutable_pair(Value::integer(2), last.clone()),
+        );
+        primitive_set_cdr(&[last, circular.clone()]).unwrap();
+
+        let check = |value: &Value| {
+            (
+                primitive_list_p(&[value.clone()]).unwrap(),
+                primitive_circular_list_p(&[value.clone()]).unwrap(),
+                primitive_dotted_list_p(&[value.clone()]).unwrap(),
+            )
+        };
+        let (t, f) = (Value::boolean(true), Value::boolean(false));
+        assert_eq!(check(&Value::Nil), (t.clone(), f.clone(), f.clone()));
+        assert_eq!(check(&proper), (t.clone(), f.clone(), f.clone()));
+        assert_eq!(check(&dotted), (f.clone(), f.clone(), t.clone()));
+        assert_eq!(check(&circular), (f.clone(), t.clone(), f.clone()));
+    }
+
+    #[test]
+    fn test_single_pair_cycle_is_circular() {
+        let pair = Value::mutable_pair(Value::integer(1), Value::Nil);
+        primitive_set_cdr(&[pair.clone(), pair.clone()]).unwrap();
+        assert_eq!(list_shape(&pair), ListShape::Circular);
+    }
 }
 
 #[cfg(test)]
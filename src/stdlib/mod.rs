@@ -32,6 +32,10 @@ pub mod control;
 pub mod effects;
 /// Exception handling and error operations.
 pub mod exceptions;
+/// Scheme-level garbage collector interface.
+pub mod gc;
+/// Character encodings for textual file ports.
+pub mod encoding;
 /// Basic input/output operations.
 pub mod io;
 /// List processing and higher-order functions.
@@ -243,9 +247,7 @@ fn primitive_eq(args: &[Value]) -> Result<Value> {
         )));
     }
     
-    // For now, use PartialEq implementation
-    // In a full implementation, this would check reference equality for mutable objects
-    Ok(Value::boolean(args[0] == args[1]))
+    Ok(Value::boolean(args[0].is_eq(&args[1])))
 }
 
 /// eqv? procedure - operational equivalence
@@ -257,9 +259,8 @@ fn primitive_eqv(args: &[Value]) -> Result<Value> {
         )));
     }
     
-    // For now, use PartialEq implementation
-    // In a full implementation, this would be more nuanced than eq? but less than equal?
-    Ok(Value::boolean(args[0] == args[1]))
+    // Numbers and characters already compare by value under eq?
+    Ok(Value::boolean(args[0].is_eq(&args[1])))
 }
 
 /// equal? procedure - structural equality
@@ -271,9 +272,7 @@ fn primitive_equal(args: &[Value]) -> Result<Value> {
         )));
     }
     
-    // For now, use PartialEq implementation
-    // In a full implementation, this would recursively check structural equality
-    Ok(Value::boolean(args[0] == args[1]))
+    Ok(Value::boolean(args[0].is_equal(&args[1])))
 }
 
 /// not procedure - logical negation
@@ -438,6 +437,28 @@ mod tests {
         assert_eq!(result, Value::boolean(false));
     }
     
+    #[test]
+    fn test_equal_on_matching_cyclic_lists() {
+        // Builds (1 2 1 2 ...) as two pairs whose last cdr points back to the head
+        fn cyclic_list() -> Value {
+            let second = Value::mutable_pair(Value::integer(2), Value::Nil);
+            let head = Value::mutable_pair(Value::integer(1), second.clone());
+            if let Value::MutablePair(_, cdr) = &second {
+                *cdr.write().unwrap() = head.clone();
+            }
+            head
+        }
+        
+        let (a, b) = (cyclic_list(), cyclic_list());
+        assert_eq!(primitive_equal(&[a.clone(), b]).unwrap(), Value::boolean(true));
+        
+        let different = Value::mutable_pair(Value::integer(1), Value::Nil);
+        if let Value::MutablePair(_, cdr) = &different {
+            *cdr.write().unwrap() = different.clone();
+        }
+        assert_eq!(primitive_equal(&[a, different]).unwrap(), Value::boolean(false));
+    }
+    
     #[test]
     fn test_boolean_equal() {
         // Test boolean=? with equal booleans
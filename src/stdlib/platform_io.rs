@@ -16,11 +16,11 @@ use crate::effects::Effect;
 use std::sync::Arc;
 use std::collections::HashMap;
 
-#[cfg(target_os = "linux")]
+#[cfg(all(target_os = "linux", feature = "unix-extensions"))]
 use nix::sys::epoll::{Epoll, EpollCreateFlags, EpollEvent, EpollFlags, EpollTimeout};
-#[cfg(target_os = "linux")]
+#[cfg(all(target_os = "linux", feature = "unix-extensions"))]
 use nix::unistd::{pipe, read, write};
-#[cfg(target_os = "linux")]
+#[cfg(all(target_os = "linux", feature = "unix-extensions"))]
 use nix::fcntl::{fcntl, FcntlArg, OFlag};
 
 #[cfg(all(target_os = "macos", feature = "advanced-io"))]
@@ -40,7 +40,7 @@ use std::time::Duration;
 /// Platform-specific I/O handle
 #[derive(Debug)]
 pub enum PlatformHandle {
-    #[cfg(target_os = "linux")]
+    #[cfg(all(target_os = "linux", feature = "unix-extensions"))]
     Epoll {
         epoll_fd: i32,
         events: Vec<EpollEvent>,
@@ -106,7 +106,7 @@ impl HighPerformanceIo {
         })
     }
     
-    #[cfg(target_os = "linux")]
+    #[cfg(all(target_os = "linux", feature = "unix-extensions"))]
     fn create_platform_handle() -> std::io::Result<PlatformHandle> {
         match Epoll::new(EpollCreateFlags::empty()) {
             Ok(epoll) => Ok(PlatformHandle::Epoll {
@@ -156,7 +156,7 @@ impl HighPerformanceIo {
         }
     }
     
-    #[cfg(not(any(target_os = "linux", target_os = "macos", windows)))]
+    #[cfg(not(any(all(target_os = "linux", feature = "unix-extensions"), target_os = "macos", windows)))]
     fn create_platform_handle() -> std::io::Result<PlatformHandle> {
         Self::create_mio_handle()
     }
@@ -186,7 +186,7 @@ impl HighPerformanceIo {
     
     pub fn poll_completions(&mut self, timeout: Option<Duration>) -> std::io::Result<Vec<IoCompletion>> {
         match &mut self.handle {
-            #[cfg(target_os = "linux")]
+            #[cfg(all(target_os = "linux", feature = "unix-extensions"))]
             PlatformHandle::Epoll { epoll_fd, events } => {
                 let fd = *epoll_fd;
                 Self::poll_epoll_static(fd, events, timeout, &mut self.pending_operations)
@@ -206,7 +206,7 @@ impl HighPerformanceIo {
         }
     }
     
-    #[cfg(target_os = "linux")]
+    #[cfg(all(target_os = "linux", feature = "unix-extensions"))]
     fn poll_epoll_static(epoll_fd: i32, events: &mut Vec<EpollEvent>, timeout: Option<Duration>, pending_operations: &mut HashMap<u64, IoOperation>) -> std::io::Result<Vec<IoCompletion>> {
         events.clear();
         events.resize(32, EpollEvent::empty());
@@ -629,7 +629,7 @@ pub fn primitive_platform_capabilities(_args: &[Value]) -> Result<Value> {
             let mut backends = Vec::new();
             backends.push(Value::string("mio".to_string()));
             
-            #[cfg(target_os = "linux")]
+            #[cfg(all(target_os = "linux", feature = "unix-extensions"))]
             {
                 backends.push(Value::string("epoll".to_string()));
                 backends.push(Value::string("io_uring".to_string()));
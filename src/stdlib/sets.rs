@@ -367,34 +367,9 @@ fn apply_procedure_with_evaluator(
     procedure: &Value,
     args: &[Value],
 ) -> Result<Value> {
-    use crate::eval::evaluator::EvalStep;
-    
-    // Start with the initial procedure application
-    let mut step = evaluator.apply_procedure(procedure.clone(), args.to_vec(), None);
-    
-    // Run the trampoline loop to handle all evaluation steps
-    loop {
-        step = match step {
-            EvalStep::Return(value) => return Ok(value),
-            EvalStep::Error(error) => return Err(Box::new(error)),
-            EvalStep::Continue { expr, env } => {
-                // Continue evaluation with the given expression and environment
-                evaluator.eval_step(&expr, env)
-            }
-            EvalStep::TailCall { procedure: proc, args: tail_args, location } => {
-                // Handle tail call by applying the procedure
-                evaluator.apply_procedure(proc, tail_args, location)
-            }
-            EvalStep::CallContinuation { continuation, value } => {
-                // Handle continuation call
-                evaluator.call_continuation(continuation, value)
-            }
-            EvalStep::NonLocalJump { value, target_stack_depth: _ } => {
-                // Non-local jump immediately returns the value
-                return Ok(value);
-            }
-        }
-    }
+    // The evaluator's own trampoline lands escapes to continuations captured
+    // inside the procedure and carries the rest out to their owners
+    evaluator.call_procedure(procedure.clone(), args.to_vec(), None)
 }
 
 /// Applies a procedure to each element of a set.
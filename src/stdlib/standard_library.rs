@@ -47,8 +47,8 @@ impl StandardLibrary {
         // Control flow procedures
         crate::stdlib::control::create_control_bindings(env);
         
-        // Type operations (when implemented)
-        // types::create_type_bindings(env);
+        // Type operations, symbol comparison and hash functions
+        crate::stdlib::types::create_type_bindings(env);
         
         // Effect system integration
         crate::stdlib::effects::create_effect_bindings(env);
@@ -69,6 +69,9 @@ impl StandardLibrary {
         // System interface procedures (R7RS Section 6.14)
         crate::stdlib::system::create_system_bindings(env);
         
+        // Garbage collector interface
+        crate::stdlib::gc::create_gc_bindings(env);
+        
         // SRFI-135 Text processing (R7RS-large)
         crate::stdlib::text::create_text_bindings(env);
         crate::stdlib::text_regex::create_regex_bindings(env);
@@ -77,6 +80,7 @@ impl StandardLibrary {
         
         // Concurrency and parallelism (R7RS-large)
         crate::stdlib::concurrency::populate_environment(env);
+        crate::stdlib::concurrency::create_mutex_bindings(env);
         
         // Set operations (SRFI-113)
         crate::stdlib::sets::install_set_primitives(env);
@@ -12,6 +12,7 @@ use crate::diagnostics::{Error as DiagnosticError, Result};
 use crate::eval::value::{
     Value, PrimitiveProcedure, PrimitiveImpl, ThreadSafeEnvironment
 };
+use crate::ast::Literal;
 use crate::effects::Effect;
 use std::sync::Arc;
 use std::collections::VecDeque;
@@ -202,6 +203,15 @@ fn bind_stream_operations(env: &Arc<ThreadSafeEnvironment>) {
         implementation: PrimitiveImpl::RustFn(primitive_stream_close),
         effects: vec![Effect::IO],
     })));
+    
+    // read-string!
+    env.define("read-string!".to_string(), Value::Primitive(Arc::new(PrimitiveProcedure {
+        name: "read-string!".to_string(),
+        arity_min: 2,
+        arity_max: Some(4),
+        implementation: PrimitiveImpl::RustFn(primitive_read_string_bang),
+        effects: vec![Effect::IO, Effect::State],
+    })));
 }
 
 fn bind_compression_operations(env: &Arc<ThreadSafeEnvironment>) {
@@ -496,6 +506,86 @@ pub fn primitive_stream_close(_args: &[Value]) -> Result<Value> {
     Ok(Value::Unspecified)
 }
 
+/// (read-string! buffer port [start [count]])
+///
+/// Reads up to `count` characters from a textual input port into a mutable
+/// string buffer starting at `start`, and returns how many were read. Fewer
+/// than `count` are read only at end of file, so 0 signals eof. A zero count
+/// returns 0 without reading from the port.
+pub fn primitive_read_string_bang(args: &[Value]) -> Result<Value> {
+    if args.len() < 2 || args.len() > 4 {
+        return Err(Box::new(DiagnosticError::runtime_error(
+            format!("read-string! expects 2 to 4 arguments, got {}", args.len()),
+            None,
+        )));
+    }
+    
+    let buffer = match &args[0] {
+        Value::MutableString(chars) => chars.clone(),
+        _ => {
+            return Err(Box::new(DiagnosticError::runtime_error(
+                "read-string! requires a mutable string buffer".to_string(),
+                None,
+            )));
+        }
+    };
+    let port = match &args[1] {
+        Value::Port(port) => port.clone(),
+        _ => {
+            return Err(Box::new(DiagnosticError::runtime_error(
+                "read-string! requires a port argument".to_string(),
+                None,
+            )));
+        }
+    };
+    
+    let length = buffer.read().unwrap().len();
+    let start = if args.len() > 2 {
+        extract_index(&args[2], "read-string!")?
+    } else {
+        0
+    };
+    let count = if args.len() > 3 {
+        extract_index(&args[3], "read-string!")?
+    } else {
+        length.saturating_sub(start)
+    };
+    if start.checked_add(count).is_none_or(|end| end > length) {
+        return Err(Box::new(DiagnosticError::runtime_error(
+            format!("read-string!: start {start} and count {count} exceed buffer length {length}"),
+            None,
+        )));
+    }
+    
+    if count == 0 {
+        return Ok(Value::integer(0));
+    }
+    
+    if !port.is_input() || !port.is_textual() {
+        return Err(Box::new(DiagnosticError::runtime_error(
+            "read-string! requires a textual input port".to_string(),
+            None,
+        )));
+    }
+    if !port.is_open() {
+        return Err(Box::new(DiagnosticError::runtime_error(
+            "read-string!: port is closed".to_string(),
+            None,
+        )));
+    }
+    
+    let mut chunk = Vec::with_capacity(count);
+    while chunk.len() < count {
+        match crate::stdlib::io::read_char_from_port(&port, false)? {
+            Value::Literal(Literal::Character(ch)) => chunk.push(ch),
+            _ => break, // End of file
+        }
+    }
+    
+    buffer.write().unwrap()[start..start + chunk.len()].copy_from_slice(&chunk);
+    Ok(Value::integer(chunk.len() as i64))
+}
+
 // === Compression Operations ===
 
 pub fn primitive_compress_stream(_args: &[Value]) -> Result<Value> {
@@ -896,6 +986,16 @@ fn extract_integer(value: &Value, operation: &str) -> Result<i64> {
 }
 
 /// Extracts a bytevector from a Value.
+fn extract_index(value: &Value, operation: &str) -> Result<usize> {
+    let index = extract_integer(value, operation)?;
+    usize::try_from(index).map_err(|_| {
+        Box::new(DiagnosticError::runtime_error(
+            format!("{operation}: index must be non-negative, got {index}"),
+            None,
+        ))
+    })
+}
+
 fn extract_bytevector(value: &Value, operation: &str) -> Result<Vec<u8>> {
     match value {
         Value::Literal(crate::ast::Literal::Bytevector(bv)) => Ok(bv.clone()),
@@ -950,6 +1050,57 @@ mod tests {
         }
     }
     
+    fn read_chunk(buffer: &Value, port: &Value, start: i64, count: i64) -> i64 {
+        let args = vec![buffer.clone(), port.clone(), Value::integer(start), Value::integer(count)];
+        primitive_read_string_bang(&args).unwrap().as_integer().unwrap()
+    }
+    
+    #[test]
+    fn test_read_string_bang_reassembles_stream_in_chunks() {
+        let source = "The quick brown fox jumps over the lazy dog, λ and all.";
+        let port = Value::Port(Arc::new(crate::eval::value::Port::new_string_input(source.to_string())));
+        let buffer = Value::mutable_string_filled(8, ' ');
+        
+        let mut reassembled = String::new();
+        loop {
+            let read = read_chunk(&buffer, &port, 0, 8);
+            if read == 0 {
+                break;
+            }
+            assert!(read == 8 || reassembled.chars().count() + read as usize == source.chars().count());
+            reassembled.extend(buffer.as_string_owned().unwrap().chars().take(read as usize));
+        }
+        
+        assert_eq!(reassembled, source);
+        assert_eq!(read_chunk(&buffer, &port, 0, 8), 0);
+    }
+    
+    #[test]
+    fn test_read_string_bang_edges() {
+        let port = Value::Port(Arc::new(crate::eval::value::Port::new_string_input("abc".to_string())));
+        let buffer = Value::mutable_string("-----".to_string());
+        
+        // A zero count leaves the port where it was
+        assert_eq!(read_chunk(&buffer, &port, 2, 0), 0);
+        assert_eq!(read_chunk(&buffer, &port, 1, 4), 3);
+        assert_eq!(buffer.as_string_owned().unwrap(), "-abc-");
+        
+        assert!(primitive_read_string_bang(&[buffer.clone(), port.clone(), Value::integer(3), Value::integer(3)]).is_err());
+        assert!(primitive_read_string_bang(&[Value::string("fixed".to_string()), port.clone()]).is_err());
+    }
+    
+    #[test]
+    fn test_char_ready_at_eof() {
+        let port = Value::Port(Arc::new(crate::eval::value::Port::new_string_input("x".to_string())));
+        let buffer = Value::mutable_string(" ".to_string());
+        assert_eq!(read_chunk(&buffer, &port, 0, 1), 1);
+        assert_eq!(
+            crate::stdlib::io::primitive_char_ready_p(&[port.clone()]).unwrap(),
+            Value::boolean(true)
+        );
+        assert_eq!(read_chunk(&buffer, &port, 0, 1), 0);
+    }
+    
     #[test]
     fn test_memory_mapping() {
         let mut temp_file = NamedTempFile::new().unwrap();
@@ -8,6 +8,7 @@ use crate::eval::value::{Value, PrimitiveProcedure, PrimitiveImpl, ThreadSafeEnv
 use crate::effects::Effect;
 use crate::ast::Literal;
 use crate::utils::symbol_name;
+use std::borrow::Cow;
 use std::sync::Arc;
 use std::collections::hash_map::DefaultHasher;
 use std::hash::{Hash, Hasher};
@@ -39,6 +40,8 @@ pub enum CharacterSet {
     Character(char),
     /// A string containing characters to match
     String(String),
+    /// An SRFI-14 character set
+    CharSet(Arc<crate::stdlib::charset::CharSet>),
     /// Default whitespace character set
     Whitespace,
 }
@@ -50,6 +53,7 @@ impl CharacterSet {
             CharacterSet::Predicate(f) => f(ch),
             CharacterSet::Character(c) => ch == *c,
             CharacterSet::String(s) => s.contains(ch),
+            CharacterSet::CharSet(set) => set.contains(ch),
             CharacterSet::Whitespace => ch.is_whitespace(),
         }
     }
@@ -58,12 +62,13 @@ impl CharacterSet {
     pub fn from_value(value: &Value) -> Result<CharacterSet> {
         match value {
             Value::Literal(Literal::Character(ch)) => Ok(CharacterSet::Character(*ch)),
+            Value::CharSet(set) => Ok(CharacterSet::CharSet(set.clone())),
             _ => {
                 if let Some(s) = value.as_string() {
                     Ok(CharacterSet::String(s.to_string()))
                 } else {
                     Err(Box::new(DiagnosticError::runtime_error(
-                        "Character set must be a character, string, or predicate".to_string(),
+                        "Character set must be a character, string, char-set, or predicate".to_string(),
                         None,
                     )))
                 }
@@ -109,6 +114,7 @@ pub fn create_string_bindings(env: &Arc<ThreadSafeEnvironment>) {
     bind_srfi13_constructors(env);
     bind_srfi13_selection(env);
     bind_srfi13_searching(env);
+    bind_srfi13_affixes(env);
     bind_srfi13_modification(env);
 }
 
@@ -175,6 +181,8 @@ fn bind_string_manipulation(env: &Arc<ThreadSafeEnvironment>) {
     // SRFI-13 enhanced case operations
     bind_primitive!(env, "string-titlecase", 1, Some(3), primitive_string_titlecase, vec![Effect::Pure]);
     bind_primitive!(env, "string-reverse", 1, Some(3), primitive_string_reverse, vec![Effect::Pure]);
+    bind_primitive!(env, "string-reverse!", 1, Some(3), primitive_string_reverse_mut, vec![Effect::State]);
+    bind_primitive!(env, "string-reverse/graphemes", 1, Some(1), primitive_string_reverse_graphemes, vec![Effect::Pure]);
 }
 
 /// Binds string conversion operations.
@@ -189,7 +197,15 @@ fn bind_string_conversion(env: &Arc<ThreadSafeEnvironment>) {
 
 /// Binds string iteration (higher-order) operations.
 fn bind_string_iteration(env: &Arc<ThreadSafeEnvironment>) {
-    bind_primitive!(env, "string-for-each", 2, None, primitive_string_for_each, vec![Effect::Pure]);
+    let string_for_each = Value::Primitive(Arc::new(PrimitiveProcedure {
+        name: "string-for-each".to_owned(),
+        arity_min: 2,
+        arity_max: None,
+        implementation: PrimitiveImpl::EvaluatorIntegrated(evaluator_string_for_each),
+        effects: vec![Effect::Pure], // May call user functions with effects
+    }));
+    env.define("string-for-each".to_owned(), string_for_each.clone());
+    env.define("builtin:string-for-each".to_owned(), string_for_each);
     bind_primitive!(env, "string-map", 2, None, primitive_string_map, vec![Effect::Pure]);
 }
 
@@ -219,7 +235,7 @@ pub fn primitive_make_string(args: &[Value]) -> Result<Value> {
     
     let length = args[0].as_integer().ok_or_else(|| {
         DiagnosticError::runtime_error(
-            "make-string first argument must be a non-negative integer".to_string(),
+            format!("make-string first argument must be a non-negative integer, got {}", args[0]),
             None,
         )
     })?;
@@ -243,7 +259,7 @@ pub fn primitive_make_string(args: &[Value]) -> Result<Value> {
 
 /// string constructor from characters
 pub fn primitive_string(args: &[Value]) -> Result<Value> {
-    let mut result = String::new();
+    let mut result = String::with_capacity(args.len());
     
     for arg in args {
         let ch = extract_character(arg, "string")?;
@@ -281,50 +297,7 @@ pub fn primitive_string_copy(args: &[Value]) -> Result<Value> {
         )));
     }
     
-    let s = extract_string(&args[0], "string-copy")?;
-    let chars: Vec<char> = s.chars().collect();
-    let length = chars.len();
-    
-    let start = if args.len() > 1 {
-        let start_idx = args[1].as_integer().ok_or_else(|| {
-            DiagnosticError::runtime_error(
-                "string-copy start index must be an integer".to_string(),
-                None,
-            )
-        })? as usize;
-        
-        if start_idx > length {
-            return Err(Box::new(DiagnosticError::runtime_error(
-                "string-copy start index out of bounds".to_string(),
-                None,
-            )));
-        }
-        start_idx
-    } else {
-        0
-    };
-    
-    let end = if args.len() > 2 {
-        let end_idx = args[2].as_integer().ok_or_else(|| {
-            DiagnosticError::runtime_error(
-                "string-copy end index must be an integer".to_string(),
-                None,
-            )
-        })? as usize;
-        
-        if end_idx > length || end_idx < start {
-            return Err(Box::new(DiagnosticError::runtime_error(
-                "string-copy end index out of bounds".to_string(),
-                None,
-            )));
-        }
-        end_idx
-    } else {
-        length
-    };
-    
-    let result: String = chars[start..end].iter().collect();
-    Ok(Value::string(result))
+    copy_string_range(args, "string-copy")
 }
 
 // ============= STRING PREDICATE IMPLEMENTATIONS =============
@@ -380,17 +353,15 @@ pub fn primitive_string_ref(args: &[Value]) -> Result<Value> {
     
     match &args[0] {
         Value::Literal(crate::ast::Literal::String(s)) => {
-            let chars: Vec<char> = s.chars().collect();
-            
-            if index >= chars.len() {
-                return Err(Box::new(DiagnosticError::runtime_error(
+            match string_char_at(s, index) {
+                Some(ch) => Ok(Value::Literal(crate::ast::Literal::Character(ch))),
+                None => Err(Box::new(DiagnosticError::runtime_error(
                     "string-ref index out of bounds".to_string(),
                     None,
-                )));
+                ))),
             }
-            
-            Ok(Value::Literal(crate::ast::Literal::Character(chars[index])))
         }
+        // Mutable strings are stored as scalar vectors, so indexing is already O(1)
         Value::MutableString(chars_arc) => {
             let chars = chars_arc.read().map_err(|_| {
                 DiagnosticError::runtime_error(
@@ -432,7 +403,15 @@ fn primitive_string_set(args: &[Value]) -> Result<Value> {
             "string-set! index must be an integer".to_string(),
             None,
         )
-    })? as usize;
+    })?;
+    
+    if index < 0 {
+        return Err(Box::new(DiagnosticError::runtime_error(
+            "string-set! index must be non-negative".to_string(),
+            None,
+        )));
+    }
+    let index = index as usize;
     
     let new_char = match &args[2] {
         Value::Literal(crate::ast::Literal::Character(ch)) => *ch,
@@ -461,15 +440,12 @@ fn primitive_string_set(args: &[Value]) -> Result<Value> {
                 )));
             }
             
+            // Storage is indexed by scalar value, so replacing a character
+            // with one of a different UTF-8 width needs no re-encoding
             chars[index] = new_char;
             Ok(Value::Unspecified)
         }
-        Value::Literal(crate::ast::Literal::String(_)) => {
-            Err(Box::new(DiagnosticError::runtime_error(
-                "string-set! can only be used with mutable strings".to_string(),
-                None,
-            )))
-        }
+        Value::Literal(crate::ast::Literal::String(_)) => Err(immutable_string_error("string-set!")),
         _ => {
             Err(Box::new(DiagnosticError::runtime_error(
                 "string-set! first argument must be a string".to_string(),
@@ -479,6 +455,131 @@ fn primitive_string_set(args: &[Value]) -> Result<Value> {
     }
 }
 
+// ============= STRING INDEX CACHE =============
+
+/// Number of scalar values between recorded byte offsets in a [`StringIndex`].
+const STRING_INDEX_STRIDE: usize = 64;
+
+/// Strings shorter than this many bytes are indexed by a direct scan.
+const STRING_INDEX_MIN_BYTES: usize = 256;
+
+/// Maximum number of indices kept in the per-thread cache.
+const STRING_INDEX_CACHE_SIZE: usize = 4;
+
+/// Chunked scalar→byte offset index for UTF-8 strings.
+///
+/// Records the byte offset of every `STRING_INDEX_STRIDE`th scalar value so
+/// that locating scalar `i` only decodes at most one stride of characters.
+#[derive(Debug, Clone)]
+pub struct StringIndex {
+    /// Byte offset of scalar `k * STRING_INDEX_STRIDE` for each `k`
+    checkpoints: Vec<usize>,
+    /// Number of Unicode scalar values in the string
+    char_len: usize,
+    /// Whether every scalar is a single byte (scalar index == byte index)
+    ascii: bool,
+}
+
+impl StringIndex {
+    /// Builds an index for `text` in a single pass.
+    pub fn new(text: &str) -> Self {
+        if text.is_ascii() {
+            return Self { checkpoints: Vec::new(), char_len: text.len(), ascii: true };
+        }
+
+        let mut checkpoints = Vec::with_capacity(text.len() / STRING_INDEX_STRIDE + 1);
+        let mut char_len = 0;
+        for (byte_offset, _) in text.char_indices() {
+            if char_len % STRING_INDEX_STRIDE == 0 {
+                checkpoints.push(byte_offset);
+            }
+            char_len += 1;
+        }
+
+        Self { checkpoints, char_len, ascii: false }
+    }
+
+    /// Returns the number of Unicode scalar values in the indexed string.
+    pub fn char_len(&self) -> usize {
+        self.char_len
+    }
+
+    /// Returns the byte offset of scalar `index` in `text`, which must be the
+    /// string this index was built from.
+    pub fn byte_offset(&self, text: &str, index: usize) -> Option<usize> {
+        if index >= self.char_len {
+            return None;
+        }
+        if self.ascii {
+            return Some(index);
+        }
+
+        let start = self.checkpoints[index / STRING_INDEX_STRIDE];
+        text[start..]
+            .char_indices()
+            .nth(index % STRING_INDEX_STRIDE)
+            .map(|(offset, _)| start + offset)
+    }
+
+    /// Returns scalar `index` of `text`, which must be the string this index
+    /// was built from.
+    pub fn char_at(&self, text: &str, index: usize) -> Option<char> {
+        self.byte_offset(text, index)
+            .and_then(|offset| text[offset..].chars().next())
+    }
+}
+
+/// A cached [`StringIndex`] with the string it was built from.
+struct CachedStringIndex {
+    /// The indexed string
+    text: String,
+    /// Index over `text`
+    index: StringIndex,
+}
+
+impl CachedStringIndex {
+    /// Whether this entry indexes `text`. Entries are matched by content
+    /// alone: values are copied on lookup, and a freed string's address can
+    /// be reused by a different one.
+    fn indexes(&self, text: &str) -> bool {
+        self.text == text
+    }
+}
+
+thread_local! {
+    /// Recently used indices, most recent last.
+    static STRING_INDEX_CACHE: std::cell::RefCell<Vec<CachedStringIndex>> =
+        const { std::cell::RefCell::new(Vec::new()) };
+}
+
+/// Returns scalar `index` of `text`.
+///
+/// Small strings are scanned directly. Larger strings use a cached
+/// [`StringIndex`], which indexes ASCII strings by byte and otherwise decodes
+/// at most one stride of characters.
+fn string_char_at(text: &str, index: usize) -> Option<char> {
+    if text.len() < STRING_INDEX_MIN_BYTES {
+        return text.chars().nth(index);
+    }
+
+    STRING_INDEX_CACHE.with(|cache| {
+        let mut cache = cache.borrow_mut();
+        let position = cache.iter().rposition(|entry| entry.indexes(text));
+        let entry = match position {
+            Some(position) => cache.remove(position),
+            None => {
+                if cache.len() >= STRING_INDEX_CACHE_SIZE {
+                    cache.remove(0);
+                }
+                CachedStringIndex { text: text.to_string(), index: StringIndex::new(text) }
+            }
+        };
+        let result = entry.index.char_at(text, index);
+        cache.push(entry);
+        result
+    })
+}
+
 // ============= STRING COMPARISON IMPLEMENTATIONS =============
 
 /// string=? procedure
@@ -682,12 +783,29 @@ pub fn primitive_string_ci_greater_equal(args: &[Value]) -> Result<Value> {
 // ============= STRING MANIPULATION IMPLEMENTATIONS =============
 
 /// string-append procedure
+///
+/// Sizes the result before copying so the strings are concatenated with a
+/// single allocation.
 pub fn primitive_string_append(args: &[Value]) -> Result<Value> {
-    let mut result = String::new();
+    let mut total_len = 0;
+    for arg in args {
+        total_len += match arg {
+            Value::Literal(Literal::String(s)) => s.len(),
+            Value::MutableString(chars) => chars.read().unwrap().iter().map(|ch| ch.len_utf8()).sum(),
+            _ => return Err(Box::new(DiagnosticError::runtime_error(
+                "string-append requires string arguments".to_string(),
+                None,
+            ))),
+        };
+    }
     
+    let mut result = String::with_capacity(total_len);
     for arg in args {
-        let s = extract_string_owned(arg, "string-append")?;
-        result.push_str(&s);
+        match arg {
+            Value::Literal(Literal::String(s)) => result.push_str(s),
+            Value::MutableString(chars) => result.extend(chars.read().unwrap().iter()),
+            _ => unreachable!("string-append arguments were checked above"),
+        }
     }
     
     Ok(Value::string(result))
@@ -702,33 +820,7 @@ pub fn primitive_substring(args: &[Value]) -> Result<Value> {
         )));
     }
     
-    let s = extract_string_owned(&args[0], "substring")?;
-    let start = args[1].as_integer().ok_or_else(|| {
-        DiagnosticError::runtime_error(
-            "substring start index must be an integer".to_string(),
-            None,
-        )
-    })? as usize;
-    
-    let end = args[2].as_integer().ok_or_else(|| {
-        DiagnosticError::runtime_error(
-            "substring end index must be an integer".to_string(),
-            None,
-        )
-    })? as usize;
-    
-    let chars: Vec<char> = s.chars().collect();
-    let length = chars.len();
-    
-    if start > length || end > length || start > end {
-        return Err(Box::new(DiagnosticError::runtime_error(
-            "substring indices out of bounds".to_string(),
-            None,
-        )));
-    }
-    
-    let result: String = chars[start..end].iter().collect();
-    Ok(Value::string(result))
+    copy_string_range(args, "substring")
 }
 
 /// string-fill! procedure (mutation)
@@ -753,12 +845,19 @@ fn primitive_string_fill(args: &[Value]) -> Result<Value> {
     
     // Optional start index (defaults to 0)
     let start_idx = if args.len() > 2 {
-        args[2].as_integer().ok_or_else(|| {
+        let start = args[2].as_integer().ok_or_else(|| {
             DiagnosticError::runtime_error(
                 "string-fill! start index must be an integer".to_string(),
                 None,
             )
-        })? as usize
+        })?;
+        if start < 0 {
+            return Err(Box::new(DiagnosticError::runtime_error(
+                "string-fill! start index must be non-negative".to_string(),
+                None,
+            )));
+        }
+        start as usize
     } else {
         0
     };
@@ -781,7 +880,15 @@ fn primitive_string_fill(args: &[Value]) -> Result<Value> {
                         "string-fill! end index must be an integer".to_string(),
                         None,
                     )
-                })? as usize;
+                })?;
+                
+                if end < 0 {
+                    return Err(Box::new(DiagnosticError::runtime_error(
+                        "string-fill! end index must be non-negative".to_string(),
+                        None,
+                    )));
+                }
+                let end = end as usize;
                 
                 if end > string_len {
                     return Err(Box::new(DiagnosticError::runtime_error(
@@ -816,12 +923,7 @@ fn primitive_string_fill(args: &[Value]) -> Result<Value> {
             
             Ok(Value::Unspecified)
         }
-        Value::Literal(crate::ast::Literal::String(_)) => {
-            Err(Box::new(DiagnosticError::runtime_error(
-                "string-fill! can only be used with mutable strings".to_string(),
-                None,
-            )))
-        }
+        Value::Literal(crate::ast::Literal::String(_)) => Err(immutable_string_error("string-fill!")),
         _ => {
             Err(Box::new(DiagnosticError::runtime_error(
                 "string-fill! first argument must be a string".to_string(),
@@ -857,6 +959,10 @@ pub fn primitive_string_upcase(args: &[Value]) -> Result<Value> {
     }
     
     let s = extract_string(&args[0], "string-upcase")?;
+    // Full case mapping: a character may expand, as in ß → SS
+    if s.is_ascii() {
+        return Ok(Value::string(s.to_ascii_uppercase()));
+    }
     Ok(Value::string(s.to_uppercase()))
 }
 
@@ -870,6 +976,10 @@ pub fn primitive_string_downcase(args: &[Value]) -> Result<Value> {
     }
     
     let s = extract_string(&args[0], "string-downcase")?;
+    // Full case mapping, including the word-final form of sigma
+    if s.is_ascii() {
+        return Ok(Value::string(s.to_ascii_lowercase()));
+    }
     Ok(Value::string(s.to_lowercase()))
 }
 
@@ -898,9 +1008,8 @@ pub fn primitive_string_to_list(args: &[Value]) -> Result<Value> {
         )));
     }
     
-    let s = extract_string(&args[0], "string->list")?;
-    let chars: Vec<char> = s.chars().collect();
-    let length = chars.len();
+    let s = extract_string_cow(&args[0], "string->list")?;
+    let length = s.chars().count();
     
     let start = if args.len() > 1 {
         args[1].as_integer().ok_or_else(|| {
@@ -931,12 +1040,14 @@ pub fn primitive_string_to_list(args: &[Value]) -> Result<Value> {
         )));
     }
     
-    let char_values: Vec<Value> = chars[start..end]
-        .iter()
-        .map(|&c| Value::Literal(crate::ast::Literal::Character(c)))
-        .collect();
+    // Cons the pairs directly from the end of the range, with no
+    // intermediate buffer of characters or values
+    let byte_offset = |index| s.char_indices().nth(index).map_or(s.len(), |(i, _)| i);
+    let range = &s[byte_offset(start)..byte_offset(end)];
     
-    Ok(Value::list(char_values))
+    Ok(range.chars().rev().fold(Value::Nil, |list, c| {
+        Value::pair(Value::Literal(Literal::Character(c)), list)
+    }))
 }
 
 /// list->string procedure
@@ -948,19 +1059,12 @@ pub fn primitive_list_to_string(args: &[Value]) -> Result<Value> {
         )));
     }
     
-    let list = args[0].as_list().ok_or_else(|| {
-        DiagnosticError::runtime_error(
-            "list->string requires a list argument".to_string(),
-            None,
-        )
-    })?;
-    
-    let mut result = String::new();
+    // The first walk validates the list and sizes the buffer exactly
+    let mut byte_length = 0;
+    for_each_list_char(&args[0], "list->string", |ch| byte_length += ch.len_utf8())?;
     
-    for value in list {
-        let ch = extract_character(&value, "list->string")?;
-        result.push(ch);
-    }
+    let mut result = String::with_capacity(byte_length);
+    for_each_list_char(&args[0], "list->string", |ch| result.push(ch))?;
     
     Ok(Value::string(result))
 }
@@ -1162,12 +1266,86 @@ fn extract_string_owned(value: &Value, operation: &str) -> Result<String> {
     })
 }
 
+/// Extracts a string from a Value, borrowing immutable strings and copying
+/// the contents of mutable ones.
+fn extract_string_cow<'a>(value: &'a Value, operation: &str) -> Result<Cow<'a, str>> {
+    match value.as_string() {
+        Some(s) => Ok(Cow::Borrowed(s)),
+        None => extract_string_owned(value, operation).map(Cow::Owned),
+    }
+}
+
+/// Copies the characters of the string in `args[0]` between the optional
+/// scalar indices `args[1]` and `args[2]` into a fresh mutable string, as
+/// shared by `substring` and `string-copy`.
+fn copy_string_range(args: &[Value], operation: &str) -> Result<Value> {
+    let s = extract_string_cow(&args[0], operation)?;
+    let length = s.chars().count();
+    let start = optional_string_index(args.get(1), 0, operation)?;
+    let end = optional_string_index(args.get(2), length, operation)?;
+    
+    if start > end || end > length {
+        return Err(Box::new(DiagnosticError::runtime_error(
+            format!("{operation}: range {start}..{end} is invalid for a string of length {length}"),
+            None,
+        )));
+    }
+    
+    let copy: String = s.chars().skip(start).take(end - start).collect();
+    Ok(Value::mutable_string(copy))
+}
+
+/// Extracts an optional non-negative index argument, defaulting when absent.
+fn optional_string_index(value: Option<&Value>, default: usize, operation: &str) -> Result<usize> {
+    let Some(value) = value else {
+        return Ok(default);
+    };
+    match value.as_integer() {
+        Some(index) if index >= 0 => Ok(index as usize),
+        _ => Err(Box::new(DiagnosticError::runtime_error(
+            format!("{operation}: index must be a non-negative integer, got {value}"),
+            None,
+        ))),
+    }
+}
+
+/// Error raised when a mutation procedure is applied to an immutable string
+/// such as a literal constant.
+fn immutable_string_error(operation: &str) -> Box<DiagnosticError> {
+    Box::new(DiagnosticError::runtime_error(
+        format!("{operation}: cannot mutate an immutable string literal"),
+        None,
+    ))
+}
+
+/// Calls `f` on each element of a proper list of characters, in order.
+fn for_each_list_char(list: &Value, operation: &str, mut f: impl FnMut(char)) -> Result<()> {
+    let mut current = list.clone();
+    loop {
+        current = match &current {
+            Value::Nil => return Ok(()),
+            Value::Pair(car, cdr) => {
+                f(extract_character(car, operation)?);
+                (**cdr).clone()
+            }
+            Value::MutablePair(car, cdr) => {
+                f(extract_character(&car.read().unwrap(), operation)?);
+                cdr.read().unwrap().clone()
+            }
+            _ => return Err(Box::new(DiagnosticError::runtime_error(
+                format!("{operation} requires a proper list argument, got {list}"),
+                None,
+            ))),
+        };
+    }
+}
+
 /// Extracts a character from a Value.
 fn extract_character(value: &Value, operation: &str) -> Result<char> {
     match value {
         Value::Literal(crate::ast::Literal::Character(c)) => Ok(*c),
         _ => Err(Box::new(DiagnosticError::runtime_error(
-            format!("{operation} requires character arguments"),
+            format!("{operation} requires character arguments, got {value}"),
             None,
         ))),
     }
@@ -1176,7 +1354,10 @@ fn extract_character(value: &Value, operation: &str) -> Result<char> {
 // ============= STRING ITERATION IMPLEMENTATIONS =============
 
 /// string-for-each procedure - R7RS required
-fn primitive_string_for_each(args: &[Value]) -> Result<Value> {
+///
+/// Walks the strings' scalar values in lockstep, so no character vectors or
+/// argument lists are built beyond the arguments of each call.
+fn evaluator_string_for_each(evaluator: &mut crate::eval::evaluator::Evaluator, args: &[Value]) -> Result<Value> {
     if args.len() < 2 {
         return Err(Box::new(DiagnosticError::runtime_error(
             "string-for-each requires at least 2 arguments".to_string(),
@@ -1194,73 +1375,23 @@ fn primitive_string_for_each(args: &[Value]) -> Result<Value> {
         )));
     }
     
-    let strings: Result<Vec<&str>> = args[1..].iter()
-        .map(|v| extract_string(v, "string-for-each"))
+    let strings: Result<Vec<Cow<'_, str>>> = args[1..].iter()
+        .map(|v| extract_string_cow(v, "string-for-each"))
         .collect();
     let strings = strings?;
-    
-    if strings.is_empty() {
-        return Ok(Value::Unspecified);
-    }
-    
-    // Find minimum length among all strings
-    let min_length = strings.iter()
-        .map(|s| s.chars().count())
-        .min()
-        .unwrap_or(0);
-    
-    // If any string is empty, return unspecified immediately
-    if min_length == 0 {
-        return Ok(Value::Unspecified);
-    }
-    
-    // Convert strings to character vectors for efficient indexing
-    let char_vectors: Vec<Vec<char>> = strings.iter()
-        .map(|s| s.chars().collect())
-        .collect();
-    
-    // Apply procedure to each character position for side effects
-    for i in 0..min_length {
-        let char_args: Vec<Value> = char_vectors.iter()
-            .map(|chars| Value::Literal(crate::ast::Literal::Character(chars[i])))
-            .collect();
-        
-        // Apply the procedure - for now we can only handle primitive procedures
-        match procedure {
-            Value::Primitive(prim) => {
-                match &prim.implementation {
-                    PrimitiveImpl::RustFn(func) => {
-                        // Call the function but ignore the result (for-each is for side effects)
-                        func(&char_args)?;
-                    },
-                    PrimitiveImpl::Native(func) => {
-                        // Call the function but ignore the result (for-each is for side effects)
-                        func(&char_args)?;
-                    },
-                    PrimitiveImpl::EvaluatorIntegrated(_) => {
-                        return Err(Box::new(DiagnosticError::runtime_error(
-                            "string-for-each with evaluator-integrated functions requires evaluator access".to_string(),
-                            None,
-                        )));
-                    },
-                    PrimitiveImpl::ForeignFn { .. } => {
-                        return Err(Box::new(DiagnosticError::runtime_error(
-                            "string-for-each with foreign functions not yet implemented".to_string(),
-                            None,
-                        )));
-                    }
-                }
-            },
-            _ => {
-                return Err(Box::new(DiagnosticError::runtime_error(
-                    "string-for-each with user-defined procedures requires evaluator integration (not yet implemented)".to_string(),
-                    None,
-                )));
+    let mut iterators: Vec<_> = strings.iter().map(|s| s.chars()).collect();
+    
+    // Stop at the end of the shortest string
+    loop {
+        let mut char_args = Vec::with_capacity(iterators.len());
+        for chars in &mut iterators {
+            match chars.next() {
+                Some(ch) => char_args.push(Value::Literal(Literal::Character(ch))),
+                None => return Ok(Value::Unspecified),
             }
         }
+        evaluator.call_procedure(procedure.clone(), char_args, None)?;
     }
-    
-    Ok(Value::Unspecified)
 }
 
 /// string-map procedure - R7RS required
@@ -1561,7 +1692,24 @@ fn bind_srfi13_searching(env: &Arc<ThreadSafeEnvironment>) {
     bind_primitive!(env, "string-skip-right", 2, Some(4), primitive_string_skip_right, vec![Effect::Pure]);
     bind_primitive!(env, "string-contains", 2, Some(4), primitive_string_contains, vec![Effect::Pure]);
     bind_primitive!(env, "string-contains-ci", 2, Some(4), primitive_string_contains_ci, vec![Effect::Pure]);
-    bind_primitive!(env, "string-count", 2, Some(4), primitive_string_count, vec![Effect::Pure]);
+
+    let string_count = Value::Primitive(Arc::new(PrimitiveProcedure {
+        name: "string-count".to_owned(),
+        arity_min: 2,
+        arity_max: Some(4),
+        implementation: PrimitiveImpl::EvaluatorIntegrated(evaluator_string_count),
+        effects: vec![Effect::Pure], // May call a user predicate with effects
+    }));
+    env.define("string-count".to_owned(), string_count.clone());
+    env.define("builtin:string-count".to_owned(), string_count);
+}
+
+/// Binds SRFI-13 prefix and suffix operations.
+fn bind_srfi13_affixes(env: &Arc<ThreadSafeEnvironment>) {
+    bind_primitive!(env, "string-prefix-length", 2, Some(6), primitive_string_prefix_length, vec![Effect::Pure]);
+    bind_primitive!(env, "string-suffix-length", 2, Some(6), primitive_string_suffix_length, vec![Effect::Pure]);
+    bind_primitive!(env, "string-prefix?", 2, Some(6), primitive_string_prefix_p, vec![Effect::Pure]);
+    bind_primitive!(env, "string-suffix?", 2, Some(6), primitive_string_suffix_p, vec![Effect::Pure]);
 }
 
 /// Binds SRFI-13 string modification operations.
@@ -2595,8 +2743,12 @@ pub fn primitive_string_contains_ci(args: &[Value]) -> Result<Value> {
     }
 }
 
-/// string-count procedure - count occurrences of characters in character set
-pub fn primitive_string_count(args: &[Value]) -> Result<Value> {
+/// string-count procedure - count characters matching a character, char-set,
+/// or predicate
+///
+/// Counts the Unicode scalar values in `[start, end)`. A predicate is applied
+/// to each of them in order, and an error it raises ends the count.
+fn evaluator_string_count(evaluator: &mut crate::eval::evaluator::Evaluator, args: &[Value]) -> Result<Value> {
     if args.len() < 2 || args.len() > 4 {
         return Err(Box::new(DiagnosticError::runtime_error(
             format!("string-count expects 2 to 4 arguments, got {}", args.len()),
@@ -2604,38 +2756,95 @@ pub fn primitive_string_count(args: &[Value]) -> Result<Value> {
         )));
     }
     
-    let s = extract_string(&args[0], "string-count")?;
-    let charset = CharacterSet::from_value(&args[1])?;
-    
-    let chars: Vec<char> = s.chars().collect();
-    let len = chars.len();
-    
-    let start = if args.len() > 2 {
-        args[2].as_integer().unwrap_or(0) as usize
-    } else {
-        0
-    };
-    
-    let end = if args.len() > 3 {
-        args[3].as_integer().unwrap_or(len as i64) as usize
-    } else {
-        len
-    };
+    let s = extract_string_cow(&args[0], "string-count")?;
+    let length = s.chars().count();
+    let start = optional_string_index(args.get(2), 0, "string-count")?;
+    let end = optional_string_index(args.get(3), length, "string-count")?;
     
-    if start > len || end > len || start > end {
+    if start > end || end > length {
         return Err(Box::new(DiagnosticError::runtime_error(
-            "string-count indices out of bounds".to_string(),
+            format!("string-count: range {start}..{end} is invalid for a string of length {length}"),
             None,
         )));
     }
     
-    let count = chars[start..end].iter()
-        .filter(|&&ch| charset.contains(ch))
-        .count();
+    let chars = s.chars().skip(start).take(end - start);
+    let count = if args[1].is_procedure() {
+        let mut count = 0;
+        for ch in chars {
+            let matched = evaluator.call_procedure(args[1].clone(), vec![Value::Literal(Literal::Character(ch))], None)?;
+            if matched.is_truthy() {
+                count += 1;
+            }
+        }
+        count
+    } else {
+        let charset = CharacterSet::from_value(&args[1])?;
+        chars.filter(|&ch| charset.contains(ch)).count()
+    };
     
     Ok(Value::integer(count as i64))
 }
 
+// SRFI-13 Prefixes and Suffixes
+
+/// Extracts the characters of `s1[start1, end1)` and `s2[start2, end2)` from
+/// the arguments `(s1 s2 [start1 end1 start2 end2])`.
+fn string_affix_ranges(args: &[Value], operation: &str) -> Result<(Vec<char>, Vec<char>)> {
+    let s1: Vec<char> = extract_string_cow(&args[0], operation)?.chars().collect();
+    let s2: Vec<char> = extract_string_cow(&args[1], operation)?.chars().collect();
+    
+    let start1 = optional_string_index(args.get(2), 0, operation)?;
+    let end1 = optional_string_index(args.get(3), s1.len(), operation)?;
+    let start2 = optional_string_index(args.get(4), 0, operation)?;
+    let end2 = optional_string_index(args.get(5), s2.len(), operation)?;
+    
+    for (start, end, length) in [(start1, end1, s1.len()), (start2, end2, s2.len())] {
+        if start > end || end > length {
+            return Err(Box::new(DiagnosticError::runtime_error(
+                format!("{operation}: range {start}..{end} is invalid for a string of length {length}"),
+                None,
+            )));
+        }
+    }
+    
+    Ok((s1[start1..end1].to_vec(), s2[start2..end2].to_vec()))
+}
+
+/// Counts the characters the two ranges share at their start.
+fn common_prefix_length(s1: &[char], s2: &[char]) -> usize {
+    s1.iter().zip(s2).take_while(|(a, b)| a == b).count()
+}
+
+/// Counts the characters the two ranges share at their end.
+fn common_suffix_length(s1: &[char], s2: &[char]) -> usize {
+    s1.iter().rev().zip(s2.iter().rev()).take_while(|(a, b)| a == b).count()
+}
+
+/// string-prefix-length procedure - length of the common prefix of two strings
+pub fn primitive_string_prefix_length(args: &[Value]) -> Result<Value> {
+    let (s1, s2) = string_affix_ranges(args, "string-prefix-length")?;
+    Ok(Value::integer(common_prefix_length(&s1, &s2) as i64))
+}
+
+/// string-suffix-length procedure - length of the common suffix of two strings
+pub fn primitive_string_suffix_length(args: &[Value]) -> Result<Value> {
+    let (s1, s2) = string_affix_ranges(args, "string-suffix-length")?;
+    Ok(Value::integer(common_suffix_length(&s1, &s2) as i64))
+}
+
+/// string-prefix? procedure - test whether the first string is a prefix of the second
+pub fn primitive_string_prefix_p(args: &[Value]) -> Result<Value> {
+    let (s1, s2) = string_affix_ranges(args, "string-prefix?")?;
+    Ok(Value::boolean(common_prefix_length(&s1, &s2) == s1.len()))
+}
+
+/// string-suffix? procedure - test whether the first string is a suffix of the second
+pub fn primitive_string_suffix_p(args: &[Value]) -> Result<Value> {
+    let (s1, s2) = string_affix_ranges(args, "string-suffix?")?;
+    Ok(Value::boolean(common_suffix_length(&s1, &s2) == s1.len()))
+}
+
 // SRFI-13 String Comparison and Hashing
 
 /// string-compare procedure - three-way comparison
@@ -2840,7 +3049,7 @@ pub fn primitive_string_reverse(args: &[Value]) -> Result<Value> {
         )));
     }
     
-    let s = extract_string(&args[0], "string-reverse")?;
+    let s = extract_string_cow(&args[0], "string-reverse")?;
     
     let chars: Vec<char> = s.chars().collect();
     let len = chars.len();
@@ -2875,6 +3084,51 @@ pub fn primitive_string_reverse(args: &[Value]) -> Result<Value> {
     Ok(Value::string(format!("{prefix}{reversed}{suffix}")))
 }
 
+/// string-reverse! procedure - reverse a mutable string in place
+pub fn primitive_string_reverse_mut(args: &[Value]) -> Result<Value> {
+    let chars_arc = match &args[0] {
+        Value::MutableString(chars_arc) => chars_arc,
+        Value::Literal(Literal::String(_)) => return Err(immutable_string_error("string-reverse!")),
+        _ => {
+            return Err(Box::new(DiagnosticError::runtime_error(
+                "string-reverse! first argument must be a string".to_string(),
+                None,
+            )))
+        }
+    };
+    let mut chars = chars_arc.write().map_err(|_| {
+        DiagnosticError::runtime_error(
+            "string-reverse! failed to acquire write lock on string".to_string(),
+            None,
+        )
+    })?;
+    
+    let length = chars.len();
+    let start = optional_string_index(args.get(1), 0, "string-reverse!")?;
+    let end = optional_string_index(args.get(2), length, "string-reverse!")?;
+    if start > end || end > length {
+        return Err(Box::new(DiagnosticError::runtime_error(
+            format!("string-reverse!: range {start}..{end} is invalid for a string of length {length}"),
+            None,
+        )));
+    }
+    
+    chars[start..end].reverse();
+    Ok(Value::Unspecified)
+}
+
+/// string-reverse/graphemes procedure - reverse by extended grapheme cluster
+///
+/// Unlike `string-reverse`, combining marks and emoji ZWJ sequences stay
+/// attached to the characters they modify.
+pub fn primitive_string_reverse_graphemes(args: &[Value]) -> Result<Value> {
+    use unicode_segmentation::UnicodeSegmentation;
+    
+    let s = extract_string_cow(&args[0], "string-reverse/graphemes")?;
+    let reversed: String = s.graphemes(true).rev().collect();
+    Ok(Value::string(reversed))
+}
+
 /// string-replace procedure (SRFI-13 enhanced version)
 pub fn primitive_string_replace_srfi13(args: &[Value]) -> Result<Value> {
     if args.len() < 4 || args.len() > 6 {
@@ -2982,6 +3236,18 @@ mod tests {
         assert_eq!(result, Value::string(""));
     }
     
+    #[test]
+    fn test_string_append_mixed_and_many() {
+        let args = vec![Value::string("ab"), Value::mutable_string("λc"), Value::string("d")];
+        assert_eq!(primitive_string_append(&args).unwrap(), Value::string("abλcd"));
+        
+        let parts: Vec<Value> = (0..10_000).map(|_| Value::string("xy")).collect();
+        let result = primitive_string_append(&parts).unwrap();
+        assert_eq!(result.string_length(), Some(20_000));
+        
+        assert!(primitive_string_append(&[Value::string("a"), Value::integer(1)]).is_err());
+    }
+    
     #[test]
     fn test_string_ref() {
         let args = vec![Value::string("hello"), Value::integer(1)];
@@ -3011,6 +3277,19 @@ mod tests {
         assert_eq!(result, Value::string("hello world"));
     }
     
+    #[test]
+    fn test_full_case_mapping() {
+        // Full mappings may change the length of the string
+        let result = primitive_string_upcase(&[Value::string("straße")]).unwrap();
+        assert_eq!(result, Value::string("STRASSE"));
+        
+        let result = primitive_string_upcase(&[Value::string("ﬁne")]).unwrap();
+        assert_eq!(result, Value::string("FINE"));
+        
+        let result = primitive_string_downcase(&[Value::string("ΟΔΟΣ")]).unwrap();
+        assert_eq!(result, Value::string("οδος"));
+    }
+    
     #[test]
     fn test_substring() {
         let args = vec![
@@ -3019,7 +3298,62 @@ mod tests {
             Value::integer(11),
         ];
         let result = primitive_substring(&args).unwrap();
-        assert_eq!(result, Value::string("world"));
+        assert_eq!(result.as_string_owned().as_deref(), Some("world"));
+    }
+    
+    #[test]
+    fn test_substring_and_string_copy_ranges() {
+        let text = || Value::string("λx.y");
+        let copy = |args: &[Value]| primitive_string_copy(args).unwrap().as_string_owned().unwrap();
+        let substring = |start, end| {
+            primitive_substring(&[text(), Value::integer(start), Value::integer(end)])
+                .map(|value| value.as_string_owned().unwrap())
+        };
+        
+        // Indices count characters, not bytes
+        assert_eq!(substring(1, 3).unwrap(), "x.");
+        assert_eq!(copy(&[text()]), "λx.y");
+        assert_eq!(copy(&[text(), Value::integer(2)]), ".y");
+        assert_eq!(copy(&[text(), Value::integer(0), Value::integer(1)]), "λ");
+        
+        // Empty ranges, including start == end == length
+        assert_eq!(substring(2, 2).unwrap(), "");
+        assert_eq!(substring(4, 4).unwrap(), "");
+        assert_eq!(copy(&[Value::string("")]), "");
+        
+        // Swapped, overlong and negative bounds
+        assert!(substring(3, 1).is_err());
+        assert!(substring(0, 5).is_err());
+        assert!(substring(-1, 2).is_err());
+        assert!(primitive_string_copy(&[text(), Value::integer(5)]).is_err());
+        assert!(primitive_substring(&[text(), Value::integer(1)]).is_err());
+    }
+    
+    #[test]
+    fn test_string_copy_is_independent_of_source() {
+        let source = Value::mutable_string("abc");
+        let copy = primitive_string_copy(std::slice::from_ref(&source)).unwrap();
+        let piece = primitive_substring(&[source.clone(), Value::integer(0), Value::integer(2)]).unwrap();
+        
+        let set = |string: &Value, index, ch| {
+            primitive_string_set(&[
+                string.clone(),
+                Value::integer(index),
+                Value::Literal(crate::ast::Literal::Character(ch)),
+            ])
+            .unwrap();
+        };
+        set(&copy, 0, 'X');
+        set(&piece, 1, 'Y');
+        assert_eq!(source.as_string_owned().as_deref(), Some("abc"));
+        assert_eq!(copy.as_string_owned().as_deref(), Some("Xbc"));
+        assert_eq!(piece.as_string_owned().as_deref(), Some("aY"));
+        
+        // Copies of literals are mutable too, and equal to their source
+        let literal_copy = primitive_string_copy(&[Value::string("lit")]).unwrap();
+        assert_eq!(literal_copy, Value::string("lit"));
+        set(&literal_copy, 2, 'p');
+        assert_eq!(literal_copy.as_string_owned().as_deref(), Some("lip"));
     }
     
     #[test]
@@ -3108,6 +3442,7 @@ mod tests {
     #[test]
     fn test_string_for_each_basic() {
         // Test string-for-each with a simple side-effect procedure
+        let mut evaluator = crate::eval::evaluator::Evaluator::new();
         let identity_proc = Arc::new(PrimitiveProcedure {
             name: "identity".to_string(),
             arity_min: 1,
@@ -3118,7 +3453,7 @@ mod tests {
         
         let string = Value::string("hello");
         let args = vec![Value::Primitive(identity_proc), string];
-        let result = primitive_string_for_each(&args).unwrap();
+        let result = evaluator_string_for_each(&mut evaluator, &args).unwrap();
         
         // string-for-each should return unspecified
         assert_eq!(result, Value::Unspecified);
@@ -3126,6 +3461,7 @@ mod tests {
     
     #[test]
     fn test_string_for_each_multiple_strings() {
+        let mut evaluator = crate::eval::evaluator::Evaluator::new();
         let first_arg_proc = Arc::new(PrimitiveProcedure {
             name: "first-arg".to_string(),
             arity_min: 1,
@@ -3137,7 +3473,7 @@ mod tests {
         let string1 = Value::string("ab");
         let string2 = Value::string("xy");
         let args = vec![Value::Primitive(first_arg_proc), string1, string2];
-        let result = primitive_string_for_each(&args).unwrap();
+        let result = evaluator_string_for_each(&mut evaluator, &args).unwrap();
         
         assert_eq!(result, Value::Unspecified);
     }
@@ -3145,11 +3481,12 @@ mod tests {
     #[test]
     fn test_string_map_for_each_errors() {
         // Test errors for both string-map and string-for-each
+        let mut evaluator = crate::eval::evaluator::Evaluator::new();
         
         // Non-procedure first argument
         let args = vec![Value::integer(42), Value::string("hello")];
         assert!(primitive_string_map(&args).is_err());
-        assert!(primitive_string_for_each(&args).is_err());
+        assert!(evaluator_string_for_each(&mut evaluator, &args).is_err());
         
         // Non-string argument
         let proc = Arc::new(PrimitiveProcedure {
@@ -3161,15 +3498,15 @@ mod tests {
         });
         let args = vec![Value::Primitive(proc.clone()), Value::integer(42)];
         assert!(primitive_string_map(&args).is_err());
-        assert!(primitive_string_for_each(&args).is_err());
+        assert!(evaluator_string_for_each(&mut evaluator, &args).is_err());
         
         // Too few arguments
         assert!(primitive_string_map(&[]).is_err());
-        assert!(primitive_string_for_each(&[]).is_err());
+        assert!(evaluator_string_for_each(&mut evaluator, &[]).is_err());
         
         let args = vec![Value::Primitive(proc)];
         assert!(primitive_string_map(&args).is_err());
-        assert!(primitive_string_for_each(&args).is_err());
+        assert!(evaluator_string_for_each(&mut evaluator, &args).is_err());
     }
     
     #[test]
@@ -3246,6 +3583,60 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_string_set_multibyte_replacement() {
+        // Replacing a 1-byte scalar with a 3-byte one keeps scalar indexing intact
+        let mut_str = primitive_make_string(&[Value::integer(3), Value::Literal(crate::ast::Literal::Character('a'))]).unwrap();
+        primitive_string_set(&[
+            mut_str.clone(),
+            Value::integer(1),
+            Value::Literal(crate::ast::Literal::Character('€')),
+        ]).unwrap();
+        
+        assert_eq!(mut_str.string_length(), Some(3));
+        assert_eq!(mut_str.as_string_owned().unwrap().len(), 5);
+        for (i, expected) in ['a', '€', 'a'].into_iter().enumerate() {
+            assert_eq!(
+                primitive_string_ref(&[mut_str.clone(), Value::integer(i as i64)]).unwrap(),
+                Value::Literal(crate::ast::Literal::Character(expected))
+            );
+        }
+        
+        // Filling with a multibyte character over a sub-range
+        primitive_string_fill(&[
+            mut_str.clone(),
+            Value::Literal(crate::ast::Literal::Character('λ')),
+            Value::integer(1),
+            Value::integer(3),
+        ]).unwrap();
+        assert_eq!(mut_str.as_string_owned(), Some("aλλ".to_string()));
+        assert_eq!(mut_str.string_length(), Some(3));
+        
+        assert!(primitive_string_set(&[
+            mut_str.clone(),
+            Value::integer(-1),
+            Value::Literal(crate::ast::Literal::Character('x')),
+        ]).is_err());
+        assert!(primitive_string_fill(&[
+            mut_str,
+            Value::Literal(crate::ast::Literal::Character('x')),
+            Value::integer(2),
+            Value::integer(1),
+        ]).is_err());
+    }
+    
+    #[test]
+    fn test_string_mutation_of_literal_errors() {
+        let literal = Value::string("constant");
+        let ch = Value::Literal(crate::ast::Literal::Character('x'));
+        
+        let err = primitive_string_set(&[literal.clone(), Value::integer(0), ch.clone()]).unwrap_err();
+        assert!(err.to_string().contains("immutable string literal"));
+        let err = primitive_string_fill(&[literal.clone(), ch]).unwrap_err();
+        assert!(err.to_string().contains("immutable string literal"));
+        assert_eq!(literal.as_string_owned(), Some("constant".to_string()));
+    }
+
     #[test]
     fn test_string_set_bounds_error() {
         // Test string-set! with out-of-bounds index
@@ -3307,6 +3698,49 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_string_index_matches_reference() {
+        let text: String = "aé中😀b".chars().cycle().take(1000).collect();
+        let reference: Vec<char> = text.chars().collect();
+        let index = StringIndex::new(&text);
+
+        assert_eq!(index.char_len(), reference.len());
+        for (i, &expected) in reference.iter().enumerate() {
+            assert_eq!(index.char_at(&text, i), Some(expected));
+            let args = vec![Value::string(text.clone()), Value::integer(i as i64)];
+            assert_eq!(
+                primitive_string_ref(&args).unwrap(),
+                Value::Literal(crate::ast::Literal::Character(expected))
+            );
+        }
+        assert_eq!(index.char_at(&text, reference.len()), None);
+        assert!(primitive_string_ref(&[Value::string(text), Value::integer(1000)]).is_err());
+
+        let ascii = "x".repeat(500);
+        let index = StringIndex::new(&ascii);
+        assert_eq!(index.byte_offset(&ascii, 499), Some(499));
+        assert_eq!(index.char_at(&ascii, 500), None);
+    }
+
+    #[test]
+    fn test_string_ref_random_access_large_string() {
+        let text: String = "λx.".chars().cycle().take(100_000).collect();
+        let reference: Vec<char> = text.chars().collect();
+        let mut args = vec![Value::string(text), Value::integer(0)];
+
+        let start = std::time::Instant::now();
+        let mut seed: u64 = 0x2545_f491_4f6c_dd1d;
+        for _ in 0..20_000 {
+            seed = seed.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+            let i = (seed >> 33) as usize % reference.len();
+            args[1] = Value::integer(i as i64);
+            let result = primitive_string_ref(&args).unwrap();
+            assert_eq!(result, Value::Literal(crate::ast::Literal::Character(reference[i])));
+        }
+        // A full scan per access would take far longer than this
+        assert!(start.elapsed() < std::time::Duration::from_secs(5));
+    }
+
     #[test]
     fn test_string_ref_mutable() {
         // Test string-ref works with mutable strings
@@ -3365,8 +3799,47 @@ mod tests {
         let args = vec![mut_str, Value::integer(6), Value::integer(11)];
         
         let result = primitive_substring(&args).unwrap();
-        assert_eq!(result, Value::string("world"));
-        assert!(result.is_immutable_string()); // Result should be immutable
+        assert_eq!(result.as_string_owned().as_deref(), Some("world"));
+        assert!(result.is_mutable_string()); // Result is a fresh mutable string
+    }
+
+    #[test]
+    fn test_string_prefix_and_suffix() {
+        let strings = |a: &str, b: &str| vec![Value::string(a), Value::string(b)];
+
+        assert_eq!(primitive_string_prefix_p(&strings("λx", "λx.x")).unwrap(), Value::boolean(true));
+        assert_eq!(primitive_string_prefix_p(&strings("x.", "λx.x")).unwrap(), Value::boolean(false));
+        assert_eq!(primitive_string_suffix_p(&strings(".x", "λx.x")).unwrap(), Value::boolean(true));
+        assert_eq!(primitive_string_suffix_p(&strings("λx.x!", "λx.x")).unwrap(), Value::boolean(false));
+        assert_eq!(primitive_string_prefix_length(&strings("λxy", "λxz")).unwrap(), Value::integer(2));
+        assert_eq!(primitive_string_suffix_length(&strings("abcλ", "xbcλ")).unwrap(), Value::integer(3));
+
+        // The empty string is a prefix and suffix of everything
+        assert_eq!(primitive_string_prefix_p(&strings("", "abc")).unwrap(), Value::boolean(true));
+        assert_eq!(primitive_string_suffix_p(&strings("", "")).unwrap(), Value::boolean(true));
+        assert_eq!(primitive_string_prefix_length(&strings("", "abc")).unwrap(), Value::integer(0));
+    }
+
+    #[test]
+    fn test_string_prefix_and_suffix_bounded_ranges() {
+        let bounded = |a: &str, b: &str, bounds: [i64; 4]| {
+            let mut args = vec![Value::string(a), Value::string(b)];
+            args.extend(bounds.map(Value::integer));
+            args
+        };
+
+        // "bc" of "abcd" against "xbcx"[1..3]
+        assert_eq!(primitive_string_prefix_p(&bounded("abcd", "xbcx", [1, 3, 1, 3])).unwrap(), Value::boolean(true));
+        assert_eq!(primitive_string_suffix_p(&bounded("abcd", "xbcx", [1, 3, 0, 3])).unwrap(), Value::boolean(true));
+        assert_eq!(primitive_string_prefix_length(&bounded("abcd", "abxd", [0, 4, 0, 4])).unwrap(), Value::integer(2));
+        assert_eq!(primitive_string_suffix_length(&bounded("abcd", "zzcd", [0, 3, 0, 3])).unwrap(), Value::integer(1));
+
+        // Only the first string's range given
+        let args = vec![Value::string("hello"), Value::string("help"), Value::integer(0), Value::integer(3)];
+        assert_eq!(primitive_string_prefix_p(&args).unwrap(), Value::boolean(true));
+
+        assert!(primitive_string_prefix_p(&bounded("abc", "abc", [2, 1, 0, 3])).is_err());
+        assert!(primitive_string_suffix_p(&bounded("abc", "abc", [0, 3, 0, 4])).is_err());
     }
 
     #[test]
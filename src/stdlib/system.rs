@@ -5,11 +5,23 @@
 //! - Command line access: command-line
 //! - Environment variables: get-environment-variable, get-environment-variables
 //! - Time functions: current-second, current-jiffy, jiffies-per-second
+//! - SRFI-19 style time objects: current-time, time?, time-type, time->seconds,
+//!   time-difference, time->string
 //! - System features: features
+//! - Filesystem: current-directory, change-directory, file-exists?, delete-file,
+//!   create-directory, directory-files
+//! - Subprocesses: open-process-ports, process?, process-wait
+//!
+//! Filesystem procedures carry the `FileSystem` effect so effect sandboxes can
+//! deny them, and report operating system failures as `file-error?` conditions.
+//! Subprocess procedures likewise carry the `Process` effect.
 
-use crate::eval::value::{Value, PrimitiveProcedure, PrimitiveImpl, ThreadSafeEnvironment};
+use crate::eval::value::{
+    Value, PrimitiveProcedure, PrimitiveImpl, ThreadSafeEnvironment, Port, PortBuffering, PortFileHandle
+};
 use crate::effects::Effect;
 use crate::diagnostics::{Error as DiagnosticError, Result};
+use crate::stdlib::exceptions::raise_file_error;
 use std::sync::{Arc, Mutex, OnceLock};
 // Removed unused HashMap import
 use std::time::{SystemTime, UNIX_EPOCH, Instant};
@@ -119,6 +131,54 @@ pub fn create_system_bindings(env: &Arc<ThreadSafeEnvironment>) {
         effects: vec![Effect::Pure],
     })));
 
+    env.define("current-time".to_string(), Value::Primitive(Arc::new(PrimitiveProcedure {
+        name: "current-time".to_string(),
+        arity_min: 0,
+        arity_max: Some(1),
+        implementation: PrimitiveImpl::RustFn(primitive_current_time),
+        effects: vec![Effect::IO],
+    })));
+
+    env.define("time?".to_string(), Value::Primitive(Arc::new(PrimitiveProcedure {
+        name: "time?".to_string(),
+        arity_min: 1,
+        arity_max: Some(1),
+        implementation: PrimitiveImpl::RustFn(primitive_time_p),
+        effects: vec![Effect::Pure],
+    })));
+
+    env.define("time-type".to_string(), Value::Primitive(Arc::new(PrimitiveProcedure {
+        name: "time-type".to_string(),
+        arity_min: 1,
+        arity_max: Some(1),
+        implementation: PrimitiveImpl::RustFn(primitive_time_type),
+        effects: vec![Effect::Pure],
+    })));
+
+    env.define("time->seconds".to_string(), Value::Primitive(Arc::new(PrimitiveProcedure {
+        name: "time->seconds".to_string(),
+        arity_min: 1,
+        arity_max: Some(1),
+        implementation: PrimitiveImpl::RustFn(primitive_time_to_seconds),
+        effects: vec![Effect::Pure],
+    })));
+
+    env.define("time-difference".to_string(), Value::Primitive(Arc::new(PrimitiveProcedure {
+        name: "time-difference".to_string(),
+        arity_min: 2,
+        arity_max: Some(2),
+        implementation: PrimitiveImpl::RustFn(primitive_time_difference),
+        effects: vec![Effect::Pure],
+    })));
+
+    env.define("time->string".to_string(), Value::Primitive(Arc::new(PrimitiveProcedure {
+        name: "time->string".to_string(),
+        arity_min: 1,
+        arity_max: Some(2),
+        implementation: PrimitiveImpl::RustFn(primitive_time_to_string),
+        effects: vec![Effect::Pure],
+    })));
+
     env.define("features".to_string(), Value::Primitive(Arc::new(PrimitiveProcedure {
         name: "features".to_string(),
         arity_min: 0,
@@ -126,6 +186,78 @@ pub fn create_system_bindings(env: &Arc<ThreadSafeEnvironment>) {
         implementation: PrimitiveImpl::RustFn(primitive_features),
         effects: vec![Effect::Pure],
     })));
+
+    env.define("current-directory".to_string(), Value::Primitive(Arc::new(PrimitiveProcedure {
+        name: "current-directory".to_string(),
+        arity_min: 0,
+        arity_max: Some(1),
+        implementation: PrimitiveImpl::RustFn(primitive_current_directory),
+        effects: vec![Effect::IO, Effect::file_system()],
+    })));
+
+    env.define("change-directory".to_string(), Value::Primitive(Arc::new(PrimitiveProcedure {
+        name: "change-directory".to_string(),
+        arity_min: 1,
+        arity_max: Some(1),
+        implementation: PrimitiveImpl::RustFn(primitive_change_directory),
+        effects: vec![Effect::IO, Effect::file_system()],
+    })));
+
+    env.define("file-exists?".to_string(), Value::Primitive(Arc::new(PrimitiveProcedure {
+        name: "file-exists?".to_string(),
+        arity_min: 1,
+        arity_max: Some(1),
+        implementation: PrimitiveImpl::RustFn(primitive_file_exists_p),
+        effects: vec![Effect::IO, Effect::file_system()],
+    })));
+
+    env.define("delete-file".to_string(), Value::Primitive(Arc::new(PrimitiveProcedure {
+        name: "delete-file".to_string(),
+        arity_min: 1,
+        arity_max: Some(1),
+        implementation: PrimitiveImpl::RustFn(primitive_delete_file),
+        effects: vec![Effect::IO, Effect::file_system()],
+    })));
+
+    env.define("create-directory".to_string(), Value::Primitive(Arc::new(PrimitiveProcedure {
+        name: "create-directory".to_string(),
+        arity_min: 1,
+        arity_max: Some(1),
+        implementation: PrimitiveImpl::RustFn(primitive_create_directory),
+        effects: vec![Effect::IO, Effect::file_system()],
+    })));
+
+    env.define("directory-files".to_string(), Value::Primitive(Arc::new(PrimitiveProcedure {
+        name: "directory-files".to_string(),
+        arity_min: 1,
+        arity_max: Some(1),
+        implementation: PrimitiveImpl::RustFn(primitive_directory_files),
+        effects: vec![Effect::IO, Effect::file_system()],
+    })));
+
+    env.define("open-process-ports".to_string(), Value::Primitive(Arc::new(PrimitiveProcedure {
+        name: "open-process-ports".to_string(),
+        arity_min: 1,
+        arity_max: None,
+        implementation: PrimitiveImpl::RustFn(primitive_open_process_ports),
+        effects: vec![Effect::IO, Effect::process()],
+    })));
+
+    env.define("process?".to_string(), Value::Primitive(Arc::new(PrimitiveProcedure {
+        name: "process?".to_string(),
+        arity_min: 1,
+        arity_max: Some(1),
+        implementation: PrimitiveImpl::RustFn(primitive_process_p),
+        effects: vec![Effect::Pure],
+    })));
+
+    env.define("process-wait".to_string(), Value::Primitive(Arc::new(PrimitiveProcedure {
+        name: "process-wait".to_string(),
+        arity_min: 1,
+        arity_max: Some(1),
+        implementation: PrimitiveImpl::RustFn(primitive_process_wait),
+        effects: vec![Effect::IO, Effect::process()],
+    })));
 }
 
 /// Bind all system interface procedures using copy-on-write semantics
@@ -188,6 +320,48 @@ pub fn bind_system_procedures_cow(env: &Arc<ThreadSafeEnvironment>) -> Arc<Threa
         implementation: PrimitiveImpl::RustFn(primitive_jiffies_per_second),
         effects: vec![Effect::Pure],
     })))
+    .define_cow("current-time".to_string(), Value::Primitive(Arc::new(PrimitiveProcedure {
+        name: "current-time".to_string(),
+        arity_min: 0,
+        arity_max: Some(1),
+        implementation: PrimitiveImpl::RustFn(primitive_current_time),
+        effects: vec![Effect::IO],
+    })))
+    .define_cow("time?".to_string(), Value::Primitive(Arc::new(PrimitiveProcedure {
+        name: "time?".to_string(),
+        arity_min: 1,
+        arity_max: Some(1),
+        implementation: PrimitiveImpl::RustFn(primitive_time_p),
+        effects: vec![Effect::Pure],
+    })))
+    .define_cow("time-type".to_string(), Value::Primitive(Arc::new(PrimitiveProcedure {
+        name: "time-type".to_string(),
+        arity_min: 1,
+        arity_max: Some(1),
+        implementation: PrimitiveImpl::RustFn(primitive_time_type),
+        effects: vec![Effect::Pure],
+    })))
+    .define_cow("time->seconds".to_string(), Value::Primitive(Arc::new(PrimitiveProcedure {
+        name: "time->seconds".to_string(),
+        arity_min: 1,
+        arity_max: Some(1),
+        implementation: PrimitiveImpl::RustFn(primitive_time_to_seconds),
+        effects: vec![Effect::Pure],
+    })))
+    .define_cow("time-difference".to_string(), Value::Primitive(Arc::new(PrimitiveProcedure {
+        name: "time-difference".to_string(),
+        arity_min: 2,
+        arity_max: Some(2),
+        implementation: PrimitiveImpl::RustFn(primitive_time_difference),
+        effects: vec![Effect::Pure],
+    })))
+    .define_cow("time->string".to_string(), Value::Primitive(Arc::new(PrimitiveProcedure {
+        name: "time->string".to_string(),
+        arity_min: 1,
+        arity_max: Some(2),
+        implementation: PrimitiveImpl::RustFn(primitive_time_to_string),
+        effects: vec![Effect::Pure],
+    })))
     .define_cow("features".to_string(), Value::Primitive(Arc::new(PrimitiveProcedure {
         name: "features".to_string(),
         arity_min: 0,
@@ -195,6 +369,69 @@ pub fn bind_system_procedures_cow(env: &Arc<ThreadSafeEnvironment>) -> Arc<Threa
         implementation: PrimitiveImpl::RustFn(primitive_features),
         effects: vec![Effect::Pure],
     })))
+    .define_cow("current-directory".to_string(), Value::Primitive(Arc::new(PrimitiveProcedure {
+        name: "current-directory".to_string(),
+        arity_min: 0,
+        arity_max: Some(1),
+        implementation: PrimitiveImpl::RustFn(primitive_current_directory),
+        effects: vec![Effect::IO, Effect::file_system()],
+    })))
+    .define_cow("change-directory".to_string(), Value::Primitive(Arc::new(PrimitiveProcedure {
+        name: "change-directory".to_string(),
+        arity_min: 1,
+        arity_max: Some(1),
+        implementation: PrimitiveImpl::RustFn(primitive_change_directory),
+        effects: vec![Effect::IO, Effect::file_system()],
+    })))
+    .define_cow("file-exists?".to_string(), Value::Primitive(Arc::new(PrimitiveProcedure {
+        name: "file-exists?".to_string(),
+        arity_min: 1,
+        arity_max: Some(1),
+        implementation: PrimitiveImpl::RustFn(primitive_file_exists_p),
+        effects: vec![Effect::IO, Effect::file_system()],
+    })))
+    .define_cow("delete-file".to_string(), Value::Primitive(Arc::new(PrimitiveProcedure {
+        name: "delete-file".to_string(),
+        arity_min: 1,
+        arity_max: Some(1),
+        implementation: PrimitiveImpl::RustFn(primitive_delete_file),
+        effects: vec![Effect::IO, Effect::file_system()],
+    })))
+    .define_cow("create-directory".to_string(), Value::Primitive(Arc::new(PrimitiveProcedure {
+        name: "create-directory".to_string(),
+        arity_min: 1,
+        arity_max: Some(1),
+        implementation: PrimitiveImpl::RustFn(primitive_create_directory),
+        effects: vec![Effect::IO, Effect::file_system()],
+    })))
+    .define_cow("directory-files".to_string(), Value::Primitive(Arc::new(PrimitiveProcedure {
+        name: "directory-files".to_string(),
+        arity_min: 1,
+        arity_max: Some(1),
+        implementation: PrimitiveImpl::RustFn(primitive_directory_files),
+        effects: vec![Effect::IO, Effect::file_system()],
+    })))
+    .define_cow("open-process-ports".to_string(), Value::Primitive(Arc::new(PrimitiveProcedure {
+        name: "open-process-ports".to_string(),
+        arity_min: 1,
+        arity_max: None,
+        implementation: PrimitiveImpl::RustFn(primitive_open_process_ports),
+        effects: vec![Effect::IO, Effect::process()],
+    })))
+    .define_cow("process?".to_string(), Value::Primitive(Arc::new(PrimitiveProcedure {
+        name: "process?".to_string(),
+        arity_min: 1,
+        arity_max: Some(1),
+        implementation: PrimitiveImpl::RustFn(primitive_process_p),
+        effects: vec![Effect::Pure],
+    })))
+    .define_cow("process-wait".to_string(), Value::Primitive(Arc::new(PrimitiveProcedure {
+        name: "process-wait".to_string(),
+        arity_min: 1,
+        arity_max: Some(1),
+        implementation: PrimitiveImpl::RustFn(primitive_process_wait),
+        effects: vec![Effect::IO, Effect::process()],
+    })))
 }
 
 // ============= PROCESS CONTROL PROCEDURES =============
@@ -399,12 +636,197 @@ pub fn primitive_jiffies_per_second(_args: &[Value]) -> Result<Value> {
     Ok(Value::integer(1_000_000_000))
 }
 
+// ============= TIME OBJECTS =============
+
+/// The clock a time object was read from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TimeType {
+    /// Wall-clock time since the Unix epoch
+    Utc,
+    /// Time since program start; never goes backwards
+    Monotonic,
+    /// Difference between two times of the same clock
+    Duration,
+}
+
+impl TimeType {
+    fn name(self) -> &'static str {
+        match self {
+            TimeType::Utc => "time-utc",
+            TimeType::Monotonic => "time-monotonic",
+            TimeType::Duration => "time-duration",
+        }
+    }
+}
+
+/// A SRFI-19 style time: whole seconds plus nanoseconds in `0..1_000_000_000`.
+#[derive(Debug, Clone, Copy)]
+struct TimeObject {
+    time_type: TimeType,
+    seconds: i64,
+    nanoseconds: u32,
+}
+
+impl TimeObject {
+    fn total_nanoseconds(&self) -> i128 {
+        self.seconds as i128 * 1_000_000_000 + self.nanoseconds as i128
+    }
+
+    fn from_nanoseconds(time_type: TimeType, total: i128) -> Self {
+        Self {
+            time_type,
+            seconds: total.div_euclid(1_000_000_000) as i64,
+            nanoseconds: total.rem_euclid(1_000_000_000) as u32,
+        }
+    }
+
+    fn into_value(self) -> Value {
+        Value::Opaque(Arc::new(self))
+    }
+
+    fn from_value<'a>(value: &'a Value, operation: &str) -> Result<&'a TimeObject> {
+        match value {
+            Value::Opaque(inner) => inner.downcast_ref::<TimeObject>(),
+            _ => None,
+        }
+        .ok_or_else(|| {
+            Box::new(DiagnosticError::runtime_error(
+                format!("{operation} requires a time object"),
+                None,
+            ))
+        })
+    }
+}
+
+/// (current-time [clock]) - Read the `time-utc` (default) or `time-monotonic` clock
+pub fn primitive_current_time(args: &[Value]) -> Result<Value> {
+    let clock = match args.first() {
+        None => TimeType::Utc,
+        Some(Value::Symbol(id)) => match crate::utils::symbol::symbol_name(*id).as_deref() {
+            Some("time-utc") => TimeType::Utc,
+            Some("time-monotonic") => TimeType::Monotonic,
+            _ => {
+                return Err(Box::new(DiagnosticError::runtime_error(
+                    format!("current-time: unknown clock {}", args[0]),
+                    None,
+                )));
+            }
+        },
+        Some(_) => {
+            return Err(Box::new(DiagnosticError::runtime_error(
+                "current-time requires a clock symbol".to_string(),
+                None,
+            )));
+        }
+    };
+
+    let elapsed = match clock {
+        TimeType::Monotonic => {
+            let state = get_system_state();
+            let state_guard = state.lock().map_err(|_| {
+                Box::new(DiagnosticError::runtime_error("Failed to access system state".to_string(), None))
+            })?;
+            state_guard.start_time.elapsed()
+        }
+        _ => SystemTime::now().duration_since(UNIX_EPOCH).map_err(|_| {
+            Box::new(DiagnosticError::runtime_error("Failed to get current time".to_string(), None))
+        })?,
+    };
+
+    Ok(TimeObject {
+        time_type: clock,
+        seconds: elapsed.as_secs() as i64,
+        nanoseconds: elapsed.subsec_nanos(),
+    }
+    .into_value())
+}
+
+/// (time? obj) - Test for a time object
+pub fn primitive_time_p(args: &[Value]) -> Result<Value> {
+    let is_time = matches!(&args[0], Value::Opaque(inner) if inner.downcast_ref::<TimeObject>().is_some());
+    Ok(Value::boolean(is_time))
+}
+
+/// (time-type time) - The clock symbol of a time object
+pub fn primitive_time_type(args: &[Value]) -> Result<Value> {
+    let time = TimeObject::from_value(&args[0], "time-type")?;
+    Ok(Value::symbol_from_str(time.time_type.name()))
+}
+
+/// (time->seconds time) - Seconds as an inexact real; epoch seconds for `time-utc`
+pub fn primitive_time_to_seconds(args: &[Value]) -> Result<Value> {
+    let time = TimeObject::from_value(&args[0], "time->seconds")?;
+    Ok(Value::number(time.total_nanoseconds() as f64 / 1_000_000_000.0))
+}
+
+/// (time-difference time1 time2) - The `time-duration` from time2 to time1
+pub fn primitive_time_difference(args: &[Value]) -> Result<Value> {
+    let later = TimeObject::from_value(&args[0], "time-difference")?;
+    let earlier = TimeObject::from_value(&args[1], "time-difference")?;
+    if later.time_type != earlier.time_type {
+        return Err(Box::new(DiagnosticError::runtime_error(
+            format!(
+                "time-difference: cannot subtract a {} from a {}",
+                earlier.time_type.name(),
+                later.time_type.name()
+            ),
+            None,
+        )));
+    }
+
+    let difference = later.total_nanoseconds() - earlier.total_nanoseconds();
+    Ok(TimeObject::from_nanoseconds(TimeType::Duration, difference).into_value())
+}
+
+/// (time->string time [format]) - Format a `time-utc` time in UTC
+///
+/// The format uses strftime directives and defaults to ISO 8601,
+/// e.g. `2024-01-31T12:00:00Z`.
+pub fn primitive_time_to_string(args: &[Value]) -> Result<Value> {
+    use chrono::format::{Item, StrftimeItems};
+
+    let time = TimeObject::from_value(&args[0], "time->string")?;
+    if time.time_type != TimeType::Utc {
+        return Err(Box::new(DiagnosticError::runtime_error(
+            format!("time->string requires a time-utc time, got a {}", time.time_type.name()),
+            None,
+        )));
+    }
+    let format = match args.get(1) {
+        Some(value) => value.as_string_owned().ok_or_else(|| {
+            Box::new(DiagnosticError::runtime_error(
+                "time->string format must be a string".to_string(),
+                None,
+            ))
+        })?,
+        None => "%Y-%m-%dT%H:%M:%SZ".to_string(),
+    };
+
+    let items: Vec<Item<'_>> = StrftimeItems::new(&format).collect();
+    if items.iter().any(|item| matches!(item, Item::Error)) {
+        return Err(Box::new(DiagnosticError::runtime_error(
+            format!("time->string: invalid format {format:?}"),
+            None,
+        )));
+    }
+    let datetime = chrono::DateTime::from_timestamp(time.seconds, time.nanoseconds).ok_or_else(|| {
+        Box::new(DiagnosticError::runtime_error(
+            "time->string: time is out of range".to_string(),
+            None,
+        ))
+    })?;
+    Ok(Value::string(datetime.format_with_items(items.into_iter()).to_string()))
+}
+
 // ============= SYSTEM FEATURES =============
 
-/// (features) - Return list of supported feature identifiers
-pub fn primitive_features(_args: &[Value]) -> Result<Value> {
+/// Returns the feature identifiers reported by `(features)` and tested by
+/// `cond-expand`.
+///
+/// Cargo features are included only when they were compiled in.
+pub fn feature_identifiers() -> Vec<&'static str> {
     // R7RS-small required features
-    let features = vec![
+    let mut features = vec![
         "r7rs",                    // R7RS compliance
         "exact-closed",            // Exact arithmetic is closed under operations
         "exact-complex",           // Exact complex numbers supported
@@ -438,10 +860,33 @@ pub fn primitive_features(_args: &[Value]) -> Result<Value> {
         "hashtables",              // Hash table support
         "records",                 // Record type support
     ];
+    
+    // Cargo features this build was compiled with
+    let cargo_features = [
+        ("minimal-repl", cfg!(feature = "minimal-repl")),
+        ("repl", cfg!(feature = "repl")),
+        ("enhanced-repl", cfg!(feature = "enhanced-repl")),
+        ("async-runtime", cfg!(feature = "async-runtime")),
+        ("network-io", cfg!(feature = "network-io")),
+        ("ffi", cfg!(feature = "ffi")),
+        ("jit", cfg!(feature = "jit")),
+        ("simd-benchmarks", cfg!(feature = "simd-benchmarks")),
+    ];
+    features.extend(
+        cargo_features
+            .iter()
+            .filter(|(_, enabled)| *enabled)
+            .map(|(name, _)| *name),
+    );
+    
+    features
+}
 
+/// (features) - Return list of supported feature identifiers
+pub fn primitive_features(_args: &[Value]) -> Result<Value> {
     // Convert to Scheme list
     let mut result = Value::Nil;
-    for feature in features.iter().rev() {
+    for feature in feature_identifiers().iter().rev() {
         let symbol_id = crate::utils::symbol::intern_symbol(feature.to_string());
         result = Value::Pair(
             Arc::new(Value::symbol(symbol_id)),
@@ -452,6 +897,335 @@ pub fn primitive_features(_args: &[Value]) -> Result<Value> {
     Ok(result)
 }
 
+// ============= FILESYSTEM =============
+
+/// (current-directory [path]) - Return the working directory, or change it when
+/// a path is supplied
+pub fn primitive_current_directory(args: &[Value]) -> Result<Value> {
+    match args {
+        [] => match std::env::current_dir() {
+            Ok(dir) => Ok(Value::string(dir.to_string_lossy().into_owned())),
+            Err(e) => raise_file_error(
+                format!("current-directory: cannot read working directory: {e}"),
+                vec![],
+            ),
+        },
+        [path] => change_directory(path, "current-directory"),
+        _ => Err(Box::new(DiagnosticError::runtime_error(
+            format!("current-directory expects 0 or 1 arguments, got {}", args.len()),
+            None,
+        ))),
+    }
+}
+
+/// (change-directory path) - Change the working directory
+pub fn primitive_change_directory(args: &[Value]) -> Result<Value> {
+    if args.len() != 1 {
+        return Err(Box::new(DiagnosticError::runtime_error(
+            format!("change-directory expects 1 argument, got {}", args.len()),
+            None,
+        )));
+    }
+
+    change_directory(&args[0], "change-directory")
+}
+
+/// (file-exists? filename) - Return #t if the file exists
+pub fn primitive_file_exists_p(args: &[Value]) -> Result<Value> {
+    if args.len() != 1 {
+        return Err(Box::new(DiagnosticError::runtime_error(
+            format!("file-exists? expects 1 argument, got {}", args.len()),
+            None,
+        )));
+    }
+
+    let path = extract_path(&args[0], "file-exists?")?;
+    Ok(Value::boolean(std::path::Path::new(&path).exists()))
+}
+
+/// (delete-file filename) - Delete a file, raising a file error if it cannot be
+/// deleted (including when it does not exist)
+pub fn primitive_delete_file(args: &[Value]) -> Result<Value> {
+    if args.len() != 1 {
+        return Err(Box::new(DiagnosticError::runtime_error(
+            format!("delete-file expects 1 argument, got {}", args.len()),
+            None,
+        )));
+    }
+
+    let path = extract_path(&args[0], "delete-file")?;
+    match std::fs::remove_file(&path) {
+        Ok(()) => Ok(Value::Unspecified),
+        Err(e) => raise_os_file_error("delete-file", "cannot delete file", &path, e),
+    }
+}
+
+/// (create-directory path) - Create a directory
+pub fn primitive_create_directory(args: &[Value]) -> Result<Value> {
+    if args.len() != 1 {
+        return Err(Box::new(DiagnosticError::runtime_error(
+            format!("create-directory expects 1 argument, got {}", args.len()),
+            None,
+        )));
+    }
+
+    let path = extract_path(&args[0], "create-directory")?;
+    match std::fs::create_dir(&path) {
+        Ok(()) => Ok(Value::Unspecified),
+        Err(e) => raise_os_file_error("create-directory", "cannot create directory", &path, e),
+    }
+}
+
+/// (directory-files path) - Return the names of the entries in a directory,
+/// sorted, excluding `.` and `..`
+pub fn primitive_directory_files(args: &[Value]) -> Result<Value> {
+    if args.len() != 1 {
+        return Err(Box::new(DiagnosticError::runtime_error(
+            format!("directory-files expects 1 argument, got {}", args.len()),
+            None,
+        )));
+    }
+
+    let path = extract_path(&args[0], "directory-files")?;
+    let entries = match std::fs::read_dir(&path) {
+        Ok(entries) => entries,
+        Err(e) => return raise_os_file_error("directory-files", "cannot read directory", &path, e),
+    };
+
+    let mut names = Vec::new();
+    for entry in entries {
+        match entry {
+            Ok(entry) => {
+                let name = entry.file_name().to_string_lossy().into_owned();
+                // read_dir never yields these, but be explicit for other platforms
+                if name != "." && name != ".." {
+                    names.push(name);
+                }
+            }
+            Err(e) => return raise_os_file_error("directory-files", "cannot read directory", &path, e),
+        }
+    }
+    names.sort();
+
+    Ok(Value::list(names.into_iter().map(Value::string).collect()))
+}
+
+// ============= SUBPROCESSES =============
+
+/// A child process spawned by `open-process-ports`.
+#[derive(Debug)]
+struct ProcessObject {
+    /// The running (or reaped) child
+    child: Mutex<std::process::Child>,
+    /// Our end of the child's standard input
+    stdin: Arc<Port>,
+}
+
+impl ProcessObject {
+    fn from_value<'a>(value: &'a Value, operation: &str) -> Result<&'a ProcessObject> {
+        match value {
+            Value::Opaque(inner) => inner.downcast_ref::<ProcessObject>(),
+            _ => None,
+        }
+        .ok_or_else(|| {
+            Box::new(DiagnosticError::runtime_error(
+                format!("{operation} requires a process object"),
+                None,
+            ))
+        })
+    }
+}
+
+/// (open-process-ports program arg ...) - Spawn a subprocess
+///
+/// Returns four values: a textual output port writing to the child's stdin,
+/// textual input ports reading its stdout and stderr, and a process object
+/// for `process-wait`. The stdin port is line buffered.
+///
+/// Stdout and stderr are drained by background threads into unbounded
+/// buffers, so a child producing more output than a pipe holds never blocks
+/// while we are still writing its input. Writing to a child that has closed
+/// its stdin raises an error.
+pub fn primitive_open_process_ports(args: &[Value]) -> Result<Value> {
+    use std::process::{Command, Stdio};
+
+    let mut words = Vec::with_capacity(args.len());
+    for arg in args {
+        words.push(arg.as_string_owned().ok_or_else(|| {
+            Box::new(DiagnosticError::runtime_error(
+                "open-process-ports requires string arguments".to_string(),
+                None,
+            ))
+        })?);
+    }
+    let program = &words[0];
+
+    let spawn_error = |e: std::io::Error| {
+        Box::new(DiagnosticError::runtime_error(
+            format!("open-process-ports: cannot run '{program}': {e}"),
+            None,
+        ))
+    };
+    let mut child = Command::new(program)
+        .args(&words[1..])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(spawn_error)?;
+
+    let (Some(stdin), Some(stdout), Some(stderr)) = (child.stdin.take(), child.stdout.take(), child.stderr.take()) else {
+        return Err(Box::new(DiagnosticError::runtime_error(
+            "open-process-ports: child pipes are unavailable".to_string(),
+            None,
+        )));
+    };
+    let stdout = drain_in_background(stdout).map_err(spawn_error)?;
+    let stderr = drain_in_background(stderr).map_err(spawn_error)?;
+
+    let stdin_port = process_port(
+        Port::new_file_output(format!("<{program} stdin>"), false),
+        PortFileHandle::TextWriter(std::io::BufWriter::new(pipe_into_file(stdin))),
+    );
+    stdin_port.set_buffering(PortBuffering::Line);
+    let stdout_port = process_port(
+        Port::new_file_input(format!("<{program} stdout>"), false),
+        PortFileHandle::TextReader(std::io::BufReader::new(stdout)),
+    );
+    let stderr_port = process_port(
+        Port::new_file_input(format!("<{program} stderr>"), false),
+        PortFileHandle::TextReader(std::io::BufReader::new(stderr)),
+    );
+
+    let process = ProcessObject {
+        child: Mutex::new(child),
+        stdin: stdin_port.clone(),
+    };
+    Ok(Value::values(vec![
+        Value::Port(stdin_port),
+        Value::Port(stdout_port),
+        Value::Port(stderr_port),
+        Value::Opaque(Arc::new(process)),
+    ]))
+}
+
+/// (process? obj) - Test for a process object
+pub fn primitive_process_p(args: &[Value]) -> Result<Value> {
+    let is_process = matches!(&args[0], Value::Opaque(inner) if inner.downcast_ref::<ProcessObject>().is_some());
+    Ok(Value::boolean(is_process))
+}
+
+/// (process-wait process) - Wait for a subprocess to exit
+///
+/// Closes the child's stdin port first, so a child reading until end of
+/// input can finish. Returns the exit code, or #f when the child was
+/// terminated by a signal. Waiting again returns the same result.
+pub fn primitive_process_wait(args: &[Value]) -> Result<Value> {
+    let process = ProcessObject::from_value(&args[0], "process-wait")?;
+    process.stdin.close();
+
+    let mut child = process.child.lock().map_err(|_| {
+        Box::new(DiagnosticError::runtime_error("process-wait: process state is poisoned".to_string(), None))
+    })?;
+    let status = child.wait().map_err(|e| {
+        Box::new(DiagnosticError::runtime_error(format!("process-wait: {e}"), None))
+    })?;
+    Ok(status.code().map_or(Value::boolean(false), |code| Value::integer(code as i64)))
+}
+
+/// Installs an open handle in a freshly created file port.
+fn process_port(port: Port, handle: PortFileHandle) -> Arc<Port> {
+    if let crate::eval::value::PortImpl::File { handle: slot, .. } = &port.implementation {
+        *slot.write().unwrap() = Some(handle);
+    }
+    Arc::new(port)
+}
+
+/// Copies everything readable from `source` into an in-memory queue on one
+/// thread and from the queue into a pipe on another, returning the pipe's
+/// read end. The child's side never waits on us reading.
+fn drain_in_background(mut source: impl std::io::Read + Send + 'static) -> std::io::Result<std::fs::File> {
+    use std::io::{ErrorKind, Write};
+
+    let (reader, mut writer) = std::io::pipe()?;
+    let (sender, receiver) = std::sync::mpsc::channel::<Vec<u8>>();
+
+    std::thread::spawn(move || {
+        let mut chunk = [0u8; 8192];
+        loop {
+            match source.read(&mut chunk) {
+                Ok(0) => break,
+                Ok(n) => {
+                    if sender.send(chunk[..n].to_vec()).is_err() {
+                        break;
+                    }
+                }
+                Err(e) if e.kind() == ErrorKind::Interrupted => continue,
+                Err(_) => break,
+            }
+        }
+    });
+    std::thread::spawn(move || {
+        for chunk in receiver {
+            // The port was closed without reading everything
+            if writer.write_all(&chunk).is_err() {
+                break;
+            }
+        }
+    });
+
+    Ok(pipe_into_file(reader))
+}
+
+/// Converts one end of a pipe into a `File` so it can back a file port.
+#[cfg(unix)]
+fn pipe_into_file<T>(pipe: T) -> std::fs::File
+where
+    std::os::fd::OwnedFd: From<T>,
+{
+    std::fs::File::from(std::os::fd::OwnedFd::from(pipe))
+}
+
+/// Converts one end of a pipe into a `File` so it can back a file port.
+#[cfg(windows)]
+fn pipe_into_file<T>(pipe: T) -> std::fs::File
+where
+    std::os::windows::io::OwnedHandle: From<T>,
+{
+    std::fs::File::from(std::os::windows::io::OwnedHandle::from(pipe))
+}
+
+/// Changes the working directory to the path held in `value`.
+fn change_directory(value: &Value, operation: &str) -> Result<Value> {
+    let path = extract_path(value, operation)?;
+    match std::env::set_current_dir(&path) {
+        Ok(()) => Ok(Value::Unspecified),
+        Err(e) => raise_os_file_error(operation, "cannot change directory to", &path, e),
+    }
+}
+
+/// Extracts a path string from a Value.
+fn extract_path(value: &Value, operation: &str) -> Result<String> {
+    match value {
+        Value::Literal(crate::ast::Literal::String(s)) => Ok(s.clone()),
+        Value::MutableString(chars) => Ok(chars.read().map_err(|_| {
+            Box::new(DiagnosticError::runtime_error("Failed to read string".to_string(), None))
+        })?.iter().collect()),
+        _ => Err(Box::new(DiagnosticError::runtime_error(
+            format!("{operation} requires a string argument"),
+            None,
+        ))),
+    }
+}
+
+/// Raises a catchable file error describing an operating system failure.
+fn raise_os_file_error(operation: &str, action: &str, path: &str, error: std::io::Error) -> Result<Value> {
+    raise_file_error(
+        format!("{operation}: {action} '{path}': {error}"),
+        vec![Value::string(path)],
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -579,6 +1353,31 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_time_objects() {
+        let epoch_time = TimeObject { time_type: TimeType::Utc, seconds: 1_000_000_000, nanoseconds: 500 }.into_value();
+        assert_eq!(
+            primitive_time_to_string(&[epoch_time.clone()]).unwrap(),
+            Value::string("2001-09-09T01:46:40Z")
+        );
+        assert_eq!(
+            primitive_time_to_string(&[epoch_time.clone(), Value::string("%d/%m/%Y %9f")]).unwrap(),
+            Value::string("09/09/2001 000000500")
+        );
+
+        // Durations borrow a second when the nanoseconds underflow
+        let earlier = TimeObject { time_type: TimeType::Utc, seconds: 10, nanoseconds: 900_000_000 }.into_value();
+        let later = TimeObject { time_type: TimeType::Utc, seconds: 12, nanoseconds: 100_000_000 }.into_value();
+        let difference = primitive_time_difference(&[later.clone(), earlier.clone()]).unwrap();
+        let duration = TimeObject::from_value(&difference, "test").unwrap();
+        assert_eq!((duration.seconds, duration.nanoseconds), (1, 200_000_000));
+        assert_eq!(primitive_time_to_seconds(&[difference]).unwrap(), Value::number(1.2));
+
+        let negative = primitive_time_difference(&[earlier, later]).unwrap();
+        let duration = TimeObject::from_value(&negative, "test").unwrap();
+        assert_eq!((duration.seconds, duration.nanoseconds), (-2, 800_000_000));
+    }
+
     #[test]
     fn test_features() {
         let result = primitive_features(&[]).unwrap();
@@ -641,4 +1440,117 @@ mod tests {
         let result = primitive_get_environment_variable(&[Value::integer(42)]);
         assert!(result.is_err());
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_filesystem_procedures() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path().to_string_lossy().into_owned();
+        let sub = dir.path().join("sub").to_string_lossy().into_owned();
+        let file = dir.path().join("data.txt");
+        std::fs::write(&file, "contents").unwrap();
+        let file = file.to_string_lossy().into_owned();
+
+        // Creating a directory makes it visible to file-exists?
+        assert_eq!(primitive_file_exists_p(&[Value::string(sub.clone())]).unwrap(), Value::boolean(false));
+        primitive_create_directory(&[Value::string(sub.clone())]).unwrap();
+        assert_eq!(primitive_file_exists_p(&[Value::string(sub.clone())]).unwrap(), Value::boolean(true));
+
+        // Listing is sorted and never includes . or ..
+        let listing = primitive_directory_files(&[Value::string(root.clone())]).unwrap();
+        assert_eq!(listing, Value::list(vec![Value::string("data.txt"), Value::string("sub")]));
+
+        // Deleting removes the file
+        primitive_delete_file(&[Value::string(file.clone())]).unwrap();
+        assert_eq!(primitive_file_exists_p(&[Value::string(file.clone())]).unwrap(), Value::boolean(false));
+        let listing = primitive_directory_files(&[Value::string(root)]).unwrap();
+        assert_eq!(listing, Value::list(vec![Value::string("sub")]));
+    }
+
+    #[test]
+    fn test_filesystem_errors_are_file_errors() {
+        let dir = tempfile::tempdir().unwrap();
+        let missing = dir.path().join("missing.txt").to_string_lossy().into_owned();
+
+        let is_file_error = |result: Result<Value>| match result {
+            Err(err) => match *err {
+                DiagnosticError::Exception { ref exception, .. } => {
+                    matches!(&exception.value, Value::ErrorObject(obj) if obj.is_file_error())
+                }
+                _ => false,
+            },
+            Ok(_) => false,
+        };
+
+        assert!(is_file_error(primitive_delete_file(&[Value::string(missing.clone())])));
+        assert!(is_file_error(primitive_directory_files(&[Value::string(missing.clone())])));
+        assert!(is_file_error(primitive_change_directory(&[Value::string(missing)])));
+        let existing = dir.path().to_string_lossy().into_owned();
+        assert!(is_file_error(primitive_create_directory(&[Value::string(existing)])));
+    }
+
+    #[test]
+    fn test_current_directory() {
+        let cwd = primitive_current_directory(&[]).unwrap();
+        let expected = std::env::current_dir().unwrap().to_string_lossy().into_owned();
+        assert_eq!(cwd, Value::string(expected));
+
+        assert!(primitive_current_directory(&[Value::integer(1)]).is_err());
+        assert!(primitive_current_directory(&[Value::string("a"), Value::string("b")]).is_err());
+    }
+
+    #[test]
+    fn test_filesystem_effect_denied_by_default_sandbox() {
+        use crate::runtime::EffectSandboxConfig;
+
+        let env = create_test_env();
+        create_system_bindings(&env);
+        let config = EffectSandboxConfig::default();
+
+        for name in ["delete-file", "create-directory", "directory-files", "current-directory"] {
+            match env.lookup(name) {
+                Some(Value::Primitive(prim)) => {
+                    assert!(prim.effects.iter().any(Effect::is_file_system));
+                    assert!(!config.permits(&prim.effects), "{name} should be denied");
+                }
+                _ => panic!("{name} should be bound"),
+            }
+        }
+
+        let permissive = EffectSandboxConfig {
+            allowed_effects: vec![Effect::IO, Effect::file_system()],
+            ..EffectSandboxConfig::default()
+        };
+        assert!(permissive.permits(&[Effect::IO, Effect::file_system()]));
+    }
+
+    #[test]
+    fn test_process_effect_denied_by_default_sandbox() {
+        use crate::runtime::EffectSandboxConfig;
+
+        let env = create_test_env();
+        create_system_bindings(&env);
+        let config = EffectSandboxConfig::default();
+
+        for name in ["open-process-ports", "process-wait"] {
+            match env.lookup(name) {
+                Some(Value::Primitive(prim)) => {
+                    assert!(prim.effects.iter().any(Effect::is_process));
+                    assert!(!config.permits(&prim.effects), "{name} should be denied");
+                }
+                _ => panic!("{name} should be bound"),
+            }
+        }
+        assert!(!Effect::file_system().is_process());
+    }
+
+    #[test]
+    fn test_features_reflect_cargo_features() {
+        let features = feature_identifiers();
+        
+        assert!(features.contains(&"ratios"));
+        assert_eq!(features.contains(&"repl"), cfg!(feature = "repl"));
+        assert_eq!(features.contains(&"enhanced-repl"), cfg!(feature = "enhanced-repl"));
+        assert_eq!(features.contains(&"jit"), cfg!(feature = "jit"));
+        assert_eq!(features.contains(&"simd-benchmarks"), cfg!(feature = "simd-benchmarks"));
+    }
+}
@@ -108,15 +108,8 @@ impl TextRegex {
     pub fn find_all(&self, text: &Text) -> Vec<TextMatch> {
         let text_str = text.as_string();
         self.regex
-            .find_iter(&text_str)
-            .map(|m| TextMatch {
-                text: Text::from_string(m.as_str().to_string()),
-                matched_text: Text::from_string(m.as_str().to_string()),
-                start: m.start(),
-                end: m.end(),
-                groups: vec![],
-                named_groups: HashMap::new(),
-            })
+            .captures_iter(&text_str)
+            .map(|captures| self.text_match(&captures))
             .collect()
     }
 
@@ -138,14 +131,31 @@ impl TextRegex {
     /// Finds the first match in the text.
     pub fn find(&self, text: &Text) -> Option<TextMatch> {
         let text_str = text.as_string();
-        self.regex.find(&text_str).map(|m| TextMatch {
-            text: Text::from_string(m.as_str().to_string()),
-            matched_text: Text::from_string(m.as_str().to_string()),
-            start: m.start(),
-            end: m.end(),
-            groups: vec![],
-            named_groups: HashMap::new(),
-        })
+        self.regex.captures(&text_str).map(|captures| self.text_match(&captures))
+    }
+
+    /// Converts engine captures into a match; `groups` excludes the whole match.
+    fn text_match(&self, captures: &crate::regex::compat::Captures<'_>) -> TextMatch {
+        let whole = captures.get(0).expect("group 0 is always set");
+        let groups: Vec<Option<Text>> = (1..captures.len())
+            .map(|i| captures.get(i).map(|m| Text::from_string_slice(m.as_str())))
+            .collect();
+        let named_groups = self
+            .regex
+            .capture_names()
+            .iter()
+            .zip(&groups)
+            .filter_map(|(name, group)| name.clone().map(|name| (name, group.clone())))
+            .collect();
+
+        TextMatch {
+            text: Text::from_string_slice(whole.as_str()),
+            matched_text: Text::from_string_slice(whole.as_str()),
+            start: whole.start(),
+            end: whole.end(),
+            groups,
+            named_groups,
+        }
     }
 
     /// Replaces all matches in the text.
@@ -611,6 +611,37 @@ impl TextOperations {
         }
     }
     
+    /// Splits text into the runs of characters between delimiters.
+    ///
+    /// Empty runs, from leading, trailing or consecutive delimiters, are
+    /// dropped. With `keep_delimiters` each delimiter is also returned as a
+    /// token of its own, in place.
+    pub fn tokenize<F>(text: &Text, is_delimiter: F, keep_delimiters: bool) -> Vec<Text>
+    where
+        F: Fn(char) -> bool,
+    {
+        let mut tokens = Vec::new();
+        let mut current = String::new();
+        
+        for ch in text.chars() {
+            if !is_delimiter(ch) {
+                current.push(ch);
+                continue;
+            }
+            if !current.is_empty() {
+                tokens.push(Text::from_string(std::mem::take(&mut current)));
+            }
+            if keep_delimiters {
+                tokens.push(Text::from_string(ch.to_string()));
+            }
+        }
+        if !current.is_empty() {
+            tokens.push(Text::from_string(current));
+        }
+        
+        tokens
+    }
+    
     /// Finds common suffix of multiple texts.
     pub fn common_suffix(texts: &[Text]) -> Text {
         if texts.is_empty() {
@@ -737,6 +768,15 @@ fn bind_advanced_operations(env: &Arc<ThreadSafeEnvironment>) {
         implementation: PrimitiveImpl::RustFn(primitive_text_wrap_lines),
         effects: vec![Effect::Pure],
     })));
+    
+    // tokenize
+    env.define("tokenize".to_string(), Value::Primitive(Arc::new(PrimitiveProcedure {
+        name: "tokenize".to_string(),
+        arity_min: 2,
+        arity_max: Some(3),
+        implementation: PrimitiveImpl::RustFn(primitive_tokenize),
+        effects: vec![Effect::Pure],
+    })));
 }
 
 // ============= PRIMITIVE IMPLEMENTATIONS =============
@@ -902,6 +942,38 @@ fn primitive_text_wrap_lines(args: &[Value]) -> Result<Value> {
     Ok(Value::list(line_values))
 }
 
+/// Tokenize implementation: (tokenize string delimiters [keep-delimiters?])
+fn primitive_tokenize(args: &[Value]) -> Result<Value> {
+    if args.len() < 2 || args.len() > 3 {
+        return Err(Box::new(DiagnosticError::runtime_error(
+            format!("tokenize expects 2 or 3 arguments, got {}", args.len()),
+            None,
+        )));
+    }
+    
+    let text = args[0].as_string_owned().map(Text::from_string).ok_or_else(|| {
+        Box::new(DiagnosticError::runtime_error(
+            "tokenize first argument must be a string".to_string(),
+            None,
+        ))
+    })?;
+    
+    let delimiters = match &args[1] {
+        Value::CharSet(charset) => charset.clone(),
+        _ => {
+            return Err(Box::new(DiagnosticError::runtime_error(
+                "tokenize delimiters must be a char-set".to_string(),
+                None,
+            )));
+        }
+    };
+    
+    let keep_delimiters = args.get(2).is_some_and(Value::is_truthy);
+    
+    let tokens = TextOperations::tokenize(&text, |ch| delimiters.contains(ch), keep_delimiters);
+    Ok(Value::list(tokens.into_iter().map(|token| Value::string(token.to_string())).collect()))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -973,6 +1045,48 @@ mod tests {
         assert!(lines.iter().all(|line| line.char_length() <= 20));
     }
 
+    fn tokens(text: &str, delimiters: crate::stdlib::charset::CharSet, keep: bool) -> Value {
+        let args = [
+            Value::string(text),
+            Value::CharSet(Arc::new(delimiters)),
+            Value::boolean(keep),
+        ];
+        primitive_tokenize(&args).unwrap()
+    }
+    
+    fn strings(items: &[&str]) -> Value {
+        Value::list(items.iter().map(|item| Value::string(*item)).collect())
+    }
+
+    #[test]
+    fn test_tokenize_path() {
+        use crate::stdlib::charset::CharSet;
+        
+        assert_eq!(tokens("/usr//local/bin/", CharSet::singleton('/'), false), strings(&["usr", "local", "bin"]));
+        assert_eq!(
+            tokens("/usr//local", CharSet::singleton('/'), true),
+            strings(&["/", "usr", "/", "/", "local"])
+        );
+        assert_eq!(tokens("", CharSet::singleton('/'), true), Value::Nil);
+        assert_eq!(tokens("///", CharSet::singleton('/'), false), Value::Nil);
+        assert_eq!(tokens("añ/βγ", CharSet::singleton('/'), false), strings(&["añ", "βγ"]));
+    }
+
+    #[test]
+    fn test_tokenize_whitespace() {
+        use crate::stdlib::charset::StandardCharSets;
+        
+        assert_eq!(
+            tokens("  let x =\t42\n", StandardCharSets::whitespace(), false),
+            strings(&["let", "x", "=", "42"])
+        );
+        assert_eq!(
+            tokens("a \tb", StandardCharSets::whitespace(), true),
+            strings(&["a", " ", "\t", "b"])
+        );
+        assert!(primitive_tokenize(&[Value::string("a b"), Value::string(" ")]).is_err());
+    }
+
     #[test]
     fn test_common_prefix_suffix() {
         let texts = vec![
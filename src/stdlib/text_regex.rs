@@ -3,6 +3,11 @@
 //!
 //! This module implements a PCRE-compatible regular expression engine
 //! with Unicode support, named capture groups, and efficient matching.
+//!
+//! From Scheme, `regex-search` and `regex-search-all` return match objects;
+//! `match-group`, `match-start` and `match-end` read the whole match or a
+//! group selected by number or name. Replacement strings may refer to
+//! groups with `$1` or `${name}`.
 
 use crate::diagnostics::{Error as DiagnosticError, Result};
 use crate::eval::value::{Value, PrimitiveProcedure, PrimitiveImpl, ThreadSafeEnvironment};
@@ -11,6 +16,7 @@ use crate::stdlib::text::Text;
 use std::sync::Arc;
 // use regex::Captures; // Removed external regex dependency
 use crate::regex::compat::{LightRegex, Captures as LightCaptures};
+use crate::regex::captures::GroupSpans;
 use std::collections::HashMap;
 
 // ============= REGEX ENGINE =============
@@ -56,6 +62,8 @@ pub struct TextMatchResult {
     pub end: usize,
     /// Captured groups (indexed)
     pub groups: Vec<Option<Text>>,
+    /// Character spans of the captured groups, aligned with `groups`
+    pub group_spans: GroupSpans,
     /// Named capture groups
     pub named_groups: HashMap<String, Option<Text>>,
 }
@@ -114,44 +122,22 @@ impl TextRegex {
     }
 
     /// Finds the first match in the text.
-    /// Note: Uses internal lightweight regex engine.
     pub fn find(&self, text: &Text) -> Option<TextMatchResult> {
         let text_str = text.to_string();
-        if let Some(m) = self.regex.find(&text_str) {
-            let start_char = text_str[..m.start()].chars().count();
-            let end_char = text_str[..m.end()].chars().count();
-            let matched_text = text.substring(start_char, end_char)?;
-            
-            Some(TextMatchResult {
-                matched_text,
-                start: start_char,
-                end: end_char,
-                groups: vec![],
-                named_groups: HashMap::new(),
-            })
-        } else {
-            None
-        }
+        let captures = self.regex.captures(&text_str)?;
+        Some(self.match_result(&captures, &char_offsets(&text_str)))
     }
 
-    /// Finds all matches in the text.
+    /// Finds all non-overlapping matches in the text.
+    ///
+    /// The search resumes after each match, and one character further after
+    /// an empty match, so empty matches cannot repeat at the same position.
     pub fn find_all(&self, text: &Text) -> Vec<TextMatchResult> {
         let text_str = text.to_string();
+        let offsets = char_offsets(&text_str);
         self.regex
-            .find_iter(&text_str)
-            .filter_map(|m| {
-                let start_char = text_str[..m.start()].chars().count();
-                let end_char = text_str[..m.end()].chars().count();
-                let matched_text = text.substring(start_char, end_char)?;
-                
-                Some(TextMatchResult {
-                    matched_text,
-                    start: start_char,
-                    end: end_char,
-                    groups: vec![],
-                    named_groups: HashMap::new(),
-                })
-            })
+            .captures_iter(&text_str)
+            .map(|captures| self.match_result(&captures, &offsets))
             .collect()
     }
 
@@ -164,20 +150,25 @@ impl TextRegex {
         }
     }
 
-    /// Replaces the first match with replacement text.
+    /// Gets the names of the capturing groups, `None` for unnamed groups.
+    pub fn group_names(&self) -> &[Option<String>] {
+        self.regex.capture_names()
+    }
+
+    /// Replaces the first match, expanding `$n` / `${name}` in `replacement`.
     pub fn replace(&self, text: &Text, replacement: &Text) -> Text {
-        let text_str = text.to_string();
         let replacement_str = replacement.to_string();
-        let result = self.regex.replace(&text_str, &replacement_str);
-        Text::from_string(result.into_owned())
+        self.splice_matches(&text.to_string(), 1, |captures, out| {
+            captures.expand(&replacement_str, out)
+        })
     }
 
-    /// Replaces all matches with replacement text.
+    /// Replaces all matches, expanding `$n` / `${name}` in `replacement`.
     pub fn replace_all(&self, text: &Text, replacement: &Text) -> Text {
-        let text_str = text.to_string();
         let replacement_str = replacement.to_string();
-        let result = self.regex.replace_all(&text_str, &replacement_str);
-        Text::from_string(result.into_owned())
+        self.splice_matches(&text.to_string(), usize::MAX, |captures, out| {
+            captures.expand(&replacement_str, out)
+        })
     }
 
     /// Replaces matches using a callback function.
@@ -186,24 +177,54 @@ impl TextRegex {
         F: Fn(&TextMatchResult) -> Text,
     {
         let text_str = text.to_string();
-        let result = self.regex.replace_all_fn(&text_str, |m| {
-            let start_char = text_str[..m.start()].chars().count();
-            let end_char = text_str[..m.end()].chars().count();
-            if let Some(matched_text) = text.substring(start_char, end_char) {
-                let match_result = TextMatchResult {
-                    matched_text: matched_text.clone(),
-                    start: start_char,
-                    end: end_char,
-                    groups: vec![],
-                    named_groups: HashMap::new(),
-                };
-                replacer(&match_result).to_string()
-            } else {
-                String::new()
-            }
-        });
-        
-        Text::from_string(result.into_owned())
+        let offsets = char_offsets(&text_str);
+        self.splice_matches(&text_str, usize::MAX, |captures, out| {
+            out.push_str(&replacer(&self.match_result(captures, &offsets)).to_string())
+        })
+    }
+
+    /// Rebuilds `text` with the first `limit` matches replaced by whatever
+    /// `replace` appends for them.
+    fn splice_matches<F>(&self, text: &str, limit: usize, mut replace: F) -> Text
+    where
+        F: FnMut(&LightCaptures<'_>, &mut String),
+    {
+        let mut out = String::with_capacity(text.len());
+        let mut last_end = 0;
+        for captures in self.regex.captures_iter(text).take(limit) {
+            let whole = captures.get(0).expect("group 0 is always set");
+            out.push_str(&text[last_end..whole.start()]);
+            replace(&captures, &mut out);
+            last_end = whole.end();
+        }
+        out.push_str(&text[last_end..]);
+        Text::from_string(out)
+    }
+
+    /// Converts engine captures into a match result with character positions.
+    fn match_result(&self, captures: &LightCaptures<'_>, offsets: &[usize]) -> TextMatchResult {
+        let whole = captures.get(0).expect("group 0 is always set");
+        let group_spans: GroupSpans = (1..captures.len())
+            .map(|i| captures.get(i).map(|m| (offsets[m.start()], offsets[m.end()])))
+            .collect();
+        let groups: Vec<Option<Text>> = (1..captures.len())
+            .map(|i| captures.get(i).map(|m| Text::from_string_slice(m.as_str())))
+            .collect();
+        let named_groups = self
+            .group_names()
+            .iter()
+            .zip(&groups)
+            .filter_map(|(name, group)| name.clone().map(|name| (name, group.clone())))
+            .collect();
+
+        TextMatchResult {
+            matched_text: Text::from_string_slice(whole.as_str()),
+            start: offsets[whole.start()],
+            end: offsets[whole.end()],
+            groups,
+            group_spans,
+            named_groups,
+        }
     }
 
     /// Splits the text by the regex pattern.
@@ -239,19 +260,44 @@ impl<'t> Iterator for TextMatchIter<'t> {
         let match_result = self.regex.find(&remaining_text)?;
         
         // Adjust positions to be relative to original text
+        let offset = self.last_end;
         let adjusted_result = TextMatchResult {
             matched_text: match_result.matched_text,
-            start: match_result.start + self.last_end,
-            end: match_result.end + self.last_end,
+            start: match_result.start + offset,
+            end: match_result.end + offset,
             groups: match_result.groups,
+            group_spans: match_result
+                .group_spans
+                .into_iter()
+                .map(|span| span.map(|(start, end)| (start + offset, end + offset)))
+                .collect(),
             named_groups: match_result.named_groups,
         };
         
-        self.last_end = adjusted_result.end;
+        // Step past an empty match so the next search cannot find it again
+        self.last_end = if adjusted_result.end == adjusted_result.start {
+            adjusted_result.end + 1
+        } else {
+            adjusted_result.end
+        };
         Some(adjusted_result)
     }
 }
 
+/// Maps byte offsets of `s` to character offsets.
+///
+/// Only entries at character boundaries (and at `s.len()`) are meaningful.
+fn char_offsets(s: &str) -> Vec<usize> {
+    let mut offsets = vec![0; s.len() + 1];
+    let mut count = 0;
+    for (byte, _) in s.char_indices() {
+        offsets[byte] = count;
+        count += 1;
+    }
+    offsets[s.len()] = count;
+    offsets
+}
+
 impl Default for RegexFlags {
     fn default() -> Self {
         Self {
@@ -295,6 +341,68 @@ impl RegexFlags {
     }
 }
 
+// ============= MATCH OBJECTS =============
+
+/// A match as seen from Scheme, returned by `regex-search` and `regex-search-all`.
+#[derive(Debug, Clone)]
+pub struct RegexMatch {
+    /// The underlying match result
+    result: TextMatchResult,
+    /// Group names, entry `i` names group `i + 1`
+    names: Vec<Option<String>>,
+}
+
+impl RegexMatch {
+    /// Wraps a match result of `regex`.
+    pub fn new(result: TextMatchResult, regex: &TextRegex) -> Self {
+        Self {
+            result,
+            names: regex.group_names().to_vec(),
+        }
+    }
+
+    /// Extracts a match object from a value.
+    pub fn from_value(value: &Value) -> Option<&Self> {
+        match value {
+            Value::Opaque(inner) => inner.downcast_ref::<RegexMatch>(),
+            _ => None,
+        }
+    }
+
+    /// Gets the character span of group `index`; group 0 is the whole match.
+    pub fn span(&self, index: usize) -> Option<(usize, usize)> {
+        if index == 0 {
+            Some((self.result.start, self.result.end))
+        } else {
+            self.result.group_spans.get(index - 1).copied().flatten()
+        }
+    }
+
+    /// Gets the text of group `index`; group 0 is the whole match.
+    pub fn group(&self, index: usize) -> Option<&Text> {
+        if index == 0 {
+            Some(&self.result.matched_text)
+        } else {
+            self.result.groups.get(index - 1).and_then(Option::as_ref)
+        }
+    }
+
+    /// Resolves a group designator: an index, or a group name as a string or symbol.
+    fn group_index(&self, group: &Value) -> Option<usize> {
+        if let Some(index) = group.as_integer() {
+            return usize::try_from(index).ok().filter(|&i| i <= self.names.len());
+        }
+        let name = match group.as_symbol() {
+            Some(id) => crate::utils::symbol_name(id)?,
+            None => group.as_string()?.to_string(),
+        };
+        self.names
+            .iter()
+            .position(|n| n.as_deref() == Some(name.as_str()))
+            .map(|i| i + 1)
+    }
+}
+
 // ============= SCHEME BINDINGS =============
 
 /// Creates regex operation bindings for the standard library.
@@ -310,6 +418,9 @@ pub fn create_regex_bindings(env: &Arc<ThreadSafeEnvironment>) {
     
     // Text splitting
     bind_regex_splitting(env);
+    
+    // Match object access
+    bind_regex_match_access(env);
 }
 
 /// Binds regex construction operations.
@@ -396,6 +507,36 @@ fn bind_regex_splitting(env: &Arc<ThreadSafeEnvironment>) {
     })));
 }
 
+/// Binds match object accessors.
+fn bind_regex_match_access(env: &Arc<ThreadSafeEnvironment>) {
+    // match-group
+    env.define("match-group".to_string(), Value::Primitive(Arc::new(PrimitiveProcedure {
+        name: "match-group".to_string(),
+        arity_min: 1,
+        arity_max: Some(2),
+        implementation: PrimitiveImpl::RustFn(primitive_match_group),
+        effects: vec![Effect::Pure],
+    })));
+    
+    // match-start
+    env.define("match-start".to_string(), Value::Primitive(Arc::new(PrimitiveProcedure {
+        name: "match-start".to_string(),
+        arity_min: 1,
+        arity_max: Some(2),
+        implementation: PrimitiveImpl::RustFn(primitive_match_start),
+        effects: vec![Effect::Pure],
+    })));
+    
+    // match-end
+    env.define("match-end".to_string(), Value::Primitive(Arc::new(PrimitiveProcedure {
+        name: "match-end".to_string(),
+        arity_min: 1,
+        arity_max: Some(2),
+        implementation: PrimitiveImpl::RustFn(primitive_match_end),
+        effects: vec![Effect::Pure],
+    })));
+}
+
 // ============= PRIMITIVE IMPLEMENTATIONS =============
 
 /// regex-compile operation
@@ -491,10 +632,7 @@ fn primitive_regex_search(args: &[Value]) -> Result<Value> {
     let regex = TextRegex::new(pattern)?;
     
     match regex.find(&text) {
-        Some(match_result) => {
-            // Return a match object - for now, return the matched text
-            Ok(match_result.matched_text.into())
-        }
+        Some(match_result) => Ok(Value::opaque(RegexMatch::new(match_result, &regex))),
         None => Ok(Value::boolean(false)),
     }
 }
@@ -521,7 +659,7 @@ fn primitive_regex_search_all(args: &[Value]) -> Result<Value> {
     let matches = regex.find_all(&text);
     let match_values: Vec<Value> = matches
         .into_iter()
-        .map(|m| m.matched_text.into())
+        .map(|m| Value::opaque(RegexMatch::new(m, &regex)))
         .collect();
     
     Ok(Value::list(match_values))
@@ -612,6 +750,53 @@ fn primitive_regex_split(args: &[Value]) -> Result<Value> {
     Ok(Value::list(part_values))
 }
 
+/// match-group operation
+fn primitive_match_group(args: &[Value]) -> Result<Value> {
+    let (m, index) = selected_group(args, "match-group")?;
+    Ok(m.group(index).map_or(Value::boolean(false), |text| text.clone().into()))
+}
+
+/// match-start operation
+fn primitive_match_start(args: &[Value]) -> Result<Value> {
+    let (m, index) = selected_group(args, "match-start")?;
+    Ok(m.span(index).map_or(Value::boolean(false), |(start, _)| Value::integer(start as i64)))
+}
+
+/// match-end operation
+fn primitive_match_end(args: &[Value]) -> Result<Value> {
+    let (m, index) = selected_group(args, "match-end")?;
+    Ok(m.span(index).map_or(Value::boolean(false), |(_, end)| Value::integer(end as i64)))
+}
+
+/// Reads the match object and optional group designator (default 0) of a match accessor.
+fn selected_group<'a>(args: &'a [Value], name: &str) -> Result<(&'a RegexMatch, usize)> {
+    if args.is_empty() || args.len() > 2 {
+        return Err(Box::new(DiagnosticError::runtime_error(
+            format!("{name} expects 1-2 arguments, got {}", args.len()),
+            None,
+        )));
+    }
+    
+    let m = RegexMatch::from_value(&args[0]).ok_or_else(|| {
+        Box::new(DiagnosticError::runtime_error(
+            format!("{name} first argument must be a match object"),
+            None,
+        ))
+    })?;
+    
+    let index = match args.get(1) {
+        Some(group) => m.group_index(group).ok_or_else(|| {
+            Box::new(DiagnosticError::runtime_error(
+                format!("{name}: no group {group} in pattern"),
+                None,
+            ))
+        })?,
+        None => 0,
+    };
+    
+    Ok((m, index))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -690,4 +875,78 @@ mod tests {
             assert_eq!(number.to_string(), "123");
         }
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_find_all_digits() {
+        let regex = TextRegex::new(r"\d+").unwrap();
+        let text = Text::from_string_slice("a1 b22 c333");
+        
+        let matches = regex.find_all(&text);
+        let found: Vec<_> = matches.iter().map(|m| (m.matched_text.to_string(), m.start, m.end)).collect();
+        assert_eq!(found, vec![
+            ("1".to_string(), 1, 2),
+            ("22".to_string(), 4, 6),
+            ("333".to_string(), 8, 11),
+        ]);
+    }
+
+    #[test]
+    fn test_empty_matches_advance() {
+        let regex = TextRegex::new(r"\d*").unwrap();
+        let text = Text::from_string_slice("a1");
+        
+        let spans: Vec<_> = regex.find_all(&text).iter().map(|m| (m.start, m.end)).collect();
+        assert_eq!(spans, vec![(0, 0), (1, 2), (2, 2)]);
+        
+        let iter_spans: Vec<_> = regex.find_iter(&text).map(|m| (m.start, m.end)).collect();
+        assert_eq!(iter_spans, vec![(0, 0), (1, 2), (2, 2)]);
+    }
+
+    #[test]
+    fn test_group_spans_use_character_positions() {
+        let regex = TextRegex::new(r"(\w+)=(\d+)").unwrap();
+        let text = Text::from_string_slice("\u{00e9}\u{00e9} k=42");
+        
+        let m = regex.find(&text).unwrap();
+        assert_eq!((m.start, m.end), (3, 7));
+        assert_eq!(m.group_spans, vec![Some((3, 4)), Some((5, 7))]);
+        assert_eq!(m.groups[1].as_ref().unwrap().to_string(), "42");
+    }
+
+    #[test]
+    fn test_replacement_backreferences() {
+        let regex = TextRegex::new(r"(?P<key>\w+)=(\d+)").unwrap();
+        let text = Text::from_string_slice("a=1, b=22");
+        
+        let first = regex.replace(&text, &Text::from_string_slice("$2:${key}"));
+        assert_eq!(first.to_string(), "1:a, b=22");
+        
+        let all = regex.replace_all(&text, &Text::from_string_slice("${key}<-$2 $$"));
+        assert_eq!(all.to_string(), "a<-1 $, b<-22 $");
+    }
+
+    #[test]
+    fn test_match_object_accessors() {
+        let search = |pattern: &str, text: &str| {
+            primitive_regex_search(&[Value::string(pattern), Value::string(text)]).unwrap()
+        };
+        
+        let m = search(r"(?P<sign>-)?(?P<digits>\d+)", "x 42");
+        assert_eq!(primitive_match_group(&[m.clone()]).unwrap(), Value::string("42"));
+        assert_eq!(primitive_match_start(&[m.clone()]).unwrap(), Value::integer(2));
+        assert_eq!(primitive_match_end(&[m.clone()]).unwrap(), Value::integer(4));
+        assert_eq!(
+            primitive_match_group(&[m.clone(), Value::string("digits")]).unwrap(),
+            Value::string("42")
+        );
+        assert_eq!(
+            primitive_match_group(&[m.clone(), Value::symbol_from_str("sign")]).unwrap(),
+            Value::boolean(false)
+        );
+        assert_eq!(primitive_match_start(&[m.clone(), Value::integer(1)]).unwrap(), Value::boolean(false));
+        assert!(primitive_match_group(&[m.clone(), Value::integer(3)]).is_err());
+        assert!(primitive_match_group(&[m, Value::string("missing")]).is_err());
+        
+        assert_eq!(search(r"\d", "abc"), Value::boolean(false));
+    }
+}
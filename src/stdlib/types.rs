@@ -1,7 +1,9 @@
 //! Type operations for the Lambdust standard library.
 //!
 //! This module implements Lambdust-specific type operations including
-//! type checking, type manipulation, and gradual typing support.
+//! type checking, type manipulation, and gradual typing support, plus
+//! the symbol comparisons `symbol=?` and `symbol<?`, and the hash
+//! procedures that agree with the container hash tables.
 
 use crate::diagnostics::{Error as DiagnosticError, Result};
 use crate::eval::value::{Value, PrimitiveProcedure, PrimitiveImpl, ThreadSafeEnvironment};
@@ -21,6 +23,12 @@ pub fn create_type_bindings(env: &Arc<ThreadSafeEnvironment>) {
     
     // Gradual typing support
     bind_gradual_typing(env);
+    
+    // Symbol comparison
+    bind_symbol_comparison(env);
+    
+    // Hash functions
+    bind_hash_functions(env);
 }
 
 /// Binds type query operations.
@@ -188,6 +196,50 @@ fn bind_gradual_typing(env: &Arc<ThreadSafeEnvironment>) {
     })));
 }
 
+/// Binds symbol comparison operations.
+fn bind_symbol_comparison(env: &Arc<ThreadSafeEnvironment>) {
+    // symbol=?
+    env.define("symbol=?".to_string(), Value::Primitive(Arc::new(PrimitiveProcedure {
+        name: "symbol=?".to_string(),
+        arity_min: 2,
+        arity_max: None,
+        implementation: PrimitiveImpl::RustFn(primitive_symbol_equal),
+        effects: vec![Effect::Pure],
+    })));
+    
+    // symbol<?
+    env.define("symbol<?".to_string(), Value::Primitive(Arc::new(PrimitiveProcedure {
+        name: "symbol<?".to_string(),
+        arity_min: 2,
+        arity_max: None,
+        implementation: PrimitiveImpl::RustFn(primitive_symbol_less),
+        effects: vec![Effect::Pure],
+    })));
+}
+
+/// Binds hash functions matching the container hash tables.
+fn bind_hash_functions(env: &Arc<ThreadSafeEnvironment>) {
+    type HashFunction = fn(&[Value]) -> Result<Value>;
+    let hash_functions: [(&str, HashFunction); 6] = [
+        ("equal-hash", primitive_equal_hash),
+        ("eq-hash", primitive_eq_hash),
+        ("string-hash", primitive_string_hash),
+        ("string-ci-hash", primitive_string_ci_hash),
+        ("symbol-hash", primitive_symbol_hash),
+        ("number-hash", primitive_number_hash),
+    ];
+    
+    for (name, implementation) in hash_functions {
+        env.define(name.to_string(), Value::Primitive(Arc::new(PrimitiveProcedure {
+            name: name.to_string(),
+            arity_min: 1,
+            arity_max: Some(1),
+            implementation: PrimitiveImpl::RustFn(implementation),
+            effects: vec![Effect::Pure],
+        })));
+    }
+}
+
 // ============= IMPLEMENTATIONS =============
 
 /// type-of procedure
@@ -406,13 +458,123 @@ fn primitive_function_return_type(_args: &[Value]) -> Result<Value> {
     )))
 }
 
+/// symbol=? procedure
+fn primitive_symbol_equal(args: &[Value]) -> Result<Value> {
+    if args.len() < 2 {
+        return Err(Box::new(DiagnosticError::runtime_error(
+            "symbol=? requires at least 2 arguments".to_string(),
+            None,
+        )));
+    }
+    
+    let keys = args
+        .iter()
+        .map(|arg| symbol_order_key(arg, "symbol=?"))
+        .collect::<Result<Vec<_>>>()?;
+    
+    Ok(Value::boolean(keys.windows(2).all(|pair| pair[0] == pair[1])))
+}
+
+/// symbol<? procedure
+fn primitive_symbol_less(args: &[Value]) -> Result<Value> {
+    if args.len() < 2 {
+        return Err(Box::new(DiagnosticError::runtime_error(
+            "symbol<? requires at least 2 arguments".to_string(),
+            None,
+        )));
+    }
+    
+    let keys = args
+        .iter()
+        .map(|arg| symbol_order_key(arg, "symbol<?"))
+        .collect::<Result<Vec<_>>>()?;
+    
+    Ok(Value::boolean(keys.windows(2).all(|pair| pair[0] < pair[1])))
+}
+
+/// equal-hash procedure
+///
+/// Returns the hash a default container hash table uses for the value.
+fn primitive_equal_hash(args: &[Value]) -> Result<Value> {
+    Ok(container_hash(&args[0]))
+}
+
+/// eq-hash procedure
+///
+/// Returns the hash an identity (`eq?`) container hash table uses for the
+/// value. It depends on the object's identity, not its contents.
+fn primitive_eq_hash(args: &[Value]) -> Result<Value> {
+    Ok(Value::integer((crate::containers::utils::identity_hash(&args[0]) >> 1) as i64))
+}
+
+/// string-hash procedure
+fn primitive_string_hash(args: &[Value]) -> Result<Value> {
+    hash_checked(&args[0], "string-hash", "string", Value::is_string)
+}
+
+/// string-ci-hash procedure
+///
+/// Hashes the case-folded string, so strings equal under `string-ci=?`
+/// hash equal. Folding follows `string-ci=?`, covering all of Unicode.
+fn primitive_string_ci_hash(args: &[Value]) -> Result<Value> {
+    match args[0].as_string_owned() {
+        Some(s) => Ok(container_hash(&Value::string(s.to_lowercase()))),
+        None => hash_checked(&args[0], "string-ci-hash", "string", Value::is_string),
+    }
+}
+
+/// symbol-hash procedure
+fn primitive_symbol_hash(args: &[Value]) -> Result<Value> {
+    hash_checked(&args[0], "symbol-hash", "symbol", Value::is_symbol)
+}
+
+/// number-hash procedure
+fn primitive_number_hash(args: &[Value]) -> Result<Value> {
+    hash_checked(&args[0], "number-hash", "number", Value::is_number)
+}
+
 // ============= HELPER FUNCTIONS =============
 
+/// Hashes a value after checking that it has the kind the procedure hashes.
+fn hash_checked(value: &Value, operation: &str, kind: &str, accepts: fn(&Value) -> bool) -> Result<Value> {
+    if !accepts(value) {
+        return Err(Box::new(DiagnosticError::runtime_error(
+            format!("{operation} requires a {kind} argument, got {value}"),
+            None,
+        )));
+    }
+    Ok(container_hash(value))
+}
+
+/// Container hash of a value as a non-negative fixnum.
+fn container_hash(value: &Value) -> Value {
+    Value::integer((crate::containers::utils::hash_value(value) >> 1) as i64)
+}
+
+/// Ordering key of a symbol: whether its name is unknown, its name, then its ID.
+///
+/// Symbol IDs follow allocation order, so they only break ties between
+/// distinct symbols sharing a name. Symbols the interner cannot name sort
+/// after all named ones.
+fn symbol_order_key(value: &Value, operation: &str) -> Result<(bool, String, usize)> {
+    match value {
+        Value::Symbol(id) => {
+            let name = crate::utils::symbol_name(*id);
+            Ok((name.is_none(), name.unwrap_or_default(), id.id()))
+        }
+        _ => Err(Box::new(DiagnosticError::runtime_error(
+            format!("{operation} requires symbol arguments, got {value}"),
+            None,
+        ))),
+    }
+}
+
 /// Gets the type name of a value.
 fn get_value_type_name(value: &Value) -> String {
     match value {
         Value::Literal(lit) => match lit {
             crate::ast::Literal::ExactInteger(_) => "integer".to_string(),
+            crate::ast::Literal::BigInteger(_) => "integer".to_string(),
             crate::ast::Literal::InexactReal(_) => "real".to_string(),
             crate::ast::Literal::Number(_) => "number".to_string(),
             crate::ast::Literal::Rational { .. } => "rational".to_string(),
@@ -428,6 +590,7 @@ fn get_value_type_name(value: &Value) -> String {
         Value::Keyword(_) => "keyword".to_string(),
         Value::Nil => "null".to_string(),
         Value::Unspecified => "unspecified".to_string(),
+        Value::EofObject => "eof-object".to_string(),
         Value::Pair(_, _) => "pair".to_string(),
         Value::MutablePair(_, _) => "pair".to_string(),
         Value::Vector(_) => "vector".to_string(),
@@ -469,6 +632,7 @@ fn get_value_type_name(value: &Value) -> String {
         Value::Set(_) => "set".to_string(),
         Value::Bag(_) => "bag".to_string(),
         Value::Generator(_) => "generator".to_string(),
+        Value::Values(_) => "values".to_string(),
         Value::Opaque(_) => "opaque".to_string(),
     }
 }
@@ -530,4 +694,91 @@ mod tests {
         let result = primitive_any_type(&[]).unwrap();
         assert!(matches!(result, Value::Type(_)));
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_symbol_less_orders_by_name() {
+        // Intern in reverse order so IDs disagree with names
+        let zeta = Value::symbol_from_str("zeta-symbol-order");
+        let alpha = Value::symbol_from_str("alpha-symbol-order");
+        
+        assert_eq!(primitive_symbol_less(&[alpha.clone(), zeta.clone()]).unwrap(), Value::boolean(true));
+        assert_eq!(primitive_symbol_less(&[zeta.clone(), alpha.clone()]).unwrap(), Value::boolean(false));
+        assert_eq!(primitive_symbol_less(&[alpha.clone(), alpha.clone()]).unwrap(), Value::boolean(false));
+        assert!(primitive_symbol_less(&[alpha, Value::string("zeta")]).is_err());
+    }
+    
+    #[test]
+    fn test_symbol_equal_chain() {
+        let a = Value::symbol_from_str("a");
+        let b = Value::symbol_from_str("b");
+        
+        assert_eq!(primitive_symbol_equal(&[a.clone(), a.clone(), a.clone()]).unwrap(), Value::boolean(true));
+        assert_eq!(primitive_symbol_equal(&[a.clone(), a.clone(), b]).unwrap(), Value::boolean(false));
+        assert!(primitive_symbol_equal(&[a]).is_err());
+    }
+    
+    #[test]
+    fn test_hash_functions_agree_with_hash_tables() {
+        use crate::containers::HashTable;
+        
+        let build = || Value::list(vec![
+            Value::string("key"),
+            Value::vector(vec![Value::integer(1), Value::symbol_from_str("nested")]),
+        ]);
+        let key = build();
+        assert_eq!(primitive_equal_hash(&[key.clone()]).unwrap(), primitive_equal_hash(&[build()]).unwrap());
+        
+        // The procedures report the hash a default table buckets the key by
+        let mut table = HashTable::new();
+        table.insert(key.clone(), Value::integer(42));
+        let table_hash = table.comparator().hash(&key);
+        assert_eq!(primitive_equal_hash(&[key]).unwrap(), Value::integer((table_hash >> 1) as i64));
+        assert_eq!(table.get(&build()), Some(&Value::integer(42)));
+        
+        let string = Value::string("hello");
+        assert_eq!(primitive_string_hash(&[string.clone()]).unwrap(), primitive_equal_hash(&[string]).unwrap());
+        assert_eq!(
+            primitive_string_hash(&[Value::mutable_string("hello")]).unwrap(),
+            primitive_string_hash(&[Value::string("hello")]).unwrap()
+        );
+        let symbol = Value::symbol_from_str("hashed");
+        assert_eq!(primitive_symbol_hash(&[symbol.clone()]).unwrap(), primitive_equal_hash(&[symbol]).unwrap());
+        assert_eq!(primitive_number_hash(&[Value::integer(7)]).unwrap(), primitive_equal_hash(&[Value::integer(7)]).unwrap());
+        
+        assert!(primitive_string_hash(&[Value::integer(1)]).is_err());
+        assert!(primitive_symbol_hash(&[Value::string("a")]).is_err());
+        assert!(primitive_number_hash(&[Value::symbol_from_str("a")]).is_err());
+    }
+    
+    #[test]
+    fn test_string_ci_hash_agrees_with_string_ci_equal() {
+        let hash = |s: &str| primitive_string_ci_hash(&[Value::string(s)]).unwrap();
+        
+        assert_eq!(hash("Content-Type"), hash("content-type"));
+        assert_eq!(hash("ÄRGER Σ"), hash("ärger σ"));
+        assert_eq!(hash(""), primitive_string_hash(&[Value::string("")]).unwrap());
+        assert_eq!(hash("lower"), primitive_string_hash(&[Value::string("lower")]).unwrap());
+        assert_eq!(primitive_string_ci_hash(&[Value::mutable_string("MiXeD")]).unwrap(), hash("mixed"));
+        assert!(primitive_string_ci_hash(&[Value::symbol_from_str("a")]).is_err());
+    }
+    
+    #[test]
+    fn test_eq_hash_follows_identity() {
+        use crate::containers::{HashComparator, HashTable};
+        
+        let build = || Value::vector(vec![Value::integer(1)]);
+        let key = build();
+        let mut table = HashTable::with_comparator(HashComparator::identity());
+        table.insert(key.clone(), Value::integer(42));
+        let table_hash = table.comparator().hash(&key);
+        assert_eq!(primitive_eq_hash(&[key.clone()]).unwrap(), Value::integer((table_hash >> 1) as i64));
+        assert_eq!(table.get(&key), Some(&Value::integer(42)));
+        assert_eq!(table.get(&build()), None);
+        
+        // Immediates hash by value
+        assert_eq!(
+            primitive_eq_hash(&[Value::symbol_from_str("a")]).unwrap(),
+            primitive_eq_hash(&[Value::symbol_from_str("a")]).unwrap()
+        );
+    }
+}
@@ -7,6 +7,7 @@ use crate::diagnostics::{Error as DiagnosticError, Result};
 use crate::eval::value::{Value, PrimitiveProcedure, PrimitiveImpl, ThreadSafeEnvironment};
 use crate::effects::Effect;
 use crate::numeric::{NumericValue, SimdNumericOps};
+use crate::runtime::{shared_runtime, ThreadPool};
 use std::sync::Arc;
 
 /// Creates vector operation bindings for the standard library.
@@ -28,6 +29,9 @@ pub fn create_vector_bindings(env: &Arc<ThreadSafeEnvironment>) {
     
     // Vector conversion
     bind_vector_conversion(env);
+    
+    // Vector searching
+    bind_vector_searching(env);
 }
 
 /// Binds vector creation operations.
@@ -130,6 +134,15 @@ fn bind_vector_manipulation(env: &Arc<ThreadSafeEnvironment>) {
         implementation: PrimitiveImpl::RustFn(primitive_vector_append),
         effects: vec![Effect::Pure],
     })));
+    
+    // vector-concatenate
+    env.define("vector-concatenate".to_string(), Value::Primitive(Arc::new(PrimitiveProcedure {
+        name: "vector-concatenate".to_string(),
+        arity_min: 1,
+        arity_max: Some(1),
+        implementation: PrimitiveImpl::RustFn(primitive_vector_concatenate),
+        effects: vec![Effect::Pure],
+    })));
 }
 
 /// Binds vector iteration operations.
@@ -139,7 +152,7 @@ fn bind_vector_iteration(env: &Arc<ThreadSafeEnvironment>) {
         name: "vector-map".to_string(),
         arity_min: 2,
         arity_max: None,
-        implementation: PrimitiveImpl::RustFn(primitive_vector_map),
+        implementation: PrimitiveImpl::EvaluatorIntegrated(evaluator_vector_map),
         effects: vec![Effect::Pure],
     })));
     
@@ -148,7 +161,43 @@ fn bind_vector_iteration(env: &Arc<ThreadSafeEnvironment>) {
         name: "vector-for-each".to_string(),
         arity_min: 2,
         arity_max: None,
-        implementation: PrimitiveImpl::RustFn(primitive_vector_for_each),
+        implementation: PrimitiveImpl::EvaluatorIntegrated(evaluator_vector_for_each),
+        effects: vec![Effect::Pure], // May call user functions with effects
+    })));
+
+    // parallel-vector-map
+    env.define("parallel-vector-map".to_string(), Value::Primitive(Arc::new(PrimitiveProcedure {
+        name: "parallel-vector-map".to_string(),
+        arity_min: 2,
+        arity_max: Some(2),
+        implementation: PrimitiveImpl::EvaluatorIntegrated(evaluator_parallel_vector_map),
+        effects: vec![Effect::Pure], // The mapped procedure is assumed pure
+    })));
+
+    // vector-map/index
+    env.define("vector-map/index".to_string(), Value::Primitive(Arc::new(PrimitiveProcedure {
+        name: "vector-map/index".to_string(),
+        arity_min: 2,
+        arity_max: None,
+        implementation: PrimitiveImpl::EvaluatorIntegrated(evaluator_vector_map_index),
+        effects: vec![Effect::Pure], // May call user functions with effects
+    })));
+    
+    // vector-for-each/index
+    env.define("vector-for-each/index".to_string(), Value::Primitive(Arc::new(PrimitiveProcedure {
+        name: "vector-for-each/index".to_string(),
+        arity_min: 2,
+        arity_max: None,
+        implementation: PrimitiveImpl::EvaluatorIntegrated(evaluator_vector_for_each_index),
+        effects: vec![Effect::Pure], // May call user functions with effects
+    })));
+    
+    // vector-count
+    env.define("vector-count".to_string(), Value::Primitive(Arc::new(PrimitiveProcedure {
+        name: "vector-count".to_string(),
+        arity_min: 2,
+        arity_max: None,
+        implementation: PrimitiveImpl::EvaluatorIntegrated(evaluator_vector_count),
         effects: vec![Effect::Pure], // May call user functions with effects
     })));
 }
@@ -174,6 +223,36 @@ fn bind_vector_conversion(env: &Arc<ThreadSafeEnvironment>) {
     })));
 }
 
+/// Binds vector searching operations (SRFI 133).
+fn bind_vector_searching(env: &Arc<ThreadSafeEnvironment>) {
+    // vector-index
+    env.define("vector-index".to_string(), Value::Primitive(Arc::new(PrimitiveProcedure {
+        name: "vector-index".to_string(),
+        arity_min: 2,
+        arity_max: None,
+        implementation: PrimitiveImpl::EvaluatorIntegrated(evaluator_vector_index),
+        effects: vec![Effect::Pure], // May call user functions with effects
+    })));
+    
+    // vector-index-right
+    env.define("vector-index-right".to_string(), Value::Primitive(Arc::new(PrimitiveProcedure {
+        name: "vector-index-right".to_string(),
+        arity_min: 2,
+        arity_max: None,
+        implementation: PrimitiveImpl::EvaluatorIntegrated(evaluator_vector_index_right),
+        effects: vec![Effect::Pure], // May call user functions with effects
+    })));
+    
+    // vector-binary-search
+    env.define("vector-binary-search".to_string(), Value::Primitive(Arc::new(PrimitiveProcedure {
+        name: "vector-binary-search".to_string(),
+        arity_min: 3,
+        arity_max: Some(3),
+        implementation: PrimitiveImpl::EvaluatorIntegrated(evaluator_vector_binary_search),
+        effects: vec![Effect::Pure], // May call user functions with effects
+    })));
+}
+
 // ============= VECTOR CREATION IMPLEMENTATIONS =============
 
 /// vector procedure
@@ -215,6 +294,9 @@ fn primitive_make_vector(args: &[Value]) -> Result<Value> {
 }
 
 /// vector-copy procedure
+///
+/// Returns a newly allocated vector holding the elements between start and
+/// end; only that range is copied out of the source, under its read lock.
 fn primitive_vector_copy(args: &[Value]) -> Result<Value> {
     if args.is_empty() || args.len() > 3 {
         return Err(Box::new(DiagnosticError::runtime_error(
@@ -223,49 +305,42 @@ fn primitive_vector_copy(args: &[Value]) -> Result<Value> {
         )));
     }
     
-    let vector = extract_vector(&args[0], "vector-copy")?;
+    let Value::Vector(vector_ref) = &args[0] else {
+        return Err(Box::new(DiagnosticError::runtime_error(
+            "vector-copy requires a vector".to_string(),
+            None,
+        )));
+    };
+    let vector = vector_ref.read().unwrap();
     let length = vector.len();
     
-    let start = if args.len() > 1 {
-        let start_idx = args[1].as_integer().ok_or_else(|| {
+    let index = |arg: &Value, name: &str| {
+        arg.as_integer().ok_or_else(|| {
             Box::new(DiagnosticError::runtime_error(
-                "vector-copy start index must be an integer".to_string(),
+                format!("vector-copy {name} index must be an integer"),
                 None,
             ))
-        })? as usize;
-        
-        if start_idx > length {
-            return Err(Box::new(DiagnosticError::runtime_error(
-                "vector-copy start index out of bounds".to_string(),
-                None,
-            )));
-        }
-        start_idx
-    } else {
-        0
+        })
     };
     
-    let end = if args.len() > 2 {
-        let end_idx = args[2].as_integer().ok_or_else(|| {
-            Box::new(DiagnosticError::runtime_error(
-                "vector-copy end index must be an integer".to_string(),
-                None,
-            ))
-        })? as usize;
-        
-        if end_idx > length || end_idx < start {
-            return Err(Box::new(DiagnosticError::runtime_error(
-                "vector-copy end index out of bounds".to_string(),
-                None,
-            )));
-        }
-        end_idx
-    } else {
-        length
-    };
+    let start = if args.len() > 1 { index(&args[1], "start")? } else { 0 };
+    if start < 0 || start as usize > length {
+        return Err(Box::new(DiagnosticError::runtime_error(
+            format!("vector-copy start index {start} out of bounds for length {length}"),
+            None,
+        )));
+    }
     
-    let result = vector[start..end].to_vec();
-    Ok(Value::vector(result))
+    let end = if args.len() > 2 { index(&args[2], "end")? } else { length as i64 };
+    if end < start || end as usize > length {
+        return Err(Box::new(DiagnosticError::runtime_error(
+            format!("vector-copy end index {end} out of bounds for start {start} and length {length}"),
+            None,
+        )));
+    }
+    
+    let elements = vector[start as usize..end as usize].to_vec();
+    Ok(Value::vector(elements))
 }
 
 // ============= VECTOR PREDICATE IMPLEMENTATIONS =============
@@ -519,11 +594,48 @@ fn primitive_vector_copy_mut(args: &[Value]) -> Result<Value> {
 
 /// vector-append procedure
 fn primitive_vector_append(args: &[Value]) -> Result<Value> {
-    let mut result = Vec::new();
+    concatenate_vectors(args, "vector-append")
+}
+
+/// vector-concatenate procedure - SRFI 133
+///
+/// `(vector-concatenate list-of-vectors)` appends the vectors of a list.
+fn primitive_vector_concatenate(args: &[Value]) -> Result<Value> {
+    if args.len() != 1 {
+        return Err(Box::new(DiagnosticError::runtime_error(
+            format!("vector-concatenate expects 1 argument, got {}", args.len()),
+            None,
+        )));
+    }
     
-    for arg in args {
-        let vector = extract_vector(arg, "vector-append")?;
-        result.extend_from_slice(&vector);
+    let vectors = args[0].as_list().ok_or_else(|| {
+        DiagnosticError::runtime_error(
+            "vector-concatenate requires a list of vectors".to_string(),
+            None,
+        )
+    })?;
+    
+    concatenate_vectors(&vectors, "vector-concatenate")
+}
+
+/// Appends `vectors` into a single vector sized up front from their total length.
+fn concatenate_vectors(vectors: &[Value], operation: &str) -> Result<Value> {
+    let mut total = 0;
+    for vector in vectors {
+        match vector {
+            Value::Vector(vector_ref) => total += vector_ref.read().unwrap().len(),
+            _ => return Err(Box::new(DiagnosticError::runtime_error(
+                format!("{operation} requires vector arguments"),
+                None,
+            ))),
+        }
+    }
+    
+    let mut result = Vec::with_capacity(total);
+    for vector in vectors {
+        if let Value::Vector(vector_ref) = vector {
+            result.extend_from_slice(&vector_ref.read().unwrap());
+        }
     }
     
     Ok(Value::vector(result))
@@ -594,14 +706,40 @@ fn primitive_list_to_vector(args: &[Value]) -> Result<Value> {
         )));
     }
     
-    let list = args[0].as_list().ok_or_else(|| {
-        Box::new(DiagnosticError::runtime_error(
-            "list->vector requires a proper list".to_string(),
-            None,
-        ))
-    })?;
+    // A single iterative walk over either kind of pair, checking for a
+    // cycle as we go
+    let mut elements = Vec::new();
+    let mut current = args[0].clone();
+    let mut slow = args[0].clone();
+    loop {
+        let (car, cdr) = match &current {
+            Value::Nil => return Ok(Value::vector(elements)),
+            Value::Pair(car, cdr) => ((**car).clone(), (**cdr).clone()),
+            Value::MutablePair(car, cdr) => (car.read().unwrap().clone(), cdr.read().unwrap().clone()),
+            _ => break,
+        };
+        elements.push(car);
+        current = cdr;
+        
+        // The tortoise moves every other step; meeting the hare means a cycle
+        if elements.len() % 2 == 0 {
+            slow = match &slow {
+                Value::Pair(_, cdr) => (**cdr).clone(),
+                Value::MutablePair(_, cdr) => cdr.read().unwrap().clone(),
+                _ => break,
+            };
+            if let (Value::MutablePair(a, _), Value::MutablePair(b, _)) = (&slow, &current)
+                && Arc::ptr_eq(a, b)
+            {
+                break;
+            }
+        }
+    }
     
-    Ok(Value::vector(list))
+    Err(Box::new(DiagnosticError::runtime_error(
+        "list->vector requires a proper list".to_string(),
+        None,
+    )))
 }
 
 // ============= HELPER FUNCTIONS =============
@@ -619,162 +757,266 @@ fn extract_vector(value: &Value, operation: &str) -> Result<Vec<Value>> {
 
 // ============= VECTOR ITERATION IMPLEMENTATIONS =============
 
-/// vector-map procedure - R7RS required
-fn primitive_vector_map(args: &[Value]) -> Result<Value> {
-    if args.len() < 2 {
+/// parallel-vector-map procedure (extension)
+///
+/// Maps a pure procedure over one vector, splitting the elements into chunks
+/// that are evaluated on the shared runtime's evaluator threads against a
+/// snapshot of the caller's globals. Results keep the element order and the
+/// error of the first failing element is the one raised. Called from one of
+/// those threads, it maps in place rather than wait on its own pool.
+fn evaluator_parallel_vector_map(evaluator: &mut crate::eval::evaluator::Evaluator, args: &[Value]) -> Result<Value> {
+    if args.len() != 2 {
         return Err(Box::new(DiagnosticError::runtime_error(
-            "vector-map requires at least 2 arguments".to_string(),
+            "parallel-vector-map requires exactly 2 arguments".to_string(),
             None,
         )));
     }
-    
+
     let procedure = &args[0];
-    let vectors = &args[1..];
-    
-    // Verify procedure is callable
     if !procedure.is_procedure() {
         return Err(Box::new(DiagnosticError::runtime_error(
-            "vector-map first argument must be a procedure".to_string(),
+            "parallel-vector-map first argument must be a procedure".to_string(),
             None,
         )));
     }
+
+    let elements = extract_vector(&args[1], "parallel-vector-map")?;
+    if ThreadPool::on_worker_thread() {
+        let results = elements
+            .into_iter()
+            .map(|element| evaluator.call_procedure(procedure.clone(), vec![element], None))
+            .collect::<Result<Vec<_>>>()?;
+        return Ok(Value::vector(results));
+    }
+
+    let globals = evaluator.global_env().to_thread_safe();
+    let results = shared_runtime().parallel_map(procedure, &elements, &globals)?;
+    Ok(Value::vector(results))
+}
+
+/// vector-map procedure - R7RS required
+///
+/// `(vector-map proc vec1 vec2 ...)` applies `proc` to the elements at each
+/// index, collecting the results into a new vector as long as the shortest
+/// argument.
+fn evaluator_vector_map(evaluator: &mut crate::eval::evaluator::Evaluator, args: &[Value]) -> Result<Value> {
+    let (procedure, vector_data, length) = extract_index_arguments(args, "vector-map")?;
+    
+    let mut results = Vec::with_capacity(length);
+    for i in 0..length {
+        results.push(call_at_index(evaluator, &procedure, &vector_data, i)?);
+    }
     
-    // Convert all arguments to vectors and find minimum length
-    let mut vector_data = Vec::new();
-    let mut min_length = usize::MAX;
+    Ok(Value::vector(results))
+}
+
+/// vector-for-each procedure - R7RS required
+///
+/// Like `vector-map` but calls `proc` for its effects only, in increasing
+/// index order.
+fn evaluator_vector_for_each(evaluator: &mut crate::eval::evaluator::Evaluator, args: &[Value]) -> Result<Value> {
+    let (procedure, vector_data, length) = extract_index_arguments(args, "vector-for-each")?;
+    
+    for i in 0..length {
+        call_at_index(evaluator, &procedure, &vector_data, i)?;
+    }
     
-    for vector_arg in vectors.iter() {
-        let vector = extract_vector(vector_arg, "vector-map")?;
-        min_length = min_length.min(vector.len());
-        vector_data.push(vector);
+    Ok(Value::Unspecified)
+}
+
+/// vector-map/index procedure (extension)
+///
+/// `(vector-map/index proc vec1 vec2 ...)` calls `proc` with the index
+/// followed by the elements at that index, collecting the results into a
+/// new vector as long as the shortest argument.
+fn evaluator_vector_map_index(evaluator: &mut crate::eval::evaluator::Evaluator, args: &[Value]) -> Result<Value> {
+    let (procedure, vector_data, length) = extract_index_arguments(args, "vector-map/index")?;
+    
+    let mut results = Vec::with_capacity(length);
+    for i in 0..length {
+        results.push(call_with_index(evaluator, &procedure, &vector_data, i)?);
     }
     
-    // If any vector is empty, return empty vector
-    if min_length == 0 || min_length == usize::MAX {
-        return Ok(Value::vector(Vec::new()));
+    Ok(Value::vector(results))
+}
+
+/// vector-for-each/index procedure (extension)
+///
+/// Like `vector-map/index` but calls `proc` for its effects only, in
+/// increasing index order.
+fn evaluator_vector_for_each_index(evaluator: &mut crate::eval::evaluator::Evaluator, args: &[Value]) -> Result<Value> {
+    let (procedure, vector_data, length) = extract_index_arguments(args, "vector-for-each/index")?;
+    
+    for i in 0..length {
+        call_with_index(evaluator, &procedure, &vector_data, i)?;
     }
     
-    // Apply procedure to each position across all vectors
-    let mut results = Vec::new();
+    Ok(Value::Unspecified)
+}
+
+/// vector-count procedure - SRFI 133
+///
+/// `(vector-count pred vec1 vec2 ...)` returns the number of indices at
+/// which `pred` returns a true value across the vectors.
+fn evaluator_vector_count(evaluator: &mut crate::eval::evaluator::Evaluator, args: &[Value]) -> Result<Value> {
+    let (predicate, vector_data, length) = extract_index_arguments(args, "vector-count")?;
+    
+    let mut count = 0;
+    for i in 0..length {
+        if index_matches(evaluator, &predicate, &vector_data, i)? {
+            count += 1;
+        }
+    }
     
-    for i in 0..min_length {
-        let mut proc_args = Vec::new();
-        for vector in &vector_data {
-            proc_args.push(vector[i].clone());
+    Ok(Value::integer(count))
+}
+
+/// Applies `procedure` to `index` and the elements at `index` of each vector.
+fn call_with_index(
+    evaluator: &mut crate::eval::evaluator::Evaluator,
+    procedure: &Value,
+    vector_data: &[Vec<Value>],
+    index: usize,
+) -> Result<Value> {
+    let mut proc_args = Vec::with_capacity(vector_data.len() + 1);
+    proc_args.push(Value::integer(index as i64));
+    proc_args.extend(vector_data.iter().map(|vector| vector[index].clone()));
+    evaluator.call_procedure(procedure.clone(), proc_args, None)
+}
+
+// ============= VECTOR SEARCHING IMPLEMENTATIONS =============
+
+/// vector-index procedure - SRFI 133
+///
+/// `(vector-index pred vec1 vec2 ...)` returns the first index at which
+/// `pred` holds across the vectors, or `#f`.
+fn evaluator_vector_index(evaluator: &mut crate::eval::evaluator::Evaluator, args: &[Value]) -> Result<Value> {
+    let (predicate, vector_data, length) = extract_index_arguments(args, "vector-index")?;
+    
+    for i in 0..length {
+        if index_matches(evaluator, &predicate, &vector_data, i)? {
+            return Ok(Value::integer(i as i64));
         }
-        
-        // Apply the procedure - for now we can only handle primitive procedures
-        match procedure {
-            Value::Primitive(prim) => {
-                let result = match &prim.implementation {
-                    PrimitiveImpl::RustFn(func) => func(&proc_args)?,
-                    PrimitiveImpl::Native(func) => func(&proc_args)?,
-                    PrimitiveImpl::EvaluatorIntegrated(_) => {
-                        return Err(Box::new(DiagnosticError::runtime_error(
-                            "vector-map with evaluator-integrated functions requires evaluator access".to_string(),
-                            None,
-                        )));
-                    }
-                    PrimitiveImpl::ForeignFn { .. } => {
-                        return Err(Box::new(DiagnosticError::runtime_error(
-                            "vector-map with foreign functions not yet implemented".to_string(),
-                            None,
-                        )));
-                    }
-                };
-                results.push(result);
-            },
-            _ => {
-                return Err(Box::new(DiagnosticError::runtime_error(
-                    "vector-map with user-defined procedures requires evaluator integration (not yet implemented)".to_string(),
-                    None,
-                )));
-            }
+    }
+    
+    Ok(Value::boolean(false))
+}
+
+/// vector-index-right procedure - SRFI 133
+///
+/// Like `vector-index` but searches from the end of the vectors.
+fn evaluator_vector_index_right(evaluator: &mut crate::eval::evaluator::Evaluator, args: &[Value]) -> Result<Value> {
+    let (predicate, vector_data, length) = extract_index_arguments(args, "vector-index-right")?;
+    
+    for i in (0..length).rev() {
+        if index_matches(evaluator, &predicate, &vector_data, i)? {
+            return Ok(Value::integer(i as i64));
         }
     }
     
-    Ok(Value::vector(results))
+    Ok(Value::boolean(false))
 }
 
-/// vector-for-each procedure - R7RS required
-fn primitive_vector_for_each(args: &[Value]) -> Result<Value> {
-    if args.len() < 2 {
+/// vector-binary-search procedure - SRFI 133
+///
+/// `(vector-binary-search vec value cmp)` searches a vector sorted with
+/// respect to `cmp`, which is called as `(cmp element value)` and returns a
+/// negative, zero or positive number. Returns a matching index or `#f`.
+fn evaluator_vector_binary_search(evaluator: &mut crate::eval::evaluator::Evaluator, args: &[Value]) -> Result<Value> {
+    if args.len() != 3 {
         return Err(Box::new(DiagnosticError::runtime_error(
-            "vector-for-each requires at least 2 arguments".to_string(),
+            format!("vector-binary-search expects 3 arguments, got {}", args.len()),
             None,
         )));
     }
     
-    let procedure = &args[0];
-    let vectors = &args[1..];
+    let vector = extract_vector(&args[0], "vector-binary-search")?;
+    let value = &args[1];
+    let comparator = &args[2];
     
-    // Verify procedure is callable
-    if !procedure.is_procedure() {
+    if !comparator.is_procedure() {
         return Err(Box::new(DiagnosticError::runtime_error(
-            "vector-for-each first argument must be a procedure".to_string(),
+            "vector-binary-search third argument must be a procedure".to_string(),
             None,
         )));
     }
     
-    // Convert all arguments to vectors and find minimum length
-    let mut vector_data = Vec::new();
-    let mut min_length = usize::MAX;
+    // Half-open search window [low, high)
+    let mut low = 0;
+    let mut high = vector.len();
     
-    for vector_arg in vectors.iter() {
-        let vector = extract_vector(vector_arg, "vector-for-each")?;
-        min_length = min_length.min(vector.len());
-        vector_data.push(vector);
+    while low < high {
+        let middle = low + (high - low) / 2;
+        let order = evaluator.call_procedure(
+            comparator.clone(),
+            vec![vector[middle].clone(), value.clone()],
+            None,
+        )?;
+        let order = order.as_number().ok_or_else(|| {
+            Box::new(DiagnosticError::runtime_error(
+                "vector-binary-search comparator must return a number".to_string(),
+                None,
+            ))
+        })?;
+        
+        if order < 0.0 {
+            low = middle + 1;
+        } else if order > 0.0 {
+            high = middle;
+        } else {
+            return Ok(Value::integer(middle as i64));
+        }
     }
     
-    // If any vector is empty, return unspecified immediately
-    if min_length == 0 || min_length == usize::MAX {
-        return Ok(Value::Unspecified);
+    Ok(Value::boolean(false))
+}
+
+/// Splits `(pred vec1 vec2 ...)` into the predicate, the vector contents
+/// and the length of the shortest vector.
+fn extract_index_arguments(args: &[Value], operation: &str) -> Result<(Value, Vec<Vec<Value>>, usize)> {
+    if args.len() < 2 {
+        return Err(Box::new(DiagnosticError::runtime_error(
+            format!("{operation} requires at least 2 arguments"),
+            None,
+        )));
     }
     
-    // Apply procedure to each position across all vectors for side effects
-    for i in 0..min_length {
-        let mut proc_args = Vec::new();
-        for vector in &vector_data {
-            proc_args.push(vector[i].clone());
-        }
-        
-        // Apply the procedure - for now we can only handle primitive procedures
-        match procedure {
-            Value::Primitive(prim) => {
-                match &prim.implementation {
-                    PrimitiveImpl::RustFn(func) => {
-                        // Call the function but ignore the result (for-each is for side effects)
-                        func(&proc_args)?;
-                    },
-                    PrimitiveImpl::Native(func) => {
-                        // Call the function but ignore the result (for-each is for side effects)
-                        func(&proc_args)?;
-                    },
-                    PrimitiveImpl::EvaluatorIntegrated(_) => {
-                        return Err(Box::new(DiagnosticError::runtime_error(
-                            "vector-for-each with evaluator-integrated functions requires evaluator access".to_string(),
-                            None,
-                        )));
-                    },
-                    PrimitiveImpl::ForeignFn { .. } => {
-                        return Err(Box::new(DiagnosticError::runtime_error(
-                            "vector-for-each with foreign functions not yet implemented".to_string(),
-                            None,
-                        )));
-                    }
-                }
-            },
-            _ => {
-                return Err(Box::new(DiagnosticError::runtime_error(
-                    "vector-for-each with user-defined procedures requires evaluator integration (not yet implemented)".to_string(),
-                    None,
-                )));
-            }
-        }
+    let predicate = args[0].clone();
+    if !predicate.is_procedure() {
+        return Err(Box::new(DiagnosticError::runtime_error(
+            format!("{operation} first argument must be a procedure"),
+            None,
+        )));
     }
     
-    // vector-for-each returns unspecified
-    Ok(Value::Unspecified)
+    let vector_data = args[1..]
+        .iter()
+        .map(|vector| extract_vector(vector, operation))
+        .collect::<Result<Vec<_>>>()?;
+    let length = vector_data.iter().map(Vec::len).min().unwrap_or(0);
+    
+    Ok((predicate, vector_data, length))
+}
+
+/// Applies `procedure` to the elements at `index` of each vector.
+fn call_at_index(
+    evaluator: &mut crate::eval::evaluator::Evaluator,
+    procedure: &Value,
+    vector_data: &[Vec<Value>],
+    index: usize,
+) -> Result<Value> {
+    let proc_args = vector_data.iter().map(|vector| vector[index].clone()).collect();
+    evaluator.call_procedure(procedure.clone(), proc_args, None)
+}
+
+/// Applies `predicate` to the elements at `index` of each vector.
+fn index_matches(
+    evaluator: &mut crate::eval::evaluator::Evaluator,
+    predicate: &Value,
+    vector_data: &[Vec<Value>],
+    index: usize,
+) -> Result<bool> {
+    Ok(call_at_index(evaluator, predicate, vector_data, index)?.is_truthy())
 }
 
 #[cfg(test)]
@@ -847,6 +1089,46 @@ mod tests {
         assert_eq!(last, Value::integer(4));
     }
     
+    #[test]
+    fn test_vector_append_three_and_empty() {
+        let vec1 = Value::vector(vec![Value::integer(1)]);
+        let vec2 = Value::vector(vec![]);
+        let vec3 = Value::vector(vec![Value::integer(2), Value::integer(3)]);
+        
+        let result = primitive_vector_append(&[vec1, vec2.clone(), vec3]).unwrap();
+        assert_eq!(
+            extract_vector(&result, "test").unwrap(),
+            vec![Value::integer(1), Value::integer(2), Value::integer(3)]
+        );
+        
+        let empty = primitive_vector_append(&[]).unwrap();
+        assert!(extract_vector(&empty, "test").unwrap().is_empty());
+        let empty = primitive_vector_append(&[vec2.clone(), vec2]).unwrap();
+        assert!(extract_vector(&empty, "test").unwrap().is_empty());
+        
+        assert!(primitive_vector_append(&[Value::vector(vec![]), Value::integer(1)]).is_err());
+    }
+    
+    #[test]
+    fn test_vector_concatenate() {
+        let vectors = Value::list(vec![
+            Value::vector(vec![Value::integer(1), Value::integer(2)]),
+            Value::vector(vec![Value::integer(3)]),
+        ]);
+        let result = primitive_vector_concatenate(&[vectors]).unwrap();
+        assert_eq!(
+            extract_vector(&result, "test").unwrap(),
+            vec![Value::integer(1), Value::integer(2), Value::integer(3)]
+        );
+        
+        let empty = primitive_vector_concatenate(&[Value::Nil]).unwrap();
+        assert!(extract_vector(&empty, "test").unwrap().is_empty());
+        
+        let mixed = Value::list(vec![Value::vector(vec![]), Value::string("abc")]);
+        assert!(primitive_vector_concatenate(&[mixed]).is_err());
+        assert!(primitive_vector_concatenate(&[Value::integer(1)]).is_err());
+    }
+    
     #[test]
     fn test_vector_copy() {
         let original = Value::vector(vec![
@@ -944,7 +1226,7 @@ mod tests {
         
         let vector = Value::vector(vec![Value::number(1.0), Value::number(2.0), Value::number(3.0)]);
         let args = vec![Value::Primitive(double_proc), vector];
-        let result = primitive_vector_map(&args).unwrap();
+        let result = evaluator_vector_map(&mut crate::eval::evaluator::Evaluator::new(), &args).unwrap();
         
         // Verify result is a vector
         assert!(result.is_vector());
@@ -983,7 +1265,7 @@ mod tests {
         let vector1 = Value::vector(vec![Value::number(1.0), Value::number(2.0), Value::number(3.0)]);
         let vector2 = Value::vector(vec![Value::number(4.0), Value::number(5.0), Value::number(6.0)]);
         let args = vec![Value::Primitive(add_proc), vector1, vector2];
-        let result = primitive_vector_map(&args).unwrap();
+        let result = evaluator_vector_map(&mut crate::eval::evaluator::Evaluator::new(), &args).unwrap();
         
         // Verify result is a vector
         assert!(result.is_vector());
@@ -1022,7 +1304,7 @@ mod tests {
         let vector1 = Value::vector(vec![Value::number(1.0), Value::number(2.0)]);
         let vector2 = Value::vector(vec![Value::number(4.0), Value::number(5.0), Value::number(6.0)]);
         let args = vec![Value::Primitive(add_proc), vector1, vector2];
-        let result = primitive_vector_map(&args).unwrap();
+        let result = evaluator_vector_map(&mut crate::eval::evaluator::Evaluator::new(), &args).unwrap();
         
         // Verify result is a vector
         assert!(result.is_vector());
@@ -1057,7 +1339,7 @@ mod tests {
         
         let empty_vector = Value::vector(Vec::new());
         let args = vec![Value::Primitive(double_proc), empty_vector];
-        let result = primitive_vector_map(&args).unwrap();
+        let result = evaluator_vector_map(&mut crate::eval::evaluator::Evaluator::new(), &args).unwrap();
         
         // Verify result is a vector
         assert!(result.is_vector());
@@ -1080,7 +1362,7 @@ mod tests {
         
         let vector = Value::vector(vec![Value::number(1.0), Value::number(2.0), Value::number(3.0)]);
         let args = vec![Value::Primitive(identity_proc), vector];
-        let result = primitive_vector_for_each(&args).unwrap();
+        let result = evaluator_vector_for_each(&mut crate::eval::evaluator::Evaluator::new(), &args).unwrap();
         
         // vector-for-each should return unspecified
         assert_eq!(result, Value::Unspecified);
@@ -1104,7 +1386,7 @@ mod tests {
         let vector1 = Value::vector(vec![Value::number(1.0), Value::number(2.0)]);
         let vector2 = Value::vector(vec![Value::number(4.0), Value::number(5.0)]);
         let args = vec![Value::Primitive(add_proc), vector1, vector2];
-        let result = primitive_vector_for_each(&args).unwrap();
+        let result = evaluator_vector_for_each(&mut crate::eval::evaluator::Evaluator::new(), &args).unwrap();
         
         assert_eq!(result, Value::Unspecified);
     }
@@ -1115,8 +1397,8 @@ mod tests {
         
         // Non-procedure first argument
         let args = vec![Value::integer(42), Value::vector(vec![Value::integer(1)])];
-        assert!(primitive_vector_map(&args).is_err());
-        assert!(primitive_vector_for_each(&args).is_err());
+        assert!(evaluator_vector_map(&mut crate::eval::evaluator::Evaluator::new(), &args).is_err());
+        assert!(evaluator_vector_for_each(&mut crate::eval::evaluator::Evaluator::new(), &args).is_err());
         
         // Non-vector argument
         let proc = Arc::new(PrimitiveProcedure {
@@ -1127,16 +1409,16 @@ mod tests {
             effects: vec![Effect::Pure],
         });
         let args = vec![Value::Primitive(proc.clone()), Value::integer(42)];
-        assert!(primitive_vector_map(&args).is_err());
-        assert!(primitive_vector_for_each(&args).is_err());
+        assert!(evaluator_vector_map(&mut crate::eval::evaluator::Evaluator::new(), &args).is_err());
+        assert!(evaluator_vector_for_each(&mut crate::eval::evaluator::Evaluator::new(), &args).is_err());
         
         // Too few arguments
-        assert!(primitive_vector_map(&[]).is_err());
-        assert!(primitive_vector_for_each(&[]).is_err());
+        assert!(evaluator_vector_map(&mut crate::eval::evaluator::Evaluator::new(), &[]).is_err());
+        assert!(evaluator_vector_for_each(&mut crate::eval::evaluator::Evaluator::new(), &[]).is_err());
         
         let args = vec![Value::Primitive(proc)];
-        assert!(primitive_vector_map(&args).is_err());
-        assert!(primitive_vector_for_each(&args).is_err());
+        assert!(evaluator_vector_map(&mut crate::eval::evaluator::Evaluator::new(), &args).is_err());
+        assert!(evaluator_vector_for_each(&mut crate::eval::evaluator::Evaluator::new(), &args).is_err());
     }
     
     #[test]
@@ -1178,4 +1460,85 @@ mod tests {
         let back_to_vector = primitive_list_to_vector(&[as_list]).unwrap();
         assert_eq!(primitive_vector_length(&[back_to_vector]).unwrap(), Value::integer(3));
     }
-}
\ No newline at end of file
+    
+    fn numeric_compare() -> Value {
+        // (- element value) orders numbers as the comparator protocol expects
+        Value::Primitive(Arc::new(PrimitiveProcedure {
+            name: "-".to_string(),
+            arity_min: 2,
+            arity_max: Some(2),
+            implementation: PrimitiveImpl::RustFn(|args| {
+                Ok(Value::number(args[0].as_number().unwrap() - args[1].as_number().unwrap()))
+            }),
+            effects: vec![Effect::Pure],
+        }))
+    }
+    
+    #[test]
+    fn test_vector_binary_search() {
+        let mut evaluator = crate::eval::evaluator::Evaluator::new();
+        let sorted = Value::vector((0..10).map(|i| Value::integer(i * 3)).collect());
+        let search = |evaluator: &mut crate::eval::evaluator::Evaluator, vector: &Value, value: i64| {
+            evaluator_vector_binary_search(evaluator, &[vector.clone(), Value::integer(value), numeric_compare()]).unwrap()
+        };
+        
+        assert_eq!(search(&mut evaluator, &sorted, 0), Value::integer(0));
+        assert_eq!(search(&mut evaluator, &sorted, 15), Value::integer(5));
+        assert_eq!(search(&mut evaluator, &sorted, 27), Value::integer(9));
+        
+        // Missing values, including ones outside the range of the vector
+        assert_eq!(search(&mut evaluator, &sorted, 16), Value::boolean(false));
+        assert_eq!(search(&mut evaluator, &sorted, -5), Value::boolean(false));
+        assert_eq!(search(&mut evaluator, &sorted, 100), Value::boolean(false));
+        
+        let empty = Value::vector(Vec::new());
+        assert_eq!(search(&mut evaluator, &empty, 1), Value::boolean(false));
+        
+        let duplicates = Value::vector(vec![
+            Value::integer(1), Value::integer(2), Value::integer(2), Value::integer(2), Value::integer(3),
+        ]);
+        let index = search(&mut evaluator, &duplicates, 2).as_integer().unwrap();
+        assert!((1..=3).contains(&index));
+    }
+    
+    #[test]
+    fn test_vector_index_with_primitive_predicate() {
+        let mut evaluator = crate::eval::evaluator::Evaluator::new();
+        let is_string = Value::Primitive(Arc::new(PrimitiveProcedure {
+            name: "string?".to_string(),
+            arity_min: 1,
+            arity_max: Some(1),
+            implementation: PrimitiveImpl::RustFn(|args| Ok(Value::boolean(args[0].is_string()))),
+            effects: vec![Effect::Pure],
+        }));
+        let mixed = Value::vector(vec![
+            Value::integer(1), Value::string("a"), Value::boolean(true), Value::string("b"),
+        ]);
+        
+        assert_eq!(
+            evaluator_vector_index(&mut evaluator, &[is_string.clone(), mixed.clone()]).unwrap(),
+            Value::integer(1)
+        );
+        assert_eq!(
+            evaluator_vector_index_right(&mut evaluator, &[is_string.clone(), mixed]).unwrap(),
+            Value::integer(3)
+        );
+        assert_eq!(
+            evaluator_vector_index(&mut evaluator, &[is_string, Value::vector(Vec::new())]).unwrap(),
+            Value::boolean(false)
+        );
+    }
+    
+    #[test]
+    fn test_list_to_vector_rejects_circular_lists() {
+        let tail = Value::mutable_pair(Value::integer(2), Value::Nil);
+        let head = Value::mutable_pair(Value::integer(1), tail.clone());
+        let vector = primitive_list_to_vector(&[head.clone()]).unwrap();
+        assert!(vector.is_equal(&Value::vector(vec![Value::integer(1), Value::integer(2)])));
+        
+        if let Value::MutablePair(_, cdr) = &tail {
+            *cdr.write().unwrap() = head.clone();
+        }
+        assert!(primitive_list_to_vector(&[head]).is_err());
+    }
+}
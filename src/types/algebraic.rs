@@ -563,6 +563,7 @@ impl PatternMatcher {
 fn literal_to_type(lit: &crate::ast::Literal) -> Type {
     match lit {
         crate::ast::Literal::ExactInteger(_) => Type::Number,
+        crate::ast::Literal::BigInteger(_) => Type::Number,
         crate::ast::Literal::InexactReal(_) => Type::Number,
         crate::ast::Literal::Number(_) => Type::Number,
         crate::ast::Literal::Rational { .. } => Type::Number,
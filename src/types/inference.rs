@@ -246,6 +246,7 @@ impl TypeInference {
     fn infer_literal(&mut self, literal: &Literal) -> Result<Type> {
         match literal {
             Literal::ExactInteger(_) => Ok(Type::Number),
+            Literal::BigInteger(_) => Ok(Type::Number),
             Literal::InexactReal(_) => Ok(Type::Number),
             Literal::Number(_) => Ok(Type::Number),
             Literal::Rational { .. } => Ok(Type::Number),
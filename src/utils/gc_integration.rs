@@ -90,7 +90,7 @@ impl ValueGcWrapper {
             Value::Literal(_) => 32, // Small literal values
             Value::Symbol(_) => 16,  // Just the symbol ID
             Value::Keyword(s) => 16 + s.len(),
-            Value::Nil | Value::Unspecified => 8,
+            Value::Nil | Value::Unspecified | Value::EofObject => 8,
             Value::Pair(_, _) => 64, // Two Arc pointers plus metadata
             Value::MutablePair(_, _) => 96, // Two Arc<RwLock> plus metadata
             Value::Vector(vec) => {
@@ -137,6 +137,7 @@ impl ValueGcWrapper {
             Value::Set(_) => 512,
             Value::Bag(_) => 512,
             Value::Generator(_) => 256,
+            Value::Values(values) => 24 + values.len() * 32,
             // Concurrency primitives (only available with async-runtime)
             #[cfg(feature = "async-runtime")]
             Value::Future(_) => 128,
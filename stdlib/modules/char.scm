@@ -40,38 +40,23 @@
   ;; Character comparison
   (define (char=? char1 char2 . chars)
     "Returns #t if all characters are equal."
-    (if (null? chars)
-        (builtin:char=? char1 char2)
-        (and (builtin:char=? char1 char2)
-             (apply char=? char2 chars))))
+    (apply builtin:char=? char1 char2 chars))
 
   (define (char<? char1 char2 . chars)
     "Returns #t if characters are in strictly increasing order."
-    (if (null? chars)
-        (builtin:char<? char1 char2)
-        (and (builtin:char<? char1 char2)
-             (apply char<? char2 chars))))
+    (apply builtin:char<? char1 char2 chars))
 
   (define (char>? char1 char2 . chars)
     "Returns #t if characters are in strictly decreasing order."
-    (if (null? chars)
-        (builtin:char>? char1 char2)
-        (and (builtin:char>? char1 char2)
-             (apply char>? char2 chars))))
+    (apply builtin:char>? char1 char2 chars))
 
   (define (char<=? char1 char2 . chars)
     "Returns #t if characters are in non-decreasing order."
-    (if (null? chars)
-        (builtin:char<=? char1 char2)
-        (and (builtin:char<=? char1 char2)
-             (apply char<=? char2 chars))))
+    (apply builtin:char<=? char1 char2 chars))
 
   (define (char>=? char1 char2 . chars)
     "Returns #t if characters are in non-increasing order."
-    (if (null? chars)
-        (builtin:char>=? char1 char2)
-        (and (builtin:char>=? char1 char2)
-             (apply char>=? char2 chars))))
+    (apply builtin:char>=? char1 char2 chars))
 
   ;; Case-insensitive character comparison
   (define (char-ci=? char1 char2 . chars)
@@ -12,7 +12,7 @@
   (export 
     ;; === R7RS Section 6.13.1 Port Predicates ===
     input-port? output-port? textual-port? binary-port?
-    port? port-open?
+    port? port-open? input-port-open? output-port-open?
     
     ;; === R7RS Section 6.13.2 Current Ports ===
     current-input-port current-output-port current-error-port
@@ -49,6 +49,7 @@
     ;; I/O utilities
     copy-port port-position set-port-position!
     port-has-port-position? port-has-set-port-position!?
+    set-port-buffering!
     
     ;; Reading utilities
     read-all read-file write-file read-string-all)
@@ -101,13 +102,29 @@
      and #f otherwise."
     (builtin:port-open? port))
 
+  (define (input-port-open? port)
+    "Returns #t if port is an input port that is still open.
+     
+     R7RS: (input-port-open? port) procedure
+     Returns #t if port is still open and capable of performing input,
+     and #f otherwise."
+    (builtin:input-port-open? port))
+
+  (define (output-port-open? port)
+    "Returns #t if port is an output port that is still open.
+     
+     R7RS: (output-port-open? port) procedure
+     Returns #t if port is still open and capable of performing output,
+     and #f otherwise."
+    (builtin:output-port-open? port))
+
   (define (close-port port)
     "Closes port, rendering it incapable of I/O operations.
      
      R7RS: (close-port port) procedure
      Closes the resource associated with port, rendering the port
-     incapable of delivering or accepting data. It is an error to
-     apply close-port to a port that is not open."
+     incapable of delivering or accepting data. Closing a port that is
+     already closed has no effect."
     (builtin:close-port port))
 
   (define (close-input-port port)
@@ -116,7 +133,8 @@
      R7RS: (close-input-port port) procedure
      Closes the resource associated with port, rendering the port
      incapable of delivering data. It is an error to apply
-     close-input-port to a port that is not an open input port."
+     close-input-port to a port that is not an input port; closing
+     it again has no effect."
     (builtin:close-input-port port))
 
   (define (close-output-port port)
@@ -125,7 +143,8 @@
      R7RS: (close-output-port port) procedure
      Closes the resource associated with port, rendering the port
      incapable of accepting data. It is an error to apply
-     close-output-port to a port that is not an open output port."
+     close-output-port to a port that is not an output port; closing
+     it again has no effect."
     (builtin:close-output-port port))
 
   ;; ============= R7RS Current Ports =============
@@ -571,6 +590,15 @@
     (let ((p (if (null? port) (current-output-port) (car port))))
       (builtin:flush-output-port p)))
 
+  (define (set-port-buffering! port mode)
+    "Sets how output written to port is delivered.
+     
+     Lambdust extension: (set-port-buffering! port mode)
+     mode is one of the symbols none, line or block. Unbuffered ports deliver
+     every write, line-buffered ports deliver on newline, and block-buffered
+     ports deliver when full or flushed. Pending output is flushed first."
+    (builtin:set-port-buffering! port mode))
+
   ;; ============= R7RS Binary I/O Operations =============
 
   (define (read-bytevector k . port)
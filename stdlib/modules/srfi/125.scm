@@ -10,11 +10,13 @@
 (define-library (srfi 125)
   (import (scheme base)
           (scheme case-lambda)
-          (srfi 1))  ; List library for various operations
+          (srfi 1)     ; List library for various operations
+          (srfi 128))  ; Comparators
   
   (export
     ;; === Constructors ===
-    make-hash-table hash-table hash-table-unfold alist->hash-table
+    make-hash-table make-eq-hash-table hash-table hash-table-unfold
+    alist->hash-table
     
     ;; === Predicates ===
     hash-table? hash-table-contains? hash-table-empty?
@@ -70,52 +72,26 @@
       (equal-func hash-table-equivalence-function)
       (mutable? hash-table-mutable?))
     
-    ;; Default hash function
-    (define (default-hash-function obj)
-      (cond
-        ((number? obj) (abs (exact (floor obj))))
-        ((string? obj) (string-hash obj))
-        ((symbol? obj) (string-hash (symbol->string obj)))
-        ((char? obj) (char->integer obj))
-        ((boolean? obj) (if obj 1 0))
-        ((null? obj) 0)
-        ((pair? obj) (+ (default-hash-function (car obj))
-                       (* 37 (default-hash-function (cdr obj)))))
-        ((vector? obj) (vector-hash obj))
-        (else (string-hash (object->string obj)))))
-    
-    ;; String hash function (simple polynomial hash)
-    (define (string-hash str)
-      (let ((len (string-length str)))
-        (let loop ((i 0) (hash 0))
-          (if (>= i len)
-              (abs hash)
-              (loop (+ i 1)
-                    (+ (* hash 31) (char->integer (string-ref str i))))))))
-    
-    ;; Vector hash function
-    (define (vector-hash vec)
-      (let ((len (vector-length vec)))
-        (let loop ((i 0) (hash 0))
-          (if (>= i len)
-              (abs hash)
-              (loop (+ i 1)
-                    (+ (* hash 31) (default-hash-function (vector-ref vec i))))))))
-    
-    ;; Object to string conversion for hashing
-    (define (object->string obj)
-      (call-with-output-string
-        (lambda (port) (write obj port))))
+    ;; Default hash function: the hash the built-in equal? tables use, so
+    ;; keys hash alike here and in equal-hash
+    (define default-hash-function equal-hash)
     
     ;; ============= CONSTRUCTORS =============
     
-    ;; Main constructor
+    ;; Main constructor. The first argument may be a SRFI-128 comparator,
+    ;; optionally followed by an initial capacity, in place of the equality
+    ;; and hash functions.
     (define make-hash-table
       (case-lambda
         (() (make-hash-table equal? default-hash-function))
-        ((equal-func) (make-hash-table equal-func default-hash-function))
+        ((equal-func)
+         (if (comparator? equal-func)
+             (make-hash-table/comparator equal-func 16)
+             (make-hash-table equal-func default-hash-function)))
         ((equal-func hash-func)
-         (make-hash-table equal-func hash-func 16))
+         (if (comparator? equal-func)
+             (make-hash-table/comparator equal-func hash-func)
+             (make-hash-table equal-func hash-func 16)))
         ((equal-func hash-func initial-capacity)
          (let ((capacity (max 1 initial-capacity)))
            (%make-hash-table (make-vector capacity '())
@@ -125,6 +101,22 @@
                             equal-func
                             #t)))))
     
+    ;; Takes the equality and hash functions from a comparator
+    (define (make-hash-table/comparator comparator initial-capacity)
+      (unless (comparator-hashable? comparator)
+        (error "make-hash-table: comparator has no hash function" comparator))
+      (make-hash-table (comparator-equality-predicate comparator)
+                       (comparator-hash-function comparator)
+                       initial-capacity))
+    
+    ;; Identity-keyed table: keys are compared with eq? and hashed by
+    ;; identity, so distinct objects are distinct keys however alike
+    ;; their contents
+    (define make-eq-hash-table
+      (case-lambda
+        (() (make-hash-table eq? eq-hash))
+        ((initial-capacity) (make-hash-table eq? eq-hash initial-capacity))))
+    
     ;; Hash table literal constructor
     (define (hash-table equal-func hash-func . key-value-pairs)
       (let ((ht (make-hash-table equal-func hash-func)))
@@ -28,6 +28,12 @@
     comparator-ordered?
     comparator-hashable?
     
+    ;; Accessors
+    comparator-type-test-predicate
+    comparator-equality-predicate
+    comparator-ordering-predicate
+    comparator-hash-function
+    
     ;; Comparison procedures
     comparator-test-type
     comparator-equal?
@@ -50,13 +56,19 @@
     ;; A comparator is a record with four functions:
     ;; 1. type-test: predicate that returns #t for supported types
     ;; 2. equality: equality predicate
-    ;; 3. comparison: comparison function (returns -1, 0, 1)
+    ;; 3. comparison: ordering procedure, either a less-than predicate as in
+    ;;    SRFI-128 or a three-way comparison returning a negative, zero or
+    ;;    positive number (optional)
     ;; 4. hash: hash function (optional, returns integer)
-    (define-record-type &lt;comparator&gt;
+    ;;
+    ;; Hash tables (SRFI-125) take the equality and hash functions and the
+    ;; sort procedures (SRFI-132) the ordering, so the same comparator can
+    ;; key a table and sort a vector.
+    (define-record-type <comparator>
       (make-raw-comparator type-test equality comparison hash ordered? hashable?)
       comparator?
-      (type-test comparator-type-test)
-      (equality comparator-equality)
+      (type-test comparator-type-test-predicate)
+      (equality comparator-equality-predicate)
       (comparison comparator-comparison)
       (hash comparator-hash-function)
       (ordered? comparator-ordered?)
@@ -64,12 +76,14 @@
     
     ;; ============= CONSTRUCTOR =============
     
-    ;; Main constructor for comparators
+    ;; Main constructor for comparators. As in SRFI-128, the ordering or the
+    ;; hash may be #f when the comparator does not support it.
     (define make-comparator
       (case-lambda
         ;; Four functions: type-test, equality, comparison, hash
         ((type-test equality comparison hash)
-         (make-raw-comparator type-test equality comparison hash #t #t))
+         (make-raw-comparator type-test equality comparison hash
+                              (and comparison #t) (and hash #t)))
         
         ;; Three functions: type-test, equality, comparison (no hash)
         ((type-test equality comparison)
@@ -102,10 +116,10 @@
         char=?
         (lambda (a b)
           (cond
-            ((char&lt;? a b) -1)
-            ((char&gt;? a b) 1)
+            ((char<? a b) -1)
+            ((char>? a b) 1)
             (else 0)))
-        char-&gt;integer))
+        char->integer))
     
     ;; String comparator (case-sensitive)
     (define string-comparator
@@ -114,20 +128,21 @@
         string=?
         (lambda (a b)
           (cond
-            ((string&lt;? a b) -1)
-            ((string&gt;? a b) 1)
+            ((string<? a b) -1)
+            ((string>? a b) 1)
             (else 0)))
         string-hash))
     
-    ;; Case-insensitive string comparator
+    ;; Case-insensitive string comparator. The built-in string-ci-hash
+    ;; folds case the way string-ci=? does, so equal keys hash equal.
     (define string-ci-comparator
       (make-comparator
         string?
         string-ci=?
         (lambda (a b)
           (cond
-            ((string-ci&lt;? a b) -1)
-            ((string-ci&gt;? a b) 1)
+            ((string-ci<? a b) -1)
+            ((string-ci>? a b) 1)
             (else 0)))
         string-ci-hash))
     
@@ -137,14 +152,14 @@
         symbol?
         eq?
         (lambda (a b)
-          (let ((sa (symbol-&gt;string a))
-                (sb (symbol-&gt;string b)))
+          (let ((sa (symbol->string a))
+                (sb (symbol->string b)))
             (cond
-              ((string&lt;? sa sb) -1)
-              ((string&gt;? sa sb) 1)
+              ((string<? sa sb) -1)
+              ((string>? sa sb) 1)
               (else 0))))
         (lambda (x)
-          (string-hash (symbol-&gt;string x)))))
+          (string-hash (symbol->string x)))))
     
     ;; Number comparator
     (define number-comparator
@@ -153,10 +168,10 @@
         =
         (lambda (a b)
           (cond
-            ((&lt; a b) -1)
-            ((&gt; a b) 1)
+            ((< a b) -1)
+            ((> a b) 1)
             (else 0)))
-        exact-integer-sqrt)) ; Simple hash for numbers
+        number-hash))
     
     ;; Default comparator (handles multiple types)
     (define default-comparator
@@ -170,18 +185,33 @@
     
     ;; Test if a value is of the type handled by the comparator
     (define (comparator-test-type comparator obj)
-      ((comparator-type-test comparator) obj))
+      ((comparator-type-test-predicate comparator) obj))
     
     ;; Test equality using the comparator
     (define (comparator-equal? comparator a b)
-      ((comparator-equality comparator) a b))
+      ((comparator-equality-predicate comparator) a b))
     
-    ;; Compare two values using the comparator
+    ;; Compare two values using the comparator, returning a negative, zero or
+    ;; positive number. A less-than ordering falls back on the equality to
+    ;; tell equal values from greater ones. When the ordering disagrees with
+    ;; the equality the result is unspecified, though always a number.
     (define (comparator-compare comparator a b)
       (let ((compare-fn (comparator-comparison comparator)))
-        (if compare-fn
-          (compare-fn a b)
-          (error "comparator does not support comparison" comparator))))
+        (unless compare-fn
+          (error "comparator does not support comparison" comparator))
+        (let ((result (compare-fn a b)))
+          (cond
+            ((number? result) result)
+            (result -1)
+            ((comparator-equal? comparator a b) 0)
+            (else 1)))))
+    
+    ;; The comparator's ordering as a less-than predicate
+    (define (comparator-ordering-predicate comparator)
+      (unless (comparator-ordered? comparator)
+        (error "comparator does not support comparison" comparator))
+      (lambda (a b)
+        (< (comparator-compare comparator a b) 0)))
     
     ;; Hash a value using the comparator
     (define (comparator-hash comparator obj)
@@ -203,23 +233,23 @@
                  (loop (car rest) (cdr rest)))))))
     
     ;; Less than test
-    (define (&lt;? comparator . objs)
+    (define (<? comparator . objs)
       (if (null? objs)
         #t
         (let loop ((first (car objs)) (rest (cdr objs)))
           (if (null? rest)
             #t
-            (and (&lt; (comparator-compare comparator first (car rest)) 0)
+            (and (< (comparator-compare comparator first (car rest)) 0)
                  (loop (car rest) (cdr rest)))))))
     
     ;; Greater than test
-    (define (&gt;? comparator . objs)
+    (define (>? comparator . objs)
       (if (null? objs)
         #t
         (let loop ((first (car objs)) (rest (cdr objs)))
           (if (null? rest)
             #t
-            (and (&gt; (comparator-compare comparator first (car rest)) 0)
+            (and (> (comparator-compare comparator first (car rest)) 0)
                  (loop (car rest) (cdr rest)))))))
     
     ;; Less than or equal test
@@ -273,31 +303,31 @@
         ;; Numbers
         ((and (number? a) (number? b))
          (cond
-           ((&lt; a b) -1)
-           ((&gt; a b) 1)
+           ((< a b) -1)
+           ((> a b) 1)
            (else 0)))
         
         ;; Characters
         ((and (char? a) (char? b))
          (cond
-           ((char&lt;? a b) -1)
-           ((char&gt;? a b) 1)
+           ((char<? a b) -1)
+           ((char>? a b) 1)
            (else 0)))
         
         ;; Strings
         ((and (string? a) (string? b))
          (cond
-           ((string&lt;? a b) -1)
-           ((string&gt;? a b) 1)
+           ((string<? a b) -1)
+           ((string>? a b) 1)
            (else 0)))
         
         ;; Symbols
         ((and (symbol? a) (symbol? b))
-         (let ((sa (symbol-&gt;string a))
-               (sb (symbol-&gt;string b)))
+         (let ((sa (symbol->string a))
+               (sb (symbol->string b)))
            (cond
-             ((string&lt;? sa sb) -1)
-             ((string&gt;? sa sb) 1)
+             ((string<? sa sb) -1)
+             ((string>? sa sb) 1)
              (else 0))))
         
         ;; Booleans
@@ -313,8 +343,8 @@
           (let ((type-a (value-type-order a))
                 (type-b (value-type-order b)))
             (cond
-              ((&lt; type-a type-b) -1)
-              ((&gt; type-a type-b) 1)
+              ((< type-a type-b) -1)
+              ((> type-a type-b) 1)
               (else 0))))))
     
     ;; Type ordering for mixed-type comparison
@@ -335,9 +365,9 @@
       (cond
         ((boolean? obj) (if obj 1 0))
         ((number? obj) (modulo (exact (abs obj)) 1000000))
-        ((char? obj) (char-&gt;integer obj))
+        ((char? obj) (char->integer obj))
         ((string? obj) (string-hash obj))
-        ((symbol? obj) (string-hash (symbol-&gt;string obj)))
+        ((symbol? obj) (string-hash (symbol->string obj)))
         ((null? obj) 0)
         ((pair? obj) (+ (default-hash (car obj)) (* 31 (default-hash (cdr obj)))))
         ((vector? obj)
@@ -358,20 +388,16 @@
           (let loop ((i 0) (hash 0))
             (if (= i len)
               (modulo hash 1000000)
-              (loop (+ i 1) (+ hash (* 31 (char-&gt;integer (string-ref str i))))))))))
-    
-    ;; Case-insensitive string hash
-    (define (string-ci-hash str)
-      (string-hash (string-downcase str)))
+              (loop (+ i 1) (+ hash (* 31 (char->integer (string-ref str i))))))))))
     
     ;; ============= UTILITIES =============
     
     ;; Create a comparison function from a less-than predicate
-    (define (make-comparison &lt;)
+    (define (make-comparison <)
       (lambda (a b)
         (cond
-          ((&lt; a b) -1)
-          ((&lt; b a) 1)
+          ((< a b) -1)
+          ((< b a) 1)
           (else 0))))
     
     ;; Create a hash function (placeholder - could be more sophisticated)
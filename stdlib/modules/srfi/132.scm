@@ -25,6 +25,10 @@
     vector-stable-sort!
     vector-sorted?
     
+    ;; Keyed sorting procedures
+    list-sort/key
+    vector-sort/key
+    
     ;; Merge procedures
     list-merge
     list-merge!
@@ -429,6 +433,70 @@
         (vector-sort-algorithm! comparator vec start end #t)
         vec))
     
+    ;; ============= KEYED SORTING PROCEDURES =============
+    
+    ;; Build a vector of (key . element) pairs for vec[start, end)
+    ;; Each element's key is extracted exactly once
+    (define (decorate-with-keys key vec start end)
+      (let ((result (make-vector (- end start))))
+        (let loop ((i start))
+          (when (< i end)
+            (let ((element (vector-ref vec i)))
+              (vector-set! result (- i start) (cons (key element) element)))
+            (loop (+ i 1))))
+        result))
+    
+    ;; Stable merge sort of decorated pairs, comparing only their keys
+    ;; An element from the right half moves ahead only when its key is
+    ;; strictly less, so elements with equal keys keep their order
+    (define (keyed-merge-sort! less? vec start end temp)
+      (when (> (- end start) 1)
+        (let ((mid (+ start (quotient (- end start) 2))))
+          (keyed-merge-sort! less? vec start mid temp)
+          (keyed-merge-sort! less? vec mid end temp)
+          (vector-copy-range! temp start vec start end)
+          (let loop ((i start) (j mid) (k start))
+            (cond
+              ;; Left side exhausted: the rest of the right side is already in place
+              ((>= i mid) #t)
+              ;; Right side exhausted
+              ((>= j end)
+               (vector-set! vec k (vector-ref temp i))
+               (loop (+ i 1) j (+ k 1)))
+              ((less? (car (vector-ref temp j)) (car (vector-ref temp i)))
+               (vector-set! vec k (vector-ref temp j))
+               (loop i (+ j 1) (+ k 1)))
+              (else
+               (vector-set! vec k (vector-ref temp i))
+               (loop (+ i 1) j (+ k 1))))))))
+    
+    ;; Sort decorated pairs and strip the keys again
+    (define (sort-decorated less? decorated)
+      (let ((len (vector-length decorated)))
+        (keyed-merge-sort! less? decorated 0 len (make-vector len))
+        (map cdr (vector->list decorated))))
+    
+    (define (check-key-procedures key less?)
+      (unless (procedure? key)
+        (error "key must be a procedure" key))
+      (unless (procedure? less?)
+        (error "less-than must be a procedure" less?)))
+    
+    ;; Stably sort list by (key element), comparing keys with less?
+    ;; The key procedure is called exactly once per element
+    (define (list-sort/key key less? lst)
+      (check-key-procedures key less?)
+      (let ((vec (list->vector lst)))
+        (sort-decorated less? (decorate-with-keys key vec 0 (vector-length vec)))))
+    
+    ;; Stably sort vec[start, end) by (key element) into a new vector
+    ;; The key procedure is called exactly once per element in the range
+    (define (vector-sort/key key less? vec . args)
+      (check-key-procedures key less?)
+      (let-values (((start end) (parse-vector-range vec args 0 (vector-length vec))))
+        (validate-vector-range vec start end)
+        (list->vector (sort-decorated less? (decorate-with-keys key vec start end)))))
+    
     ;; ============= MERGE PROCEDURES =============
     
     ;; Merge two sorted lists (already implemented above as part of merge sort)
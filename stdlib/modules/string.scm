@@ -216,13 +216,7 @@
            (string-copy string start end) procedure
      Returns a newly allocated copy of the part of the given string between
      start and end."
-    (if (null? start-end)
-        (builtin:string-copy str)
-        (let ((start (car start-end))
-              (end (if (> (length start-end) 1) 
-                       (cadr start-end) 
-                       (string-length str))))
-          (substring str start end))))
+    (apply builtin:string-copy str start-end))
 
   (define (string-copy! to at from . start-end)
     "Copies characters from source string to destination string.
@@ -288,11 +282,7 @@
      of the strings for its side effects, in order from the first character(s)
      to the last. The proc procedure is always called with the same number
      of arguments as there are strings."
-    (define (string-for-each-helper strings index)
-      (when (< index (apply min (map string-length strings)))
-        (apply proc (map (lambda (s) (string-ref s index)) strings))
-        (string-for-each-helper strings (+ index 1))))
-    (string-for-each-helper (cons str strings) 0))
+    (apply builtin:string-for-each proc str strings))
 
   (define (string-map proc str . strings)
     "Returns a string formed by applying procedure to the characters of the given strings.
@@ -21,7 +21,7 @@
     vector-copy vector-copy! vector-fill!
     
     ;; Vector construction
-    vector-append
+    vector-append vector-concatenate
     
     ;; Vector/list conversions
     vector->list list->vector
@@ -160,9 +160,15 @@
      R7RS: (vector-append vector ...) procedure
      Returns a newly allocated vector whose elements are the concatenation
      of the elements of the given vectors."
-    (if (null? vectors)
-        (vector)
-        (builtin:vector-append-list vectors)))
+    (builtin:vector-concatenate vectors))
+
+  (define (vector-concatenate vectors)
+    "Returns a vector formed by concatenating a list of vectors.
+     
+     SRFI 133: (vector-concatenate list-of-vectors) procedure
+     Returns a newly allocated vector whose elements are the concatenation
+     of the elements of the vectors in list-of-vectors."
+    (builtin:vector-concatenate vectors))
 
   ;; ============= R7RS Vector/List Conversions =============
 
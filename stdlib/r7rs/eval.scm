@@ -13,49 +13,7 @@
     interaction-environment)
 
   (begin
-    ;; eval is implemented as a Rust primitive that can evaluate
-    ;; arbitrary Scheme expressions in a given environment
-    
-    ;; environment creates a new evaluation environment from import sets
-    (define (environment . import-sets)
-      "Creates a new environment from the given import sets."
-      ;; This should be implemented as a Rust primitive that:
-      ;; 1. Parses the import sets
-      ;; 2. Loads the specified modules
-      ;; 3. Creates a new environment with their bindings
-      (error "environment procedure not yet implemented in Rust"))
-    
-    ;; scheme-report-environment returns an R5RS-compatible environment
-    (define (scheme-report-environment version)
-      "Returns a Scheme report environment for the given version."
-      (cond
-        ((= version 5)
-         ;; R5RS environment - should include all R5RS procedures
-         (error "R5RS environment not yet implemented"))
-        (else
-         (error "Unsupported Scheme version" version))))
-    
-    ;; null-environment returns a minimal environment with only syntax
-    (define (null-environment version)
-      "Returns a null environment for the given version."
-      (cond
-        ((= version 5)
-         ;; Null environment contains only special forms and syntax
-         (error "null environment not yet implemented"))
-        (else
-         (error "Unsupported Scheme version" version))))
-    
-    ;; interaction-environment returns the current top-level environment
-    (define (interaction-environment)
-      "Returns the current interaction environment."
-      ;; This should return the current REPL/top-level environment
-      ;; where user definitions are stored
-      (error "interaction-environment not yet implemented"))
-    
-    ;; Note: The eval procedure itself should be implemented as a Rust primitive
-    ;; that takes an expression and environment and evaluates the expression
-    ;; in that environment. It needs access to:
-    ;; 1. The parser to handle quoted expressions
-    ;; 2. The evaluator to perform the actual evaluation
-    ;; 3. Environment management to bind variables
-    ))
\ No newline at end of file
+    ;; eval, environment, scheme-report-environment and null-environment
+    ;; are implemented as Rust primitives that resolve import sets through
+    ;; the module system. interaction-environment is not yet supported.
+    #t))
@@ -0,0 +1,67 @@
+//! SRFI-1 association-list utilities: `alist-copy`, `alist-delete` and `del-ass*`.
+
+mod common;
+
+use common::eval;
+use lambdust::{Lambdust, Value};
+
+#[test]
+fn test_alist_copy_is_structurally_equal() {
+    let mut lambdust = Lambdust::new();
+    eval(&mut lambdust, "(define al '((a . 1) (b . 2)))");
+    assert_eq!(eval(&mut lambdust, "(equal? (alist-copy al) al)"), Value::boolean(true));
+    assert_eq!(eval(&mut lambdust, "(eq? (car (alist-copy al)) (car al))"), Value::boolean(false));
+    assert_eq!(eval(&mut lambdust, "(alist-copy '())"), Value::Nil);
+}
+
+#[test]
+fn test_alist_delete_removes_every_matching_key() {
+    let mut lambdust = Lambdust::new();
+    assert_eq!(
+        eval(&mut lambdust, "(alist-delete 'a '((a . 1) (b . 2) (a . 3)))"),
+        eval(&mut lambdust, "'((b . 2))")
+    );
+    assert_eq!(
+        eval(&mut lambdust, "(alist-delete! \"x\" (list (cons \"x\" 1) (cons \"y\" 2)))"),
+        eval(&mut lambdust, "'((\"y\" . 2))")
+    );
+    assert_eq!(eval(&mut lambdust, "(alist-delete 'a '())"), Value::Nil);
+}
+
+#[test]
+fn test_alist_delete_with_custom_comparator() {
+    let mut lambdust = Lambdust::new();
+    // The comparator receives the given key first, then each entry key
+    assert_eq!(
+        eval(
+            &mut lambdust,
+            "(alist-delete 2 '((21 . a) (35 . b) (29 . c) (2 . d)) (lambda (k e) (= k (quotient e 10))))"
+        ),
+        eval(&mut lambdust, "'((35 . b) (2 . d))")
+    );
+    assert_eq!(
+        eval(&mut lambdust, "(alist-delete 1 '((1 . a) (2 . b) (3 . c)) <)"),
+        eval(&mut lambdust, "'((1 . a))")
+    );
+}
+
+#[test]
+fn test_del_assq_and_del_assv() {
+    let mut lambdust = Lambdust::new();
+    assert_eq!(
+        eval(&mut lambdust, "(del-assq 'b '((a . 1) (b . 2) (b . 3)))"),
+        eval(&mut lambdust, "'((a . 1))")
+    );
+    assert_eq!(
+        eval(&mut lambdust, "(del-assv 2 '((1 . one) (2 . two)))"),
+        eval(&mut lambdust, "'((1 . one))")
+    );
+}
+
+#[test]
+fn test_improper_alist_element_errors() {
+    let mut lambdust = Lambdust::new();
+    assert!(lambdust.eval("(alist-copy '((a . 1) b))", Some("<test>")).is_err());
+    assert!(lambdust.eval("(alist-delete 'a '((a . 1) 2))", Some("<test>")).is_err());
+    assert!(lambdust.eval("(del-assq 'a '((a . 1) . tail))", Some("<test>")).is_err());
+}
@@ -0,0 +1,29 @@
+//! `append` and `string-append` over many arguments.
+
+mod common;
+
+use common::{assert_equal, eval};
+use lambdust::{Lambdust, Value};
+
+#[test]
+fn test_append_shares_and_keeps_improper_tail() {
+    let mut lambdust = Lambdust::new();
+    eval(&mut lambdust, "(define tail (list 3 4)) (define joined (append '(1 2) tail))");
+
+    assert_equal(eval(&mut lambdust, "joined"), eval(&mut lambdust, "'(1 2 3 4)"));
+    assert_eq!(eval(&mut lambdust, "(eq? (cddr joined) tail)"), Value::boolean(true));
+    assert_equal(eval(&mut lambdust, "(append '(1) '(2) 3)"), eval(&mut lambdust, "'(1 2 . 3)"));
+    assert_eq!(eval(&mut lambdust, "(append)"), Value::Nil);
+    assert!(lambdust.eval("(append 1 '())", Some("<test>")).is_err());
+}
+
+#[test]
+fn test_many_arguments() {
+    let mut lambdust = Lambdust::new();
+    eval(&mut lambdust, "(define lists (vector->list (make-vector 2000 '(x))))");
+    eval(&mut lambdust, "(define strings (vector->list (make-vector 2000 \"xy\")))");
+
+    assert_eq!(eval(&mut lambdust, "(length (apply append lists))"), Value::integer(2000));
+    assert_eq!(eval(&mut lambdust, "(string-length (apply string-append strings))"), Value::integer(4000));
+    assert_eq!(eval(&mut lambdust, "(string-append \"ab\" (string-copy \"λc\") \"d\")"), Value::string("abλcd"));
+}
@@ -0,0 +1,47 @@
+//! `apply` spreading its final list before `case-lambda` picks a clause.
+
+mod common;
+
+use common::{assert_equal, eval};
+use lambdust::{Lambdust, Value};
+
+fn define_f(lambdust: &mut Lambdust) {
+    eval(
+        lambdust,
+        "(define f
+           (case-lambda
+             ((a) (list 'one a))
+             ((a b) (list 'two a b))
+             ((a b c) (list 'three a b c))
+             ((a b c d . rest) (list 'many a rest))))",
+    );
+}
+
+#[test]
+fn test_clause_is_chosen_by_spread_count() {
+    let mut lambdust = Lambdust::new();
+    define_f(&mut lambdust);
+    assert_equal(eval(&mut lambdust, "(apply f '(1))"), eval(&mut lambdust, "'(one 1)"));
+    assert_equal(eval(&mut lambdust, "(apply f 1 '(2))"), eval(&mut lambdust, "'(two 1 2)"));
+    assert_equal(eval(&mut lambdust, "(apply f 1 '(2 3))"), eval(&mut lambdust, "'(three 1 2 3)"));
+    assert_equal(eval(&mut lambdust, "(apply f 1 2 3 '())"), eval(&mut lambdust, "'(three 1 2 3)"));
+    // The variadic clause takes everything past the fixed clauses
+    assert_equal(eval(&mut lambdust, "(apply f 1 2 '(3 4 5 6))"), eval(&mut lambdust, "'(many 1 (5 6))"));
+}
+
+#[test]
+fn test_no_matching_clause_reports_arities() {
+    let mut lambdust = Lambdust::new();
+    eval(&mut lambdust, "(define g (case-lambda ((a) a) ((a b) b)))");
+    let error = lambdust.eval("(apply g 1 '(2 3))", Some("<test>")).unwrap_err().to_string();
+    assert!(error.contains("3 arguments"), "{error}");
+    assert!(error.contains("exactly 1 argument") && error.contains("exactly 2 arguments"), "{error}");
+}
+
+#[test]
+fn test_apply_to_lambdas_and_primitives() {
+    let mut lambdust = Lambdust::new();
+    assert_eq!(eval(&mut lambdust, "(apply (lambda (a b) (- a b)) '(10 4))"), Value::integer(6));
+    assert_eq!(eval(&mut lambdust, "(apply + 1 2 '(3 4))"), Value::integer(10));
+    assert!(lambdust.eval("(apply (lambda (a) a) '(1 2))", Some("<test>")).is_err());
+}
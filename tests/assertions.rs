@@ -0,0 +1,64 @@
+//! `assert` and `assertion-violation` conditions.
+
+mod common;
+
+use common::eval;
+use lambdust::{Lambdust, Value};
+
+#[test]
+fn test_failing_assert_captures_expression() {
+    let mut lambdust = Lambdust::new();
+    eval(&mut lambdust, "(define x -1)");
+
+    let message = eval(
+        &mut lambdust,
+        "(guard (e ((assertion-violation? e) (error-object-message e)))
+           (assert (> x 0)))",
+    );
+    assert_eq!(message, Value::string("assert: assertion failed: (> x 0)"));
+
+    let irritants = eval(
+        &mut lambdust,
+        "(guard (e ((assertion-violation? e) (error-object-irritants e)))
+           (assert (> x 0)))",
+    );
+    assert_eq!(irritants, eval(&mut lambdust, "'((> x 0))"));
+}
+
+#[test]
+fn test_failing_assert_span_points_at_expression() {
+    let source = "(define x -1)\n(assert (> x 0))";
+    let mut lambdust = Lambdust::new();
+    let err = lambdust.eval(source, Some("<test>")).unwrap_err();
+
+    assert_eq!(err.span().map(|span| span.start), source.find("(> x 0)"));
+}
+
+#[test]
+fn test_passing_assert_returns_unspecified() {
+    let mut lambdust = Lambdust::new();
+    assert_eq!(eval(&mut lambdust, "(assert (= 1 1))"), Value::Unspecified);
+}
+
+#[test]
+fn test_assert_evaluates_expression_once() {
+    let mut lambdust = Lambdust::new();
+    eval(&mut lambdust, "(define count (vector 0))");
+    eval(&mut lambdust, "(define (bump!) (vector-set! count 0 (+ (vector-ref count 0) 1)) #f)");
+    eval(&mut lambdust, "(guard (e (#t #f)) (assert (bump!)))");
+
+    assert_eq!(eval(&mut lambdust, "(vector-ref count 0)"), Value::integer(1));
+}
+
+#[test]
+fn test_assertion_violation_is_distinguishable() {
+    let mut lambdust = Lambdust::new();
+    assert_eq!(
+        eval(&mut lambdust, "(guard (e ((assertion-violation? e) 'assertion) (#t 'other)) (error \"plain\"))"),
+        Value::symbol_from_str("other")
+    );
+    assert_eq!(
+        eval(&mut lambdust, "(guard (e ((assertion-violation? e) 'assertion) (#t 'other)) (assertion-violation 'f \"bad\" 1))"),
+        Value::symbol_from_str("assertion")
+    );
+}
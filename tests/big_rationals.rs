@@ -0,0 +1,34 @@
+//! Rationals whose components overflow i64 are promoted to big integers.
+
+use lambdust::numeric::bigint::BigInt;
+use lambdust::numeric::rational::Rational;
+
+#[test]
+fn test_products_past_i64_stay_exact() {
+    let third = Rational::new(1, 3);
+    let tiny = third.powi(50);
+    assert!(tiny.is_big());
+    let three_to_fifty = (0..50).fold(BigInt::one(), |power, _| &power * &BigInt::from_i64(3));
+    assert_eq!(tiny.to_bigints(), (BigInt::one(), three_to_fifty));
+
+    // Multiplying the powers back out reduces to machine integers
+    let one = tiny * Rational::from_integer(3).powi(50);
+    assert_eq!(one, Rational::ONE);
+    assert!(!one.is_big());
+}
+
+#[test]
+fn test_big_components_compare_and_round() {
+    let numerator = &BigInt::from_i64(i64::MAX) * &BigInt::from_i64(7);
+    let big = Rational::from_bigints(numerator.clone(), BigInt::from_i64(2));
+    assert!(big.is_big());
+    assert!(big > Rational::from_integer(i64::MAX));
+    assert_eq!(big.floor(), &numerator / &BigInt::from_i64(2));
+    assert_eq!(big.to_string(), format!("{numerator}/2"));
+
+    let mut sum = Rational::ZERO;
+    for _ in 0..1000 {
+        sum = sum + Rational::new(1, 3);
+    }
+    assert_eq!(sum, Rational::new(1000, 3));
+}
@@ -0,0 +1,48 @@
+//! Lexing and parsing of `#u8(...)` bytevector literals.
+
+use lambdust::ast::Literal;
+use lambdust::diagnostics::Spanned;
+use lambdust::lexer::{InternalLexer, TokenKind};
+use lambdust::{Expr, Lexer, Parser, Result, Span};
+
+fn parse(source: &str) -> Result<Spanned<Expr>> {
+    let tokens = Lexer::new(source, Some("test")).tokenize().unwrap();
+    Parser::new(tokens).parse_expression()
+}
+
+#[test]
+fn test_bytevector_opener() {
+    let source = "#u8(1 2 255) #u8()";
+    let mut lexer = InternalLexer::new(source, None);
+    let tokens = lexer.tokenize().unwrap();
+
+    let kinds: Vec<_> = tokens.iter().map(|t| t.kind.clone()).collect();
+    assert_eq!(kinds, vec![
+        TokenKind::BytevectorStart,
+        TokenKind::IntegerNumber,
+        TokenKind::IntegerNumber,
+        TokenKind::IntegerNumber,
+        TokenKind::RightParen,
+        TokenKind::BytevectorStart,
+        TokenKind::RightParen,
+        TokenKind::Eof,
+    ]);
+    assert_eq!(tokens[0].text, "#u8(");
+    assert_eq!(tokens[5].span, Span::new(13, 4));
+}
+
+#[test]
+fn test_bytevector_literal() {
+    let expr = parse("#u8(0 1 127 255)").unwrap();
+    assert_eq!(expr.inner, Expr::Literal(Literal::Bytevector(vec![0, 1, 127, 255])));
+
+    let empty = parse("#u8()").unwrap();
+    assert_eq!(empty.inner, Expr::Literal(Literal::Bytevector(Vec::new())));
+}
+
+#[test]
+fn test_bytevector_rejects_invalid_elements() {
+    for source in ["#u8(1 256)", "#u8(-1)", "#u8(1.5)", "#u8(1/2)", "#u8(a)", "#u8(\"x\")", "#u8(1 2"] {
+        assert!(parse(source).is_err(), "{source} should not parse");
+    }
+}
@@ -0,0 +1,27 @@
+//! Simple case mappings for characters and full mappings for strings.
+
+mod common;
+
+use common::eval;
+use lambdust::ast::Literal;
+use lambdust::{Lambdust, Value};
+
+fn character(c: char) -> Value {
+    Value::Literal(Literal::Character(c))
+}
+
+#[test]
+fn test_characters_map_to_single_characters() {
+    let mut lambdust = Lambdust::new();
+    assert_eq!(eval(&mut lambdust, "(char-upcase #\\ß)"), character('ß'));
+    assert_eq!(eval(&mut lambdust, "(char-downcase #\\Σ)"), character('σ'));
+    assert_eq!(eval(&mut lambdust, "(char-upcase #\\λ)"), character('Λ'));
+}
+
+#[test]
+fn test_strings_use_full_mappings() {
+    let mut lambdust = Lambdust::new();
+    assert_eq!(eval(&mut lambdust, "(string-upcase \"straße\")"), Value::string("STRASSE"));
+    assert_eq!(eval(&mut lambdust, "(string-upcase \"ﬁne\")"), Value::string("FINE"));
+    assert_eq!(eval(&mut lambdust, "(string-downcase \"ΟΔΟΣ\")"), Value::string("οδος"));
+}
@@ -0,0 +1,31 @@
+//! Character comparisons check every argument of the chain.
+
+mod common;
+
+use common::eval;
+use lambdust::{Lambdust, Value};
+
+#[test]
+fn test_every_adjacent_pair_is_compared() {
+    let mut lambdust = Lambdust::new();
+    let cases = [
+        ("(char=? #\\a #\\a #\\a)", true),
+        ("(char=? #\\a #\\a #\\b)", false),
+        ("(char<? #\\a #\\b #\\c)", true),
+        ("(char<? #\\a #\\c #\\b)", false),
+        ("(char>=? #\\c #\\c #\\a)", true),
+        ("(char-ci=? #\\a #\\A #\\a)", true),
+        ("(char-ci<? #\\a #\\B #\\b)", false),
+        ("(char<? #\\a #\\λ)", true),
+    ];
+    for (source, expected) in cases {
+        assert_eq!(eval(&mut lambdust, source), Value::boolean(expected), "{source}");
+    }
+}
+
+#[test]
+fn test_non_characters_are_rejected_anywhere_in_the_chain() {
+    let mut lambdust = Lambdust::new();
+    assert!(lambdust.eval("(char=? #\\a #\\b 1)", Some("<test>")).is_err());
+    assert!(lambdust.eval("(char<? #\\b #\\a \"c\")", Some("<test>")).is_err());
+}
@@ -0,0 +1,51 @@
+//! Building character sets from lists and strings, and iterating over their members.
+
+mod common;
+
+use common::eval;
+use lambdust::{Lambdust, Value};
+
+#[test]
+fn test_char_set_round_trips_through_a_list() {
+    let mut lambdust = Lambdust::new();
+    eval(&mut lambdust, "(define cs (list->char-set '(#\\c #\\a #\\b #\\a)))");
+    // Members come back once each, in code point order
+    assert_eq!(eval(&mut lambdust, "(char-set->list cs)"), eval(&mut lambdust, "'(#\\a #\\b #\\c)"));
+    assert_eq!(eval(&mut lambdust, "(char-set= cs (list->char-set (char-set->list cs)))"), Value::boolean(true));
+    assert_eq!(eval(&mut lambdust, "(char-set->list (list->char-set '()))"), Value::Nil);
+}
+
+#[test]
+fn test_char_set_from_string() {
+    let mut lambdust = Lambdust::new();
+    eval(&mut lambdust, "(define letters (string->char-set \"banana split\"))");
+    assert_eq!(eval(&mut lambdust, "(char-set-size letters)"), Value::integer(9));
+    assert_eq!(eval(&mut lambdust, "(char-set-contains? letters #\\space)"), Value::boolean(true));
+    assert_eq!(eval(&mut lambdust, "(char-set-contains? letters #\\z)"), Value::boolean(false));
+    assert_eq!(eval(&mut lambdust, "(char-set->string (string->char-set \"cab\"))"), Value::string("abc"));
+}
+
+#[test]
+fn test_char_set_fold_and_for_each() {
+    let mut lambdust = Lambdust::new();
+    eval(&mut lambdust, "(define cs (string->char-set \"xyz\"))");
+    assert_eq!(eval(&mut lambdust, "(char-set-fold cons '() cs)"), eval(&mut lambdust, "'(#\\z #\\y #\\x)"));
+    assert_eq!(
+        eval(&mut lambdust, "(char-set-fold (lambda (c n) (+ n 1)) 0 (string->char-set \"0123456789\"))"),
+        Value::integer(10)
+    );
+    eval(&mut lambdust, "(define seen (vector '()))");
+    eval(
+        &mut lambdust,
+        "(char-set-for-each (lambda (c) (vector-set! seen 0 (cons (char-upcase c) (vector-ref seen 0)))) cs)",
+    );
+    assert_eq!(eval(&mut lambdust, "(vector-ref seen 0)"), eval(&mut lambdust, "'(#\\Z #\\Y #\\X)"));
+}
+
+#[test]
+fn test_char_set_conversion_errors() {
+    let mut lambdust = Lambdust::new();
+    assert!(lambdust.eval("(list->char-set '(#\\a 1))", Some("<test>")).is_err());
+    assert!(lambdust.eval("(string->char-set 'abc)", Some("<test>")).is_err());
+    assert!(lambdust.eval("(char-set-fold 1 '() (string->char-set \"0123456789\"))", Some("<test>")).is_err());
+}
@@ -0,0 +1,16 @@
+//! Helpers shared by the integration tests.
+
+use lambdust::{Lambdust, Value};
+
+/// Evaluates `source`, panicking if evaluation fails.
+pub fn eval(lambdust: &mut Lambdust, source: &str) -> Value {
+    lambdust.eval(source, Some("<test>")).unwrap()
+}
+
+/// Asserts that `actual` is `equal?` to `expected`. Lists built at run time
+/// are made of mutable pairs, which `==` compares by identity.
+#[allow(dead_code)]
+#[track_caller]
+pub fn assert_equal(actual: Value, expected: Value) {
+    assert!(actual.is_equal(&expected), "{actual} is not equal? to {expected}");
+}
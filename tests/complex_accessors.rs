@@ -0,0 +1,76 @@
+//! `make-rectangular`, `make-polar` and the `real-part`, `imag-part`,
+//! `magnitude` and `angle` accessors on complex and real numbers.
+
+mod common;
+
+use common::eval;
+use lambdust::{Lambdust, Value};
+
+fn is_true(lambdust: &mut Lambdust, source: &str) -> bool {
+    eval(lambdust, source) == Value::boolean(true)
+}
+
+#[test]
+fn test_rectangular_parts() {
+    let mut lambdust = Lambdust::new();
+    assert!(is_true(&mut lambdust, "(= (real-part (make-rectangular 1.5 -2)) 1.5)"));
+    assert!(is_true(&mut lambdust, "(= (imag-part (make-rectangular 1.5 -2)) -2)"));
+    assert!(is_true(&mut lambdust, "(= (real-part 3+4i) 3)"));
+    assert!(is_true(&mut lambdust, "(= (imag-part 3+4i) 4)"));
+}
+
+#[test]
+fn test_reals_as_complex() {
+    let mut lambdust = Lambdust::new();
+    assert_eq!(eval(&mut lambdust, "(real-part 7)"), Value::integer(7));
+    assert_eq!(eval(&mut lambdust, "(imag-part 7)"), Value::integer(0));
+    assert_eq!(eval(&mut lambdust, "(real-part 1/2)"), eval(&mut lambdust, "1/2"));
+    assert_eq!(eval(&mut lambdust, "(imag-part 2.5)"), Value::integer(0));
+    assert_eq!(eval(&mut lambdust, "(magnitude -7)"), Value::integer(7));
+    assert_eq!(eval(&mut lambdust, "(magnitude -1/2)"), eval(&mut lambdust, "1/2"));
+}
+
+#[test]
+fn test_magnitude_is_the_modulus() {
+    let mut lambdust = Lambdust::new();
+    assert!(is_true(&mut lambdust, "(= (magnitude 3+4i) 5)"));
+    assert!(is_true(&mut lambdust, "(= (magnitude (make-rectangular -5 12)) 13)"));
+    assert!(is_true(&mut lambdust, "(= (magnitude (make-rectangular 0 -2)) 2)"));
+}
+
+#[test]
+fn test_angle_is_in_principal_range() {
+    let mut lambdust = Lambdust::new();
+    assert!(is_true(&mut lambdust, "(= (angle -1) (* 4 (atan 1)))"));
+    assert!(is_true(&mut lambdust, "(= (angle 1) 0)"));
+    assert!(is_true(&mut lambdust, "(= (angle (make-rectangular 0 1)) (* 2 (atan 1)))"));
+    assert!(is_true(&mut lambdust, "(= (angle (make-rectangular 0 -1)) (* -2 (atan 1)))"));
+    // Both signed zeros on the negative real axis give π rather than -π
+    assert!(is_true(&mut lambdust, "(= (angle (make-rectangular -1 0.0)) (* 4 (atan 1)))"));
+    assert!(is_true(&mut lambdust, "(= (angle (make-rectangular -1 -0.0)) (* 4 (atan 1)))"));
+}
+
+#[test]
+fn test_make_polar_round_trip() {
+    let mut lambdust = Lambdust::new();
+    eval(&mut lambdust, "(define z (make-polar 2 (* 2 (atan 1))))");
+    assert!(is_true(&mut lambdust, "(< (abs (real-part z)) 1e-12)"));
+    assert!(is_true(&mut lambdust, "(< (abs (- (imag-part z) 2)) 1e-12)"));
+    assert!(is_true(&mut lambdust, "(< (abs (- (magnitude z) 2)) 1e-12)"));
+    assert!(is_true(&mut lambdust, "(< (abs (- (angle z) (* 2 (atan 1)))) 1e-12)"));
+    eval(&mut lambdust, "(define w (make-rectangular 3 -4))");
+    assert!(is_true(
+        &mut lambdust,
+        "(let ((v (make-polar (magnitude w) (angle w))))
+           (and (< (abs (- (real-part v) 3)) 1e-12)
+                (< (abs (+ (imag-part v) 4)) 1e-12)))"
+    ));
+}
+
+#[test]
+fn test_wrong_argument_count_is_an_error() {
+    let mut lambdust = Lambdust::new();
+    assert!(lambdust.eval("(make-rectangular 1)", Some("<test>")).is_err());
+    assert!(lambdust.eval("(angle 1 2)", Some("<test>")).is_err());
+    assert!(lambdust.eval("(magnitude 'x)", Some("<test>")).is_err());
+}
@@ -0,0 +1,62 @@
+//! `cond-expand` tested against the `(features)` list.
+
+mod common;
+
+use common::eval;
+use lambdust::{Lambdust, Value};
+
+#[test]
+fn test_cond_expand_ratios() {
+    let mut lambdust = Lambdust::new();
+    assert_eq!(eval(&mut lambdust, "(cond-expand (ratios 'yes) (else 'no))"), Value::symbol_from_str("yes"));
+}
+
+#[test]
+fn test_cond_expand_requirements() {
+    let mut lambdust = Lambdust::new();
+    assert_eq!(
+        eval(&mut lambdust, "(cond-expand ((and r7rs (not no-such-feature)) 1) (else 2))"),
+        Value::integer(1)
+    );
+    assert_eq!(
+        eval(&mut lambdust, "(cond-expand ((or no-such-feature other-feature) 1) (else 2))"),
+        Value::integer(2)
+    );
+    assert_eq!(
+        eval(&mut lambdust, "(cond-expand ((library (scheme base)) 'base) (else 'none))"),
+        Value::symbol_from_str("base")
+    );
+}
+
+#[test]
+fn test_cond_expand_follows_compiled_features() {
+    let mut lambdust = Lambdust::new();
+    let expected = Value::boolean(cfg!(feature = "repl"));
+    assert_eq!(eval(&mut lambdust, "(cond-expand (repl #t) (else #f))"), expected);
+    assert_eq!(eval(&mut lambdust, "(if (memq 'repl (features)) #t #f)"), expected);
+}
+
+#[test]
+fn test_cond_expand_library_requirements() {
+    let mut lambdust = Lambdust::new();
+    assert_eq!(
+        eval(&mut lambdust, "(cond-expand ((library (lambdust string)) 'present) (else 'absent))"),
+        Value::symbol_from_str("present")
+    );
+    assert_eq!(
+        eval(&mut lambdust, "(cond-expand ((library (scheme no-such-library)) 'present) (else 'absent))"),
+        Value::symbol_from_str("absent")
+    );
+    assert_eq!(
+        eval(&mut lambdust, "(cond-expand ((and ratios (library (lambdust string))) 'both) (else 'neither))"),
+        Value::symbol_from_str("both")
+    );
+}
+
+#[test]
+fn test_cond_expand_without_match_expands_to_nothing() {
+    let mut lambdust = Lambdust::new();
+    eval(&mut lambdust, "(define x 1)");
+    eval(&mut lambdust, "(cond-expand (no-such-feature (set! x 2)))");
+    assert_eq!(eval(&mut lambdust, "x"), Value::integer(1));
+}
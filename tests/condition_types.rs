@@ -0,0 +1,96 @@
+//! Classification of read and file failures as `read-error?` and
+//! `file-error?` conditions, and user-defined condition types.
+
+mod common;
+
+use common::{assert_equal, eval};
+use lambdust::{Lambdust, Value};
+
+const CLASSIFY: &str = "(define (classify thunk)
+  (guard (e ((read-error? e) 'read-error)
+            ((file-error? e) 'file-error)
+            ((error-object? e) 'error)
+            (#t 'other))
+    (thunk)))";
+
+#[test]
+fn test_malformed_datum_is_read_error() {
+    let mut lambdust = Lambdust::new();
+    eval(&mut lambdust, CLASSIFY);
+    assert_eq!(
+        eval(&mut lambdust, "(classify (lambda () (read (open-input-string \"(1 2\"))))"),
+        Value::symbol_from_str("read-error")
+    );
+}
+
+#[test]
+fn test_missing_file_is_file_error() {
+    let mut lambdust = Lambdust::new();
+    eval(&mut lambdust, CLASSIFY);
+    assert_eq!(
+        eval(&mut lambdust, "(classify (lambda () (open-input-file \"/nonexistent/lambdust-missing.scm\")))"),
+        Value::symbol_from_str("file-error")
+    );
+}
+
+#[test]
+fn test_user_error_is_neither() {
+    let mut lambdust = Lambdust::new();
+    eval(&mut lambdust, CLASSIFY);
+    assert_eq!(
+        eval(&mut lambdust, "(classify (lambda () (error \"plain\" 1)))"),
+        Value::symbol_from_str("error")
+    );
+    assert_equal(
+        eval(&mut lambdust, "(guard (e (#t (list (read-error? e) (file-error? e)))) (error \"plain\"))"),
+        eval(&mut lambdust, "'(#f #f)")
+    );
+}
+
+#[test]
+fn test_custom_condition_type_caught_by_type() {
+    let mut lambdust = Lambdust::new();
+    eval(&mut lambdust, "(define &timeout (make-condition-type '&timeout &error '(seconds)))");
+    eval(&mut lambdust, "(define &other (make-condition-type '&other #f '()))");
+    assert_equal(
+        eval(&mut lambdust, "(guard (e ((condition/type? e &other) 'other)
+                                        ((condition/type? e &timeout) (condition-ref e 'seconds)))
+                               (raise (make-condition &timeout 'message \"timed out\" 'irritants '() 'seconds 30)))"),
+        Value::integer(30)
+    );
+    assert_equal(
+        eval(&mut lambdust, "(let ((c (make-condition &timeout 'message \"m\" 'irritants '() 'seconds 1)))
+                               (list (condition? c) (condition/type? c &error) (condition/type? c &other)))"),
+        eval(&mut lambdust, "'(#t #t #f)")
+    );
+    assert_eq!(eval(&mut lambdust, "(condition-type? &timeout)"), Value::boolean(true));
+    assert_eq!(eval(&mut lambdust, "(condition? 42)"), Value::boolean(false));
+}
+
+#[test]
+fn test_builtin_errors_are_typed_conditions() {
+    let mut lambdust = Lambdust::new();
+    assert_equal(
+        eval(&mut lambdust, "(guard (e (#t (list (condition/type? e &file-error) (condition/type? e &error)
+                                                 (condition/type? e &read-error))))
+                               (open-input-file \"/nonexistent/lambdust-missing.scm\"))"),
+        eval(&mut lambdust, "'(#t #t #f)")
+    );
+    assert_eq!(
+        eval(&mut lambdust, "(guard (e (#t (condition/type? e &error))) (read (open-input-string \"(1 2\")))"),
+        Value::boolean(true)
+    );
+    assert_equal(
+        eval(&mut lambdust, "(guard (e (#t (list (condition-ref e 'message) (condition-ref e 'irritants)))) (error \"bad\" 1 2))"),
+        eval(&mut lambdust, "'(\"bad\" (1 2))")
+    );
+}
+
+#[test]
+fn test_missing_condition_field_is_an_error() {
+    let mut lambdust = Lambdust::new();
+    eval(&mut lambdust, "(define &point (make-condition-type '&point #f '(x)))");
+    assert!(lambdust.eval("(condition-ref (make-condition &point 'x 1) 'y)", Some("<test>")).is_err());
+    assert!(lambdust.eval("(make-condition &point)", Some("<test>")).is_err());
+    assert!(lambdust.eval("(guard (e (#t (condition-ref e 'seconds))) (error \"plain\"))", Some("<test>")).is_err());
+}
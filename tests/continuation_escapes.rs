@@ -0,0 +1,130 @@
+//! A continuation called inside Rust code that runs a nested trampoline
+//! escapes the whole extent, not just the inner call.
+
+mod common;
+
+use common::{assert_equal, eval};
+use lambdust::{Lambdust, Value};
+
+fn escape_value(source: &str) -> Value {
+    let mut lambdust = Lambdust::new();
+    eval(&mut lambdust, source)
+}
+
+#[test]
+fn test_escape_from_guard_body() {
+    assert_eq!(
+        escape_value("(call/cc (lambda (k) (+ 100 (guard (e (#t 0)) (k 1)))))"),
+        Value::integer(1)
+    );
+}
+
+#[test]
+fn test_escape_from_exception_handler_thunk() {
+    assert_eq!(
+        escape_value(
+            "(call/cc (lambda (k)
+               (+ 100 (with-exception-handler (lambda (e) 0) (lambda () (k 1))))))",
+        ),
+        Value::integer(1)
+    );
+}
+
+#[test]
+fn test_escape_from_exception_handler() {
+    assert_eq!(
+        escape_value(
+            "(call/cc (lambda (k)
+               (+ 100 (with-exception-handler (lambda (e) (k e))
+                        (lambda () (raise-continuable 1))))))",
+        ),
+        Value::integer(1)
+    );
+}
+
+#[test]
+fn test_escape_from_dynamic_wind_thunk() {
+    assert_eq!(
+        escape_value(
+            "(call/cc (lambda (k)
+               (+ 100 (dynamic-wind (lambda () #f) (lambda () (k 1)) (lambda () #f)))))",
+        ),
+        Value::integer(1)
+    );
+}
+
+#[test]
+fn test_escape_from_forced_promise() {
+    assert_eq!(
+        escape_value("(call/cc (lambda (k) (+ 100 (force (delay (k 1))))))"),
+        Value::integer(1)
+    );
+}
+
+#[test]
+fn test_escape_from_vector_map() {
+    assert_eq!(
+        escape_value("(call/cc (lambda (k) (vector-map (lambda (x) (k x)) #(1 2))))"),
+        Value::integer(1)
+    );
+}
+
+#[test]
+fn test_escape_from_map() {
+    assert_eq!(
+        escape_value("(call/cc (lambda (k) (map (lambda (x) (k x)) '(1 2))))"),
+        Value::integer(1)
+    );
+}
+
+#[test]
+fn test_escape_from_set_for_each() {
+    assert_eq!(
+        escape_value("(call/cc (lambda (k) (set-for-each (lambda (x) (k x)) (set 5))))"),
+        Value::integer(5)
+    );
+}
+
+#[test]
+fn test_escape_from_bag_for_each() {
+    assert_eq!(
+        escape_value("(call/cc (lambda (k) (bag-for-each (lambda (x) (k x)) (bag 5))))"),
+        Value::integer(5)
+    );
+}
+
+#[test]
+fn test_escape_from_values_producer() {
+    assert_eq!(
+        escape_value("(call/cc (lambda (k) (call-with-values (lambda () (k 1)) list)))"),
+        Value::integer(1)
+    );
+}
+
+#[test]
+fn test_escape_from_do_loop() {
+    assert_eq!(
+        escape_value("(call/cc (lambda (k) (do ((i 0 (+ i 1))) ((= i 10) 'done) (if (= i 3) (k i)))))"),
+        Value::integer(3)
+    );
+}
+
+#[test]
+fn test_escaped_value_reaches_enclosing_expression() {
+    assert_equal(
+        escape_value("(list (call/cc (lambda (k) (+ 100 (guard (e (#t 0)) (k 1))))))"),
+        escape_value("'(1)"),
+    );
+}
+
+#[test]
+fn test_jump_inside_nested_call_stays_inside() {
+    assert_equal(
+        escape_value("(map (lambda (x) (call/cc (lambda (k) (k (* x 10))))) '(1 2 3))"),
+        escape_value("'(10 20 30)"),
+    );
+    assert_equal(
+        escape_value("(call-with-values (lambda () (call/cc (lambda (k) (k 1 2)))) list)"),
+        escape_value("'(1 2)"),
+    );
+}
@@ -0,0 +1,29 @@
+//! `#;` datum comments drop the next datum when reading and evaluating.
+
+mod common;
+
+use common::{assert_equal, eval};
+use lambdust::ast::Program;
+use lambdust::{Lambdust, Lexer, Parser, Result, Value};
+
+fn parse(source: &str) -> Result<Program> {
+    let tokens = Lexer::new(source, Some("test")).tokenize().unwrap();
+    Parser::with_settings(tokens, 1, false).parse()
+}
+
+#[test]
+fn test_commented_datums_are_not_evaluated() {
+    let mut lambdust = Lambdust::new();
+    assert_equal(eval(&mut lambdust, "'(1 #;(2 3) 4)"), eval(&mut lambdust, "'(1 4)"));
+    assert_eq!(eval(&mut lambdust, "(+ 1 #;#;2 3 4)"), Value::integer(5));
+    assert_eq!(eval(&mut lambdust, "(+ 1 #;(undefined-procedure) 2)"), Value::integer(3));
+    assert_eq!(eval(&mut lambdust, "#| block |# #;(car '()) 5"), Value::integer(5));
+}
+
+#[test]
+fn test_datum_comment_without_datum() {
+    let err = parse("(1 #;)").unwrap_err();
+    assert!(err.to_string().contains("no datum to comment out"), "{err}");
+    assert!(parse("(1 #;").unwrap_err().is_incomplete_input());
+    assert!(parse("1 #;").unwrap_err().is_incomplete_input());
+}
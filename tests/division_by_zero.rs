@@ -0,0 +1,50 @@
+//! Exact zero divisors raise catchable assertion violations while inexact
+//! zeros follow IEEE 754.
+
+mod common;
+
+use common::{assert_equal, eval};
+use lambdust::{Lambdust, Value};
+
+const CATCH: &str = "(define-syntax catch
+  (syntax-rules ()
+    ((_ expr) (guard (e ((assertion-violation? e) 'assertion-violation)
+                        (#t 'other))
+                expr))))";
+
+#[test]
+fn test_exact_division_by_zero_is_catchable() {
+    let mut lambdust = Lambdust::new();
+    eval(&mut lambdust, CATCH);
+    for source in ["(catch (/ 1 0))", "(catch (/ 0))", "(catch (/ 1.5 0))", "(catch (/ 6 2 0))"] {
+        assert_eq!(eval(&mut lambdust, source), Value::symbol_from_str("assertion-violation"), "{source}");
+    }
+}
+
+#[test]
+fn test_integer_division_by_zero_is_catchable() {
+    let mut lambdust = Lambdust::new();
+    eval(&mut lambdust, CATCH);
+    for operation in ["quotient", "remainder", "modulo", "floor-quotient", "truncate-remainder", "floor/"] {
+        let source = format!("(catch ({operation} 5 0))");
+        assert_eq!(eval(&mut lambdust, &source), Value::symbol_from_str("assertion-violation"), "{source}");
+    }
+    assert_eq!(eval(&mut lambdust, "(catch (modulo 5.0 0.0))"), Value::symbol_from_str("assertion-violation"));
+}
+
+#[test]
+fn test_division_by_zero_error_message() {
+    let mut lambdust = Lambdust::new();
+    assert_equal(
+        eval(&mut lambdust, "(guard (e (#t (list (error-object-message e) (error-object-irritants e)))) (modulo 5 0))"),
+        Value::list(vec![Value::string("modulo: division by zero"), Value::list(vec![Value::integer(5)])])
+    );
+}
+
+#[test]
+fn test_inexact_division_by_zero_follows_ieee() {
+    let mut lambdust = Lambdust::new();
+    assert_eq!(eval(&mut lambdust, "(/ 1.0 0.0)"), Value::number(f64::INFINITY));
+    assert_eq!(eval(&mut lambdust, "(/ -1 0.0)"), Value::number(f64::NEG_INFINITY));
+    assert_eq!(eval(&mut lambdust, "(nan? (/ 0.0 0.0))"), Value::boolean(true));
+}
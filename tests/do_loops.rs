@@ -0,0 +1,45 @@
+//! `do` loops with a reused loop environment and with escaping closures.
+
+mod common;
+
+use common::eval;
+use lambdust::{Lambdust, Value};
+use std::time::{Duration, Instant};
+
+#[test]
+fn test_closures_see_distinct_iterations() {
+    let mut lambdust = Lambdust::new();
+    eval(
+        &mut lambdust,
+        "(define thunks
+           (do ((i 0 (+ i 1))
+                (acc '() (cons (lambda () i) acc)))
+               ((= i 3) (reverse acc))))",
+    );
+    assert_eq!(
+        eval(&mut lambdust, "(map (lambda (thunk) (thunk)) thunks)"),
+        Value::list(vec![Value::integer(0), Value::integer(1), Value::integer(2)])
+    );
+}
+
+#[test]
+fn test_variable_without_step_keeps_its_value() {
+    let mut lambdust = Lambdust::new();
+    assert_eq!(
+        eval(&mut lambdust, "(do ((i 0 (+ i 1)) (total 0)) ((= i 4) total) (set! total (+ total i)))"),
+        Value::integer(6)
+    );
+}
+
+#[test]
+fn test_numeric_loop_of_a_million_iterations() {
+    let mut lambdust = Lambdust::new();
+    let start = Instant::now();
+    let result = eval(
+        &mut lambdust,
+        "(do ((i 0 (+ i 1)) (sum 0 (+ sum i))) ((= i 1000000) sum))",
+    );
+
+    assert_eq!(result, Value::integer(499_999_500_000));
+    assert!(start.elapsed() < Duration::from_secs(60));
+}
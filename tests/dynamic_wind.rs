@@ -0,0 +1,149 @@
+//! `dynamic-wind` before/after ordering on normal exit, errors and
+//! continuation escapes.
+
+mod common;
+
+use common::eval;
+use lambdust::{Lambdust, Value};
+
+fn setup_counters(lambdust: &mut Lambdust) {
+    eval(lambdust, "(define counts (vector 0 0))");
+    eval(lambdust, "(define (before) (vector-set! counts 0 (+ (vector-ref counts 0) 1)))");
+    eval(lambdust, "(define (after) (vector-set! counts 1 (+ (vector-ref counts 1) 1)))");
+}
+
+#[test]
+fn test_dynamic_wind_normal_exit() {
+    let mut lambdust = Lambdust::new();
+    setup_counters(&mut lambdust);
+
+    let result = eval(&mut lambdust, "(dynamic-wind before (lambda () 42) after)");
+    assert_eq!(result, Value::integer(42));
+    assert_eq!(eval(&mut lambdust, "(vector-ref counts 0)"), Value::integer(1));
+    assert_eq!(eval(&mut lambdust, "(vector-ref counts 1)"), Value::integer(1));
+}
+
+#[test]
+fn test_dynamic_wind_escape_runs_after_once() {
+    let mut lambdust = Lambdust::new();
+    setup_counters(&mut lambdust);
+
+    let result = eval(
+        &mut lambdust,
+        "(call/cc (lambda (k) (dynamic-wind before (lambda () (k 'escaped)) after)))",
+    );
+    assert_eq!(result, Value::symbol_from_str("escaped"));
+    assert_eq!(eval(&mut lambdust, "(vector-ref counts 0)"), Value::integer(1));
+    assert_eq!(eval(&mut lambdust, "(vector-ref counts 1)"), Value::integer(1));
+}
+
+#[test]
+fn test_escape_from_nested_dynamic_wind_delivers_value() {
+    let mut lambdust = Lambdust::new();
+    setup_counters(&mut lambdust);
+
+    let result = eval(
+        &mut lambdust,
+        "(call/cc (lambda (k) (+ 100 (dynamic-wind before (lambda () (k 1)) after))))",
+    );
+    assert_eq!(result, Value::integer(1));
+    assert_eq!(eval(&mut lambdust, "(vector-ref counts 0)"), Value::integer(1));
+    assert_eq!(eval(&mut lambdust, "(vector-ref counts 1)"), Value::integer(1));
+}
+
+#[test]
+fn test_reentry_through_stored_continuation_reruns_before() {
+    let mut lambdust = Lambdust::new();
+    setup_counters(&mut lambdust);
+    eval(&mut lambdust, "(define saved (vector #f))");
+
+    let first = eval(
+        &mut lambdust,
+        "(dynamic-wind
+           before
+           (lambda () (call/cc (lambda (k) (vector-set! saved 0 k) 'first)))
+           after)",
+    );
+    assert_eq!(first, Value::symbol_from_str("first"));
+    assert_eq!(eval(&mut lambdust, "(vector-ref counts 0)"), Value::integer(1));
+    assert_eq!(eval(&mut lambdust, "(vector-ref counts 1)"), Value::integer(1));
+
+    // Each re-entry winds into the extent and back out of it
+    for round in 2..=3 {
+        let again = eval(&mut lambdust, "((vector-ref saved 0) 'again)");
+        assert_eq!(again, Value::symbol_from_str("again"));
+        assert_eq!(eval(&mut lambdust, "(vector-ref counts 0)"), Value::integer(round));
+        assert_eq!(eval(&mut lambdust, "(vector-ref counts 1)"), Value::integer(round));
+        assert_eq!(eval(&mut lambdust, "(dynamic-wind-stack)"), Value::Nil);
+    }
+}
+
+#[test]
+fn test_nested_escape_unwinds_innermost_first() {
+    let mut lambdust = Lambdust::new();
+    eval(&mut lambdust, "(define trace (vector '()))");
+    eval(&mut lambdust, "(define (note x) (lambda () (vector-set! trace 0 (cons x (vector-ref trace 0)))))");
+
+    eval(
+        &mut lambdust,
+        "(call/cc
+           (lambda (k)
+             (dynamic-wind
+               (note 'before-outer)
+               (lambda ()
+                 (dynamic-wind
+                   (note 'before-inner)
+                   (lambda () (k 'done))
+                   (note 'after-inner)))
+               (note 'after-outer))))",
+    );
+
+    let expected = eval(
+        &mut lambdust,
+        "'(after-outer after-inner before-inner before-outer)",
+    );
+    assert_eq!(eval(&mut lambdust, "(vector-ref trace 0)"), expected);
+}
+
+#[test]
+fn test_dynamic_wind_error_exit_runs_after() {
+    let mut lambdust = Lambdust::new();
+    setup_counters(&mut lambdust);
+
+    let result = eval(
+        &mut lambdust,
+        "(guard (e (#t 'caught))
+           (dynamic-wind before (lambda () (raise 'boom)) after))",
+    );
+    assert_eq!(result, Value::symbol_from_str("caught"));
+    assert_eq!(eval(&mut lambdust, "(vector-ref counts 0)"), Value::integer(1));
+    assert_eq!(eval(&mut lambdust, "(vector-ref counts 1)"), Value::integer(1));
+}
+
+#[test]
+fn test_dynamic_wind_stack_lists_named_extents() {
+    let mut lambdust = Lambdust::new();
+    eval(&mut lambdust, "(define (noop) #f)");
+
+    let inside = eval(
+        &mut lambdust,
+        "(dynamic-wind/named 'outer noop
+           (lambda ()
+             (dynamic-wind/named 'inner noop (lambda () (dynamic-wind-stack)) noop))
+           noop)",
+    );
+    assert_eq!(
+        inside,
+        Value::list(vec![Value::symbol_from_str("inner"), Value::symbol_from_str("outer")])
+    );
+    assert_eq!(eval(&mut lambdust, "(dynamic-wind-stack)"), Value::Nil);
+
+    // Plain dynamic-wind extents appear unnamed
+    let mixed = eval(
+        &mut lambdust,
+        "(dynamic-wind/named 'outer noop
+           (lambda () (dynamic-wind noop dynamic-wind-stack noop))
+           noop)",
+    );
+    assert_eq!(mixed, Value::list(vec![Value::boolean(false), Value::symbol_from_str("outer")]));
+}
@@ -0,0 +1,83 @@
+//! `dynamic-wind` after thunks run as a raise unwinds toward a `guard`.
+
+mod common;
+
+use common::{assert_equal, eval};
+use lambdust::Lambdust;
+
+fn with_log() -> Lambdust {
+    let mut lambdust = Lambdust::new();
+    eval(&mut lambdust, "(define log (vector '()))");
+    eval(&mut lambdust, "(define (note x) (vector-set! log 0 (cons x (vector-ref log 0))))");
+    lambdust
+}
+
+#[test]
+fn test_after_thunk_runs_before_guard_clause() {
+    let mut lambdust = with_log();
+    eval(
+        &mut lambdust,
+        "(guard (e (#t (note (list 'clause e))))
+           (dynamic-wind
+             (lambda () (note 'before))
+             (lambda () (raise 'boom) (note 'unreached))
+             (lambda () (note 'after))))",
+    );
+    assert_equal(eval(&mut lambdust, "(reverse (vector-ref log 0))"), eval(&mut lambdust, "'(before after (clause boom))"));
+}
+
+#[test]
+fn test_nested_extents_unwind_innermost_first() {
+    let mut lambdust = with_log();
+    eval(
+        &mut lambdust,
+        "(guard (e ((symbol? e) (note 'clause)))
+           (dynamic-wind
+             (lambda () (note 'outer-before))
+             (lambda ()
+               (dynamic-wind
+                 (lambda () (note 'inner-before))
+                 (lambda () (raise 'boom))
+                 (lambda () (note 'inner-after))))
+             (lambda () (note 'outer-after))))",
+    );
+    assert_eq!(
+        eval(&mut lambdust, "(reverse (vector-ref log 0))"),
+        eval(&mut lambdust, "'(outer-before inner-before inner-after outer-after clause)")
+    );
+}
+
+#[test]
+fn test_guard_inside_extent_does_not_leave_it() {
+    let mut lambdust = with_log();
+    eval(
+        &mut lambdust,
+        "(dynamic-wind
+           (lambda () (note 'before))
+           (lambda ()
+             (guard (e (#t (note 'caught))) (raise 'boom))
+             (note 'still-inside))
+           (lambda () (note 'after)))",
+    );
+    assert_eq!(
+        eval(&mut lambdust, "(reverse (vector-ref log 0))"),
+        eval(&mut lambdust, "'(before caught still-inside after)")
+    );
+}
+
+#[test]
+fn test_handler_runs_inside_the_extent_of_the_raise() {
+    let mut lambdust = with_log();
+    // A with-exception-handler handler runs at the raise, before any unwinding
+    eval(
+        &mut lambdust,
+        "(with-exception-handler
+           (lambda (e) (note 'handler) 0)
+           (lambda ()
+             (dynamic-wind
+               (lambda () (note 'before))
+               (lambda () (raise-continuable 'resume))
+               (lambda () (note 'after)))))",
+    );
+    assert_eq!(eval(&mut lambdust, "(reverse (vector-ref log 0))"), eval(&mut lambdust, "'(before handler after)"));
+}
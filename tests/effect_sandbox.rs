@@ -0,0 +1,35 @@
+//! Primitives applied under an effect sandbox.
+
+mod common;
+
+use common::eval;
+use lambdust::effects::Effect;
+use lambdust::runtime::EffectSandboxConfig;
+use lambdust::{Lambdust, Value};
+
+#[test]
+fn test_default_sandbox_denies_filesystem_access() {
+    let path = std::env::temp_dir().join(format!("lambdust-{}-sandboxed.txt", std::process::id()));
+    std::fs::write(&path, "keep").unwrap();
+    let mut lambdust = Lambdust::new();
+    lambdust.runtime_mut().evaluator_mut().set_effect_sandbox(Some(EffectSandboxConfig::default()));
+
+    assert_eq!(eval(&mut lambdust, "(+ 1 2)"), Value::integer(3));
+    let error = lambdust.eval(&format!("(delete-file {:?})", path.to_string_lossy()), None).unwrap_err();
+    assert!(error.to_string().contains("not permitted"), "unexpected error: {error}");
+    assert!(path.exists());
+    std::fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn test_sandbox_allows_permitted_effects() {
+    let mut lambdust = Lambdust::new();
+    lambdust.runtime_mut().evaluator_mut().set_effect_sandbox(Some(EffectSandboxConfig {
+        allowed_effects: vec![Effect::IO, Effect::file_system()],
+        ..EffectSandboxConfig::default()
+    }));
+    assert_eq!(eval(&mut lambdust, "(file-exists? \"/no/such/lambdust/file\")"), Value::boolean(false));
+
+    lambdust.runtime_mut().evaluator_mut().set_effect_sandbox(None);
+    assert_eq!(eval(&mut lambdust, "(string? (current-directory))"), Value::boolean(true));
+}
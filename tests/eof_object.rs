@@ -0,0 +1,32 @@
+//! The canonical end-of-file object and the port readers that return it.
+
+mod common;
+
+use common::eval;
+use lambdust::{Lambdust, Value};
+
+#[test]
+fn test_eof_object_is_a_singleton() {
+    let mut lambdust = Lambdust::new();
+    assert_eq!(eval(&mut lambdust, "(eq? (eof-object) (eof-object))"), Value::boolean(true));
+    assert_eq!(eval(&mut lambdust, "(eof-object? (eof-object))"), Value::boolean(true));
+    assert_eq!(eval(&mut lambdust, "(eof-object? '*eof*)"), Value::boolean(false));
+    assert_eq!(eval(&mut lambdust, "(eof-object)"), Value::EofObject);
+}
+
+#[test]
+fn test_readers_return_eof_at_end_of_input() {
+    let mut lambdust = Lambdust::new();
+    for source in [
+        "(let ((p (open-input-string \"a\"))) (read-char p) (read-char p))",
+        "(peek-char (open-input-string \"\"))",
+        "(let ((p (open-input-string \"x\"))) (read p) (read p))",
+        "(let ((p (open-input-string \"line\"))) (read-line p) (read-line p))",
+        "(read-string 3 (open-input-string \"\"))",
+        "(let ((p (open-input-bytevector (bytevector 1)))) (read-u8 p) (read-u8 p))",
+    ] {
+        assert_eq!(eval(&mut lambdust, source), Value::EofObject, "{source}");
+        let wrapped = format!("(eq? {source} (eof-object))");
+        assert_eq!(eval(&mut lambdust, &wrapped), Value::boolean(true), "{source}");
+    }
+}
@@ -0,0 +1,44 @@
+//! `eq?` identity and the `eq-hash` procedure behind identity hash tables.
+
+mod common;
+
+use common::eval;
+use lambdust::{Lambdust, Value};
+
+#[test]
+fn test_distinct_equal_objects_are_not_eq() {
+    let mut lambdust = Lambdust::new();
+    eval(&mut lambdust, "(define a (list 1 2))");
+    eval(&mut lambdust, "(define b (list 1 2))");
+
+    assert_eq!(eval(&mut lambdust, "(eq? a a)"), Value::boolean(true));
+    assert_eq!(eval(&mut lambdust, "(eq? a b)"), Value::boolean(false));
+    assert_eq!(eval(&mut lambdust, "(eqv? a b)"), Value::boolean(false));
+    assert_eq!(eval(&mut lambdust, "(equal? a b)"), Value::boolean(true));
+    assert_eq!(eval(&mut lambdust, "(eq? (string-copy \"x\") (string-copy \"x\"))"), Value::boolean(false));
+    assert_eq!(eval(&mut lambdust, "(eq? 'sym 'sym)"), Value::boolean(true));
+    assert_eq!(eval(&mut lambdust, "(eqv? 42 42)"), Value::boolean(true));
+}
+
+#[test]
+fn test_memq_and_assq_use_identity() {
+    let mut lambdust = Lambdust::new();
+    eval(&mut lambdust, "(define key (list 'k))");
+    eval(&mut lambdust, "(define alist (list (cons (list 'k) 1) (cons key 2)))");
+
+    assert_eq!(eval(&mut lambdust, "(cdr (assq key alist))"), Value::integer(2));
+    assert_eq!(eval(&mut lambdust, "(cdr (assoc key alist))"), Value::integer(1));
+    assert_eq!(eval(&mut lambdust, "(memq (list 'k) (list key))"), Value::boolean(false));
+}
+
+#[test]
+fn test_eq_hash_is_stable_across_minor_collection() {
+    let mut lambdust = Lambdust::new();
+    eval(&mut lambdust, "(define v (vector 1 2 3))");
+    eval(&mut lambdust, "(define before (eq-hash v))");
+    eval(&mut lambdust, "(vector-set! v 0 'changed)");
+    eval(&mut lambdust, "(collect-garbage 'minor)");
+
+    assert_eq!(eval(&mut lambdust, "(= before (eq-hash v))"), Value::boolean(true));
+    assert_eq!(eval(&mut lambdust, "(= (eq-hash 'a) (eq-hash 'a))"), Value::boolean(true));
+}
@@ -0,0 +1,70 @@
+//! Structural `equal?` over vectors, deep nesting and cyclic data.
+
+mod common;
+
+use common::eval;
+use lambdust::{Lambdust, Value};
+
+#[test]
+fn test_equal_compares_distinct_structures_by_contents() {
+    let mut lambdust = Lambdust::new();
+    assert_eq!(eval(&mut lambdust, "(equal? (vector 1 \"a\" '(b)) (vector 1 \"a\" '(b)))"), Value::boolean(true));
+    assert_eq!(eval(&mut lambdust, "(equal? (vector 1 2) (vector 1 2 3))"), Value::boolean(false));
+    assert_eq!(eval(&mut lambdust, "(equal? (make-vector 1000 (list 1 2)) (make-vector 1000 (list 1 2)))"), Value::boolean(true));
+    assert_eq!(eval(&mut lambdust, "(equal? (list 1 (vector 2 '(3))) (list 1 (vector 2 '(4))))"), Value::boolean(false));
+    assert_eq!(eval(&mut lambdust, "(let ((v (vector 1))) (equal? v v))"), Value::boolean(true));
+}
+
+#[test]
+fn test_equal_on_deeply_nested_lists() {
+    let mut lambdust = Lambdust::new();
+    eval(
+        &mut lambdust,
+        "(define (nest n)
+           (do ((i 0 (+ i 1)) (acc '() (list acc))) ((= i n) acc)))",
+    );
+    eval(&mut lambdust, "(define a (nest 100000))");
+    eval(&mut lambdust, "(define b (nest 100000))");
+    eval(&mut lambdust, "(define c (nest 99999))");
+    assert_eq!(eval(&mut lambdust, "(equal? a b)"), Value::boolean(true));
+    assert_eq!(eval(&mut lambdust, "(equal? a c)"), Value::boolean(false));
+
+    // Releasing a list drops its pairs recursively, which would overflow the
+    // test thread's stack at this depth, so the interpreter is leaked
+    std::mem::forget(lambdust);
+}
+
+#[test]
+fn test_equal_terminates_on_matching_cyclic_vectors() {
+    let mut lambdust = Lambdust::new();
+    eval(&mut lambdust, "(define a (vector 1 #f))");
+    eval(&mut lambdust, "(vector-set! a 1 a)");
+    eval(&mut lambdust, "(define b (vector 1 #f))");
+    eval(&mut lambdust, "(vector-set! b 1 b)");
+    assert_eq!(eval(&mut lambdust, "(equal? a b)"), Value::boolean(true));
+    
+    eval(&mut lambdust, "(define c (vector 2 #f))");
+    eval(&mut lambdust, "(vector-set! c 1 c)");
+    assert_eq!(eval(&mut lambdust, "(equal? a c)"), Value::boolean(false));
+}
+
+#[test]
+fn test_equal_terminates_on_matching_cyclic_lists() {
+    let mut lambdust = Lambdust::new();
+    eval(&mut lambdust, "(define a (list 1 2))");
+    eval(&mut lambdust, "(set-cdr! (cdr a) a)");
+    eval(&mut lambdust, "(define b (list 1 2))");
+    eval(&mut lambdust, "(set-cdr! (cdr b) b)");
+    assert_eq!(eval(&mut lambdust, "(equal? a b)"), Value::boolean(true));
+
+    eval(&mut lambdust, "(define c (list 1 3))");
+    eval(&mut lambdust, "(set-cdr! (cdr c) c)");
+    assert_eq!(eval(&mut lambdust, "(equal? a c)"), Value::boolean(false));
+}
+
+#[test]
+fn test_member_and_assoc_use_structural_equality() {
+    let mut lambdust = Lambdust::new();
+    assert_eq!(eval(&mut lambdust, "(pair? (member (vector 1 2) (list 0 (vector 1 2))))"), Value::boolean(true));
+    assert_eq!(eval(&mut lambdust, "(cdr (assoc (vector 'k) (list (cons (vector 'k) 'v))))"), Value::symbol_from_str("v"));
+}
@@ -0,0 +1,43 @@
+//! Source locations recorded on conditions raised by `error`, `raise` and
+//! `assertion-violation`.
+
+use lambdust::{Lambdust, Value};
+
+const SOURCE: &str = "(define (check x)\n  (if (< x 0)\n      (error \"negative\" x)\n      x))\n(check -1)";
+
+#[test]
+fn test_error_span_points_at_raising_call() {
+    let mut lambdust = Lambdust::new();
+    let err = lambdust.eval(SOURCE, Some("<test>")).unwrap_err();
+
+    let span = err.span().expect("error should carry a span");
+    assert_eq!(span.start, SOURCE.find("(error").unwrap());
+    assert!(err.display_with_location(SOURCE).contains("line 3"));
+}
+
+#[test]
+fn test_error_object_location_inside_guard() {
+    let mut lambdust = Lambdust::new();
+    let result = lambdust
+        .eval(
+            "(guard (e (#t (error-object-location e))) (error \"boom\" 1))",
+            Some("<test>"),
+        )
+        .unwrap();
+
+    assert!(result.is_pair());
+}
+
+#[test]
+fn test_assertion_violation_is_an_error_object() {
+    let mut lambdust = Lambdust::new();
+    let result = lambdust
+        .eval(
+            "(guard (e ((error-object? e) (error-object-message e)))
+               (assertion-violation 'check \"bad value\" 7))",
+            Some("<test>"),
+        )
+        .unwrap();
+
+    assert_eq!(result, Value::string("check: bad value"));
+}
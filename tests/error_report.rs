@@ -0,0 +1,31 @@
+//! Top-level reports of uncaught errors and their irritants.
+
+use lambdust::Lambdust;
+
+#[test]
+fn test_uncaught_error_writes_irritants() {
+    let mut lambdust = Lambdust::new();
+    let error = lambdust
+        .eval("(error \"bad value\" \"foo\" 42)", Some("<test>"))
+        .unwrap_err();
+
+    assert_eq!(error.report(), "bad value \"foo\" 42");
+    assert!(error.to_string().contains("bad value \"foo\" 42"));
+}
+
+#[test]
+fn test_irritants_use_write_form() {
+    let mut lambdust = Lambdust::new();
+    let error = lambdust
+        .eval("(error \"unexpected\" #\\a '(1 \"two\") 'sym)", Some("<test>"))
+        .unwrap_err();
+
+    assert_eq!(error.report(), "unexpected #\\a (1 \"two\") sym");
+}
+
+#[test]
+fn test_error_without_irritants_reports_message() {
+    let mut lambdust = Lambdust::new();
+    let error = lambdust.eval("(error \"plain\")", Some("<test>")).unwrap_err();
+    assert_eq!(error.report(), "plain");
+}
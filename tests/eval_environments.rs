@@ -0,0 +1,75 @@
+//! `eval` in the R5RS `scheme-report-environment` and `null-environment`,
+//! and in environments built by `environment` from import sets.
+
+mod common;
+
+use common::eval;
+use lambdust::{Lambdust, Value};
+
+#[test]
+fn test_eval_in_scheme_report_environment() {
+    let mut lambdust = Lambdust::new();
+    assert_eq!(
+        eval(&mut lambdust, "(eval '(* 7 (car (list 2 3))) (scheme-report-environment 5))"),
+        Value::integer(14)
+    );
+    assert_eq!(
+        eval(&mut lambdust, "(eval '(do ((i 0 (+ i 1)) (acc '() (cons i acc))) ((= i 3) (reverse acc))) (scheme-report-environment 5))"),
+        Value::list(vec![Value::integer(0), Value::integer(1), Value::integer(2)])
+    );
+    assert_eq!(eval(&mut lambdust, "(environment? (scheme-report-environment 5))"), Value::boolean(true));
+}
+
+#[test]
+fn test_non_r5rs_binding_is_absent() {
+    let mut lambdust = Lambdust::new();
+    // string-map and make-generator are bound globally but are not R5RS
+    assert_eq!(eval(&mut lambdust, "(procedure? string-map)"), Value::boolean(true));
+    assert!(lambdust.eval("(eval '(string-map char-upcase \"abc\") (scheme-report-environment 5))", Some("<test>")).is_err());
+    assert!(lambdust.eval("(eval 'make-generator (scheme-report-environment 5))", Some("<test>")).is_err());
+}
+
+#[test]
+fn test_null_environment_has_only_syntax() {
+    let mut lambdust = Lambdust::new();
+    assert_eq!(
+        eval(&mut lambdust, "(eval '(if #f 1 ((lambda (x) x) 'ok)) (null-environment 5))"),
+        Value::symbol_from_str("ok")
+    );
+    assert!(lambdust.eval("(eval '(car '(1 2)) (null-environment 5))", Some("<test>")).is_err());
+}
+
+#[test]
+fn test_unsupported_version_errors() {
+    let mut lambdust = Lambdust::new();
+    assert!(lambdust.eval("(scheme-report-environment 7)", Some("<test>")).is_err());
+    assert!(lambdust.eval("(null-environment 4)", Some("<test>")).is_err());
+}
+
+#[test]
+fn test_definitions_are_rejected() {
+    let mut lambdust = Lambdust::new();
+    eval(&mut lambdust, "(define r5rs (scheme-report-environment 5))");
+    assert!(lambdust.eval("(eval '(define x 1) r5rs)", Some("<test>")).is_err());
+    assert!(lambdust.eval("(eval '(begin (+ 1 2) (define (f) 1)) r5rs)", Some("<test>")).is_err());
+    assert!(lambdust.eval("(eval '(define x 1) (null-environment 5))", Some("<test>")).is_err());
+    // Internal definitions stay local to their body
+    assert_eq!(eval(&mut lambdust, "(eval '(let () (define y 2) (* y y)) r5rs)"), Value::integer(4));
+}
+
+#[test]
+fn test_environment_from_import_sets() {
+    let mut lambdust = Lambdust::new();
+    assert_eq!(
+        eval(&mut lambdust, "(eval '(square 5) (environment '(scheme base)))"),
+        Value::integer(25)
+    );
+    assert!(lambdust.eval("(eval 'car (environment '(only (scheme base) cdr)))", Some("<test>")).is_err());
+}
+
+#[test]
+fn test_eval_requires_an_environment() {
+    let mut lambdust = Lambdust::new();
+    assert!(lambdust.eval("(eval '(+ 1 2) 42)", Some("<test>")).is_err());
+    assert_eq!(eval(&mut lambdust, "(environment? 42)"), Value::boolean(false));
+}
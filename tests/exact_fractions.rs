@@ -0,0 +1,26 @@
+//! `exact` gives a float's binary fraction and `exact->simplest` the
+//! simplest rational that rounds to it.
+
+mod common;
+
+use common::eval;
+use lambdust::{Lambdust, Value};
+
+#[test]
+fn test_exact_is_the_binary_fraction() {
+    let mut lambdust = Lambdust::new();
+    assert_eq!(eval(&mut lambdust, "(exact 0.1)"), eval(&mut lambdust, "3602879701896397/36028797018963968"));
+    assert_eq!(eval(&mut lambdust, "(exact 0.75)"), eval(&mut lambdust, "3/4"));
+    assert_eq!(eval(&mut lambdust, "(= (inexact (exact 0.1)) 0.1)"), Value::boolean(true));
+}
+
+#[test]
+fn test_exact_to_simplest() {
+    let mut lambdust = Lambdust::new();
+    assert_eq!(eval(&mut lambdust, "(exact->simplest 0.1)"), eval(&mut lambdust, "1/10"));
+    assert_eq!(eval(&mut lambdust, "(exact->simplest -0.1)"), eval(&mut lambdust, "-1/10"));
+    assert_eq!(eval(&mut lambdust, "(exact->simplest 123.456)"), eval(&mut lambdust, "15432/125"));
+    assert_eq!(eval(&mut lambdust, "(exact->simplest 4.0)"), Value::integer(4));
+    assert_eq!(eval(&mut lambdust, "(exact->simplest 2/7)"), eval(&mut lambdust, "2/7"));
+    assert!(lambdust.eval("(exact->simplest 5e-324)", Some("<test>")).is_err());
+}
@@ -0,0 +1,38 @@
+//! `exact-integer-sqrt` and the integer square root behind it.
+
+mod common;
+
+use common::{assert_equal, eval};
+use lambdust::numeric::bigint::BigInt;
+use lambdust::Lambdust;
+
+#[test]
+fn test_exact_integer_sqrt_procedure() {
+    let mut lambdust = Lambdust::new();
+    let cases = [
+        ("0", "'(0 0)"),
+        ("17", "'(4 1)"),
+        ("9223372036854775807", "'(3037000499 5928526806)"),
+        ("(exact 1e22)", "'(100000000000 0)"),
+    ];
+    for (argument, expected) in cases {
+        let source = format!("(call-with-values (lambda () (exact-integer-sqrt {argument})) list)");
+        assert_equal(eval(&mut lambdust, &source), eval(&mut lambdust, expected));
+    }
+
+    assert!(lambdust.eval("(exact-integer-sqrt -1)", Some("<test>")).is_err());
+    assert!(lambdust.eval("(exact-integer-sqrt 4.0)", Some("<test>")).is_err());
+}
+
+#[test]
+fn test_bigint_root_is_exact_past_f64_precision() {
+    let root = &BigInt::from_str_radix(&format!("3{}", "1".repeat(60)), 10).unwrap() + &BigInt::from_i64(9);
+    let square = &root * &root;
+    assert_eq!(square.exact_integer_sqrt(), Some((root.clone(), BigInt::zero())));
+
+    let below = &square - &BigInt::one();
+    let root_below = &root - &BigInt::one();
+    assert_eq!(below.isqrt(), Some(root_below.clone()));
+    assert_eq!(below.exact_integer_sqrt().unwrap().1, &below - &(&root_below * &root_below));
+    assert_eq!(BigInt::from_i64(-9).exact_integer_sqrt(), None);
+}
@@ -0,0 +1,149 @@
+//! `with-exception-handler` semantics: handler chaining, continuable
+//! resumption and restoration of the handler stack around `guard`.
+
+mod common;
+
+use common::{assert_equal, eval};
+use lambdust::{Lambdust, Value};
+
+#[test]
+fn test_raise_continuable_resumes_with_handler_value() {
+    let mut lambdust = Lambdust::new();
+    let result = eval(
+        &mut lambdust,
+        "(with-exception-handler
+           (lambda (e) (* e 10))
+           (lambda () (+ 1 (raise-continuable 4))))",
+    );
+    assert_eq!(result, Value::integer(41));
+}
+
+#[test]
+fn test_inner_handler_reraise_reaches_outer_handler() {
+    let mut lambdust = Lambdust::new();
+    // The inner handler runs with only the outer handler installed, so its
+    // own raise goes outward instead of recursing into itself
+    let result = eval(
+        &mut lambdust,
+        "(with-exception-handler
+           (lambda (e) (* e 2))
+           (lambda ()
+             (with-exception-handler
+               (lambda (e) (+ 1 (raise-continuable (+ e 1))))
+               (lambda () (raise-continuable 10)))))",
+    );
+    assert_eq!(result, Value::integer(23));
+}
+
+#[test]
+fn test_handler_raising_new_error_reaches_outer_handler_once() {
+    let mut lambdust = Lambdust::new();
+    eval(&mut lambdust, "(define inner-calls (vector 0))");
+    // The outer handler passes the condition it was given on to the guard;
+    // the inner handler must run exactly once and never see its own raise
+    let result = eval(
+        &mut lambdust,
+        "(guard (c (#t c))
+           (with-exception-handler
+             (lambda (e) (raise (list 'outer (error-object-message e))))
+             (lambda ()
+               (with-exception-handler
+                 (lambda (e)
+                   (vector-set! inner-calls 0 (+ (vector-ref inner-calls 0) 1))
+                   (error \"handler failed\" e))
+                 (lambda () (raise 'boom))))))",
+    );
+    assert_equal(result, eval(&mut lambdust, "'(outer \"handler failed\")"));
+    assert_eq!(eval(&mut lambdust, "(vector-ref inner-calls 0)"), Value::integer(1));
+    assert_eq!(lambdust.runtime().evaluator().exception_handler_depth(), 0);
+}
+
+#[test]
+fn test_handler_reraising_original_condition_reaches_outer_handler() {
+    let mut lambdust = Lambdust::new();
+    eval(&mut lambdust, "(define original (list 'original))");
+    let result = eval(
+        &mut lambdust,
+        "(guard (c (#t c))
+           (with-exception-handler
+             (lambda (e) (raise (eq? e original)))
+             (lambda ()
+               (with-exception-handler
+                 (lambda (e) (raise e))
+                 (lambda () (raise original))))))",
+    );
+    assert_eq!(result, Value::boolean(true));
+    assert_eq!(lambdust.runtime().evaluator().exception_handler_depth(), 0);
+}
+
+#[test]
+fn test_non_continuable_reraise_reaches_enclosing_guard() {
+    let mut lambdust = Lambdust::new();
+    let result = eval(
+        &mut lambdust,
+        "(guard (e ((pair? e) (cadr e)))
+           (with-exception-handler
+             (lambda (e) (raise (list 'wrapped e)))
+             (lambda () (raise 'boom))))",
+    );
+    assert_eq!(result, Value::symbol_from_str("boom"));
+}
+
+#[test]
+fn test_handler_returning_from_raise_is_an_error() {
+    let mut lambdust = Lambdust::new();
+    let result = eval(
+        &mut lambdust,
+        "(guard (e ((error-object? e) 'secondary))
+           (with-exception-handler
+             (lambda (e) 0)
+             (lambda () (raise 'not-continuable))))",
+    );
+    assert_eq!(result, Value::symbol_from_str("secondary"));
+
+    assert!(lambdust
+        .eval(
+            "(with-exception-handler (lambda (e) 0) (lambda () (raise 'oops)))",
+            Some("<test>"),
+        )
+        .is_err());
+}
+
+#[test]
+fn test_handler_stack_restored_after_guard() {
+    let mut lambdust = Lambdust::new();
+    // The guard catches the first raise; afterwards the outer handler is
+    // current again and handles the continuable raise
+    let result = eval(
+        &mut lambdust,
+        "(with-exception-handler
+           (lambda (e) 100)
+           (lambda ()
+             (+ (guard (e (#t 1)) (raise 'inner))
+                (raise-continuable 'outer))))",
+    );
+    assert_eq!(result, Value::integer(101));
+
+    // A guard with no matching clause re-raises to the outer handler
+    let result = eval(
+        &mut lambdust,
+        "(with-exception-handler
+           (lambda (e) (if (eq? e 'unmatched) 7 0))
+           (lambda () (guard (e ((string? e) 'wrong)) (raise-continuable 'unmatched))))",
+    );
+    assert_eq!(result, Value::integer(7));
+
+    assert_eq!(lambdust.runtime().evaluator().exception_handler_depth(), 0);
+}
+
+#[test]
+fn test_handler_stack_restored_after_error_escapes() {
+    let mut lambdust = Lambdust::new();
+    assert!(lambdust
+        .eval(
+            "(with-exception-handler (lambda (e) (raise e)) (lambda () (raise 'escape)))",
+            Some("<test>"),
+        )
+        .is_err());
+    assert_eq!(lambdust.runtime().evaluator().exception_handler_depth(), 0);
+}
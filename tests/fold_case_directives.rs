@@ -0,0 +1,50 @@
+//! `#!fold-case` and `#!no-fold-case` directives read from ports.
+
+mod common;
+
+use common::eval;
+use lambdust::{Lambdust, Value};
+
+#[test]
+fn test_fold_case_directive_folds_identifiers() {
+    let mut lambdust = Lambdust::new();
+    assert_eq!(
+        eval(&mut lambdust, "(read (open-input-string \"#!fold-case FOO\"))"),
+        Value::symbol_from_str("foo")
+    );
+    assert_eq!(
+        eval(&mut lambdust, "(read (open-input-string \"#!no-fold-case FOO\"))"),
+        Value::symbol_from_str("FOO")
+    );
+    assert_eq!(eval(&mut lambdust, "(read (open-input-string \"FOO\"))"), Value::symbol_from_str("FOO"));
+}
+
+#[test]
+fn test_fold_case_persists_across_reads() {
+    let mut lambdust = Lambdust::new();
+    eval(&mut lambdust, "(define p (open-input-string \"Abc #!fold-case Def (GHI \\\"STR\\\") #!no-fold-case Jkl\"))");
+    assert_eq!(eval(&mut lambdust, "(read p)"), Value::symbol_from_str("Abc"));
+    assert_eq!(eval(&mut lambdust, "(read p)"), Value::symbol_from_str("def"));
+    // Strings are data, not identifiers
+    assert_eq!(eval(&mut lambdust, "(read p)"), eval(&mut lambdust, "'(ghi \"STR\")"));
+    assert_eq!(eval(&mut lambdust, "(read p)"), Value::symbol_from_str("Jkl"));
+    assert_eq!(eval(&mut lambdust, "(eof-object? (read p))"), Value::boolean(true));
+}
+
+#[test]
+fn test_directive_mid_datum_and_bar_symbols() {
+    let mut lambdust = Lambdust::new();
+    assert_eq!(
+        eval(&mut lambdust, "(read (open-input-string \"(A #!fold-case B |C|)\"))"),
+        Value::list(vec![
+            Value::symbol_from_str("A"),
+            Value::symbol_from_str("b"),
+            Value::symbol_from_str("C"),
+        ])
+    );
+    // A trailing directive leaves nothing to read
+    assert_eq!(
+        eval(&mut lambdust, "(eof-object? (read (open-input-string \"#!fold-case\")))"),
+        Value::boolean(true)
+    );
+}
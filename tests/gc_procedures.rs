@@ -0,0 +1,34 @@
+//! `collect-garbage`, `gc-statistics` and `set-gc-config!` act on the
+//! collector owned by the evaluator running them.
+
+mod common;
+
+use common::eval;
+use lambdust::{Lambdust, Value};
+
+fn statistic(lambdust: &mut Lambdust, key: &str) -> Value {
+    eval(lambdust, &format!("(cdr (assq '{key} (gc-statistics)))"))
+}
+
+#[test]
+fn test_collections_are_counted_per_interpreter() {
+    let mut lambdust = Lambdust::new();
+    assert_eq!(statistic(&mut lambdust, "major-collections"), Value::integer(0));
+
+    eval(&mut lambdust, "(collect-garbage)");
+    eval(&mut lambdust, "(collect-garbage 'major)");
+    assert_eq!(statistic(&mut lambdust, "major-collections"), Value::integer(2));
+
+    let mut other = Lambdust::new();
+    assert_eq!(statistic(&mut other, "major-collections"), Value::integer(0));
+}
+
+#[test]
+fn test_gc_config_is_visible_in_statistics() {
+    let mut lambdust = Lambdust::new();
+    eval(&mut lambdust, "(set-gc-config! 'target-major-pause-ms 40)");
+    assert_eq!(statistic(&mut lambdust, "target-major-pause-ms"), Value::integer(40));
+
+    assert!(lambdust.eval("(set-gc-config! 'heap-size 1)", Some("<test>")).is_err());
+    assert!(lambdust.eval("(collect-garbage 'tiny)", Some("<test>")).is_err());
+}
@@ -0,0 +1,53 @@
+//! `make-generator`, resuming a body between successive `yield`s.
+
+mod common;
+
+use common::eval;
+use lambdust::{Lambdust, Value};
+
+#[test]
+fn test_range_generator_collects_yielded_values() {
+    let mut lambdust = Lambdust::new();
+    eval(
+        &mut lambdust,
+        "(define (range-generator start end)
+           (make-generator
+             (lambda (yield)
+               (do ((i start (+ i 1))) ((= i end)) (yield i)))))",
+    );
+
+    assert_eq!(
+        eval(&mut lambdust, "(generator->list (range-generator 0 5))"),
+        Value::list((0..5).map(Value::integer).collect())
+    );
+    assert_eq!(
+        eval(&mut lambdust, "(generator->list (range-generator 0 5) 2)"),
+        Value::list((0..2).map(Value::integer).collect())
+    );
+}
+
+#[test]
+fn test_finished_generator_keeps_returning_eof() {
+    let mut lambdust = Lambdust::new();
+    eval(&mut lambdust, "(define gen (make-generator (lambda (yield) (yield 'only))))");
+
+    assert_eq!(eval(&mut lambdust, "(gen)"), Value::symbol_from_str("only"));
+    for _ in 0..3 {
+        assert_eq!(eval(&mut lambdust, "(eof-object? (gen))"), Value::boolean(true));
+    }
+}
+
+#[test]
+fn test_generator_that_never_yields() {
+    let mut lambdust = Lambdust::new();
+    eval(&mut lambdust, "(define gen (make-generator (lambda (yield) (+ 1 2))))");
+
+    assert_eq!(eval(&mut lambdust, "(eof-object? (gen))"), Value::boolean(true));
+    assert_eq!(eval(&mut lambdust, "(eof-object? (gen))"), Value::boolean(true));
+}
+
+#[test]
+fn test_make_generator_rejects_non_procedures() {
+    let mut lambdust = Lambdust::new();
+    assert!(lambdust.eval("(make-generator 42)", Some("<test>")).is_err());
+}
@@ -0,0 +1,63 @@
+//! `guard` unwinds like an escape-only continuation, so exception-heavy
+//! loops stay bounded, while `raise-continuable` still resumes.
+
+mod common;
+
+use common::{assert_equal, eval};
+use lambdust::{Lambdust, Value};
+
+fn stack_depth(lambdust: &Lambdust) -> usize {
+    lambdust.runtime().evaluator().stack_trace().len()
+}
+
+#[test]
+fn test_guard_catches_from_nested_calls() {
+    let mut lambdust = Lambdust::new();
+    eval(&mut lambdust, "(define (deep n) (if (= n 0) (raise 'bottom) (+ 1 (deep (- n 1)))))");
+    assert_equal(
+        eval(&mut lambdust, "(guard (e ((symbol? e) (list 'caught e))) (deep 50))"),
+        eval(&mut lambdust, "'(caught bottom)")
+    );
+    // With no matching clause the condition escapes the guard
+    assert!(lambdust.eval("(guard (e ((string? e) 'never)) (deep 3))", Some("<test>")).is_err());
+    assert_eq!(lambdust.runtime().evaluator().exception_handler_depth(), 0);
+}
+
+#[test]
+fn test_guarded_raise_loop_does_not_grow() {
+    let mut lambdust = Lambdust::new();
+    eval(
+        &mut lambdust,
+        "(define (count-raises-from i n caught)
+           (if (= i n)
+               caught
+               (count-raises-from (+ i 1) n (+ caught (guard (e ((number? e) 1)) (raise i))))))",
+    );
+    eval(&mut lambdust, "(define (count-raises n) (guard (e (#t 'outer)) (count-raises-from 0 n 0)))");
+
+    let before = stack_depth(&lambdust);
+    assert_eq!(eval(&mut lambdust, "(count-raises 10)"), Value::integer(10));
+    let after_few = stack_depth(&lambdust) - before;
+
+    let before = stack_depth(&lambdust);
+    assert_eq!(eval(&mut lambdust, "(count-raises 20000)"), Value::integer(20000));
+    let after_many = stack_depth(&lambdust) - before;
+
+    // Everything the guarded body pushed is discarded when the guard exits
+    assert_eq!(after_few, after_many);
+    assert_eq!(lambdust.runtime().evaluator().exception_handler_depth(), 0);
+}
+
+#[test]
+fn test_raise_continuable_still_resumes() {
+    let mut lambdust = Lambdust::new();
+    assert_eq!(
+        eval(
+            &mut lambdust,
+            "(with-exception-handler
+               (lambda (e) (* e 10))
+               (lambda () (+ 1 (raise-continuable 4))))",
+        ),
+        Value::integer(41)
+    );
+}
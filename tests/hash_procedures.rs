@@ -0,0 +1,30 @@
+//! `equal-hash`, `string-hash`, `symbol-hash` and `number-hash`.
+
+mod common;
+
+use common::eval;
+use lambdust::{Lambdust, Value};
+
+#[test]
+fn test_equal_values_hash_alike() {
+    let mut lambdust = Lambdust::new();
+    let cases = [
+        "(= (equal-hash (list \"key\" (vector 1 'nested))) (equal-hash (list \"key\" (vector 1 'nested))))",
+        "(= (string-hash \"hello\") (equal-hash \"hello\"))",
+        "(= (string-hash (string-copy \"hello\")) (string-hash \"hello\"))",
+        "(= (symbol-hash 'hashed) (equal-hash 'hashed))",
+        "(= (number-hash 7) (equal-hash 7))",
+        "(exact-integer? (equal-hash '(1 2)))",
+    ];
+    for source in cases {
+        assert_eq!(eval(&mut lambdust, source), Value::boolean(true), "{source}");
+    }
+}
+
+#[test]
+fn test_typed_hashes_check_their_argument() {
+    let mut lambdust = Lambdust::new();
+    for source in ["(string-hash 1)", "(symbol-hash \"a\")", "(number-hash 'a)"] {
+        assert!(lambdust.eval(source, Some("<test>")).is_err(), "{source}");
+    }
+}
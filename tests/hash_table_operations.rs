@@ -0,0 +1,56 @@
+//! Clearing, copying, sizing and rehash-threshold tuning of hash tables.
+
+use lambdust::containers::{HashComparator, HashTable, ThreadSafeHashTable};
+use lambdust::Value;
+
+#[test]
+fn test_copy_does_not_share_entries() {
+    let mut table = HashTable::with_load_factor(16, HashComparator::with_default(), 0.5).unwrap();
+    table.hash_table_set(&[(Value::string("a"), Value::integer(1))]).unwrap();
+
+    let mut copy = table.hash_table_copy(true);
+    assert_eq!(copy.rehash_threshold(), 0.5);
+    copy.hash_table_set(&[(Value::string("a"), Value::integer(10)), (Value::string("b"), Value::integer(2))]).unwrap();
+    assert_eq!(table.get(&Value::string("a")), Some(&Value::integer(1)));
+    assert_eq!(table.hash_table_size(), 1);
+    assert_eq!(copy.hash_table_size(), 2);
+
+    let mut frozen = table.hash_table_copy(false);
+    assert!(frozen.hash_table_clear().is_err());
+    assert_eq!(frozen.hash_table_size(), 1);
+
+    let shared = ThreadSafeHashTable::new();
+    shared.insert(Value::string("k"), Value::integer(1));
+    shared.copy(true).insert(Value::string("k"), Value::integer(2));
+    assert_eq!(shared.get(&Value::string("k")), Some(Value::integer(1)));
+}
+
+#[test]
+fn test_clear_keeps_capacity() {
+    let mut table = HashTable::with_capacity(4);
+    for i in 0..20 {
+        table.insert(Value::integer(i), Value::integer(i));
+    }
+    let capacity = table.capacity();
+
+    table.hash_table_clear().unwrap();
+    assert!(table.hash_table_empty());
+    assert_eq!(table.capacity(), capacity);
+    table.insert(Value::integer(3), Value::integer(-3));
+    assert_eq!(table.get(&Value::integer(3)), Some(&Value::integer(-3)));
+}
+
+#[test]
+fn test_rehash_threshold_controls_growth() {
+    assert!(HashTable::with_load_factor(8, HashComparator::with_default(), 0.0).is_err());
+
+    let mut table = HashTable::with_load_factor(8, HashComparator::with_default(), 1.0).unwrap();
+    for i in 0..8 {
+        table.insert(Value::integer(i), Value::integer(i));
+    }
+    assert_eq!(table.capacity(), 8);
+
+    table.set_rehash_threshold(0.5).unwrap();
+    table.insert(Value::integer(8), Value::integer(8));
+    assert!(table.capacity() > 8);
+}
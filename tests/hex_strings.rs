@@ -0,0 +1,30 @@
+//! `bytevector->hex-string` and `hex-string->bytevector`.
+
+mod common;
+
+use common::eval;
+use lambdust::{Lambdust, Value};
+
+#[test]
+fn test_round_trip_with_and_without_delimiter() {
+    let mut lambdust = Lambdust::new();
+    assert_eq!(eval(&mut lambdust, "(bytevector->hex-string #u8(0 127 171 255))"), Value::string("007fabff"));
+    assert_eq!(eval(&mut lambdust, "(bytevector->hex-string #u8(0 127) \":\")"), Value::string("00:7f"));
+    assert_eq!(
+        eval(&mut lambdust, "(equal? (hex-string->bytevector \"00:7f:ab\" \":\") #u8(0 127 171))"),
+        Value::boolean(true)
+    );
+    assert_eq!(
+        eval(&mut lambdust, "(equal? (hex-string->bytevector \"00 7F\\nAB\") #u8(0 127 171))"),
+        Value::boolean(true)
+    );
+    assert_eq!(eval(&mut lambdust, "(bytevector->hex-string #u8())"), Value::string(""));
+}
+
+#[test]
+fn test_malformed_hex_is_rejected() {
+    let mut lambdust = Lambdust::new();
+    for source in ["(hex-string->bytevector \"abc\")", "(hex-string->bytevector \"zz\")"] {
+        assert!(lambdust.eval(source, Some("<test>")).is_err(), "{source}");
+    }
+}
@@ -0,0 +1,40 @@
+//! Nested R7RS import sets, renaming and conflicts between imports.
+
+mod common;
+
+use common::eval;
+use lambdust::{Lambdust, Value};
+
+#[test]
+fn test_nested_import_set_binds_only_prefixed_names() {
+    let mut lambdust = Lambdust::new();
+    eval(&mut lambdust, "(import (prefix (only (scheme base) car cdr) base-))");
+
+    assert_eq!(eval(&mut lambdust, "(base-car '(1 2))"), Value::integer(1));
+    assert_eq!(eval(&mut lambdust, "(base-car (base-cdr '(1 2)))"), Value::integer(2));
+    assert!(lambdust.eval("(base-cons 1 2)", Some("<test>")).is_err());
+}
+
+#[test]
+fn test_rename_import_set() {
+    let mut lambdust = Lambdust::new();
+    eval(&mut lambdust, "(import (rename (only (scheme base) car cdr) (car head)))");
+
+    assert_eq!(eval(&mut lambdust, "(head '(1 2))"), Value::integer(1));
+    assert!(lambdust.eval("(import (rename (only (scheme base) cdr) (car head)))", Some("<test>")).is_err());
+}
+
+#[test]
+fn test_same_name_from_two_import_sets_conflicts_unless_renamed() {
+    let mut lambdust = Lambdust::new();
+    let conflicting = "(import (rename (only (scheme base) car) (car head))
+                               (rename (only (scheme base) cdr) (cdr head)))";
+    assert!(lambdust.eval(conflicting, Some("<test>")).is_err());
+
+    eval(
+        &mut lambdust,
+        "(import (rename (only (scheme base) car) (car head))
+                 (rename (only (scheme base) cdr) (cdr tail)))",
+    );
+    assert_eq!(eval(&mut lambdust, "(tail '(1 2))"), eval(&mut lambdust, "'(2)"));
+}
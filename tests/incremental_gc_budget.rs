@@ -0,0 +1,49 @@
+//! Incremental collection steps stay within their wall-clock budget.
+
+use lambdust::runtime::gc::{GcConfigBuilder, GcSystem, ObjectHeader};
+use lambdust::Value;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+#[test]
+fn test_budget_is_adjustable() {
+    let gc_system = GcSystem::new(GcConfigBuilder::new().build()).unwrap();
+    assert_eq!(gc_system.incremental_budget(), Duration::from_millis(1));
+
+    gc_system.set_incremental_budget(Duration::from_micros(200));
+    assert_eq!(gc_system.incremental_budget(), Duration::from_micros(200));
+}
+
+#[test]
+fn test_marking_is_spread_over_bounded_steps() {
+    let config = GcConfigBuilder::new()
+        .young_generation_mb(16)
+        .old_generation_mb(64)
+        .build();
+    let gc_system = GcSystem::new(config).unwrap();
+
+    let headers: Vec<_> = (0..10_000)
+        .map(|i| gc_system.allocate(Value::integer(i), 16).unwrap())
+        .collect();
+    for header in &headers {
+        gc_system.root_set.add_global_root(Arc::as_ptr(header) as *mut ObjectHeader).unwrap();
+    }
+
+    let budget = Duration::from_micros(50);
+    gc_system.set_incremental_budget(budget);
+
+    let mut steps = 0;
+    loop {
+        let start = Instant::now();
+        let complete = gc_system.collect_incremental_step().unwrap();
+        assert!(start.elapsed() < budget + Duration::from_millis(20));
+        steps += 1;
+        if complete {
+            break;
+        }
+        assert!(steps < 1_000_000, "incremental collection never completed");
+    }
+
+    assert!(steps > 8, "marking finished in a single step");
+    assert!(headers.iter().all(|header| header.is_marked()));
+}
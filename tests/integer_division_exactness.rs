@@ -0,0 +1,46 @@
+//! Integer division stays exact for exact operands and is inexact otherwise.
+
+mod common;
+
+use common::eval;
+use lambdust::{Lambdust, Value};
+
+#[test]
+fn test_exact_operands_give_exact_results() {
+    let mut lambdust = Lambdust::new();
+    let cases = [
+        ("(quotient 7 2)", 3),
+        ("(remainder 7 2)", 1),
+        ("(modulo -7 2)", 1),
+        ("(floor-quotient -7 2)", -4),
+        ("(truncate-quotient -7 2)", -3),
+    ];
+    for (source, expected) in cases {
+        assert_eq!(eval(&mut lambdust, source), Value::integer(expected), "{source}");
+        assert_eq!(eval(&mut lambdust, &format!("(exact? {source})")), Value::boolean(true), "{source}");
+    }
+    assert_eq!(eval(&mut lambdust, "(quotient (exact 1e22) 7)").to_string(), "1428571428571428571428");
+}
+
+#[test]
+fn test_inexact_operands_give_inexact_results() {
+    let mut lambdust = Lambdust::new();
+    let cases = [
+        ("(quotient 7.0 2)", "3.0"),
+        ("(remainder 7 2.0)", "1.0"),
+        ("(modulo -7.0 2)", "1.0"),
+        ("(floor-quotient -7.0 2)", "-4.0"),
+    ];
+    for (source, expected) in cases {
+        assert_eq!(eval(&mut lambdust, source), eval(&mut lambdust, expected), "{source}");
+        assert_eq!(eval(&mut lambdust, &format!("(inexact? {source})")), Value::boolean(true), "{source}");
+    }
+    assert!(lambdust.eval("(quotient 7.0 0)", Some("<test>")).is_err());
+}
+
+#[test]
+fn test_overflowing_fixnum_quotient_becomes_big() {
+    let mut lambdust = Lambdust::new();
+    let quotient = eval(&mut lambdust, "(quotient (- (- (expt 2 62)) (expt 2 62)) -1)");
+    assert_eq!(quotient.to_string(), "9223372036854775808");
+}
@@ -0,0 +1,45 @@
+//! `JitCompiler::precompile` warms up functions before they run.
+
+use lambdust::eval::Environment;
+use lambdust::jit::{CompilationResult, CompilationTier, JitCompiler};
+use lambdust::{Expr, Lexer, Parser};
+use std::sync::Arc;
+use std::time::Duration;
+
+fn parse(source: &str) -> Expr {
+    let tokens = Lexer::new(source, Some("test")).tokenize().unwrap();
+    Parser::new(tokens).parse_expression().unwrap().inner
+}
+
+// The compiler takes its environment behind an Arc even though
+// environments are single-threaded
+#[allow(clippy::arc_with_non_send_sync)]
+fn empty_env() -> Arc<Environment> {
+    Arc::new(Environment::new(None, 0))
+}
+
+#[test]
+fn test_precompiled_code_is_cached() {
+    let jit = JitCompiler::new().unwrap();
+    let env = empty_env();
+    let square = parse("(lambda (x) (* x x))");
+
+    assert!(jit.get_compiled_code(&square).unwrap().is_none());
+    let result = jit.precompile("square", &square, &env, CompilationTier::JitBasic).unwrap();
+    assert!(matches!(result, CompilationResult::Success { .. }));
+    assert!(jit.get_compiled_code(&square).unwrap().is_some());
+
+    let again = jit.precompile("square", &square, &env, CompilationTier::JitBasic).unwrap();
+    assert!(matches!(again, CompilationResult::Success { compilation_time, .. } if compilation_time == Duration::ZERO));
+}
+
+#[test]
+fn test_unsuitable_expressions_fail() {
+    let jit = JitCompiler::new().unwrap();
+    let env = empty_env();
+    let constant = parse("\"not a function\"");
+
+    let result = jit.precompile("constant", &constant, &env, CompilationTier::JitBasic).unwrap();
+    assert!(matches!(result, CompilationResult::Failed { ref error, .. } if error.contains("not compilable")));
+    assert!(jit.get_compiled_code(&constant).unwrap().is_none());
+}
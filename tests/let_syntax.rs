@@ -0,0 +1,45 @@
+//! `let-syntax` and `letrec-syntax` macro bindings scoped to their body.
+
+mod common;
+
+use common::{assert_equal, eval};
+use lambdust::{Lambdust, Value};
+
+#[test]
+fn test_let_syntax_binds_macro_in_body() {
+    let mut lambdust = Lambdust::new();
+    assert_eq!(
+        eval(&mut lambdust, "(let-syntax ((double (syntax-rules () ((_ x) (+ x x))))) (double 21))"),
+        Value::integer(42)
+    );
+}
+
+#[test]
+fn test_letrec_syntax_sees_sibling_macros() {
+    let mut lambdust = Lambdust::new();
+    assert_eq!(
+        eval(
+            &mut lambdust,
+            "(letrec-syntax ((double (syntax-rules () ((_ x) (add x x))))
+                             (add (syntax-rules () ((_ a b) (+ a b)))))
+               (double 21))"
+        ),
+        Value::integer(42)
+    );
+}
+
+#[test]
+fn test_let_syntax_binding_is_not_visible_after_body() {
+    let mut lambdust = Lambdust::new();
+    eval(&mut lambdust, "(let-syntax ((triple (syntax-rules () ((_ x) (* 3 x))))) (triple 1))");
+    assert!(lambdust.eval("(triple 2)", Some("<test>")).is_err());
+}
+
+#[test]
+fn test_let_syntax_shadows_special_form() {
+    let mut lambdust = Lambdust::new();
+    assert_equal(
+        eval(&mut lambdust, "(let-syntax ((when (syntax-rules () ((_ c e) (list c e))))) (when #f 1))"),
+        Value::list(vec![Value::boolean(false), Value::integer(1)])
+    );
+}
@@ -0,0 +1,65 @@
+//! Modifying freshly built lists with `list-set!`, `set-car!` and `set-cdr!`.
+
+mod common;
+
+use common::{assert_equal, eval};
+use lambdust::{Lambdust, Value};
+
+#[test]
+fn test_list_set_on_fresh_lists() {
+    let mut lambdust = Lambdust::new();
+    eval(&mut lambdust, "(define l (list 1 2 3))");
+    eval(&mut lambdust, "(list-set! l 1 'two)");
+    assert_eq!(eval(&mut lambdust, "(equal? l '(1 two 3))"), Value::boolean(true));
+
+    eval(&mut lambdust, "(define m (make-list 2 0))");
+    eval(&mut lambdust, "(list-set! m 0 'first)");
+    assert_eq!(eval(&mut lambdust, "(car m)"), Value::symbol_from_str("first"));
+
+    eval(&mut lambdust, "(define c (list-copy '(a b c)))");
+    eval(&mut lambdust, "(list-set! c 2 'z)");
+    assert_eq!(eval(&mut lambdust, "(equal? c '(a b z))"), Value::boolean(true));
+}
+
+#[test]
+fn test_set_car_and_set_cdr_on_fresh_lists() {
+    let mut lambdust = Lambdust::new();
+    eval(&mut lambdust, "(define l (list 1 2 3))");
+    eval(&mut lambdust, "(set-car! (cdr l) 20)");
+    eval(&mut lambdust, "(set-cdr! (cdr (cdr l)) (list 4))");
+    assert_eq!(eval(&mut lambdust, "(equal? l '(1 20 3 4))"), Value::boolean(true));
+    assert_eq!(eval(&mut lambdust, "(length l)"), Value::integer(4));
+}
+
+#[test]
+fn test_fresh_lists_work_with_list_procedures() {
+    let mut lambdust = Lambdust::new();
+    eval(&mut lambdust, "(define l (list 'a 'b (list 'c)))");
+    assert_eq!(eval(&mut lambdust, "(car (memq 'b l))"), Value::symbol_from_str("b"));
+    assert_eq!(eval(&mut lambdust, "(pair? (member (list 'c) l))"), Value::boolean(true));
+    assert_eq!(
+        eval(&mut lambdust, "(cdr (assq 'y (list (cons 'x 1) (cons 'y 2))))"),
+        Value::integer(2)
+    );
+    assert_eq!(eval(&mut lambdust, "(equal? (reverse l) '((c) b a))"), Value::boolean(true));
+    assert_eq!(eval(&mut lambdust, "(equal? (append l '(d)) '(a b (c) d))"), Value::boolean(true));
+}
+
+#[test]
+fn test_literal_lists_stay_immutable() {
+    let mut lambdust = Lambdust::new();
+    let result = lambdust.eval("(list-set! '(1 2 3) 0 'x)", Some("<test>"));
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_fresh_lists_are_distinct_objects() {
+    let mut lambdust = Lambdust::new();
+    eval(&mut lambdust, "(define l (list 1 2))");
+    assert_eq!(eval(&mut lambdust, "l"), eval(&mut lambdust, "l"));
+    assert_ne!(eval(&mut lambdust, "l"), eval(&mut lambdust, "(list 1 2)"));
+    assert_equal(
+        eval(&mut lambdust, "(list (eqv? l l) (eqv? l (list 1 2)) (equal? l (list 1 2)))"),
+        eval(&mut lambdust, "'(#t #f #t)")
+    );
+}
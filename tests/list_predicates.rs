@@ -0,0 +1,24 @@
+//! `list?`, `circular-list?` and `dotted-list?` on proper and improper lists.
+
+mod common;
+
+use common::eval;
+use lambdust::{Lambdust, Value};
+
+#[test]
+fn test_proper_list() {
+    let mut lambdust = Lambdust::new();
+    assert_eq!(eval(&mut lambdust, "(list? '(1 2 3))"), Value::boolean(true));
+    assert_eq!(eval(&mut lambdust, "(list? '())"), Value::boolean(true));
+    assert_eq!(eval(&mut lambdust, "(circular-list? '(1 2 3))"), Value::boolean(false));
+    assert_eq!(eval(&mut lambdust, "(dotted-list? '(1 2 3))"), Value::boolean(false));
+}
+
+#[test]
+fn test_dotted_list() {
+    let mut lambdust = Lambdust::new();
+    assert_eq!(eval(&mut lambdust, "(list? (cons 1 (cons 2 3)))"), Value::boolean(false));
+    assert_eq!(eval(&mut lambdust, "(dotted-list? (cons 1 (cons 2 3)))"), Value::boolean(true));
+    assert_eq!(eval(&mut lambdust, "(dotted-list? (cons 1 2))"), Value::boolean(true));
+    assert_eq!(eval(&mut lambdust, "(circular-list? (cons 1 2))"), Value::boolean(false));
+}
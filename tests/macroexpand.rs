@@ -0,0 +1,83 @@
+//! `macroexpand` and `macroexpand-1` on quoted forms.
+
+mod common;
+
+use common::eval;
+use lambdust::{Lambdust, Value};
+
+fn define_my_list(lambdust: &mut Lambdust) {
+    eval(lambdust, "(define-syntax my-list (syntax-rules () ((_ x ...) (list x ...))))");
+}
+
+#[test]
+fn test_quoted_keywords_are_plain_data() {
+    let mut lambdust = Lambdust::new();
+    assert_eq!(eval(&mut lambdust, "(car '(if a b))"), Value::symbol_from_str("if"));
+    assert_eq!(eval(&mut lambdust, "(length (quote (lambda (x) x)))"), Value::integer(3));
+    assert_eq!(
+        eval(&mut lambdust, "''a"),
+        Value::list(vec![Value::symbol_from_str("quote"), Value::symbol_from_str("a")])
+    );
+}
+
+#[test]
+fn test_macroexpand_1_expands_when_one_step() {
+    let mut lambdust = Lambdust::new();
+    assert_eq!(
+        eval(&mut lambdust, "(macroexpand-1 '(when (> x 0) (display x) x))"),
+        eval(&mut lambdust, "'(if (> x 0) (begin (display x) x))")
+    );
+}
+
+#[test]
+fn test_macroexpand_expands_when_fully() {
+    let mut lambdust = Lambdust::new();
+    assert_eq!(
+        eval(&mut lambdust, "(macroexpand '(when (> x 0) (display x) x))"),
+        eval(&mut lambdust, "'(if (> x 0) ((lambda () (display x) x)))")
+    );
+}
+
+#[test]
+fn test_syntax_rules_macro_one_step_and_fully() {
+    let mut lambdust = Lambdust::new();
+    define_my_list(&mut lambdust);
+
+    // One step leaves the nested use alone
+    assert_eq!(
+        eval(&mut lambdust, "(macroexpand-1 '(my-list 1 (my-list 2 3)))"),
+        eval(&mut lambdust, "'(list 1 (my-list 2 3))")
+    );
+    assert_eq!(
+        eval(&mut lambdust, "(macroexpand '(my-list 1 (my-list 2 3)))"),
+        eval(&mut lambdust, "'(list 1 (list 2 3))")
+    );
+}
+
+#[test]
+fn test_macroexpand_reaches_deeply_nested_uses() {
+    let mut lambdust = Lambdust::new();
+    define_my_list(&mut lambdust);
+    assert_eq!(
+        eval(&mut lambdust, "(macroexpand '(f (g (my-list (when a b)))))"),
+        eval(&mut lambdust, "'(f (g (list (if a ((lambda () b))))))")
+    );
+}
+
+#[test]
+fn test_non_macro_forms_are_unchanged() {
+    let mut lambdust = Lambdust::new();
+    assert_eq!(eval(&mut lambdust, "(macroexpand '(+ 1 2))"), eval(&mut lambdust, "'(+ 1 2)"));
+    assert_eq!(eval(&mut lambdust, "(macroexpand 'x)"), Value::symbol_from_str("x"));
+    assert_eq!(eval(&mut lambdust, "(macroexpand 42)"), Value::integer(42));
+    // Only the head of the form is expanded by macroexpand-1
+    assert_eq!(
+        eval(&mut lambdust, "(macroexpand-1 '(+ 1 (when a b)))"),
+        eval(&mut lambdust, "'(+ 1 (when a b))")
+    );
+    // Quoted data is not expanded
+    assert_eq!(
+        eval(&mut lambdust, "(macroexpand '(quote (when a b)))"),
+        eval(&mut lambdust, "''(when a b)")
+    );
+}
@@ -0,0 +1,26 @@
+//! `max` and `min` exactness contagion and NaN propagation.
+
+mod common;
+
+use common::eval;
+use lambdust::{Lambdust, Value};
+
+#[test]
+fn test_inexact_argument_makes_result_inexact() {
+    let mut lambdust = Lambdust::new();
+    assert_eq!(eval(&mut lambdust, "(max 3 4.0)"), eval(&mut lambdust, "4.0"));
+    assert_eq!(eval(&mut lambdust, "(max 4 3.0)"), eval(&mut lambdust, "4.0"));
+    assert_eq!(eval(&mut lambdust, "(min 1.0 0)"), eval(&mut lambdust, "0.0"));
+    assert_eq!(eval(&mut lambdust, "(apply max '(1 7 3))"), Value::integer(7));
+    assert_eq!(eval(&mut lambdust, "(min 1/2 1/3)"), eval(&mut lambdust, "1/3"));
+}
+
+#[test]
+fn test_nan_propagates() {
+    let mut lambdust = Lambdust::new();
+    for source in ["(nan? (max +nan.0 1))", "(nan? (min 1 +nan.0))"] {
+        assert_eq!(eval(&mut lambdust, source), Value::boolean(true), "{source}");
+    }
+    assert!(lambdust.eval("(max)", Some("<test>")).is_err());
+    assert!(lambdust.eval("(max \"a\")", Some("<test>")).is_err());
+}
@@ -0,0 +1,33 @@
+//! The adaptive minor collection trigger rate-limits collections below the
+//! young generation's high-water mark.
+
+use lambdust::runtime::gc::{GcConfigBuilder, MinorCollectionTrigger};
+use std::time::{Duration, Instant};
+
+#[test]
+fn test_collects_early_only_under_allocation_pressure() {
+    let config = GcConfigBuilder::new().build();
+    let trigger = MinorCollectionTrigger::from_config(&config);
+    let now = Instant::now();
+    let soon = Some(Duration::from_micros(1));
+
+    assert!(!trigger.should_collect(now, 0.5, soon));
+    assert!(trigger.should_collect(now, 0.85, soon));
+    assert!(!trigger.should_collect(now, 0.85, Some(Duration::from_secs(5))));
+    assert!(trigger.should_collect(now, config.young_high_water, None));
+}
+
+#[test]
+fn test_bursts_wait_for_the_minimum_interval() {
+    let config = GcConfigBuilder::new().build();
+    let mut trigger = MinorCollectionTrigger::from_config(&config);
+    let start = Instant::now();
+    let soon = Some(Duration::from_micros(1));
+    let interval = Duration::from_millis(config.min_minor_interval_ms);
+
+    trigger.record_collection(start);
+    assert!(!trigger.should_collect(start + interval / 2, 0.85, soon));
+    assert!(trigger.should_collect(start + interval, 0.85, soon));
+    // The high-water mark is never rate-limited
+    assert!(trigger.should_collect(start + interval / 2, config.young_high_water, soon));
+}
@@ -0,0 +1,88 @@
+//! `values`, `call-with-values` and continuations invoked with several values.
+
+mod common;
+
+use common::{assert_equal, eval};
+use lambdust::{Lambdust, Value};
+
+fn list(values: &[i64]) -> Value {
+    Value::list(values.iter().map(|&n| Value::integer(n)).collect())
+}
+
+#[test]
+fn test_call_with_values_spreads_values() {
+    let mut lambdust = Lambdust::new();
+    assert_equal(
+        eval(&mut lambdust, "(call-with-values (lambda () (values 1 2 3)) list)"),
+        list(&[1, 2, 3])
+    );
+    assert_equal(eval(&mut lambdust, "(call-with-values (lambda () 4) list)"), list(&[4]));
+}
+
+#[test]
+fn test_continuation_accepts_several_values() {
+    let mut lambdust = Lambdust::new();
+    assert_equal(
+        eval(&mut lambdust, "(call-with-values (lambda () (call/cc (lambda (k) (k 1 2)))) list)"),
+        list(&[1, 2])
+    );
+}
+
+#[test]
+fn test_continuation_accepts_zero_values() {
+    let mut lambdust = Lambdust::new();
+    assert_eq!(
+        eval(&mut lambdust, "(call-with-values (lambda () (call/cc (lambda (k) (k)))) list)"),
+        Value::Nil
+    );
+}
+
+#[test]
+fn test_continuation_with_one_value_stays_single() {
+    let mut lambdust = Lambdust::new();
+    assert_equal(
+        eval(&mut lambdust, "(call-with-values (lambda () (call/cc (lambda (k) (k 5)))) list)"),
+        list(&[5])
+    );
+    assert_eq!(eval(&mut lambdust, "(call/cc (lambda (k) (k 41)))"), Value::integer(41));
+}
+
+#[test]
+fn test_single_value_contexts_reject_other_counts() {
+    let mut lambdust = Lambdust::new();
+    for source in [
+        "(define x (values))",
+        "(define y (values 1 2))",
+        "(+ 1 (values 2 3))",
+        "((values car cdr) '(1 2))",
+        "(if (values) 1 2)",
+    ] {
+        assert!(lambdust.eval(source, Some("<test>")).is_err(), "{source}");
+    }
+
+    eval(&mut lambdust, "(define z 0)");
+    assert!(lambdust.eval("(set! z (values 1 2))", Some("<test>")).is_err());
+    assert_eq!(eval(&mut lambdust, "z"), Value::integer(0));
+}
+
+#[test]
+fn test_exactly_one_value_unwraps() {
+    let mut lambdust = Lambdust::new();
+    assert_eq!(eval(&mut lambdust, "(+ 1 (values 2))"), Value::integer(3));
+    eval(&mut lambdust, "(define w (values 4))");
+    assert_eq!(eval(&mut lambdust, "w"), Value::integer(4));
+    assert_eq!(eval(&mut lambdust, "(if (values #f) 1 2)"), Value::integer(2));
+}
+
+#[test]
+fn test_call_with_values_consumer_is_a_tail_call() {
+    let mut lambdust = Lambdust::new();
+    eval(
+        &mut lambdust,
+        "(define (count-up n acc)
+           (if (= n 0)
+               acc
+               (call-with-values (lambda () (values (- n 1) (+ acc 2))) count-up)))",
+    );
+    assert_eq!(eval(&mut lambdust, "(count-up 1000000 0)"), Value::integer(2_000_000));
+}
@@ -0,0 +1,71 @@
+//! SRFI-18 mutexes shared between evaluator threads.
+
+mod common;
+
+use common::eval;
+use lambdust::{Lambdust, Value};
+
+#[test]
+fn test_with_mutex_prevents_lost_updates() {
+    let mut lambdust = Lambdust::new();
+    eval(&mut lambdust, "(define m (make-mutex 'counter))");
+    eval(&mut lambdust, "(define counter (vector 0))");
+    eval(&mut lambdust, "
+        (define (bump n)
+          (if (> n 0)
+              (begin
+                (with-mutex m (lambda () (vector-set! counter 0 (+ (vector-ref counter 0) 1))))
+                (bump (- n 1)))))");
+    eval(&mut lambdust, "(parallel-vector-map bump #(2000 2000))");
+    assert_eq!(eval(&mut lambdust, "(vector-ref counter 0)"), Value::integer(4000));
+}
+
+#[test]
+fn test_with_mutex_cannot_share_a_variable() {
+    // Assignments to a global made by worker threads stay in their copies
+    // of the globals, so counters have to live in a shared data structure
+    let mut lambdust = Lambdust::new();
+    eval(&mut lambdust, "(define m (make-mutex 'counter))");
+    eval(&mut lambdust, "(define counter 0)");
+    eval(&mut lambdust, "(parallel-vector-map (lambda (i) (with-mutex m (lambda () (set! counter (+ counter 1))))) #(0 1))");
+    assert_eq!(eval(&mut lambdust, "counter"), Value::integer(0));
+}
+
+#[test]
+fn test_lock_and_unlock() {
+    let mut lambdust = Lambdust::new();
+    eval(&mut lambdust, "(define m (make-mutex 'm))");
+    assert_eq!(eval(&mut lambdust, "(mutex? m)"), Value::boolean(true));
+    assert_eq!(eval(&mut lambdust, "(mutex? 'm)"), Value::boolean(false));
+    assert_eq!(eval(&mut lambdust, "(mutex-name m)"), Value::symbol_from_str("m"));
+    assert_eq!(eval(&mut lambdust, "(mutex-lock! m)"), Value::boolean(true));
+    assert_eq!(eval(&mut lambdust, "(mutex-unlock! m)"), Value::boolean(true));
+    assert_eq!(eval(&mut lambdust, "(with-mutex m (lambda () 42))"), Value::integer(42));
+}
+
+#[test]
+fn test_with_mutex_unlocks_on_escape() {
+    let mut lambdust = Lambdust::new();
+    eval(&mut lambdust, "(define m (make-mutex))");
+    assert_eq!(
+        eval(&mut lambdust, "(call/cc (lambda (k) (with-mutex m (lambda () (k 'escaped)))))"),
+        Value::symbol_from_str("escaped")
+    );
+    assert!(lambdust.eval("(with-mutex m (lambda () (error \"boom\")))", Some("<test>")).is_err());
+    // Both exits released the mutex
+    assert_eq!(eval(&mut lambdust, "(mutex-lock! m)"), Value::boolean(true));
+}
+
+#[test]
+fn test_misuse_is_an_error() {
+    let mut lambdust = Lambdust::new();
+    eval(&mut lambdust, "(define m (make-mutex))");
+    assert!(lambdust.eval("(mutex-unlock! m)", Some("<test>")).is_err());
+
+    // Mutexes are not reentrant
+    eval(&mut lambdust, "(mutex-lock! m)");
+    let err = lambdust.eval("(mutex-lock! m)", Some("<test>")).unwrap_err();
+    assert!(err.to_string().contains("already locked by this thread"), "{err}");
+    assert!(lambdust.eval("(with-mutex m (lambda () 1))", Some("<test>")).is_err());
+    assert!(lambdust.eval("(mutex-lock! 'm)", Some("<test>")).is_err());
+}
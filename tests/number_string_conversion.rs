@@ -0,0 +1,63 @@
+//! `number->string` prints the shortest decimal that `string->number`
+//! reads back as the same inexact number.
+
+mod common;
+
+use common::eval;
+use lambdust::{Lambdust, Value};
+
+fn number_to_string(lambdust: &mut Lambdust, source: &str) -> String {
+    eval(lambdust, &format!("(number->string {source})")).as_string_owned().unwrap()
+}
+
+#[test]
+fn test_tricky_doubles_round_trip() {
+    let mut lambdust = Lambdust::new();
+    let tricky = [
+        0.1, 0.2 + 0.1, 1.0 / 3.0, 2.0, 100.0, 123456789.125, 9007199254740993.0,
+        1e21, 1e300, 1.7976931348623157e308, 1e-7, 1e-300,
+        // Smallest normal and subnormals
+        2.2250738585072014e-308, 2.225073858507201e-308, 5e-324,
+    ];
+
+    for r in tricky.into_iter().flat_map(|r| [r, -r]) {
+        let text = number_to_string(&mut lambdust, &format!("(string->number \"{r:?}\")"));
+        assert_eq!(text.parse::<f64>().unwrap(), r, "{text}");
+        assert_eq!(
+            eval(&mut lambdust, &format!("(= (string->number \"{text}\") (string->number \"{r:?}\"))")),
+            Value::boolean(true),
+            "{text}"
+        );
+        assert_eq!(
+            eval(&mut lambdust, &format!("(inexact? (string->number \"{text}\"))")),
+            Value::boolean(true),
+            "{text} read back exact"
+        );
+    }
+}
+
+#[test]
+fn test_shortest_forms() {
+    let mut lambdust = Lambdust::new();
+    assert_eq!(number_to_string(&mut lambdust, "0.1"), "0.1");
+    assert_eq!(number_to_string(&mut lambdust, "(+ 0.1 0.2)"), "0.30000000000000004");
+    assert_eq!(number_to_string(&mut lambdust, "2.0"), "2.0");
+    assert_eq!(number_to_string(&mut lambdust, "(string->number \"1e300\")"), "1e300");
+    assert_eq!(number_to_string(&mut lambdust, "(string->number \"5e-324\")"), "5e-324");
+    assert_eq!(number_to_string(&mut lambdust, "42"), "42");
+    assert_eq!(number_to_string(&mut lambdust, "3/4"), "3/4");
+}
+
+#[test]
+fn test_signed_zero_and_special_values() {
+    let mut lambdust = Lambdust::new();
+    assert_eq!(number_to_string(&mut lambdust, "-0.0"), "-0.0");
+    assert_eq!(number_to_string(&mut lambdust, "(string->number \"-0.0\")"), "-0.0");
+    assert_eq!(number_to_string(&mut lambdust, "0.0"), "0.0");
+    assert_eq!(number_to_string(&mut lambdust, "+inf.0"), "+inf.0");
+    assert_eq!(number_to_string(&mut lambdust, "-inf.0"), "-inf.0");
+    assert_eq!(number_to_string(&mut lambdust, "+nan.0"), "+nan.0");
+    assert_eq!(eval(&mut lambdust, "(string->number \"+inf.0\")"), eval(&mut lambdust, "+inf.0"));
+    assert_eq!(eval(&mut lambdust, "(string->number \"-inf.0\")"), eval(&mut lambdust, "-inf.0"));
+    assert_eq!(eval(&mut lambdust, "(nan? (string->number \"+nan.0\"))"), Value::boolean(true));
+}
@@ -0,0 +1,73 @@
+//! The numeric tower predicates and the `exact`/`inexact` conversions
+//! across every kind of number the runtime represents.
+
+mod common;
+
+use common::eval;
+use lambdust::{Lambdust, Value};
+
+const PREDICATES: [&str; 10] = [
+    "number?", "complex?", "real?", "rational?", "integer?",
+    "exact?", "inexact?", "exact-integer?", "finite?", "nan?",
+];
+
+/// Each row gives an expression and the expected result of every predicate
+/// in `PREDICATES`, in order.
+const MATRIX: [(&str, [bool; 10]); 10] = [
+    ("1", [true, true, true, true, true, true, false, true, true, false]),
+    ("2.0", [true, true, true, true, true, false, true, false, true, false]),
+    ("1/2", [true, true, true, true, false, true, false, false, true, false]),
+    ("0.5", [true, true, true, true, false, false, true, false, true, false]),
+    ("+nan.0", [true, true, true, false, false, false, true, false, false, true]),
+    ("+inf.0", [true, true, true, false, false, false, true, false, false, false]),
+    ("-inf.0", [true, true, true, false, false, false, true, false, false, false]),
+    ("(exact 1e30)", [true, true, true, true, true, true, false, true, true, false]),
+    ("(make-rectangular 1 2)", [true, true, false, false, false, false, true, false, true, false]),
+    ("(make-rectangular 3 0)", [true, true, true, true, true, false, true, false, true, false]),
+];
+
+#[test]
+fn test_predicate_matrix() {
+    let mut lambdust = Lambdust::new();
+    for (expr, expected) in MATRIX {
+        for (predicate, want) in PREDICATES.iter().zip(expected) {
+            let source = format!("({predicate} {expr})");
+            assert_eq!(eval(&mut lambdust, &source), Value::boolean(want), "{source}");
+        }
+    }
+}
+
+#[test]
+fn test_non_numbers_fail_type_predicates() {
+    let mut lambdust = Lambdust::new();
+    for expr in ["'a", "\"1\"", "#\\1", "'(1)"] {
+        for predicate in ["number?", "complex?", "real?", "rational?", "integer?", "exact-integer?"] {
+            let source = format!("({predicate} {expr})");
+            assert_eq!(eval(&mut lambdust, &source), Value::boolean(false), "{source}");
+        }
+    }
+}
+
+#[test]
+fn test_bignum_range_predicates() {
+    let mut lambdust = Lambdust::new();
+    assert_eq!(eval(&mut lambdust, "(exact-nonnegative-integer? (exact 1e30))"), Value::boolean(true));
+    assert_eq!(eval(&mut lambdust, "(exact-nonnegative-integer? (exact -1e30))"), Value::boolean(false));
+    assert_eq!(eval(&mut lambdust, "(exact-rational? (exact 1e30))"), Value::boolean(true));
+    assert_eq!(eval(&mut lambdust, "(infinite? (exact 1e30))"), Value::boolean(false));
+    assert_eq!(eval(&mut lambdust, "(fixnum? (exact 1e30))"), Value::boolean(false));
+    assert_eq!(eval(&mut lambdust, "(flonum? (exact 1e30))"), Value::boolean(false));
+}
+
+#[test]
+fn test_legacy_names_alias_exact_and_inexact() {
+    let mut lambdust = Lambdust::new();
+    assert_eq!(eval(&mut lambdust, "(exact->inexact 1/2)"), eval(&mut lambdust, "(inexact 1/2)"));
+    assert_eq!(eval(&mut lambdust, "(inexact->exact 0.25)"), eval(&mut lambdust, "(exact 0.25)"));
+    assert_eq!(eval(&mut lambdust, "(exact 2.0)"), Value::integer(2));
+    assert_eq!(eval(&mut lambdust, "(inexact? (inexact 1))"), Value::boolean(true));
+    assert_eq!(eval(&mut lambdust, "(= (inexact 1/2) 0.5)"), Value::boolean(true));
+    assert_eq!(eval(&mut lambdust, "(exact (inexact 1/4))"), eval(&mut lambdust, "1/4"));
+    assert!(lambdust.eval("(exact +nan.0)", Some("<test>")).is_err());
+    assert!(lambdust.eval("(inexact->exact +inf.0)", Some("<test>")).is_err());
+}
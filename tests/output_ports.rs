@@ -0,0 +1,73 @@
+//! Output primitives writing to an explicit port, including `write-string` bounds.
+
+mod common;
+
+use common::eval;
+use lambdust::{Lambdust, Value};
+
+#[test]
+fn test_write_string_substring_to_string_port() {
+    let mut lambdust = Lambdust::new();
+    eval(&mut lambdust, "(define out (open-output-string))");
+    eval(&mut lambdust, "(write-string \"hello world\" out 6)");
+    eval(&mut lambdust, "(write-string \"-abc-\" out 1 4)");
+    eval(&mut lambdust, "(write-string \"λx.x\" out 0 2)");
+    assert_eq!(eval(&mut lambdust, "(get-output-string out)"), Value::string("worldabcλx"));
+}
+
+#[test]
+fn test_output_primitives_honor_port_argument() {
+    let mut lambdust = Lambdust::new();
+    eval(&mut lambdust, "(define out (open-output-string))");
+    eval(
+        &mut lambdust,
+        "(begin
+           (write-char #\\a out)
+           (display \"b\" out)
+           (write \"c\" out)
+           (newline out)
+           (display '(1 #\\d) out))",
+    );
+    assert_eq!(eval(&mut lambdust, "(get-output-string out)"), Value::string("ab\"c\"\n(1 d)"));
+}
+
+#[test]
+fn test_write_string_bounds_are_validated() {
+    let mut lambdust = Lambdust::new();
+    eval(&mut lambdust, "(define out (open-output-string))");
+    for source in [
+        "(write-string \"abc\" out 2 1)",
+        "(write-string \"abc\" out 0 4)",
+        "(write-string \"abc\" out -1)",
+        "(write-string \"abc\" out 4)",
+    ] {
+        assert!(lambdust.eval(source, Some("<test>")).is_err(), "{source} should fail");
+    }
+    // Empty ranges are fine, including at the end of the string
+    eval(&mut lambdust, "(write-string \"abc\" out 3 3)");
+    assert_eq!(eval(&mut lambdust, "(get-output-string out)"), Value::string(""));
+}
+
+#[test]
+fn test_writing_to_closed_port_errors() {
+    let mut lambdust = Lambdust::new();
+    eval(&mut lambdust, "(define out (open-output-string))");
+    eval(&mut lambdust, "(close-port out)");
+    for source in [
+        "(write-char #\\a out)",
+        "(write-string \"abc\" out)",
+        "(newline out)",
+        "(write 1 out)",
+        "(display 1 out)",
+    ] {
+        assert!(lambdust.eval(source, Some("<test>")).is_err(), "{source} should fail");
+    }
+}
+
+#[test]
+fn test_output_to_input_port_errors() {
+    let mut lambdust = Lambdust::new();
+    assert!(lambdust
+        .eval("(write-char #\\a (open-input-string \"x\"))", Some("<test>"))
+        .is_err());
+}
@@ -0,0 +1,77 @@
+//! `parallel-vector-map` chunking a pure procedure across threads.
+
+mod common;
+
+use common::eval;
+use lambdust::{Lambdust, Value};
+
+#[test]
+fn test_matches_sequential_map_for_cpu_heavy_procedure() {
+    let mut lambdust = Lambdust::new();
+    eval(
+        &mut lambdust,
+        "(define (fib n) (if (< n 2) n (+ (fib (- n 1)) (fib (- n 2)))))
+         (define inputs (make-vector 24 0))
+         (do ((i 0 (+ i 1))) ((= i 24)) (vector-set! inputs i (modulo i 12)))",
+    );
+
+    assert_eq!(
+        eval(&mut lambdust, "(equal? (parallel-vector-map fib inputs) (vector-map fib inputs))"),
+        Value::boolean(true)
+    );
+    assert_eq!(eval(&mut lambdust, "(vector-ref (parallel-vector-map fib inputs) 11)"), Value::integer(89));
+}
+
+#[test]
+fn test_workers_see_the_callers_globals() {
+    let mut lambdust = Lambdust::new();
+    eval(
+        &mut lambdust,
+        "(define (fact n) (if (= n 0) 1 (* n (fact (- n 1)))))
+         (define offset 100)",
+    );
+
+    assert_eq!(
+        eval(&mut lambdust, "(equal? (parallel-vector-map (lambda (n) (+ offset (fact n))) #(0 3 5)) #(101 106 220))"),
+        Value::boolean(true)
+    );
+}
+
+#[test]
+fn test_nested_maps_run_on_the_calling_worker() {
+    let mut lambdust = Lambdust::new();
+    assert_eq!(
+        eval(
+            &mut lambdust,
+            "(equal? (parallel-vector-map (lambda (v) (vector-length (parallel-vector-map - v))) (vector #(1 2) #(3)))
+                     #(2 1))",
+        ),
+        Value::boolean(true)
+    );
+}
+
+#[test]
+fn test_empty_vector() {
+    let mut lambdust = Lambdust::new();
+    assert_eq!(
+        eval(&mut lambdust, "(equal? (parallel-vector-map (lambda (x) (* x x)) #()) #())"),
+        Value::boolean(true)
+    );
+}
+
+#[test]
+fn test_first_failing_element_is_reported() {
+    let mut lambdust = Lambdust::new();
+    eval(
+        &mut lambdust,
+        "(define (checked x)
+           (if (or (= x 3) (= x 7)) (error \"bad element\" x) x))",
+    );
+
+    let error = lambdust
+        .eval("(parallel-vector-map checked #(0 1 2 3 4 5 6 7 8 9))", Some("<test>"))
+        .unwrap_err();
+    let report = error.report();
+    assert!(report.contains("bad element"), "{report}");
+    assert!(report.contains('3') && !report.contains('7'), "{report}");
+}
@@ -0,0 +1,48 @@
+//! `set-port-buffering!` and `flush-output-port` on file ports.
+
+mod common;
+
+use common::eval;
+use lambdust::Lambdust;
+
+fn open(lambdust: &mut Lambdust, path: &std::path::Path, mode: &str) {
+    eval(
+        lambdust,
+        &format!(
+            "(define port (open-output-file {:?})) (set-port-buffering! port '{mode})",
+            path.to_string_lossy()
+        ),
+    );
+}
+
+#[test]
+fn test_block_buffered_output_waits_for_flush() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("block.txt");
+    let mut lambdust = Lambdust::new();
+    open(&mut lambdust, &path, "block");
+
+    eval(&mut lambdust, "(display \"held\" port)");
+    assert_eq!(std::fs::read_to_string(&path).unwrap(), "");
+    eval(&mut lambdust, "(flush-output-port port)");
+    assert_eq!(std::fs::read_to_string(&path).unwrap(), "held");
+}
+
+#[test]
+fn test_line_buffered_output_waits_for_newline() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("line.txt");
+    let mut lambdust = Lambdust::new();
+    open(&mut lambdust, &path, "line");
+
+    eval(&mut lambdust, "(display \"partial\" port)");
+    assert_eq!(std::fs::read_to_string(&path).unwrap(), "");
+    eval(&mut lambdust, "(newline port)");
+    assert_eq!(std::fs::read_to_string(&path).unwrap(), "partial\n");
+}
+
+#[test]
+fn test_unknown_buffering_mode() {
+    let mut lambdust = Lambdust::new();
+    assert!(lambdust.eval("(set-port-buffering! (current-output-port) 'full)", Some("<test>")).is_err());
+}
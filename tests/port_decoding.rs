@@ -0,0 +1,66 @@
+//! Decoding of textual file ports opened with an explicit encoding.
+
+mod common;
+
+use common::eval;
+use lambdust::{Lambdust, Value};
+
+/// Writes `bytes` to a fresh file in the temporary directory.
+fn write_temp_file(name: &str, bytes: &[u8]) -> String {
+    let path = std::env::temp_dir().join(format!("lambdust-{}-{name}", std::process::id()));
+    std::fs::write(&path, bytes).unwrap();
+    path.to_string_lossy().into_owned()
+}
+
+#[test]
+fn test_strict_decoding_raises_read_error() {
+    let path = write_temp_file("strict.txt", b"ab\xffcd");
+    let mut lambdust = Lambdust::new();
+    eval(&mut lambdust, &format!("(define port (open-input-file {path:?} 'utf-8 'strict))"));
+
+    assert_eq!(eval(&mut lambdust, "(read-string 2 port)"), Value::string("ab"));
+    assert_eq!(
+        eval(&mut lambdust, "(guard (e ((read-error? e) 'malformed)) (read-char port))"),
+        Value::symbol_from_str("malformed")
+    );
+    std::fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn test_lenient_decoding_substitutes_replacement_character() {
+    let path = write_temp_file("lenient.txt", b"ab\xffc\xc3\xa9");
+    let mut lambdust = Lambdust::new();
+    eval(&mut lambdust, &format!("(define port (open-input-file {path:?} 'utf-8 'lenient))"));
+
+    assert_eq!(eval(&mut lambdust, "(read-string 10 port)"), Value::string("ab\u{FFFD}c\u{e9}"));
+    std::fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn test_latin1_reads_one_character_per_byte() {
+    let path = write_temp_file("latin1.txt", b"caf\xe9");
+    let mut lambdust = Lambdust::new();
+    eval(&mut lambdust, &format!("(define port (open-input-file {path:?} 'latin-1))"));
+
+    assert_eq!(eval(&mut lambdust, "(read-string 4 port)"), Value::string("caf\u{e9}"));
+    std::fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn test_unrepresentable_character_raises_catchable_condition() {
+    let path = write_temp_file("latin1-out.txt", b"");
+    let mut lambdust = Lambdust::new();
+    eval(&mut lambdust, &format!("(define port (open-output-file {path:?} 'latin-1))"));
+
+    assert_eq!(
+        eval(&mut lambdust, "(guard (e (#t 'caught)) (write-char #\\x3bb port))"),
+        Value::symbol_from_str("caught")
+    );
+    assert_eq!(
+        eval(&mut lambdust, "(guard (e ((read-error? e) 'unrepresentable)) (write-string \"λ\" port))"),
+        Value::symbol_from_str("unrepresentable")
+    );
+    eval(&mut lambdust, "(close-port port)");
+    assert!(std::fs::read(&path).unwrap().is_empty());
+    std::fs::remove_file(path).unwrap();
+}
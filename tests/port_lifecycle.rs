@@ -0,0 +1,85 @@
+//! Port predicates, idempotent closing, and I/O on closed ports.
+
+mod common;
+
+use common::eval;
+use lambdust::{Lambdust, Value};
+
+#[test]
+fn test_predicates_on_port_kinds() {
+    let mut lambdust = Lambdust::new();
+    eval(
+        &mut lambdust,
+        "(define ports
+           (list (open-input-string \"abc\")
+                 (open-output-string)
+                 (open-input-bytevector (bytevector 1 2))
+                 (open-output-bytevector)))",
+    );
+    let predicates = [
+        ("port?", [true, true, true, true]),
+        ("input-port?", [true, false, true, false]),
+        ("output-port?", [false, true, false, true]),
+        ("textual-port?", [true, true, false, false]),
+        ("binary-port?", [false, false, true, true]),
+        ("input-port-open?", [true, false, true, false]),
+        ("output-port-open?", [false, true, false, true]),
+    ];
+    for (predicate, expected) in predicates {
+        assert_eq!(
+            eval(&mut lambdust, &format!("(map {predicate} ports)")),
+            Value::list(expected.into_iter().map(Value::boolean).collect()),
+            "{predicate}"
+        );
+    }
+    assert_eq!(eval(&mut lambdust, "(port? \"abc\")"), Value::boolean(false));
+}
+
+#[test]
+fn test_closed_ports_report_closed() {
+    let mut lambdust = Lambdust::new();
+    eval(&mut lambdust, "(define in (open-input-string \"abc\"))");
+    eval(&mut lambdust, "(define out (open-output-bytevector))");
+    eval(&mut lambdust, "(close-input-port in)");
+    eval(&mut lambdust, "(close-output-port out)");
+
+    assert_eq!(eval(&mut lambdust, "(input-port-open? in)"), Value::boolean(false));
+    assert_eq!(eval(&mut lambdust, "(output-port-open? out)"), Value::boolean(false));
+    assert_eq!(eval(&mut lambdust, "(input-port? in)"), Value::boolean(true));
+}
+
+#[test]
+fn test_double_close_is_safe() {
+    let mut lambdust = Lambdust::new();
+    eval(&mut lambdust, "(define port (open-output-string))");
+    eval(&mut lambdust, "(write-string \"kept\" port)");
+    for close in ["close-port", "close-output-port", "close-port"] {
+        eval(&mut lambdust, &format!("({close} port)"));
+    }
+    assert_eq!(eval(&mut lambdust, "(get-output-string port)"), Value::string("kept"));
+}
+
+#[test]
+fn test_operations_on_closed_ports_error() {
+    let mut lambdust = Lambdust::new();
+    eval(&mut lambdust, "(define in (open-input-string \"abc\"))");
+    eval(&mut lambdust, "(define bytes (open-input-bytevector (bytevector 1)))");
+    eval(&mut lambdust, "(define out (open-output-string))");
+    eval(&mut lambdust, "(close-port in)");
+    eval(&mut lambdust, "(close-port in)");
+    eval(&mut lambdust, "(close-port bytes)");
+    eval(&mut lambdust, "(close-port out)");
+
+    for source in [
+        "(read-char in)",
+        "(peek-char in)",
+        "(read-line in)",
+        "(char-ready? in)",
+        "(read-u8 bytes)",
+        "(u8-ready? bytes)",
+        "(write-char #\\a out)",
+    ] {
+        let error = lambdust.eval(source, Some("<test>")).unwrap_err();
+        assert!(error.to_string().contains("closed"), "{source}: {error}");
+    }
+}
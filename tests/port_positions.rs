@@ -0,0 +1,53 @@
+//! `port-position` and `set-port-position!` on bytevector and file ports.
+
+mod common;
+
+use common::eval;
+use lambdust::{Lambdust, Value};
+
+#[test]
+fn test_bytevector_input_port_positions() {
+    let mut lambdust = Lambdust::new();
+    eval(&mut lambdust, "(define port (open-input-bytevector #u8(10 20 30 40))) (read-u8 port)");
+
+    assert_eq!(eval(&mut lambdust, "(port-position port)"), Value::integer(1));
+    eval(&mut lambdust, "(set-port-position! port 3)");
+    assert_eq!(eval(&mut lambdust, "(read-u8 port)"), Value::integer(40));
+    assert!(lambdust.eval("(set-port-position! port 5)", Some("<test>")).is_err());
+}
+
+#[test]
+fn test_bytevector_output_port_overwrites_and_zero_fills() {
+    let mut lambdust = Lambdust::new();
+    let source = "(let ((port (open-output-bytevector)))
+                    (for-each (lambda (byte) (write-u8 byte port)) '(1 2 3))
+                    (set-port-position! port 1)
+                    (write-u8 9 port)
+                    (set-port-position! port 5)
+                    (write-u8 7 port)
+                    (equal? (get-output-bytevector port) #u8(1 9 3 0 0 7)))";
+    assert_eq!(eval(&mut lambdust, source), Value::boolean(true));
+}
+
+#[test]
+fn test_file_input_port_positions_count_bytes() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("seek.txt");
+    std::fs::write(&path, "héllo").unwrap();
+
+    let mut lambdust = Lambdust::new();
+    eval(
+        &mut lambdust,
+        &format!("(define port (open-input-file {:?})) (read-char port) (read-char port)", path.to_string_lossy()),
+    );
+    assert_eq!(eval(&mut lambdust, "(port-position port)"), Value::integer(3));
+    eval(&mut lambdust, "(set-port-position! port 1)");
+    assert_eq!(eval(&mut lambdust, "(read-string 4 port)"), Value::string("éllo"));
+}
+
+#[test]
+fn test_string_ports_have_no_position() {
+    let mut lambdust = Lambdust::new();
+    assert_eq!(eval(&mut lambdust, "(port-has-port-position? (open-input-string \"abc\"))"), Value::boolean(false));
+    assert!(lambdust.eval("(port-position (open-input-string \"abc\"))", Some("<test>")).is_err());
+}
@@ -0,0 +1,68 @@
+//! `port->string`, `port->lines` and `port->list` reading an input port
+//! to its end.
+
+mod common;
+
+use common::eval;
+use lambdust::{Lambdust, Value};
+
+#[test]
+fn test_port_to_list_reads_every_datum() {
+    let mut lambdust = Lambdust::new();
+    assert_eq!(
+        eval(&mut lambdust, "(port->list (open-input-string \"(1 2) foo \\\"bar\\\"\\n42 #t\"))"),
+        eval(&mut lambdust, "'((1 2) foo \"bar\" 42 #t)")
+    );
+    // Reading resumes where earlier reads stopped
+    assert_eq!(
+        eval(&mut lambdust, "(let ((p (open-input-string \"1 2 3\"))) (read p) (port->list p))"),
+        eval(&mut lambdust, "'(2 3)")
+    );
+}
+
+#[test]
+fn test_port_to_lines_splits_on_newlines() {
+    let mut lambdust = Lambdust::new();
+    assert_eq!(
+        eval(&mut lambdust, "(port->lines (open-input-string \"first line\\nsecond\\n\\nfourth\\n\"))"),
+        eval(&mut lambdust, "'(\"first line\" \"second\" \"\" \"fourth\")")
+    );
+}
+
+#[test]
+fn test_port_to_string_reads_the_rest() {
+    let mut lambdust = Lambdust::new();
+    assert_eq!(
+        eval(&mut lambdust, "(let ((p (open-input-string \"abc\\ndef\"))) (read-char p) (port->string p))"),
+        Value::string("bc\ndef")
+    );
+    assert_eq!(
+        eval(&mut lambdust, "(port->string (open-input-bytevector (bytevector 206 187 32 120)))"),
+        Value::string("λ x")
+    );
+    assert_eq!(
+        eval(&mut lambdust, "(port->list (open-input-bytevector (bytevector 40 97 41 32 98)))"),
+        eval(&mut lambdust, "'((a) b)")
+    );
+}
+
+#[test]
+fn test_empty_ports() {
+    let mut lambdust = Lambdust::new();
+    assert_eq!(eval(&mut lambdust, "(port->string (open-input-string \"\"))"), Value::string(""));
+    assert_eq!(eval(&mut lambdust, "(port->list (open-input-string \"\"))"), Value::Nil);
+    assert_eq!(eval(&mut lambdust, "(port->lines (open-input-string \"\"))"), Value::Nil);
+}
+
+#[test]
+fn test_malformed_datum_propagates() {
+    let mut lambdust = Lambdust::new();
+    assert_eq!(
+        eval(
+            &mut lambdust,
+            "(guard (e ((read-error? e) 'read-error)) (port->list (open-input-string \"1 2 (3\")))"
+        ),
+        Value::symbol_from_str("read-error")
+    );
+    assert!(lambdust.eval("(port->string (open-output-string))", Some("<test>")).is_err());
+}
@@ -0,0 +1,143 @@
+//! Procedural macros defined with `er-macro-transformer` and
+//! `sc-macro-transformer`.
+
+mod common;
+
+use common::{assert_equal, eval};
+use lambdust::{Lambdust, Value};
+
+#[test]
+fn test_er_or_does_not_capture_user_tmp() {
+    let mut lambdust = Lambdust::new();
+    eval(
+        &mut lambdust,
+        "(define-syntax my-or
+           (er-macro-transformer
+             (lambda (form rename compare)
+               (let ((a (cadr form))
+                     (b (caddr form))
+                     (tmp (rename 'tmp)))
+                 (list (rename 'let) (list (list tmp a))
+                       (list (rename 'if) tmp tmp b))))))",
+    );
+    eval(&mut lambdust, "(define tmp 5)");
+
+    assert_eq!(eval(&mut lambdust, "(my-or #f tmp)"), Value::integer(5));
+    assert_eq!(eval(&mut lambdust, "(my-or 1 tmp)"), Value::integer(1));
+}
+
+#[test]
+fn test_er_compare_matches_renamed_keyword() {
+    let mut lambdust = Lambdust::new();
+    eval(
+        &mut lambdust,
+        "(define-syntax else-clause?
+           (er-macro-transformer
+             (lambda (form rename compare)
+               (if (compare (cadr form) (rename 'else)) #t #f))))",
+    );
+
+    assert_eq!(eval(&mut lambdust, "(else-clause? else)"), Value::boolean(true));
+    assert_eq!(eval(&mut lambdust, "(else-clause? other)"), Value::boolean(false));
+}
+
+#[test]
+fn test_sc_or_does_not_capture_user_tmp() {
+    let mut lambdust = Lambdust::new();
+    eval(
+        &mut lambdust,
+        "(define-syntax my-or
+           (sc-macro-transformer
+             (lambda (form env)
+               (let ((a (make-syntactic-closure env '() (cadr form)))
+                     (b (make-syntactic-closure env '() (caddr form))))
+                 (list 'let (list (list 'tmp a))
+                       (list 'if 'tmp 'tmp b))))))",
+    );
+    eval(&mut lambdust, "(define tmp 5)");
+
+    assert_eq!(eval(&mut lambdust, "(my-or #f tmp)"), Value::integer(5));
+    assert_eq!(eval(&mut lambdust, "(my-or 1 tmp)"), Value::integer(1));
+}
+
+#[test]
+fn test_procedural_macro_operands_are_spliced() {
+    let mut lambdust = Lambdust::new();
+    eval(
+        &mut lambdust,
+        "(define-syntax call-twice
+           (er-macro-transformer
+             (lambda (form rename compare)
+               (list (rename 'begin) (list (cadr form)) (list (cadr form))))))",
+    );
+    eval(&mut lambdust, "(define count (vector 0))");
+    eval(&mut lambdust, "(call-twice (lambda () (vector-set! count 0 (+ (vector-ref count 0) 1))))");
+
+    assert_eq!(eval(&mut lambdust, "(vector-ref count 0)"), Value::integer(2));
+}
+
+#[test]
+fn test_non_transformer_define_syntax_errors() {
+    let mut lambdust = Lambdust::new();
+    assert!(lambdust.eval("(define-syntax bad 42)", Some("<test>")).is_err());
+}
+
+#[test]
+fn test_sc_introduced_identifiers_refer_to_the_macro_environment() {
+    let mut lambdust = Lambdust::new();
+    eval(
+        &mut lambdust,
+        "(define-syntax add-offset
+           (let ((offset 100))
+             (sc-macro-transformer
+               (lambda (form env)
+                 (list '+ 'offset (make-syntactic-closure env '() (cadr form)))))))",
+    );
+
+    assert_eq!(eval(&mut lambdust, "(add-offset 1)"), Value::integer(101));
+    assert_eq!(eval(&mut lambdust, "(let ((offset 1)) (add-offset offset))"), Value::integer(101));
+}
+
+#[test]
+fn test_sc_introduced_globals_are_not_captured_by_user_bindings() {
+    let mut lambdust = Lambdust::new();
+    eval(
+        &mut lambdust,
+        "(define-syntax singleton
+           (sc-macro-transformer
+             (lambda (form env)
+               (list 'list (make-syntactic-closure env '() (cadr form))))))",
+    );
+
+    assert_equal(eval(&mut lambdust, "(let ((list vector)) (singleton 1))"), eval(&mut lambdust, "'(1)"));
+}
+
+#[test]
+fn test_syntactic_closure_refers_to_its_environment() {
+    let mut lambdust = Lambdust::new();
+    eval(
+        &mut lambdust,
+        "(define-syntax with-list
+           (sc-macro-transformer
+             (lambda (form env)
+               (list 'let (list (list 'list 'vector))
+                     (make-syntactic-closure env '() (cadr form))))))",
+    );
+
+    assert_equal(eval(&mut lambdust, "(with-list (list 1 2))"), eval(&mut lambdust, "'(1 2)"));
+}
+
+#[test]
+fn test_syntactic_closure_free_names_see_the_expansion() {
+    let mut lambdust = Lambdust::new();
+    eval(
+        &mut lambdust,
+        "(define-syntax with-it
+           (sc-macro-transformer
+             (lambda (form env)
+               (list 'let (list (list 'it (make-syntactic-closure env '() (cadr form))))
+                     (make-syntactic-closure env '(it) (caddr form))))))",
+    );
+
+    assert_eq!(eval(&mut lambdust, "(with-it 42 (+ it 1))"), Value::integer(43));
+}
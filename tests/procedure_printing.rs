@@ -0,0 +1,43 @@
+//! `write` and `display` of procedures showing their name and arity.
+
+mod common;
+
+use common::eval;
+use lambdust::Lambdust;
+
+fn rendered(lambdust: &mut Lambdust, printer: &str, expr: &str) -> String {
+    let source = format!("(let ((port (open-output-string))) ({printer} {expr} port) (get-output-string port))");
+    eval(lambdust, &source).as_string_owned().unwrap()
+}
+
+fn assert_renders(lambdust: &mut Lambdust, expr: &str, expected: &str) {
+    assert_eq!(rendered(lambdust, "display", expr), expected);
+    assert_eq!(rendered(lambdust, "write", expr), expected);
+}
+
+#[test]
+fn test_primitive_shows_name_and_arity() {
+    let mut lambdust = Lambdust::new();
+    assert_renders(&mut lambdust, "car", "#<procedure car (1)>");
+}
+
+#[test]
+fn test_named_lambda_shows_parameters() {
+    let mut lambdust = Lambdust::new();
+    eval(&mut lambdust, "(define (scale factor . values) values)");
+    eval(&mut lambdust, "(define (square x) (* x x))");
+    assert_renders(&mut lambdust, "square", "#<procedure square (x)>");
+    assert_renders(&mut lambdust, "scale", "#<procedure scale (factor . values)>");
+}
+
+#[test]
+fn test_anonymous_lambda() {
+    let mut lambdust = Lambdust::new();
+    assert_renders(&mut lambdust, "(lambda (x) x)", "#<procedure anonymous>");
+}
+
+#[test]
+fn test_continuation() {
+    let mut lambdust = Lambdust::new();
+    assert_renders(&mut lambdust, "(call/cc (lambda (k) k))", "#<continuation>");
+}
@@ -0,0 +1,108 @@
+//! Subprocess pipes through `open-process-ports` and `process-wait`.
+#![cfg(unix)]
+
+mod common;
+
+use common::{assert_equal, eval};
+use lambdust::{Lambdust, Value};
+
+#[test]
+fn test_pipe_text_through_cat() {
+    let mut lambdust = Lambdust::new();
+    assert_equal(
+        eval(
+            &mut lambdust,
+            "(receive (in out err proc) (open-process-ports \"cat\")
+               (write-string \"hello\\nworld\\n\" in)
+               (close-port in)
+               (list (read-line out) (read-line out) (eof-object? (read-line out))
+                     (process-wait proc)))"
+        ),
+        eval(&mut lambdust, "'(\"hello\" \"world\" #t 0)")
+    );
+}
+
+#[test]
+fn test_echo_arguments_and_exit_codes() {
+    let mut lambdust = Lambdust::new();
+    assert_equal(
+        eval(
+            &mut lambdust,
+            "(receive (in out err proc) (open-process-ports \"echo\" \"one\" \"two\")
+               (list (read-line out) (process? proc) (process-wait proc) (process-wait proc)))"
+        ),
+        eval(&mut lambdust, "'(\"one two\" #t 0 0)")
+    );
+    assert_eq!(
+        eval(&mut lambdust, "(receive (in out err proc) (open-process-ports \"false\") (process-wait proc))"),
+        Value::integer(1)
+    );
+    assert_eq!(eval(&mut lambdust, "(process? 42)"), Value::boolean(false));
+}
+
+#[test]
+fn test_large_output_does_not_deadlock() {
+    let mut lambdust = Lambdust::new();
+    // Far more than a pipe buffer, written before anything is read back
+    assert_equal(
+        eval(
+            &mut lambdust,
+            "(receive (in out err proc) (open-process-ports \"cat\")
+               (do ((i 0 (+ i 1))) ((= i 20000))
+                 (write-string \"0123456789abcdef\\n\" in))
+               (close-port in)
+               (do ((count 0 (+ count 1)))
+                   ((eof-object? (read-line out)) (list count (process-wait proc)))))"
+        ),
+        eval(&mut lambdust, "'(20000 0)")
+    );
+}
+
+#[test]
+fn test_wait_closes_stdin_for_the_child() {
+    let mut lambdust = Lambdust::new();
+    // cat only exits once its input ends
+    assert_equal(
+        eval(
+            &mut lambdust,
+            "(receive (in out err proc) (open-process-ports \"cat\")
+               (write-string \"pending\\n\" in)
+               (list (process-wait proc) (read-line out)))"
+        ),
+        eval(&mut lambdust, "'(0 \"pending\")")
+    );
+}
+
+#[test]
+fn test_child_that_closes_stdin_early() {
+    let mut lambdust = Lambdust::new();
+    eval(
+        &mut lambdust,
+        "(define ports (receive (in out err proc) (open-process-ports \"true\") (vector in out proc)))",
+    );
+    // Writing eventually fails once the child is gone instead of hanging
+    assert!(lambdust
+        .eval(
+            "(do ((i 0 (+ i 1))) ((= i 20000))
+               (write-string \"0123456789abcdef\\n\" (vector-ref ports 0)))",
+            Some("<test>"),
+        )
+        .is_err());
+    assert_eq!(eval(&mut lambdust, "(process-wait (vector-ref ports 2))"), Value::integer(0));
+    assert_eq!(eval(&mut lambdust, "(eof-object? (read-line (vector-ref ports 1)))"), Value::boolean(true));
+}
+
+#[test]
+fn test_stderr_and_spawn_failures() {
+    let mut lambdust = Lambdust::new();
+    assert_equal(
+        eval(
+            &mut lambdust,
+            "(receive (in out err proc) (open-process-ports \"sh\" \"-c\" \"echo oops >&2; exit 3\")
+               (list (read-line err) (eof-object? (read-line out)) (process-wait proc)))"
+        ),
+        eval(&mut lambdust, "'(\"oops\" #t 3)")
+    );
+    assert!(lambdust.eval("(open-process-ports \"/nonexistent/program\")", Some("<test>")).is_err());
+    assert!(lambdust.eval("(open-process-ports 'cat)", Some("<test>")).is_err());
+}
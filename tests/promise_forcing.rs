@@ -0,0 +1,144 @@
+//! Creating and forcing promises, including thunks that use `dynamic-wind`,
+//! raise, or escape through continuations.
+
+mod common;
+
+use common::eval;
+use lambdust::{Lambdust, Value};
+
+/// Counts thunk runs and extent entries and exits in a shared vector, which
+/// closures can update in place.
+fn setup_counters(lambdust: &mut Lambdust) {
+    eval(lambdust, "(define counts (make-vector 3 0))");
+    eval(lambdust, "(define (bump! i) (vector-set! counts i (+ (vector-ref counts i) 1)) (vector-ref counts i))");
+    eval(lambdust, "(define (before) (bump! 1))");
+    eval(lambdust, "(define (after) (bump! 2))");
+}
+
+#[test]
+fn test_force_memoizes_and_winds_once() {
+    let mut lambdust = Lambdust::new();
+    setup_counters(&mut lambdust);
+    eval(
+        &mut lambdust,
+        "(define p
+           (delay
+             (dynamic-wind before (lambda () (bump! 0) 'value) after)))",
+    );
+
+    assert_eq!(eval(&mut lambdust, "(force p)"), Value::symbol_from_str("value"));
+    assert_eq!(
+        eval(&mut lambdust, "(dynamic-wind before (lambda () (force p)) after)"),
+        Value::symbol_from_str("value")
+    );
+    assert_eq!(eval(&mut lambdust, "(vector-ref counts 0)"), Value::integer(1));
+    assert_eq!(eval(&mut lambdust, "(vector-ref counts 1)"), Value::integer(2));
+    assert_eq!(eval(&mut lambdust, "(vector-ref counts 2)"), Value::integer(2));
+}
+
+#[test]
+fn test_raising_thunk_is_not_memoized() {
+    let mut lambdust = Lambdust::new();
+    setup_counters(&mut lambdust);
+    eval(
+        &mut lambdust,
+        "(define p
+           (delay
+             (dynamic-wind
+               before
+               (lambda ()
+                 (if (= (bump! 0) 1) (raise 'boom) 'recovered))
+               after)))",
+    );
+
+    assert_eq!(
+        eval(&mut lambdust, "(guard (e (#t e)) (force p))"),
+        Value::symbol_from_str("boom")
+    );
+    assert_eq!(eval(&mut lambdust, "(vector-ref counts 2)"), Value::integer(1));
+    assert_eq!(eval(&mut lambdust, "(force p)"), Value::symbol_from_str("recovered"));
+    assert_eq!(eval(&mut lambdust, "(force p)"), Value::symbol_from_str("recovered"));
+    assert_eq!(eval(&mut lambdust, "(vector-ref counts 0)"), Value::integer(2));
+    assert_eq!(eval(&mut lambdust, "(vector-ref counts 2)"), Value::integer(2));
+}
+
+#[test]
+fn test_escaping_thunk_is_not_memoized() {
+    let mut lambdust = Lambdust::new();
+    setup_counters(&mut lambdust);
+    // The escape continuation is kept in the vector's last slot
+    eval(&mut lambdust, "(define escape (make-vector 1 #f))");
+    eval(
+        &mut lambdust,
+        "(define p
+           (delay
+             (begin
+               (bump! 0)
+               (if (vector-ref escape 0) ((vector-ref escape 0) 'escaped) 'finished))))",
+    );
+
+    assert_eq!(
+        eval(&mut lambdust, "(call/cc (lambda (k) (vector-set! escape 0 k) (force p)))"),
+        Value::symbol_from_str("escaped")
+    );
+    eval(&mut lambdust, "(vector-set! escape 0 #f)");
+    assert_eq!(eval(&mut lambdust, "(force p)"), Value::symbol_from_str("finished"));
+    assert_eq!(eval(&mut lambdust, "(force p)"), Value::symbol_from_str("finished"));
+    assert_eq!(eval(&mut lambdust, "(vector-ref counts 0)"), Value::integer(2));
+}
+
+#[test]
+fn test_reentrant_force_keeps_first_value() {
+    let mut lambdust = Lambdust::new();
+    setup_counters(&mut lambdust);
+    // The thunk reaches its own promise through a vector
+    eval(&mut lambdust, "(define self (make-vector 1 #f))");
+    eval(
+        &mut lambdust,
+        "(vector-set! self 0
+           (delay (if (> (bump! 0) 5) (vector-ref counts 0) (force (vector-ref self 0)))))",
+    );
+
+    assert_eq!(eval(&mut lambdust, "(force (vector-ref self 0))"), Value::integer(6));
+    assert_eq!(eval(&mut lambdust, "(force (vector-ref self 0))"), Value::integer(6));
+}
+
+#[test]
+fn test_promise_predicate() {
+    let mut lambdust = Lambdust::new();
+    assert_eq!(eval(&mut lambdust, "(promise? (delay (+ 1 2)))"), Value::boolean(true));
+    assert_eq!(eval(&mut lambdust, "(promise? (make-promise 3))"), Value::boolean(true));
+    assert_eq!(eval(&mut lambdust, "(promise? (eager 3))"), Value::boolean(true));
+    assert_eq!(eval(&mut lambdust, "(promise? 3)"), Value::boolean(false));
+    assert_eq!(eval(&mut lambdust, "(promise? (lambda () 3))"), Value::boolean(false));
+}
+
+#[test]
+fn test_make_promise_is_already_forced() {
+    let mut lambdust = Lambdust::new();
+    // The argument is the value, even when it is a procedure
+    eval(&mut lambdust, "(define (thunk) 'ran)");
+    assert_eq!(eval(&mut lambdust, "(eq? (force (make-promise thunk)) thunk)"), Value::boolean(true));
+    assert_eq!(eval(&mut lambdust, "(force (make-promise 7))"), Value::integer(7));
+
+    // A promise argument is returned unchanged
+    eval(&mut lambdust, "(define p (delay 1))");
+    assert_eq!(eval(&mut lambdust, "(eq? (make-promise p) p)"), Value::boolean(true));
+}
+
+#[test]
+fn test_delay_runs_only_when_forced() {
+    let mut lambdust = Lambdust::new();
+    setup_counters(&mut lambdust);
+    eval(&mut lambdust, "(define p (delay (bump! 0)))");
+    assert_eq!(eval(&mut lambdust, "(vector-ref counts 0)"), Value::integer(0));
+    assert_eq!(eval(&mut lambdust, "(force p)"), Value::integer(1));
+    assert_eq!(eval(&mut lambdust, "(force p)"), Value::integer(1));
+}
+
+#[test]
+fn test_force_returns_non_promises_unchanged() {
+    let mut lambdust = Lambdust::new();
+    assert_eq!(eval(&mut lambdust, "(force 42)"), Value::integer(42));
+    assert_eq!(eval(&mut lambdust, "(force 'a)"), Value::symbol_from_str("a"));
+}
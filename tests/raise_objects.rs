@@ -0,0 +1,58 @@
+//! `raise` of arbitrary objects: handlers and `guard` receive the raised
+//! object itself.
+
+mod common;
+
+use common::eval;
+use lambdust::{Lambdust, Value};
+
+#[test]
+fn test_guard_catches_raised_symbol_and_number() {
+    let mut lambdust = Lambdust::new();
+    assert_eq!(
+        eval(&mut lambdust, "(guard (e (#t (eq? e 'oops))) (raise 'oops))"),
+        Value::boolean(true)
+    );
+    assert_eq!(
+        eval(&mut lambdust, "(guard (e (#t (eqv? e 42))) (raise 42))"),
+        Value::boolean(true)
+    );
+    assert_eq!(
+        eval(&mut lambdust, "(guard (e ((symbol? e) (error-object? e))) (raise 'oops))"),
+        Value::boolean(false)
+    );
+    assert_eq!(
+        eval(&mut lambdust, "(guard (e ((number? e) (error-object? e))) (raise 42))"),
+        Value::boolean(false)
+    );
+}
+
+#[test]
+fn test_raised_objects_keep_their_identity() {
+    let mut lambdust = Lambdust::new();
+    eval(&mut lambdust, "(define payload (vector 1 2 3))");
+    assert_eq!(
+        eval(&mut lambdust, "(guard (e (#t (eq? e payload))) (raise payload))"),
+        Value::boolean(true)
+    );
+    assert_eq!(
+        eval(
+            &mut lambdust,
+            "(with-exception-handler
+               (lambda (e) (eq? e payload))
+               (lambda () (raise-continuable payload)))",
+        ),
+        Value::boolean(true)
+    );
+}
+
+#[test]
+fn test_reraised_error_object_is_the_same_object() {
+    let mut lambdust = Lambdust::new();
+    eval(&mut lambdust, "(define caught (guard (e (#t e)) (error \"boom\" 1)))");
+    assert_eq!(
+        eval(&mut lambdust, "(guard (e (#t (eq? e caught))) (raise caught))"),
+        Value::boolean(true)
+    );
+    assert_eq!(eval(&mut lambdust, "(error-object? caught)"), Value::boolean(true));
+}
@@ -0,0 +1,42 @@
+//! `read-string!` filling a mutable buffer, and `char-ready?` at end of file.
+
+mod common;
+
+use common::eval;
+use lambdust::{Lambdust, Value};
+
+#[test]
+fn test_reads_into_buffer_range() {
+    let mut lambdust = Lambdust::new();
+    eval(&mut lambdust, "(define buffer (make-string 5 #\\-)) (define port (open-input-string \"abc\"))");
+
+    assert_eq!(eval(&mut lambdust, "(read-string! buffer port 1 0)"), Value::integer(0));
+    assert_eq!(eval(&mut lambdust, "(read-string! buffer port 1 3)"), Value::integer(3));
+    assert_eq!(eval(&mut lambdust, "buffer"), Value::string("-abc-"));
+    assert_eq!(eval(&mut lambdust, "(read-string! buffer port)"), Value::integer(0));
+    assert!(lambdust.eval("(read-string! buffer port 3 3)", Some("<test>")).is_err());
+    assert!(lambdust.eval("(read-string! \"fixed\" port)", Some("<test>")).is_err());
+}
+
+#[test]
+fn test_reassembles_stream_in_chunks() {
+    let mut lambdust = Lambdust::new();
+    eval(
+        &mut lambdust,
+        "(define (read-chunks port buffer pieces)
+           (let ((count (read-string! buffer port)))
+             (if (zero? count)
+                 (apply string-append (reverse pieces))
+                 (read-chunks port buffer (cons (substring buffer 0 count) pieces)))))",
+    );
+    let source = "(read-chunks (open-input-string \"The quick brown fox, λ and all.\") (make-string 8 #\\space) '())";
+    assert_eq!(eval(&mut lambdust, source), Value::string("The quick brown fox, λ and all."));
+}
+
+#[test]
+fn test_char_ready_at_end_of_file() {
+    let mut lambdust = Lambdust::new();
+    eval(&mut lambdust, "(define port (open-input-string \"x\")) (read-char port)");
+    assert_eq!(eval(&mut lambdust, "(char-ready? port)"), Value::boolean(true));
+    assert_eq!(eval(&mut lambdust, "(eof-object? (read-char port))"), Value::boolean(true));
+}
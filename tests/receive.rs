@@ -0,0 +1,49 @@
+//! SRFI-8 `receive`, binding a producer's values to lambda-style formals.
+
+mod common;
+
+use common::{assert_equal, eval};
+use lambdust::{Lambdust, Value};
+
+#[test]
+fn test_receive_binds_each_value() {
+    let mut lambdust = Lambdust::new();
+    assert_eq!(eval(&mut lambdust, "(receive (q r) (floor/ 17 5) (+ q r))"), Value::integer(5));
+    assert_equal(eval(&mut lambdust, "(receive (q r) (truncate/ -7 2) (list q r))"), eval(&mut lambdust, "'(-3 -1)"));
+}
+
+#[test]
+fn test_receive_rest_formal() {
+    let mut lambdust = Lambdust::new();
+    assert_equal(
+        eval(&mut lambdust, "(receive (a . rest) (values 1 2 3) (list a rest))"),
+        Value::list(vec![Value::integer(1), Value::list(vec![Value::integer(2), Value::integer(3)])])
+    );
+    // The rest formal may capture no values at all
+    assert_eq!(eval(&mut lambdust, "(receive (a b . rest) (values 1 2) (null? rest))"), Value::boolean(true));
+    assert_equal(
+        eval(&mut lambdust, "(receive all (values 1 2) all)"),
+        Value::list(vec![Value::integer(1), Value::integer(2)])
+    );
+}
+
+#[test]
+fn test_receive_single_value_producer() {
+    let mut lambdust = Lambdust::new();
+    assert_eq!(eval(&mut lambdust, "(receive (x) (* 6 7) x)"), Value::integer(42));
+}
+
+#[test]
+fn test_receive_body_sees_enclosing_scope() {
+    let mut lambdust = Lambdust::new();
+    eval(&mut lambdust, "(define (sum-and-scale k) (receive (q r) (floor/ 17 5) (* k (+ q r))))");
+    assert_eq!(eval(&mut lambdust, "(sum-and-scale 10)"), Value::integer(50));
+}
+
+#[test]
+fn test_receive_value_count_mismatch_errors() {
+    let mut lambdust = Lambdust::new();
+    assert!(lambdust.eval("(receive (a b) (values 1 2 3) a)", Some("<test>")).is_err());
+    assert!(lambdust.eval("(receive (a b) 1 a)", Some("<test>")).is_err());
+    assert!(lambdust.eval("(receive (a b . rest) (values 1) a)", Some("<test>")).is_err());
+}
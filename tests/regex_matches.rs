@@ -0,0 +1,54 @@
+//! Regex match objects, capture groups and backreferences in replacements.
+
+mod common;
+
+use common::eval;
+use lambdust::{Lambdust, Value};
+
+fn strings(values: &[&str]) -> Value {
+    Value::list(values.iter().map(|&s| Value::string(s)).collect())
+}
+
+#[test]
+fn test_search_all_digits() {
+    let mut lambdust = Lambdust::new();
+    assert_eq!(
+        eval(&mut lambdust, r#"(map match-group (regex-search-all "\\d+" "x1 y22 z333"))"#),
+        strings(&["1", "22", "333"])
+    );
+    assert_eq!(
+        eval(&mut lambdust, r#"(map match-start (regex-search-all "\\d+" "x1 y22 z333"))"#),
+        Value::list(vec![Value::integer(1), Value::integer(4), Value::integer(8)])
+    );
+}
+
+#[test]
+fn test_match_groups_by_index_and_name() {
+    let mut lambdust = Lambdust::new();
+    eval(&mut lambdust, r#"(define m (regex-search "(?P<key>\\w+)=(\\d+)" "set width=640"))"#);
+    assert_eq!(eval(&mut lambdust, "(match-group m 'key)"), Value::string("width"));
+    assert_eq!(eval(&mut lambdust, "(match-group m 2)"), Value::string("640"));
+    assert_eq!(eval(&mut lambdust, "(match-end m 2)"), Value::integer(13));
+}
+
+#[test]
+fn test_non_participating_named_group() {
+    let mut lambdust = Lambdust::new();
+    assert_eq!(
+        eval(&mut lambdust, r#"(match-group (regex-search "(?P<sign>-)?(\\d+)" "42") "sign")"#),
+        Value::boolean(false)
+    );
+}
+
+#[test]
+fn test_replace_with_backreferences() {
+    let mut lambdust = Lambdust::new();
+    assert_eq!(
+        eval(&mut lambdust, r#"(regex-replace-all "(\\w+)@(?P<host>\\w+)" "ann@home bob@work" "${host}:$1")"#),
+        Value::string("home:ann work:bob")
+    );
+    assert_eq!(
+        eval(&mut lambdust, r#"(regex-replace "(\\d+)" "a1 b2" "<$1>")"#),
+        Value::string("a<1> b2")
+    );
+}
@@ -0,0 +1,46 @@
+//! `remainder` and `modulo` signs for fixnum and big integer operands.
+
+mod common;
+
+use common::eval;
+use lambdust::{Lambdust, Value};
+
+#[test]
+fn test_fixnum_signs() {
+    let mut lambdust = Lambdust::new();
+    let cases = [(7, 3, 1, 1), (7, -3, 1, -2), (-7, 3, -1, 2), (-7, -3, -1, -1)];
+    for (a, b, remainder, modulo) in cases {
+        assert_eq!(eval(&mut lambdust, &format!("(remainder {a} {b})")), Value::integer(remainder));
+        assert_eq!(eval(&mut lambdust, &format!("(modulo {a} {b})")), Value::integer(modulo));
+    }
+    assert!(lambdust.eval("(modulo 7 0)", Some("<test>")).is_err());
+}
+
+#[test]
+fn test_big_integer_signs() {
+    let mut lambdust = Lambdust::new();
+    // (exact 1e22) and (exact 3e20) are beyond a fixnum
+    let cases = [
+        ("1e22", "3e20", "100000000000000000000", "100000000000000000000"),
+        ("1e22", "-3e20", "100000000000000000000", "-200000000000000000000"),
+        ("-1e22", "3e20", "-100000000000000000000", "200000000000000000000"),
+        ("-1e22", "-3e20", "-100000000000000000000", "-100000000000000000000"),
+    ];
+    for (a, b, remainder, modulo) in cases {
+        let result = |operation: &str, lambdust: &mut Lambdust| {
+            eval(lambdust, &format!("({operation} (exact {a}) (exact {b}))")).to_string()
+        };
+        assert_eq!(result("remainder", &mut lambdust), remainder, "(remainder {a} {b})");
+        assert_eq!(result("modulo", &mut lambdust), modulo, "(modulo {a} {b})");
+    }
+}
+
+#[test]
+fn test_mixed_fixnum_and_big_operands() {
+    let mut lambdust = Lambdust::new();
+    assert_eq!(eval(&mut lambdust, "(modulo (exact -1e20) 7)"), Value::integer(5));
+    assert_eq!(eval(&mut lambdust, "(remainder (exact -1e20) 7)"), Value::integer(-2));
+    assert_eq!(eval(&mut lambdust, "(remainder 7 (exact -1e20))"), Value::integer(7));
+    assert_eq!(eval(&mut lambdust, "(modulo 7 (exact -1e20))").to_string(), "-99999999999999999993");
+    assert!(lambdust.eval("(remainder (exact 1e22) 0)", Some("<test>")).is_err());
+}
@@ -0,0 +1,46 @@
+//! Multi-line REPL input tests.
+//!
+//! These feed input through the same accumulator the REPLs use behind
+//! their continuation prompt and evaluate the completed expression.
+
+#![cfg(any(feature = "minimal-repl", feature = "repl", feature = "enhanced-repl"))]
+
+use lambdust::repl::MultilineInput;
+use lambdust::{Lambdust, Value};
+
+#[cfg(any(feature = "repl", feature = "enhanced-repl"))]
+#[test]
+fn test_multiline_editing_enabled_by_default() {
+    assert!(lambdust::repl::ReplConfig::default().multiline_editing);
+}
+
+#[test]
+fn test_two_line_define_across_continuation_prompt() {
+    let mut input = MultilineInput::new(true);
+    let mut lambdust = Lambdust::new();
+
+    // First line leaves the definition open, so the REPL shows the
+    // continuation prompt instead of evaluating
+    assert!(input.feed("(define (square x)").is_none());
+    assert!(input.is_pending());
+
+    let complete = input.feed("  (* x x))").expect("definition should be complete");
+    lambdust.eval(&complete, Some("<repl>")).unwrap();
+
+    let result = lambdust.eval("(square 7)", Some("<repl>")).unwrap();
+    assert_eq!(result, Value::integer(49));
+}
+
+#[test]
+fn test_string_with_parens_does_not_trigger_continuation() {
+    let mut input = MultilineInput::new(true);
+
+    let complete = input
+        .feed("(define greeting \"(hello\")")
+        .expect("parens inside strings must not affect balance");
+
+    let mut lambdust = Lambdust::new();
+    lambdust.eval(&complete, Some("<repl>")).unwrap();
+    let result = lambdust.eval("greeting", Some("<repl>")).unwrap();
+    assert_eq!(result, Value::string("(hello"));
+}
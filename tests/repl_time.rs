@@ -0,0 +1,22 @@
+//! The REPL `:time` command's result and timing.
+#![cfg(feature = "minimal-repl")]
+
+use lambdust::repl::time_eval;
+use lambdust::{Lambdust, Value};
+use std::time::Duration;
+
+#[test]
+fn test_time_reports_value_and_elapsed_time() {
+    let mut lambdust = Lambdust::new();
+    let timing = time_eval(&mut lambdust, "(vector-length (make-vector 1000000))").unwrap();
+
+    assert_eq!(timing.value, Value::integer(1_000_000));
+    assert!(timing.elapsed < Duration::from_secs(60));
+    assert_eq!(timing.to_string(), format!("time: {:?}", timing.elapsed));
+}
+
+#[test]
+fn test_time_propagates_errors() {
+    let mut lambdust = Lambdust::new();
+    assert!(time_eval(&mut lambdust, "(car '())").is_err());
+}
@@ -0,0 +1,27 @@
+//! Rounding keeps exactness, and `exact` of a rounded float is an exact
+//! integer of any size.
+
+mod common;
+
+use common::eval;
+use lambdust::{Lambdust, Value};
+
+#[test]
+fn test_exact_of_rounded_floats() {
+    let mut lambdust = Lambdust::new();
+    assert_eq!(eval(&mut lambdust, "(exact (truncate 3.7))"), Value::integer(3));
+    assert_eq!(eval(&mut lambdust, "(exact (floor -3.7))"), Value::integer(-4));
+    assert_eq!(eval(&mut lambdust, "(exact (round 2.5))"), Value::integer(2));
+    assert_eq!(eval(&mut lambdust, "(truncate 3.7)"), eval(&mut lambdust, "3.0"));
+    assert_eq!(eval(&mut lambdust, "(round 7/2)"), Value::integer(4));
+}
+
+#[test]
+fn test_large_floats_become_exact_big_integers() {
+    let mut lambdust = Lambdust::new();
+    let big = eval(&mut lambdust, "(exact (floor 1e20))");
+    assert_eq!(big.to_string(), "100000000000000000000");
+    assert_eq!(eval(&mut lambdust, "(exact-integer? (exact (floor 1e20)))"), Value::boolean(true));
+    assert_eq!(eval(&mut lambdust, "(= (exact 1e20) (exact (floor 1e20)))"), Value::boolean(true));
+    assert!(lambdust.eval("(exact +inf.0)", Some("<test>")).is_err());
+}
@@ -0,0 +1,62 @@
+//! `floor->exact`, `ceiling->exact`, `truncate->exact` and `round->exact`.
+
+mod common;
+
+use common::eval;
+use lambdust::{Lambdust, Value};
+
+#[test]
+fn test_each_rounding_gives_exact_integers() {
+    let mut lambdust = Lambdust::new();
+    let cases = [
+        ("floor->exact", [(2.5, 2), (-2.5, -3), (7.0, 7)]),
+        ("ceiling->exact", [(2.5, 3), (-2.5, -2), (7.0, 7)]),
+        ("truncate->exact", [(2.7, 2), (-2.7, -2), (7.0, 7)]),
+        ("round->exact", [(2.5, 2), (-3.5, -4), (2.6, 3)]),
+    ];
+    for (procedure, samples) in cases {
+        for (input, expected) in samples {
+            let source = format!("({procedure} {input:?})");
+            assert_eq!(eval(&mut lambdust, &source), Value::integer(expected), "{source}");
+            let exact = format!("(exact-integer? {source})");
+            assert_eq!(eval(&mut lambdust, &exact), Value::boolean(true), "{exact}");
+        }
+    }
+}
+
+#[test]
+fn test_exact_arguments() {
+    let mut lambdust = Lambdust::new();
+    assert_eq!(eval(&mut lambdust, "(floor->exact 7/2)"), Value::integer(3));
+    assert_eq!(eval(&mut lambdust, "(ceiling->exact -7/2)"), Value::integer(-3));
+    assert_eq!(eval(&mut lambdust, "(round->exact 5)"), Value::integer(5));
+}
+
+#[test]
+fn test_large_floats_become_big_integers() {
+    let mut lambdust = Lambdust::new();
+    for procedure in ["floor->exact", "ceiling->exact", "truncate->exact", "round->exact"] {
+        assert_eq!(
+            eval(&mut lambdust, &format!("(= ({procedure} 1e20) (exact 1e20))")),
+            Value::boolean(true),
+            "{procedure}"
+        );
+        assert_eq!(
+            eval(&mut lambdust, &format!("(exact-integer? ({procedure} -1e30))")),
+            Value::boolean(true),
+            "{procedure}"
+        );
+    }
+}
+
+#[test]
+fn test_infinities_and_nan_are_errors() {
+    let mut lambdust = Lambdust::new();
+    for procedure in ["floor->exact", "ceiling->exact", "truncate->exact", "round->exact"] {
+        for value in ["+inf.0", "-inf.0", "+nan.0"] {
+            let source = format!("({procedure} {value})");
+            assert!(lambdust.eval(&source, Some("<test>")).is_err(), "{source}");
+        }
+    }
+    assert!(lambdust.eval("(floor->exact 'a)", Some("<test>")).is_err());
+}
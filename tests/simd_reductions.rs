@@ -0,0 +1,29 @@
+//! SIMD `sum`, `min` and `max` reductions over numeric vectors.
+
+use lambdust::numeric::NumericValue;
+
+#[test]
+fn test_inexact_vectors_reduce_in_floating_point() {
+    let values: Vec<f64> = (0..1000).map(|i| ((i * 37) % 1000) as f64 - 500.0).collect();
+    let vector = NumericValue::real_vector(values.clone());
+
+    let expected: f64 = values.iter().sum();
+    match vector.simd_sum().unwrap() {
+        NumericValue::Real(sum) => assert!((sum - expected).abs() < 1e-9),
+        other => panic!("expected a real sum, got {other:?}"),
+    }
+    assert_eq!(vector.simd_min().unwrap(), NumericValue::Real(-500.0));
+    assert_eq!(vector.simd_max().unwrap(), NumericValue::Real(499.0));
+}
+
+#[test]
+fn test_exact_vectors_stay_exact() {
+    let exact = NumericValue::Vector((1..=100).map(NumericValue::integer).collect());
+    assert_eq!(exact.simd_sum().unwrap(), NumericValue::integer(5050));
+    assert_eq!(exact.simd_min().unwrap(), NumericValue::integer(1));
+    assert_eq!(exact.simd_max().unwrap(), NumericValue::integer(100));
+
+    assert_eq!(NumericValue::Vector(vec![]).simd_sum().unwrap(), NumericValue::integer(0));
+    assert!(NumericValue::Vector(vec![]).simd_min().is_err());
+    assert!(NumericValue::integer(3).simd_max().is_err());
+}
@@ -0,0 +1,43 @@
+//! `string`, `make-string` and `list->string` build strings from
+//! characters and reject anything else, naming the offending value.
+
+mod common;
+
+use common::eval;
+use lambdust::{Lambdust, Value};
+
+fn error_message(lambdust: &mut Lambdust, source: &str) -> String {
+    lambdust.eval(source, Some("<test>")).unwrap_err().to_string()
+}
+
+#[test]
+fn test_string_from_chars() {
+    let mut lambdust = Lambdust::new();
+    assert_eq!(eval(&mut lambdust, "(string)"), Value::string(""));
+    assert_eq!(eval(&mut lambdust, "(string #\\a)"), Value::string("a"));
+    assert_eq!(eval(&mut lambdust, "(string #\\a #\\λ #\\space #\\z)"), Value::string("aλ z"));
+    assert!(error_message(&mut lambdust, "(string #\\a 42 #\\b)").contains("42"));
+    assert!(error_message(&mut lambdust, "(string \"a\")").contains("\"a\""));
+}
+
+#[test]
+fn test_make_string_fill() {
+    let mut lambdust = Lambdust::new();
+    assert_eq!(eval(&mut lambdust, "(string=? (make-string 3 #\\x) \"xxx\")"), Value::boolean(true));
+    assert_eq!(eval(&mut lambdust, "(string=? (make-string 3) \"   \")"), Value::boolean(true));
+    assert_eq!(eval(&mut lambdust, "(string-length (make-string 0 #\\x))"), Value::integer(0));
+    assert!(error_message(&mut lambdust, "(make-string 3 'x)").contains("x"));
+    assert!(error_message(&mut lambdust, "(make-string 'three #\\x)").contains("three"));
+    assert!(lambdust.eval("(make-string -1)", Some("<test>")).is_err());
+}
+
+#[test]
+fn test_list_to_string_validates_elements() {
+    let mut lambdust = Lambdust::new();
+    assert_eq!(eval(&mut lambdust, "(list->string '())"), Value::string(""));
+    assert_eq!(eval(&mut lambdust, "(list->string (list #\\o #\\k))"), Value::string("ok"));
+    assert!(error_message(&mut lambdust, "(list->string (list #\\a 7))").contains('7'));
+    assert!(error_message(&mut lambdust, "(list->string (list #\\a \"b\"))").contains("\"b\""));
+    assert!(lambdust.eval("(list->string (cons #\\a #\\b))", Some("<test>")).is_err());
+    assert!(lambdust.eval("(list->string #\\a)", Some("<test>")).is_err());
+}
@@ -0,0 +1,41 @@
+//! `string-count` with characters, char-sets, predicates and bounds.
+
+mod common;
+
+use common::eval;
+use lambdust::{Lambdust, Value};
+
+#[test]
+fn test_count_with_char_set() {
+    let mut lambdust = Lambdust::new();
+    eval(&mut lambdust, "(define vowels (string->char-set \"aeiou\"))");
+    assert_eq!(eval(&mut lambdust, "(string-count \"the quick brown fox\" vowels)"), Value::integer(5));
+    assert_eq!(eval(&mut lambdust, "(string-count \"rhythm\" vowels)"), Value::integer(0));
+}
+
+#[test]
+fn test_count_specific_char() {
+    let mut lambdust = Lambdust::new();
+    assert_eq!(eval(&mut lambdust, "(string-count \"banana\" #\\a)"), Value::integer(3));
+    // Counting is over Unicode scalar values
+    assert_eq!(eval(&mut lambdust, "(string-count \"λx.λy.x\" #\\λ)"), Value::integer(2));
+    assert_eq!(eval(&mut lambdust, "(string-count \"\" #\\a)"), Value::integer(0));
+}
+
+#[test]
+fn test_count_with_predicate() {
+    let mut lambdust = Lambdust::new();
+    assert_eq!(eval(&mut lambdust, "(string-count \"a1b22c333\" char-numeric?)"), Value::integer(6));
+    assert!(lambdust.eval("(string-count \"abc\" (lambda (c) (error \"bad\" c)))", Some("<test>")).is_err());
+}
+
+#[test]
+fn test_bounded_count() {
+    let mut lambdust = Lambdust::new();
+    assert_eq!(eval(&mut lambdust, "(string-count \"banana\" #\\a 2)"), Value::integer(2));
+    assert_eq!(eval(&mut lambdust, "(string-count \"banana\" #\\a 1 4)"), Value::integer(2));
+    assert_eq!(eval(&mut lambdust, "(string-count \"banana\" #\\a 3 3)"), Value::integer(0));
+    assert!(lambdust.eval("(string-count \"banana\" #\\a 4 2)", Some("<test>")).is_err());
+    assert!(lambdust.eval("(string-count \"banana\" #\\a 0 7)", Some("<test>")).is_err());
+    assert!(lambdust.eval("(string-count \"banana\" #\\a -1)", Some("<test>")).is_err());
+}
@@ -0,0 +1,67 @@
+//! Large string/list conversions and `string-for-each` over scalar values.
+
+mod common;
+
+use common::eval;
+use lambdust::{Lambdust, Value};
+use std::time::{Duration, Instant};
+
+#[test]
+fn test_large_string_round_trip() {
+    let mut lambdust = Lambdust::new();
+    eval(&mut lambdust, "(define text (make-string 200000 #\\λ))");
+    eval(&mut lambdust, "(string-set! text 0 #\\a)");
+    eval(&mut lambdust, "(string-set! text 199999 #\\z)");
+
+    let start = Instant::now();
+    eval(&mut lambdust, "(define chars (string->list text))");
+    eval(&mut lambdust, "(define back (list->string chars))");
+    let elapsed = start.elapsed();
+
+    assert_eq!(eval(&mut lambdust, "(length chars)"), Value::integer(200_000));
+    assert_eq!(eval(&mut lambdust, "(car chars)"), eval(&mut lambdust, "#\\a"));
+    assert_eq!(eval(&mut lambdust, "(list-ref chars 100000)"), eval(&mut lambdust, "#\\λ"));
+    assert_eq!(eval(&mut lambdust, "(string=? back text)"), Value::boolean(true));
+    assert!(elapsed < Duration::from_secs(10), "conversion took {elapsed:?}");
+
+    // Releasing a list drops its pairs recursively, which would overflow the
+    // test thread's stack on 200k pairs, so the interpreter is leaked
+    std::mem::forget(lambdust);
+}
+
+#[test]
+fn test_string_to_list_range() {
+    let mut lambdust = Lambdust::new();
+    assert_eq!(
+        eval(&mut lambdust, "(list->string (string->list \"añbc\" 1 3))"),
+        Value::string("ñb")
+    );
+    assert_eq!(eval(&mut lambdust, "(string->list \"abc\" 3)"), Value::Nil);
+}
+
+#[test]
+fn test_string_for_each_accumulates() {
+    // Results are accumulated in vector cells because a `set!` made by a
+    // closure only changes its own copy of the environment: even
+    // (let ((n 0)) (for-each (lambda (x) (set! n (+ n x))) '(1 2 3)) n)
+    // evaluates to 0. That is a known evaluator bug, not string-for-each's.
+    let mut lambdust = Lambdust::new();
+    eval(&mut lambdust, "(define total (vector 0))");
+    eval(
+        &mut lambdust,
+        "(string-for-each
+           (lambda (c) (vector-set! total 0 (+ (vector-ref total 0) (char->integer c))))
+           \"aé😀\")",
+    );
+    assert_eq!(eval(&mut lambdust, "(vector-ref total 0)"), Value::integer(97 + 0xE9 + 0x1F600));
+
+    eval(&mut lambdust, "(define pairs (vector '()))");
+    eval(
+        &mut lambdust,
+        "(string-for-each (lambda (a b) (vector-set! pairs 0 (cons (string a b) (vector-ref pairs 0)))) \"abc\" \"xy\")",
+    );
+    assert_eq!(
+        eval(&mut lambdust, "(vector-ref pairs 0)"),
+        Value::list(vec![Value::string("by"), Value::string("ax")])
+    );
+}
@@ -0,0 +1,41 @@
+//! `string-set!` and `string-fill!` on mutable strings, including
+//! replacements that change the UTF-8 length of a character.
+
+mod common;
+
+use common::eval;
+use lambdust::{Lambdust, Value};
+
+#[test]
+fn test_string_set_replaces_ascii_with_multibyte() {
+    let mut lambdust = Lambdust::new();
+    eval(&mut lambdust, "(define s (make-string 3 #\\a))");
+    eval(&mut lambdust, "(string-set! s 1 #\\λ)");
+    assert_eq!(eval(&mut lambdust, "s"), Value::string("aλa"));
+    assert_eq!(eval(&mut lambdust, "(string-length s)"), Value::integer(3));
+    assert_eq!(eval(&mut lambdust, "(string-ref s 1)"), eval(&mut lambdust, "#\\λ"));
+    assert_eq!(eval(&mut lambdust, "(string-ref s 2)"), eval(&mut lambdust, "#\\a"));
+
+    // And back to a single byte
+    eval(&mut lambdust, "(string-set! s 1 #\\b)");
+    assert_eq!(eval(&mut lambdust, "s"), Value::string("aba"));
+}
+
+#[test]
+fn test_string_fill_with_bounds() {
+    let mut lambdust = Lambdust::new();
+    eval(&mut lambdust, "(define s (string-copy \"abcde\"))");
+    eval(&mut lambdust, "(string-fill! s #\\語 1 3)");
+    assert_eq!(eval(&mut lambdust, "s"), Value::string("a語語de"));
+    eval(&mut lambdust, "(string-fill! s #\\z)");
+    assert_eq!(eval(&mut lambdust, "s"), Value::string("zzzzz"));
+}
+
+#[test]
+fn test_literal_strings_are_immutable() {
+    let mut lambdust = Lambdust::new();
+    let error = lambdust.eval("(string-set! \"abc\" 0 #\\x)", Some("<test>")).unwrap_err().to_string();
+    assert!(error.contains("immutable"), "{error}");
+    assert!(lambdust.eval("(string-fill! \"abc\" #\\x)", Some("<test>")).is_err());
+    assert!(lambdust.eval("(string-set! (make-string 2 #\\a) 2 #\\b)", Some("<test>")).is_err());
+}
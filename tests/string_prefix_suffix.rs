@@ -0,0 +1,42 @@
+//! `string-prefix?`, `string-suffix?` and the prefix and suffix lengths.
+
+mod common;
+
+use common::eval;
+use lambdust::{Lambdust, Value};
+
+#[test]
+fn test_whole_strings() {
+    let mut lambdust = Lambdust::new();
+    let cases = [
+        ("(string-prefix? \"λx\" \"λx.x\")", Value::boolean(true)),
+        ("(string-prefix? \"x.\" \"λx.x\")", Value::boolean(false)),
+        ("(string-suffix? \".x\" (string-copy \"λx.x\"))", Value::boolean(true)),
+        ("(string-suffix? \"λx.x!\" \"λx.x\")", Value::boolean(false)),
+        ("(string-prefix? \"\" \"abc\")", Value::boolean(true)),
+        ("(string-prefix-length \"λxy\" \"λxz\")", Value::integer(2)),
+        ("(string-suffix-length \"abcλ\" \"xbcλ\")", Value::integer(3)),
+    ];
+    for (source, expected) in cases {
+        assert_eq!(eval(&mut lambdust, source), expected, "{source}");
+    }
+}
+
+#[test]
+fn test_bounded_ranges() {
+    let mut lambdust = Lambdust::new();
+    let cases = [
+        ("(string-prefix? \"abcd\" \"xbcx\" 1 3 1 3)", Value::boolean(true)),
+        ("(string-prefix? \"bc\" \"xbcx\" 0 2 1 3)", Value::boolean(true)),
+        ("(string-suffix? \"abcd\" \"xbcx\" 1 3 0 3)", Value::boolean(true)),
+        ("(string-prefix? \"hello\" \"help\" 0 3)", Value::boolean(true)),
+        ("(string-prefix-length \"abcd\" \"abxd\" 0 4 0 4)", Value::integer(2)),
+        ("(string-suffix-length \"abcd\" \"zzcd\" 0 3 0 3)", Value::integer(1)),
+    ];
+    for (source, expected) in cases {
+        assert_eq!(eval(&mut lambdust, source), expected, "{source}");
+    }
+
+    assert!(lambdust.eval("(string-prefix? \"abc\" \"abc\" 2 1 0 3)", Some("<test>")).is_err());
+    assert!(lambdust.eval("(string-suffix? \"abc\" \"abc\" 0 3 0 4)", Some("<test>")).is_err());
+}
@@ -0,0 +1,46 @@
+//! `string-ref` on large strings goes through a cached scalar index, which
+//! must never be applied to a string it was not built from.
+
+mod common;
+
+use common::eval;
+use lambdust::ast::Literal;
+use lambdust::{Lambdust, Value};
+
+fn character(c: char) -> Value {
+    Value::Literal(Literal::Character(c))
+}
+
+#[test]
+fn test_string_ref_on_large_strings() {
+    let mut lambdust = Lambdust::new();
+    let text = format!("{}λ{}", "a".repeat(300), "b".repeat(300));
+    eval(&mut lambdust, &format!("(define s \"{text}\")"));
+
+    assert_eq!(eval(&mut lambdust, "(string-ref s 0)"), character('a'));
+    assert_eq!(eval(&mut lambdust, "(string-ref s 300)"), character('λ'));
+    assert_eq!(eval(&mut lambdust, "(string-ref s 600)"), character('b'));
+    assert!(lambdust.eval("(string-ref s 601)", Some("<test>")).is_err());
+}
+
+#[test]
+fn test_string_ref_on_strings_of_different_widths() {
+    let mut lambdust = Lambdust::new();
+    let narrow = "a".repeat(300);
+    let wide = "é".repeat(150);
+
+    // Both literals are the same number of bytes, and the first one's
+    // storage may be reused for the second
+    assert_eq!(
+        eval(&mut lambdust, &format!("(string-ref \"{narrow}\" 1)")),
+        character('a')
+    );
+    assert_eq!(
+        eval(&mut lambdust, &format!("(string-ref \"{wide}\" 1)")),
+        character('é')
+    );
+    assert_eq!(
+        eval(&mut lambdust, &format!("(string-ref \"{wide}\" 149)")),
+        character('é')
+    );
+}
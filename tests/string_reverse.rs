@@ -0,0 +1,50 @@
+//! Scalar and grapheme-cluster string reversal.
+
+mod common;
+
+use common::eval;
+use lambdust::{Lambdust, Value};
+
+#[test]
+fn test_combining_accent_stays_on_its_base() {
+    let mut lambdust = Lambdust::new();
+    // "café" spelled with e followed by U+0301 COMBINING ACUTE ACCENT
+    eval(&mut lambdust, "(define cafe (string #\\c #\\a #\\f #\\e #\\x301))");
+
+    // Naive scalar reversal moves the accent onto nothing at the front
+    assert_eq!(eval(&mut lambdust, "(string-reverse cafe)"), Value::string("\u{301}efac"));
+    // Grapheme reversal keeps "é" together
+    assert_eq!(eval(&mut lambdust, "(string-reverse/graphemes cafe)"), Value::string("e\u{301}fac"));
+    assert_eq!(
+        eval(&mut lambdust, "(string=? (string-reverse cafe) (string-reverse/graphemes cafe))"),
+        Value::boolean(false)
+    );
+}
+
+#[test]
+fn test_emoji_zwj_sequence_reverses_as_one_cluster() {
+    let mut lambdust = Lambdust::new();
+    // A family emoji joined by zero width joiners, followed by "ab"
+    let family = "\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}";
+    eval(&mut lambdust, &format!("(define s \"{family}ab\")"));
+    assert_eq!(
+        eval(&mut lambdust, "(string-reverse/graphemes s)"),
+        Value::string(format!("ba{family}"))
+    );
+    assert_eq!(eval(&mut lambdust, "(string-reverse/graphemes \"\")"), Value::string(""));
+}
+
+#[test]
+fn test_string_reverse_in_place() {
+    let mut lambdust = Lambdust::new();
+    eval(&mut lambdust, "(define s (string-copy \"abcdef\"))");
+    eval(&mut lambdust, "(string-reverse! s)");
+    assert_eq!(eval(&mut lambdust, "s"), Value::string("fedcba"));
+    // The range is end-exclusive: "edc" at indices 1..4 becomes "cde"
+    eval(&mut lambdust, "(string-reverse! s 1 4)");
+    assert_eq!(eval(&mut lambdust, "s"), Value::string("fcdeba"));
+
+    assert!(lambdust.eval("(string-reverse! \"literal\")", Some("<test>")).is_err());
+    assert!(lambdust.eval("(string-reverse! s 4 2)", Some("<test>")).is_err());
+    assert!(lambdust.eval("(string-reverse! s 0 7)", Some("<test>")).is_err());
+}
@@ -0,0 +1,35 @@
+//! `substring` and `string-copy` ranges and the independence of copies.
+
+mod common;
+
+use common::eval;
+use lambdust::{Lambdust, Value};
+
+#[test]
+fn test_ranges_count_characters() {
+    let mut lambdust = Lambdust::new();
+    assert_eq!(eval(&mut lambdust, "(substring \"λx.y\" 1 3)"), Value::string("x."));
+    assert_eq!(eval(&mut lambdust, "(string-copy \"λx.y\" 2)"), Value::string(".y"));
+    assert_eq!(eval(&mut lambdust, "(string-copy \"λx.y\" 0 1)"), Value::string("λ"));
+    assert_eq!(eval(&mut lambdust, "(substring \"λx.y\" 4 4)"), Value::string(""));
+
+    for source in ["(substring \"abc\" 3 1)", "(substring \"abc\" 0 4)", "(substring \"abc\" -1 2)", "(string-copy \"abc\" 4)"] {
+        assert!(lambdust.eval(source, Some("<test>")).is_err(), "{source}");
+    }
+}
+
+#[test]
+fn test_copies_are_fresh_mutable_strings() {
+    let mut lambdust = Lambdust::new();
+    eval(
+        &mut lambdust,
+        "(define source (string-copy \"abc\"))
+         (define copy (string-copy source))
+         (define piece (substring source 0 2))
+         (string-set! copy 0 #\\X)
+         (string-set! piece 1 #\\Y)",
+    );
+    assert_eq!(eval(&mut lambdust, "source"), Value::string("abc"));
+    assert_eq!(eval(&mut lambdust, "copy"), Value::string("Xbc"));
+    assert_eq!(eval(&mut lambdust, "piece"), Value::string("aY"));
+}
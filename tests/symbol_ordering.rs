@@ -0,0 +1,34 @@
+//! `symbol=?` and `symbol<?` comparisons.
+
+mod common;
+
+use common::eval;
+use lambdust::{Lambdust, Value};
+
+#[test]
+fn test_symbol_equal_chain() {
+    let mut lambdust = Lambdust::new();
+    assert_eq!(eval(&mut lambdust, "(symbol=? 'a 'a 'a)"), Value::boolean(true));
+    assert_eq!(eval(&mut lambdust, "(symbol=? 'a 'a 'b)"), Value::boolean(false));
+    assert_eq!(eval(&mut lambdust, "(symbol=? 'a (string->symbol \"a\"))"), Value::boolean(true));
+}
+
+#[test]
+fn test_symbol_less_orders_by_name() {
+    let mut lambdust = Lambdust::new();
+    assert_eq!(eval(&mut lambdust, "(symbol<? 'pear 'apple)"), Value::boolean(false));
+    assert_eq!(eval(&mut lambdust, "(symbol<? 'apple 'fig 'pear)"), Value::boolean(true));
+    assert_eq!(eval(&mut lambdust, "(symbol<? 'fig 'fig)"), Value::boolean(false));
+}
+
+#[test]
+fn test_symbol_from_str_is_eq_to_read_symbol() {
+    let mut lambdust = Lambdust::new();
+    assert_eq!(eval(&mut lambdust, "'pear"), Value::symbol_from_str("pear"));
+}
+
+#[test]
+fn test_symbol_comparison_rejects_non_symbols() {
+    let mut lambdust = Lambdust::new();
+    assert!(lambdust.eval("(symbol<? 'a \"b\")", Some("<test>")).is_err());
+}
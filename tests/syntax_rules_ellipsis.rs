@@ -0,0 +1,77 @@
+//! `syntax-rules` with a custom ellipsis identifier and `_` wildcards.
+
+mod common;
+
+use common::{assert_equal, eval};
+use lambdust::{Lambdust, Value};
+
+#[test]
+fn test_custom_ellipsis_replaces_dots() {
+    let mut lambdust = Lambdust::new();
+    eval(
+        &mut lambdust,
+        "(define-syntax my-list
+           (syntax-rules ::: ()
+             ((_ x :::) (list x :::))))",
+    );
+
+    assert_equal(
+        eval(&mut lambdust, "(my-list 1 2 3)"),
+        Value::list(vec![Value::integer(1), Value::integer(2), Value::integer(3)])
+    );
+}
+
+#[test]
+fn test_dots_are_a_pattern_variable_under_custom_ellipsis() {
+    let mut lambdust = Lambdust::new();
+    eval(
+        &mut lambdust,
+        "(define-syntax first-and-rest
+           (syntax-rules ::: ()
+             ((_ ... x :::) (list ... (list x :::)))))",
+    );
+
+    assert_equal(
+        eval(&mut lambdust, "(first-and-rest 1 2 3)"),
+        Value::list(vec![
+            Value::integer(1),
+            Value::list(vec![Value::integer(2), Value::integer(3)]),
+        ])
+    );
+}
+
+#[test]
+fn test_wildcards_ignore_their_arguments() {
+    let mut lambdust = Lambdust::new();
+    eval(
+        &mut lambdust,
+        "(define-syntax middle
+           (syntax-rules ()
+             ((_ _ b _) b)))",
+    );
+
+    // Each `_` matches independently, so the ignored operands may differ
+    assert_eq!(eval(&mut lambdust, "(middle 1 2 \"three\")"), Value::integer(2));
+    assert_eq!(eval(&mut lambdust, "(middle (car '()) 2 undefined-variable)"), Value::integer(2));
+}
+
+#[test]
+fn test_wildcard_under_custom_ellipsis() {
+    let mut lambdust = Lambdust::new();
+    eval(
+        &mut lambdust,
+        "(define-syntax count-args
+           (syntax-rules ::: ()
+             ((_ _ :::) (quote done))))",
+    );
+
+    assert_eq!(eval(&mut lambdust, "(count-args a b c)"), Value::symbol_from_str("done"));
+}
+
+#[test]
+fn test_misplaced_custom_ellipsis_errors() {
+    let mut lambdust = Lambdust::new();
+    assert!(lambdust
+        .eval("(define-syntax bad (syntax-rules ::: () ((::: x) x)))", Some("<test>"))
+        .is_err());
+}
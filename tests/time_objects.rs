@@ -0,0 +1,69 @@
+//! SRFI-19 style time objects: `current-time`, `time-difference` and `time->string`.
+
+mod common;
+
+use common::eval;
+use lambdust::{Lambdust, Value};
+
+fn as_f64(value: Value) -> f64 {
+    match value {
+        Value::Literal(literal) => literal.to_f64().expect("a real number"),
+        other => panic!("expected a number, got {other}"),
+    }
+}
+
+#[test]
+fn test_monotonic_difference_around_a_sleep() {
+    let mut lambdust = Lambdust::new();
+    eval(&mut lambdust, "(define start (current-time 'time-monotonic))");
+    std::thread::sleep(std::time::Duration::from_millis(20));
+    eval(&mut lambdust, "(define elapsed (time-difference (current-time 'time-monotonic) start))");
+
+    assert_eq!(eval(&mut lambdust, "(time-type elapsed)"), Value::symbol_from_str("time-duration"));
+    let seconds = as_f64(eval(&mut lambdust, "(time->seconds elapsed)"));
+    assert!(seconds >= 0.02, "elapsed {seconds}s is shorter than the sleep");
+    assert!(seconds < 5.0, "elapsed {seconds}s is implausibly long");
+}
+
+#[test]
+fn test_real_clock_times_are_epoch_seconds() {
+    let mut lambdust = Lambdust::new();
+    let scheme_seconds = as_f64(eval(&mut lambdust, "(time->seconds (current-time))"));
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_secs_f64();
+    assert!((scheme_seconds - now).abs() < 5.0);
+    assert_eq!(eval(&mut lambdust, "(time-type (current-time 'time-utc))"), Value::symbol_from_str("time-utc"));
+    assert_eq!(eval(&mut lambdust, "(time? (current-time))"), Value::boolean(true));
+    assert_eq!(eval(&mut lambdust, "(time? 42)"), Value::boolean(false));
+}
+
+#[test]
+fn test_formatting_a_real_clock_time() {
+    let mut lambdust = Lambdust::new();
+    let formatted = eval(&mut lambdust, "(time->string (current-time))");
+    let text = formatted.as_string().expect("a string").to_string();
+    // e.g. 2024-01-31T12:00:00Z
+    assert_eq!(text.len(), 20, "unexpected timestamp {text}");
+    assert_eq!(&text[4..5], "-");
+    assert_eq!(&text[10..11], "T");
+    assert!(text.ends_with('Z'));
+
+    let year = eval(&mut lambdust, "(string->number (time->string (current-time) \"%Y\"))");
+    assert!(as_f64(year) >= 2024.0);
+}
+
+#[test]
+fn test_time_errors() {
+    let mut lambdust = Lambdust::new();
+    for source in [
+        "(current-time 'time-tai)",
+        "(time-difference (current-time) (current-time 'time-monotonic))",
+        "(time->string (current-time 'time-monotonic))",
+        "(time->string (current-time) 42)",
+        "(time->seconds 1)",
+    ] {
+        assert!(lambdust.eval(source, Some("<test>")).is_err(), "{source} should fail");
+    }
+}
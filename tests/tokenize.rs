@@ -0,0 +1,30 @@
+//! `tokenize` splitting strings on a char-set of delimiters.
+
+mod common;
+
+use common::{assert_equal, eval};
+use lambdust::{Lambdust, Value};
+
+#[test]
+fn test_delimiters_are_dropped_by_default() {
+    let mut lambdust = Lambdust::new();
+    assert_equal(
+        eval(&mut lambdust, "(tokenize \"/usr//local/bin/\" (char-set #\\/))"),
+        eval(&mut lambdust, "'(\"usr\" \"local\" \"bin\")"),
+    );
+    assert_equal(
+        eval(&mut lambdust, "(tokenize \"añ/βγ\" (char-set #\\/))"),
+        eval(&mut lambdust, "'(\"añ\" \"βγ\")"),
+    );
+    assert_eq!(eval(&mut lambdust, "(tokenize \"///\" (char-set #\\/))"), Value::Nil);
+}
+
+#[test]
+fn test_delimiters_can_be_kept() {
+    let mut lambdust = Lambdust::new();
+    assert_equal(
+        eval(&mut lambdust, "(tokenize \"a \\tb\" (char-set #\\space #\\tab) #t)"),
+        eval(&mut lambdust, "'(\"a\" \" \" \"\\t\" \"b\")"),
+    );
+    assert!(lambdust.eval("(tokenize \"a b\" \" \")", Some("<test>")).is_err());
+}
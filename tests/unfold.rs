@@ -0,0 +1,67 @@
+//! SRFI-1 `unfold` and `unfold-right` list builders.
+
+mod common;
+
+use common::{assert_equal, eval};
+use lambdust::{Lambdust, Value};
+
+fn integers(values: &[i64]) -> Value {
+    Value::list(values.iter().map(|&i| Value::integer(i)).collect())
+}
+
+#[test]
+fn test_unfold_builds_ascending_list() {
+    let mut lambdust = Lambdust::new();
+    assert_eq!(
+        eval(&mut lambdust, "(unfold (lambda (x) (> x 5)) (lambda (x) x) (lambda (x) (+ x 1)) 1)"),
+        integers(&[1, 2, 3, 4, 5])
+    );
+    assert_eq!(
+        eval(&mut lambdust, "(unfold (lambda (x) (> x 3)) (lambda (x) (* x x)) (lambda (x) (+ x 1)) 1)"),
+        integers(&[1, 4, 9])
+    );
+}
+
+#[test]
+fn test_unfold_tail_generator() {
+    let mut lambdust = Lambdust::new();
+    assert_equal(
+        eval(
+            &mut lambdust,
+            "(unfold null? car cdr '(1 2 3) (lambda (x) (list 'end)))"
+        ),
+        eval(&mut lambdust, "'(1 2 3 end)")
+    );
+}
+
+#[test]
+fn test_unfold_right_builds_descending_list() {
+    let mut lambdust = Lambdust::new();
+    assert_eq!(
+        eval(&mut lambdust, "(unfold-right zero? (lambda (x) x) (lambda (x) (- x 1)) 5)"),
+        integers(&[1, 2, 3, 4, 5])
+    );
+    assert_eq!(
+        eval(&mut lambdust, "(unfold-right (lambda (x) (> x 5)) (lambda (x) x) (lambda (x) (+ x 1)) 1)"),
+        integers(&[5, 4, 3, 2, 1])
+    );
+    // The optional tail ends the result
+    assert_eq!(
+        eval(&mut lambdust, "(unfold-right null? car cdr '(1 2 3) '(0))"),
+        integers(&[3, 2, 1, 0])
+    );
+}
+
+#[test]
+fn test_immediately_true_stop_yields_empty_list() {
+    let mut lambdust = Lambdust::new();
+    assert_eq!(eval(&mut lambdust, "(unfold (lambda (x) #t) car cdr 'seed)"), Value::Nil);
+    assert_eq!(eval(&mut lambdust, "(unfold-right (lambda (x) #t) car cdr 'seed)"), Value::Nil);
+}
+
+#[test]
+fn test_unfold_requires_procedures() {
+    let mut lambdust = Lambdust::new();
+    assert!(lambdust.eval("(unfold 1 car cdr '(1))", Some("<test>")).is_err());
+    assert!(lambdust.eval("(unfold-right null? car cdr)", Some("<test>")).is_err());
+}
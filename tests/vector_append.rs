@@ -0,0 +1,25 @@
+//! `vector-append` and `vector-concatenate`.
+
+mod common;
+
+use common::eval;
+use lambdust::{Lambdust, Value};
+
+#[test]
+fn test_vector_append() {
+    let mut lambdust = Lambdust::new();
+    assert_eq!(eval(&mut lambdust, "(equal? (vector-append #(1) #() #(2 3)) #(1 2 3))"), Value::boolean(true));
+    assert_eq!(eval(&mut lambdust, "(vector-length (vector-append))"), Value::integer(0));
+    assert!(lambdust.eval("(vector-append #() 1)", Some("<test>")).is_err());
+}
+
+#[test]
+fn test_vector_concatenate() {
+    let mut lambdust = Lambdust::new();
+    assert_eq!(
+        eval(&mut lambdust, "(equal? (vector-concatenate (list #(1 2) #(3))) #(1 2 3))"),
+        Value::boolean(true)
+    );
+    assert_eq!(eval(&mut lambdust, "(vector-length (vector-concatenate '()))"), Value::integer(0));
+    assert!(lambdust.eval("(vector-concatenate (list #() \"abc\"))", Some("<test>")).is_err());
+}
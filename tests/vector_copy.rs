@@ -0,0 +1,58 @@
+//! `vector-copy` and `bytevector-copy` return fresh storage for the
+//! requested range and validate their bounds.
+
+mod common;
+
+use common::eval;
+use lambdust::{Lambdust, Value};
+
+#[test]
+fn test_subrange_copy_is_independent_of_source() {
+    let mut lambdust = Lambdust::new();
+    eval(&mut lambdust, "(define source (vector 0 1 2 3 4))");
+    eval(&mut lambdust, "(define copy (vector-copy source 1 4))");
+    assert_eq!(eval(&mut lambdust, "(vector->list copy)"), eval(&mut lambdust, "'(1 2 3)"));
+
+    eval(&mut lambdust, "(vector-set! source 1 'changed)");
+    eval(&mut lambdust, "(vector-set! copy 2 'mine)");
+    assert_eq!(eval(&mut lambdust, "(vector->list source)"), eval(&mut lambdust, "'(0 changed 2 3 4)"));
+    assert_eq!(eval(&mut lambdust, "(vector->list copy)"), eval(&mut lambdust, "'(1 2 mine)"));
+}
+
+#[test]
+fn test_full_and_empty_copies() {
+    let mut lambdust = Lambdust::new();
+    eval(&mut lambdust, "(define source (vector 'a 'b))");
+    eval(&mut lambdust, "(define full (vector-copy source))");
+    assert_eq!(eval(&mut lambdust, "(eq? full source)"), Value::boolean(false));
+    assert_eq!(eval(&mut lambdust, "(equal? full source)"), Value::boolean(true));
+    eval(&mut lambdust, "(vector-fill! full 'z)");
+    assert_eq!(eval(&mut lambdust, "(vector->list source)"), eval(&mut lambdust, "'(a b)"));
+
+    assert_eq!(eval(&mut lambdust, "(vector-length (vector-copy source 2))"), Value::integer(0));
+    assert_eq!(eval(&mut lambdust, "(vector-length (vector-copy source 1 1))"), Value::integer(0));
+    assert_eq!(eval(&mut lambdust, "(vector-length (vector-copy (vector)))"), Value::integer(0));
+}
+
+#[test]
+fn test_vector_copy_bounds() {
+    let mut lambdust = Lambdust::new();
+    eval(&mut lambdust, "(define v (vector 1 2 3))");
+    for source in ["(vector-copy v 4)", "(vector-copy v -1)", "(vector-copy v 2 1)", "(vector-copy v 0 4)", "(vector-copy '(1 2))"] {
+        assert!(lambdust.eval(source, Some("<test>")).is_err(), "{source}");
+    }
+}
+
+#[test]
+fn test_bytevector_copy_ranges_and_bounds() {
+    let mut lambdust = Lambdust::new();
+    eval(&mut lambdust, "(define bv (bytevector 1 2 3 4 5))");
+    assert_eq!(eval(&mut lambdust, "(bytevector-copy bv)"), eval(&mut lambdust, "#u8(1 2 3 4 5)"));
+    assert_eq!(eval(&mut lambdust, "(bytevector-copy bv 3)"), eval(&mut lambdust, "#u8(4 5)"));
+    assert_eq!(eval(&mut lambdust, "(bytevector-copy bv 1 4)"), eval(&mut lambdust, "#u8(2 3 4)"));
+    assert_eq!(eval(&mut lambdust, "(bytevector-copy bv 5)"), eval(&mut lambdust, "#u8()"));
+    assert_eq!(eval(&mut lambdust, "(bytevector-copy bv 2 2)"), eval(&mut lambdust, "#u8()"));
+    for source in ["(bytevector-copy bv 6)", "(bytevector-copy bv -1)", "(bytevector-copy bv 3 2)", "(bytevector-copy bv 0 6)"] {
+        assert!(lambdust.eval(source, Some("<test>")).is_err(), "{source}");
+    }
+}
@@ -0,0 +1,68 @@
+//! `vector-map/index`, `vector-for-each/index` and `vector-count` with
+//! user-defined procedures.
+
+mod common;
+
+use common::{assert_equal, eval};
+use lambdust::{Lambdust, Value};
+
+#[test]
+fn test_map_index_pairs_indices_with_values() {
+    let mut lambdust = Lambdust::new();
+    assert_eq!(
+        eval(&mut lambdust, "(equal? (vector-map/index cons (vector 'a 'b 'c)) (vector '(0 . a) '(1 . b) '(2 . c)))"),
+        Value::boolean(true)
+    );
+    // Several vectors are walked in step, up to the shortest
+    assert_eq!(
+        eval(
+            &mut lambdust,
+            "(equal? (vector-map/index (lambda (i x y) (+ i x y)) (vector 10 20 30) (vector 1 2)) (vector 11 23))",
+        ),
+        Value::boolean(true)
+    );
+    assert_eq!(eval(&mut lambdust, "(vector-length (vector-map/index cons (vector)))"), Value::integer(0));
+}
+
+#[test]
+fn test_for_each_index_visits_in_order() {
+    let mut lambdust = Lambdust::new();
+    eval(&mut lambdust, "(define seen (vector '()))");
+    eval(
+        &mut lambdust,
+        "(vector-for-each/index
+           (lambda (i x) (vector-set! seen 0 (cons (list i x) (vector-ref seen 0))))
+           (vector \"x\" \"y\"))",
+    );
+    assert_equal(eval(&mut lambdust, "(vector-ref seen 0)"), eval(&mut lambdust, "'((1 \"y\") (0 \"x\"))"));
+
+    eval(&mut lambdust, "(define calls (vector 0))");
+    eval(
+        &mut lambdust,
+        "(vector-for-each/index (lambda (i x) (vector-set! calls 0 (+ (vector-ref calls 0) 1))) (vector))",
+    );
+    assert_eq!(eval(&mut lambdust, "(vector-ref calls 0)"), Value::integer(0));
+}
+
+#[test]
+fn test_vector_count() {
+    let mut lambdust = Lambdust::new();
+    assert_eq!(eval(&mut lambdust, "(vector-count even? (vector 1 2 3 4 6))"), Value::integer(3));
+    assert_eq!(eval(&mut lambdust, "(vector-count even? (vector))"), Value::integer(0));
+    // Any non-#f result counts, not just #t
+    assert_eq!(
+        eval(&mut lambdust, "(vector-count (lambda (x) (memv x '(2 3))) (vector 1 2 3 4))"),
+        Value::integer(2)
+    );
+    assert_eq!(
+        eval(&mut lambdust, "(vector-count < (vector 1 5 3) (vector 2 4 6 8))"),
+        Value::integer(2)
+    );
+}
+
+#[test]
+fn test_non_procedure_is_an_error() {
+    let mut lambdust = Lambdust::new();
+    assert!(lambdust.eval("(vector-count 1 (vector 1))", Some("<test>")).is_err());
+    assert!(lambdust.eval("(vector-map/index cons '(1 2))", Some("<test>")).is_err());
+}
@@ -0,0 +1,35 @@
+//! `vector-binary-search`, `vector-index` and `vector-index-right` with
+//! user-defined comparators and predicates.
+
+mod common;
+
+use common::eval;
+use lambdust::{Lambdust, Value};
+
+#[test]
+fn test_binary_search_sorted_numbers() {
+    let mut lambdust = Lambdust::new();
+    eval(&mut lambdust, "(define v (vector 1 4 9 16 25 36 49))");
+    eval(&mut lambdust, "(define (cmp a b) (- a b))");
+
+    assert_eq!(eval(&mut lambdust, "(vector-binary-search v 25 cmp)"), Value::integer(4));
+    assert_eq!(eval(&mut lambdust, "(vector-binary-search v 1 cmp)"), Value::integer(0));
+    assert_eq!(eval(&mut lambdust, "(vector-binary-search v 10 cmp)"), Value::boolean(false));
+    assert_eq!(eval(&mut lambdust, "(vector-binary-search v 0 cmp)"), Value::boolean(false));
+    assert_eq!(eval(&mut lambdust, "(vector-binary-search v 50 cmp)"), Value::boolean(false));
+    assert_eq!(eval(&mut lambdust, "(vector-binary-search (vector) 1 cmp)"), Value::boolean(false));
+}
+
+#[test]
+fn test_vector_index_on_mixed_vector() {
+    let mut lambdust = Lambdust::new();
+    eval(&mut lambdust, "(define v (vector 'a \"b\" 3 #t 5 \"c\"))");
+
+    assert_eq!(eval(&mut lambdust, "(vector-index number? v)"), Value::integer(2));
+    assert_eq!(eval(&mut lambdust, "(vector-index-right number? v)"), Value::integer(4));
+    assert_eq!(
+        eval(&mut lambdust, "(vector-index (lambda (x) (and (number? x) (> x 4))) v)"),
+        Value::integer(4)
+    );
+    assert_eq!(eval(&mut lambdust, "(vector-index char? v)"), Value::boolean(false));
+}
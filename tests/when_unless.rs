@@ -0,0 +1,24 @@
+//! `when` and `unless` evaluate their body only when the test allows it.
+
+mod common;
+
+use common::eval;
+use lambdust::{Lambdust, Value};
+
+#[test]
+fn test_body_value_is_returned() {
+    let mut lambdust = Lambdust::new();
+    assert_eq!(eval(&mut lambdust, "(when #t 1 2 3)"), Value::integer(3));
+    assert_eq!(eval(&mut lambdust, "(unless #f 1 2 3)"), Value::integer(3));
+    assert_eq!(eval(&mut lambdust, "(when '() 'empty-list-is-true)"), eval(&mut lambdust, "'empty-list-is-true"));
+}
+
+#[test]
+fn test_skipped_body_is_not_evaluated() {
+    let mut lambdust = Lambdust::new();
+    eval(&mut lambdust, "(define count 0)");
+    assert_eq!(eval(&mut lambdust, "(when #f (set! count 1) undefined-var)"), Value::Unspecified);
+    assert_eq!(eval(&mut lambdust, "(unless #t (set! count 1) undefined-var)"), Value::Unspecified);
+    assert_eq!(eval(&mut lambdust, "count"), Value::integer(0));
+    assert_eq!(eval(&mut lambdust, "(when #t)"), Value::Unspecified);
+}
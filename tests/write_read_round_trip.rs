@@ -0,0 +1,145 @@
+//! `read` returns a datum equal to the one `write` printed, for every readable type.
+
+mod common;
+
+use common::eval;
+use lambdust::{Lambdust, Value};
+
+fn round_trip_interpreter() -> Lambdust {
+    let mut lambdust = Lambdust::new();
+    eval(
+        &mut lambdust,
+        "(define (round-trip x)
+           (let ((out (open-output-string)))
+             (write x out)
+             (read (open-input-string (get-output-string out)))))",
+    );
+    lambdust
+}
+
+#[test]
+fn test_representative_values_round_trip() {
+    let mut lambdust = round_trip_interpreter();
+    let samples = [
+        // Numbers
+        "0", "-42", "9007199254740993", "1.0", "-0.5", "1e20", "1.5e-7", "3/4", "-7/2",
+        "(make-rectangular 1.5 -2.0)", "(make-rectangular 0.0 1.0)", "+inf.0", "-inf.0",
+        // Characters
+        "#\\a", "#\\space", "#\\newline", "#\\x41", "#\\(", "#\\x", "(integer->char 7)",
+        "(integer->char 0)", "(integer->char 127)", "(integer->char 1)", "#\\λ",
+        // Strings
+        "\"\"", "\"plain\"", "\"quote \\\" and backslash \\\\\"", "\"tab\\tline\\n\"",
+        "(string (integer->char 1) #\\| #\\x3bb)",
+        // Symbols
+        "'abc", "'list->vector", "'+", "(string->symbol \"...\")", "(string->symbol \"hello world\")",
+        "(string->symbol \"\")", "(string->symbol \"42\")", "(string->symbol \"a|b\\\\c\")",
+        "(string->symbol \"(paren)\")", "(string->symbol \"+1\")",
+        // Booleans and lists
+        "#t", "#f", "'()", "(cons 1 (cons (list 2.5 \"three\") (cons #\\4 'five)))",
+        // Vectors and bytevectors
+        "(vector)", "(vector 1 \"two\" #\\3 'four (list 5.5 (vector)))", "#u8()", "#u8(0 127 255)",
+        "#()", "#(1 #(2) (a . b) if)",
+    ];
+
+    for source in samples {
+        eval(&mut lambdust, &format!("(define original {source})"));
+        eval(&mut lambdust, "(define back (round-trip original))");
+
+        assert_eq!(
+            eval(&mut lambdust, "(equal? back original)"),
+            Value::boolean(true),
+            "{source} did not read back as an equal datum"
+        );
+        // The written forms also agree, which catches a change of exactness
+        assert_eq!(
+            eval(&mut lambdust, "back").to_string(),
+            eval(&mut lambdust, "original").to_string(),
+            "{source}"
+        );
+    }
+}
+
+#[test]
+fn test_nan_round_trips() {
+    let mut lambdust = round_trip_interpreter();
+    assert_eq!(eval(&mut lambdust, "(nan? (round-trip +nan.0))"), Value::boolean(true));
+}
+
+#[test]
+fn test_written_forms() {
+    let mut lambdust = Lambdust::new();
+    let written = |lambdust: &mut Lambdust, source: &str| {
+        let value = eval(lambdust, &format!("(let ((out (open-output-string))) (write {source} out) (get-output-string out))"));
+        value.as_string_owned().unwrap()
+    };
+
+    assert_eq!(written(&mut lambdust, "(string->symbol \"hello world\")"), "|hello world|");
+    assert_eq!(written(&mut lambdust, "(integer->char 27)"), "#\\escape");
+    assert_eq!(written(&mut lambdust, "(string (integer->char 1))"), "\"\\x1;\"");
+    assert_eq!(written(&mut lambdust, "#u8(1 2)"), "#u8(1 2)");
+}
+
+#[test]
+fn test_vector_literals_in_source() {
+    let mut lambdust = Lambdust::new();
+    assert_eq!(eval(&mut lambdust, "(vector-ref #(1 2 3) 1)"), Value::integer(2));
+    assert_eq!(
+        eval(&mut lambdust, "(equal? (vector-map * #(1 2 3) #(1 2 3)) #(1 4 9))"),
+        Value::boolean(true)
+    );
+    // Elements are data, quoted or not
+    assert_eq!(eval(&mut lambdust, "#(a (if b) \"c\")").to_string(), "#(a (if b) \"c\")");
+    assert_eq!(eval(&mut lambdust, "(equal? '#(x 1) #(x 1))"), Value::boolean(true));
+    assert_eq!(eval(&mut lambdust, "(vector-length (cadr '(#() #(1 2))))"), Value::integer(2));
+}
+
+#[test]
+fn test_reading_several_data_from_one_port() {
+    let mut lambdust = Lambdust::new();
+    eval(
+        &mut lambdust,
+        "(define port (open-input-string \"(1 #(2 #\\\\)) . 3) |a b| #u8(7) \\\"x y\\\" ; comment\\n sym\"))",
+    );
+
+    let data: Vec<String> = (0..5).map(|_| eval(&mut lambdust, "(read port)").to_string()).collect();
+    assert_eq!(data, ["(1 #(2 #\\)) . 3)", "|a b|", "#u8(7)", "\"x y\"", "sym"]);
+    assert_eq!(eval(&mut lambdust, "(eof-object? (read port))"), Value::boolean(true));
+}
+
+#[test]
+fn test_named_characters_round_trip() {
+    let mut lambdust = round_trip_interpreter();
+    let names = [
+        ("null", 0), ("alarm", 7), ("backspace", 8), ("tab", 9), ("newline", 10),
+        ("return", 13), ("escape", 27), ("space", 32), ("delete", 127),
+    ];
+
+    for (name, code) in names {
+        let literal = format!("#\\{name}");
+        assert_eq!(eval(&mut lambdust, &format!("(char->integer {literal})")), Value::integer(code), "{literal}");
+        assert_eq!(
+            eval(&mut lambdust, &format!("(let ((out (open-output-string))) (write {literal} out) (get-output-string out))")),
+            Value::string(literal.clone())
+        );
+        assert_eq!(
+            eval(&mut lambdust, &format!("(eqv? (round-trip {literal}) (integer->char {code}))")),
+            Value::boolean(true),
+            "{literal}"
+        );
+    }
+
+    // Hex escapes read as the character and write back under its name or itself
+    assert_eq!(eval(&mut lambdust, "(eqv? #\\x7f #\\delete)"), Value::boolean(true));
+    assert_eq!(eval(&mut lambdust, "(round-trip #\\x3bb)").to_string(), "#\\λ");
+    assert_eq!(eval(&mut lambdust, "(round-trip #\\x1)").to_string(), "#\\x1");
+}
+
+#[test]
+fn test_malformed_character_literals_are_errors() {
+    let mut lambdust = Lambdust::new();
+    for source in ["#\\xZZ", "#\\x41q", "#\\spacebar", "#\\xD800"] {
+        assert!(lambdust.eval(source, Some("<test>")).is_err(), "{source}");
+    }
+    // A lone x is the letter itself
+    assert_eq!(eval(&mut lambdust, "(char->integer #\\x)"), Value::integer(120));
+}